@@ -0,0 +1,216 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! The message flow of a minimal tiling window manager, using only XRB's
+//! types.
+//!
+//! XRB doesn't connect to an X server itself, so the connection is simulated
+//! here: requests are written to an outgoing buffer, and events are read from
+//! bytes as they would be received from the X server. A real window manager
+//! would send and receive these bytes over its connection, having already
+//! completed the connection setup.
+
+use cornflakes::{Readable, Writable};
+
+use xrb::x11::{
+	events::{ConfigureRequest, DestroyNotify, MapRequest, UnmapNotify},
+	requests::{ChangeWindowAttributes, ConfigureWindow, MapWindow},
+	Attribute,
+	AttributeMask,
+	ConfigureWindowMask,
+	ConfigureWindowValues,
+	EventMask,
+	StackMode,
+	Window,
+};
+
+/// The width of the (simulated) screen.
+const SCREEN_WIDTH: u16 = 1920;
+/// The height of the (simulated) screen.
+const SCREEN_HEIGHT: u16 = 1080;
+
+/// A window manager which tiles its windows side-by-side in columns.
+struct WindowManager {
+	/// The requests which would be sent to the X server.
+	requests: Vec<u8>,
+	/// The windows currently being managed, from left to right.
+	clients: Vec<Window>,
+}
+
+impl WindowManager {
+	fn new() -> Self {
+		Self {
+			requests: Vec::new(),
+			clients: Vec::new(),
+		}
+	}
+
+	/// Writes the given `request` to be sent to the X server.
+	fn send(&mut self, request: &impl Writable) {
+		request
+			.write_to(&mut self.requests)
+			.expect("requests can always be written to a Vec");
+	}
+
+	/// Selects `SubstructureRedirect` on the `root` window, so that the window
+	/// manager decides how its children are mapped and configured.
+	///
+	/// Only one client may select `SubstructureRedirect` on a window, so this
+	/// fails with an `Access` error if another window manager is running.
+	fn manage(&mut self, root: Window) {
+		let values = [Attribute::EventMask(
+			EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+		)];
+
+		self.send(&ChangeWindowAttributes {
+			target: root,
+			value_mask: AttributeMask::EVENT_MASK,
+			values: &values,
+		});
+	}
+
+	/// Reads an event from the given `bytes` and handles it.
+	fn handle_event(&mut self, bytes: &[u8]) -> Result<(), cornflakes::ReadError> {
+		// The first byte is the event code: the most significant bit is set if
+		// the event was sent with `SendEvent`.
+		let Some((code, mut reader)) = bytes.split_first() else {
+			return Ok(());
+		};
+		let reader = &mut reader;
+
+		match code & 0x7f {
+			17 => {
+				let event = DestroyNotify::read_from(reader)?;
+				self.unmanage(event.window);
+			}
+
+			18 => {
+				let event = UnmapNotify::read_from(reader)?;
+				self.unmanage(event.window);
+			}
+
+			20 => {
+				let event = MapRequest::read_from(reader)?;
+
+				if !self.clients.contains(&event.window) {
+					self.clients.push(event.window);
+				}
+
+				self.send(&MapWindow {
+					target: event.window,
+				});
+				self.tile();
+			}
+
+			23 => {
+				let event = ConfigureRequest::read_from(reader)?;
+
+				if self.clients.contains(&event.window) {
+					// Managed windows are placed by the window manager, so
+					// their geometry is restored rather than changed.
+					self.tile();
+				} else {
+					// Windows which aren't managed yet are configured as they
+					// requested.
+					self.send(&ConfigureWindow {
+						target: event.window,
+						values: event.values(),
+					});
+				}
+			}
+
+			// Other events aren't relevant to this window manager.
+			_ => {}
+		}
+
+		Ok(())
+	}
+
+	/// Stops managing the given `window`, if it is managed.
+	fn unmanage(&mut self, window: Window) {
+		if let Some(index) = self.clients.iter().position(|client| *client == window) {
+			self.clients.remove(index);
+			self.tile();
+		}
+	}
+
+	/// Configures every managed window to fill its column of the screen.
+	fn tile(&mut self) {
+		let Ok(columns) = u16::try_from(self.clients.len()) else {
+			return;
+		};
+
+		if columns == 0 {
+			return;
+		}
+
+		let width = SCREEN_WIDTH / columns;
+
+		for (column, client) in (0..columns).zip(self.clients.clone()) {
+			let x = (column * width).try_into().unwrap_or(i16::MAX);
+
+			self.send(&ConfigureWindow {
+				target: client,
+				values: ConfigureWindowValues::new()
+					.position(x, 0)
+					.size(width, SCREEN_HEIGHT)
+					.restack(StackMode::Below, None),
+			});
+		}
+	}
+}
+
+/// Returns the bytes the X server would send for the given `event`.
+fn receive(event: &impl Writable) -> Vec<u8> {
+	let mut bytes = Vec::new();
+	event
+		.write_to(&mut bytes)
+		.expect("events can always be written to a Vec");
+
+	bytes
+}
+
+fn main() -> Result<(), cornflakes::ReadError> {
+	let root = Window::new(0x0000_0100);
+	let (terminal, editor) = (Window::new(0x0040_0001), Window::new(0x0060_0001));
+
+	let mut wm = WindowManager::new();
+	wm.manage(root);
+
+	// Two clients ask for their windows to be mapped...
+	for window in [terminal, editor] {
+		wm.handle_event(&receive(&MapRequest {
+			_sequence_: 1,
+			parent: root,
+			window,
+		}))?;
+	}
+
+	// ...one of them tries to resize itself...
+	wm.handle_event(&receive(&ConfigureRequest {
+		_sequence_: 2,
+		stack_mode: StackMode::Above,
+		parent: root,
+		window: editor,
+		sibling: None,
+		x: 0,
+		y: 0,
+		width: 640,
+		height: 480,
+		border_width: 0,
+		value_mask: ConfigureWindowMask::WIDTH | ConfigureWindowMask::HEIGHT,
+	}))?;
+
+	// ...and then the other is closed.
+	wm.handle_event(&receive(&DestroyNotify {
+		_sequence_: 3,
+		event: root,
+		window: terminal,
+	}))?;
+
+	assert_eq!(wm.clients, [editor]);
+	println!("{} bytes of requests would be sent", wm.requests.len());
+
+	Ok(())
+}
@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Feeds arbitrary bytes into every event, reply, and error decoder.
+//!
+//! Reading a message must return an error, rather than panicking, however
+//! malformed or truncated its bytes are. Run with `cargo fuzz run decode`.
+
+#![no_main]
+
+use cornflakes::Readable;
+use libfuzzer_sys::fuzz_target;
+
+use xrb::x11::{errors, events, extensions::*};
+
+// Generates the list of functions reading each of the given types, discarding
+// the result: only whether reading panics matters.
+macro_rules! decoders {
+	($($type:ty),* $(,)?) => {
+		[$(
+			|mut bytes: &[u8]| {
+				let _ = <$type as Readable>::read_from(&mut bytes);
+			},
+		)*]
+	};
+}
+
+const DECODERS: &[fn(&[u8])] = &decoders![
+	errors::AnyError,
+	events::DestroyNotify,
+	events::UnmapNotify,
+	events::MapRequest,
+	events::ConfigureRequest,
+	events::CirculateNotify,
+	events::CirculateRequest,
+	composite::QueryVersionReply,
+	composite::GetOverlayWindowReply,
+	damage::QueryVersionReply,
+	damage::DamageNotify,
+	render::QueryVersionReply,
+	render::QueryPictFormatsReply,
+	shm::QueryVersionReply,
+	shm::GetImageReply,
+	shm::CreateSegmentReply,
+	shm::ShmCompletion,
+	xc_misc::GetVersionReply,
+	xc_misc::GetXidRangeReply,
+	xc_misc::GetXidListReply,
+	xfixes::QueryVersionReply,
+	xfixes::FetchRegionReply,
+];
+
+fuzz_target!(|data: &[u8]| {
+	// The first byte chooses which decoder to use.
+	if let Some((&choice, bytes)) = data.split_first() {
+		DECODERS[usize::from(choice) % DECODERS.len()](bytes);
+	}
+});
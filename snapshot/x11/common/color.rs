@@ -0,0 +1,294 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::ColorChannelMask;
+#[doc = " A color with 16 bits for each channel, as used in colormaps."]
+#[doc = ""]
+#[doc = " The X server scales each channel to the number of bits its colormaps"]
+#[doc = " actually support, so `0xffff` is always the full intensity of a"]
+#[doc = " channel."]
+#[doc(alias = "RGB")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Rgb16 {
+	#[doc = " The intensity of the red channel."]
+	pub red: u16,
+	#[doc = " The intensity of the green channel."]
+	pub green: u16,
+	#[doc = " The intensity of the blue channel."]
+	pub blue: u16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Rgb16 {
+	fn static_byte_size() -> usize {
+		<u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Rgb16 {
+	fn byte_size(&self) -> usize {
+		let Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__red__)
+			+ cornflakes::ByteSize::byte_size(__green__)
+			+ cornflakes::ByteSize::byte_size(__blue__)
+	}
+}
+#[doc = " The color to store in a colormap cell in a [`StoreColors`] request."]
+#[doc = ""]
+#[doc = " [`StoreColors`]: crate::x11::requests::StoreColors"]
+#[doc(alias = "COLORITEM")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ColorItem {
+	#[doc = " The pixel value of the colormap cell."]
+	pub pixel: u32,
+	#[doc = " The color to store in the cell."]
+	pub color: Rgb16,
+	#[doc = " Which of the channels of the `color` are stored; the others are"]
+	#[doc = " left unchanged."]
+	pub channels: ColorChannelMask,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ColorItem {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <Rgb16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <ColorChannelMask as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ColorItem {
+	fn byte_size(&self) -> usize {
+		let Self {
+			pixel: __pixel__,
+			color: __color__,
+			channels: __channels__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__pixel__)
+			+ cornflakes::ByteSize::byte_size(__color__)
+			+ cornflakes::ByteSize::byte_size(__channels__)
+	}
+}
+impl cornflakes::Writable for Rgb16 {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+		} = self;
+		cornflakes::Writable::write_to(__red__, writer)?;
+		cornflakes::Writable::write_to(__green__, writer)?;
+		cornflakes::Writable::write_to(__blue__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Rgb16 {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __red__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __green__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __blue__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Rgb16 {
+	const NAME: &'static str = "Rgb16";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+		} = self;
+		cornflakes::Writable::write_to(__red__, writer)?;
+		writer.field("red", __red__);
+		cornflakes::Writable::write_to(__green__, writer)?;
+		writer.field("green", __green__);
+		cornflakes::Writable::write_to(__blue__, writer)?;
+		writer.field("blue", __blue__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for ColorItem {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			pixel: __pixel__,
+			color: __color__,
+			channels: __channels__,
+		} = self;
+		cornflakes::Writable::write_to(__pixel__, writer)?;
+		cornflakes::Writable::write_to(__color__, writer)?;
+		cornflakes::Writable::write_to(__channels__, writer)?;
+		writer.put_u8(0);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ColorItem {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __pixel__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __color__ = <Rgb16 as cornflakes::Readable>::read_from(reader)?;
+		let __channels__ = <ColorChannelMask as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		Ok(Self {
+			pixel: __pixel__,
+			color: __color__,
+			channels: __channels__,
+		})
+	}
+}
+impl xrb::PrettyPrint for ColorItem {
+	const NAME: &'static str = "ColorItem";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			pixel: __pixel__,
+			color: __color__,
+			channels: __channels__,
+		} = self;
+		cornflakes::Writable::write_to(__pixel__, writer)?;
+		writer.field("pixel", __pixel__);
+		cornflakes::Writable::write_to(__color__, writer)?;
+		writer.field("color", __color__);
+		cornflakes::Writable::write_to(__channels__, writer)?;
+		writer.field("channels", __channels__);
+		writer.put_u8(0);
+		writer.unused();
+		Ok(())
+	}
+}
+#[doc = " A color with 8 bits for each channel, as commonly used outside of the X"]
+#[doc = " protocol."]
+#[doc = ""]
+#[doc = " This is converted to an [`Rgb16`] to be sent to the X server."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Rgb {
+	#[doc = " The intensity of the red channel."]
+	pub red: u8,
+	#[doc = " The intensity of the green channel."]
+	pub green: u8,
+	#[doc = " The intensity of the blue channel."]
+	pub blue: u8,
+}
+impl Rgb16 {
+	#[doc = " Black: every channel at zero intensity."]
+	pub const BLACK: Self = Self::new(0, 0, 0);
+	#[doc = " White: every channel at full intensity."]
+	pub const WHITE: Self = Self::new(0xffff, 0xffff, 0xffff);
+	#[doc = " Creates a new [`Rgb16`] with the given channels."]
+	#[must_use]
+	pub const fn new(red: u16, green: u16, blue: u16) -> Self {
+		Self { red, green, blue }
+	}
+	#[doc = " Converts this color to an [`Rgb`], rounding each channel to the"]
+	#[doc = " nearest 8-bit intensity."]
+	#[must_use]
+	pub const fn to_rgb(self) -> Rgb {
+		#[doc = " Scales a 16-bit channel to 8 bits."]
+		#[allow(clippy::cast_possible_truncation)]
+		const fn scale(channel: u16) -> u8 {
+			((channel as u32 * 0xff + 0x7fff) / 0xffff) as u8
+		}
+		Rgb::new(scale(self.red), scale(self.green), scale(self.blue))
+	}
+}
+impl Rgb {
+	#[doc = " Creates a new [`Rgb`] with the given channels."]
+	#[must_use]
+	pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+		Self { red, green, blue }
+	}
+	#[doc = " Creates a new [`Rgb`] from a hex code, such as `0xff8000`."]
+	#[doc = ""]
+	#[doc = " Bits above the lower 24 are ignored."]
+	#[must_use]
+	pub const fn from_hex(hex: u32) -> Self {
+		let [_, red, green, blue] = hex.to_be_bytes();
+		Self::new(red, green, blue)
+	}
+	#[doc = " Converts this color to an [`Rgb16`], scaling each channel so that full"]
+	#[doc = " intensity remains full intensity."]
+	#[must_use]
+	pub const fn to_rgb16(self) -> Rgb16 {
+		#[doc = " Scales an 8-bit channel to 16 bits."]
+		const fn scale(channel: u8) -> u16 {
+			channel as u16 * 0x0101
+		}
+		Rgb16::new(scale(self.red), scale(self.green), scale(self.blue))
+	}
+}
+impl From<Rgb> for Rgb16 {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_rgb16()
+	}
+}
+impl ColorItem {
+	#[doc = " Creates a new [`ColorItem`] which stores every channel of the `color`"]
+	#[doc = " in the cell with the given `pixel` value."]
+	#[must_use]
+	pub const fn new(pixel: u32, color: Rgb16) -> Self {
+		Self {
+			pixel,
+			color,
+			channels: ColorChannelMask::all(),
+		}
+	}
+	#[doc = " Only stores the given `channels` of the color."]
+	#[must_use]
+	pub const fn with_channels(mut self, channels: ColorChannelMask) -> Self {
+		self.channels = channels;
+		self
+	}
+}
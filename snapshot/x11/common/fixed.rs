@@ -0,0 +1,420 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[doc = " A fixed-point number with 16 integer bits and 16 fractional bits."]
+#[doc = ""]
+#[doc = " No message in the X protocol contains floating point numbers; non-integer"]
+#[doc = " values, such as those used by the RENDER extension, are instead sent as"]
+#[doc = " fixed-point numbers. This means that `Eq` and `Hash` can be derived for"]
+#[doc = " every message."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Fixed(i32);
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Fixed {
+	fn static_byte_size() -> usize {
+		<i32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Fixed {
+	fn byte_size(&self) -> usize {
+		let Self(__0__) = self;
+		cornflakes::ByteSize::byte_size(__0__)
+	}
+}
+#[doc = " A fixed-point number with 32 integer bits and 32 fractional bits."]
+#[doc = ""]
+#[doc = " This is written as its signed integer part followed by its unsigned"]
+#[doc = " fractional part."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FixedPoint {
+	integral: i32,
+	fractional: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for FixedPoint {
+	fn static_byte_size() -> usize {
+		<i32 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for FixedPoint {
+	fn byte_size(&self) -> usize {
+		let Self {
+			integral: __integral__,
+			fractional: __fractional__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__integral__)
+			+ cornflakes::ByteSize::byte_size(__fractional__)
+	}
+}
+impl cornflakes::Writable for Fixed {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self(__0__) = self;
+		cornflakes::Writable::write_to(__0__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Fixed {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __0__ = <i32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self(__0__))
+	}
+}
+impl xrb::PrettyPrint for Fixed {
+	const NAME: &'static str = "Fixed";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self(__0__) = self;
+		cornflakes::Writable::write_to(__0__, writer)?;
+		writer.field("0", __0__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for FixedPoint {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			integral: __integral__,
+			fractional: __fractional__,
+		} = self;
+		cornflakes::Writable::write_to(__integral__, writer)?;
+		cornflakes::Writable::write_to(__fractional__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FixedPoint {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __integral__ = <i32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __fractional__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			integral: __integral__,
+			fractional: __fractional__,
+		})
+	}
+}
+impl xrb::PrettyPrint for FixedPoint {
+	const NAME: &'static str = "FixedPoint";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			integral: __integral__,
+			fractional: __fractional__,
+		} = self;
+		cornflakes::Writable::write_to(__integral__, writer)?;
+		writer.field("integral", __integral__);
+		cornflakes::Writable::write_to(__fractional__, writer)?;
+		writer.field("fractional", __fractional__);
+		Ok(())
+	}
+}
+#[doc = " How a floating point number is rounded when it is converted to a"]
+#[doc = " fixed-point number."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Rounding {
+	#[doc = " Rounds to the nearest fixed-point number, and away from zero when"]
+	#[doc = " halfway between two."]
+	#[default]
+	Nearest,
+	#[doc = " Rounds towards negative infinity."]
+	Floor,
+	#[doc = " Rounds towards positive infinity."]
+	Ceil,
+	#[doc = " Rounds towards zero."]
+	TowardZero,
+}
+impl Rounding {
+	const fn round(self, value: f64) -> f64 {
+		match self {
+			Self::Nearest => value.round(),
+			Self::Floor => value.floor(),
+			Self::Ceil => value.ceil(),
+			Self::TowardZero => value.trunc(),
+		}
+	}
+}
+impl Fixed {
+	#[doc = " The number of fractional bits in a [`Fixed`] number."]
+	const FRACTIONAL_BITS: u32 = 16;
+	#[doc = " `0`."]
+	pub const ZERO: Self = Self(0);
+	#[doc = " `1`."]
+	pub const ONE: Self = Self(1 << Self::FRACTIONAL_BITS);
+	#[doc = " The smallest value that can be represented: `-32768`."]
+	pub const MIN: Self = Self(i32::MIN);
+	#[doc = " The largest value that can be represented, just under `32768`."]
+	pub const MAX: Self = Self(i32::MAX);
+	#[doc = " Creates a new [`Fixed`] number from its raw 16.16 representation."]
+	#[must_use]
+	pub const fn from_raw(raw: i32) -> Self {
+		Self(raw)
+	}
+	#[doc = " Gets the raw 16.16 representation of the [`Fixed`] number."]
+	#[must_use]
+	pub const fn raw(self) -> i32 {
+		self.0
+	}
+	#[doc = " Creates the [`Fixed`] number closest to the given `value`."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`Fixed`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	pub fn from_f64(value: f64) -> Self {
+		Self::from_f64_rounded(value, Rounding::Nearest)
+	}
+	#[doc = " Creates a [`Fixed`] number from the given `value`, rounded with the"]
+	#[doc = " given `rounding` if it can't be represented exactly."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`Fixed`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "float to int casts saturate, which is the intended behavior"
+	)]
+	pub fn from_f64_rounded(value: f64, rounding: Rounding) -> Self {
+		Self(rounding.round(value * f64::from(1 << Self::FRACTIONAL_BITS)) as i32)
+	}
+	#[doc = " Creates the [`Fixed`] number closest to the given `value`."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`Fixed`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	pub fn from_f32(value: f32) -> Self {
+		Self::from_f64(value.into())
+	}
+	#[doc = " Creates a [`Fixed`] number from the given `value`, rounded with the"]
+	#[doc = " given `rounding` if it can't be represented exactly."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`Fixed`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	pub fn from_f32_rounded(value: f32, rounding: Rounding) -> Self {
+		Self::from_f64_rounded(value.into(), rounding)
+	}
+	#[doc = " Converts the [`Fixed`] number to an `f64`."]
+	#[doc = ""]
+	#[doc = " This conversion is exact."]
+	#[must_use]
+	pub fn to_f64(self) -> f64 {
+		f64::from(self.0) / f64::from(1 << Self::FRACTIONAL_BITS)
+	}
+	#[doc = " The integer part of the [`Fixed`] number, rounded towards negative"]
+	#[doc = " infinity."]
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the 16 integer bits of a `Fixed` number always fit in an `i16`"
+	)]
+	pub const fn floor(self) -> i16 {
+		(self.0 >> Self::FRACTIONAL_BITS) as i16
+	}
+	#[doc = " Adds `other`, returning [`None`] if the result would overflow."]
+	#[must_use]
+	pub const fn checked_add(self, other: Self) -> Option<Self> {
+		match self.0.checked_add(other.0) {
+			Some(raw) => Some(Self(raw)),
+			None => None,
+		}
+	}
+	#[doc = " Subtracts `other`, returning [`None`] if the result would overflow."]
+	#[must_use]
+	pub const fn checked_sub(self, other: Self) -> Option<Self> {
+		match self.0.checked_sub(other.0) {
+			Some(raw) => Some(Self(raw)),
+			None => None,
+		}
+	}
+	#[doc = " Multiplies by `other`, returning [`None`] if the result would overflow."]
+	#[doc = ""]
+	#[doc = " The result is rounded towards negative infinity."]
+	#[must_use]
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		let raw = (i64::from(self.0) * i64::from(other.0)) >> Self::FRACTIONAL_BITS;
+		i32::try_from(raw).ok().map(Self)
+	}
+	#[doc = " Divides by `other`, returning [`None`] if `other` is zero or the result"]
+	#[doc = " would overflow."]
+	#[doc = ""]
+	#[doc = " The result is rounded towards zero."]
+	#[must_use]
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		let raw = (i64::from(self.0) << Self::FRACTIONAL_BITS).checked_div(i64::from(other.0))?;
+		i32::try_from(raw).ok().map(Self)
+	}
+}
+impl From<i16> for Fixed {
+	fn from(value: i16) -> Self {
+		Self(i32::from(value) << Self::FRACTIONAL_BITS)
+	}
+}
+impl FixedPoint {
+	#[doc = " The number of fractional bits in a [`FixedPoint`] number."]
+	const FRACTIONAL_BITS: u32 = 32;
+	#[doc = " `0`."]
+	pub const ZERO: Self = Self::from_raw(0);
+	#[doc = " `1`."]
+	pub const ONE: Self = Self::from_raw(1 << Self::FRACTIONAL_BITS);
+	#[doc = " The smallest value that can be represented: `-2^31`."]
+	pub const MIN: Self = Self::from_raw(i64::MIN);
+	#[doc = " The largest value that can be represented, just under `2^31`."]
+	pub const MAX: Self = Self::from_raw(i64::MAX);
+	#[doc = " Creates a new [`FixedPoint`] number from its integer and fractional"]
+	#[doc = " parts."]
+	#[must_use]
+	pub const fn new(integral: i32, fractional: u32) -> Self {
+		Self {
+			integral,
+			fractional,
+		}
+	}
+	#[doc = " Creates a new [`FixedPoint`] number from its raw 32.32 representation."]
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		reason = "the raw representation is split into its upper and lower 32 bits"
+	)]
+	pub const fn from_raw(raw: i64) -> Self {
+		Self::new((raw >> Self::FRACTIONAL_BITS) as i32, raw as u32)
+	}
+	#[doc = " Gets the raw 32.32 representation of the [`FixedPoint`] number."]
+	#[must_use]
+	pub const fn raw(self) -> i64 {
+		((self.integral as i64) << Self::FRACTIONAL_BITS) | self.fractional as i64
+	}
+	#[doc = " The integer part of the [`FixedPoint`] number, rounded towards negative"]
+	#[doc = " infinity."]
+	#[must_use]
+	pub const fn integral(self) -> i32 {
+		self.integral
+	}
+	#[doc = " The fractional part of the [`FixedPoint`] number, in units of `2^-32`."]
+	#[must_use]
+	pub const fn fractional(self) -> u32 {
+		self.fractional
+	}
+	#[doc = " Creates the [`FixedPoint`] number closest to the given `value`."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`FixedPoint`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	pub fn from_f64(value: f64) -> Self {
+		Self::from_f64_rounded(value, Rounding::Nearest)
+	}
+	#[doc = " Creates a [`FixedPoint`] number from the given `value`, rounded with"]
+	#[doc = " the given `rounding` if it can't be represented exactly."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`FixedPoint`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "float to int casts saturate, which is the intended behavior"
+	)]
+	pub fn from_f64_rounded(value: f64, rounding: Rounding) -> Self {
+		Self::from_raw(rounding.round(value * 2_f64.powi(32)) as i64)
+	}
+	#[doc = " Creates the [`FixedPoint`] number closest to the given `value`."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`FixedPoint`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	pub fn from_f32(value: f32) -> Self {
+		Self::from_f64(value.into())
+	}
+	#[doc = " Creates a [`FixedPoint`] number from the given `value`, rounded with"]
+	#[doc = " the given `rounding` if it can't be represented exactly."]
+	#[doc = ""]
+	#[doc = " Values outside of the range of a [`FixedPoint`] number saturate at its"]
+	#[doc = " minimum or maximum, and NaN is converted to zero."]
+	#[must_use]
+	pub fn from_f32_rounded(value: f32, rounding: Rounding) -> Self {
+		Self::from_f64_rounded(value.into(), rounding)
+	}
+	#[doc = " Converts the [`FixedPoint`] number to an `f64`."]
+	#[doc = ""]
+	#[doc = " This conversion is exact unless the integer part is large enough that"]
+	#[doc = " the value needs more than 53 significant bits."]
+	#[must_use]
+	pub fn to_f64(self) -> f64 {
+		f64::from(self.integral) + f64::from(self.fractional) / 2_f64.powi(32)
+	}
+	#[doc = " Adds `other`, returning [`None`] if the result would overflow."]
+	#[must_use]
+	pub const fn checked_add(self, other: Self) -> Option<Self> {
+		match self.raw().checked_add(other.raw()) {
+			Some(raw) => Some(Self::from_raw(raw)),
+			None => None,
+		}
+	}
+	#[doc = " Subtracts `other`, returning [`None`] if the result would overflow."]
+	#[must_use]
+	pub const fn checked_sub(self, other: Self) -> Option<Self> {
+		match self.raw().checked_sub(other.raw()) {
+			Some(raw) => Some(Self::from_raw(raw)),
+			None => None,
+		}
+	}
+	#[doc = " Multiplies by `other`, returning [`None`] if the result would overflow."]
+	#[doc = ""]
+	#[doc = " The result is rounded towards negative infinity."]
+	#[must_use]
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		let raw = (i128::from(self.raw()) * i128::from(other.raw())) >> Self::FRACTIONAL_BITS;
+		i64::try_from(raw).ok().map(Self::from_raw)
+	}
+	#[doc = " Divides by `other`, returning [`None`] if `other` is zero or the result"]
+	#[doc = " would overflow."]
+	#[doc = ""]
+	#[doc = " The result is rounded towards zero."]
+	#[must_use]
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		let raw =
+			(i128::from(self.raw()) << Self::FRACTIONAL_BITS).checked_div(other.raw().into())?;
+		i64::try_from(raw).ok().map(Self::from_raw)
+	}
+}
+impl From<i32> for FixedPoint {
+	fn from(value: i32) -> Self {
+		Self::new(value, 0)
+	}
+}
+impl From<Fixed> for FixedPoint {
+	fn from(value: Fixed) -> Self {
+		Self::from_raw(i64::from(value.0) << (Self::FRACTIONAL_BITS - Fixed::FRACTIONAL_BITS))
+	}
+}
+macro_rules ! arithmetic { ($ ($ type : ty) ,*) => { $ (arithmetic ! { $ type : Add :: add , AddAssign :: add_assign => checked_add , "overflow when adding" ; Sub :: sub , SubAssign :: sub_assign => checked_sub , "overflow when subtracting" ; Mul :: mul , MulAssign :: mul_assign => checked_mul , "overflow when multiplying" ; Div :: div , DivAssign :: div_assign => checked_div , "division by zero or overflow when dividing" ; } impl Neg for $ type { type Output = Self ; fn neg (self) -> Self { Self :: ZERO - self } }) * } ; ($ type : ty : $ ($ op : ident ::$ method : ident , $ op_assign : ident ::$ method_assign : ident => $ checked : ident , $ message : literal ;) *) => { $ (impl $ op for $ type { type Output = Self ; fn $ method (self , other : Self) -> Self { self .$ checked (other) . expect ($ message) } } impl $ op_assign for $ type { fn $ method_assign (& mut self , other : Self) { * self = $ op ::$ method (* self , other) ; } }) * } ; }
+arithmetic!(Fixed, FixedPoint);
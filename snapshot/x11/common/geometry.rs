@@ -0,0 +1,731 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::Timestamp;
+#[doc = " A point with coordinates."]
+#[doc = ""]
+#[doc = " The units for the coordinates are not specified: they are usually"]
+#[doc = " pixels relative to the origin of a drawable."]
+#[doc(alias = "POINT")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Point {
+	#[doc = " X-coordinate of the point."]
+	pub x: i16,
+	#[doc = " Y-coordinate of the point."]
+	pub y: i16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Point {
+	fn static_byte_size() -> usize {
+		<i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Point {
+	fn byte_size(&self) -> usize {
+		let Self { x: __x__, y: __y__ } = self;
+		cornflakes::ByteSize::byte_size(__x__) + cornflakes::ByteSize::byte_size(__y__)
+	}
+}
+#[doc = " A rectangle with coordinates and dimensions."]
+#[doc = ""]
+#[doc = " The coordinates are those of the upper-left corner of the rectangle. The"]
+#[doc = " units for the coordinates and dimensions are not specified."]
+#[doc(alias = "RECTANGLE")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Rectangle {
+	#[doc = " X-coordinate of the upper-left corner of the rectangle."]
+	pub x: i16,
+	#[doc = " Y-coordinate of the upper-left corner of the rectangle."]
+	pub y: i16,
+	#[doc = " Width of the rectangle."]
+	pub width: u16,
+	#[doc = " Height of the rectangle."]
+	pub height: u16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Rectangle {
+	fn static_byte_size() -> usize {
+		<i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Rectangle {
+	fn byte_size(&self) -> usize {
+		let Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__x__)
+			+ cornflakes::ByteSize::byte_size(__y__)
+			+ cornflakes::ByteSize::byte_size(__width__)
+			+ cornflakes::ByteSize::byte_size(__height__)
+	}
+}
+#[doc = " An arc (the geometry kind) with coordinates, dimensions, and angles."]
+#[doc = ""]
+#[doc = " The arc is part of the ellipse which fits within the rectangle given by"]
+#[doc = " its coordinates and dimensions. Angles are given in 64ths of a degree,"]
+#[doc = " counterclockwise from the three o'clock position."]
+#[doc(alias = "ARC")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GeomArc {
+	#[doc = " X-coordinate of the arc."]
+	pub x: i16,
+	#[doc = " Y-coordinate of the arc."]
+	pub y: i16,
+	#[doc = " Width of the arc."]
+	pub width: u16,
+	#[doc = " Height of the arc."]
+	pub height: u16,
+	#[doc = " The angle at which the arc starts."]
+	pub start: i16,
+	#[doc = " The angle which the arc extends through, relative to its `start`."]
+	pub extent: i16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for GeomArc {
+	fn static_byte_size() -> usize {
+		<i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for GeomArc {
+	fn byte_size(&self) -> usize {
+		let Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			start: __start__,
+			extent: __extent__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__x__)
+			+ cornflakes::ByteSize::byte_size(__y__)
+			+ cornflakes::ByteSize::byte_size(__width__)
+			+ cornflakes::ByteSize::byte_size(__height__)
+			+ cornflakes::ByteSize::byte_size(__start__)
+			+ cornflakes::ByteSize::byte_size(__extent__)
+	}
+}
+#[doc = " A line segment between two points."]
+#[doc(alias = "SEGMENT")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Segment {
+	#[doc = " X-coordinate of the start of the segment."]
+	pub x1: i16,
+	#[doc = " Y-coordinate of the start of the segment."]
+	pub y1: i16,
+	#[doc = " X-coordinate of the end of the segment."]
+	pub x2: i16,
+	#[doc = " Y-coordinate of the end of the segment."]
+	pub y2: i16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Segment {
+	fn static_byte_size() -> usize {
+		<i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Segment {
+	fn byte_size(&self) -> usize {
+		let Self {
+			x1: __x1__,
+			y1: __y1__,
+			x2: __x2__,
+			y2: __y2__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__x1__)
+			+ cornflakes::ByteSize::byte_size(__y1__)
+			+ cornflakes::ByteSize::byte_size(__x2__)
+			+ cornflakes::ByteSize::byte_size(__y2__)
+	}
+}
+#[doc = " The position of the pointer at a point in time, as recorded in its"]
+#[doc = " motion history."]
+#[doc = ""]
+#[doc = " The coordinates are relative to the origin of the window given in the"]
+#[doc = " [`GetMotionEvents`] request."]
+#[doc = ""]
+#[doc = " [`GetMotionEvents`]: crate::x11::requests::GetMotionEvents"]
+#[doc(alias = "TIMECOORD")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TimeCoord {
+	#[doc = " The time at which the pointer was at this position."]
+	pub time: Timestamp,
+	#[doc = " X-coordinate of the pointer."]
+	pub x: i16,
+	#[doc = " Y-coordinate of the pointer."]
+	pub y: i16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for TimeCoord {
+	fn static_byte_size() -> usize {
+		<Timestamp as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for TimeCoord {
+	fn byte_size(&self) -> usize {
+		let Self {
+			time: __time__,
+			x: __x__,
+			y: __y__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__time__)
+			+ cornflakes::ByteSize::byte_size(__x__)
+			+ cornflakes::ByteSize::byte_size(__y__)
+	}
+}
+impl cornflakes::Writable for Point {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { x: __x__, y: __y__ } = self;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Point {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { x: __x__, y: __y__ })
+	}
+}
+impl xrb::PrettyPrint for Point {
+	const NAME: &'static str = "Point";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { x: __x__, y: __y__ } = self;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for Rectangle {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+		} = self;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		cornflakes::Writable::write_to(__height__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Rectangle {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Rectangle {
+	const NAME: &'static str = "Rectangle";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+		} = self;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		cornflakes::Writable::write_to(__width__, writer)?;
+		writer.field("width", __width__);
+		cornflakes::Writable::write_to(__height__, writer)?;
+		writer.field("height", __height__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for GeomArc {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			start: __start__,
+			extent: __extent__,
+		} = self;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		cornflakes::Writable::write_to(__height__, writer)?;
+		cornflakes::Writable::write_to(__start__, writer)?;
+		cornflakes::Writable::write_to(__extent__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GeomArc {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __start__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __extent__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			start: __start__,
+			extent: __extent__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GeomArc {
+	const NAME: &'static str = "GeomArc";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			start: __start__,
+			extent: __extent__,
+		} = self;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		cornflakes::Writable::write_to(__width__, writer)?;
+		writer.field("width", __width__);
+		cornflakes::Writable::write_to(__height__, writer)?;
+		writer.field("height", __height__);
+		cornflakes::Writable::write_to(__start__, writer)?;
+		writer.field("start", __start__);
+		cornflakes::Writable::write_to(__extent__, writer)?;
+		writer.field("extent", __extent__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for Segment {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			x1: __x1__,
+			y1: __y1__,
+			x2: __x2__,
+			y2: __y2__,
+		} = self;
+		cornflakes::Writable::write_to(__x1__, writer)?;
+		cornflakes::Writable::write_to(__y1__, writer)?;
+		cornflakes::Writable::write_to(__x2__, writer)?;
+		cornflakes::Writable::write_to(__y2__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Segment {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x1__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y1__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x2__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y2__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			x1: __x1__,
+			y1: __y1__,
+			x2: __x2__,
+			y2: __y2__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Segment {
+	const NAME: &'static str = "Segment";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			x1: __x1__,
+			y1: __y1__,
+			x2: __x2__,
+			y2: __y2__,
+		} = self;
+		cornflakes::Writable::write_to(__x1__, writer)?;
+		writer.field("x1", __x1__);
+		cornflakes::Writable::write_to(__y1__, writer)?;
+		writer.field("y1", __y1__);
+		cornflakes::Writable::write_to(__x2__, writer)?;
+		writer.field("x2", __x2__);
+		cornflakes::Writable::write_to(__y2__, writer)?;
+		writer.field("y2", __y2__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for TimeCoord {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			time: __time__,
+			x: __x__,
+			y: __y__,
+		} = self;
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for TimeCoord {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			time: __time__,
+			x: __x__,
+			y: __y__,
+		})
+	}
+}
+impl xrb::PrettyPrint for TimeCoord {
+	const NAME: &'static str = "TimeCoord";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			time: __time__,
+			x: __x__,
+			y: __y__,
+		} = self;
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		Ok(())
+	}
+}
+impl Point {
+	#[doc = " Creates a new [`Point`] with the given coordinates."]
+	#[must_use]
+	pub const fn new(x: i16, y: i16) -> Self {
+		Self { x, y }
+	}
+	#[doc = " Moves this point by the given offsets."]
+	#[doc = ""]
+	#[doc = " Like the coordinates of the X protocol, the coordinates wrap on"]
+	#[doc = " overflow."]
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self {
+			x: self.x.wrapping_add(dx),
+			y: self.y.wrapping_add(dy),
+		}
+	}
+}
+impl TimeCoord {
+	#[doc = " The position of the pointer as a [`Point`]."]
+	#[must_use]
+	pub const fn point(self) -> Point {
+		Point::new(self.x, self.y)
+	}
+}
+impl From<(i16, i16)> for Point {
+	fn from((x, y): (i16, i16)) -> Self {
+		Self { x, y }
+	}
+}
+impl From<Point> for (i16, i16) {
+	fn from(Point { x, y }: Point) -> Self {
+		(x, y)
+	}
+}
+impl Rectangle {
+	#[doc = " Creates a new [`Rectangle`] with the given coordinates of its"]
+	#[doc = " upper-left corner and dimensions."]
+	#[must_use]
+	pub const fn new(x: i16, y: i16, width: u16, height: u16) -> Self {
+		Self {
+			x,
+			y,
+			width,
+			height,
+		}
+	}
+	#[doc = " Returns whether the given `point` is within this rectangle."]
+	#[doc = ""]
+	#[doc = " The right and bottom edges are not included."]
+	#[must_use]
+	pub fn contains(&self, point: Point) -> bool {
+		let (x, y) = (i32::from(point.x), i32::from(point.y));
+		(i32::from(self.x)..self.right()).contains(&x)
+			&& (i32::from(self.y)..self.bottom()).contains(&y)
+	}
+	#[doc = " Returns the area covered by both this rectangle and the `other`, or"]
+	#[doc = " [`None`] if they do not overlap."]
+	#[must_use]
+	pub fn intersect(&self, other: &Self) -> Option<Self> {
+		let left = self.x.max(other.x);
+		let top = self.y.max(other.y);
+		let right = self.right().min(other.right());
+		let bottom = self.bottom().min(other.bottom());
+		let width = u16::try_from(right - i32::from(left)).ok()?;
+		let height = u16::try_from(bottom - i32::from(top)).ok()?;
+		(width > 0 && height > 0).then_some(Self::new(left, top, width, height))
+	}
+	#[doc = " Moves this rectangle by the given offsets."]
+	#[doc = ""]
+	#[doc = " Like the coordinates of the X protocol, the coordinates wrap on"]
+	#[doc = " overflow."]
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self {
+			x: self.x.wrapping_add(dx),
+			y: self.y.wrapping_add(dy),
+			..self
+		}
+	}
+	#[doc = " The x-coordinate just beyond the right edge of this rectangle."]
+	fn right(&self) -> i32 {
+		i32::from(self.x) + i32::from(self.width)
+	}
+	#[doc = " The y-coordinate just beyond the bottom edge of this rectangle."]
+	fn bottom(&self) -> i32 {
+		i32::from(self.y) + i32::from(self.height)
+	}
+}
+impl Default for Rectangle {
+	fn default() -> Self {
+		Self {
+			x: 0,
+			y: 0,
+			width: 1,
+			height: 1,
+		}
+	}
+}
+impl GeomArc {
+	#[doc = " The rectangle which bounds the ellipse that this arc is part of."]
+	#[must_use]
+	pub const fn bounds(&self) -> Rectangle {
+		Rectangle::new(self.x, self.y, self.width, self.height)
+	}
+	#[doc = " Moves this arc by the given offsets."]
+	#[doc = ""]
+	#[doc = " Like the coordinates of the X protocol, the coordinates wrap on"]
+	#[doc = " overflow."]
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self {
+			x: self.x.wrapping_add(dx),
+			y: self.y.wrapping_add(dy),
+			..self
+		}
+	}
+}
+impl Segment {
+	#[doc = " Creates a new [`Segment`] between the given points."]
+	#[must_use]
+	pub const fn new(start: Point, end: Point) -> Self {
+		Self {
+			x1: start.x,
+			y1: start.y,
+			x2: end.x,
+			y2: end.y,
+		}
+	}
+	#[doc = " The point at which this segment starts."]
+	#[must_use]
+	pub const fn start(&self) -> Point {
+		Point::new(self.x1, self.y1)
+	}
+	#[doc = " The point at which this segment ends."]
+	#[must_use]
+	pub const fn end(&self) -> Point {
+		Point::new(self.x2, self.y2)
+	}
+	#[doc = " Moves this segment by the given offsets."]
+	#[doc = ""]
+	#[doc = " Like the coordinates of the X protocol, the coordinates wrap on"]
+	#[doc = " overflow."]
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self::new(self.start().translate(dx, dy), self.end().translate(dx, dy))
+	}
+}
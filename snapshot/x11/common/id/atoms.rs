@@ -0,0 +1,387 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+#[doc = " A unique ID corresponding to a defined string name."]
+#[doc = ""]
+#[doc = " Atoms exist to provide a fixed-length representation of common strings. They"]
+#[doc = " are used to identify properties, types, and selection."]
+#[doc = ""]
+#[doc = " An [`InternAtom`] request can be sent to the X server to get or create a"]
+#[doc = " corresponding `Atom` for a given string of text."]
+#[doc = ""]
+#[doc = " # Examples"]
+#[doc = " [`WM_NAME`] is an `Atom` representing a property used for a window's title."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Atom {
+	#[doc = " The ID for the `Atom`."]
+	pub id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Atom {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Atom {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+impl Atom {
+	#[doc = " Creates a new [`Atom`] with the given ID."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Atom`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+#[doc = " An [`Atom`] representing the string \"PRIMARY\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const PRIMARY: Atom = Atom::new(1);
+#[doc = " An [`Atom`] representing the string \"SECONDARY\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const SECONDARY: Atom = Atom::new(2);
+#[doc = " An [`Atom`] representing the string \"ARC\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const ARC: Atom = Atom::new(3);
+#[doc = " An [`Atom`] representing the string \"ATOM\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const ATOM: Atom = Atom::new(4);
+#[doc = " An [`Atom`] representing the string \"BITMAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const BITMAP: Atom = Atom::new(5);
+#[doc = " An [`Atom`] representing the string \"CARDINAL\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CARDINAL: Atom = Atom::new(6);
+#[doc = " An [`Atom`] representing the string \"COLORMAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const COLORMAP: Atom = Atom::new(7);
+#[doc = " An [`Atom`] representing the string \"CURSOR\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CURSOR: Atom = Atom::new(8);
+#[doc = " An [`Atom`] representing the string \"CUR_BUFFER0\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER0: Atom = Atom::new(9);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER1\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER1: Atom = Atom::new(10);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER2\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER2: Atom = Atom::new(11);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER3\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER3: Atom = Atom::new(12);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER4\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER4: Atom = Atom::new(13);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER5\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER5: Atom = Atom::new(14);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER6\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER6: Atom = Atom::new(15);
+#[doc = " An [`Atom`] representing the string \"CUT_BUFFER7\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CUT_BUFFER7: Atom = Atom::new(16);
+#[doc = " An [`Atom`] representing the string \"DRAWABLE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const DRAWABLE: Atom = Atom::new(17);
+#[doc = " An [`Atom`] representing the string \"FONT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const FONT: Atom = Atom::new(18);
+#[doc = " An [`Atom`] representing the string \"INTEGER\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const INTEGER: Atom = Atom::new(19);
+#[doc = " An [`Atom`] representing the string \"PIXMAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const PIXMAP: Atom = Atom::new(20);
+#[doc = " An [`Atom`] representing the string \"POINT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const POINT: Atom = Atom::new(21);
+#[doc = " An [`Atom`] representing the string \"RECTANGLE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RECTANGLE: Atom = Atom::new(22);
+#[doc = " An [`Atom`] representing the string \"RESOURCE_MANAGER\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RESOURCE_MANAGER: Atom = Atom::new(23);
+#[doc = " An [`Atom`] representing the string \"RGB_COLOR_MAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_COLOR_MAP: Atom = Atom::new(24);
+#[doc = " An [`Atom`] representing the string \"RGB_BEST_MAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_BEST_MAP: Atom = Atom::new(25);
+#[doc = " An [`Atom`] representing the string \"RGB_BLUE_MAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_BLUE_MAP: Atom = Atom::new(26);
+#[doc = " An [`Atom`] representing the string \"RGB_DEFAULT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_DEFAULT_MAP: Atom = Atom::new(27);
+#[doc = " An [`Atom`] representing the string \"RGB_GRAY_MAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_GRAY_MAP: Atom = Atom::new(28);
+#[doc = " An [`Atom`] representing the string \"RGB_GREEN_MAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_GREEN_MAP: Atom = Atom::new(29);
+#[doc = " An [`Atom`] representing the string \"RGB_RED_MAP\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RGB_RED_MAP: Atom = Atom::new(30);
+#[doc = " An [`Atom`] representing the string \"STRING\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const STRING: Atom = Atom::new(31);
+#[doc = " An [`Atom`] representing the string \"VISUALID\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const VISUALID: Atom = Atom::new(32);
+#[doc = " An [`Atom`] representing the string \"WINDOW\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WINDOW: Atom = Atom::new(33);
+#[doc = " An [`Atom`] representing the string \"WM_COMMAND\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_COMMAND: Atom = Atom::new(34);
+#[doc = " An [`Atom`] representing the string \"WM_HINTS\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_HINTS: Atom = Atom::new(35);
+#[doc = " An [`Atom`] representing the string \"WM_CLIENT_MACHINE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_CLIENT_MACHINE: Atom = Atom::new(36);
+#[doc = " An [`Atom`] representing the string \"WM_ICON_NAME\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_ICON_NAME: Atom = Atom::new(37);
+#[doc = " An [`Atom`] representing the string \"WM_ICON_SIZE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_ICON_SIZE: Atom = Atom::new(38);
+#[doc = " An [`Atom`] representing the string \"WM_NAME\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_NAME: Atom = Atom::new(39);
+#[doc = " An [`Atom`] representing the string \"WM_NORMAL_HINTS\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_NORMAL_HINTS: Atom = Atom::new(40);
+#[doc = " An [`Atom`] representing the string \"WM_SIZE_HINTS\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_SIZE_HINTS: Atom = Atom::new(41);
+#[doc = " An [`Atom`] representing the string \"WM_ZOOM_HINTS\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_ZOOM_HINTS: Atom = Atom::new(42);
+#[doc = " An [`Atom`] representing the string \"MIN_SPACE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const MIN_SPACE: Atom = Atom::new(43);
+#[doc = " An [`Atom`] representing the string \"NORM_SPACE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const NORM_SPACE: Atom = Atom::new(44);
+#[doc = " An [`Atom`] representing the string \"MAX_SPACE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const MAX_SPACE: Atom = Atom::new(45);
+#[doc = " An [`Atom`] representing the string \"END_SPACE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const END_SPACE: Atom = Atom::new(46);
+#[doc = " An [`Atom`] representing the string \"SUPERSCRIPT_X\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const SUPERSCRIPT_X: Atom = Atom::new(47);
+#[doc = " An [`Atom`] representing the string \"SUPERSCRIPT_Y\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const SUPERSCRIPT_Y: Atom = Atom::new(48);
+#[doc = " An [`Atom`] representing the string \"SUBSCRIPT_X\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const SUBSCRIPT_X: Atom = Atom::new(49);
+#[doc = " An [`Atom`] representing the string \"SUBSCRIPT_Y\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const SUBSCRIPT_Y: Atom = Atom::new(50);
+#[doc = " An [`Atom`] representing the string \"UNDERLINE_POSITION\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const UNDERLINE_POSITION: Atom = Atom::new(51);
+#[doc = " An [`Atom`] representing the string \"UNDERLINE_THICKNESS\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const UNDERLINE_THICKNESS: Atom = Atom::new(52);
+#[doc = " An [`Atom`] representing the string \"STRIKEOUT_ASCENT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const STRIKEOUT_ASCENT: Atom = Atom::new(53);
+#[doc = " An [`Atom`] representing the string \"STRIKEOUT_DESCENT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const STRIKEOUT_DESCENT: Atom = Atom::new(54);
+#[doc = " An [`Atom`] representing the string \"ITALIC_ANGLE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const ITALIC_ANGLE: Atom = Atom::new(55);
+#[doc = " An [`Atom`] representing the string \"X_HEIGHT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const X_HEIGHT: Atom = Atom::new(56);
+#[doc = " An [`Atom`] representing the string \"QUAD_WIDTH\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const QUAD_WIDTH: Atom = Atom::new(57);
+#[doc = " An [`Atom`] representing the string \"WEIGHT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WEIGHT: Atom = Atom::new(58);
+#[doc = " An [`Atom`] representing the string \"POINT_SIZE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const POINT_SIZE: Atom = Atom::new(59);
+#[doc = " An [`Atom`] representing the string \"RESOLUTION\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const RESOLUTION: Atom = Atom::new(60);
+#[doc = " An [`Atom`] representing the string \"COPYRIGHT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const COPYRIGHT: Atom = Atom::new(61);
+#[doc = " An [`Atom`] representing the string \"NOTICE\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const NOTICE: Atom = Atom::new(62);
+#[doc = " An [`Atom`] representing the string \"FONT_NAME\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const FONT_NAME: Atom = Atom::new(63);
+#[doc = " An [`Atom`] representing the string \"FAMILY_NAME\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const FAMILY_NAME: Atom = Atom::new(64);
+#[doc = " An [`Atom`] representing the string \"FULL_NAME\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const FULL_NAME: Atom = Atom::new(65);
+#[doc = " An [`Atom`] representing the string \"CAP_HEIGHT\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const CAP_HEIGHT: Atom = Atom::new(66);
+#[doc = " An [`Atom`] representing the string \"WM_CLASS\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_CLASS: Atom = Atom::new(67);
+#[doc = " An [`Atom`] representing the string \"WM_TRANSIENT_FOR\"."]
+#[doc = ""]
+#[doc = " This atom is predefined in the X protocol; that is, it is not defined per"]
+#[doc = " connection and is always known to be the same ID."]
+pub const WM_TRANSIENT_FOR: Atom = Atom::new(68);
@@ -0,0 +1,387 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../../snapshot/x11/common/id/atoms.rs"
+)]
+pub mod atoms;
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VisualId(u32);
+#[automatically_derived]
+impl cornflakes::StaticByteSize for VisualId {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for VisualId {
+	fn byte_size(&self) -> usize {
+		let Self(__0__) = self;
+		cornflakes::ByteSize::byte_size(__0__)
+	}
+}
+impl VisualId {
+	#[doc = " Creates a new [`VisualId`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self(id)
+	}
+	#[doc = " Creates a new [`VisualId`] with an `id` of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self(0)
+	}
+	#[doc = " Gets the `id` of the [`VisualId`]."]
+	#[must_use]
+	pub const fn id(&self) -> u32 {
+		self.0
+	}
+}
+#[doc = " An ID for resources that is unique among other resources."]
+#[doc = ""]
+#[doc = " A _resource_ is a:"]
+#[doc = " - [`Window`]; or a"]
+#[doc = " - [`Pixmap`]; or a"]
+#[doc = " - [`Cursor`]; or a"]
+#[doc = " - [`Font`]; or a"]
+#[doc = " - [`GraphicsContext`]; or a"]
+#[doc = " - [`Colormap`]."]
+#[doc = ""]
+#[doc = " A resource ID must only be unique among other resources. For example, let's"]
+#[doc = " say that a [`Window`] has a resource ID of `7` - this means that no other"]
+#[doc = " resource, whether it's a [`Window`] or another resource like a [`Font`], is"]
+#[doc = " allowed to share that resource ID of 7. An ID that is _not_ a resource (e.g."]
+#[doc = " [`Atom`]), however, may use the same ID as a resource."]
+#[doc(notable_trait)]
+pub trait ResId {
+	#[doc = " The resource ID for this resource."]
+	fn res_id(&self) -> u32;
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Window {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Window {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Window {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Pixmap {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Pixmap {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Pixmap {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Cursor {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Cursor {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Cursor {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Font {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Font {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Font {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GraphicsContext {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for GraphicsContext {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for GraphicsContext {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Colormap {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Colormap {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Colormap {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[doc = " The resource ID of either a [`Window`] or a [`Pixmap`]."]
+#[doc = ""]
+#[doc = " Both [`Window`]s and [`Pixmap`]s can be drawn to, and many requests accept"]
+#[doc = " either. A [`Drawable`] can be created from either with [`From`], or with"]
+#[doc = " [`Window::as_drawable`] and [`Pixmap::as_drawable`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Drawable {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Drawable {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Drawable {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[doc = " The resource ID of either a [`Font`] or a [`GraphicsContext`]."]
+#[doc = ""]
+#[doc = " When a [`GraphicsContext`] is used as a [`Fontable`], its font is used. A"]
+#[doc = " [`Fontable`] can be created from either with [`From`], or with"]
+#[doc = " [`Font::as_fontable`] and [`GraphicsContext::as_fontable`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Fontable {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Fontable {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Fontable {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+impl From<Window> for Drawable {
+	fn from(window: Window) -> Self {
+		window.as_drawable()
+	}
+}
+impl From<Pixmap> for Drawable {
+	fn from(pixmap: Pixmap) -> Self {
+		pixmap.as_drawable()
+	}
+}
+impl From<Font> for Fontable {
+	fn from(font: Font) -> Self {
+		font.as_fontable()
+	}
+}
+impl From<GraphicsContext> for Fontable {
+	fn from(context: GraphicsContext) -> Self {
+		context.as_fontable()
+	}
+}
+impl ResId for Window {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for Pixmap {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for Cursor {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for Font {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for GraphicsContext {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for Colormap {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for Drawable {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl ResId for Fontable {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl Window {
+	#[doc = " Creates a new [`Window`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Window`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+	#[doc = " Returns this [`Window`] as a [`Drawable`] with the same ID."]
+	#[must_use]
+	pub const fn as_drawable(self) -> Drawable {
+		Drawable { id: self.id }
+	}
+}
+impl Pixmap {
+	#[doc = " Creates a new [`Pixmap`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Pixmap`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+	#[doc = " Returns this [`Pixmap`] as a [`Drawable`] with the same ID."]
+	#[must_use]
+	pub const fn as_drawable(self) -> Drawable {
+		Drawable { id: self.id }
+	}
+}
+impl Cursor {
+	#[doc = " Creates a new [`Cursor`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Cursor`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl Font {
+	#[doc = " Creates a new [`Font`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Font`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+	#[doc = " Returns this [`Font`] as a [`Fontable`] with the same ID."]
+	#[must_use]
+	pub const fn as_fontable(self) -> Fontable {
+		Fontable { id: self.id }
+	}
+}
+impl GraphicsContext {
+	#[doc = " Creates a new [`GraphicsContext`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`GraphicsContext`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+	#[doc = " Returns this [`GraphicsContext`] as a [`Fontable`] with the same ID."]
+	#[must_use]
+	pub const fn as_fontable(self) -> Fontable {
+		Fontable { id: self.id }
+	}
+}
+impl Colormap {
+	#[doc = " Creates a new [`Colormap`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Colormap`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl Drawable {
+	#[doc = " Creates a new [`Drawable`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Drawable`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl Fontable {
+	#[doc = " Creates a new [`Fontable`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Fontable`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+fn _assert_object_safety(_res_id: &dyn ResId) {}
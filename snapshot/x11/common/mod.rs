@@ -0,0 +1,976 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use std::fmt;
+#[path = "../../../src/x11/common/any_event.rs"]
+mod any_event;
+#[path = "../../../src/x11/common/build_error.rs"]
+mod build_error;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/color.rs"
+)]
+mod color;
+#[path = "../../../src/x11/common/configure_window_values.rs"]
+mod configure_window_values;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/fixed.rs"
+)]
+mod fixed;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/font.rs"
+)]
+mod font;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/geometry.rs"
+)]
+mod geometry;
+#[path = "../../../src/x11/common/graphics_context_values.rs"]
+mod graphics_context_values;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/id/mod.rs"
+)]
+mod id;
+#[path = "../../../src/x11/common/keyboard_control.rs"]
+mod keyboard_control;
+#[path = "../../../src/x11/common/masks.rs"]
+mod masks;
+#[path = "../../../src/x11/common/pointer_control.rs"]
+mod pointer_control;
+#[path = "../../../src/x11/common/points.rs"]
+mod points;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/string.rs"
+)]
+mod string;
+#[path = "../../../src/x11/common/text.rs"]
+mod text;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/values.rs"
+)]
+mod values;
+#[path = "../../../src/x11/common/window_attributes.rs"]
+mod window_attributes;
+#[path = "../../../src/x11/common/wrappers.rs"]
+mod wrappers;
+pub use any_event::*;
+pub use build_error::*;
+pub use color::*;
+pub use configure_window_values::*;
+pub use fixed::*;
+pub use font::*;
+pub use geometry::*;
+pub use graphics_context_values::*;
+pub use id::atoms::Atom;
+pub use id::*;
+pub use keyboard_control::*;
+pub use masks::*;
+pub use pointer_control::*;
+pub use points::*;
+pub use string::*;
+pub use text::*;
+pub use values::*;
+pub use window_attributes::*;
+pub use wrappers::*;
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Status {
+	Success,
+	Busy,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Status {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Status {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " Whether a [`ForceScreenSaver`] request activates the screen saver or"]
+#[doc = " resets it."]
+#[doc = ""]
+#[doc = " [`ForceScreenSaver`]: super::requests::ForceScreenSaver"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ScreenSaverMode {
+	#[doc = " Deactivates the screen saver if it is active, and restarts its timeout"]
+	#[doc = " as if the keyboard or pointer had been used."]
+	Reset,
+	#[doc = " Activates the screen saver, even if it is disabled."]
+	Activate,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ScreenSaverMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ScreenSaverMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " Whether the screen is blanked when the screen saver is activated, rather"]
+#[doc = " than showing a changing pattern."]
+#[doc = ""]
+#[doc = " This is set with a [`SetScreenSaver`] request, and returned in a"]
+#[doc = " [`GetScreenSaverReply`]."]
+#[doc = ""]
+#[doc = " [`SetScreenSaver`]: super::requests::SetScreenSaver"]
+#[doc = " [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Blanking {
+	#[doc = " A changing pattern is shown."]
+	No,
+	#[doc = " The screen is blanked if the hardware supports it."]
+	Yes,
+	#[doc = " The X server's default is restored."]
+	#[doc = ""]
+	#[doc = " This is never returned in a [`GetScreenSaverReply`]."]
+	#[doc = ""]
+	#[doc = " [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply"]
+	Default,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Blanking {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Blanking {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " Whether the screen saver may be activated while the screen can't be"]
+#[doc = " blanked and regenerating it would generate exposure events."]
+#[doc = ""]
+#[doc = " This is set with a [`SetScreenSaver`] request, and returned in a"]
+#[doc = " [`GetScreenSaverReply`]."]
+#[doc = ""]
+#[doc = " [`SetScreenSaver`]: super::requests::SetScreenSaver"]
+#[doc = " [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Exposures {
+	#[doc = " The screen saver is not activated until exposures are no longer"]
+	#[doc = " needed."]
+	No,
+	#[doc = " The screen saver is activated, and windows are exposed once it is"]
+	#[doc = " deactivated."]
+	Yes,
+	#[doc = " The X server's default is restored."]
+	#[doc = ""]
+	#[doc = " This is never returned in a [`GetScreenSaverReply`]."]
+	#[doc = ""]
+	#[doc = " [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply"]
+	Default,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Exposures {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Exposures {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " What happens to a client's resources when its connection is closed, as"]
+#[doc = " set by a [`SetCloseDownMode`] request."]
+#[doc = ""]
+#[doc = " [`SetCloseDownMode`]: super::requests::SetCloseDownMode"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CloseDownMode {
+	#[doc = " The client's resources are destroyed."]
+	Destroy,
+	#[doc = " The client's resources are kept until they are killed with a"]
+	#[doc = " `KillClient` request."]
+	RetainPermanent,
+	#[doc = " The client's resources are kept until they are killed with a"]
+	#[doc = " `KillClient` request with `AllTemporary`."]
+	RetainTemporary,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for CloseDownMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for CloseDownMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl fmt::Display for CloseDownMode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Destroy => "destroy resources",
+			Self::RetainPermanent => "retain resources permanently",
+			Self::RetainTemporary => "retain resources temporarily",
+		})
+	}
+}
+#[doc = " Whether the X server only accepts connections from hosts in its access"]
+#[doc = " control list."]
+#[doc = ""]
+#[doc = " This is set with a [`SetAccessControl`] request, and returned in a"]
+#[doc = " [`ListHostsReply`]."]
+#[doc = ""]
+#[doc = " [`SetAccessControl`]: super::requests::SetAccessControl"]
+#[doc = " [`ListHostsReply`]: super::requests::ListHostsReply"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AccessControl {
+	#[doc = " Connections are accepted from any host."]
+	Disable,
+	#[doc = " Connections are only accepted from hosts in the access control list."]
+	Enable,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for AccessControl {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for AccessControl {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl fmt::Display for AccessControl {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Disable => "access control disabled",
+			Self::Enable => "access control enabled",
+		})
+	}
+}
+#[doc = " The 'type' of 'best size' being queried in a [`QueryBestSize`] request."]
+#[doc = ""]
+#[doc = " [`QueryBestSize`]: super::QueryBestSize"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum QueryBestSizeClass {
+	Cursor,
+	Tile,
+	Stipple,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for QueryBestSizeClass {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for QueryBestSizeClass {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ColormapAlloc {
+	None,
+	All,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ColormapAlloc {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ColormapAlloc {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Shape {
+	Complex,
+	Nonconvex,
+	Convex,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Shape {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Shape {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CoordinateMode {
+	Origin,
+	Previous,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for CoordinateMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for CoordinateMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for CoordinateMode {
+	fn default() -> Self {
+		Self::Origin
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Ordering {
+	Unsorted,
+	Ysorted,
+	YxSorted,
+	YxBanded,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Ordering {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Ordering {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DrawDirection {
+	LeftToRight,
+	RightToLeft,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for DrawDirection {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for DrawDirection {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for DrawDirection {
+	fn default() -> Self {
+		Self::LeftToRight
+	}
+}
+#[doc = " Which frozen devices are released by an [`AllowEvents`] request, and how."]
+#[doc = ""]
+#[doc = " A device is frozen by a grab with a [`GrabMode::Sync`] mode: events for"]
+#[doc = " it are queued rather than sent until it is released."]
+#[doc = ""]
+#[doc = " [`AllowEvents`]: super::requests::AllowEvents"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AllowEventsMode {
+	#[doc = " Releases the pointer, if it is frozen by this client."]
+	AsyncPointer,
+	#[doc = " Releases the pointer, if it is frozen and actively grabbed by this"]
+	#[doc = " client, until the next `ButtonPress` or `ButtonRelease` event is"]
+	#[doc = " reported to this client, after which it is frozen again."]
+	SyncPointer,
+	#[doc = " If the pointer is actively grabbed by this client and frozen by the"]
+	#[doc = " activation of a passive grab, the grab is released and the event which"]
+	#[doc = " activated it is processed again, ignoring passive grabs on the grab"]
+	#[doc = " window and its ancestors."]
+	ReplayPointer,
+	#[doc = " Releases the keyboard, if it is frozen by this client."]
+	AsyncKeyboard,
+	#[doc = " Releases the keyboard, if it is frozen and actively grabbed by this"]
+	#[doc = " client, until the next `KeyPress` or `KeyRelease` event is reported to"]
+	#[doc = " this client, after which it is frozen again."]
+	SyncKeyboard,
+	#[doc = " If the keyboard is actively grabbed by this client and frozen by the"]
+	#[doc = " activation of a passive grab, the grab is released and the event which"]
+	#[doc = " activated it is processed again, ignoring passive grabs on the grab"]
+	#[doc = " window and its ancestors."]
+	ReplayKeyboard,
+	#[doc = " Releases both the pointer and the keyboard, if they are both frozen by"]
+	#[doc = " this client."]
+	AsyncBoth,
+	#[doc = " Releases both the pointer and the keyboard, if they are both frozen by"]
+	#[doc = " this client, until the next pointer button or key event is reported"]
+	#[doc = " to this client, after which they are both frozen again."]
+	SyncBoth,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for AllowEventsMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for AllowEventsMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for AllowEventsMode {
+	fn default() -> Self {
+		Self::AsyncBoth
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum HostFamilyA {
+	Internet,
+	Decnet,
+	Chaos,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for HostFamilyA {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for HostFamilyA {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for HostFamilyA {
+	fn default() -> Self {
+		Self::Internet
+	}
+}
+#[doc = " How events for a device are processed while it is grabbed."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum GrabMode {
+	#[doc = " The device is frozen: its events are queued, rather than processed,"]
+	#[doc = " until the grabbing client releases it with an [`AllowEvents`] request"]
+	#[doc = " or the grab is released."]
+	#[doc = ""]
+	#[doc = " [`AllowEvents`]: super::requests::AllowEvents"]
+	#[doc(alias = "Synchronous")]
+	Sync,
+	#[doc = " Events for the device continue to be processed as usual."]
+	#[doc(alias = "Asynchronous")]
+	Async,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for GrabMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for GrabMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for GrabMode {
+	fn default() -> Self {
+		Self::Async
+	}
+}
+#[doc = " Whether a [`GrabPointer`] or [`GrabKeyboard`] request succeeded, or, if it"]
+#[doc = " did not, why."]
+#[doc = ""]
+#[doc = " [`GrabPointer`]: super::requests::GrabPointer"]
+#[doc = " [`GrabKeyboard`]: super::requests::GrabKeyboard"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum GrabStatus {
+	#[doc = " The device was grabbed."]
+	Success,
+	#[doc = " The device is already actively grabbed by another client."]
+	AlreadyGrabbed,
+	#[doc = " The time given is earlier than the last time the device was grabbed,"]
+	#[doc = " or later than the current time."]
+	InvalidTime,
+	#[doc = " The grab window, or the window the pointer is confined to, is not"]
+	#[doc = " viewable, or the window the pointer is confined to is completely"]
+	#[doc = " outside of the root window."]
+	NotViewable,
+	#[doc = " The device is frozen by an active grab of another client."]
+	Frozen,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for GrabStatus {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for GrabStatus {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl GrabStatus {
+	#[doc = " Returns whether the device was grabbed."]
+	#[must_use]
+	pub const fn is_success(self) -> bool {
+		matches!(self, Self::Success)
+	}
+}
+#[doc = " The direction in which the children of a window are circulated by a"]
+#[doc = " [`CirculateWindow`] request."]
+#[doc = ""]
+#[doc = " [`CirculateWindow`]: super::requests::CirculateWindow"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CirculateDirection {
+	#[doc = " Raises the lowest occluded child to the top of the stack."]
+	RaiseLowest,
+	#[doc = " Lowers the highest child which occludes another to the bottom of the"]
+	#[doc = " stack."]
+	LowerHighest,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for CirculateDirection {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for CirculateDirection {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " Where a window was placed in (or is to be placed in) the stack of its"]
+#[doc = " siblings as the result of a [`CirculateWindow`] request."]
+#[doc = ""]
+#[doc = " [`CirculateWindow`]: super::requests::CirculateWindow"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Place {
+	#[doc = " The top of the stack, above all of its siblings."]
+	Top,
+	#[doc = " The bottom of the stack, below all of its siblings."]
+	Bottom,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Place {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Place {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " How a property was changed, as reported in a [`PropertyNotify`] event."]
+#[doc = ""]
+#[doc = " [`PropertyNotify`]: super::events::PropertyNotify"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PropertyState {
+	#[doc = " The property was given a new value, even if it is the same as its old"]
+	#[doc = " value."]
+	NewValue,
+	#[doc = " The property was deleted."]
+	Deleted,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for PropertyState {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PropertyState {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " Whether a colormap is installed, as reported in a [`ColormapNotify`] event."]
+#[doc = ""]
+#[doc = " [`ColormapNotify`]: super::events::ColormapNotify"]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ColormapState {
+	#[doc = " The colormap is not installed."]
+	Uninstalled,
+	#[doc = " The colormap is installed."]
+	Installed,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ColormapState {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ColormapState {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EditMode {
+	Insert,
+	Delete,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for EditMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for EditMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for EditMode {
+	fn default() -> Self {
+		Self::Insert
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Format {
+	XyPixmap = 1,
+	Zpixmap = 2,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Format {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Format {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " How a window is restacked by a `ConfigureWindow` request."]
+#[doc = ""]
+#[doc = " If a sibling is given, the window is restacked relative to that sibling;"]
+#[doc = " otherwise, it is restacked relative to all of its siblings."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum StackMode {
+	#[doc = " The window is placed just above the sibling, or at the top of the"]
+	#[doc = " stack."]
+	Above,
+	#[doc = " The window is placed just below the sibling, or at the bottom of the"]
+	#[doc = " stack."]
+	Below,
+	#[doc = " The window is placed at the top of the stack if the sibling, or any"]
+	#[doc = " sibling, occludes it."]
+	TopIf,
+	#[doc = " The window is placed at the bottom of the stack if it occludes the"]
+	#[doc = " sibling, or any sibling."]
+	BottomIf,
+	#[doc = " The window is placed at the top of the stack if the sibling, or any"]
+	#[doc = " sibling, occludes it, or at the bottom of the stack if it occludes the"]
+	#[doc = " sibling, or any sibling."]
+	Opposite,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for StackMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for StackMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for StackMode {
+	fn default() -> Self {
+		Self::Above
+	}
+}
+#[doc = " Whether a keyboard LED is turned on or off by a [`KeyboardControl`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LedMode {
+	Off,
+	On,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for LedMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for LedMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " Whether keys repeat when they are held, as set by a [`KeyboardControl`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AutoRepeatMode {
+	Off,
+	On,
+	#[doc = " The key is restored to its default auto-repeat mode."]
+	#[doc = ""]
+	#[doc = " This may only be used for a specific key; it can't be used to restore"]
+	#[doc = " the global auto-repeat mode."]
+	Default,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for AutoRepeatMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for AutoRepeatMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MapState {
+	Unmapped,
+	Unviewable,
+	Viewable,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for MapState {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for MapState {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for MapState {
+	fn default() -> Self {
+		Self::Unmapped
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BackingStore {
+	NotUseful,
+	WhenMapped,
+	Always,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for BackingStore {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for BackingStore {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum WindowClass {
+	InputOutput = 1,
+	InputOnly = 2,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for WindowClass {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for WindowClass {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for WindowClass {
+	fn default() -> Self {
+		Self::InputOutput
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BitGravity {
+	Forget,
+	NorthWest,
+	North,
+	NorthEast,
+	West,
+	Center,
+	East,
+	SouthWest,
+	South,
+	SouthEast,
+	Static,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for BitGravity {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for BitGravity {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for BitGravity {
+	fn default() -> Self {
+		Self::NorthWest
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum WinGravity {
+	Unmap,
+	NorthWest,
+	North,
+	NorthEast,
+	West,
+	Center,
+	East,
+	SouthWest,
+	South,
+	SouthEast,
+	Static,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for WinGravity {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for WinGravity {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for WinGravity {
+	fn default() -> Self {
+		Self::NorthWest
+	}
+}
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Host {
+	#[doc = " The protocol family of the host, e.g. [InternetV6](HostFamily::InternetV6)."]
+	pub family: HostFamily,
+	#[doc = " The address of the host in question."]
+	pub address: String8,
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Host {
+	fn byte_size(&self) -> usize {
+		let Self {
+			family: __family__,
+			address: __address__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__family__) + cornflakes::ByteSize::byte_size(__address__)
+	}
+}
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum HostFamily {
+	Internet,
+	Decnet,
+	Chaos,
+	ServerInterpreted,
+	InternetV6,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for HostFamily {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for HostFamily {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for HostFamily {
+	fn default() -> Self {
+		Self::ServerInterpreted
+	}
+}
+#[doc = " An identifier representing the concept of all possible keys."]
+#[doc = ""]
+#[doc = " The difference between a `Keysym` and a [`Keycode`] is that the `Keysym`"]
+#[doc = " universally represents the concept of any particular key, while the"]
+#[doc = " [`Keycode`] refers to the specific position of a key on the user's keyboard,"]
+#[doc = " as interpreted by the device drivers."]
+#[doc = ""]
+#[doc = " For example, the concept of an `F13` key always exists as a `Keysym`, even"]
+#[doc = " if there is no such key represented by a [`Keycode`] for the actual keyboard"]
+#[doc = " currently in use."]
+pub type Keysym = u32;
+#[doc = " An identifier for the location of a key as interepreted by OS drivers."]
+#[doc = ""]
+#[doc = " The difference between a `Keycode` and a [`Keysym`] is that the `Keycode`"]
+#[doc = " refers to the specific position of a key on the user's keyboard, as"]
+#[doc = " interpreted by the device drivers, while the [`Keysym`] universally"]
+#[doc = " represents the concept of any particular key."]
+#[doc = ""]
+#[doc = " For example, the concept of an `F13` key always exists as a [`Keysym`], even"]
+#[doc = " if there is no such key represented by a `Keycode` for the actual keyboard"]
+#[doc = " currently in use."]
+pub type Keycode = u8;
+#[doc = " A button on the mouse."]
+#[doc = ""]
+#[doc = " For example, button 1 is the primary mouse button, commonly found on the"]
+#[doc = " left of a mouse."]
+pub type Button = u8;
+pub type Timestamp = u32;
+#[doc = " Specifies how to pick the window to revert focus to when the current"]
+#[doc = " window is unmapped."]
+pub enum RevertTo {
+	#[doc = " Revert the focus to none at all."]
+	#[doc = ""]
+	#[doc = " It is recommended to avoid setting this: it might lead to behavior you"]
+	#[doc = " don't expect. Only set this as the [`RevertTo`] if you know the"]
+	#[doc = " potential consequences."]
+	None,
+	PointerRoot,
+	#[doc = " Revert the focus to the parent of the window."]
+	#[doc = ""]
+	#[doc = " This is the recommended [`RevertTo`] option for most cases."]
+	Parent,
+}
+impl Default for RevertTo {
+	fn default() -> Self {
+		Self::Parent
+	}
+}
+#[doc = " The destination for an [`Event`] in a [`SendEvent`] request."]
+#[doc = ""]
+#[doc = " This is the window that the event will be sent to."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Destination {
+	#[doc = " The [`Window`] the pointer is currently within."]
+	PointerWindow,
+	#[doc = " The [`Window`] that currently has input focus."]
+	InputFocus,
+	#[doc = " A specific [`Window`]."]
+	Specific(Window),
+}
+impl Default for Destination {
+	fn default() -> Self {
+		Self::InputFocus
+	}
+}
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use cornflakes::*;
+use std::io::Error;
+use std::string::{FromUtf8Error, String};
+#[doc = " A string of text with 1-byte characters."]
+#[doc = ""]
+#[doc = " This is different from the built-in [`String`] in that Rust's [`String`]"]
+#[doc = " is encoded with 4 bytes per character."]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct String8(Vec<u8>);
+#[automatically_derived]
+impl cornflakes::ByteSize for String8 {
+	fn byte_size(&self) -> usize {
+		let Self(__0__) = self;
+		cornflakes::ByteSize::byte_size(__0__)
+	}
+}
+#[doc = " A string of text with 2-byte characters."]
+#[doc = ""]
+#[doc = " This is different from the built-in [`String`] in that Rust's [`String`]"]
+#[doc = " is encoded with 4 bytes per character."]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct String16(Vec<(u8, u8)>);
+#[automatically_derived]
+impl cornflakes::ByteSize for String16 {
+	fn byte_size(&self) -> usize {
+		let Self(__0__) = self;
+		cornflakes::ByteSize::byte_size(__0__)
+	}
+}
+#[doc = " A string of text with 1-byte characters, encoded with its length."]
+#[doc = ""]
+#[doc = " This is different from the built-in [`String`] in that Rust's [`String`]"]
+#[doc = " is encoded with 4 bytes per character, and from [`String8`] in that the"]
+#[doc = " length of the string is included in (de)serialization."]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LenString8(Vec<u8>);
+impl ByteSize for LenString8 {
+	fn byte_size(&self) -> usize {
+		1 + self.0.byte_size()
+	}
+}
+impl FromBytes for LenString8 {
+	fn read_from(reader: &mut impl ByteReader) -> Result<Self, Error> {
+		let len = reader.read_u8() as usize;
+		Ok(Self(reader.read_with_size(len)?))
+	}
+}
+impl ToBytes for LenString8 {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "`LenString8`'s length must fit in a `u8` value by definition"
+	)]
+	fn write_to(&self, writer: &mut impl ByteWriter) -> Result<(), Error>
+	where
+		Self: Sized,
+	{
+		writer.write(self.0.len() as u8)?;
+		writer.write_all(&self.0)?;
+		Ok(())
+	}
+}
+impl From<String> for String8 {
+	fn from(text: String) -> Self {
+		Self(text.chars().map(|r#char| r#char as u8).collect())
+	}
+}
+impl TryFrom<String8> for String {
+	type Error = FromUtf8Error;
+	fn try_from(text: String8) -> Result<Self, FromUtf8Error> {
+		Self::from_utf8(
+			text.0
+				.iter()
+				.flat_map(|&r#char| u32::from(r#char).to_ne_bytes())
+				.collect(),
+		)
+	}
+}
+impl From<String> for LenString8 {
+	fn from(text: String) -> Self {
+		Self(text.chars().map(|r#char| r#char as u8).collect())
+	}
+}
+impl TryFrom<LenString8> for String {
+	type Error = FromUtf8Error;
+	fn try_from(text: LenString8) -> Result<Self, FromUtf8Error> {
+		Self::from_utf8(
+			text.0
+				.iter()
+				.flat_map(|&r#char| u32::from(r#char).to_ne_bytes())
+				.collect(),
+		)
+	}
+}
+impl From<String> for String16 {
+	fn from(text: String) -> Self {
+		Self(
+			text.chars()
+				.map(|r#char| {
+					let bytes = (r#char as u16).to_ne_bytes();
+					(bytes[0], bytes[1])
+				})
+				.collect(),
+		)
+	}
+}
+impl TryFrom<String16> for String {
+	type Error = FromUtf8Error;
+	fn try_from(text: String16) -> Result<Self, Self::Error> {
+		Self::from_utf8(
+			text.0
+				.iter()
+				.flat_map(|&(a, b)| u32::from(u16::from_ne_bytes([a, b])).to_ne_bytes())
+				.collect(),
+		)
+	}
+}
@@ -0,0 +1,310 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::*;
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Attribute {
+	BackgroundPixmap(Option<Relatable<Pixmap>>),
+	BackgroundPixel(u32),
+	BorderPixmap(Inheritable<Pixmap>),
+	BorderPixel(u32),
+	BitGravity(BitGravity),
+	WinGravity(WinGravity),
+	BackingStore(BackingStore),
+	BackingPlanes(u32),
+	BackingPixel(u32),
+	OverrideRedirect(bool),
+	SaveUnder(bool),
+	EventMask(EventMask),
+	DoNotPropagateMask(DeviceEventMask),
+	Colormap(Inheritable<Colormap>),
+	Cursor(Option<Cursor>),
+}
+#[doc = " A component of a [`GraphicsContext`], as set in the list of values of a"]
+#[doc = " `CreateGraphicsContext` or `ChangeGraphicsContext` request."]
+#[doc = ""]
+#[doc = " Every value is written as 4 bytes in the list of values, regardless of its"]
+#[doc = " type; see [`GraphicsContextValues`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum GraphicsContextValue {
+	#[doc = " How the source and destination pixels are combined when drawing."]
+	Function(Function),
+	#[doc = " Which bit planes of the destination are drawn to."]
+	PlaneMask(u32),
+	#[doc = " The pixel used for the foreground."]
+	Foreground(u32),
+	#[doc = " The pixel used for the background."]
+	Background(u32),
+	#[doc = " The width of lines in pixels, where `0` draws 'thin' one-pixel lines"]
+	#[doc = " with a faster algorithm."]
+	LineWidth(u16),
+	#[doc = " Which sections of lines are drawn."]
+	LineStyle(LineStyle),
+	#[doc = " How the ends of lines are drawn."]
+	CapStyle(CapStyle),
+	#[doc = " How the corners of wide lines are drawn."]
+	JoinStyle(JoinStyle),
+	#[doc = " How lines, text, and fills are filled in."]
+	FillStyle(FillStyle),
+	#[doc = " Which pixels are inside a polygon filled with `FillPoly`."]
+	FillRule(FillRule),
+	#[doc = " The pixmap tiled by the [`FillStyle::Tiled`] fill style."]
+	Tile(Pixmap),
+	#[doc = " The bitmap used by the stippled fill styles."]
+	Stipple(Pixmap),
+	#[doc = " The x-coordinate of the origin of the tile or stipple."]
+	TileStippleXorigin(i16),
+	#[doc = " The y-coordinate of the origin of the tile or stipple."]
+	TileStippleYorigin(i16),
+	#[doc = " The font used for text."]
+	Font(Font),
+	#[doc = " Whether drawing to a window is clipped by its children."]
+	SubwindowMode(SubwindowMode),
+	#[doc = " Whether `GraphicsExposure` events are generated for `CopyArea` and"]
+	#[doc = " `CopyPlane` requests."]
+	GraphicsExposures(bool),
+	#[doc = " The x-coordinate of the origin of the clip mask."]
+	ClipXorigin(i16),
+	#[doc = " The y-coordinate of the origin of the clip mask."]
+	ClipYorigin(i16),
+	#[doc = " The bitmap which drawing is clipped to, or [`None`] to not clip."]
+	ClipMask(Option<Pixmap>),
+	#[doc = " The offset into the dash pattern at which lines start."]
+	DashOffset(u16),
+	#[doc = " The length of both the dashes and the gaps between them in dashed"]
+	#[doc = " lines."]
+	Dashes(u8),
+	#[doc = " Whether arcs filled with `PolyFillArc` are filled as chords or as pie"]
+	#[doc = " slices."]
+	ArcMode(ArcMode),
+}
+impl cornflakes::StaticByteSize for Attribute {
+	fn static_byte_size() -> usize {
+		4
+	}
+}
+impl cornflakes::ByteSize for Attribute {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+impl cornflakes::StaticByteSize for GraphicsContextValue {
+	fn static_byte_size() -> usize {
+		4
+	}
+}
+impl cornflakes::ByteSize for GraphicsContextValue {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+#[doc = " How the source pixels and destination pixels are combined when drawing."]
+#[doc = ""]
+#[doc = " Each function is a bitwise operation on the `source` and `destination`."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Function {
+	Clear,
+	And,
+	AndReverse,
+	Copy,
+	AndInverted,
+	NoOp,
+	Xor,
+	Or,
+	Nor,
+	Equiv,
+	Invert,
+	OrReverse,
+	CopyInverted,
+	OrInverted,
+	Nand,
+	Set,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Function {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Function {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for Function {
+	fn default() -> Self {
+		Self::Copy
+	}
+}
+#[doc = " Which sections of lines are drawn."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LineStyle {
+	Solid,
+	OnOffDash,
+	DoubleDash,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for LineStyle {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for LineStyle {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for LineStyle {
+	fn default() -> Self {
+		Self::Solid
+	}
+}
+#[doc = " How the ends of lines are drawn."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CapStyle {
+	NotLast,
+	Butt,
+	Round,
+	Projecting,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for CapStyle {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for CapStyle {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for CapStyle {
+	fn default() -> Self {
+		Self::Butt
+	}
+}
+#[doc = " How the corners of wide lines are drawn."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum JoinStyle {
+	Miter,
+	Round,
+	Bevel,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for JoinStyle {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for JoinStyle {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for JoinStyle {
+	fn default() -> Self {
+		Self::Miter
+	}
+}
+#[doc = " How lines, text, and fills are filled in."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FillStyle {
+	Solid,
+	Tiled,
+	Stippled,
+	OpaqueStippled,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for FillStyle {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for FillStyle {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for FillStyle {
+	fn default() -> Self {
+		Self::Solid
+	}
+}
+#[doc = " Which pixels are considered to be inside a polygon."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FillRule {
+	EvenOdd,
+	Winding,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for FillRule {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for FillRule {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for FillRule {
+	fn default() -> Self {
+		Self::EvenOdd
+	}
+}
+#[doc = " Whether drawing to a window is clipped by its children."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SubwindowMode {
+	ClipByChildren,
+	IncludeInferiors,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for SubwindowMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for SubwindowMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for SubwindowMode {
+	fn default() -> Self {
+		Self::ClipByChildren
+	}
+}
+#[doc = " Whether filled arcs are filled as chords or as pie slices."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ArcMode {
+	Chord,
+	PieSlice,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ArcMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ArcMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl Default for ArcMode {
+	fn default() -> Self {
+		Self::PieSlice
+	}
+}
@@ -0,0 +1,332 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::extensions::{
+	composite, damage, render, shm, xc_misc, xfixes, Extension, Extensions,
+};
+use std::{error::Error, fmt};
+#[doc = " An X protocol error that can be returned when sending requests."]
+pub trait Xerror: Error {
+	#[doc = " The unique error code for this type of error."]
+	fn code(&self) -> u8;
+	#[doc = " The sequence of the last associated request sent to the X server."]
+	fn sequence(&self) -> u16;
+	#[doc = " The minor opcode of the last associated request."]
+	fn minor_opcode(&self) -> u16;
+	#[doc = " The major opcode of the last associated request."]
+	fn major_opcode(&self) -> u8;
+}
+#[doc = " An X protocol error of any kind, as received from the X server."]
+#[doc = ""]
+#[doc = " Errors are distinguished from replies and events by a first byte of"]
+#[doc = " `0`; that byte is not included when an `AnyError` is read or written."]
+#[doc = ""]
+#[doc = " An `AnyError` is displayed with the names of the error and of the"]
+#[doc = " request which caused it, where they are known. For example:"]
+#[doc = " `BadWindow (value 0x1234) in response to ConfigureWindow (opcode 12,"]
+#[doc = " sequence 42)`."]
+#[doc = ""]
+#[doc = " The names of extension errors and requests are known if the error is"]
+#[doc = " displayed within the [`scope`] of the connection's [`Extensions`]."]
+#[doc = ""]
+#[doc = " [`scope`]: Extensions::scope"]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct AnyError {
+	#[doc = " The code identifying the type of error."]
+	pub code: u8,
+	#[doc = " The sequence number of the request which caused the error."]
+	pub sequence: u16,
+	#[doc = " The resource ID, atom, or value which caused the error, if the"]
+	#[doc = " type of error has one."]
+	pub bad_value: u32,
+	#[doc = " The minor opcode of the request which caused the error."]
+	pub minor_opcode: u16,
+	#[doc = " The major opcode of the request which caused the error."]
+	pub major_opcode: u8,
+}
+impl cornflakes::Writable for AnyError {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			code: __code__,
+			sequence: __sequence__,
+			bad_value: __bad_value__,
+			minor_opcode: __minor_opcode__,
+			major_opcode: __major_opcode__,
+		} = self;
+		cornflakes::Writable::write_to(__code__, writer)?;
+		cornflakes::Writable::write_to(__sequence__, writer)?;
+		cornflakes::Writable::write_to(__bad_value__, writer)?;
+		cornflakes::Writable::write_to(__minor_opcode__, writer)?;
+		cornflakes::Writable::write_to(__major_opcode__, writer)?;
+		writer.put_bytes(0, 21);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for AnyError {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __code__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __sequence__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __bad_value__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_opcode__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_opcode__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 21;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			code: __code__,
+			sequence: __sequence__,
+			bad_value: __bad_value__,
+			minor_opcode: __minor_opcode__,
+			major_opcode: __major_opcode__,
+		})
+	}
+}
+impl xrb::PrettyPrint for AnyError {
+	const NAME: &'static str = "AnyError";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			code: __code__,
+			sequence: __sequence__,
+			bad_value: __bad_value__,
+			minor_opcode: __minor_opcode__,
+			major_opcode: __major_opcode__,
+		} = self;
+		cornflakes::Writable::write_to(__code__, writer)?;
+		writer.field("code", __code__);
+		cornflakes::Writable::write_to(__sequence__, writer)?;
+		writer.field("sequence", __sequence__);
+		cornflakes::Writable::write_to(__bad_value__, writer)?;
+		writer.field("bad_value", __bad_value__);
+		cornflakes::Writable::write_to(__minor_opcode__, writer)?;
+		writer.field("minor_opcode", __minor_opcode__);
+		cornflakes::Writable::write_to(__major_opcode__, writer)?;
+		writer.field("major_opcode", __major_opcode__);
+		writer.put_bytes(0, 21);
+		writer.unused();
+		Ok(())
+	}
+}
+impl Xerror for AnyError {
+	fn code(&self) -> u8 {
+		self.code
+	}
+	fn sequence(&self) -> u16 {
+		self.sequence
+	}
+	fn minor_opcode(&self) -> u16 {
+		self.minor_opcode
+	}
+	fn major_opcode(&self) -> u8 {
+		self.major_opcode
+	}
+}
+impl Error for AnyError {}
+impl fmt::Display for AnyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let extensions = Extensions::current();
+		match error(&extensions, self.code) {
+			Some((name, false)) => write!(f, "{name}")?,
+			Some((name, true)) => write!(f, "{name} (value {:#x})", self.bad_value)?,
+			None => write!(
+				f,
+				"unknown error {} (value {:#x})",
+				self.code, self.bad_value
+			)?,
+		}
+		write!(f, " in response to ")?;
+		let (major, minor, sequence) = (self.major_opcode, self.minor_opcode, self.sequence);
+		if let Some(extension) = extension_by_opcode(&extensions, major) {
+			let name = (extension.request)(minor).unwrap_or("request");
+			write!(
+				f,
+				"{} {name} (opcode {major}.{minor}, sequence {sequence})",
+				extension.extension.name()
+			)
+		} else if let Some(name) = core_request_name(major) {
+			write!(f, "{name} (opcode {major}, sequence {sequence})")
+		} else {
+			write!(
+				f,
+				"unknown request (opcode {major}.{minor}, sequence {sequence})"
+			)
+		}
+	}
+}
+#[doc = " Returns the name of the request with the given opcodes, if it is known."]
+#[doc = ""]
+#[doc = " The names of extension requests are only known if the extension's codes"]
+#[doc = " are in the given `extensions`."]
+#[must_use]
+pub fn request_name(
+	extensions: &Extensions,
+	major_opcode: u8,
+	minor_opcode: u16,
+) -> Option<&'static str> {
+	extension_by_opcode(extensions, major_opcode).map_or_else(
+		|| core_request_name(major_opcode),
+		|extension| (extension.request)(minor_opcode),
+	)
+}
+#[doc = " Returns the name of the error with the given `code`, such as `BadWindow`,"]
+#[doc = " if it is known."]
+#[doc = ""]
+#[doc = " The names of extension errors are only known if the extension's codes are"]
+#[doc = " in the given `extensions`."]
+#[must_use]
+pub fn error_name(extensions: &Extensions, code: u8) -> Option<&'static str> {
+	error(extensions, code).map(|(name, _)| name)
+}
+#[doc = " Returns the name of the error with the given `code`, and whether that type"]
+#[doc = " of error has a `bad_value`."]
+fn error(extensions: &Extensions, code: u8) -> Option<(&'static str, bool)> {
+	if let Some(&error) = code
+		.checked_sub(1)
+		.and_then(|index| CORE_ERRORS.get(usize::from(index)))
+	{
+		return Some(error);
+	}
+	EXTENSIONS.iter().find_map(|names| {
+		let first_error = extensions.get(names.extension)?.first_error();
+		code.checked_sub(first_error)
+			.and_then(|index| names.errors.get(usize::from(index)))
+			.copied()
+	})
+}
+#[doc = " Returns the names of the extension assigned the given `major_opcode` in"]
+#[doc = " the `extensions`, if it is known."]
+fn extension_by_opcode(
+	extensions: &Extensions,
+	major_opcode: u8,
+) -> Option<&'static ExtensionNames> {
+	let extension = extensions.by_major_opcode(major_opcode)?;
+	EXTENSIONS.iter().find(|names| names.extension == extension)
+}
+#[doc = " The names of an extension's requests and errors."]
+struct ExtensionNames {
+	extension: Extension,
+	#[doc = " Returns the name of the request with the given minor opcode."]
+	request: fn(u16) -> Option<&'static str>,
+	#[doc = " The extension's errors in order of their codes, and whether each has a"]
+	#[doc = " `bad_value`."]
+	errors: &'static [(&'static str, bool)],
+}
+#[doc = " The core errors in order of their codes, starting at `1`, and whether each"]
+#[doc = " has a `bad_value`."]
+pub(crate) const CORE_ERRORS: [(&str, bool); 17] = [
+	("BadRequest", false),
+	("BadValue", true),
+	("BadWindow", true),
+	("BadPixmap", true),
+	("BadAtom", true),
+	("BadCursor", true),
+	("BadFont", true),
+	("BadMatch", false),
+	("BadDrawable", true),
+	("BadAccess", false),
+	("BadAlloc", false),
+	("BadColormap", true),
+	("BadGContext", true),
+	("BadIDChoice", true),
+	("BadName", false),
+	("BadLength", false),
+	("BadImplementation", false),
+];
+static EXTENSIONS: [ExtensionNames; 6] = [
+	ExtensionNames {
+		extension: composite::EXTENSION,
+		request: composite_request_name,
+		errors: &[],
+	},
+	ExtensionNames {
+		extension: damage::EXTENSION,
+		request: damage_request_name,
+		errors: &[("BadDamage", true)],
+	},
+	ExtensionNames {
+		extension: render::EXTENSION,
+		request: render_request_name,
+		errors: &[
+			("BadPictFormat", true),
+			("BadPicture", true),
+			("BadPictOp", false),
+			("BadGlyphSet", true),
+			("BadGlyph", true),
+		],
+	},
+	ExtensionNames {
+		extension: shm::EXTENSION,
+		request: shm_request_name,
+		errors: &[("BadShmSeg", true)],
+	},
+	ExtensionNames {
+		extension: xc_misc::EXTENSION,
+		request: xc_misc_request_name,
+		errors: &[],
+	},
+	ExtensionNames {
+		extension: xfixes::EXTENSION,
+		request: xfixes_request_name,
+		errors: &[("BadRegion", true)],
+	},
+];
+macro_rules ! request_names { ($ ($ vis : vis fn $ function : ident ($ opcode : ty) { $ ($ code : literal => $ name : ident ,) * }) *) => { $ ($ vis const fn $ function (opcode : $ opcode) -> Option <&'static str > { match opcode { $ ($ code => Some (stringify ! ($ name)) ,) * _ => None , } }) * } ; }
+request_names! { pub (crate) fn core_request_name (u8) { 1 => CreateWindow , 2 => ChangeWindowAttributes , 3 => GetWindowAttributes , 4 => DestroyWindow , 5 => DestroySubwindows , 6 => ChangeSaveSet , 7 => ReparentWindow , 8 => MapWindow , 9 => MapSubwindows , 10 => UnmapWindow , 11 => UnmapSubwindows , 12 => ConfigureWindow , 13 => CirculateWindow , 14 => GetGeometry , 15 => QueryTree , 16 => InternAtom , 17 => GetAtomName , 18 => ChangeProperty , 19 => DeleteProperty , 20 => GetProperty , 21 => ListProperties , 22 => SetSelectionOwner , 23 => GetSelectionOwner , 24 => ConvertSelection , 25 => SendEvent , 26 => GrabPointer , 27 => UngrabPointer , 28 => GrabButton , 29 => UngrabButton , 30 => ChangeActivePointerGrab , 31 => GrabKeyboard , 32 => UngrabKeyboard , 33 => GrabKey , 34 => UngrabKey , 35 => AllowEvents , 36 => GrabServer , 37 => UngrabServer , 38 => QueryPointer , 39 => GetMotionEvents , 40 => TranslateCoordinates , 41 => WarpPointer , 42 => SetInputFocus , 43 => GetInputFocus , 44 => QueryKeymap , 45 => OpenFont , 46 => CloseFont , 47 => QueryFont , 48 => QueryTextExtents , 49 => ListFonts , 50 => ListFontsWithInfo , 51 => SetFontPath , 52 => GetFontPath , 53 => CreatePixmap , 54 => FreePixmap , 55 => CreateGraphicsContext , 56 => ChangeGraphicsContext , 57 => CopyGraphicsContext , 58 => SetDashes , 59 => SetClipRectangles , 60 => FreeGraphicsContext , 61 => ClearArea , 62 => CopyArea , 63 => CopyPlane , 64 => PolyPoint , 65 => PolyLine , 66 => PolySegment , 67 => PolyRectangle , 68 => PolyArc , 69 => FillPoly , 70 => PolyFillRectangle , 71 => PolyFillArc , 72 => PutImage , 73 => GetImage , 74 => PolyText8 , 75 => PolyText16 , 76 => ImageText8 , 77 => ImageText16 , 78 => CreateColormap , 79 => FreeColormap , 80 => CopyColormapAndFree , 81 => InstallColormap , 82 => UninstallColormap , 83 => ListInstalledColormaps , 84 => AllocColor , 85 => AllocNamedColor , 86 => AllocColorCells , 87 => AllocColorPlanes , 88 => FreeColors , 89 => StoreColors , 90 => StoreNamedColor , 91 => QueryColors , 92 => LookupColor , 93 => CreateCursor , 94 => CreateGlyphCursor , 95 => FreeCursor , 96 => RecolorCursor , 97 => QueryBestSize , 98 => QueryExtension , 99 => ListExtensions , 100 => ChangeKeyboardMapping , 101 => GetKeyboardMapping , 102 => ChangeKeyboardControl , 103 => GetKeyboardControl , 104 => Bell , 105 => ChangePointerControl , 106 => GetPointerControl , 107 => SetScreenSaver , 108 => GetScreenSaver , 109 => ChangeHosts , 110 => ListHosts , 111 => SetAccessControl , 112 => SetCloseDownMode , 113 => KillClient , 114 => RotateProperties , 115 => ForceScreenSaver , 116 => SetPointerMapping , 117 => GetPointerMapping , 118 => SetModifierMapping , 119 => GetModifierMapping , 127 => NoOperation , } fn composite_request_name (u16) { 0 => QueryVersion , 1 => RedirectWindow , 2 => RedirectSubwindows , 3 => UnredirectWindow , 4 => UnredirectSubwindows , 5 => CreateRegionFromBorderClip , 6 => NameWindowPixmap , 7 => GetOverlayWindow , 8 => ReleaseOverlayWindow , } fn damage_request_name (u16) { 0 => QueryVersion , 1 => Create , 2 => Destroy , 3 => Subtract , 4 => Add , } fn render_request_name (u16) { 0 => QueryVersion , 1 => QueryPictFormats , 4 => CreatePicture , 7 => FreePicture , 8 => Composite , 17 => CreateGlyphSet , 19 => FreeGlyphSet , 20 => AddGlyphs , 22 => FreeGlyphs , 26 => FillRectangles , } fn shm_request_name (u16) { 0 => QueryVersion , 1 => Attach , 2 => Detach , 3 => PutImage , 4 => GetImage , 7 => CreateSegment , } fn xc_misc_request_name (u16) { 0 => GetVersion , 1 => GetXidRange , 2 => GetXidList , } fn xfixes_request_name (u16) { 0 => QueryVersion , 5 => CreateRegion , 6 => CreateRegionFromBitmap , 7 => CreateRegionFromWindow , 8 => CreateRegionFromGc , 10 => DestroyRegion , 11 => SetRegion , 12 => CopyRegion , 13 => UnionRegion , 14 => IntersectRegion , 15 => SubtractRegion , 16 => InvertRegion , 17 => TranslateRegion , 18 => RegionExtents , 19 => FetchRegion , 21 => SetWindowShapeRegion , } }
+macro_rules! _errors {
+	($ ($ t : tt) *) => {};
+}
+_errors! { # [error ("the major or minor opcode does not specify a valid request")] pub struct RequestXerror (1) { } # [error ("`{bad_value:?}` falls outside the range of values accepted by this request")] pub struct ValueXerror (2) { bad_value } # [error ("`{bad_res_id:?}` is not a defined window resource ID")] pub struct WindowXerror (3) { bad_res_id } # [error ("`{bad_res_id:?}` is not a defined pixmap resource ID")] pub struct PixmapXerror (4) { bad_res_id } # [error ("`{bad_atom_id:?}` is not a defined atom ID")] pub struct AtomXerror (5) { bad_atom_id } # [error ("`${bad_res_id:?}` is not a defined cursor resource ID")] pub struct CursorXerror (6) { bad_res_id } # [error ("`${bad_res_id:?}` is not a defined font resource ID, or font or gcontext resource ID")] pub struct FontXerror (7) { bad_res_id } # [error ("a given argument or arguments did not match a valid value")] pub struct MatchXerror (8) { } # [error ("`${bad_res_id:?}` is not a defined window or pixmap resource ID")] pub struct DrawableXerror (9) { bad_res_id } # [error ("unauthorized access")] pub struct AccessXerror (10) { } # [error ("failed to allocate the requested resource")] pub struct AllocXerror (11) { } # [error ("`{bad_res_id:?}` is not a defined colormap resource ID")] pub struct ColormapXerror (12) { bad_res_id } # [error ("`{bad_res_id:?}` is not a defined gcontext resource ID")] pub struct GcontextXerror (13) { bad_res_id } # [error ("the chosen resource ID is either already in use or it is not assigned to this client")] pub struct IdChoiceXerror (14) { bad_res_id } # [error ("a font or color of the specified name does not exist")] pub struct NameXerror (15) { } # [error ("the length of a request is too short or too long")] pub struct LengthXerror (16) { } # [error ("the X server does not support some aspect of this request")] pub struct ImplementationXerror (17) { } }
@@ -0,0 +1,3312 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::*;
+#[doc = " Sent when a pointer `button` is pressed."]
+#[doc = ""]
+#[doc = " This event is sent to the client which has selected `ButtonPress` on"]
+#[doc = " the `event` window, or to the client which has grabbed the pointer."]
+#[doc = " Pressing a button while the pointer is not grabbed may activate a grab;"]
+#[doc = " see [`ButtonPress::activates_grab`]."]
+pub struct ButtonPress {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The button which was pressed."]
+	pub button: Button,
+	#[doc = " The time at which the event occurred."]
+	pub time: Timestamp,
+	#[doc = " The root window of the screen on which the event occurred."]
+	pub root: Window,
+	#[doc = " The window on which this event was selected, or the grab window of"]
+	#[doc = " an active pointer grab."]
+	pub event: Window,
+	#[doc = " The child of the `event` window containing the pointer, if any."]
+	pub child: Option<Window>,
+	#[doc = " The x-coordinate of the pointer, relative to the `root` window."]
+	pub root_x: i16,
+	#[doc = " The y-coordinate of the pointer, relative to the `root` window."]
+	pub root_y: i16,
+	#[doc = " The x-coordinate of the pointer, relative to the `event` window."]
+	pub event_x: i16,
+	#[doc = " The y-coordinate of the pointer, relative to the `event` window."]
+	pub event_y: i16,
+	#[doc = " The modifier keys and buttons held immediately before the event."]
+	pub state: ModifierMask,
+	#[doc = " Whether the `event` window is on the same screen as the `root`"]
+	#[doc = " window."]
+	#[doc = ""]
+	#[doc = " If it is not, `event_x`, `event_y`, and `child` are zero."]
+	pub same_screen: bool,
+}
+#[doc = " Sent when a pointer `button` is released."]
+#[doc = ""]
+#[doc = " This event is sent to the client which has selected `ButtonRelease` on"]
+#[doc = " the `event` window, or to the client which has grabbed the pointer."]
+#[doc = " Releasing the last button which is held releases a grab activated by a"]
+#[doc = " button press; see [`ButtonRelease::releases_grab`]."]
+pub struct ButtonRelease {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The button which was released."]
+	pub button: Button,
+	#[doc = " The time at which the event occurred."]
+	pub time: Timestamp,
+	#[doc = " The root window of the screen on which the event occurred."]
+	pub root: Window,
+	#[doc = " The window on which this event was selected, or the grab window of"]
+	#[doc = " an active pointer grab."]
+	pub event: Window,
+	#[doc = " The child of the `event` window containing the pointer, if any."]
+	pub child: Option<Window>,
+	#[doc = " The x-coordinate of the pointer, relative to the `root` window."]
+	pub root_x: i16,
+	#[doc = " The y-coordinate of the pointer, relative to the `root` window."]
+	pub root_y: i16,
+	#[doc = " The x-coordinate of the pointer, relative to the `event` window."]
+	pub event_x: i16,
+	#[doc = " The y-coordinate of the pointer, relative to the `event` window."]
+	pub event_y: i16,
+	#[doc = " The modifier keys and buttons held immediately before the event."]
+	pub state: ModifierMask,
+	#[doc = " Whether the `event` window is on the same screen as the `root`"]
+	#[doc = " window."]
+	#[doc = ""]
+	#[doc = " If it is not, `event_x`, `event_y`, and `child` are zero."]
+	pub same_screen: bool,
+}
+#[doc = " Sent when a `window` is destroyed."]
+#[doc = ""]
+#[doc = " This event is sent to clients which have selected `StructureNotify` on"]
+#[doc = " the `window`, and to clients which have selected `SubstructureNotify`"]
+#[doc = " on its parent."]
+pub struct DestroyNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The window on which this event was selected: either the `window`"]
+	#[doc = " itself or its parent."]
+	pub event: Window,
+	#[doc = " The window which was destroyed."]
+	pub window: Window,
+}
+#[doc = " Sent when a `window` is unmapped."]
+#[doc = ""]
+#[doc = " This event is sent to clients which have selected `StructureNotify` on"]
+#[doc = " the `window`, and to clients which have selected `SubstructureNotify`"]
+#[doc = " on its parent."]
+pub struct UnmapNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The window on which this event was selected: either the `window`"]
+	#[doc = " itself or its parent."]
+	pub event: Window,
+	#[doc = " The window which was unmapped."]
+	pub window: Window,
+	#[doc = " Whether the `window` was unmapped because its parent was resized"]
+	#[doc = " and it has a `win_gravity` of [`WinGravity::Unmap`]."]
+	pub from_configure: bool,
+}
+#[doc = " Sent when a [`MapWindow`] request is sent for a `window` whose parent"]
+#[doc = " has `SubstructureRedirect` selected, and which doesn't have"]
+#[doc = " `override_redirect` set."]
+#[doc = ""]
+#[doc = " The request is not carried out: the client which selected"]
+#[doc = " `SubstructureRedirect`, typically a window manager, is responsible for"]
+#[doc = " deciding whether to map the `window`."]
+#[doc = ""]
+#[doc = " [`MapWindow`]: crate::x11::requests::MapWindow"]
+pub struct MapRequest {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub parent: Window,
+	#[doc = " The window which would be mapped."]
+	pub window: Window,
+}
+#[doc = " Sent when a [`ConfigureWindow`] request is sent for a `window` whose"]
+#[doc = " parent has `SubstructureRedirect` selected, and which doesn't have"]
+#[doc = " `override_redirect` set."]
+#[doc = ""]
+#[doc = " The request is not carried out: the client which selected"]
+#[doc = " `SubstructureRedirect`, typically a window manager, is responsible for"]
+#[doc = " deciding whether, and how, to configure the `window`. Only the values"]
+#[doc = " included in the `value_mask` were given in the request."]
+#[doc = ""]
+#[doc = " [`ConfigureWindow`]: crate::x11::requests::ConfigureWindow"]
+pub struct ConfigureRequest {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub stack_mode: StackMode,
+	pub parent: Window,
+	#[doc = " The window which would be configured."]
+	pub window: Window,
+	pub sibling: Option<Window>,
+	pub x: i16,
+	pub y: i16,
+	pub width: u16,
+	pub height: u16,
+	pub border_width: u16,
+	#[doc = " Which of the values were given in the [`ConfigureWindow`] request."]
+	#[doc = ""]
+	#[doc = " [`ConfigureWindow`]: crate::x11::requests::ConfigureWindow"]
+	pub value_mask: ConfigureWindowMask,
+}
+#[doc = " Sent when a `window` is restacked as the result of a"]
+#[doc = " [`CirculateWindow`] request."]
+#[doc = ""]
+#[doc = " This event is sent to clients which have selected `StructureNotify` on"]
+#[doc = " the `window`, and to clients which have selected `SubstructureNotify`"]
+#[doc = " on its parent."]
+#[doc = ""]
+#[doc = " [`CirculateWindow`]: crate::x11::requests::CirculateWindow"]
+pub struct CirculateNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The window on which this event was selected: either the `window`"]
+	#[doc = " itself or its parent."]
+	pub event: Window,
+	#[doc = " The window which was restacked."]
+	pub window: Window,
+	#[doc = " Where the `window` was placed in the stack of its siblings."]
+	pub place: Place,
+}
+#[doc = " Sent when a [`CirculateWindow`] request is sent for a `parent` window"]
+#[doc = " on which `SubstructureRedirect` has been selected."]
+#[doc = ""]
+#[doc = " The request is not carried out: the client which selected"]
+#[doc = " `SubstructureRedirect` is responsible for deciding whether, and how, to"]
+#[doc = " restack the `window`."]
+#[doc = ""]
+#[doc = " [`CirculateWindow`]: crate::x11::requests::CirculateWindow"]
+pub struct CirculateRequest {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub parent: Window,
+	#[doc = " The window which would be restacked."]
+	pub window: Window,
+	#[doc = " Where the `window` would be placed in the stack of its siblings."]
+	pub place: Place,
+}
+#[doc = " Sent when a `property` of a `window` is changed or deleted."]
+#[doc = ""]
+#[doc = " This event is sent to clients which have selected `PropertyChange` on"]
+#[doc = " the `window`. Properties may change many times in quick succession;"]
+#[doc = " see [`PropertyDebouncer`] to handle only the latest of each."]
+#[doc = ""]
+#[doc = " [`PropertyDebouncer`]: crate::x11::property_debounce::PropertyDebouncer"]
+pub struct PropertyNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The window whose property was changed."]
+	pub window: Window,
+	#[doc = " The property which was changed."]
+	pub property: Atom,
+	#[doc = " The time at which the property was changed."]
+	pub time: Timestamp,
+	#[doc = " Whether the property was given a new value or deleted."]
+	pub state: PropertyState,
+}
+#[doc = " Sent to the previous `owner` of a `selection` when it loses ownership"]
+#[doc = " of it."]
+#[doc = ""]
+#[doc = " Ownership is lost when another client sets itself as the owner with a"]
+#[doc = " [`SetSelectionOwner`] request, when the `owner` window is destroyed, or"]
+#[doc = " when the selection is cleared by setting its owner to `None`."]
+#[doc = ""]
+#[doc = " [`SetSelectionOwner`]: crate::x11::requests::SetSelectionOwner"]
+pub struct SelectionClear {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The time at which ownership of the `selection` was last changed, as"]
+	#[doc = " given in the [`SetSelectionOwner`] request."]
+	#[doc = ""]
+	#[doc = " [`SetSelectionOwner`]: crate::x11::requests::SetSelectionOwner"]
+	pub time: Timestamp,
+	#[doc = " The window which no longer owns the `selection`."]
+	pub owner: Window,
+	#[doc = " The selection which was lost."]
+	pub selection: Atom,
+}
+#[doc = " Sent to the `owner` of a `selection` when another client requests that"]
+#[doc = " it be converted with a [`ConvertSelection`] request."]
+#[doc = ""]
+#[doc = " The `owner` should convert the `selection` to the `target` type, store"]
+#[doc = " the result in the `property` on the `requestor` window, and then send a"]
+#[doc = " [`SelectionNotify`] event to the `requestor` with a [`SendEvent`]"]
+#[doc = " request. If the `selection` can't be converted, the [`SelectionNotify`]"]
+#[doc = " event should be sent with a `property` of `None`."]
+#[doc = ""]
+#[doc = " [`ConvertSelection`]: crate::x11::requests::ConvertSelection"]
+#[doc = " [`SendEvent`]: crate::x11::requests::SendEvent"]
+pub struct SelectionRequest {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The time given in the [`ConvertSelection`] request."]
+	#[doc = ""]
+	#[doc = " [`ConvertSelection`]: crate::x11::requests::ConvertSelection"]
+	pub time: Time,
+	#[doc = " The window which owns the `selection`."]
+	pub owner: Window,
+	#[doc = " The window which requested the conversion."]
+	pub requestor: Window,
+	#[doc = " The selection which is to be converted."]
+	pub selection: Atom,
+	#[doc = " The type which the `selection` is to be converted to."]
+	pub target: Atom,
+	#[doc = " The property in which the converted `selection` is to be stored."]
+	#[doc = ""]
+	#[doc = " If this is `None`, the requestor is an obsolete client, and the"]
+	#[doc = " `target` atom should be used as the property instead."]
+	pub property: Option<Atom>,
+}
+#[doc = " Sent in reply to a [`ConvertSelection`] request."]
+#[doc = ""]
+#[doc = " The X server sends this event itself if the `selection` has no owner."]
+#[doc = " Otherwise, it is sent by the owner of the `selection` with a"]
+#[doc = " [`SendEvent`] request, in response to a [`SelectionRequest`] event."]
+#[doc = ""]
+#[doc = " [`ConvertSelection`]: crate::x11::requests::ConvertSelection"]
+#[doc = " [`SendEvent`]: crate::x11::requests::SendEvent"]
+pub struct SelectionNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The time given in the [`ConvertSelection`] request."]
+	#[doc = ""]
+	#[doc = " [`ConvertSelection`]: crate::x11::requests::ConvertSelection"]
+	pub time: Time,
+	#[doc = " The window which requested the conversion."]
+	pub requestor: Window,
+	#[doc = " The selection which was converted."]
+	pub selection: Atom,
+	#[doc = " The type which the `selection` was converted to."]
+	pub target: Atom,
+	#[doc = " The property on the `requestor` window in which the converted"]
+	#[doc = " `selection` was stored, or `None` if it could not be converted."]
+	pub property: Option<Atom>,
+}
+#[doc = " Sent when the colormap of a `window` is changed, or when that colormap"]
+#[doc = " is installed or uninstalled."]
+#[doc = ""]
+#[doc = " This event is sent to clients which have selected `ColormapChange` on"]
+#[doc = " the `window`. See [`ColormapTracker`] to follow which colormaps are"]
+#[doc = " installed on each screen."]
+#[doc = ""]
+#[doc = " [`ColormapTracker`]: crate::x11::installed_colormaps::ColormapTracker"]
+pub struct ColormapNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The window whose colormap was changed, installed, or uninstalled."]
+	pub window: Window,
+	#[doc = " The `window`'s colormap, or `None` if it was freed."]
+	pub colormap: Option<Colormap>,
+	#[doc = " Whether the `window`'s colormap attribute was changed, rather than"]
+	#[doc = " the colormap being installed or uninstalled."]
+	pub new: bool,
+	#[doc = " Whether the `colormap` is now installed."]
+	pub state: ColormapState,
+}
+impl cornflakes::Writable for ButtonPress {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		cornflakes::Writable::write_to(__button__, writer)?;
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__root__, writer)?;
+		cornflakes::Writable::write_to(__event__, writer)?;
+		cornflakes::Writable::write_to(__child__, writer)?;
+		cornflakes::Writable::write_to(__root_x__, writer)?;
+		cornflakes::Writable::write_to(__root_y__, writer)?;
+		cornflakes::Writable::write_to(__event_x__, writer)?;
+		cornflakes::Writable::write_to(__event_y__, writer)?;
+		cornflakes::Writable::write_to(__state__, writer)?;
+		cornflakes::Writable::write_to(__same_screen__, writer)?;
+		writer.put_u8(0);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ButtonPress {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __button__ = <Button as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __root__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __child__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <ModifierMask as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __same_screen__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		Ok(Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		})
+	}
+}
+impl xrb::PrettyPrint for ButtonPress {
+	const NAME: &'static str = "ButtonPress";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		cornflakes::Writable::write_to(__button__, writer)?;
+		writer.field("button", __button__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__root__, writer)?;
+		writer.field("root", __root__);
+		cornflakes::Writable::write_to(__event__, writer)?;
+		writer.field("event", __event__);
+		cornflakes::Writable::write_to(__child__, writer)?;
+		writer.field("child", __child__);
+		cornflakes::Writable::write_to(__root_x__, writer)?;
+		writer.field("root_x", __root_x__);
+		cornflakes::Writable::write_to(__root_y__, writer)?;
+		writer.field("root_y", __root_y__);
+		cornflakes::Writable::write_to(__event_x__, writer)?;
+		writer.field("event_x", __event_x__);
+		cornflakes::Writable::write_to(__event_y__, writer)?;
+		writer.field("event_y", __event_y__);
+		cornflakes::Writable::write_to(__state__, writer)?;
+		writer.field("state", __state__);
+		cornflakes::Writable::write_to(__same_screen__, writer)?;
+		writer.field("same_screen", __same_screen__);
+		writer.put_u8(0);
+		writer.unused();
+		Ok(())
+	}
+}
+impl ButtonPress {
+	#[doc = "Creates a new [`ButtonPress`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`ButtonPress`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		button: Button,
+		time: Timestamp,
+		root: Window,
+		event: Window,
+		child: Option<Window>,
+		root_x: i16,
+		root_y: i16,
+		event_x: i16,
+		event_y: i16,
+		state: ModifierMask,
+		same_screen: bool,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			button,
+			time,
+			root,
+			event,
+			child,
+			root_x,
+			root_y,
+			event_x,
+			event_y,
+			state,
+			same_screen,
+		}
+	}
+}
+impl xrb::Event for ButtonPress {
+	fn code() -> u8 {
+		4
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for ButtonPress {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		let __button__ = <Button as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __root__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __child__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <ModifierMask as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __same_screen__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		Ok(Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		})
+	}
+}
+impl cornflakes::Writable for ButtonRelease {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		cornflakes::Writable::write_to(__button__, writer)?;
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__root__, writer)?;
+		cornflakes::Writable::write_to(__event__, writer)?;
+		cornflakes::Writable::write_to(__child__, writer)?;
+		cornflakes::Writable::write_to(__root_x__, writer)?;
+		cornflakes::Writable::write_to(__root_y__, writer)?;
+		cornflakes::Writable::write_to(__event_x__, writer)?;
+		cornflakes::Writable::write_to(__event_y__, writer)?;
+		cornflakes::Writable::write_to(__state__, writer)?;
+		cornflakes::Writable::write_to(__same_screen__, writer)?;
+		writer.put_u8(0);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ButtonRelease {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __button__ = <Button as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __root__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __child__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <ModifierMask as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __same_screen__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		Ok(Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		})
+	}
+}
+impl xrb::PrettyPrint for ButtonRelease {
+	const NAME: &'static str = "ButtonRelease";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		cornflakes::Writable::write_to(__button__, writer)?;
+		writer.field("button", __button__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__root__, writer)?;
+		writer.field("root", __root__);
+		cornflakes::Writable::write_to(__event__, writer)?;
+		writer.field("event", __event__);
+		cornflakes::Writable::write_to(__child__, writer)?;
+		writer.field("child", __child__);
+		cornflakes::Writable::write_to(__root_x__, writer)?;
+		writer.field("root_x", __root_x__);
+		cornflakes::Writable::write_to(__root_y__, writer)?;
+		writer.field("root_y", __root_y__);
+		cornflakes::Writable::write_to(__event_x__, writer)?;
+		writer.field("event_x", __event_x__);
+		cornflakes::Writable::write_to(__event_y__, writer)?;
+		writer.field("event_y", __event_y__);
+		cornflakes::Writable::write_to(__state__, writer)?;
+		writer.field("state", __state__);
+		cornflakes::Writable::write_to(__same_screen__, writer)?;
+		writer.field("same_screen", __same_screen__);
+		writer.put_u8(0);
+		writer.unused();
+		Ok(())
+	}
+}
+impl ButtonRelease {
+	#[doc = "Creates a new [`ButtonRelease`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`ButtonRelease`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		button: Button,
+		time: Timestamp,
+		root: Window,
+		event: Window,
+		child: Option<Window>,
+		root_x: i16,
+		root_y: i16,
+		event_x: i16,
+		event_y: i16,
+		state: ModifierMask,
+		same_screen: bool,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			button,
+			time,
+			root,
+			event,
+			child,
+			root_x,
+			root_y,
+			event_x,
+			event_y,
+			state,
+			same_screen,
+		}
+	}
+}
+impl xrb::Event for ButtonRelease {
+	fn code() -> u8 {
+		5
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for ButtonRelease {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		let __button__ = <Button as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __root__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __child__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __root_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __event_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <ModifierMask as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __same_screen__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		Ok(Self {
+			_sequence_,
+			button: __button__,
+			time: __time__,
+			root: __root__,
+			event: __event__,
+			child: __child__,
+			root_x: __root_x__,
+			root_y: __root_y__,
+			event_x: __event_x__,
+			event_y: __event_y__,
+			state: __state__,
+			same_screen: __same_screen__,
+		})
+	}
+}
+impl cornflakes::Writable for DestroyNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__event__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.put_bytes(0, 20);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for DestroyNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+		})
+	}
+}
+impl xrb::PrettyPrint for DestroyNotify {
+	const NAME: &'static str = "DestroyNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__event__, writer)?;
+		writer.field("event", __event__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		writer.put_bytes(0, 20);
+		writer.unused();
+		Ok(())
+	}
+}
+impl DestroyNotify {
+	#[doc = "Creates a new [`DestroyNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`DestroyNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, event: Window, window: Window) -> Self {
+		Self {
+			_sequence_: sequence,
+			event,
+			window,
+		}
+	}
+}
+impl xrb::Event for DestroyNotify {
+	fn code() -> u8 {
+		17
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for DestroyNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+		})
+	}
+}
+impl cornflakes::Writable for UnmapNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			from_configure: __from_configure__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__event__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__from_configure__, writer)?;
+		writer.put_bytes(0, 19);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for UnmapNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __from_configure__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 19;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			from_configure: __from_configure__,
+		})
+	}
+}
+impl xrb::PrettyPrint for UnmapNotify {
+	const NAME: &'static str = "UnmapNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			from_configure: __from_configure__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__event__, writer)?;
+		writer.field("event", __event__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__from_configure__, writer)?;
+		writer.field("from_configure", __from_configure__);
+		writer.put_bytes(0, 19);
+		writer.unused();
+		Ok(())
+	}
+}
+impl UnmapNotify {
+	#[doc = "Creates a new [`UnmapNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`UnmapNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, event: Window, window: Window, from_configure: bool) -> Self {
+		Self {
+			_sequence_: sequence,
+			event,
+			window,
+			from_configure,
+		}
+	}
+}
+impl xrb::Event for UnmapNotify {
+	fn code() -> u8 {
+		18
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for UnmapNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __from_configure__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 19;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			from_configure: __from_configure__,
+		})
+	}
+}
+impl cornflakes::Writable for MapRequest {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__parent__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.put_bytes(0, 20);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for MapRequest {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __parent__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+		})
+	}
+}
+impl xrb::PrettyPrint for MapRequest {
+	const NAME: &'static str = "MapRequest";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__parent__, writer)?;
+		writer.field("parent", __parent__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		writer.put_bytes(0, 20);
+		writer.unused();
+		Ok(())
+	}
+}
+impl MapRequest {
+	#[doc = "Creates a new [`MapRequest`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`MapRequest`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, parent: Window, window: Window) -> Self {
+		Self {
+			_sequence_: sequence,
+			parent,
+			window,
+		}
+	}
+}
+impl xrb::Event for MapRequest {
+	fn code() -> u8 {
+		20
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for MapRequest {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __parent__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+		})
+	}
+}
+impl cornflakes::Writable for ConfigureRequest {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			stack_mode: __stack_mode__,
+			parent: __parent__,
+			window: __window__,
+			sibling: __sibling__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			border_width: __border_width__,
+			value_mask: __value_mask__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		cornflakes::Writable::write_to(__stack_mode__, writer)?;
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__parent__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__sibling__, writer)?;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		cornflakes::Writable::write_to(__height__, writer)?;
+		cornflakes::Writable::write_to(__border_width__, writer)?;
+		cornflakes::Writable::write_to(__value_mask__, writer)?;
+		writer.put_bytes(0, 4);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ConfigureRequest {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __stack_mode__ = <StackMode as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __parent__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __sibling__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __border_width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		let __value_mask__ = <ConfigureWindowMask as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			stack_mode: __stack_mode__,
+			parent: __parent__,
+			window: __window__,
+			sibling: __sibling__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			border_width: __border_width__,
+			value_mask: __value_mask__,
+		})
+	}
+}
+impl xrb::PrettyPrint for ConfigureRequest {
+	const NAME: &'static str = "ConfigureRequest";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			stack_mode: __stack_mode__,
+			parent: __parent__,
+			window: __window__,
+			sibling: __sibling__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			border_width: __border_width__,
+			value_mask: __value_mask__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		cornflakes::Writable::write_to(__stack_mode__, writer)?;
+		writer.field("stack_mode", __stack_mode__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__parent__, writer)?;
+		writer.field("parent", __parent__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__sibling__, writer)?;
+		writer.field("sibling", __sibling__);
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		cornflakes::Writable::write_to(__width__, writer)?;
+		writer.field("width", __width__);
+		cornflakes::Writable::write_to(__height__, writer)?;
+		writer.field("height", __height__);
+		cornflakes::Writable::write_to(__border_width__, writer)?;
+		writer.field("border_width", __border_width__);
+		cornflakes::Writable::write_to(__value_mask__, writer)?;
+		writer.field("value_mask", __value_mask__);
+		writer.put_bytes(0, 4);
+		writer.unused();
+		Ok(())
+	}
+}
+impl ConfigureRequest {
+	#[doc = "Creates a new [`ConfigureRequest`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`ConfigureRequest`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		stack_mode: StackMode,
+		parent: Window,
+		window: Window,
+		sibling: Option<Window>,
+		x: i16,
+		y: i16,
+		width: u16,
+		height: u16,
+		border_width: u16,
+		value_mask: ConfigureWindowMask,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			stack_mode,
+			parent,
+			window,
+			sibling,
+			x,
+			y,
+			width,
+			height,
+			border_width,
+			value_mask,
+		}
+	}
+}
+impl xrb::Event for ConfigureRequest {
+	fn code() -> u8 {
+		23
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for ConfigureRequest {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		let __stack_mode__ = <StackMode as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		let __parent__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __sibling__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __border_width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		let __value_mask__ = <ConfigureWindowMask as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			stack_mode: __stack_mode__,
+			parent: __parent__,
+			window: __window__,
+			sibling: __sibling__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			border_width: __border_width__,
+			value_mask: __value_mask__,
+		})
+	}
+}
+impl cornflakes::Writable for CirculateNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			place: __place__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__event__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.put_bytes(0, 4);
+		cornflakes::Writable::write_to(__place__, writer)?;
+		writer.put_bytes(0, 15);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CirculateNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __place__ = <Place as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			place: __place__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CirculateNotify {
+	const NAME: &'static str = "CirculateNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			place: __place__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__event__, writer)?;
+		writer.field("event", __event__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		writer.put_bytes(0, 4);
+		writer.unused();
+		cornflakes::Writable::write_to(__place__, writer)?;
+		writer.field("place", __place__);
+		writer.put_bytes(0, 15);
+		writer.unused();
+		Ok(())
+	}
+}
+impl CirculateNotify {
+	#[doc = "Creates a new [`CirculateNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CirculateNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, event: Window, window: Window, place: Place) -> Self {
+		Self {
+			_sequence_: sequence,
+			event,
+			window,
+			place,
+		}
+	}
+}
+impl xrb::Event for CirculateNotify {
+	fn code() -> u8 {
+		26
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for CirculateNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __event__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __place__ = <Place as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			event: __event__,
+			window: __window__,
+			place: __place__,
+		})
+	}
+}
+impl cornflakes::Writable for CirculateRequest {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+			place: __place__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__parent__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.put_bytes(0, 4);
+		cornflakes::Writable::write_to(__place__, writer)?;
+		writer.put_bytes(0, 15);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CirculateRequest {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __parent__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __place__ = <Place as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+			place: __place__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CirculateRequest {
+	const NAME: &'static str = "CirculateRequest";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+			place: __place__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__parent__, writer)?;
+		writer.field("parent", __parent__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		writer.put_bytes(0, 4);
+		writer.unused();
+		cornflakes::Writable::write_to(__place__, writer)?;
+		writer.field("place", __place__);
+		writer.put_bytes(0, 15);
+		writer.unused();
+		Ok(())
+	}
+}
+impl CirculateRequest {
+	#[doc = "Creates a new [`CirculateRequest`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CirculateRequest`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, parent: Window, window: Window, place: Place) -> Self {
+		Self {
+			_sequence_: sequence,
+			parent,
+			window,
+			place,
+		}
+	}
+}
+impl xrb::Event for CirculateRequest {
+	fn code() -> u8 {
+		27
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for CirculateRequest {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __parent__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __place__ = <Place as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			parent: __parent__,
+			window: __window__,
+			place: __place__,
+		})
+	}
+}
+impl cornflakes::Writable for PropertyNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			window: __window__,
+			property: __property__,
+			time: __time__,
+			state: __state__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__property__, writer)?;
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__state__, writer)?;
+		writer.put_bytes(0, 15);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PropertyNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __property__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <PropertyState as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			window: __window__,
+			property: __property__,
+			time: __time__,
+			state: __state__,
+		})
+	}
+}
+impl xrb::PrettyPrint for PropertyNotify {
+	const NAME: &'static str = "PropertyNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			window: __window__,
+			property: __property__,
+			time: __time__,
+			state: __state__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__property__, writer)?;
+		writer.field("property", __property__);
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__state__, writer)?;
+		writer.field("state", __state__);
+		writer.put_bytes(0, 15);
+		writer.unused();
+		Ok(())
+	}
+}
+impl PropertyNotify {
+	#[doc = "Creates a new [`PropertyNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`PropertyNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		window: Window,
+		property: Atom,
+		time: Timestamp,
+		state: PropertyState,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			window,
+			property,
+			time,
+			state,
+		}
+	}
+}
+impl xrb::Event for PropertyNotify {
+	fn code() -> u8 {
+		28
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for PropertyNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __property__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <PropertyState as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			window: __window__,
+			property: __property__,
+			time: __time__,
+			state: __state__,
+		})
+	}
+}
+impl cornflakes::Writable for SelectionClear {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			selection: __selection__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__owner__, writer)?;
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SelectionClear {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __owner__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			selection: __selection__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SelectionClear {
+	const NAME: &'static str = "SelectionClear";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			selection: __selection__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__owner__, writer)?;
+		writer.field("owner", __owner__);
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		writer.field("selection", __selection__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl SelectionClear {
+	#[doc = "Creates a new [`SelectionClear`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SelectionClear`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, time: Timestamp, owner: Window, selection: Atom) -> Self {
+		Self {
+			_sequence_: sequence,
+			time,
+			owner,
+			selection,
+		}
+	}
+}
+impl xrb::Event for SelectionClear {
+	fn code() -> u8 {
+		29
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for SelectionClear {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __time__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __owner__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			selection: __selection__,
+		})
+	}
+}
+impl cornflakes::Writable for SelectionRequest {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__owner__, writer)?;
+		cornflakes::Writable::write_to(__requestor__, writer)?;
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		cornflakes::Writable::write_to(__target__, writer)?;
+		cornflakes::Writable::write_to(__property__, writer)?;
+		writer.put_bytes(0, 4);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SelectionRequest {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __time__ = <Time as cornflakes::Readable>::read_from(reader)?;
+		let __owner__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __requestor__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __target__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __property__ = <Option<Atom> as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SelectionRequest {
+	const NAME: &'static str = "SelectionRequest";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__owner__, writer)?;
+		writer.field("owner", __owner__);
+		cornflakes::Writable::write_to(__requestor__, writer)?;
+		writer.field("requestor", __requestor__);
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		writer.field("selection", __selection__);
+		cornflakes::Writable::write_to(__target__, writer)?;
+		writer.field("target", __target__);
+		cornflakes::Writable::write_to(__property__, writer)?;
+		writer.field("property", __property__);
+		writer.put_bytes(0, 4);
+		writer.unused();
+		Ok(())
+	}
+}
+impl SelectionRequest {
+	#[doc = "Creates a new [`SelectionRequest`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SelectionRequest`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		time: Time,
+		owner: Window,
+		requestor: Window,
+		selection: Atom,
+		target: Atom,
+		property: Option<Atom>,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			time,
+			owner,
+			requestor,
+			selection,
+			target,
+			property,
+		}
+	}
+}
+impl xrb::Event for SelectionRequest {
+	fn code() -> u8 {
+		30
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for SelectionRequest {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __time__ = <Time as cornflakes::Readable>::read_from(reader)?;
+		let __owner__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __requestor__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __target__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __property__ = <Option<Atom> as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			time: __time__,
+			owner: __owner__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		})
+	}
+}
+impl cornflakes::Writable for SelectionNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			time: __time__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__time__, writer)?;
+		cornflakes::Writable::write_to(__requestor__, writer)?;
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		cornflakes::Writable::write_to(__target__, writer)?;
+		cornflakes::Writable::write_to(__property__, writer)?;
+		writer.put_bytes(0, 8);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SelectionNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __time__ = <Time as cornflakes::Readable>::read_from(reader)?;
+		let __requestor__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __target__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __property__ = <Option<Atom> as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 8;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			time: __time__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SelectionNotify {
+	const NAME: &'static str = "SelectionNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			time: __time__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__time__, writer)?;
+		writer.field("time", __time__);
+		cornflakes::Writable::write_to(__requestor__, writer)?;
+		writer.field("requestor", __requestor__);
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		writer.field("selection", __selection__);
+		cornflakes::Writable::write_to(__target__, writer)?;
+		writer.field("target", __target__);
+		cornflakes::Writable::write_to(__property__, writer)?;
+		writer.field("property", __property__);
+		writer.put_bytes(0, 8);
+		writer.unused();
+		Ok(())
+	}
+}
+impl SelectionNotify {
+	#[doc = "Creates a new [`SelectionNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SelectionNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		time: Time,
+		requestor: Window,
+		selection: Atom,
+		target: Atom,
+		property: Option<Atom>,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			time,
+			requestor,
+			selection,
+			target,
+			property,
+		}
+	}
+}
+impl xrb::Event for SelectionNotify {
+	fn code() -> u8 {
+		31
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for SelectionNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __time__ = <Time as cornflakes::Readable>::read_from(reader)?;
+		let __requestor__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __target__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __property__ = <Option<Atom> as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 8;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			time: __time__,
+			requestor: __requestor__,
+			selection: __selection__,
+			target: __target__,
+			property: __property__,
+		})
+	}
+}
+impl cornflakes::Writable for ColormapNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			window: __window__,
+			colormap: __colormap__,
+			new: __new__,
+			state: __state__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__colormap__, writer)?;
+		cornflakes::Writable::write_to(__new__, writer)?;
+		cornflakes::Writable::write_to(__state__, writer)?;
+		writer.put_bytes(0, 18);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ColormapNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __colormap__ = <Option<Colormap> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __new__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <ColormapState as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 18;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			window: __window__,
+			colormap: __colormap__,
+			new: __new__,
+			state: __state__,
+		})
+	}
+}
+impl xrb::PrettyPrint for ColormapNotify {
+	const NAME: &'static str = "ColormapNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			window: __window__,
+			colormap: __colormap__,
+			new: __new__,
+			state: __state__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__colormap__, writer)?;
+		writer.field("colormap", __colormap__);
+		cornflakes::Writable::write_to(__new__, writer)?;
+		writer.field("new", __new__);
+		cornflakes::Writable::write_to(__state__, writer)?;
+		writer.field("state", __state__);
+		writer.put_bytes(0, 18);
+		writer.unused();
+		Ok(())
+	}
+}
+impl ColormapNotify {
+	#[doc = "Creates a new [`ColormapNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`ColormapNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		window: Window,
+		colormap: Option<Colormap>,
+		new: bool,
+		state: ColormapState,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			window,
+			colormap,
+			new,
+			state,
+		}
+	}
+}
+impl xrb::Event for ColormapNotify {
+	fn code() -> u8 {
+		32
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for ColormapNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __colormap__ = <Option<Colormap> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __new__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		let __state__ = <ColormapState as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 18;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			window: __window__,
+			colormap: __colormap__,
+			new: __new__,
+			state: __state__,
+		})
+	}
+}
+impl ConfigureRequest {
+	#[doc = " Returns the values given in the [`ConfigureWindow`] request."]
+	#[doc = ""]
+	#[doc = " Only the values included in the `value_mask` are [`Some`]. This allows"]
+	#[doc = " a window manager to forward the request unchanged:"]
+	#[doc = ""]
+	#[doc = " ```ignore"]
+	#[doc = " ConfigureWindow {"]
+	#[doc = "     target: event.window,"]
+	#[doc = "     values: event.values(),"]
+	#[doc = " }"]
+	#[doc = " ```"]
+	#[doc = ""]
+	#[doc = " [`ConfigureWindow`]: crate::x11::requests::ConfigureWindow"]
+	#[must_use]
+	pub fn values(&self) -> ConfigureWindowValues {
+		let given = |bit| self.value_mask.contains(bit);
+		ConfigureWindowValues {
+			x: given(ConfigureWindowMask::X).then_some(self.x),
+			y: given(ConfigureWindowMask::Y).then_some(self.y),
+			width: given(ConfigureWindowMask::WIDTH).then_some(self.width),
+			height: given(ConfigureWindowMask::HEIGHT).then_some(self.height),
+			border_width: given(ConfigureWindowMask::BORDER_WIDTH).then_some(self.border_width),
+			sibling: self.sibling.filter(|_| given(ConfigureWindowMask::SIBLING)),
+			stack_mode: given(ConfigureWindowMask::STACK_MODE).then_some(self.stack_mode),
+		}
+	}
+}
@@ -0,0 +1,1623 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use super::xfixes::Region;
+use crate::x11::{extensions::Extension, *};
+#[doc = " The name of the Composite extension, used to query its codes with"]
+#[doc = " `QueryExtension`."]
+pub const NAME: &str = "Composite";
+#[doc = " The Composite extension, whose codes are looked up in the [current]"]
+#[doc = " [`Extensions`] when its messages are written or read."]
+#[doc = ""]
+#[doc = " [current]: super::Extensions::current"]
+#[doc = " [`Extensions`]: super::Extensions"]
+pub const EXTENSION: Extension = Extension::new(NAME);
+#[doc = " Whether the X server or the client is responsible for updating the"]
+#[doc = " parent of a redirected window."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum UpdateMode {
+	#[doc = " The X server automatically draws redirected windows to their"]
+	#[doc = " parent."]
+	Automatic,
+	#[doc = " The client which redirected the windows, typically a compositing"]
+	#[doc = " manager, draws them to their parent itself."]
+	Manual,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for UpdateMode {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for UpdateMode {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl cornflakes::Writable for UpdateMode {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		match self {
+			Self::Automatic => {
+				writer.put_u8(0);
+			}
+			Self::Manual => {
+				writer.put_u8(1);
+			}
+		}
+		Ok(())
+	}
+}
+impl cornflakes::Readable for UpdateMode {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		match <u8 as cornflakes::Readable>::read_from(reader)? {
+			0 => Ok(Self::Automatic),
+			1 => Ok(Self::Manual),
+			other_discrim => Err(cornflakes::ReadError::UnrecognizedDiscriminant(
+				other_discrim,
+			)),
+		}
+	}
+}
+impl xrb::WireEnum for UpdateMode {
+	const WIDTH: usize = 1;
+	fn raw(&self) -> u32 {
+		match self {
+			Self::Automatic => 0,
+			Self::Manual => 1,
+		}
+	}
+}
+#[doc = " Informs the X server of the version of the Composite extension"]
+#[doc = " supported by the client, and queries the version supported by the X"]
+#[doc = " server."]
+pub struct QueryVersion {
+	pub client_major_version: u32,
+	pub client_minor_version: u32,
+}
+pub struct QueryVersionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub major_version: u32,
+	pub minor_version: u32,
+}
+#[doc = " Redirects the hierarchy of the given `window` to off-screen storage."]
+pub struct RedirectWindow {
+	pub window: Window,
+	#[doc = " Only one client may redirect a window with [`UpdateMode::Manual`]."]
+	pub update: UpdateMode,
+}
+#[doc = " Redirects the hierarchies of all current and future children of the"]
+#[doc = " given `window` to off-screen storage."]
+pub struct RedirectSubwindows {
+	pub window: Window,
+	#[doc = " Only one client may redirect a window with [`UpdateMode::Manual`]."]
+	pub update: UpdateMode,
+}
+#[doc = " Stops redirecting the hierarchy of the given `window`."]
+pub struct UnredirectWindow {
+	pub window: Window,
+	#[doc = " The `update` mode the window was redirected with."]
+	pub update: UpdateMode,
+}
+#[doc = " Stops redirecting the hierarchies of the children of the given"]
+#[doc = " `window`."]
+pub struct UnredirectSubwindows {
+	pub window: Window,
+	#[doc = " The `update` mode the children were redirected with."]
+	pub update: UpdateMode,
+}
+#[doc = " Creates a new `region` from the border clip of the given `window`: the"]
+#[doc = " area of the window, including its border, which is visible."]
+pub struct CreateRegionFromBorderClip {
+	pub region: Region,
+	pub window: Window,
+}
+#[doc = " Assigns the given `pixmap` to the off-screen storage of the given"]
+#[doc = " redirected `window`."]
+#[doc = ""]
+#[doc = " The `pixmap` remains valid after the window is unmapped or resized,"]
+#[doc = " at which point the window is given new off-screen storage."]
+pub struct NameWindowPixmap {
+	pub window: Window,
+	pub pixmap: Pixmap,
+}
+#[doc = " Returns the overlay window of the screen of the given `window`,"]
+#[doc = " mapping it if it isn't already."]
+pub struct GetOverlayWindow {
+	pub window: Window,
+}
+pub struct GetOverlayWindowReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " A window above all other windows but below the screen saver, which"]
+	#[doc = " is not affected by redirection."]
+	pub overlay_window: Window,
+}
+#[doc = " Releases the overlay window of the screen of the given `window`,"]
+#[doc = " unmapping it once it has been released by all clients."]
+pub struct ReleaseOverlayWindow {
+	pub window: Window,
+}
+impl cornflakes::Writable for QueryVersion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<QueryVersionReply>>::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(<Self as xrb::Request<QueryVersionReply>>::length(self));
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersion {
+	const NAME: &'static str = "QueryVersion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 0;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		writer.field("client_major_version", __client_major_version__);
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		writer.field("client_minor_version", __client_minor_version__);
+		Ok(())
+	}
+}
+impl QueryVersion {
+	#[doc = "Creates a new [`QueryVersion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(client_major_version: u32, client_minor_version: u32) -> Self {
+		Self {
+			client_major_version,
+			client_minor_version,
+		}
+	}
+}
+impl xrb::Request<QueryVersionReply> for QueryVersion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(0)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for QueryVersionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<QueryVersion>>::length(self));
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersionReply {
+	const NAME: &'static str = "QueryVersionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<QueryVersion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		writer.field("major_version", __major_version__);
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.field("minor_version", __minor_version__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl QueryVersionReply {
+	#[doc = "Creates a new [`QueryVersionReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersionReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, major_version: u32, minor_version: u32) -> Self {
+		Self {
+			_sequence_: sequence,
+			major_version,
+			minor_version,
+		}
+	}
+}
+impl xrb::Reply<QueryVersion> for QueryVersionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for RedirectWindow {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(1);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for RedirectWindow {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl xrb::PrettyPrint for RedirectWindow {
+	const NAME: &'static str = "RedirectWindow";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 1;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.field("update", __update__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl RedirectWindow {
+	#[doc = "Creates a new [`RedirectWindow`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`RedirectWindow`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window, update: UpdateMode) -> Self {
+		Self { window, update }
+	}
+}
+impl xrb::Request<()> for RedirectWindow {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(1)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for RedirectWindow {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl cornflakes::Writable for RedirectSubwindows {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(2);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for RedirectSubwindows {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl xrb::PrettyPrint for RedirectSubwindows {
+	const NAME: &'static str = "RedirectSubwindows";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 2;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.field("update", __update__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl RedirectSubwindows {
+	#[doc = "Creates a new [`RedirectSubwindows`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`RedirectSubwindows`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window, update: UpdateMode) -> Self {
+		Self { window, update }
+	}
+}
+impl xrb::Request<()> for RedirectSubwindows {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(2)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for RedirectSubwindows {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl cornflakes::Writable for UnredirectWindow {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(3);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for UnredirectWindow {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl xrb::PrettyPrint for UnredirectWindow {
+	const NAME: &'static str = "UnredirectWindow";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 3;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.field("update", __update__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl UnredirectWindow {
+	#[doc = "Creates a new [`UnredirectWindow`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`UnredirectWindow`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window, update: UpdateMode) -> Self {
+		Self { window, update }
+	}
+}
+impl xrb::Request<()> for UnredirectWindow {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(3)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for UnredirectWindow {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl cornflakes::Writable for UnredirectSubwindows {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(4);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for UnredirectSubwindows {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl xrb::PrettyPrint for UnredirectSubwindows {
+	const NAME: &'static str = "UnredirectSubwindows";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			update: __update__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 4;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__update__, writer)?;
+		writer.field("update", __update__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl UnredirectSubwindows {
+	#[doc = "Creates a new [`UnredirectSubwindows`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`UnredirectSubwindows`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window, update: UpdateMode) -> Self {
+		Self { window, update }
+	}
+}
+impl xrb::Request<()> for UnredirectSubwindows {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(4)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for UnredirectSubwindows {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __update__ = <UpdateMode as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			window: __window__,
+			update: __update__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateRegionFromBorderClip {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			window: __window__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(5);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateRegionFromBorderClip {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			window: __window__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateRegionFromBorderClip {
+	const NAME: &'static str = "CreateRegionFromBorderClip";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			window: __window__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 5;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		Ok(())
+	}
+}
+impl CreateRegionFromBorderClip {
+	#[doc = "Creates a new [`CreateRegionFromBorderClip`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateRegionFromBorderClip`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region, window: Window) -> Self {
+		Self { region, window }
+	}
+}
+impl xrb::Request<()> for CreateRegionFromBorderClip {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(5)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateRegionFromBorderClip {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			window: __window__,
+		})
+	}
+}
+impl cornflakes::Writable for NameWindowPixmap {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			pixmap: __pixmap__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(6);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__pixmap__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for NameWindowPixmap {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __pixmap__ = <Pixmap as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			window: __window__,
+			pixmap: __pixmap__,
+		})
+	}
+}
+impl xrb::PrettyPrint for NameWindowPixmap {
+	const NAME: &'static str = "NameWindowPixmap";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			pixmap: __pixmap__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 6;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__pixmap__, writer)?;
+		writer.field("pixmap", __pixmap__);
+		Ok(())
+	}
+}
+impl NameWindowPixmap {
+	#[doc = "Creates a new [`NameWindowPixmap`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`NameWindowPixmap`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window, pixmap: Pixmap) -> Self {
+		Self { window, pixmap }
+	}
+}
+impl xrb::Request<()> for NameWindowPixmap {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(6)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for NameWindowPixmap {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __pixmap__ = <Pixmap as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			window: __window__,
+			pixmap: __pixmap__,
+		})
+	}
+}
+impl cornflakes::Writable for GetOverlayWindow {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { window: __window__ } = self;
+		writer.put_u8(<Self as xrb::Request<GetOverlayWindowReply>>::major_opcode());
+		writer.put_u8(7);
+		writer.put_u16(<Self as xrb::Request<GetOverlayWindowReply>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetOverlayWindow {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { window: __window__ })
+	}
+}
+impl xrb::PrettyPrint for GetOverlayWindow {
+	const NAME: &'static str = "GetOverlayWindow";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { window: __window__ } = self;
+		{
+			let value = <Self as xrb::Request<GetOverlayWindowReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 7;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<GetOverlayWindowReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		Ok(())
+	}
+}
+impl GetOverlayWindow {
+	#[doc = "Creates a new [`GetOverlayWindow`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetOverlayWindow`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window) -> Self {
+		Self { window }
+	}
+}
+impl xrb::Request<GetOverlayWindowReply> for GetOverlayWindow {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(7)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetOverlayWindow {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { window: __window__ })
+	}
+}
+impl cornflakes::Writable for GetOverlayWindowReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			overlay_window: __overlay_window__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<GetOverlayWindow>>::length(self));
+		cornflakes::Writable::write_to(__overlay_window__, writer)?;
+		writer.put_bytes(0, 20);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetOverlayWindowReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		let __overlay_window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			overlay_window: __overlay_window__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetOverlayWindowReply {
+	const NAME: &'static str = "GetOverlayWindowReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			overlay_window: __overlay_window__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<GetOverlayWindow>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__overlay_window__, writer)?;
+		writer.field("overlay_window", __overlay_window__);
+		writer.put_bytes(0, 20);
+		writer.unused();
+		Ok(())
+	}
+}
+impl GetOverlayWindowReply {
+	#[doc = "Creates a new [`GetOverlayWindowReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetOverlayWindowReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, overlay_window: Window) -> Self {
+		Self {
+			_sequence_: sequence,
+			overlay_window,
+		}
+	}
+}
+impl xrb::Reply<GetOverlayWindow> for GetOverlayWindowReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetOverlayWindowReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __overlay_window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			overlay_window: __overlay_window__,
+		})
+	}
+}
+impl cornflakes::Writable for ReleaseOverlayWindow {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { window: __window__ } = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(8);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ReleaseOverlayWindow {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { window: __window__ })
+	}
+}
+impl xrb::PrettyPrint for ReleaseOverlayWindow {
+	const NAME: &'static str = "ReleaseOverlayWindow";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { window: __window__ } = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 8;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		Ok(())
+	}
+}
+impl ReleaseOverlayWindow {
+	#[doc = "Creates a new [`ReleaseOverlayWindow`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`ReleaseOverlayWindow`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window) -> Self {
+		Self { window }
+	}
+}
+impl xrb::Request<()> for ReleaseOverlayWindow {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(8)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for ReleaseOverlayWindow {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { window: __window__ })
+	}
+}
@@ -0,0 +1,1238 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use super::xfixes::Region;
+use crate::x11::{extensions::Extension, *};
+#[doc = " The name of the DAMAGE extension, used to query its codes with"]
+#[doc = " `QueryExtension`."]
+pub const NAME: &str = "DAMAGE";
+#[doc = " The DAMAGE extension, whose codes are looked up in the [current]"]
+#[doc = " [`Extensions`] when its messages are written or read."]
+#[doc = ""]
+#[doc = " [current]: super::Extensions::current"]
+#[doc = " [`Extensions`]: super::Extensions"]
+pub const EXTENSION: Extension = Extension::new(NAME);
+#[doc = " An object which tracks the areas of a drawable which have been"]
+#[doc = " modified ('damaged')."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Damage {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Damage {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Damage {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[doc = " How often [`DamageNotify`] events are sent for a [`Damage`] object."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReportLevel {
+	#[doc = " A [`DamageNotify`] event is sent for every damaged rectangle."]
+	RawRectangles,
+	#[doc = " A [`DamageNotify`] event is sent for every damaged rectangle not"]
+	#[doc = " already within the damaged area."]
+	DeltaRectangles,
+	#[doc = " A [`DamageNotify`] event is sent whenever the bounding box of the"]
+	#[doc = " damaged area grows."]
+	BoundingBox,
+	#[doc = " A [`DamageNotify`] event is sent only when the damaged area goes"]
+	#[doc = " from empty to non-empty."]
+	NonEmpty,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ReportLevel {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ReportLevel {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl cornflakes::Writable for Damage {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Damage {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { id: __id__ })
+	}
+}
+impl xrb::PrettyPrint for Damage {
+	const NAME: &'static str = "Damage";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		writer.field("id", __id__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for ReportLevel {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		match self {
+			Self::RawRectangles => {
+				writer.put_u8(0);
+			}
+			Self::DeltaRectangles => {
+				writer.put_u8(1);
+			}
+			Self::BoundingBox => {
+				writer.put_u8(2);
+			}
+			Self::NonEmpty => {
+				writer.put_u8(3);
+			}
+		}
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ReportLevel {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		match <u8 as cornflakes::Readable>::read_from(reader)? {
+			0 => Ok(Self::RawRectangles),
+			1 => Ok(Self::DeltaRectangles),
+			2 => Ok(Self::BoundingBox),
+			3 => Ok(Self::NonEmpty),
+			other_discrim => Err(cornflakes::ReadError::UnrecognizedDiscriminant(
+				other_discrim,
+			)),
+		}
+	}
+}
+impl xrb::WireEnum for ReportLevel {
+	const WIDTH: usize = 1;
+	fn raw(&self) -> u32 {
+		match self {
+			Self::RawRectangles => 0,
+			Self::DeltaRectangles => 1,
+			Self::BoundingBox => 2,
+			Self::NonEmpty => 3,
+		}
+	}
+}
+impl Damage {
+	#[doc = " Creates a new [`Damage`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Damage`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl ResId for Damage {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+#[doc = " Informs the X server of the version of the DAMAGE extension supported"]
+#[doc = " by the client, and queries the version supported by the X server."]
+pub struct QueryVersion {
+	pub client_major_version: u32,
+	pub client_minor_version: u32,
+}
+pub struct QueryVersionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub major_version: u32,
+	pub minor_version: u32,
+}
+#[doc = " Creates a new `damage` object which tracks modifications to the given"]
+#[doc = " drawable."]
+pub struct Create {
+	pub damage: Damage,
+	#[doc = " The [`Window`] or [`Pixmap`] to track."]
+	pub drawable: Drawable,
+	pub level: ReportLevel,
+}
+#[doc = " Destroys the given `damage` object."]
+pub struct Destroy {
+	pub damage: Damage,
+}
+#[doc = " Removes the `repair` region from the damaged area of the given"]
+#[doc = " `damage` object."]
+pub struct Subtract {
+	pub damage: Damage,
+	#[doc = " The region to remove from the damaged area, or [`None`] to remove"]
+	#[doc = " the whole damaged area."]
+	pub repair: Option<Region>,
+	#[doc = " If not [`None`], this region is set to the area which was removed."]
+	pub parts: Option<Region>,
+}
+#[doc = " Reports the given `region` of a drawable as damaged, as if it had"]
+#[doc = " been drawn to."]
+pub struct Add {
+	#[doc = " The [`Window`] or [`Pixmap`] which was damaged."]
+	pub drawable: Drawable,
+	pub region: Region,
+}
+#[doc = " Sent when a drawable tracked by a [`Damage`] object is modified,"]
+#[doc = " according to its [`ReportLevel`]."]
+pub struct DamageNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The [`ReportLevel`] of the `damage` object, with the most"]
+	#[doc = " significant bit set if more `DamageNotify` events follow this one."]
+	pub level: u8,
+	#[doc = " The [`Window`] or [`Pixmap`] which was damaged."]
+	pub drawable: Drawable,
+	pub damage: Damage,
+	pub timestamp: Timestamp,
+	#[doc = " The area of the drawable which was damaged."]
+	pub area: Rectangle,
+	#[doc = " The geometry of the drawable."]
+	pub geometry: Rectangle,
+}
+impl cornflakes::Writable for QueryVersion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<QueryVersionReply>>::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(<Self as xrb::Request<QueryVersionReply>>::length(self));
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersion {
+	const NAME: &'static str = "QueryVersion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 0;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		writer.field("client_major_version", __client_major_version__);
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		writer.field("client_minor_version", __client_minor_version__);
+		Ok(())
+	}
+}
+impl QueryVersion {
+	#[doc = "Creates a new [`QueryVersion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(client_major_version: u32, client_minor_version: u32) -> Self {
+		Self {
+			client_major_version,
+			client_minor_version,
+		}
+	}
+}
+impl xrb::Request<QueryVersionReply> for QueryVersion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(0)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for QueryVersionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<QueryVersion>>::length(self));
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersionReply {
+	const NAME: &'static str = "QueryVersionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<QueryVersion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		writer.field("major_version", __major_version__);
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.field("minor_version", __minor_version__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl QueryVersionReply {
+	#[doc = "Creates a new [`QueryVersionReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersionReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, major_version: u32, minor_version: u32) -> Self {
+		Self {
+			_sequence_: sequence,
+			major_version,
+			minor_version,
+		}
+	}
+}
+impl xrb::Reply<QueryVersion> for QueryVersionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for Create {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			damage: __damage__,
+			drawable: __drawable__,
+			level: __level__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(1);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__level__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Create {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __level__ = <ReportLevel as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			damage: __damage__,
+			drawable: __drawable__,
+			level: __level__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Create {
+	const NAME: &'static str = "Create";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			damage: __damage__,
+			drawable: __drawable__,
+			level: __level__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 1;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		writer.field("damage", __damage__);
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__level__, writer)?;
+		writer.field("level", __level__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl Create {
+	#[doc = "Creates a new [`Create`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Create`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(damage: Damage, drawable: Drawable, level: ReportLevel) -> Self {
+		Self {
+			damage,
+			drawable,
+			level,
+		}
+	}
+}
+impl xrb::Request<()> for Create {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(1)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Create {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __level__ = <ReportLevel as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			damage: __damage__,
+			drawable: __drawable__,
+			level: __level__,
+		})
+	}
+}
+impl cornflakes::Writable for Destroy {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { damage: __damage__ } = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(2);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Destroy {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { damage: __damage__ })
+	}
+}
+impl xrb::PrettyPrint for Destroy {
+	const NAME: &'static str = "Destroy";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { damage: __damage__ } = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 2;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		writer.field("damage", __damage__);
+		Ok(())
+	}
+}
+impl Destroy {
+	#[doc = "Creates a new [`Destroy`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Destroy`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(damage: Damage) -> Self {
+		Self { damage }
+	}
+}
+impl xrb::Request<()> for Destroy {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(2)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Destroy {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { damage: __damage__ })
+	}
+}
+impl cornflakes::Writable for Subtract {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			damage: __damage__,
+			repair: __repair__,
+			parts: __parts__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(3);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		cornflakes::Writable::write_to(__repair__, writer)?;
+		cornflakes::Writable::write_to(__parts__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Subtract {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		let __repair__ = <Option<Region> as cornflakes::Readable>::read_from(reader)?;
+		let __parts__ = <Option<Region> as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			damage: __damage__,
+			repair: __repair__,
+			parts: __parts__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Subtract {
+	const NAME: &'static str = "Subtract";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			damage: __damage__,
+			repair: __repair__,
+			parts: __parts__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 3;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		writer.field("damage", __damage__);
+		cornflakes::Writable::write_to(__repair__, writer)?;
+		writer.field("repair", __repair__);
+		cornflakes::Writable::write_to(__parts__, writer)?;
+		writer.field("parts", __parts__);
+		Ok(())
+	}
+}
+impl Subtract {
+	#[doc = "Creates a new [`Subtract`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Subtract`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(damage: Damage, repair: Option<Region>, parts: Option<Region>) -> Self {
+		Self {
+			damage,
+			repair,
+			parts,
+		}
+	}
+}
+impl xrb::Request<()> for Subtract {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(3)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Subtract {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		let __repair__ = <Option<Region> as cornflakes::Readable>::read_from(reader)?;
+		let __parts__ = <Option<Region> as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			damage: __damage__,
+			repair: __repair__,
+			parts: __parts__,
+		})
+	}
+}
+impl cornflakes::Writable for Add {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			drawable: __drawable__,
+			region: __region__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(4);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__region__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Add {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			drawable: __drawable__,
+			region: __region__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Add {
+	const NAME: &'static str = "Add";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			drawable: __drawable__,
+			region: __region__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 4;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		Ok(())
+	}
+}
+impl Add {
+	#[doc = "Creates a new [`Add`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Add`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(drawable: Drawable, region: Region) -> Self {
+		Self { drawable, region }
+	}
+}
+impl xrb::Request<()> for Add {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(4)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Add {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			drawable: __drawable__,
+			region: __region__,
+		})
+	}
+}
+impl cornflakes::Writable for DamageNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			level: __level__,
+			drawable: __drawable__,
+			damage: __damage__,
+			timestamp: __timestamp__,
+			area: __area__,
+			geometry: __geometry__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		cornflakes::Writable::write_to(__level__, writer)?;
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		cornflakes::Writable::write_to(__timestamp__, writer)?;
+		cornflakes::Writable::write_to(__area__, writer)?;
+		cornflakes::Writable::write_to(__geometry__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for DamageNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __level__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		let __timestamp__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __area__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		let __geometry__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			_sequence_,
+			level: __level__,
+			drawable: __drawable__,
+			damage: __damage__,
+			timestamp: __timestamp__,
+			area: __area__,
+			geometry: __geometry__,
+		})
+	}
+}
+impl xrb::PrettyPrint for DamageNotify {
+	const NAME: &'static str = "DamageNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			level: __level__,
+			drawable: __drawable__,
+			damage: __damage__,
+			timestamp: __timestamp__,
+			area: __area__,
+			geometry: __geometry__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		cornflakes::Writable::write_to(__level__, writer)?;
+		writer.field("level", __level__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__damage__, writer)?;
+		writer.field("damage", __damage__);
+		cornflakes::Writable::write_to(__timestamp__, writer)?;
+		writer.field("timestamp", __timestamp__);
+		cornflakes::Writable::write_to(__area__, writer)?;
+		writer.field("area", __area__);
+		cornflakes::Writable::write_to(__geometry__, writer)?;
+		writer.field("geometry", __geometry__);
+		Ok(())
+	}
+}
+impl DamageNotify {
+	#[doc = "Creates a new [`DamageNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`DamageNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		level: u8,
+		drawable: Drawable,
+		damage: Damage,
+		timestamp: Timestamp,
+		area: Rectangle,
+		geometry: Rectangle,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			level,
+			drawable,
+			damage,
+			timestamp,
+			area,
+			geometry,
+		}
+	}
+}
+impl xrb::Event for DamageNotify {
+	fn code() -> u8 {
+		EXTENSION.first_event()
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for DamageNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __level__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __damage__ = <Damage as cornflakes::Readable>::read_from(reader)?;
+		let __timestamp__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __area__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		let __geometry__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			_sequence_,
+			level: __level__,
+			drawable: __drawable__,
+			damage: __damage__,
+			timestamp: __timestamp__,
+			area: __area__,
+			geometry: __geometry__,
+		})
+	}
+}
@@ -0,0 +1,3773 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::{extensions::Extension, *};
+#[doc = " The name of the RENDER extension, used to query its codes with"]
+#[doc = " `QueryExtension`."]
+pub const NAME: &str = "RENDER";
+#[doc = " The RENDER extension, whose codes are looked up in the [current]"]
+#[doc = " [`Extensions`] when its messages are written or read."]
+#[doc = ""]
+#[doc = " [current]: super::Extensions::current"]
+#[doc = " [`Extensions`]: super::Extensions"]
+pub const EXTENSION: Extension = Extension::new(NAME);
+#[doc = " The ID of a glyph within a [`GlyphSet`]."]
+pub type Glyph = u32;
+#[doc = " The ID of a [`PictFormInfo`]: a format which a [`Picture`] may have."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PictFormat(u32);
+#[automatically_derived]
+impl cornflakes::StaticByteSize for PictFormat {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictFormat {
+	fn byte_size(&self) -> usize {
+		let Self(__0__) = self;
+		cornflakes::ByteSize::byte_size(__0__)
+	}
+}
+#[doc = " A drawable, along with the format and other state used to composite it."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Picture {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Picture {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Picture {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[doc = " A set of glyph images which may be composited."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GlyphSet {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for GlyphSet {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for GlyphSet {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+impl cornflakes::Writable for PictFormat {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self(__0__) = self;
+		cornflakes::Writable::write_to(__0__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictFormat {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __0__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self(__0__))
+	}
+}
+impl xrb::PrettyPrint for PictFormat {
+	const NAME: &'static str = "PictFormat";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self(__0__) = self;
+		cornflakes::Writable::write_to(__0__, writer)?;
+		writer.field("0", __0__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for Picture {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Picture {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { id: __id__ })
+	}
+}
+impl xrb::PrettyPrint for Picture {
+	const NAME: &'static str = "Picture";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		writer.field("id", __id__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for GlyphSet {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GlyphSet {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { id: __id__ })
+	}
+}
+impl xrb::PrettyPrint for GlyphSet {
+	const NAME: &'static str = "GlyphSet";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		writer.field("id", __id__);
+		Ok(())
+	}
+}
+impl PictFormat {
+	#[doc = " Creates a new [`PictFormat`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self(id)
+	}
+	#[doc = " Creates a new [`PictFormat`] with an `id` of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self(0)
+	}
+	#[doc = " Gets the `id` of the [`PictFormat`]."]
+	#[must_use]
+	pub const fn id(&self) -> u32 {
+		self.0
+	}
+}
+impl Picture {
+	#[doc = " Creates a new [`Picture`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Picture`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl ResId for Picture {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+impl GlyphSet {
+	#[doc = " Creates a new [`GlyphSet`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`GlyphSet`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl ResId for GlyphSet {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+#[doc = " Whether a [`PictFormInfo`] describes an indexed or a direct format."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PictType {
+	#[doc = " Pixel values are indices into a colormap."]
+	Indexed,
+	#[doc = " Pixel values directly encode their color channels."]
+	Direct,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for PictType {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictType {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " The position and size of each color channel within the pixel values of"]
+#[doc = " a direct [`PictFormInfo`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DirectFormat {
+	pub red_shift: u16,
+	pub red_mask: u16,
+	pub green_shift: u16,
+	pub green_mask: u16,
+	pub blue_shift: u16,
+	pub blue_mask: u16,
+	pub alpha_shift: u16,
+	pub alpha_mask: u16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for DirectFormat {
+	fn static_byte_size() -> usize {
+		<u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for DirectFormat {
+	fn byte_size(&self) -> usize {
+		let Self {
+			red_shift: __red_shift__,
+			red_mask: __red_mask__,
+			green_shift: __green_shift__,
+			green_mask: __green_mask__,
+			blue_shift: __blue_shift__,
+			blue_mask: __blue_mask__,
+			alpha_shift: __alpha_shift__,
+			alpha_mask: __alpha_mask__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__red_shift__)
+			+ cornflakes::ByteSize::byte_size(__red_mask__)
+			+ cornflakes::ByteSize::byte_size(__green_shift__)
+			+ cornflakes::ByteSize::byte_size(__green_mask__)
+			+ cornflakes::ByteSize::byte_size(__blue_shift__)
+			+ cornflakes::ByteSize::byte_size(__blue_mask__)
+			+ cornflakes::ByteSize::byte_size(__alpha_shift__)
+			+ cornflakes::ByteSize::byte_size(__alpha_mask__)
+	}
+}
+#[doc = " A format which a [`Picture`] may have."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PictFormInfo {
+	pub id: PictFormat,
+	pub r#type: PictType,
+	pub depth: u8,
+	#[doc = " The layout of color channels, if `r#type` is [`PictType::Direct`]."]
+	pub direct: DirectFormat,
+	#[doc = " The colormap of the format, if `r#type` is [`PictType::Indexed`]."]
+	pub colormap: Option<Colormap>,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for PictFormInfo {
+	fn static_byte_size() -> usize {
+		<PictFormat as cornflakes::StaticByteSize>::static_byte_size()
+			+ <PictType as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u8 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <DirectFormat as cornflakes::StaticByteSize>::static_byte_size()
+			+ <Option<Colormap> as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictFormInfo {
+	fn byte_size(&self) -> usize {
+		let Self {
+			id: __id__,
+			r#type: __type__,
+			depth: __depth__,
+			direct: __direct__,
+			colormap: __colormap__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__id__)
+			+ cornflakes::ByteSize::byte_size(__type__)
+			+ cornflakes::ByteSize::byte_size(__depth__)
+			+ cornflakes::ByteSize::byte_size(__direct__)
+			+ cornflakes::ByteSize::byte_size(__colormap__)
+	}
+}
+#[doc = " The [`PictFormat`] used for windows with a particular `visual`."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PictVisual {
+	pub visual: VisualId,
+	pub format: PictFormat,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for PictVisual {
+	fn static_byte_size() -> usize {
+		<VisualId as cornflakes::StaticByteSize>::static_byte_size()
+			+ <PictFormat as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictVisual {
+	fn byte_size(&self) -> usize {
+		let Self {
+			visual: __visual__,
+			format: __format__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__visual__) + cornflakes::ByteSize::byte_size(__format__)
+	}
+}
+#[doc = " The [`PictVisual`]s supported for a particular `depth` on a screen."]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PictDepth {
+	pub depth: u8,
+	pub visuals: Vec<PictVisual>,
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictDepth {
+	fn byte_size(&self) -> usize {
+		let Self {
+			depth: __depth__,
+			visuals: __visuals__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__depth__) + cornflakes::ByteSize::byte_size(__visuals__)
+	}
+}
+#[doc = " The [`PictDepth`]s supported by a screen."]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PictScreen {
+	#[doc = " The format used for core protocol requests that have no visual."]
+	pub fallback: PictFormat,
+	pub depths: Vec<PictDepth>,
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictScreen {
+	fn byte_size(&self) -> usize {
+		let Self {
+			fallback: __fallback__,
+			depths: __depths__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__fallback__) + cornflakes::ByteSize::byte_size(__depths__)
+	}
+}
+#[doc = " The operator used to composite a source [`Picture`] onto a destination"]
+#[doc = " [`Picture`]."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PictOp {
+	Clear,
+	Src,
+	Dst,
+	Over,
+	OverReverse,
+	In,
+	InReverse,
+	Out,
+	OutReverse,
+	Atop,
+	AtopReverse,
+	Xor,
+	Add,
+	Saturate,
+	DisjointClear = 16,
+	DisjointSrc,
+	DisjointDst,
+	DisjointOver,
+	DisjointOverReverse,
+	DisjointIn,
+	DisjointInReverse,
+	DisjointOut,
+	DisjointOutReverse,
+	DisjointAtop,
+	DisjointAtopReverse,
+	DisjointXor,
+	ConjointClear = 32,
+	ConjointSrc,
+	ConjointDst,
+	ConjointOver,
+	ConjointOverReverse,
+	ConjointIn,
+	ConjointInReverse,
+	ConjointOut,
+	ConjointOutReverse,
+	ConjointAtop,
+	ConjointAtopReverse,
+	ConjointXor,
+	Multiply = 48,
+	Screen,
+	Overlay,
+	Darken,
+	Lighten,
+	ColorDodge,
+	ColorBurn,
+	HardLight,
+	SoftLight,
+	Difference,
+	Exclusion,
+	HslHue,
+	HslSaturation,
+	HslColor,
+	HslLuminosity,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for PictOp {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for PictOp {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " A color with 16-bit red, green, blue, and alpha channels."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Color {
+	pub red: u16,
+	pub green: u16,
+	pub blue: u16,
+	pub alpha: u16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Color {
+	fn static_byte_size() -> usize {
+		<u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Color {
+	fn byte_size(&self) -> usize {
+		let Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+			alpha: __alpha__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__red__)
+			+ cornflakes::ByteSize::byte_size(__green__)
+			+ cornflakes::ByteSize::byte_size(__blue__)
+			+ cornflakes::ByteSize::byte_size(__alpha__)
+	}
+}
+#[doc = " The dimensions and positioning of a glyph's image."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GlyphInfo {
+	pub width: u16,
+	pub height: u16,
+	#[doc = " The x-coordinate of the glyph's origin within its image."]
+	pub x: i16,
+	#[doc = " The y-coordinate of the glyph's origin within its image."]
+	pub y: i16,
+	#[doc = " The change in x-coordinate to the origin of the next glyph."]
+	pub x_offset: i16,
+	#[doc = " The change in y-coordinate to the origin of the next glyph."]
+	pub y_offset: i16,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for GlyphInfo {
+	fn static_byte_size() -> usize {
+		<u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <u16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+			+ <i16 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for GlyphInfo {
+	fn byte_size(&self) -> usize {
+		let Self {
+			width: __width__,
+			height: __height__,
+			x: __x__,
+			y: __y__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+		} = self;
+		cornflakes::ByteSize::byte_size(__width__)
+			+ cornflakes::ByteSize::byte_size(__height__)
+			+ cornflakes::ByteSize::byte_size(__x__)
+			+ cornflakes::ByteSize::byte_size(__y__)
+			+ cornflakes::ByteSize::byte_size(__x_offset__)
+			+ cornflakes::ByteSize::byte_size(__y_offset__)
+	}
+}
+impl cornflakes::Writable for PictType {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		match self {
+			Self::Indexed => {
+				writer.put_u8(0);
+			}
+			Self::Direct => {
+				writer.put_u8(1);
+			}
+		}
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictType {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		match <u8 as cornflakes::Readable>::read_from(reader)? {
+			0 => Ok(Self::Indexed),
+			1 => Ok(Self::Direct),
+			other_discrim => Err(cornflakes::ReadError::UnrecognizedDiscriminant(
+				other_discrim,
+			)),
+		}
+	}
+}
+impl xrb::WireEnum for PictType {
+	const WIDTH: usize = 1;
+	fn raw(&self) -> u32 {
+		match self {
+			Self::Indexed => 0,
+			Self::Direct => 1,
+		}
+	}
+}
+impl cornflakes::Writable for DirectFormat {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			red_shift: __red_shift__,
+			red_mask: __red_mask__,
+			green_shift: __green_shift__,
+			green_mask: __green_mask__,
+			blue_shift: __blue_shift__,
+			blue_mask: __blue_mask__,
+			alpha_shift: __alpha_shift__,
+			alpha_mask: __alpha_mask__,
+		} = self;
+		cornflakes::Writable::write_to(__red_shift__, writer)?;
+		cornflakes::Writable::write_to(__red_mask__, writer)?;
+		cornflakes::Writable::write_to(__green_shift__, writer)?;
+		cornflakes::Writable::write_to(__green_mask__, writer)?;
+		cornflakes::Writable::write_to(__blue_shift__, writer)?;
+		cornflakes::Writable::write_to(__blue_mask__, writer)?;
+		cornflakes::Writable::write_to(__alpha_shift__, writer)?;
+		cornflakes::Writable::write_to(__alpha_mask__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for DirectFormat {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __red_shift__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __red_mask__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __green_shift__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __green_mask__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __blue_shift__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __blue_mask__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __alpha_shift__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __alpha_mask__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			red_shift: __red_shift__,
+			red_mask: __red_mask__,
+			green_shift: __green_shift__,
+			green_mask: __green_mask__,
+			blue_shift: __blue_shift__,
+			blue_mask: __blue_mask__,
+			alpha_shift: __alpha_shift__,
+			alpha_mask: __alpha_mask__,
+		})
+	}
+}
+impl xrb::PrettyPrint for DirectFormat {
+	const NAME: &'static str = "DirectFormat";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			red_shift: __red_shift__,
+			red_mask: __red_mask__,
+			green_shift: __green_shift__,
+			green_mask: __green_mask__,
+			blue_shift: __blue_shift__,
+			blue_mask: __blue_mask__,
+			alpha_shift: __alpha_shift__,
+			alpha_mask: __alpha_mask__,
+		} = self;
+		cornflakes::Writable::write_to(__red_shift__, writer)?;
+		writer.field("red_shift", __red_shift__);
+		cornflakes::Writable::write_to(__red_mask__, writer)?;
+		writer.field("red_mask", __red_mask__);
+		cornflakes::Writable::write_to(__green_shift__, writer)?;
+		writer.field("green_shift", __green_shift__);
+		cornflakes::Writable::write_to(__green_mask__, writer)?;
+		writer.field("green_mask", __green_mask__);
+		cornflakes::Writable::write_to(__blue_shift__, writer)?;
+		writer.field("blue_shift", __blue_shift__);
+		cornflakes::Writable::write_to(__blue_mask__, writer)?;
+		writer.field("blue_mask", __blue_mask__);
+		cornflakes::Writable::write_to(__alpha_shift__, writer)?;
+		writer.field("alpha_shift", __alpha_shift__);
+		cornflakes::Writable::write_to(__alpha_mask__, writer)?;
+		writer.field("alpha_mask", __alpha_mask__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for PictFormInfo {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			id: __id__,
+			r#type: __type__,
+			depth: __depth__,
+			direct: __direct__,
+			colormap: __colormap__,
+		} = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		cornflakes::Writable::write_to(__type__, writer)?;
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.put_bytes(0, 2);
+		cornflakes::Writable::write_to(__direct__, writer)?;
+		cornflakes::Writable::write_to(__colormap__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictFormInfo {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __id__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		let __type__ = <PictType as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depth__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 2;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __direct__ = <DirectFormat as cornflakes::Readable>::read_from(reader)?;
+		let __colormap__ = <Option<Colormap> as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			id: __id__,
+			r#type: __type__,
+			depth: __depth__,
+			direct: __direct__,
+			colormap: __colormap__,
+		})
+	}
+}
+impl xrb::PrettyPrint for PictFormInfo {
+	const NAME: &'static str = "PictFormInfo";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			id: __id__,
+			r#type: __type__,
+			depth: __depth__,
+			direct: __direct__,
+			colormap: __colormap__,
+		} = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		writer.field("id", __id__);
+		cornflakes::Writable::write_to(__type__, writer)?;
+		writer.field("type", __type__);
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.field("depth", __depth__);
+		writer.put_bytes(0, 2);
+		writer.unused();
+		cornflakes::Writable::write_to(__direct__, writer)?;
+		writer.field("direct", __direct__);
+		cornflakes::Writable::write_to(__colormap__, writer)?;
+		writer.field("colormap", __colormap__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for PictVisual {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			visual: __visual__,
+			format: __format__,
+		} = self;
+		cornflakes::Writable::write_to(__visual__, writer)?;
+		cornflakes::Writable::write_to(__format__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictVisual {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __visual__ = <VisualId as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			visual: __visual__,
+			format: __format__,
+		})
+	}
+}
+impl xrb::PrettyPrint for PictVisual {
+	const NAME: &'static str = "PictVisual";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			visual: __visual__,
+			format: __format__,
+		} = self;
+		cornflakes::Writable::write_to(__visual__, writer)?;
+		writer.field("visual", __visual__);
+		cornflakes::Writable::write_to(__format__, writer)?;
+		writer.field("format", __format__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for PictDepth {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			depth: __depth__,
+			visuals: __visuals__,
+		} = self;
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.put_bytes(0, 1);
+		let __visuals_len__: u16 = self.visuals.len() as u16;
+		cornflakes::Writable::write_to(&__visuals_len__, writer)?;
+		writer.put_bytes(0, 4);
+		cornflakes::Writable::write_to(__visuals__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictDepth {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depth__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 1;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __visuals_len__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __visuals__ = {
+			let context: usize = {
+				let visuals_len: &u16 = &__visuals_len__;
+				*visuals_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<PictVisual as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			depth: __depth__,
+			visuals: __visuals__,
+		})
+	}
+}
+impl xrb::PrettyPrint for PictDepth {
+	const NAME: &'static str = "PictDepth";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			depth: __depth__,
+			visuals: __visuals__,
+		} = self;
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.field("depth", __depth__);
+		writer.put_bytes(0, 1);
+		writer.unused();
+		let __visuals_len__: u16 = self.visuals.len() as u16;
+		cornflakes::Writable::write_to(&__visuals_len__, writer)?;
+		writer.field("visuals_len", &__visuals_len__);
+		writer.put_bytes(0, 4);
+		writer.unused();
+		cornflakes::Writable::write_to(__visuals__, writer)?;
+		writer.field("visuals", __visuals__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for PictScreen {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			fallback: __fallback__,
+			depths: __depths__,
+		} = self;
+		let __depths_len__: u32 = self.depths.len() as u32;
+		cornflakes::Writable::write_to(&__depths_len__, writer)?;
+		cornflakes::Writable::write_to(__fallback__, writer)?;
+		cornflakes::Writable::write_to(__depths__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictScreen {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depths_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __fallback__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		let __depths__ = {
+			let context: usize = {
+				let depths_len: &u32 = &__depths_len__;
+				*depths_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<PictDepth as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			fallback: __fallback__,
+			depths: __depths__,
+		})
+	}
+}
+impl xrb::PrettyPrint for PictScreen {
+	const NAME: &'static str = "PictScreen";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			fallback: __fallback__,
+			depths: __depths__,
+		} = self;
+		let __depths_len__: u32 = self.depths.len() as u32;
+		cornflakes::Writable::write_to(&__depths_len__, writer)?;
+		writer.field("depths_len", &__depths_len__);
+		cornflakes::Writable::write_to(__fallback__, writer)?;
+		writer.field("fallback", __fallback__);
+		cornflakes::Writable::write_to(__depths__, writer)?;
+		writer.field("depths", __depths__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for PictOp {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		match self {
+			Self::Clear => {
+				writer.put_u8(0);
+			}
+			Self::Src => {
+				writer.put_u8(1);
+			}
+			Self::Dst => {
+				writer.put_u8(2);
+			}
+			Self::Over => {
+				writer.put_u8(3);
+			}
+			Self::OverReverse => {
+				writer.put_u8(4);
+			}
+			Self::In => {
+				writer.put_u8(5);
+			}
+			Self::InReverse => {
+				writer.put_u8(6);
+			}
+			Self::Out => {
+				writer.put_u8(7);
+			}
+			Self::OutReverse => {
+				writer.put_u8(8);
+			}
+			Self::Atop => {
+				writer.put_u8(9);
+			}
+			Self::AtopReverse => {
+				writer.put_u8(10);
+			}
+			Self::Xor => {
+				writer.put_u8(11);
+			}
+			Self::Add => {
+				writer.put_u8(12);
+			}
+			Self::Saturate => {
+				writer.put_u8(13);
+			}
+			Self::DisjointClear => {
+				writer.put_u8(16);
+			}
+			Self::DisjointSrc => {
+				writer.put_u8(17);
+			}
+			Self::DisjointDst => {
+				writer.put_u8(18);
+			}
+			Self::DisjointOver => {
+				writer.put_u8(19);
+			}
+			Self::DisjointOverReverse => {
+				writer.put_u8(20);
+			}
+			Self::DisjointIn => {
+				writer.put_u8(21);
+			}
+			Self::DisjointInReverse => {
+				writer.put_u8(22);
+			}
+			Self::DisjointOut => {
+				writer.put_u8(23);
+			}
+			Self::DisjointOutReverse => {
+				writer.put_u8(24);
+			}
+			Self::DisjointAtop => {
+				writer.put_u8(25);
+			}
+			Self::DisjointAtopReverse => {
+				writer.put_u8(26);
+			}
+			Self::DisjointXor => {
+				writer.put_u8(27);
+			}
+			Self::ConjointClear => {
+				writer.put_u8(32);
+			}
+			Self::ConjointSrc => {
+				writer.put_u8(33);
+			}
+			Self::ConjointDst => {
+				writer.put_u8(34);
+			}
+			Self::ConjointOver => {
+				writer.put_u8(35);
+			}
+			Self::ConjointOverReverse => {
+				writer.put_u8(36);
+			}
+			Self::ConjointIn => {
+				writer.put_u8(37);
+			}
+			Self::ConjointInReverse => {
+				writer.put_u8(38);
+			}
+			Self::ConjointOut => {
+				writer.put_u8(39);
+			}
+			Self::ConjointOutReverse => {
+				writer.put_u8(40);
+			}
+			Self::ConjointAtop => {
+				writer.put_u8(41);
+			}
+			Self::ConjointAtopReverse => {
+				writer.put_u8(42);
+			}
+			Self::ConjointXor => {
+				writer.put_u8(43);
+			}
+			Self::Multiply => {
+				writer.put_u8(48);
+			}
+			Self::Screen => {
+				writer.put_u8(49);
+			}
+			Self::Overlay => {
+				writer.put_u8(50);
+			}
+			Self::Darken => {
+				writer.put_u8(51);
+			}
+			Self::Lighten => {
+				writer.put_u8(52);
+			}
+			Self::ColorDodge => {
+				writer.put_u8(53);
+			}
+			Self::ColorBurn => {
+				writer.put_u8(54);
+			}
+			Self::HardLight => {
+				writer.put_u8(55);
+			}
+			Self::SoftLight => {
+				writer.put_u8(56);
+			}
+			Self::Difference => {
+				writer.put_u8(57);
+			}
+			Self::Exclusion => {
+				writer.put_u8(58);
+			}
+			Self::HslHue => {
+				writer.put_u8(59);
+			}
+			Self::HslSaturation => {
+				writer.put_u8(60);
+			}
+			Self::HslColor => {
+				writer.put_u8(61);
+			}
+			Self::HslLuminosity => {
+				writer.put_u8(62);
+			}
+		}
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PictOp {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		match <u8 as cornflakes::Readable>::read_from(reader)? {
+			0 => Ok(Self::Clear),
+			1 => Ok(Self::Src),
+			2 => Ok(Self::Dst),
+			3 => Ok(Self::Over),
+			4 => Ok(Self::OverReverse),
+			5 => Ok(Self::In),
+			6 => Ok(Self::InReverse),
+			7 => Ok(Self::Out),
+			8 => Ok(Self::OutReverse),
+			9 => Ok(Self::Atop),
+			10 => Ok(Self::AtopReverse),
+			11 => Ok(Self::Xor),
+			12 => Ok(Self::Add),
+			13 => Ok(Self::Saturate),
+			16 => Ok(Self::DisjointClear),
+			17 => Ok(Self::DisjointSrc),
+			18 => Ok(Self::DisjointDst),
+			19 => Ok(Self::DisjointOver),
+			20 => Ok(Self::DisjointOverReverse),
+			21 => Ok(Self::DisjointIn),
+			22 => Ok(Self::DisjointInReverse),
+			23 => Ok(Self::DisjointOut),
+			24 => Ok(Self::DisjointOutReverse),
+			25 => Ok(Self::DisjointAtop),
+			26 => Ok(Self::DisjointAtopReverse),
+			27 => Ok(Self::DisjointXor),
+			32 => Ok(Self::ConjointClear),
+			33 => Ok(Self::ConjointSrc),
+			34 => Ok(Self::ConjointDst),
+			35 => Ok(Self::ConjointOver),
+			36 => Ok(Self::ConjointOverReverse),
+			37 => Ok(Self::ConjointIn),
+			38 => Ok(Self::ConjointInReverse),
+			39 => Ok(Self::ConjointOut),
+			40 => Ok(Self::ConjointOutReverse),
+			41 => Ok(Self::ConjointAtop),
+			42 => Ok(Self::ConjointAtopReverse),
+			43 => Ok(Self::ConjointXor),
+			48 => Ok(Self::Multiply),
+			49 => Ok(Self::Screen),
+			50 => Ok(Self::Overlay),
+			51 => Ok(Self::Darken),
+			52 => Ok(Self::Lighten),
+			53 => Ok(Self::ColorDodge),
+			54 => Ok(Self::ColorBurn),
+			55 => Ok(Self::HardLight),
+			56 => Ok(Self::SoftLight),
+			57 => Ok(Self::Difference),
+			58 => Ok(Self::Exclusion),
+			59 => Ok(Self::HslHue),
+			60 => Ok(Self::HslSaturation),
+			61 => Ok(Self::HslColor),
+			62 => Ok(Self::HslLuminosity),
+			other_discrim => Err(cornflakes::ReadError::UnrecognizedDiscriminant(
+				other_discrim,
+			)),
+		}
+	}
+}
+impl xrb::WireEnum for PictOp {
+	const WIDTH: usize = 1;
+	fn raw(&self) -> u32 {
+		match self {
+			Self::Clear => 0,
+			Self::Src => 1,
+			Self::Dst => 2,
+			Self::Over => 3,
+			Self::OverReverse => 4,
+			Self::In => 5,
+			Self::InReverse => 6,
+			Self::Out => 7,
+			Self::OutReverse => 8,
+			Self::Atop => 9,
+			Self::AtopReverse => 10,
+			Self::Xor => 11,
+			Self::Add => 12,
+			Self::Saturate => 13,
+			Self::DisjointClear => 16,
+			Self::DisjointSrc => 17,
+			Self::DisjointDst => 18,
+			Self::DisjointOver => 19,
+			Self::DisjointOverReverse => 20,
+			Self::DisjointIn => 21,
+			Self::DisjointInReverse => 22,
+			Self::DisjointOut => 23,
+			Self::DisjointOutReverse => 24,
+			Self::DisjointAtop => 25,
+			Self::DisjointAtopReverse => 26,
+			Self::DisjointXor => 27,
+			Self::ConjointClear => 32,
+			Self::ConjointSrc => 33,
+			Self::ConjointDst => 34,
+			Self::ConjointOver => 35,
+			Self::ConjointOverReverse => 36,
+			Self::ConjointIn => 37,
+			Self::ConjointInReverse => 38,
+			Self::ConjointOut => 39,
+			Self::ConjointOutReverse => 40,
+			Self::ConjointAtop => 41,
+			Self::ConjointAtopReverse => 42,
+			Self::ConjointXor => 43,
+			Self::Multiply => 48,
+			Self::Screen => 49,
+			Self::Overlay => 50,
+			Self::Darken => 51,
+			Self::Lighten => 52,
+			Self::ColorDodge => 53,
+			Self::ColorBurn => 54,
+			Self::HardLight => 55,
+			Self::SoftLight => 56,
+			Self::Difference => 57,
+			Self::Exclusion => 58,
+			Self::HslHue => 59,
+			Self::HslSaturation => 60,
+			Self::HslColor => 61,
+			Self::HslLuminosity => 62,
+		}
+	}
+}
+impl cornflakes::Writable for Color {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+			alpha: __alpha__,
+		} = self;
+		cornflakes::Writable::write_to(__red__, writer)?;
+		cornflakes::Writable::write_to(__green__, writer)?;
+		cornflakes::Writable::write_to(__blue__, writer)?;
+		cornflakes::Writable::write_to(__alpha__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Color {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __red__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __green__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __blue__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __alpha__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+			alpha: __alpha__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Color {
+	const NAME: &'static str = "Color";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			red: __red__,
+			green: __green__,
+			blue: __blue__,
+			alpha: __alpha__,
+		} = self;
+		cornflakes::Writable::write_to(__red__, writer)?;
+		writer.field("red", __red__);
+		cornflakes::Writable::write_to(__green__, writer)?;
+		writer.field("green", __green__);
+		cornflakes::Writable::write_to(__blue__, writer)?;
+		writer.field("blue", __blue__);
+		cornflakes::Writable::write_to(__alpha__, writer)?;
+		writer.field("alpha", __alpha__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for GlyphInfo {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			width: __width__,
+			height: __height__,
+			x: __x__,
+			y: __y__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+		} = self;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		cornflakes::Writable::write_to(__height__, writer)?;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		cornflakes::Writable::write_to(__x_offset__, writer)?;
+		cornflakes::Writable::write_to(__y_offset__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GlyphInfo {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x_offset__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y_offset__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			width: __width__,
+			height: __height__,
+			x: __x__,
+			y: __y__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GlyphInfo {
+	const NAME: &'static str = "GlyphInfo";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			width: __width__,
+			height: __height__,
+			x: __x__,
+			y: __y__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+		} = self;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		writer.field("width", __width__);
+		cornflakes::Writable::write_to(__height__, writer)?;
+		writer.field("height", __height__);
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		cornflakes::Writable::write_to(__x_offset__, writer)?;
+		writer.field("x_offset", __x_offset__);
+		cornflakes::Writable::write_to(__y_offset__, writer)?;
+		writer.field("y_offset", __y_offset__);
+		Ok(())
+	}
+}
+#[doc = " Informs the X server of the version of the RENDER extension supported"]
+#[doc = " by the client, and queries the version supported by the X server."]
+pub struct QueryVersion {
+	pub client_major_version: u32,
+	pub client_minor_version: u32,
+}
+pub struct QueryVersionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub major_version: u32,
+	pub minor_version: u32,
+}
+#[doc = " Queries the [`PictFormInfo`]s supported by the X server, and which of"]
+#[doc = " them are used for each screen, depth, and visual."]
+pub struct QueryPictFormats;
+pub struct QueryPictFormatsReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub formats: Vec<PictFormInfo>,
+	pub screens: Vec<PictScreen>,
+	#[doc = " The subpixel order of each screen: unknown (`0`), horizontal RGB"]
+	#[doc = " (`1`), horizontal BGR (`2`), vertical RGB (`3`), vertical BGR (`4`),"]
+	#[doc = " or none (`5`)."]
+	pub subpixels: Vec<u32>,
+}
+#[doc = " Creates a new `picture` for the given drawable."]
+pub struct CreatePicture {
+	pub picture: Picture,
+	#[doc = " The [`Window`] or [`Pixmap`] to create the picture for."]
+	pub drawable: Drawable,
+	#[doc = " The format of the picture, which must have the same depth as the"]
+	#[doc = " drawable."]
+	pub format: PictFormat,
+	#[doc = " Which of the picture's attributes are given in `values`."]
+	pub value_mask: u32,
+	#[doc = " One value for each bit set in the `value_mask`, in order."]
+	pub values: Vec<u32>,
+}
+#[doc = " Destroys the given `picture`."]
+pub struct FreePicture {
+	pub picture: Picture,
+}
+#[doc = " Composites the `source` picture onto the `destination` picture with"]
+#[doc = " the given `op`."]
+pub struct Composite {
+	pub op: PictOp,
+	pub source: Picture,
+	#[doc = " If not [`None`], the alpha channel of this picture is multiplied"]
+	#[doc = " with the `source` when compositing."]
+	pub mask: Option<Picture>,
+	pub destination: Picture,
+	pub source_x: i16,
+	pub source_y: i16,
+	pub mask_x: i16,
+	pub mask_y: i16,
+	pub destination_x: i16,
+	pub destination_y: i16,
+	pub width: u16,
+	pub height: u16,
+}
+#[doc = " Creates a new `glyphset` with the given `format` for its glyph images."]
+pub struct CreateGlyphSet {
+	pub glyphset: GlyphSet,
+	pub format: PictFormat,
+}
+#[doc = " Destroys the given `glyphset`."]
+pub struct FreeGlyphSet {
+	pub glyphset: GlyphSet,
+}
+#[doc = " Adds glyphs to the given `glyphset`."]
+pub struct AddGlyphs {
+	pub glyphset: GlyphSet,
+	#[doc = " The IDs of the glyphs to add."]
+	pub glyphs: Vec<Glyph>,
+	#[doc = " The [`GlyphInfo`] of each of the `glyphs`, in the same order."]
+	pub infos: Vec<GlyphInfo>,
+	#[doc = " The images of each of the `glyphs`, in the same order, with each"]
+	#[doc = " scanline padded to a multiple of 4 bytes."]
+	pub images: Vec<u8>,
+}
+#[doc = " Removes the given `glyphs` from the given `glyphset`."]
+pub struct FreeGlyphs {
+	pub glyphset: GlyphSet,
+	pub glyphs: Vec<Glyph>,
+}
+#[doc = " Fills the given `rectangles` of the `destination` picture with the"]
+#[doc = " given `color`, using the given `op`."]
+pub struct FillRectangles {
+	pub op: PictOp,
+	pub destination: Picture,
+	pub color: Color,
+	pub rectangles: Vec<Rectangle>,
+}
+impl cornflakes::Writable for QueryVersion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<QueryVersionReply>>::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(<Self as xrb::Request<QueryVersionReply>>::length(self));
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersion {
+	const NAME: &'static str = "QueryVersion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 0;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		writer.field("client_major_version", __client_major_version__);
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		writer.field("client_minor_version", __client_minor_version__);
+		Ok(())
+	}
+}
+impl QueryVersion {
+	#[doc = "Creates a new [`QueryVersion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(client_major_version: u32, client_minor_version: u32) -> Self {
+		Self {
+			client_major_version,
+			client_minor_version,
+		}
+	}
+}
+impl xrb::Request<QueryVersionReply> for QueryVersion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(0)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for QueryVersionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<QueryVersion>>::length(self));
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersionReply {
+	const NAME: &'static str = "QueryVersionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<QueryVersion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		writer.field("major_version", __major_version__);
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.field("minor_version", __minor_version__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl QueryVersionReply {
+	#[doc = "Creates a new [`QueryVersionReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersionReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, major_version: u32, minor_version: u32) -> Self {
+		Self {
+			_sequence_: sequence,
+			major_version,
+			minor_version,
+		}
+	}
+}
+impl xrb::Reply<QueryVersion> for QueryVersionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for QueryPictFormats {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self = self;
+		writer.put_u8(<Self as xrb::Request<QueryPictFormatsReply>>::major_opcode());
+		writer.put_u8(1);
+		writer.put_u16(<Self as xrb::Request<QueryPictFormatsReply>>::length(self));
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryPictFormats {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		Ok(Self)
+	}
+}
+impl xrb::PrettyPrint for QueryPictFormats {
+	const NAME: &'static str = "QueryPictFormats";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self = self;
+		{
+			let value = <Self as xrb::Request<QueryPictFormatsReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 1;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<QueryPictFormatsReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		Ok(())
+	}
+}
+impl xrb::Request<QueryPictFormatsReply> for QueryPictFormats {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(1)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryPictFormats {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		Ok(Self)
+	}
+}
+impl cornflakes::Writable for QueryPictFormatsReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			formats: __formats__,
+			screens: __screens__,
+			subpixels: __subpixels__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<QueryPictFormats>>::length(self));
+		let __formats_len__: u32 = self.formats.len() as u32;
+		cornflakes::Writable::write_to(&__formats_len__, writer)?;
+		let __screens_len__: u32 = self.screens.len() as u32;
+		cornflakes::Writable::write_to(&__screens_len__, writer)?;
+		let __depths_len__: u32 = self
+			.screens
+			.iter()
+			.map(|screen| screen.depths.len() as u32)
+			.sum::<u32>();
+		cornflakes::Writable::write_to(&__depths_len__, writer)?;
+		let __visuals_len__: u32 = self
+			.screens
+			.iter()
+			.flat_map(|screen| &screen.depths)
+			.map(|depth| depth.visuals.len() as u32)
+			.sum::<u32>();
+		cornflakes::Writable::write_to(&__visuals_len__, writer)?;
+		let __subpixels_len__: u32 = self.subpixels.len() as u32;
+		cornflakes::Writable::write_to(&__subpixels_len__, writer)?;
+		writer.put_bytes(0, 4);
+		cornflakes::Writable::write_to(__formats__, writer)?;
+		cornflakes::Writable::write_to(__screens__, writer)?;
+		cornflakes::Writable::write_to(__subpixels__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryPictFormatsReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __formats_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __screens_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depths_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __visuals_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __subpixels_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __formats__ = {
+			let context: usize = {
+				let formats_len: &u32 = &__formats_len__;
+				*formats_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<PictFormInfo as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __screens__ = {
+			let context: usize = {
+				let screens_len: &u32 = &__screens_len__;
+				*screens_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<PictScreen as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __subpixels__ = {
+			let context: usize = {
+				let subpixels_len: &u32 = &__subpixels_len__;
+				*subpixels_len as usize
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(4usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u32 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			_sequence_,
+			formats: __formats__,
+			screens: __screens__,
+			subpixels: __subpixels__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryPictFormatsReply {
+	const NAME: &'static str = "QueryPictFormatsReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			formats: __formats__,
+			screens: __screens__,
+			subpixels: __subpixels__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<QueryPictFormats>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		let __formats_len__: u32 = self.formats.len() as u32;
+		cornflakes::Writable::write_to(&__formats_len__, writer)?;
+		writer.field("formats_len", &__formats_len__);
+		let __screens_len__: u32 = self.screens.len() as u32;
+		cornflakes::Writable::write_to(&__screens_len__, writer)?;
+		writer.field("screens_len", &__screens_len__);
+		let __depths_len__: u32 = self
+			.screens
+			.iter()
+			.map(|screen| screen.depths.len() as u32)
+			.sum::<u32>();
+		cornflakes::Writable::write_to(&__depths_len__, writer)?;
+		writer.field("depths_len", &__depths_len__);
+		let __visuals_len__: u32 = self
+			.screens
+			.iter()
+			.flat_map(|screen| &screen.depths)
+			.map(|depth| depth.visuals.len() as u32)
+			.sum::<u32>();
+		cornflakes::Writable::write_to(&__visuals_len__, writer)?;
+		writer.field("visuals_len", &__visuals_len__);
+		let __subpixels_len__: u32 = self.subpixels.len() as u32;
+		cornflakes::Writable::write_to(&__subpixels_len__, writer)?;
+		writer.field("subpixels_len", &__subpixels_len__);
+		writer.put_bytes(0, 4);
+		writer.unused();
+		cornflakes::Writable::write_to(__formats__, writer)?;
+		writer.field("formats", __formats__);
+		cornflakes::Writable::write_to(__screens__, writer)?;
+		writer.field("screens", __screens__);
+		cornflakes::Writable::write_to(__subpixels__, writer)?;
+		writer.field("subpixels", __subpixels__);
+		Ok(())
+	}
+}
+impl xrb::Reply<QueryPictFormats> for QueryPictFormatsReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryPictFormatsReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __formats_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __screens_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depths_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __visuals_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __subpixels_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 4;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __formats__ = {
+			let context: usize = {
+				let formats_len: &u32 = &__formats_len__;
+				*formats_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<PictFormInfo as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __screens__ = {
+			let context: usize = {
+				let screens_len: &u32 = &__screens_len__;
+				*screens_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<PictScreen as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __subpixels__ = {
+			let context: usize = {
+				let subpixels_len: &u32 = &__subpixels_len__;
+				*subpixels_len as usize
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(4usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u32 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			_sequence_,
+			formats: __formats__,
+			screens: __screens__,
+			subpixels: __subpixels__,
+		})
+	}
+}
+impl cornflakes::Writable for CreatePicture {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			picture: __picture__,
+			drawable: __drawable__,
+			format: __format__,
+			value_mask: __value_mask__,
+			values: __values__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(4);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__picture__, writer)?;
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__format__, writer)?;
+		cornflakes::Writable::write_to(__value_mask__, writer)?;
+		cornflakes::Writable::write_to(__values__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreatePicture {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __picture__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __value_mask__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __values__ = {
+			let context: usize = {
+				let value_mask: &u32 = &__value_mask__;
+				value_mask.count_ones() as usize
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(4usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u32 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			picture: __picture__,
+			drawable: __drawable__,
+			format: __format__,
+			value_mask: __value_mask__,
+			values: __values__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreatePicture {
+	const NAME: &'static str = "CreatePicture";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			picture: __picture__,
+			drawable: __drawable__,
+			format: __format__,
+			value_mask: __value_mask__,
+			values: __values__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 4;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__picture__, writer)?;
+		writer.field("picture", __picture__);
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__format__, writer)?;
+		writer.field("format", __format__);
+		cornflakes::Writable::write_to(__value_mask__, writer)?;
+		writer.field("value_mask", __value_mask__);
+		cornflakes::Writable::write_to(__values__, writer)?;
+		writer.field("values", __values__);
+		Ok(())
+	}
+}
+impl xrb::Request<()> for CreatePicture {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(4)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreatePicture {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __picture__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __value_mask__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __values__ = {
+			let context: usize = {
+				let value_mask: &u32 = &__value_mask__;
+				value_mask.count_ones() as usize
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(4usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u32 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			picture: __picture__,
+			drawable: __drawable__,
+			format: __format__,
+			value_mask: __value_mask__,
+			values: __values__,
+		})
+	}
+}
+impl cornflakes::Writable for FreePicture {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			picture: __picture__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(7);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__picture__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FreePicture {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __picture__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			picture: __picture__,
+		})
+	}
+}
+impl xrb::PrettyPrint for FreePicture {
+	const NAME: &'static str = "FreePicture";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			picture: __picture__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 7;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__picture__, writer)?;
+		writer.field("picture", __picture__);
+		Ok(())
+	}
+}
+impl FreePicture {
+	#[doc = "Creates a new [`FreePicture`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`FreePicture`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(picture: Picture) -> Self {
+		Self { picture }
+	}
+}
+impl xrb::Request<()> for FreePicture {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(7)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for FreePicture {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __picture__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			picture: __picture__,
+		})
+	}
+}
+impl cornflakes::Writable for Composite {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			op: __op__,
+			source: __source__,
+			mask: __mask__,
+			destination: __destination__,
+			source_x: __source_x__,
+			source_y: __source_y__,
+			mask_x: __mask_x__,
+			mask_y: __mask_y__,
+			destination_x: __destination_x__,
+			destination_y: __destination_y__,
+			width: __width__,
+			height: __height__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(8);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__op__, writer)?;
+		writer.put_bytes(0, 3);
+		cornflakes::Writable::write_to(__source__, writer)?;
+		cornflakes::Writable::write_to(__mask__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		cornflakes::Writable::write_to(__source_x__, writer)?;
+		cornflakes::Writable::write_to(__source_y__, writer)?;
+		cornflakes::Writable::write_to(__mask_x__, writer)?;
+		cornflakes::Writable::write_to(__mask_y__, writer)?;
+		cornflakes::Writable::write_to(__destination_x__, writer)?;
+		cornflakes::Writable::write_to(__destination_y__, writer)?;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		cornflakes::Writable::write_to(__height__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Composite {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __op__ = <PictOp as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __source__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		let __mask__ = <Option<Picture> as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __source_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __source_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __mask_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __mask_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __destination_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __destination_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			op: __op__,
+			source: __source__,
+			mask: __mask__,
+			destination: __destination__,
+			source_x: __source_x__,
+			source_y: __source_y__,
+			mask_x: __mask_x__,
+			mask_y: __mask_y__,
+			destination_x: __destination_x__,
+			destination_y: __destination_y__,
+			width: __width__,
+			height: __height__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Composite {
+	const NAME: &'static str = "Composite";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			op: __op__,
+			source: __source__,
+			mask: __mask__,
+			destination: __destination__,
+			source_x: __source_x__,
+			source_y: __source_y__,
+			mask_x: __mask_x__,
+			mask_y: __mask_y__,
+			destination_x: __destination_x__,
+			destination_y: __destination_y__,
+			width: __width__,
+			height: __height__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 8;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__op__, writer)?;
+		writer.field("op", __op__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		cornflakes::Writable::write_to(__source__, writer)?;
+		writer.field("source", __source__);
+		cornflakes::Writable::write_to(__mask__, writer)?;
+		writer.field("mask", __mask__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		cornflakes::Writable::write_to(__source_x__, writer)?;
+		writer.field("source_x", __source_x__);
+		cornflakes::Writable::write_to(__source_y__, writer)?;
+		writer.field("source_y", __source_y__);
+		cornflakes::Writable::write_to(__mask_x__, writer)?;
+		writer.field("mask_x", __mask_x__);
+		cornflakes::Writable::write_to(__mask_y__, writer)?;
+		writer.field("mask_y", __mask_y__);
+		cornflakes::Writable::write_to(__destination_x__, writer)?;
+		writer.field("destination_x", __destination_x__);
+		cornflakes::Writable::write_to(__destination_y__, writer)?;
+		writer.field("destination_y", __destination_y__);
+		cornflakes::Writable::write_to(__width__, writer)?;
+		writer.field("width", __width__);
+		cornflakes::Writable::write_to(__height__, writer)?;
+		writer.field("height", __height__);
+		Ok(())
+	}
+}
+impl Composite {
+	#[doc = "Creates a new [`Composite`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Composite`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		op: PictOp,
+		source: Picture,
+		mask: Option<Picture>,
+		destination: Picture,
+		source_x: i16,
+		source_y: i16,
+		mask_x: i16,
+		mask_y: i16,
+		destination_x: i16,
+		destination_y: i16,
+		width: u16,
+		height: u16,
+	) -> Self {
+		Self {
+			op,
+			source,
+			mask,
+			destination,
+			source_x,
+			source_y,
+			mask_x,
+			mask_y,
+			destination_x,
+			destination_y,
+			width,
+			height,
+		}
+	}
+}
+impl xrb::Request<()> for Composite {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(8)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Composite {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __op__ = <PictOp as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __source__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		let __mask__ = <Option<Picture> as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __source_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __source_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __mask_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __mask_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __destination_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __destination_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			op: __op__,
+			source: __source__,
+			mask: __mask__,
+			destination: __destination__,
+			source_x: __source_x__,
+			source_y: __source_y__,
+			mask_x: __mask_x__,
+			mask_y: __mask_y__,
+			destination_x: __destination_x__,
+			destination_y: __destination_y__,
+			width: __width__,
+			height: __height__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateGlyphSet {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			glyphset: __glyphset__,
+			format: __format__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(17);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		cornflakes::Writable::write_to(__format__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateGlyphSet {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			glyphset: __glyphset__,
+			format: __format__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateGlyphSet {
+	const NAME: &'static str = "CreateGlyphSet";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			glyphset: __glyphset__,
+			format: __format__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 17;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		writer.field("glyphset", __glyphset__);
+		cornflakes::Writable::write_to(__format__, writer)?;
+		writer.field("format", __format__);
+		Ok(())
+	}
+}
+impl CreateGlyphSet {
+	#[doc = "Creates a new [`CreateGlyphSet`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateGlyphSet`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(glyphset: GlyphSet, format: PictFormat) -> Self {
+		Self { glyphset, format }
+	}
+}
+impl xrb::Request<()> for CreateGlyphSet {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(17)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateGlyphSet {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <PictFormat as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			glyphset: __glyphset__,
+			format: __format__,
+		})
+	}
+}
+impl cornflakes::Writable for FreeGlyphSet {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			glyphset: __glyphset__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(19);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FreeGlyphSet {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			glyphset: __glyphset__,
+		})
+	}
+}
+impl xrb::PrettyPrint for FreeGlyphSet {
+	const NAME: &'static str = "FreeGlyphSet";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			glyphset: __glyphset__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 19;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		writer.field("glyphset", __glyphset__);
+		Ok(())
+	}
+}
+impl FreeGlyphSet {
+	#[doc = "Creates a new [`FreeGlyphSet`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`FreeGlyphSet`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(glyphset: GlyphSet) -> Self {
+		Self { glyphset }
+	}
+}
+impl xrb::Request<()> for FreeGlyphSet {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(19)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for FreeGlyphSet {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			glyphset: __glyphset__,
+		})
+	}
+}
+impl cornflakes::Writable for AddGlyphs {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+			infos: __infos__,
+			images: __images__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(20);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		let __glyphs_len__: u32 = self.glyphs.len() as u32;
+		cornflakes::Writable::write_to(&__glyphs_len__, writer)?;
+		cornflakes::Writable::write_to(__glyphs__, writer)?;
+		cornflakes::Writable::write_to(__infos__, writer)?;
+		cornflakes::Writable::write_to(__images__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for AddGlyphs {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __glyphs_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __glyphs__ = {
+			let context: usize = {
+				let glyphs_len: &u32 = &__glyphs_len__;
+				*glyphs_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Glyph as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __infos__ = {
+			let context: usize = {
+				let glyphs_len: &u32 = &__glyphs_len__;
+				*glyphs_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<GlyphInfo as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __images__ = {
+			let context: usize = {
+				let glyphs_len: &u32 = &__glyphs_len__;
+				(usize::from(_length_) * 4).saturating_sub(12 + *glyphs_len as usize * 16)
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(1usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u8 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+			infos: __infos__,
+			images: __images__,
+		})
+	}
+}
+impl xrb::PrettyPrint for AddGlyphs {
+	const NAME: &'static str = "AddGlyphs";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+			infos: __infos__,
+			images: __images__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 20;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		writer.field("glyphset", __glyphset__);
+		let __glyphs_len__: u32 = self.glyphs.len() as u32;
+		cornflakes::Writable::write_to(&__glyphs_len__, writer)?;
+		writer.field("glyphs_len", &__glyphs_len__);
+		cornflakes::Writable::write_to(__glyphs__, writer)?;
+		writer.field("glyphs", __glyphs__);
+		cornflakes::Writable::write_to(__infos__, writer)?;
+		writer.field("infos", __infos__);
+		cornflakes::Writable::write_to(__images__, writer)?;
+		writer.field("images", __images__);
+		Ok(())
+	}
+}
+impl xrb::Request<()> for AddGlyphs {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(20)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for AddGlyphs {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __glyphs_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __glyphs__ = {
+			let context: usize = {
+				let glyphs_len: &u32 = &__glyphs_len__;
+				*glyphs_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Glyph as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __infos__ = {
+			let context: usize = {
+				let glyphs_len: &u32 = &__glyphs_len__;
+				*glyphs_len as usize
+			};
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<GlyphInfo as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		let __images__ = {
+			let context: usize = {
+				let glyphs_len: &u32 = &__glyphs_len__;
+				(usize::from(_length_) * 4).saturating_sub(12 + *glyphs_len as usize * 16)
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(1usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u8 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+			infos: __infos__,
+			images: __images__,
+		})
+	}
+}
+impl cornflakes::Writable for FreeGlyphs {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(22);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		cornflakes::Writable::write_to(__glyphs__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FreeGlyphs {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		let __glyphs__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(8) / 4;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Glyph as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+		})
+	}
+}
+impl xrb::PrettyPrint for FreeGlyphs {
+	const NAME: &'static str = "FreeGlyphs";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 22;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__glyphset__, writer)?;
+		writer.field("glyphset", __glyphset__);
+		cornflakes::Writable::write_to(__glyphs__, writer)?;
+		writer.field("glyphs", __glyphs__);
+		Ok(())
+	}
+}
+impl xrb::Request<()> for FreeGlyphs {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(22)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for FreeGlyphs {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __glyphset__ = <GlyphSet as cornflakes::Readable>::read_from(reader)?;
+		let __glyphs__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(8) / 4;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Glyph as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			glyphset: __glyphset__,
+			glyphs: __glyphs__,
+		})
+	}
+}
+impl cornflakes::Writable for FillRectangles {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			op: __op__,
+			destination: __destination__,
+			color: __color__,
+			rectangles: __rectangles__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(26);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__op__, writer)?;
+		writer.put_bytes(0, 3);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		cornflakes::Writable::write_to(__color__, writer)?;
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FillRectangles {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __op__ = <PictOp as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __destination__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		let __color__ = <Color as cornflakes::Readable>::read_from(reader)?;
+		let __rectangles__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(20) / 8;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			op: __op__,
+			destination: __destination__,
+			color: __color__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl xrb::PrettyPrint for FillRectangles {
+	const NAME: &'static str = "FillRectangles";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			op: __op__,
+			destination: __destination__,
+			color: __color__,
+			rectangles: __rectangles__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 26;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__op__, writer)?;
+		writer.field("op", __op__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		cornflakes::Writable::write_to(__color__, writer)?;
+		writer.field("color", __color__);
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		writer.field("rectangles", __rectangles__);
+		Ok(())
+	}
+}
+impl xrb::Request<()> for FillRectangles {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(26)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for FillRectangles {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __op__ = <PictOp as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __destination__ = <Picture as cornflakes::Readable>::read_from(reader)?;
+		let __color__ = <Color as cornflakes::Readable>::read_from(reader)?;
+		let __rectangles__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(20) / 8;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			op: __op__,
+			destination: __destination__,
+			color: __color__,
+			rectangles: __rectangles__,
+		})
+	}
+}
@@ -0,0 +1,2437 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::{extensions::Extension, *};
+#[doc = " The name of the MIT-SHM extension, used to query its codes with"]
+#[doc = " `QueryExtension`."]
+pub const NAME: &str = "MIT-SHM";
+#[doc = " The MIT-SHM extension, whose codes are looked up in the [current]"]
+#[doc = " [`Extensions`] when its messages are written or read."]
+#[doc = ""]
+#[doc = " [current]: super::Extensions::current"]
+#[doc = " [`Extensions`]: super::Extensions"]
+pub const EXTENSION: Extension = Extension::new(NAME);
+#[doc = " A shared memory segment which has been attached by the X server."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Seg {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Seg {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Seg {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+impl cornflakes::Writable for Seg {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Seg {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { id: __id__ })
+	}
+}
+impl xrb::PrettyPrint for Seg {
+	const NAME: &'static str = "Seg";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		writer.field("id", __id__);
+		Ok(())
+	}
+}
+impl Seg {
+	#[doc = " Creates a new [`Seg`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Seg`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl ResId for Seg {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+#[doc = " Queries the version of the MIT-SHM extension supported by the X server."]
+pub struct QueryVersion;
+pub struct QueryVersionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " Whether pixmaps can be created with their contents in shared"]
+	#[doc = " memory."]
+	pub shared_pixmaps: bool,
+	pub major_version: u16,
+	pub minor_version: u16,
+	#[doc = " The user ID of the X server process."]
+	pub uid: u16,
+	#[doc = " The group ID of the X server process."]
+	pub gid: u16,
+	#[doc = " The format of shared memory pixmaps, if `shared_pixmaps` is `true`."]
+	pub pixmap_format: Format,
+}
+#[doc = " Attaches the System V shared memory segment `shmid` to the X server as"]
+#[doc = " the given `segment`."]
+pub struct Attach {
+	pub segment: Seg,
+	#[doc = " The System V shared memory identifier of the segment."]
+	pub shmid: u32,
+	#[doc = " Whether the X server should attach the segment as read-only."]
+	pub read_only: bool,
+}
+#[doc = " Detaches the given `segment` from the X server."]
+#[doc = ""]
+#[doc = " The segment is only actually detached once any pixmaps which use it"]
+#[doc = " have been destroyed."]
+pub struct Detach {
+	pub segment: Seg,
+}
+#[doc = " Draws an image read from a shared memory `segment` to a drawable."]
+pub struct PutImage {
+	#[doc = " The [`Window`] or [`Pixmap`] to draw to."]
+	pub drawable: Drawable,
+	pub context: GraphicsContext,
+	#[doc = " The width of the whole image in the shared memory segment."]
+	pub total_width: u16,
+	#[doc = " The height of the whole image in the shared memory segment."]
+	pub total_height: u16,
+	pub src_x: u16,
+	pub src_y: u16,
+	pub src_width: u16,
+	pub src_height: u16,
+	pub dst_x: i16,
+	pub dst_y: i16,
+	pub depth: u8,
+	pub format: BitmapFormat,
+	#[doc = " Whether a [`ShmCompletion`] event should be sent once the X server"]
+	#[doc = " has finished reading from the shared memory segment."]
+	pub send_event: bool,
+	pub segment: Seg,
+	#[doc = " The offset of the image within the shared memory segment."]
+	pub offset: u32,
+}
+#[doc = " Writes the contents of a rectangle of a drawable to a shared memory"]
+#[doc = " `segment`."]
+pub struct GetImage {
+	#[doc = " The [`Window`] or [`Pixmap`] to read from."]
+	pub drawable: Drawable,
+	pub x: i16,
+	pub y: i16,
+	pub width: u16,
+	pub height: u16,
+	pub plane_mask: u32,
+	pub format: Format,
+	pub segment: Seg,
+	#[doc = " The offset within the shared memory segment at which to write the"]
+	#[doc = " image."]
+	pub offset: u32,
+}
+pub struct GetImageReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub depth: u8,
+	pub visual: Option<VisualId>,
+	#[doc = " The number of bytes written to the shared memory segment."]
+	pub size: u32,
+}
+#[doc = " Has the X server create a new shared memory segment and attach it as"]
+#[doc = " the given `segment`."]
+#[doc = ""]
+#[doc = " The segment's file descriptor is sent with the reply as ancillary data"]
+#[doc = " over the connection's Unix domain socket; it is not part of the reply"]
+#[doc = " itself."]
+pub struct CreateSegment {
+	pub segment: Seg,
+	#[doc = " The size of the segment in bytes."]
+	pub size: u32,
+	#[doc = " Whether the X server should attach the segment as read-only."]
+	pub read_only: bool,
+}
+pub struct CreateSegmentReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+}
+#[doc = " Sent once the X server has finished with a shared memory segment used"]
+#[doc = " in a [`PutImage`] request with `send_event` set."]
+pub struct ShmCompletion {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The [`Window`] or [`Pixmap`] which was drawn to."]
+	pub drawable: Drawable,
+	#[doc = " The minor opcode of the request which generated this event."]
+	pub minor_event: u16,
+	#[doc = " The major opcode of the request which generated this event."]
+	pub major_event: u8,
+	pub segment: Seg,
+	pub offset: u32,
+}
+impl cornflakes::Writable for QueryVersion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self = self;
+		writer.put_u8(<Self as xrb::Request<QueryVersionReply>>::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(<Self as xrb::Request<QueryVersionReply>>::length(self));
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		Ok(Self)
+	}
+}
+impl xrb::PrettyPrint for QueryVersion {
+	const NAME: &'static str = "QueryVersion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self = self;
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 0;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		Ok(())
+	}
+}
+impl xrb::Request<QueryVersionReply> for QueryVersion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(0)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		Ok(Self)
+	}
+}
+impl cornflakes::Writable for QueryVersionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			shared_pixmaps: __shared_pixmaps__,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+			uid: __uid__,
+			gid: __gid__,
+			pixmap_format: __pixmap_format__,
+		} = self;
+		writer.put_u8(1);
+		cornflakes::Writable::write_to(__shared_pixmaps__, writer)?;
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<QueryVersion>>::length(self));
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		cornflakes::Writable::write_to(__uid__, writer)?;
+		cornflakes::Writable::write_to(__gid__, writer)?;
+		cornflakes::Writable::write_to(__pixmap_format__, writer)?;
+		writer.put_bytes(0, 15);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __shared_pixmaps__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __uid__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __gid__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		let __pixmap_format__ = <Format as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			shared_pixmaps: __shared_pixmaps__,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+			uid: __uid__,
+			gid: __gid__,
+			pixmap_format: __pixmap_format__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersionReply {
+	const NAME: &'static str = "QueryVersionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			shared_pixmaps: __shared_pixmaps__,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+			uid: __uid__,
+			gid: __gid__,
+			pixmap_format: __pixmap_format__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		cornflakes::Writable::write_to(__shared_pixmaps__, writer)?;
+		writer.field("shared_pixmaps", __shared_pixmaps__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<QueryVersion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		writer.field("major_version", __major_version__);
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.field("minor_version", __minor_version__);
+		cornflakes::Writable::write_to(__uid__, writer)?;
+		writer.field("uid", __uid__);
+		cornflakes::Writable::write_to(__gid__, writer)?;
+		writer.field("gid", __gid__);
+		cornflakes::Writable::write_to(__pixmap_format__, writer)?;
+		writer.field("pixmap_format", __pixmap_format__);
+		writer.put_bytes(0, 15);
+		writer.unused();
+		Ok(())
+	}
+}
+impl QueryVersionReply {
+	#[doc = "Creates a new [`QueryVersionReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersionReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		shared_pixmaps: bool,
+		major_version: u16,
+		minor_version: u16,
+		uid: u16,
+		gid: u16,
+		pixmap_format: Format,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			shared_pixmaps,
+			major_version,
+			minor_version,
+			uid,
+			gid,
+			pixmap_format,
+		}
+	}
+}
+impl xrb::Reply<QueryVersion> for QueryVersionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __shared_pixmaps__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __uid__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __gid__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		let __pixmap_format__ = <Format as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 15;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			shared_pixmaps: __shared_pixmaps__,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+			uid: __uid__,
+			gid: __gid__,
+			pixmap_format: __pixmap_format__,
+		})
+	}
+}
+impl cornflakes::Writable for Attach {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			segment: __segment__,
+			shmid: __shmid__,
+			read_only: __read_only__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(1);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		cornflakes::Writable::write_to(__shmid__, writer)?;
+		cornflakes::Writable::write_to(__read_only__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Attach {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __shmid__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __read_only__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			segment: __segment__,
+			shmid: __shmid__,
+			read_only: __read_only__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Attach {
+	const NAME: &'static str = "Attach";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			segment: __segment__,
+			shmid: __shmid__,
+			read_only: __read_only__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 1;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		writer.field("segment", __segment__);
+		cornflakes::Writable::write_to(__shmid__, writer)?;
+		writer.field("shmid", __shmid__);
+		cornflakes::Writable::write_to(__read_only__, writer)?;
+		writer.field("read_only", __read_only__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl Attach {
+	#[doc = "Creates a new [`Attach`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Attach`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(segment: Seg, shmid: u32, read_only: bool) -> Self {
+		Self {
+			segment,
+			shmid,
+			read_only,
+		}
+	}
+}
+impl xrb::Request<()> for Attach {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(1)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Attach {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __shmid__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __read_only__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			segment: __segment__,
+			shmid: __shmid__,
+			read_only: __read_only__,
+		})
+	}
+}
+impl cornflakes::Writable for Detach {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			segment: __segment__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(2);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Detach {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			segment: __segment__,
+		})
+	}
+}
+impl xrb::PrettyPrint for Detach {
+	const NAME: &'static str = "Detach";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			segment: __segment__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 2;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		writer.field("segment", __segment__);
+		Ok(())
+	}
+}
+impl Detach {
+	#[doc = "Creates a new [`Detach`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`Detach`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(segment: Seg) -> Self {
+		Self { segment }
+	}
+}
+impl xrb::Request<()> for Detach {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(2)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for Detach {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			segment: __segment__,
+		})
+	}
+}
+impl cornflakes::Writable for PutImage {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			drawable: __drawable__,
+			context: __context__,
+			total_width: __total_width__,
+			total_height: __total_height__,
+			src_x: __src_x__,
+			src_y: __src_y__,
+			src_width: __src_width__,
+			src_height: __src_height__,
+			dst_x: __dst_x__,
+			dst_y: __dst_y__,
+			depth: __depth__,
+			format: __format__,
+			send_event: __send_event__,
+			segment: __segment__,
+			offset: __offset__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(3);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__context__, writer)?;
+		cornflakes::Writable::write_to(__total_width__, writer)?;
+		cornflakes::Writable::write_to(__total_height__, writer)?;
+		cornflakes::Writable::write_to(__src_x__, writer)?;
+		cornflakes::Writable::write_to(__src_y__, writer)?;
+		cornflakes::Writable::write_to(__src_width__, writer)?;
+		cornflakes::Writable::write_to(__src_height__, writer)?;
+		cornflakes::Writable::write_to(__dst_x__, writer)?;
+		cornflakes::Writable::write_to(__dst_y__, writer)?;
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		cornflakes::Writable::write_to(__format__, writer)?;
+		cornflakes::Writable::write_to(__send_event__, writer)?;
+		writer.put_bytes(0, 1);
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		cornflakes::Writable::write_to(__offset__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for PutImage {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __context__ = <GraphicsContext as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __total_width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __total_height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_x__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_y__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dst_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dst_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depth__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <BitmapFormat as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __send_event__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 1;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __offset__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			drawable: __drawable__,
+			context: __context__,
+			total_width: __total_width__,
+			total_height: __total_height__,
+			src_x: __src_x__,
+			src_y: __src_y__,
+			src_width: __src_width__,
+			src_height: __src_height__,
+			dst_x: __dst_x__,
+			dst_y: __dst_y__,
+			depth: __depth__,
+			format: __format__,
+			send_event: __send_event__,
+			segment: __segment__,
+			offset: __offset__,
+		})
+	}
+}
+impl xrb::PrettyPrint for PutImage {
+	const NAME: &'static str = "PutImage";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			drawable: __drawable__,
+			context: __context__,
+			total_width: __total_width__,
+			total_height: __total_height__,
+			src_x: __src_x__,
+			src_y: __src_y__,
+			src_width: __src_width__,
+			src_height: __src_height__,
+			dst_x: __dst_x__,
+			dst_y: __dst_y__,
+			depth: __depth__,
+			format: __format__,
+			send_event: __send_event__,
+			segment: __segment__,
+			offset: __offset__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 3;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__context__, writer)?;
+		writer.field("context", __context__);
+		cornflakes::Writable::write_to(__total_width__, writer)?;
+		writer.field("total_width", __total_width__);
+		cornflakes::Writable::write_to(__total_height__, writer)?;
+		writer.field("total_height", __total_height__);
+		cornflakes::Writable::write_to(__src_x__, writer)?;
+		writer.field("src_x", __src_x__);
+		cornflakes::Writable::write_to(__src_y__, writer)?;
+		writer.field("src_y", __src_y__);
+		cornflakes::Writable::write_to(__src_width__, writer)?;
+		writer.field("src_width", __src_width__);
+		cornflakes::Writable::write_to(__src_height__, writer)?;
+		writer.field("src_height", __src_height__);
+		cornflakes::Writable::write_to(__dst_x__, writer)?;
+		writer.field("dst_x", __dst_x__);
+		cornflakes::Writable::write_to(__dst_y__, writer)?;
+		writer.field("dst_y", __dst_y__);
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.field("depth", __depth__);
+		cornflakes::Writable::write_to(__format__, writer)?;
+		writer.field("format", __format__);
+		cornflakes::Writable::write_to(__send_event__, writer)?;
+		writer.field("send_event", __send_event__);
+		writer.put_bytes(0, 1);
+		writer.unused();
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		writer.field("segment", __segment__);
+		cornflakes::Writable::write_to(__offset__, writer)?;
+		writer.field("offset", __offset__);
+		Ok(())
+	}
+}
+impl PutImage {
+	#[doc = "Creates a new [`PutImage`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`PutImage`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		drawable: Drawable,
+		context: GraphicsContext,
+		total_width: u16,
+		total_height: u16,
+		src_x: u16,
+		src_y: u16,
+		src_width: u16,
+		src_height: u16,
+		dst_x: i16,
+		dst_y: i16,
+		depth: u8,
+		format: BitmapFormat,
+		send_event: bool,
+		segment: Seg,
+		offset: u32,
+	) -> Self {
+		Self {
+			drawable,
+			context,
+			total_width,
+			total_height,
+			src_x,
+			src_y,
+			src_width,
+			src_height,
+			dst_x,
+			dst_y,
+			depth,
+			format,
+			send_event,
+			segment,
+			offset,
+		}
+	}
+}
+impl xrb::Request<()> for PutImage {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(3)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for PutImage {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		let __context__ = <GraphicsContext as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __total_width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __total_height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_x__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_y__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __src_height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dst_x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dst_y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depth__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <BitmapFormat as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __send_event__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 1;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __offset__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			drawable: __drawable__,
+			context: __context__,
+			total_width: __total_width__,
+			total_height: __total_height__,
+			src_x: __src_x__,
+			src_y: __src_y__,
+			src_width: __src_width__,
+			src_height: __src_height__,
+			dst_x: __dst_x__,
+			dst_y: __dst_y__,
+			depth: __depth__,
+			format: __format__,
+			send_event: __send_event__,
+			segment: __segment__,
+			offset: __offset__,
+		})
+	}
+}
+impl cornflakes::Writable for GetImage {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			drawable: __drawable__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			plane_mask: __plane_mask__,
+			format: __format__,
+			segment: __segment__,
+			offset: __offset__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<GetImageReply>>::major_opcode());
+		writer.put_u8(4);
+		writer.put_u16(<Self as xrb::Request<GetImageReply>>::length(self));
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__x__, writer)?;
+		cornflakes::Writable::write_to(__y__, writer)?;
+		cornflakes::Writable::write_to(__width__, writer)?;
+		cornflakes::Writable::write_to(__height__, writer)?;
+		cornflakes::Writable::write_to(__plane_mask__, writer)?;
+		cornflakes::Writable::write_to(__format__, writer)?;
+		writer.put_bytes(0, 3);
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		cornflakes::Writable::write_to(__offset__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetImage {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __plane_mask__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <Format as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __offset__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			drawable: __drawable__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			plane_mask: __plane_mask__,
+			format: __format__,
+			segment: __segment__,
+			offset: __offset__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetImage {
+	const NAME: &'static str = "GetImage";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			drawable: __drawable__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			plane_mask: __plane_mask__,
+			format: __format__,
+			segment: __segment__,
+			offset: __offset__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<GetImageReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 4;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<GetImageReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__x__, writer)?;
+		writer.field("x", __x__);
+		cornflakes::Writable::write_to(__y__, writer)?;
+		writer.field("y", __y__);
+		cornflakes::Writable::write_to(__width__, writer)?;
+		writer.field("width", __width__);
+		cornflakes::Writable::write_to(__height__, writer)?;
+		writer.field("height", __height__);
+		cornflakes::Writable::write_to(__plane_mask__, writer)?;
+		writer.field("plane_mask", __plane_mask__);
+		cornflakes::Writable::write_to(__format__, writer)?;
+		writer.field("format", __format__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		writer.field("segment", __segment__);
+		cornflakes::Writable::write_to(__offset__, writer)?;
+		writer.field("offset", __offset__);
+		Ok(())
+	}
+}
+impl GetImage {
+	#[doc = "Creates a new [`GetImage`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetImage`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		drawable: Drawable,
+		x: i16,
+		y: i16,
+		width: u16,
+		height: u16,
+		plane_mask: u32,
+		format: Format,
+		segment: Seg,
+		offset: u32,
+	) -> Self {
+		Self {
+			drawable,
+			x,
+			y,
+			width,
+			height,
+			plane_mask,
+			format,
+			segment,
+			offset,
+		}
+	}
+}
+impl xrb::Request<GetImageReply> for GetImage {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(4)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetImage {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __width__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __height__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __plane_mask__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		let __format__ = <Format as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __offset__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			drawable: __drawable__,
+			x: __x__,
+			y: __y__,
+			width: __width__,
+			height: __height__,
+			plane_mask: __plane_mask__,
+			format: __format__,
+			segment: __segment__,
+			offset: __offset__,
+		})
+	}
+}
+impl cornflakes::Writable for GetImageReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			depth: __depth__,
+			visual: __visual__,
+			size: __size__,
+		} = self;
+		writer.put_u8(1);
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<GetImage>>::length(self));
+		cornflakes::Writable::write_to(__visual__, writer)?;
+		cornflakes::Writable::write_to(__size__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetImageReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depth__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		let __visual__ = <Option<VisualId> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __size__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			depth: __depth__,
+			visual: __visual__,
+			size: __size__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetImageReply {
+	const NAME: &'static str = "GetImageReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			depth: __depth__,
+			visual: __visual__,
+			size: __size__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		cornflakes::Writable::write_to(__depth__, writer)?;
+		writer.field("depth", __depth__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<GetImage>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__visual__, writer)?;
+		writer.field("visual", __visual__);
+		cornflakes::Writable::write_to(__size__, writer)?;
+		writer.field("size", __size__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl GetImageReply {
+	#[doc = "Creates a new [`GetImageReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetImageReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, depth: u8, visual: Option<VisualId>, size: u32) -> Self {
+		Self {
+			_sequence_: sequence,
+			depth,
+			visual,
+			size,
+		}
+	}
+}
+impl xrb::Reply<GetImage> for GetImageReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetImageReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __depth__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		let __visual__ = <Option<VisualId> as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __size__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			depth: __depth__,
+			visual: __visual__,
+			size: __size__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateSegment {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			segment: __segment__,
+			size: __size__,
+			read_only: __read_only__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<CreateSegmentReply>>::major_opcode());
+		writer.put_u8(7);
+		writer.put_u16(<Self as xrb::Request<CreateSegmentReply>>::length(self));
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		cornflakes::Writable::write_to(__size__, writer)?;
+		cornflakes::Writable::write_to(__read_only__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateSegment {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __size__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __read_only__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			segment: __segment__,
+			size: __size__,
+			read_only: __read_only__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateSegment {
+	const NAME: &'static str = "CreateSegment";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			segment: __segment__,
+			size: __size__,
+			read_only: __read_only__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<CreateSegmentReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 7;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<CreateSegmentReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		writer.field("segment", __segment__);
+		cornflakes::Writable::write_to(__size__, writer)?;
+		writer.field("size", __size__);
+		cornflakes::Writable::write_to(__read_only__, writer)?;
+		writer.field("read_only", __read_only__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl CreateSegment {
+	#[doc = "Creates a new [`CreateSegment`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateSegment`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(segment: Seg, size: u32, read_only: bool) -> Self {
+		Self {
+			segment,
+			size,
+			read_only,
+		}
+	}
+}
+impl xrb::Request<CreateSegmentReply> for CreateSegment {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(7)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateSegment {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __size__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __read_only__ = <bool as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			segment: __segment__,
+			size: __size__,
+			read_only: __read_only__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateSegmentReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { _sequence_ } = self;
+		writer.put_u8(1);
+		let __fd_count__: u8 = 1;
+		cornflakes::Writable::write_to(&__fd_count__, writer)?;
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<CreateSegment>>::length(self));
+		writer.put_bytes(0, 24);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateSegmentReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __fd_count__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		{
+			let len: usize = 24;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self { _sequence_ })
+	}
+}
+impl xrb::PrettyPrint for CreateSegmentReply {
+	const NAME: &'static str = "CreateSegmentReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { _sequence_ } = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		let __fd_count__: u8 = 1;
+		cornflakes::Writable::write_to(&__fd_count__, writer)?;
+		writer.field("fd_count", &__fd_count__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<CreateSegment>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		writer.put_bytes(0, 24);
+		writer.unused();
+		Ok(())
+	}
+}
+impl CreateSegmentReply {
+	#[doc = "Creates a new [`CreateSegmentReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateSegmentReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16) -> Self {
+		Self {
+			_sequence_: sequence,
+		}
+	}
+}
+impl xrb::Reply<CreateSegment> for CreateSegmentReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateSegmentReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __fd_count__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		{
+			let len: usize = 24;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self { _sequence_ })
+	}
+}
+impl cornflakes::Writable for ShmCompletion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			drawable: __drawable__,
+			minor_event: __minor_event__,
+			major_event: __major_event__,
+			segment: __segment__,
+			offset: __offset__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		cornflakes::Writable::write_to(__minor_event__, writer)?;
+		cornflakes::Writable::write_to(__major_event__, writer)?;
+		writer.put_bytes(0, 1);
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		cornflakes::Writable::write_to(__offset__, writer)?;
+		writer.put_bytes(0, 12);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ShmCompletion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_event__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_event__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 1;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __offset__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 12;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			drawable: __drawable__,
+			minor_event: __minor_event__,
+			major_event: __major_event__,
+			segment: __segment__,
+			offset: __offset__,
+		})
+	}
+}
+impl xrb::PrettyPrint for ShmCompletion {
+	const NAME: &'static str = "ShmCompletion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			drawable: __drawable__,
+			minor_event: __minor_event__,
+			major_event: __major_event__,
+			segment: __segment__,
+			offset: __offset__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__drawable__, writer)?;
+		writer.field("drawable", __drawable__);
+		cornflakes::Writable::write_to(__minor_event__, writer)?;
+		writer.field("minor_event", __minor_event__);
+		cornflakes::Writable::write_to(__major_event__, writer)?;
+		writer.field("major_event", __major_event__);
+		writer.put_bytes(0, 1);
+		writer.unused();
+		cornflakes::Writable::write_to(__segment__, writer)?;
+		writer.field("segment", __segment__);
+		cornflakes::Writable::write_to(__offset__, writer)?;
+		writer.field("offset", __offset__);
+		writer.put_bytes(0, 12);
+		writer.unused();
+		Ok(())
+	}
+}
+impl ShmCompletion {
+	#[doc = "Creates a new [`ShmCompletion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`ShmCompletion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		drawable: Drawable,
+		minor_event: u16,
+		major_event: u8,
+		segment: Seg,
+		offset: u32,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			drawable,
+			minor_event,
+			major_event,
+			segment,
+			offset,
+		}
+	}
+}
+impl xrb::Event for ShmCompletion {
+	fn code() -> u8 {
+		EXTENSION.first_event()
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for ShmCompletion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __drawable__ = <Drawable as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_event__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_event__ = <u8 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 1;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __segment__ = <Seg as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __offset__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 12;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			drawable: __drawable__,
+			minor_event: __minor_event__,
+			major_event: __major_event__,
+			segment: __segment__,
+			offset: __offset__,
+		})
+	}
+}
@@ -0,0 +1,1032 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::extensions::Extension;
+#[doc = " The name of the XC-MISC extension, used to query its codes with"]
+#[doc = " `QueryExtension`."]
+pub const NAME: &str = "XC-MISC";
+#[doc = " The XC-MISC extension, whose codes are looked up in the [current]"]
+#[doc = " [`Extensions`] when its messages are written or read."]
+#[doc = ""]
+#[doc = " [current]: super::Extensions::current"]
+#[doc = " [`Extensions`]: super::Extensions"]
+pub const EXTENSION: Extension = Extension::new(NAME);
+#[doc = " Informs the X server of the version of the XC-MISC extension supported"]
+#[doc = " by the client, and queries the version supported by the X server."]
+pub struct GetVersion {
+	pub client_major_version: u16,
+	pub client_minor_version: u16,
+}
+pub struct GetVersionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub major_version: u16,
+	pub minor_version: u16,
+}
+#[doc = " Queries a range of resource IDs which are not in use by this client."]
+#[doc = ""]
+#[doc = " This is used once all of the resource IDs given in the connection"]
+#[doc = " setup have been allocated; see [`XidAllocator`]."]
+#[doc = ""]
+#[doc = " [`XidAllocator`]: crate::x11::xid::XidAllocator"]
+pub struct GetXidRange;
+pub struct GetXidRangeReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The first resource ID in the range."]
+	pub start_id: u32,
+	#[doc = " The number of resource IDs in the range."]
+	#[doc = ""]
+	#[doc = " If this is `0`, there are no resource IDs left."]
+	pub count: u32,
+}
+#[doc = " Queries up to `count` individual resource IDs which are not in use by"]
+#[doc = " this client."]
+pub struct GetXidList {
+	pub count: u32,
+}
+pub struct GetXidListReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub ids: Vec<u32>,
+}
+impl cornflakes::Writable for GetVersion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<GetVersionReply>>::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(<Self as xrb::Request<GetVersionReply>>::length(self));
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetVersion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetVersion {
+	const NAME: &'static str = "GetVersion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<GetVersionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 0;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<GetVersionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		writer.field("client_major_version", __client_major_version__);
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		writer.field("client_minor_version", __client_minor_version__);
+		Ok(())
+	}
+}
+impl GetVersion {
+	#[doc = "Creates a new [`GetVersion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetVersion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(client_major_version: u16, client_minor_version: u16) -> Self {
+		Self {
+			client_major_version,
+			client_minor_version,
+		}
+	}
+}
+impl xrb::Request<GetVersionReply> for GetVersion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(0)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetVersion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for GetVersionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<GetVersion>>::length(self));
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.put_bytes(0, 20);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetVersionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetVersionReply {
+	const NAME: &'static str = "GetVersionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<GetVersion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		writer.field("major_version", __major_version__);
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.field("minor_version", __minor_version__);
+		writer.put_bytes(0, 20);
+		writer.unused();
+		Ok(())
+	}
+}
+impl GetVersionReply {
+	#[doc = "Creates a new [`GetVersionReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetVersionReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, major_version: u16, minor_version: u16) -> Self {
+		Self {
+			_sequence_: sequence,
+			major_version,
+			minor_version,
+		}
+	}
+}
+impl xrb::Reply<GetVersion> for GetVersionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetVersionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u16 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for GetXidRange {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self = self;
+		writer.put_u8(<Self as xrb::Request<GetXidRangeReply>>::major_opcode());
+		writer.put_u8(1);
+		writer.put_u16(<Self as xrb::Request<GetXidRangeReply>>::length(self));
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetXidRange {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		Ok(Self)
+	}
+}
+impl xrb::PrettyPrint for GetXidRange {
+	const NAME: &'static str = "GetXidRange";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self = self;
+		{
+			let value = <Self as xrb::Request<GetXidRangeReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 1;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<GetXidRangeReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		Ok(())
+	}
+}
+impl xrb::Request<GetXidRangeReply> for GetXidRange {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(1)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetXidRange {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		Ok(Self)
+	}
+}
+impl cornflakes::Writable for GetXidRangeReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			start_id: __start_id__,
+			count: __count__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<GetXidRange>>::length(self));
+		cornflakes::Writable::write_to(__start_id__, writer)?;
+		cornflakes::Writable::write_to(__count__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetXidRangeReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __start_id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __count__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			start_id: __start_id__,
+			count: __count__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetXidRangeReply {
+	const NAME: &'static str = "GetXidRangeReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			start_id: __start_id__,
+			count: __count__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<GetXidRange>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__start_id__, writer)?;
+		writer.field("start_id", __start_id__);
+		cornflakes::Writable::write_to(__count__, writer)?;
+		writer.field("count", __count__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl GetXidRangeReply {
+	#[doc = "Creates a new [`GetXidRangeReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetXidRangeReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, start_id: u32, count: u32) -> Self {
+		Self {
+			_sequence_: sequence,
+			start_id,
+			count,
+		}
+	}
+}
+impl xrb::Reply<GetXidRange> for GetXidRangeReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetXidRangeReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __start_id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __count__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			start_id: __start_id__,
+			count: __count__,
+		})
+	}
+}
+impl cornflakes::Writable for GetXidList {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { count: __count__ } = self;
+		writer.put_u8(<Self as xrb::Request<GetXidListReply>>::major_opcode());
+		writer.put_u8(2);
+		writer.put_u16(<Self as xrb::Request<GetXidListReply>>::length(self));
+		cornflakes::Writable::write_to(__count__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetXidList {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __count__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { count: __count__ })
+	}
+}
+impl xrb::PrettyPrint for GetXidList {
+	const NAME: &'static str = "GetXidList";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { count: __count__ } = self;
+		{
+			let value = <Self as xrb::Request<GetXidListReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 2;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<GetXidListReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__count__, writer)?;
+		writer.field("count", __count__);
+		Ok(())
+	}
+}
+impl GetXidList {
+	#[doc = "Creates a new [`GetXidList`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`GetXidList`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(count: u32) -> Self {
+		Self { count }
+	}
+}
+impl xrb::Request<GetXidListReply> for GetXidList {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(2)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetXidList {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __count__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { count: __count__ })
+	}
+}
+impl cornflakes::Writable for GetXidListReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			ids: __ids__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<GetXidList>>::length(self));
+		let __ids_len__: u32 = self.ids.len() as u32;
+		cornflakes::Writable::write_to(&__ids_len__, writer)?;
+		writer.put_bytes(0, 20);
+		cornflakes::Writable::write_to(__ids__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for GetXidListReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __ids_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __ids__ = {
+			let context: usize = {
+				let ids_len: &u32 = &__ids_len__;
+				*ids_len as usize
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(4usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u32 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			_sequence_,
+			ids: __ids__,
+		})
+	}
+}
+impl xrb::PrettyPrint for GetXidListReply {
+	const NAME: &'static str = "GetXidListReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			ids: __ids__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<GetXidList>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		let __ids_len__: u32 = self.ids.len() as u32;
+		cornflakes::Writable::write_to(&__ids_len__, writer)?;
+		writer.field("ids_len", &__ids_len__);
+		writer.put_bytes(0, 20);
+		writer.unused();
+		cornflakes::Writable::write_to(__ids__, writer)?;
+		writer.field("ids", __ids__);
+		Ok(())
+	}
+}
+impl xrb::Reply<GetXidList> for GetXidListReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for GetXidListReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __ids_len__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 20;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __ids__ = {
+			let context: usize = {
+				let ids_len: &u32 = &__ids_len__;
+				*ids_len as usize
+			};
+			let mut list = Vec::with_capacity({
+				let len = context.saturating_mul(4usize);
+				if reader.remaining() < len {
+					return Err(cornflakes::ReadError::Other(Box::new(
+						xrb::UnexpectedEndOfData {
+							expected: len,
+							found: reader.remaining(),
+						},
+					)));
+				}
+				context
+			});
+			for _ in 0..context {
+				list.push(<u32 as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			_sequence_,
+			ids: __ids__,
+		})
+	}
+}
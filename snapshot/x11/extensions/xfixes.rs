@@ -0,0 +1,3067 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+use crate::x11::{extensions::Extension, *};
+#[doc = " The name of the XFIXES extension, used to query its codes with"]
+#[doc = " `QueryExtension`."]
+pub const NAME: &str = "XFIXES";
+#[doc = " The XFIXES extension, whose codes are looked up in the [current]"]
+#[doc = " [`Extensions`] when its messages are written or read."]
+#[doc = ""]
+#[doc = " [current]: super::Extensions::current"]
+#[doc = " [`Extensions`]: super::Extensions"]
+pub const EXTENSION: Extension = Extension::new(NAME);
+#[doc = " A set of rectangles stored by the X server."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Region {
+	id: u32,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for Region {
+	fn static_byte_size() -> usize {
+		<u32 as cornflakes::StaticByteSize>::static_byte_size()
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for Region {
+	fn byte_size(&self) -> usize {
+		let Self { id: __id__ } = self;
+		cornflakes::ByteSize::byte_size(__id__)
+	}
+}
+#[doc = " Which of a window's shapes a [`Region`] is taken from or applied to."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ShapeKind {
+	#[doc = " The window's bounding shape, including its border."]
+	Bounding,
+	#[doc = " The window's clip shape, excluding its border."]
+	Clip,
+	#[doc = " The shape of the window which receives input."]
+	Input,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for ShapeKind {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for ShapeKind {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+#[doc = " The change to a selection's owner which a [`SelectionNotify`] event"]
+#[doc = " was sent for."]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SelectionEvent {
+	#[doc = " The selection's owner was set with a `SetSelectionOwner` request."]
+	SetSelectionOwner,
+	#[doc = " The window which owned the selection was destroyed."]
+	SelectionWindowDestroy,
+	#[doc = " The client which owned the selection closed its connection."]
+	SelectionClientClose,
+}
+#[automatically_derived]
+impl cornflakes::StaticByteSize for SelectionEvent {
+	fn static_byte_size() -> usize {
+		1
+	}
+}
+#[automatically_derived]
+impl cornflakes::ByteSize for SelectionEvent {
+	fn byte_size(&self) -> usize {
+		1
+	}
+}
+impl cornflakes::Writable for Region {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for Region {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __id__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { id: __id__ })
+	}
+}
+impl xrb::PrettyPrint for Region {
+	const NAME: &'static str = "Region";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { id: __id__ } = self;
+		cornflakes::Writable::write_to(__id__, writer)?;
+		writer.field("id", __id__);
+		Ok(())
+	}
+}
+impl cornflakes::Writable for ShapeKind {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		match self {
+			Self::Bounding => {
+				writer.put_u8(0);
+			}
+			Self::Clip => {
+				writer.put_u8(1);
+			}
+			Self::Input => {
+				writer.put_u8(2);
+			}
+		}
+		Ok(())
+	}
+}
+impl cornflakes::Readable for ShapeKind {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		match <u8 as cornflakes::Readable>::read_from(reader)? {
+			0 => Ok(Self::Bounding),
+			1 => Ok(Self::Clip),
+			2 => Ok(Self::Input),
+			other_discrim => Err(cornflakes::ReadError::UnrecognizedDiscriminant(
+				other_discrim,
+			)),
+		}
+	}
+}
+impl xrb::WireEnum for ShapeKind {
+	const WIDTH: usize = 1;
+	fn raw(&self) -> u32 {
+		match self {
+			Self::Bounding => 0,
+			Self::Clip => 1,
+			Self::Input => 2,
+		}
+	}
+}
+impl cornflakes::Writable for SelectionEvent {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		match self {
+			Self::SetSelectionOwner => {
+				writer.put_u8(0);
+			}
+			Self::SelectionWindowDestroy => {
+				writer.put_u8(1);
+			}
+			Self::SelectionClientClose => {
+				writer.put_u8(2);
+			}
+		}
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SelectionEvent {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		match <u8 as cornflakes::Readable>::read_from(reader)? {
+			0 => Ok(Self::SetSelectionOwner),
+			1 => Ok(Self::SelectionWindowDestroy),
+			2 => Ok(Self::SelectionClientClose),
+			other_discrim => Err(cornflakes::ReadError::UnrecognizedDiscriminant(
+				other_discrim,
+			)),
+		}
+	}
+}
+impl xrb::WireEnum for SelectionEvent {
+	const WIDTH: usize = 1;
+	fn raw(&self) -> u32 {
+		match self {
+			Self::SetSelectionOwner => 0,
+			Self::SelectionWindowDestroy => 1,
+			Self::SelectionClientClose => 2,
+		}
+	}
+}
+impl Region {
+	#[doc = " Creates a new [`Region`] with the given `id`."]
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+	#[doc = " Creates a new [`Region`] with an ID of `0`."]
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+impl ResId for Region {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+#[doc = " Informs the X server of the version of the XFIXES extension supported"]
+#[doc = " by the client, and queries the version supported by the X server."]
+#[doc = ""]
+#[doc = " This must be sent before any other XFIXES requests."]
+pub struct QueryVersion {
+	pub client_major_version: u32,
+	pub client_minor_version: u32,
+}
+pub struct QueryVersionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub major_version: u32,
+	pub minor_version: u32,
+}
+#[doc = " Selects which changes to the owner of the given `selection` the"]
+#[doc = " `window`'s client is sent [`SelectionNotify`] events for."]
+#[doc = ""]
+#[doc = " An empty `event_mask` stops the events from being sent."]
+pub struct SelectSelectionInput {
+	pub window: Window,
+	pub selection: Atom,
+	pub event_mask: SelectionEventMask,
+}
+#[doc = " Creates a new `region` which is the union of the given `rectangles`."]
+pub struct CreateRegion {
+	pub region: Region,
+	pub rectangles: Vec<Rectangle>,
+}
+#[doc = " Creates a new `region` from the set bits of the given `bitmap`."]
+pub struct CreateRegionFromBitmap {
+	pub region: Region,
+	#[doc = " A [`Pixmap`] with a depth of `1`."]
+	pub bitmap: Pixmap,
+}
+#[doc = " Creates a new `region` from one of the shapes of the given `window`."]
+pub struct CreateRegionFromWindow {
+	pub region: Region,
+	pub window: Window,
+	#[doc = " Either [`ShapeKind::Bounding`] or [`ShapeKind::Clip`]."]
+	pub kind: ShapeKind,
+}
+#[doc = " Creates a new `region` from the clip list of the given `context`."]
+pub struct CreateRegionFromGc {
+	pub region: Region,
+	pub context: GraphicsContext,
+}
+#[doc = " Destroys the given `region`."]
+pub struct DestroyRegion {
+	pub region: Region,
+}
+#[doc = " Replaces the contents of the given `region` with the union of the"]
+#[doc = " given `rectangles`."]
+pub struct SetRegion {
+	pub region: Region,
+	pub rectangles: Vec<Rectangle>,
+}
+#[doc = " Replaces the contents of the `destination` region with those of the"]
+#[doc = " `source` region."]
+pub struct CopyRegion {
+	pub source: Region,
+	pub destination: Region,
+}
+#[doc = " Sets the `destination` region to the union of two regions."]
+pub struct UnionRegion {
+	pub source1: Region,
+	pub source2: Region,
+	pub destination: Region,
+}
+#[doc = " Sets the `destination` region to the intersection of two regions."]
+pub struct IntersectRegion {
+	pub source1: Region,
+	pub source2: Region,
+	pub destination: Region,
+}
+#[doc = " Sets the `destination` region to `source1` with `source2` subtracted"]
+#[doc = " from it."]
+pub struct SubtractRegion {
+	pub source1: Region,
+	pub source2: Region,
+	pub destination: Region,
+}
+#[doc = " Sets the `destination` region to the area of the `bounds` not covered"]
+#[doc = " by the `source` region."]
+pub struct InvertRegion {
+	pub source: Region,
+	pub bounds: Rectangle,
+	pub destination: Region,
+}
+#[doc = " Moves the given `region` by `dx` and `dy`."]
+pub struct TranslateRegion {
+	pub region: Region,
+	pub dx: i16,
+	pub dy: i16,
+}
+#[doc = " Sets the `destination` region to the smallest rectangle which contains"]
+#[doc = " the `source` region."]
+pub struct RegionExtents {
+	pub source: Region,
+	pub destination: Region,
+}
+#[doc = " Returns the rectangles which make up the given `region`."]
+pub struct FetchRegion {
+	pub region: Region,
+}
+pub struct FetchRegionReply {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	#[doc = " The smallest rectangle which contains the region."]
+	pub extents: Rectangle,
+	pub rectangles: Vec<Rectangle>,
+}
+#[doc = " Sets one of the shapes of the given `window` to the given `region`,"]
+#[doc = " offset by `x_offset` and `y_offset`."]
+#[doc = ""]
+#[doc = " If `region` is [`None`], the shape is removed."]
+pub struct SetWindowShapeRegion {
+	pub window: Window,
+	pub kind: ShapeKind,
+	pub x_offset: i16,
+	pub y_offset: i16,
+	pub region: Option<Region>,
+}
+#[doc = " Sent when the owner of a `selection` changes, if the change was"]
+#[doc = " selected with [`SelectSelectionInput`]."]
+pub struct SelectionNotify {
+	#[doc = r" The sequence number associated with the last relevant"]
+	#[doc = r" request sent to the X server."]
+	#[allow(clippy::pub_underscore_fields)]
+	pub _sequence_: u16,
+	pub subtype: SelectionEvent,
+	#[doc = " The window which the event was selected on."]
+	pub window: Window,
+	#[doc = " The new owner of the `selection`, or [`None`] if it has no owner."]
+	pub owner: Option<Window>,
+	pub selection: Atom,
+	#[doc = " The time at which the event was generated."]
+	pub timestamp: Timestamp,
+	#[doc = " The time at which ownership of the `selection` was last changed."]
+	pub selection_timestamp: Timestamp,
+}
+impl cornflakes::Writable for QueryVersion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<QueryVersionReply>>::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(<Self as xrb::Request<QueryVersionReply>>::length(self));
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersion {
+	const NAME: &'static str = "QueryVersion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 0;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<QueryVersionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__client_major_version__, writer)?;
+		writer.field("client_major_version", __client_major_version__);
+		cornflakes::Writable::write_to(__client_minor_version__, writer)?;
+		writer.field("client_minor_version", __client_minor_version__);
+		Ok(())
+	}
+}
+impl QueryVersion {
+	#[doc = "Creates a new [`QueryVersion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(client_major_version: u32, client_minor_version: u32) -> Self {
+		Self {
+			client_major_version,
+			client_minor_version,
+		}
+	}
+}
+impl xrb::Request<QueryVersionReply> for QueryVersion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(0)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __client_minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			client_major_version: __client_major_version__,
+			client_minor_version: __client_minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for QueryVersionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<QueryVersion>>::length(self));
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.put_bytes(0, 16);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for QueryVersionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl xrb::PrettyPrint for QueryVersionReply {
+	const NAME: &'static str = "QueryVersionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<QueryVersion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__major_version__, writer)?;
+		writer.field("major_version", __major_version__);
+		cornflakes::Writable::write_to(__minor_version__, writer)?;
+		writer.field("minor_version", __minor_version__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		Ok(())
+	}
+}
+impl QueryVersionReply {
+	#[doc = "Creates a new [`QueryVersionReply`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`QueryVersionReply`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(sequence: u16, major_version: u32, minor_version: u32) -> Self {
+		Self {
+			_sequence_: sequence,
+			major_version,
+			minor_version,
+		}
+	}
+}
+impl xrb::Reply<QueryVersion> for QueryVersionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for QueryVersionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __major_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __minor_version__ = <u32 as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			major_version: __major_version__,
+			minor_version: __minor_version__,
+		})
+	}
+}
+impl cornflakes::Writable for SelectSelectionInput {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			selection: __selection__,
+			event_mask: __event_mask__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(2);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		cornflakes::Writable::write_to(__event_mask__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SelectSelectionInput {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __event_mask__ = <SelectionEventMask as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			window: __window__,
+			selection: __selection__,
+			event_mask: __event_mask__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SelectSelectionInput {
+	const NAME: &'static str = "SelectSelectionInput";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			selection: __selection__,
+			event_mask: __event_mask__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 2;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		writer.field("selection", __selection__);
+		cornflakes::Writable::write_to(__event_mask__, writer)?;
+		writer.field("event_mask", __event_mask__);
+		Ok(())
+	}
+}
+impl SelectSelectionInput {
+	#[doc = "Creates a new [`SelectSelectionInput`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SelectSelectionInput`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(window: Window, selection: Atom, event_mask: SelectionEventMask) -> Self {
+		Self {
+			window,
+			selection,
+			event_mask,
+		}
+	}
+}
+impl xrb::Request<()> for SelectSelectionInput {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(2)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for SelectSelectionInput {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __event_mask__ = <SelectionEventMask as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			window: __window__,
+			selection: __selection__,
+			event_mask: __event_mask__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(5);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __rectangles__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(8) / 8;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateRegion {
+	const NAME: &'static str = "CreateRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 5;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		writer.field("rectangles", __rectangles__);
+		Ok(())
+	}
+}
+impl xrb::Request<()> for CreateRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(5)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __rectangles__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(8) / 8;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateRegionFromBitmap {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			bitmap: __bitmap__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(6);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__bitmap__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateRegionFromBitmap {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __bitmap__ = <Pixmap as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			bitmap: __bitmap__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateRegionFromBitmap {
+	const NAME: &'static str = "CreateRegionFromBitmap";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			bitmap: __bitmap__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 6;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__bitmap__, writer)?;
+		writer.field("bitmap", __bitmap__);
+		Ok(())
+	}
+}
+impl CreateRegionFromBitmap {
+	#[doc = "Creates a new [`CreateRegionFromBitmap`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateRegionFromBitmap`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region, bitmap: Pixmap) -> Self {
+		Self { region, bitmap }
+	}
+}
+impl xrb::Request<()> for CreateRegionFromBitmap {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(6)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateRegionFromBitmap {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __bitmap__ = <Pixmap as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			bitmap: __bitmap__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateRegionFromWindow {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			window: __window__,
+			kind: __kind__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(7);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__kind__, writer)?;
+		writer.put_bytes(0, 3);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateRegionFromWindow {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __kind__ = <ShapeKind as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			region: __region__,
+			window: __window__,
+			kind: __kind__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateRegionFromWindow {
+	const NAME: &'static str = "CreateRegionFromWindow";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			window: __window__,
+			kind: __kind__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 7;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__kind__, writer)?;
+		writer.field("kind", __kind__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		Ok(())
+	}
+}
+impl CreateRegionFromWindow {
+	#[doc = "Creates a new [`CreateRegionFromWindow`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateRegionFromWindow`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region, window: Window, kind: ShapeKind) -> Self {
+		Self {
+			region,
+			window,
+			kind,
+		}
+	}
+}
+impl xrb::Request<()> for CreateRegionFromWindow {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(7)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateRegionFromWindow {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __kind__ = <ShapeKind as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			region: __region__,
+			window: __window__,
+			kind: __kind__,
+		})
+	}
+}
+impl cornflakes::Writable for CreateRegionFromGc {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			context: __context__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(8);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__context__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CreateRegionFromGc {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __context__ = <GraphicsContext as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			context: __context__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CreateRegionFromGc {
+	const NAME: &'static str = "CreateRegionFromGc";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			context: __context__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 8;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__context__, writer)?;
+		writer.field("context", __context__);
+		Ok(())
+	}
+}
+impl CreateRegionFromGc {
+	#[doc = "Creates a new [`CreateRegionFromGc`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CreateRegionFromGc`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region, context: GraphicsContext) -> Self {
+		Self { region, context }
+	}
+}
+impl xrb::Request<()> for CreateRegionFromGc {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(8)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CreateRegionFromGc {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __context__ = <GraphicsContext as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			context: __context__,
+		})
+	}
+}
+impl cornflakes::Writable for DestroyRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { region: __region__ } = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(10);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for DestroyRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { region: __region__ })
+	}
+}
+impl xrb::PrettyPrint for DestroyRegion {
+	const NAME: &'static str = "DestroyRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { region: __region__ } = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 10;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		Ok(())
+	}
+}
+impl DestroyRegion {
+	#[doc = "Creates a new [`DestroyRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`DestroyRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region) -> Self {
+		Self { region }
+	}
+}
+impl xrb::Request<()> for DestroyRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(10)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for DestroyRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { region: __region__ })
+	}
+}
+impl cornflakes::Writable for SetRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(11);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SetRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __rectangles__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(8) / 8;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SetRegion {
+	const NAME: &'static str = "SetRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 11;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		writer.field("rectangles", __rectangles__);
+		Ok(())
+	}
+}
+impl xrb::Request<()> for SetRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(11)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for SetRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __rectangles__ = {
+			let context: usize = (usize::from(_length_) * 4).saturating_sub(8) / 8;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			region: __region__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl cornflakes::Writable for CopyRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			source: __source__,
+			destination: __destination__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(12);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__source__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for CopyRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __source__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source: __source__,
+			destination: __destination__,
+		})
+	}
+}
+impl xrb::PrettyPrint for CopyRegion {
+	const NAME: &'static str = "CopyRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			source: __source__,
+			destination: __destination__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 12;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__source__, writer)?;
+		writer.field("source", __source__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		Ok(())
+	}
+}
+impl CopyRegion {
+	#[doc = "Creates a new [`CopyRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`CopyRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(source: Region, destination: Region) -> Self {
+		Self {
+			source,
+			destination,
+		}
+	}
+}
+impl xrb::Request<()> for CopyRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(12)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for CopyRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __source__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source: __source__,
+			destination: __destination__,
+		})
+	}
+}
+impl cornflakes::Writable for UnionRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(13);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__source1__, writer)?;
+		cornflakes::Writable::write_to(__source2__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for UnionRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __source1__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __source2__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		})
+	}
+}
+impl xrb::PrettyPrint for UnionRegion {
+	const NAME: &'static str = "UnionRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 13;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__source1__, writer)?;
+		writer.field("source1", __source1__);
+		cornflakes::Writable::write_to(__source2__, writer)?;
+		writer.field("source2", __source2__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		Ok(())
+	}
+}
+impl UnionRegion {
+	#[doc = "Creates a new [`UnionRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`UnionRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(source1: Region, source2: Region, destination: Region) -> Self {
+		Self {
+			source1,
+			source2,
+			destination,
+		}
+	}
+}
+impl xrb::Request<()> for UnionRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(13)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for UnionRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __source1__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __source2__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		})
+	}
+}
+impl cornflakes::Writable for IntersectRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(14);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__source1__, writer)?;
+		cornflakes::Writable::write_to(__source2__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for IntersectRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __source1__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __source2__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		})
+	}
+}
+impl xrb::PrettyPrint for IntersectRegion {
+	const NAME: &'static str = "IntersectRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 14;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__source1__, writer)?;
+		writer.field("source1", __source1__);
+		cornflakes::Writable::write_to(__source2__, writer)?;
+		writer.field("source2", __source2__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		Ok(())
+	}
+}
+impl IntersectRegion {
+	#[doc = "Creates a new [`IntersectRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`IntersectRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(source1: Region, source2: Region, destination: Region) -> Self {
+		Self {
+			source1,
+			source2,
+			destination,
+		}
+	}
+}
+impl xrb::Request<()> for IntersectRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(14)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for IntersectRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __source1__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __source2__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		})
+	}
+}
+impl cornflakes::Writable for SubtractRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(15);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__source1__, writer)?;
+		cornflakes::Writable::write_to(__source2__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SubtractRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __source1__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __source2__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SubtractRegion {
+	const NAME: &'static str = "SubtractRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 15;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__source1__, writer)?;
+		writer.field("source1", __source1__);
+		cornflakes::Writable::write_to(__source2__, writer)?;
+		writer.field("source2", __source2__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		Ok(())
+	}
+}
+impl SubtractRegion {
+	#[doc = "Creates a new [`SubtractRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SubtractRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(source1: Region, source2: Region, destination: Region) -> Self {
+		Self {
+			source1,
+			source2,
+			destination,
+		}
+	}
+}
+impl xrb::Request<()> for SubtractRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(15)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for SubtractRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __source1__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __source2__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source1: __source1__,
+			source2: __source2__,
+			destination: __destination__,
+		})
+	}
+}
+impl cornflakes::Writable for InvertRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			source: __source__,
+			bounds: __bounds__,
+			destination: __destination__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(16);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__source__, writer)?;
+		cornflakes::Writable::write_to(__bounds__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for InvertRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __source__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __bounds__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source: __source__,
+			bounds: __bounds__,
+			destination: __destination__,
+		})
+	}
+}
+impl xrb::PrettyPrint for InvertRegion {
+	const NAME: &'static str = "InvertRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			source: __source__,
+			bounds: __bounds__,
+			destination: __destination__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 16;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__source__, writer)?;
+		writer.field("source", __source__);
+		cornflakes::Writable::write_to(__bounds__, writer)?;
+		writer.field("bounds", __bounds__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		Ok(())
+	}
+}
+impl InvertRegion {
+	#[doc = "Creates a new [`InvertRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`InvertRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(source: Region, bounds: Rectangle, destination: Region) -> Self {
+		Self {
+			source,
+			bounds,
+			destination,
+		}
+	}
+}
+impl xrb::Request<()> for InvertRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(16)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for InvertRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __source__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __bounds__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source: __source__,
+			bounds: __bounds__,
+			destination: __destination__,
+		})
+	}
+}
+impl cornflakes::Writable for TranslateRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			region: __region__,
+			dx: __dx__,
+			dy: __dy__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(17);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		cornflakes::Writable::write_to(__dx__, writer)?;
+		cornflakes::Writable::write_to(__dy__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for TranslateRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dx__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dy__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			dx: __dx__,
+			dy: __dy__,
+		})
+	}
+}
+impl xrb::PrettyPrint for TranslateRegion {
+	const NAME: &'static str = "TranslateRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			region: __region__,
+			dx: __dx__,
+			dy: __dy__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 17;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		cornflakes::Writable::write_to(__dx__, writer)?;
+		writer.field("dx", __dx__);
+		cornflakes::Writable::write_to(__dy__, writer)?;
+		writer.field("dy", __dy__);
+		Ok(())
+	}
+}
+impl TranslateRegion {
+	#[doc = "Creates a new [`TranslateRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`TranslateRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region, dx: i16, dy: i16) -> Self {
+		Self { region, dx, dy }
+	}
+}
+impl xrb::Request<()> for TranslateRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(17)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for TranslateRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dx__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __dy__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			region: __region__,
+			dx: __dx__,
+			dy: __dy__,
+		})
+	}
+}
+impl cornflakes::Writable for RegionExtents {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			source: __source__,
+			destination: __destination__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(18);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__source__, writer)?;
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for RegionExtents {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __source__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source: __source__,
+			destination: __destination__,
+		})
+	}
+}
+impl xrb::PrettyPrint for RegionExtents {
+	const NAME: &'static str = "RegionExtents";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			source: __source__,
+			destination: __destination__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 18;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__source__, writer)?;
+		writer.field("source", __source__);
+		cornflakes::Writable::write_to(__destination__, writer)?;
+		writer.field("destination", __destination__);
+		Ok(())
+	}
+}
+impl RegionExtents {
+	#[doc = "Creates a new [`RegionExtents`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`RegionExtents`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(source: Region, destination: Region) -> Self {
+		Self {
+			source,
+			destination,
+		}
+	}
+}
+impl xrb::Request<()> for RegionExtents {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(18)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for RegionExtents {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __source__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		let __destination__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			source: __source__,
+			destination: __destination__,
+		})
+	}
+}
+impl cornflakes::Writable for FetchRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self { region: __region__ } = self;
+		writer.put_u8(<Self as xrb::Request<FetchRegionReply>>::major_opcode());
+		writer.put_u8(19);
+		writer.put_u16(<Self as xrb::Request<FetchRegionReply>>::length(self));
+		cornflakes::Writable::write_to(__region__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FetchRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { region: __region__ })
+	}
+}
+impl xrb::PrettyPrint for FetchRegion {
+	const NAME: &'static str = "FetchRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self { region: __region__ } = self;
+		{
+			let value = <Self as xrb::Request<FetchRegionReply>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 19;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<FetchRegionReply>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		Ok(())
+	}
+}
+impl FetchRegion {
+	#[doc = "Creates a new [`FetchRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`FetchRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(region: Region) -> Self {
+		Self { region }
+	}
+}
+impl xrb::Request<FetchRegionReply> for FetchRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(19)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for FetchRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __region__ = <Region as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self { region: __region__ })
+	}
+}
+impl cornflakes::Writable for FetchRegionReply {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			extents: __extents__,
+			rectangles: __rectangles__,
+		} = self;
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(*_sequence_);
+		writer.put_u32(<Self as xrb::Reply<FetchRegion>>::length(self));
+		cornflakes::Writable::write_to(__extents__, writer)?;
+		writer.put_bytes(0, 16);
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for FetchRegionReply {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		if reader.remaining() < 4usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 4usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u32();
+		let __extents__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __rectangles__ = {
+			let context: usize = (_length_ as usize) / 2;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			_sequence_,
+			extents: __extents__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl xrb::PrettyPrint for FetchRegionReply {
+	const NAME: &'static str = "FetchRegionReply";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			extents: __extents__,
+			rectangles: __rectangles__,
+		} = self;
+		{
+			let value = 1_u8;
+			writer.put_u8(value);
+			writer.field("reply", &value);
+		}
+		writer.put_u8(0);
+		writer.unused();
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		{
+			let value = <Self as xrb::Reply<FetchRegion>>::length(self);
+			writer.put_u32(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__extents__, writer)?;
+		writer.field("extents", __extents__);
+		writer.put_bytes(0, 16);
+		writer.unused();
+		cornflakes::Writable::write_to(__rectangles__, writer)?;
+		writer.field("rectangles", __rectangles__);
+		Ok(())
+	}
+}
+impl xrb::Reply<FetchRegion> for FetchRegionReply {
+	fn sequence(&self) -> Option<u16> {
+		Some(self._sequence_)
+	}
+	fn length(&self) -> u32 {
+		0
+	}
+}
+impl xrb::DecodeBody for FetchRegionReply {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Reply {
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			length: _length_,
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "reply",
+					found: *header,
+				},
+			)));
+		};
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		if reader.remaining() < 1usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 1usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		xrb::skip_padding(reader, 1)?;
+		let reader = _body_reader_;
+		let __extents__ = <Rectangle as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 16;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		let __rectangles__ = {
+			let context: usize = (_length_ as usize) / 2;
+			let mut list = Vec::with_capacity(context.min(reader.remaining()));
+			for _ in 0..context {
+				list.push(<Rectangle as cornflakes::Readable>::read_from(reader)?);
+			}
+			list
+		};
+		Ok(Self {
+			_sequence_,
+			extents: __extents__,
+			rectangles: __rectangles__,
+		})
+	}
+}
+impl cornflakes::Writable for SetWindowShapeRegion {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			window: __window__,
+			kind: __kind__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+			region: __region__,
+		} = self;
+		writer.put_u8(<Self as xrb::Request<()>>::major_opcode());
+		writer.put_u8(21);
+		writer.put_u16(<Self as xrb::Request<()>>::length(self));
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__kind__, writer)?;
+		writer.put_bytes(0, 3);
+		cornflakes::Writable::write_to(__x_offset__, writer)?;
+		cornflakes::Writable::write_to(__y_offset__, writer)?;
+		cornflakes::Writable::write_to(__region__, writer)?;
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SetWindowShapeRegion {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _length_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __kind__ = <ShapeKind as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x_offset__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y_offset__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		let __region__ = <Option<Region> as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			window: __window__,
+			kind: __kind__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+			region: __region__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SetWindowShapeRegion {
+	const NAME: &'static str = "SetWindowShapeRegion";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			window: __window__,
+			kind: __kind__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+			region: __region__,
+		} = self;
+		{
+			let value = <Self as xrb::Request<()>>::major_opcode();
+			writer.put_u8(value);
+			writer.field("major_opcode", &value);
+		}
+		{
+			let value = 21;
+			writer.put_u8(value);
+			writer.field("minor_opcode", &value);
+		}
+		{
+			let value = <Self as xrb::Request<()>>::length(self);
+			writer.put_u16(value);
+			writer.field("length", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__kind__, writer)?;
+		writer.field("kind", __kind__);
+		writer.put_bytes(0, 3);
+		writer.unused();
+		cornflakes::Writable::write_to(__x_offset__, writer)?;
+		writer.field("x_offset", __x_offset__);
+		cornflakes::Writable::write_to(__y_offset__, writer)?;
+		writer.field("y_offset", __y_offset__);
+		cornflakes::Writable::write_to(__region__, writer)?;
+		writer.field("region", __region__);
+		Ok(())
+	}
+}
+impl SetWindowShapeRegion {
+	#[doc = "Creates a new [`SetWindowShapeRegion`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SetWindowShapeRegion`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		window: Window,
+		kind: ShapeKind,
+		x_offset: i16,
+		y_offset: i16,
+		region: Option<Region>,
+	) -> Self {
+		Self {
+			window,
+			kind,
+			x_offset,
+			y_offset,
+			region,
+		}
+	}
+}
+impl xrb::Request<()> for SetWindowShapeRegion {
+	fn major_opcode() -> u8 {
+		EXTENSION.major_opcode()
+	}
+	fn minor_opcode() -> Option<u8> {
+		Some(21)
+	}
+	fn length(&self) -> u16 {
+		0
+	}
+}
+impl xrb::DecodeBody for SetWindowShapeRegion {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Request {
+			metabyte: _metabyte_,
+			length: _length_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "request",
+					found: *header,
+				},
+			)));
+		};
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __kind__ = <ShapeKind as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 3;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __x_offset__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let __y_offset__ = <i16 as cornflakes::Readable>::read_from(reader)?;
+		let __region__ = <Option<Region> as cornflakes::Readable>::read_from(reader)?;
+		Ok(Self {
+			window: __window__,
+			kind: __kind__,
+			x_offset: __x_offset__,
+			y_offset: __y_offset__,
+			region: __region__,
+		})
+	}
+}
+impl cornflakes::Writable for SelectionNotify {
+	fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), cornflakes::WriteError> {
+		let Self {
+			_sequence_,
+			subtype: __subtype__,
+			window: __window__,
+			owner: __owner__,
+			selection: __selection__,
+			timestamp: __timestamp__,
+			selection_timestamp: __selection_timestamp__,
+		} = self;
+		writer.put_u8(<Self as xrb::Event>::code());
+		cornflakes::Writable::write_to(__subtype__, writer)?;
+		writer.put_u16(*_sequence_);
+		cornflakes::Writable::write_to(__window__, writer)?;
+		cornflakes::Writable::write_to(__owner__, writer)?;
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		cornflakes::Writable::write_to(__timestamp__, writer)?;
+		cornflakes::Writable::write_to(__selection_timestamp__, writer)?;
+		writer.put_bytes(0, 8);
+		Ok(())
+	}
+}
+impl cornflakes::Readable for SelectionNotify {
+	fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, cornflakes::ReadError> {
+		let __subtype__ = <SelectionEvent as cornflakes::Readable>::read_from(reader)?;
+		if reader.remaining() < 2usize {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: 2usize,
+					found: reader.remaining(),
+				},
+			)));
+		}
+		let _sequence_ = reader.get_u16();
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __owner__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __timestamp__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __selection_timestamp__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 8;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			subtype: __subtype__,
+			window: __window__,
+			owner: __owner__,
+			selection: __selection__,
+			timestamp: __timestamp__,
+			selection_timestamp: __selection_timestamp__,
+		})
+	}
+}
+impl xrb::PrettyPrint for SelectionNotify {
+	const NAME: &'static str = "SelectionNotify";
+	fn trace(&self, writer: &mut xrb::Trace) -> Result<(), cornflakes::WriteError> {
+		#[allow(unused_imports)]
+		use bytes::BufMut as _;
+		let Self {
+			_sequence_,
+			subtype: __subtype__,
+			window: __window__,
+			owner: __owner__,
+			selection: __selection__,
+			timestamp: __timestamp__,
+			selection_timestamp: __selection_timestamp__,
+		} = self;
+		{
+			let value = <Self as xrb::Event>::code();
+			writer.put_u8(value);
+			writer.field("code", &value);
+		}
+		cornflakes::Writable::write_to(__subtype__, writer)?;
+		writer.field("subtype", __subtype__);
+		{
+			let value = *_sequence_;
+			writer.put_u16(value);
+			writer.field("sequence", &value);
+		}
+		cornflakes::Writable::write_to(__window__, writer)?;
+		writer.field("window", __window__);
+		cornflakes::Writable::write_to(__owner__, writer)?;
+		writer.field("owner", __owner__);
+		cornflakes::Writable::write_to(__selection__, writer)?;
+		writer.field("selection", __selection__);
+		cornflakes::Writable::write_to(__timestamp__, writer)?;
+		writer.field("timestamp", __timestamp__);
+		cornflakes::Writable::write_to(__selection_timestamp__, writer)?;
+		writer.field("selection_timestamp", __selection_timestamp__);
+		writer.put_bytes(0, 8);
+		writer.unused();
+		Ok(())
+	}
+}
+impl SelectionNotify {
+	#[doc = "Creates a new [`SelectionNotify`] from its fields.\n\nThis is a `const fn`, so it can be used to build a [`SelectionNotify`] at compile time."]
+	#[must_use]
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(
+		sequence: u16,
+		subtype: SelectionEvent,
+		window: Window,
+		owner: Option<Window>,
+		selection: Atom,
+		timestamp: Timestamp,
+		selection_timestamp: Timestamp,
+	) -> Self {
+		Self {
+			_sequence_: sequence,
+			subtype,
+			window,
+			owner,
+			selection,
+			timestamp,
+			selection_timestamp,
+		}
+	}
+}
+impl xrb::Event for SelectionNotify {
+	fn code() -> u8 {
+		EXTENSION.first_event()
+	}
+	fn sequence(&self) -> u16 {
+		self._sequence_
+	}
+}
+impl xrb::DecodeBody for SelectionNotify {
+	fn decode_body(
+		header: &xrb::Header,
+		reader: &mut impl bytes::Buf,
+	) -> Result<Self, cornflakes::ReadError> {
+		let xrb::Header::Event {
+			code,
+			metabyte: _metabyte_,
+			sequence: _sequence_,
+			..
+		} = *header
+		else {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedHeader {
+					expected: "event",
+					found: *header,
+				},
+			)));
+		};
+		if code != <Self as xrb::Event>::code() {
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEventCode {
+					expected: <Self as xrb::Event>::code(),
+					found: code,
+				},
+			)));
+		}
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		let __subtype__ = <SelectionEvent as cornflakes::Readable>::read_from(reader)?;
+		let reader = _body_reader_;
+		let __window__ = <Window as cornflakes::Readable>::read_from(reader)?;
+		let __owner__ = <Option<Window> as cornflakes::Readable>::read_from(reader)?;
+		let __selection__ = <Atom as cornflakes::Readable>::read_from(reader)?;
+		let __timestamp__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		let __selection_timestamp__ = <Timestamp as cornflakes::Readable>::read_from(reader)?;
+		{
+			let len: usize = 8;
+			if reader.remaining() < len {
+				return Err(cornflakes::ReadError::Other(Box::new(
+					xrb::UnexpectedEndOfData {
+						expected: len,
+						found: reader.remaining(),
+					},
+				)));
+			}
+			xrb::skip_padding(reader, len)?;
+		}
+		Ok(Self {
+			_sequence_,
+			subtype: __subtype__,
+			window: __window__,
+			owner: __owner__,
+			selection: __selection__,
+			timestamp: __timestamp__,
+			selection_timestamp: __selection_timestamp__,
+		})
+	}
+}
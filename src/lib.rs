@@ -39,6 +39,17 @@
 //! development purpose is to provide a foundation for higher-level Rust API
 //! wrapper libraries. It is used by [X.RS](https://crates.io/crates/xrs), the
 //! official accompanying API library for XRB.
+//!
+//! # Features
+//! - `macros` (default): expands XRB's definitions with `xrbk_macro` when XRB
+//!   is compiled.
+//! - `codegen-snapshot`: uses the expansions of XRB's definitions checked in to
+//!   `snapshot/` instead, so that `xrbk_macro` and its dependencies don't need
+//!   to be compiled. This is for downstream crates which only need XRB's data
+//!   structures, and is enabled with `default-features = false`.
+
+#[cfg(not(any(feature = "macros", feature = "codegen-snapshot")))]
+compile_error!("either the `macros` or `codegen-snapshot` feature must be enabled");
 
 /// The major version of the X protocol used in XRB.
 ///
@@ -51,5 +62,22 @@ pub const PROTOCOL_MAJOR_VERSION: u16 = 11;
 /// protocol; seeing as this has not happened since the 80s, it's probably safe to assume it won't.
 pub const PROTOCOL_MINOR_VERSION: u16 = 0;
 
-// /// Implementations for the core X11 protocol.
-// mod x11;
+// Re-exported so that definitions in other crates can refer to XRB with
+// `#[xrbk(crate = "...")]`, which also brings these into scope from XRB.
+pub use bytes;
+pub use cornflakes;
+
+/// Padding and length arithmetic, as used by the messages generated by
+/// `define!`.
+pub mod util;
+
+/// Implementations for the core X11 protocol.
+#[cfg(feature = "x11")]
+pub mod x11;
+
+// The code generated by `define!` refers to XRB's traits and helpers as
+// `xrb::...`, both in XRB and in the crates which define extensions with it.
+#[cfg(feature = "x11")]
+extern crate self as xrb;
+#[cfg(feature = "x11")]
+pub use x11::prelude::{self, *};
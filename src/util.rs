@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// The number of bytes in each of the units that lengths are measured in by
+/// the X protocol.
+pub const UNIT: usize = 4;
+
+/// The size of replies and events, beyond which the length of a reply or
+/// generic event is measured.
+const MESSAGE_SIZE: usize = 32;
+
+/// The number of bytes needed to pad `n` bytes to a multiple of 4.
+///
+/// This is the `pad(E)` of the X protocol's encoding: lists of bytes, such as
+/// strings, are followed by this many unused bytes.
+#[must_use]
+pub const fn pad(n: usize) -> usize {
+	(UNIT - n % UNIT) % UNIT
+}
+
+/// `n` bytes padded to a multiple of 4: `n + pad(n)`.
+#[must_use]
+pub const fn padded(n: usize) -> usize {
+	n + pad(n)
+}
+
+/// The number of 4-byte units needed to hold `n` bytes, including their
+/// [padding](pad).
+#[must_use]
+pub const fn units(n: usize) -> usize {
+	n.div_ceil(UNIT)
+}
+
+/// The number of bytes in the given number of 4-byte `units`, such as the
+/// length of a request.
+#[must_use]
+pub const fn bytes(units: usize) -> usize {
+	units * UNIT
+}
+
+/// The number of 4-byte units in `n` bytes beyond the first 32, as given in
+/// the length of a reply or generic event of `n` bytes.
+///
+/// Replies and generic events are at least 32 bytes long, so smaller sizes
+/// give `0`.
+#[must_use]
+pub const fn extra_units(n: usize) -> usize {
+	n.saturating_sub(MESSAGE_SIZE) / UNIT
+}
+
+/// The total number of bytes in a reply or generic event with the given
+/// `length`: the inverse of [`extra_units`].
+#[must_use]
+pub const fn extra_bytes(length: usize) -> usize {
+	MESSAGE_SIZE + bytes(length)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lengths_are_measured_in_units() {
+		assert_eq!([0, 1, 2, 3, 4, 5].map(pad), [0, 3, 2, 1, 0, 3]);
+		assert_eq!([0, 1, 4, 5].map(padded), [0, 4, 4, 8]);
+		assert_eq!([0, 1, 4, 5].map(units), [0, 1, 1, 2]);
+		assert_eq!(bytes(units(5)), padded(5));
+
+		assert_eq!(extra_units(20), 0);
+		assert_eq!(extra_units(32), 0);
+		assert_eq!(extra_units(44), 3);
+		assert_eq!(extra_bytes(extra_units(44)), 44);
+	}
+}
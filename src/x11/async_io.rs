@@ -0,0 +1,189 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt, io};
+
+use cornflakes::{ReadError, Readable, Writable, WriteError};
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::x11::{
+	byte_order::ByteOrder,
+	framing::{self, MESSAGE_SIZE},
+};
+
+/// An error encountered when reading or writing a message asynchronously.
+#[derive(Debug)]
+pub enum AsyncIoError {
+	/// The underlying reader or writer failed.
+	Io(io::Error),
+
+	/// The message's bytes were received, but could not be read.
+	Read(ReadError),
+	/// The message could not be written, so nothing was sent.
+	Write(WriteError),
+}
+
+impl fmt::Display for AsyncIoError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Io(error) => write!(f, "{error}"),
+
+			Self::Read(ReadError::Other(error)) => write!(f, "failed to read message: {error}"),
+			Self::Read(error) => write!(f, "failed to read message: {error:?}"),
+
+			Self::Write(WriteError::Other(error)) => {
+				write!(f, "failed to write message: {error}")
+			}
+			Self::Write(error) => write!(f, "failed to write message: {error:?}"),
+		}
+	}
+}
+
+impl Error for AsyncIoError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Io(error) => Some(error),
+
+			Self::Read(ReadError::Other(error)) | Self::Write(WriteError::Other(error)) => {
+				Some(error.as_ref())
+			}
+
+			_ => None,
+		}
+	}
+}
+
+impl From<io::Error> for AsyncIoError {
+	fn from(error: io::Error) -> Self {
+		Self::Io(error)
+	}
+}
+
+/// Writes the given `message` to the asynchronous `writer` in the given
+/// `byte_order`.
+///
+/// The `message` is written to a buffer first, so nothing is sent if it can't
+/// be written.
+///
+/// # Errors
+/// Returns [`AsyncIoError::Write`] if the `message` could not be written, or
+/// [`AsyncIoError::Io`] if the `writer` failed.
+pub async fn write_to_async<W>(
+	message: &impl Writable,
+	byte_order: ByteOrder,
+	writer: &mut W,
+) -> Result<(), AsyncIoError>
+where
+	W: AsyncWrite + Unpin,
+{
+	let mut bytes = vec![];
+	message
+		.write_to(&mut byte_order.writer(&mut bytes))
+		.map_err(AsyncIoError::Write)?;
+
+	writer.write_all(&bytes).await?;
+
+	Ok(())
+}
+
+/// Waits for `len` bytes from the asynchronous `reader`, then reads them as a
+/// `T` in the given `byte_order`.
+///
+/// # Errors
+/// Returns [`AsyncIoError::Io`] if the `reader` failed or ended before `len`
+/// bytes were received, or [`AsyncIoError::Read`] if they could not be read
+/// as a `T`.
+pub async fn read_from_async<T, R>(
+	reader: &mut R,
+	len: usize,
+	byte_order: ByteOrder,
+) -> Result<T, AsyncIoError>
+where
+	T: Readable,
+	R: AsyncRead + Unpin,
+{
+	let mut bytes = vec![0; len];
+	reader.read_exact(&mut bytes).await?;
+
+	T::read_from(&mut byte_order.reader(&bytes[..])).map_err(AsyncIoError::Read)
+}
+
+/// Waits for the whole of the next message sent by the X server from the
+/// asynchronous `reader`, returning its bytes, including its first byte.
+///
+/// The first [`MESSAGE_SIZE`] bytes are awaited first; if the message is a
+/// reply or a `GenericEvent`, the rest of it is then awaited according to the
+/// length it gives (see [`framing::message_len`]). The returned bytes can be
+/// told apart with [`framing::classify`] and passed to a [`ReplyDecoder`] or
+/// read as an error or event.
+///
+/// # Errors
+/// Returns an error if the `reader` failed or ended before the whole message
+/// was received.
+///
+/// [`ReplyDecoder`]: crate::x11::reply_decoder::ReplyDecoder
+pub async fn read_server_message<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<Vec<u8>>
+where
+	R: AsyncRead + Unpin,
+{
+	let mut header = [0; MESSAGE_SIZE];
+	reader.read_exact(&mut header).await?;
+
+	let mut message = header.to_vec();
+	message.resize(framing::message_len(&header, byte_order), 0);
+	reader.read_exact(&mut message[MESSAGE_SIZE..]).await?;
+
+	Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+	use futures::{executor::block_on, io::Cursor};
+
+	use super::*;
+
+	#[test]
+	fn values_round_trip() {
+		let byte_order = ByteOrder::LeastSignificantFirst;
+
+		let mut writer = Cursor::new(vec![]);
+		block_on(write_to_async(&0x1234_u16, byte_order, &mut writer)).unwrap();
+		assert_eq!(writer.get_ref(), &[0x34, 0x12]);
+
+		let mut reader = Cursor::new(writer.into_inner());
+		let value: u16 = block_on(read_from_async(&mut reader, 2, byte_order)).unwrap();
+		assert_eq!(value, 0x1234);
+	}
+
+	#[test]
+	fn server_messages_are_read_whole() {
+		let mut reply = vec![0; MESSAGE_SIZE + 8];
+		reply[0] = framing::REPLY;
+		reply[4..8].copy_from_slice(&2u32.to_be_bytes());
+
+		let mut event = vec![0; MESSAGE_SIZE];
+		event[0] = 12;
+
+		let mut reader = Cursor::new([reply.clone(), event.clone()].concat());
+		let byte_order = ByteOrder::MostSignificantFirst;
+
+		assert_eq!(
+			block_on(read_server_message(&mut reader, byte_order)).unwrap(),
+			reply
+		);
+		assert_eq!(
+			block_on(read_server_message(&mut reader, byte_order)).unwrap(),
+			event
+		);
+
+		// The stream ends partway through the reply's extra bytes.
+		let mut reader = Cursor::new(reply[..36].to_vec());
+		assert_eq!(
+			block_on(read_server_message(&mut reader, byte_order))
+				.unwrap_err()
+				.kind(),
+			io::ErrorKind::UnexpectedEof
+		);
+	}
+}
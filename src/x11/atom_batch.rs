@@ -0,0 +1,269 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use crate::x11::{
+	requests::{InternAtom, InternAtomReply},
+	traits::Reply,
+	Atom,
+	String8,
+};
+
+/// A batch of [`InternAtom`] requests, such as for the atoms a client needs
+/// when it starts.
+///
+/// The requests are generated in the order that the names were given, with
+/// repeated names only being interned once. Since the X server assigns
+/// consecutive sequence numbers to consecutive requests, an [`AtomResolver`]
+/// can then match each [`InternAtomReply`] back to its name by its sequence
+/// number alone.
+///
+/// # Examples
+/// ```ignore
+/// let batch = InternAtomBatch::new(["WM_PROTOCOLS", "WM_DELETE_WINDOW"], false);
+///
+/// for request in batch.requests() {
+///     connection.send(request)?;
+/// }
+///
+/// let mut resolver = batch.resolver(first_sequence);
+///
+/// while !resolver.is_complete() {
+///     resolver.resolve(&connection.receive::<InternAtomReply>()?);
+/// }
+///
+/// let wm_protocols = resolver.get("WM_PROTOCOLS");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct InternAtomBatch {
+	only_if_exists: bool,
+	names: Vec<String>,
+}
+
+/// Matches the replies to an [`InternAtomBatch`]'s requests back to the names
+/// of the atoms by their sequence numbers.
+///
+/// This is created with [`InternAtomBatch::resolver`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct AtomResolver {
+	/// The sequence number of the request for the first name.
+	first_sequence: u16,
+	names: Vec<String>,
+	/// The atom for each name, which is [`None`] until its reply has been
+	/// received.
+	atoms: Vec<Option<Atom>>,
+	/// Whether the reply for each name has been received.
+	received: Vec<bool>,
+	/// The number of names whose replies have not yet been received.
+	remaining: usize,
+}
+
+impl InternAtomBatch {
+	/// Creates a new [`InternAtomBatch`] interning the given `names` in order.
+	///
+	/// If `only_if_exists` is `true`, atoms which do not already exist will
+	/// not be created, and will instead be resolved to [`None`].
+	pub fn new<I, S>(names: I, only_if_exists: bool) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		let mut batch = Self {
+			only_if_exists,
+			names: Vec::new(),
+		};
+
+		for name in names {
+			batch.push(name);
+		}
+
+		batch
+	}
+
+	/// Adds a `name` to the end of this batch, unless it is already in it.
+	///
+	/// Returns whether the `name` was added.
+	pub fn push(&mut self, name: impl Into<String>) -> bool {
+		let name = name.into();
+
+		if self.names.contains(&name) {
+			return false;
+		}
+
+		self.names.push(name);
+		true
+	}
+
+	/// The names of the atoms in this batch, in the order that they are
+	/// requested.
+	#[must_use]
+	pub fn names(&self) -> &[String] {
+		&self.names
+	}
+
+	/// The number of atoms in this batch.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.names.len()
+	}
+
+	/// Returns whether there are no atoms in this batch.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.names.is_empty()
+	}
+
+	/// Returns an iterator over the [`InternAtom`] requests for this batch,
+	/// which must be sent one after another in this order.
+	pub fn requests(&self) -> impl Iterator<Item = InternAtom> + '_ {
+		self.names.iter().map(|name| InternAtom {
+			only_if_exists: self.only_if_exists,
+			name: String8::from(name.clone()),
+		})
+	}
+
+	/// Creates an [`AtomResolver`] for this batch's replies, given the
+	/// sequence number of the first of its [`requests`].
+	///
+	/// [`requests`]: InternAtomBatch::requests
+	#[must_use]
+	pub fn resolver(self, first_sequence: u16) -> AtomResolver {
+		AtomResolver {
+			first_sequence,
+			atoms: vec![None; self.names.len()],
+			received: vec![false; self.names.len()],
+			remaining: self.names.len(),
+			names: self.names,
+		}
+	}
+}
+
+impl AtomResolver {
+	/// Returns the name interned by the request with the given `sequence`
+	/// number, if it is part of this batch.
+	#[must_use]
+	pub fn name(&self, sequence: u16) -> Option<&str> {
+		self.index(sequence).map(|index| &*self.names[index])
+	}
+
+	/// Records the `atom` returned for the request with the given `sequence`
+	/// number.
+	///
+	/// Returns the name of the atom, or [`None`] if the request is not part of
+	/// this batch.
+	pub fn resolve_atom(&mut self, sequence: u16, atom: Option<Atom>) -> Option<&str> {
+		let index = self.index(sequence)?;
+
+		if !self.received[index] {
+			self.received[index] = true;
+			self.remaining -= 1;
+		}
+		self.atoms[index] = atom;
+
+		Some(&self.names[index])
+	}
+
+	/// Records the atom returned in the given `reply`.
+	///
+	/// Returns the name of the atom, or [`None`] if the reply is not to a
+	/// request in this batch.
+	pub fn resolve(&mut self, reply: &InternAtomReply) -> Option<&str> {
+		self.resolve_atom(reply.sequence()?, reply.atom)
+	}
+
+	/// Returns whether the replies to all of the batch's requests have been
+	/// received.
+	#[must_use]
+	pub const fn is_complete(&self) -> bool {
+		self.remaining == 0
+	}
+
+	/// Returns the atom for the given `name`, if its reply has been received
+	/// and the atom exists.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<Atom> {
+		let index = self.names.iter().position(|other| other == name)?;
+
+		self.atoms[index]
+	}
+
+	/// Returns the names and atoms whose replies have been received, in the
+	/// order that they were requested.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, Option<Atom>)> + '_ {
+		self.names
+			.iter()
+			.zip(&self.atoms)
+			.zip(&self.received)
+			.filter(|(_, &received)| received)
+			.map(|((name, &atom), _)| (&**name, atom))
+	}
+
+	/// Converts this resolver into a map from each name whose reply has been
+	/// received to its atom.
+	#[must_use]
+	pub fn into_map(self) -> HashMap<String, Option<Atom>> {
+		self.names
+			.into_iter()
+			.zip(self.atoms)
+			.zip(self.received)
+			.filter(|(_, received)| *received)
+			.map(|(entry, _)| entry)
+			.collect()
+	}
+
+	fn index(&self, sequence: u16) -> Option<usize> {
+		// Sequence numbers wrap, so the batch may continue past `65535`.
+		let index = usize::from(sequence.wrapping_sub(self.first_sequence));
+
+		(index < self.names.len()).then_some(index)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn replies_are_matched_by_sequence_order() {
+		let batch =
+			InternAtomBatch::new(["WM_PROTOCOLS", "WM_DELETE_WINDOW", "WM_PROTOCOLS"], false);
+
+		assert_eq!(batch.names(), ["WM_PROTOCOLS", "WM_DELETE_WINDOW"]);
+
+		let mut resolver = batch.resolver(65535);
+
+		assert_eq!(
+			resolver.resolve_atom(0, Some(Atom::new(300))),
+			Some("WM_DELETE_WINDOW")
+		);
+		assert!(!resolver.is_complete());
+		assert_eq!(
+			resolver.resolve_atom(65535, Some(Atom::new(299))),
+			Some("WM_PROTOCOLS")
+		);
+		assert!(resolver.is_complete());
+
+		assert_eq!(resolver.get("WM_PROTOCOLS"), Some(Atom::new(299)));
+		assert_eq!(resolver.get("WM_DELETE_WINDOW"), Some(Atom::new(300)));
+	}
+
+	#[test]
+	fn other_sequences_are_not_resolved() {
+		let mut resolver = InternAtomBatch::new(["_NET_WM_NAME"], true).resolver(10);
+
+		assert_eq!(resolver.resolve_atom(9, None), None);
+		assert_eq!(resolver.resolve_atom(11, None), None);
+		assert_eq!(resolver.name(10), Some("_NET_WM_NAME"));
+
+		resolver.resolve_atom(10, None);
+
+		assert!(resolver.is_complete());
+		assert_eq!(resolver.get("_NET_WM_NAME"), None);
+		assert_eq!(
+			resolver.iter().collect::<Vec<_>>(),
+			[("_NET_WM_NAME", None)]
+		);
+	}
+}
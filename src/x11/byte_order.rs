@@ -0,0 +1,394 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+/// The order in which the bytes of multi-byte values are sent on a
+/// connection.
+///
+/// The client chooses the byte order in the connection setup, and both it and
+/// the X server then use that byte order for every message. XRB writes and
+/// reads messages in [`MostSignificantFirst`] order by default; wrap the
+/// writer or reader with [`writer`] or [`reader`] to use a particular byte
+/// order instead:
+/// ```ignore
+/// let mut bytes = vec![];
+/// request.write_to(&mut ByteOrder::LeastSignificantFirst.writer(&mut bytes))?;
+///
+/// let event = MapRequest::read_from(&mut ByteOrder::LeastSignificantFirst.reader(reader))?;
+/// ```
+///
+/// [`MostSignificantFirst`]: ByteOrder::MostSignificantFirst
+/// [`writer`]: ByteOrder::writer
+/// [`reader`]: ByteOrder::reader
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ByteOrder {
+	/// Big-endian: the most significant byte is sent first.
+	MostSignificantFirst,
+	/// Little-endian: the least significant byte is sent first.
+	LeastSignificantFirst,
+}
+
+impl ByteOrder {
+	/// The byte order of the machine this is running on.
+	///
+	/// Using the native byte order means neither the client nor, if it is on
+	/// the same machine, the X server has to swap bytes.
+	#[must_use]
+	pub const fn native() -> Self {
+		if cfg!(target_endian = "big") {
+			Self::MostSignificantFirst
+		} else {
+			Self::LeastSignificantFirst
+		}
+	}
+
+	/// The opposite byte order to this one.
+	///
+	/// `ByteOrder::native().opposite()` is the byte order of a machine with
+	/// the other endianness, so messages can be tested in both byte orders
+	/// without such a machine.
+	#[must_use]
+	pub const fn opposite(self) -> Self {
+		match self {
+			Self::MostSignificantFirst => Self::LeastSignificantFirst,
+			Self::LeastSignificantFirst => Self::MostSignificantFirst,
+		}
+	}
+
+	/// Returns whether this is the byte order of the machine this is running
+	/// on.
+	#[must_use]
+	pub const fn is_native(self) -> bool {
+		matches!(
+			(self, Self::native()),
+			(Self::MostSignificantFirst, Self::MostSignificantFirst)
+				| (Self::LeastSignificantFirst, Self::LeastSignificantFirst)
+		)
+	}
+
+	/// The byte sent at the start of the connection setup to choose this byte
+	/// order: `B` for [`MostSignificantFirst`], `l` for
+	/// [`LeastSignificantFirst`].
+	///
+	/// [`MostSignificantFirst`]: ByteOrder::MostSignificantFirst
+	/// [`LeastSignificantFirst`]: ByteOrder::LeastSignificantFirst
+	#[must_use]
+	pub const fn setup_byte(self) -> u8 {
+		match self {
+			Self::MostSignificantFirst => b'B',
+			Self::LeastSignificantFirst => b'l',
+		}
+	}
+
+	/// Returns the byte order chosen by the given connection setup `byte`, if
+	/// it is valid.
+	#[must_use]
+	pub const fn from_setup_byte(byte: u8) -> Option<Self> {
+		match byte {
+			b'B' => Some(Self::MostSignificantFirst),
+			b'l' => Some(Self::LeastSignificantFirst),
+
+			_ => None,
+		}
+	}
+
+	/// Wraps the given `writer` so that values are written in this byte
+	/// order.
+	pub const fn writer<W: BufMut>(self, writer: W) -> OrderedWriter<W> {
+		OrderedWriter {
+			byte_order: self,
+			inner: writer,
+		}
+	}
+
+	/// Wraps the given `reader` so that values are read in this byte order.
+	pub const fn reader<R: Buf>(self, reader: R) -> OrderedReader<R> {
+		OrderedReader {
+			byte_order: self,
+			inner: reader,
+		}
+	}
+}
+
+/// A writer which writes values in a particular [`ByteOrder`].
+///
+/// This is created with [`ByteOrder::writer`].
+#[derive(Debug)]
+pub struct OrderedWriter<W> {
+	byte_order: ByteOrder,
+	inner: W,
+}
+
+/// A reader which reads values in a particular [`ByteOrder`].
+///
+/// This is created with [`ByteOrder::reader`].
+#[derive(Debug)]
+pub struct OrderedReader<R> {
+	byte_order: ByteOrder,
+	inner: R,
+}
+
+impl<W> OrderedWriter<W> {
+	/// The [`ByteOrder`] in which values are written.
+	pub const fn byte_order(&self) -> ByteOrder {
+		self.byte_order
+	}
+
+	/// Returns the wrapped writer.
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+}
+
+impl<R> OrderedReader<R> {
+	/// The [`ByteOrder`] in which values are read.
+	pub const fn byte_order(&self) -> ByteOrder {
+		self.byte_order
+	}
+
+	/// Returns the wrapped reader.
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+// Generates `BufMut` methods which write a value in the writer's byte order.
+macro_rules! put {
+	($($put:ident, $put_le:ident: $type:ty;)*) => {
+		$(
+			fn $put(&mut self, n: $type) {
+				match self.byte_order {
+					ByteOrder::MostSignificantFirst => self.inner.$put(n),
+					ByteOrder::LeastSignificantFirst => self.inner.$put_le(n),
+				}
+			}
+		)*
+	};
+}
+
+// Generates `Buf` methods which read a value in the reader's byte order.
+macro_rules! get {
+	($($get:ident, $get_le:ident: $type:ty;)*) => {
+		$(
+			fn $get(&mut self) -> $type {
+				match self.byte_order {
+					ByteOrder::MostSignificantFirst => self.inner.$get(),
+					ByteOrder::LeastSignificantFirst => self.inner.$get_le(),
+				}
+			}
+		)*
+	};
+}
+
+// SAFETY: every method is forwarded to the wrapped writer, which upholds the
+// requirements of `BufMut` itself.
+unsafe impl<W: BufMut> BufMut for OrderedWriter<W> {
+	fn remaining_mut(&self) -> usize {
+		self.inner.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		// SAFETY: the caller upholds the same requirements for the wrapped
+		// writer.
+		unsafe { self.inner.advance_mut(cnt) }
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.inner.chunk_mut()
+	}
+
+	fn put_slice(&mut self, src: &[u8]) {
+		self.inner.put_slice(src);
+	}
+
+	fn put_bytes(&mut self, val: u8, cnt: usize) {
+		self.inner.put_bytes(val, cnt);
+	}
+
+	put! {
+		put_u16, put_u16_le: u16;
+		put_i16, put_i16_le: i16;
+		put_u32, put_u32_le: u32;
+		put_i32, put_i32_le: i32;
+		put_u64, put_u64_le: u64;
+		put_i64, put_i64_le: i64;
+		put_f32, put_f32_le: f32;
+		put_f64, put_f64_le: f64;
+	}
+}
+
+impl<R: Buf> Buf for OrderedReader<R> {
+	fn remaining(&self) -> usize {
+		self.inner.remaining()
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.inner.chunk()
+	}
+
+	fn advance(&mut self, cnt: usize) {
+		self.inner.advance(cnt);
+	}
+
+	get! {
+		get_u16, get_u16_le: u16;
+		get_i16, get_i16_le: i16;
+		get_u32, get_u32_le: u32;
+		get_i32, get_i32_le: i32;
+		get_u64, get_u64_le: u64;
+		get_i64, get_i64_le: i64;
+		get_f32, get_f32_le: f32;
+		get_f64, get_f64_le: f64;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cornflakes::{Readable, Writable};
+
+	use super::*;
+
+	use crate::x11::{
+		atoms,
+		read::Header,
+		requests::{ChangeProperty, PropertyData, PropertyMode, PropertyValue},
+		ResId, Window,
+	};
+
+	const BOTH: [ByteOrder; 2] = [
+		ByteOrder::MostSignificantFirst,
+		ByteOrder::LeastSignificantFirst,
+	];
+
+	fn change_property(data: PropertyData) -> ChangeProperty {
+		ChangeProperty {
+			mode: PropertyMode::Replace,
+			target: Window::new(0x0040_0001),
+			property: atoms::WM_NAME,
+			value: PropertyValue {
+				r#type: atoms::CARDINAL,
+				data,
+			},
+		}
+	}
+
+	#[test]
+	fn values_are_written_in_byte_order() {
+		let mut msb = vec![];
+		let mut lsb = vec![];
+
+		for (byte_order, bytes) in [
+			(ByteOrder::MostSignificantFirst, &mut msb),
+			(ByteOrder::LeastSignificantFirst, &mut lsb),
+		] {
+			let writer = &mut byte_order.writer(bytes);
+
+			0x12_u8.write_to(writer).unwrap();
+			0x3456_u16.write_to(writer).unwrap();
+			0x789a_bcde_u32.write_to(writer).unwrap();
+		}
+
+		assert_eq!(msb, [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde]);
+		assert_eq!(lsb, [0x12, 0x56, 0x34, 0xde, 0xbc, 0x9a, 0x78]);
+	}
+
+	#[test]
+	fn values_are_read_in_byte_order() {
+		let reader = &mut ByteOrder::LeastSignificantFirst.reader(&[0x56, 0x34, 0x12, 0x00][..]);
+
+		assert_eq!(u16::read_from(reader).unwrap(), 0x3456);
+		assert_eq!(i16::read_from(reader).unwrap(), 0x0012);
+		assert_eq!(reader.remaining(), 0);
+	}
+
+	#[test]
+	fn byte_orders_are_opposite() {
+		let native = ByteOrder::native();
+
+		assert!(native.is_native());
+		assert!(!native.opposite().is_native());
+		assert_eq!(native.opposite().opposite(), native);
+
+		for byte_order in BOTH {
+			assert_eq!(
+				ByteOrder::from_setup_byte(byte_order.setup_byte()),
+				Some(byte_order)
+			);
+		}
+	}
+
+	#[test]
+	fn requests_are_written_in_byte_order() {
+		let request = change_property(PropertyData::Format16(vec![0x0102, 0x0304]));
+		let mut bytes = [vec![], vec![]];
+
+		for (byte_order, bytes) in BOTH.into_iter().zip(&mut bytes) {
+			request.write_to(&mut byte_order.writer(bytes)).unwrap();
+		}
+
+		let [msb, lsb] = bytes;
+
+		// The opcode, mode, and format are single bytes, and are not swapped.
+		assert_eq!(msb[..2], lsb[..2]);
+		assert_eq!(msb[16], 16);
+		assert_eq!(lsb[16], 16);
+
+		// The length, window, and data are.
+		assert_eq!(msb[2..4], [0, 7]);
+		assert_eq!(lsb[2..4], [7, 0]);
+		assert_eq!(msb[4..8], [0x00, 0x40, 0x00, 0x01]);
+		assert_eq!(lsb[4..8], [0x01, 0x00, 0x40, 0x00]);
+		assert_eq!(msb[24..28], [0x01, 0x02, 0x03, 0x04]);
+		assert_eq!(lsb[24..28], [0x02, 0x01, 0x04, 0x03]);
+	}
+
+	#[test]
+	fn requests_round_trip_in_both_byte_orders() {
+		for data in [
+			PropertyData::Format8(b"xterm".to_vec()),
+			PropertyData::Format16(vec![0x0102, 0xfffe]),
+			PropertyData::Format32(vec![0x0102_0304, 0xdead_beef]),
+		] {
+			let request = change_property(data);
+
+			for byte_order in BOTH {
+				let mut bytes = vec![];
+				request
+					.write_to(&mut byte_order.writer(&mut bytes))
+					.unwrap();
+
+				let reader = &mut byte_order.reader(&bytes[1..]);
+
+				assert_eq!(ChangeProperty::read_from(reader).unwrap(), request);
+				assert_eq!(reader.remaining(), 0);
+			}
+		}
+	}
+
+	#[test]
+	fn headers_are_parsed_in_byte_order() {
+		let request = change_property(PropertyData::Format8(b"xterm".to_vec()));
+
+		for byte_order in BOTH {
+			let mut bytes = vec![];
+			request
+				.write_to(&mut byte_order.writer(&mut bytes))
+				.unwrap();
+
+			let header = Header::parse_request(byte_order.reader(&bytes[..])).unwrap();
+			assert_eq!(header.message_len(), bytes.len());
+		}
+
+		let reply = ByteOrder::LeastSignificantFirst.reader(&[1, 8, 42, 0, 2, 0, 0, 0][..]);
+		assert_eq!(
+			Header::parse(reply).unwrap(),
+			Header::Reply {
+				metabyte: 8,
+				sequence: 42,
+				length: 2
+			}
+		);
+	}
+}
@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::io;
+
+use bytes::BytesMut;
+use cornflakes::{Writable, WriteError};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::x11::{
+	byte_order::ByteOrder,
+	framing::{self, ServerMessageKind, MESSAGE_SIZE},
+};
+
+/// A `tokio-util` codec which splits the bytes sent by the X server into
+/// whole [frames], and writes messages to the X server.
+///
+/// Each frame is an error, reply, or event, found with the same rules as
+/// [`framing::message_len`]: the first [`MESSAGE_SIZE`] bytes are awaited,
+/// then, for replies and `GenericEvent`s, the number of extra bytes given by
+/// their length. Frames are not otherwise read, so they can be routed by
+/// their [kind] before they are decoded.
+///
+/// # Examples
+/// ```ignore
+/// let (reader, writer) = stream.into_split();
+///
+/// let mut frames = FramedRead::new(reader, X11Codec::new(byte_order));
+/// let mut requests = FramedWrite::new(writer, X11Codec::new(byte_order));
+///
+/// requests.send(GetInputFocus).await?;
+///
+/// while let Some(frame) = frames.next().await {
+///     let frame = frame?;
+///
+///     match frame.kind {
+///         ServerMessageKind::Reply => replies.decode(&frame.bytes),
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// [frames]: Frame
+/// [kind]: Frame::kind
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct X11Codec {
+	byte_order: ByteOrder,
+}
+
+/// A whole message sent by the X server, as split by an [`X11Codec`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Frame {
+	/// The kind of message, as given by its first byte.
+	pub kind: ServerMessageKind,
+	/// The bytes of the message, including its first byte.
+	pub bytes: BytesMut,
+}
+
+impl X11Codec {
+	/// Creates a new [`X11Codec`] for a connection using the given
+	/// `byte_order`.
+	#[must_use]
+	pub const fn new(byte_order: ByteOrder) -> Self {
+		Self { byte_order }
+	}
+
+	/// The byte order of the connection.
+	#[must_use]
+	pub const fn byte_order(&self) -> ByteOrder {
+		self.byte_order
+	}
+}
+
+impl Decoder for X11Codec {
+	type Item = Frame;
+	type Error = io::Error;
+
+	fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Frame>> {
+		let Some(header) = src.get(..MESSAGE_SIZE) else {
+			src.reserve(MESSAGE_SIZE - src.len());
+
+			return Ok(None);
+		};
+		let header: &[u8; MESSAGE_SIZE] =
+			header.try_into().expect("`header` is `MESSAGE_SIZE` bytes");
+
+		let kind = framing::classify(header[0]);
+		let len = framing::message_len(header, self.byte_order);
+
+		if src.len() < len {
+			src.reserve(len - src.len());
+
+			return Ok(None);
+		}
+
+		Ok(Some(Frame {
+			kind,
+			bytes: src.split_to(len),
+		}))
+	}
+}
+
+impl<T: Writable> Encoder<T> for X11Codec {
+	type Error = io::Error;
+
+	fn encode(&mut self, message: T, dst: &mut BytesMut) -> io::Result<()> {
+		// The message is written to a separate buffer so that nothing is left
+		// in `dst` if it can't be written.
+		let mut bytes = BytesMut::new();
+
+		message
+			.write_to(&mut self.byte_order.writer(&mut bytes))
+			.map_err(|error| {
+				let error = match error {
+					WriteError::Other(error) => error.to_string(),
+					error => format!("{error:?}"),
+				};
+
+				io::Error::new(
+					io::ErrorKind::InvalidInput,
+					format!("failed to write message: {error}"),
+				)
+			})?;
+
+		dst.unsplit(bytes);
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn frames_are_split_once_complete() {
+		let mut codec = X11Codec::new(ByteOrder::MostSignificantFirst);
+
+		let mut reply = vec![0; MESSAGE_SIZE + 4];
+		reply[0] = framing::REPLY;
+		reply[7] = 1;
+
+		let mut error = vec![0; MESSAGE_SIZE];
+		error[1] = 3;
+
+		let mut src = BytesMut::from(&reply[..20]);
+		assert_eq!(codec.decode(&mut src).unwrap(), None);
+
+		src.extend_from_slice(&reply[20..34]);
+		assert_eq!(codec.decode(&mut src).unwrap(), None);
+
+		src.extend_from_slice(&reply[34..]);
+		src.extend_from_slice(&error);
+
+		let frame = codec.decode(&mut src).unwrap().unwrap();
+		assert_eq!(frame.kind, ServerMessageKind::Reply);
+		assert_eq!(frame.bytes, reply);
+
+		let frame = codec.decode(&mut src).unwrap().unwrap();
+		assert_eq!(frame.kind, ServerMessageKind::Error);
+		assert_eq!(frame.bytes, error);
+
+		assert!(src.is_empty());
+		assert_eq!(codec.decode(&mut src).unwrap(), None);
+	}
+
+	#[test]
+	fn messages_are_encoded_in_the_byte_order() {
+		let mut codec = X11Codec::new(ByteOrder::LeastSignificantFirst);
+		let mut dst = BytesMut::new();
+
+		codec.encode(0x0102_0304_u32, &mut dst).unwrap();
+		codec.encode(0x0506_u16, &mut dst).unwrap();
+
+		assert_eq!(dst, [4, 3, 2, 1, 6, 5][..]);
+	}
+}
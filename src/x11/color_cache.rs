@@ -0,0 +1,303 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use crate::x11::{
+	requests::{AllocColor, AllocColorReply, FreeColors},
+	Colormap, Rgb16,
+};
+
+/// A cache of the pixel values allocated for colors in a [`Colormap`] with
+/// [`AllocColor`] requests, counting how many times each color is used.
+///
+/// Allocating a colormap cell requires a round trip to the X server, and
+/// colormaps on displays with few bits per pixel only have a few cells. This
+/// cache allocates each color once, no matter how many times it is
+/// [acquired], and only frees its cell once it has been [released] as many
+/// times. Freed cells are collected so that they can be freed with a single
+/// [`FreeColors`] request.
+///
+/// Colors are cached by the color that was requested, not the color the X
+/// server actually allocated, as several requested colors may be allocated
+/// the same cell.
+///
+/// # Examples
+/// ```ignore
+/// let mut cache = ColorCache::new(colormap);
+///
+/// let pixel = match cache.acquire(color) {
+///     Acquired::Cached(pixel) => pixel,
+///
+///     Acquired::Alloc(request) => {
+///         let reply = connection.send_and_receive(request)?;
+///
+///         cache.insert(color, &reply)
+///     }
+/// };
+///
+/// // Later, once the color is no longer used...
+/// cache.release(color);
+///
+/// let freed = cache.take_freed();
+///
+/// if !freed.is_empty() {
+///     connection.send(freed.request())?;
+/// }
+/// ```
+///
+/// [acquired]: ColorCache::acquire
+/// [released]: ColorCache::release
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ColorCache {
+	colormap: Colormap,
+	entries: HashMap<Rgb16, Entry>,
+	/// The pixel values of cells which are no longer used and have not yet
+	/// been freed.
+	freed: Vec<u32>,
+}
+
+/// A color allocated in a [`ColorCache`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Entry {
+	pixel: u32,
+	/// The number of times the color has been acquired and not released.
+	refs: usize,
+}
+
+/// The result of [acquiring] a color from a [`ColorCache`].
+///
+/// [acquiring]: ColorCache::acquire
+pub enum Acquired {
+	/// The color is already allocated, with this pixel value.
+	Cached(u32),
+	/// The color is not yet allocated: this request must be sent and its
+	/// reply [inserted] into the cache.
+	///
+	/// [inserted]: ColorCache::insert
+	Alloc(AllocColor),
+}
+
+/// The pixel values of cells freed from a [`ColorCache`], which are freed
+/// together with a single [`FreeColors`] request.
+///
+/// This is created with [`ColorCache::take_freed`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FreedColors {
+	colormap: Colormap,
+	pixels: Vec<u32>,
+}
+
+impl ColorCache {
+	/// Creates a new, empty [`ColorCache`] for colors allocated in the given
+	/// `colormap`.
+	#[must_use]
+	pub fn new(colormap: Colormap) -> Self {
+		Self {
+			colormap,
+			entries: HashMap::new(),
+			freed: Vec::new(),
+		}
+	}
+
+	/// The colormap in which this cache's colors are allocated.
+	#[must_use]
+	pub const fn colormap(&self) -> Colormap {
+		self.colormap
+	}
+
+	/// Acquires the given `color`, returning its pixel value if it has
+	/// already been allocated, or otherwise the [`AllocColor`] request which
+	/// allocates it.
+	///
+	/// If a request is returned, the color has not been acquired until the
+	/// request's reply is [inserted]. If the request fails, nothing needs to
+	/// be done.
+	///
+	/// [inserted]: ColorCache::insert
+	pub fn acquire(&mut self, color: Rgb16) -> Acquired {
+		if let Some(entry) = self.entries.get_mut(&color) {
+			entry.refs += 1;
+
+			return Acquired::Cached(entry.pixel);
+		}
+
+		Acquired::Alloc(AllocColor {
+			colormap: self.colormap,
+			color,
+		})
+	}
+
+	/// Records the `reply` to the [`AllocColor`] request for the given
+	/// requested `color`, acquiring the color and returning its pixel value.
+	///
+	/// If the color was already allocated by the time the `reply` arrived,
+	/// such as if it was acquired again before the reply was received, the
+	/// existing pixel value is returned and the new allocation is queued to be
+	/// freed.
+	pub fn insert(&mut self, color: Rgb16, reply: &AllocColorReply) -> u32 {
+		if let Some(entry) = self.entries.get_mut(&color) {
+			entry.refs += 1;
+			self.freed.push(reply.pixel);
+
+			return entry.pixel;
+		}
+
+		self.entries.insert(
+			color,
+			Entry {
+				pixel: reply.pixel,
+				refs: 1,
+			},
+		);
+
+		reply.pixel
+	}
+
+	/// Returns the pixel value of the given `color`, if it is allocated,
+	/// without acquiring it.
+	#[must_use]
+	pub fn get(&self, color: Rgb16) -> Option<u32> {
+		self.entries.get(&color).map(|entry| entry.pixel)
+	}
+
+	/// Releases the given `color`, which was acquired earlier.
+	///
+	/// Once the color has been released as many times as it was acquired, its
+	/// cell is queued to be freed with [`take_freed`]. Returns whether the
+	/// color was allocated.
+	///
+	/// [`take_freed`]: ColorCache::take_freed
+	pub fn release(&mut self, color: Rgb16) -> bool {
+		let Some(entry) = self.entries.get_mut(&color) else {
+			return false;
+		};
+
+		entry.refs -= 1;
+
+		if entry.refs == 0 {
+			self.freed.push(entry.pixel);
+			self.entries.remove(&color);
+		}
+
+		true
+	}
+
+	/// Releases every color in this cache, regardless of how many times it
+	/// was acquired, queuing all of their cells to be freed.
+	pub fn release_all(&mut self) {
+		self.freed
+			.extend(self.entries.drain().map(|(_, entry)| entry.pixel));
+	}
+
+	/// The number of colors which are allocated.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Returns whether no colors are allocated.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Takes the pixel values of the cells which have been released since
+	/// they were last taken, so that they can be freed.
+	pub fn take_freed(&mut self) -> FreedColors {
+		FreedColors {
+			colormap: self.colormap,
+			pixels: std::mem::take(&mut self.freed),
+		}
+	}
+}
+
+impl FreedColors {
+	/// The pixel values of the freed cells.
+	#[must_use]
+	pub fn pixels(&self) -> &[u32] {
+		&self.pixels
+	}
+
+	/// Returns whether there are no cells to free, in which case there is no
+	/// need to send a [`FreeColors`] request.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pixels.is_empty()
+	}
+
+	/// The [`FreeColors`] request which frees these cells.
+	#[must_use]
+	pub fn request(&self) -> FreeColors<'_> {
+		FreeColors {
+			colormap: self.colormap,
+			plane_mask: 0,
+			pixels: &self.pixels,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const RED: Rgb16 = Rgb16::new(0xffff, 0, 0);
+	const BLUE: Rgb16 = Rgb16::new(0, 0, 0xffff);
+
+	fn reply(pixel: u32) -> AllocColorReply {
+		AllocColorReply {
+			_sequence_: 0,
+			color: RED,
+			pixel,
+		}
+	}
+
+	#[test]
+	fn colors_are_allocated_once_and_freed_when_released() {
+		let mut cache = ColorCache::new(Colormap::new(0x20));
+
+		assert!(matches!(
+			cache.acquire(RED),
+			Acquired::Alloc(AllocColor { color: RED, .. })
+		));
+		assert_eq!(cache.insert(RED, &reply(7)), 7);
+
+		assert!(matches!(cache.acquire(RED), Acquired::Cached(7)));
+		assert_eq!(cache.get(RED), Some(7));
+
+		assert!(cache.release(RED));
+		assert!(cache.take_freed().is_empty());
+
+		assert!(cache.release(RED));
+		assert_eq!(cache.get(RED), None);
+		assert!(!cache.release(RED));
+
+		let freed = cache.take_freed();
+		assert_eq!(freed.pixels(), [7]);
+		assert_eq!(freed.request().colormap, Colormap::new(0x20));
+		assert!(cache.take_freed().is_empty());
+	}
+
+	#[test]
+	fn duplicate_allocations_are_freed() {
+		let mut cache = ColorCache::new(Colormap::new(0x20));
+
+		// Both acquisitions are sent before either reply is received.
+		assert!(matches!(cache.acquire(BLUE), Acquired::Alloc(_)));
+		assert!(matches!(cache.acquire(BLUE), Acquired::Alloc(_)));
+
+		assert_eq!(cache.insert(BLUE, &reply(3)), 3);
+		assert_eq!(cache.insert(BLUE, &reply(3)), 3);
+		assert_eq!(cache.take_freed().pixels(), [3]);
+
+		cache.acquire(RED);
+		cache.insert(RED, &reply(4));
+		cache.release_all();
+
+		let mut freed = cache.take_freed().pixels().to_vec();
+		freed.sort_unstable();
+		assert_eq!(freed, [3, 4]);
+		assert!(cache.is_empty());
+	}
+}
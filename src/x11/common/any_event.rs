@@ -0,0 +1,171 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	byte_order::ByteOrder,
+	read::{UnexpectedEndOfData, UnexpectedEventCode},
+	traits::Event,
+	BuildError,
+};
+
+/// An event of any type, as the 32 bytes in which it is sent.
+///
+/// This is how an event is carried in a [`SendEvent`] request: the request
+/// does not need to know the event's type, only that it fits in 32 bytes. An
+/// [`AnyEvent`] can be [created] from any core event, and [decoded] back into
+/// a particular type of event once its [`code`] is known.
+///
+/// Unlike most values, an [`AnyEvent`] is encoded in a particular
+/// [`ByteOrder`] when it is created, rather than when it is written: this
+/// must be the byte order of the connection on which it is sent.
+///
+/// # Examples
+/// ```ignore
+/// let event = AnyEvent::new(&selection_notify, connection.byte_order())?;
+///
+/// connection.send(SendEvent {
+///     propagate: false,
+///     destination: Destination::Specific(selection_notify.requestor),
+///     event_mask: EventMask::empty(),
+///     event,
+/// })?;
+/// ```
+///
+/// [`SendEvent`]: crate::x11::requests::SendEvent
+/// [created]: AnyEvent::new
+/// [decoded]: AnyEvent::decode
+/// [`code`]: AnyEvent::code
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AnyEvent([u8; Self::SIZE]);
+
+impl AnyEvent {
+	/// The size of every core event, in bytes.
+	pub const SIZE: usize = 32;
+
+	/// The bit which the X server sets in the code of an event which was sent
+	/// with a [`SendEvent`] request.
+	///
+	/// [`SendEvent`]: crate::x11::requests::SendEvent
+	pub const SENT_FLAG: u8 = 0x80;
+
+	/// Encodes the given `event` in the given `byte_order`, padding it to
+	/// [`SIZE`] bytes.
+	///
+	/// # Errors
+	/// Returns a [`BuildError::TooLong`] if the `event` is longer than
+	/// [`SIZE`] bytes, such as a generic event, or any error returned while
+	/// writing the `event`.
+	///
+	/// [`SIZE`]: AnyEvent::SIZE
+	pub fn new<E>(event: &E, byte_order: ByteOrder) -> Result<Self, WriteError>
+	where
+		E: Event + Writable,
+	{
+		let mut bytes = Vec::with_capacity(Self::SIZE);
+		event.write_to(&mut byte_order.writer(&mut bytes))?;
+
+		if bytes.len() > Self::SIZE {
+			return Err(BuildError::TooLong {
+				name: "event",
+				max: Self::SIZE,
+				len: bytes.len(),
+			}
+			.into());
+		}
+
+		let mut event = [0; Self::SIZE];
+		event[..bytes.len()].copy_from_slice(&bytes);
+
+		Ok(Self(event))
+	}
+
+	/// Creates an [`AnyEvent`] from the 32 bytes of an event.
+	#[must_use]
+	pub const fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+		Self(bytes)
+	}
+
+	/// The 32 bytes of this event.
+	#[must_use]
+	pub const fn as_bytes(&self) -> &[u8; Self::SIZE] {
+		&self.0
+	}
+
+	/// The code of this event, without the [`SENT_FLAG`].
+	///
+	/// [`SENT_FLAG`]: AnyEvent::SENT_FLAG
+	#[must_use]
+	pub const fn code(&self) -> u8 {
+		self.0[0] & !Self::SENT_FLAG
+	}
+
+	/// Returns whether this event was sent by a client with a [`SendEvent`]
+	/// request, rather than generated by the X server.
+	///
+	/// [`SendEvent`]: crate::x11::requests::SendEvent
+	#[must_use]
+	pub const fn is_sent(&self) -> bool {
+		self.0[0] & Self::SENT_FLAG != 0
+	}
+
+	/// Returns whether this is an event of type `E`.
+	#[must_use]
+	pub fn is<E: Event>(&self) -> bool {
+		self.code() == E::code()
+	}
+
+	/// Decodes this event as an event of type `E`, which was encoded in the
+	/// given `byte_order`.
+	///
+	/// # Errors
+	/// Returns an [`UnexpectedEventCode`] if this is not an event of type
+	/// `E`, or any error returned while reading the event.
+	pub fn decode<E>(&self, byte_order: ByteOrder) -> Result<E, ReadError>
+	where
+		E: Event + Readable,
+	{
+		if !self.is::<E>() {
+			return Err(ReadError::Other(Box::new(UnexpectedEventCode {
+				expected: E::code(),
+				found: self.code(),
+			})));
+		}
+
+		// Events are read after their code.
+		E::read_from(&mut byte_order.reader(&self.0[1..]))
+	}
+}
+
+impl ByteSize for AnyEvent {
+	fn byte_size(&self) -> usize {
+		Self::SIZE
+	}
+}
+
+impl Writable for AnyEvent {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_slice(&self.0);
+
+		Ok(())
+	}
+}
+
+impl Readable for AnyEvent {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < Self::SIZE {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: Self::SIZE,
+				found: reader.remaining(),
+			})));
+		}
+
+		let mut event = [0; Self::SIZE];
+		reader.copy_to_slice(&mut event);
+
+		Ok(Self(event))
+	}
+}
@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use cornflakes::WriteError;
+
+/// The error returned when a value put together by a builder can't be
+/// written as it is, such as a [`KeyboardControl`] with a bell volume above
+/// `100`.
+///
+/// When a value is written, this is returned in a [`WriteError::Other`],
+/// from which it can be downcast:
+/// ```ignore
+/// match connection.send(request) {
+///     Err(WriteError::Other(error)) => match error.downcast_ref::<BuildError>() {
+///         Some(BuildError::OutOfRange { name, .. }) => {
+///             eprintln!("the {name} setting is out of range");
+///         }
+///
+///         // ...
+///     },
+///
+///     // ...
+/// }
+/// ```
+///
+/// [`WriteError::Other`]: cornflakes::WriteError::Other
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BuildError {
+	/// There are more entries in a list than can be written.
+	TooManyEntries {
+		/// What the list is a list of.
+		name: &'static str,
+		/// The maximum number of entries.
+		max: usize,
+		/// The number of entries which were given.
+		found: usize,
+	},
+
+	/// A value is outside of the range which the X server accepts for it.
+	///
+	/// The X server would generate a `Value` error for this value.
+	OutOfRange {
+		/// What the value is.
+		name: &'static str,
+		/// The value which was given.
+		value: i64,
+		/// The minimum value, inclusive.
+		min: i64,
+		/// The maximum value, inclusive.
+		max: i64,
+	},
+
+	/// A string is longer than can be written.
+	TooLong {
+		/// What the string is.
+		name: &'static str,
+		/// The maximum length.
+		max: usize,
+		/// The length of the string which was given.
+		len: usize,
+	},
+}
+
+impl BuildError {
+	/// Returns a [`BuildError::OutOfRange`] if the `value` is not within
+	/// `min..=max`.
+	pub(crate) fn check_range(
+		name: &'static str,
+		value: impl Into<i64>,
+		min: impl Into<i64>,
+		max: impl Into<i64>,
+	) -> Result<(), Self> {
+		let (value, min, max) = (value.into(), min.into(), max.into());
+
+		if (min..=max).contains(&value) {
+			Ok(())
+		} else {
+			Err(Self::OutOfRange {
+				name,
+				value,
+				min,
+				max,
+			})
+		}
+	}
+}
+
+impl fmt::Display for BuildError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::TooManyEntries { name, max, found } => {
+				write!(
+					f,
+					"too many {name}: {found} were given, but the maximum is {max}"
+				)
+			}
+
+			Self::OutOfRange {
+				name,
+				value,
+				min,
+				max,
+			} => write!(f, "{name} of {value} is out of the range {min} to {max}"),
+
+			Self::TooLong { name, max, len } => {
+				write!(
+					f,
+					"{name} is too long: it is {len} long, but the maximum is {max}"
+				)
+			}
+		}
+	}
+}
+
+impl Error for BuildError {}
+
+impl From<BuildError> for WriteError {
+	fn from(error: BuildError) -> Self {
+		Self::Other(Box::new(error))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ranges_are_inclusive() {
+		assert_eq!(
+			BuildError::check_range("bell percent", 100_u8, 0, 100),
+			Ok(())
+		);
+		assert_eq!(
+			BuildError::check_range("bell percent", 101_u8, 0, 100),
+			Err(BuildError::OutOfRange {
+				name: "bell percent",
+				value: 101,
+				min: 0,
+				max: 100,
+			})
+		);
+	}
+}
@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use crate::x11::ColorChannelMask;
+
+define! {
+	/// A color with 16 bits for each channel, as used in colormaps.
+	///
+	/// The X server scales each channel to the number of bits its colormaps
+	/// actually support, so `0xffff` is always the full intensity of a
+	/// channel.
+	#[doc(alias = "RGB")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, StaticByteSize, ByteSize)]
+	pub struct Rgb16 {
+		/// The intensity of the red channel.
+		pub red: u16,
+		/// The intensity of the green channel.
+		pub green: u16,
+		/// The intensity of the blue channel.
+		pub blue: u16,
+	}
+
+	/// The color to store in a colormap cell in a [`StoreColors`] request.
+	///
+	/// [`StoreColors`]: crate::x11::requests::StoreColors
+	#[doc(alias = "COLORITEM")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct ColorItem {
+		/// The pixel value of the colormap cell.
+		pub pixel: u32,
+		/// The color to store in the cell.
+		pub color: Rgb16,
+		/// Which of the channels of the `color` are stored; the others are
+		/// left unchanged.
+		pub channels: ColorChannelMask,
+		(),
+	}
+}
+
+/// A color with 8 bits for each channel, as commonly used outside of the X
+/// protocol.
+///
+/// This is converted to an [`Rgb16`] to be sent to the X server.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Rgb {
+	/// The intensity of the red channel.
+	pub red: u8,
+	/// The intensity of the green channel.
+	pub green: u8,
+	/// The intensity of the blue channel.
+	pub blue: u8,
+}
+
+impl Rgb16 {
+	/// Black: every channel at zero intensity.
+	pub const BLACK: Self = Self::new(0, 0, 0);
+	/// White: every channel at full intensity.
+	pub const WHITE: Self = Self::new(0xffff, 0xffff, 0xffff);
+
+	/// Creates a new [`Rgb16`] with the given channels.
+	#[must_use]
+	pub const fn new(red: u16, green: u16, blue: u16) -> Self {
+		Self { red, green, blue }
+	}
+
+	/// Converts this color to an [`Rgb`], rounding each channel to the
+	/// nearest 8-bit intensity.
+	#[must_use]
+	pub const fn to_rgb(self) -> Rgb {
+		/// Scales a 16-bit channel to 8 bits.
+		#[allow(clippy::cast_possible_truncation)]
+		const fn scale(channel: u16) -> u8 {
+			// The result is at most `0xff`.
+			((channel as u32 * 0xff + 0x7fff) / 0xffff) as u8
+		}
+
+		Rgb::new(scale(self.red), scale(self.green), scale(self.blue))
+	}
+}
+
+impl Rgb {
+	/// Creates a new [`Rgb`] with the given channels.
+	#[must_use]
+	pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+		Self { red, green, blue }
+	}
+
+	/// Creates a new [`Rgb`] from a hex code, such as `0xff8000`.
+	///
+	/// Bits above the lower 24 are ignored.
+	#[must_use]
+	pub const fn from_hex(hex: u32) -> Self {
+		let [_, red, green, blue] = hex.to_be_bytes();
+
+		Self::new(red, green, blue)
+	}
+
+	/// Converts this color to an [`Rgb16`], scaling each channel so that full
+	/// intensity remains full intensity.
+	#[must_use]
+	pub const fn to_rgb16(self) -> Rgb16 {
+		/// Scales an 8-bit channel to 16 bits.
+		const fn scale(channel: u8) -> u16 {
+			channel as u16 * 0x0101
+		}
+
+		Rgb16::new(scale(self.red), scale(self.green), scale(self.blue))
+	}
+}
+
+impl From<Rgb> for Rgb16 {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_rgb16()
+	}
+}
+
+impl ColorItem {
+	/// Creates a new [`ColorItem`] which stores every channel of the `color`
+	/// in the cell with the given `pixel` value.
+	#[must_use]
+	pub const fn new(pixel: u32, color: Rgb16) -> Self {
+		Self {
+			pixel,
+			color,
+			channels: ColorChannelMask::all(),
+		}
+	}
+
+	/// Only stores the given `channels` of the color.
+	#[must_use]
+	pub const fn with_channels(mut self, channels: ColorChannelMask) -> Self {
+		self.channels = channels;
+		self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn channels_are_scaled_between_8_and_16_bits() {
+		let orange = Rgb::from_hex(0xff_80_00);
+
+		assert_eq!(orange, Rgb::new(0xff, 0x80, 0x00));
+		assert_eq!(Rgb16::from(orange), Rgb16::new(0xffff, 0x8080, 0x0000));
+		assert_eq!(Rgb16::from(orange).to_rgb(), orange);
+
+		// 16-bit channels are rounded to the nearest 8-bit intensity.
+		assert_eq!(
+			Rgb16::new(0x8101, 0x8000, 0x7fc0).to_rgb(),
+			Rgb::new(0x81, 0x80, 0x7f)
+		);
+		assert_eq!(Rgb16::WHITE.to_rgb(), Rgb::new(0xff, 0xff, 0xff));
+	}
+}
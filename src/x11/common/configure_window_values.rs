@@ -0,0 +1,282 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	read::{read_value_list, skip_padding, UnexpectedEndOfData},
+	*,
+};
+
+/// The values changed by a [`ConfigureWindow`] request, each of which is
+/// only changed if it is [`Some`].
+///
+/// The values are written as a [`ConfigureWindowMask`] and 2 unused bytes,
+/// followed by one 4-byte value for each value that is [`Some`], in the order
+/// of their bits in the mask.
+///
+/// # Examples
+/// ```ignore
+/// connection.send(ConfigureWindow {
+///     target: window,
+///     values: ConfigureWindowValues::new()
+///         .position(0, 0)
+///         .restack(StackMode::Above, None),
+/// })?;
+/// ```
+///
+/// [`ConfigureWindow`]: super::requests::ConfigureWindow
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ConfigureWindowValues {
+	/// The x-coordinate of the window's top-left corner, relative to its
+	/// parent, outside of its border.
+	pub x: Option<i16>,
+	/// The y-coordinate of the window's top-left corner, relative to its
+	/// parent, outside of its border.
+	pub y: Option<i16>,
+	/// The width of the window, not including its border.
+	pub width: Option<u16>,
+	/// The height of the window, not including its border.
+	pub height: Option<u16>,
+	/// The width of the window's border.
+	pub border_width: Option<u16>,
+	/// The sibling which the window is restacked relative to.
+	///
+	/// This may only be given with a [`stack_mode`].
+	///
+	/// [`stack_mode`]: ConfigureWindowValues::stack_mode
+	pub sibling: Option<Window>,
+	/// How the window is restacked, relative to the [`sibling`] if one is
+	/// given, or otherwise relative to all of its siblings.
+	///
+	/// [`sibling`]: ConfigureWindowValues::sibling
+	pub stack_mode: Option<StackMode>,
+}
+
+impl ConfigureWindowValues {
+	/// Creates a new [`ConfigureWindowValues`] with no values given.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			x: None,
+			y: None,
+			width: None,
+			height: None,
+			border_width: None,
+			sibling: None,
+			stack_mode: None,
+		}
+	}
+
+	/// The [`ConfigureWindowMask`] of the values which are given.
+	#[must_use]
+	pub fn mask(&self) -> ConfigureWindowMask {
+		let mut mask = ConfigureWindowMask::empty();
+
+		mask.set(ConfigureWindowMask::X, self.x.is_some());
+		mask.set(ConfigureWindowMask::Y, self.y.is_some());
+		mask.set(ConfigureWindowMask::WIDTH, self.width.is_some());
+		mask.set(ConfigureWindowMask::HEIGHT, self.height.is_some());
+		mask.set(
+			ConfigureWindowMask::BORDER_WIDTH,
+			self.border_width.is_some(),
+		);
+		mask.set(ConfigureWindowMask::SIBLING, self.sibling.is_some());
+		mask.set(ConfigureWindowMask::STACK_MODE, self.stack_mode.is_some());
+
+		mask
+	}
+
+	/// Returns whether no values are given.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.mask().is_empty()
+	}
+
+	/// Moves the window to the given coordinates, relative to its parent.
+	#[must_use]
+	pub const fn position(mut self, x: i16, y: i16) -> Self {
+		self.x = Some(x);
+		self.y = Some(y);
+
+		self
+	}
+
+	/// Resizes the window to the given dimensions, not including its border.
+	#[must_use]
+	pub const fn size(mut self, width: u16, height: u16) -> Self {
+		self.width = Some(width);
+		self.height = Some(height);
+
+		self
+	}
+
+	/// Sets the width of the window's border.
+	#[must_use]
+	pub const fn border_width(mut self, border_width: u16) -> Self {
+		self.border_width = Some(border_width);
+		self
+	}
+
+	/// Restacks the window with the given `stack_mode`, relative to the given
+	/// `sibling`, or to all of its siblings if it is [`None`].
+	#[must_use]
+	pub const fn restack(mut self, stack_mode: StackMode, sibling: Option<Window>) -> Self {
+		self.stack_mode = Some(stack_mode);
+		self.sibling = sibling;
+
+		self
+	}
+
+	/// The 4-byte values that are written, in the order of their bits in the
+	/// [`ConfigureWindowMask`].
+	fn values(&self) -> impl Iterator<Item = u32> {
+		// Coordinates are sign-extended to fill the value.
+		#[allow(clippy::cast_sign_loss)]
+		let coord = |coord: i16| i32::from(coord) as u32;
+
+		[
+			self.x.map(coord),
+			self.y.map(coord),
+			self.width.map(u32::from),
+			self.height.map(u32::from),
+			self.border_width.map(u32::from),
+			self.sibling.map(|sibling| sibling.res_id()),
+			self.stack_mode.map(|stack_mode| stack_mode as u32),
+		]
+		.into_iter()
+		.flatten()
+	}
+}
+
+impl ByteSize for ConfigureWindowValues {
+	fn byte_size(&self) -> usize {
+		// The mask and 2 unused bytes, followed by a 4-byte value for each
+		// value given.
+		4 + 4 * self.values().count()
+	}
+}
+
+impl Writable for ConfigureWindowValues {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u16(self.mask().bits());
+		writer.put_bytes(0, 2);
+
+		for value in self.values() {
+			writer.put_u32(value);
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for ConfigureWindowValues {
+	/// Reads a list of values which continues to the end of the `reader`, such
+	/// as at the end of a [`ConfigureWindow`] request.
+	///
+	/// Values for bits which are not defined in [`ConfigureWindowMask`] are
+	/// skipped.
+	///
+	/// # Errors
+	/// Returns a [`ValueListMismatch`] if the number of values does not match
+	/// the number of bits set in the mask.
+	///
+	/// [`ConfigureWindow`]: super::requests::ConfigureWindow
+	/// [`ValueListMismatch`]: crate::x11::read::ValueListMismatch
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 4 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 4,
+				found: reader.remaining(),
+			})));
+		}
+
+		let mask = reader.get_u16();
+		skip_padding(reader, 2)?;
+
+		let mut values = Self::new();
+		let raw_values = read_value_list(reader, u32::from(mask))?;
+
+		// Each value is matched to the next bit set in the mask.
+		let bits = (0..16).map(|bit| 1 << bit).filter(|bit| mask & bit != 0);
+
+		// Coordinates and dimensions are only the lower 16 bits of their
+		// values.
+		#[allow(clippy::cast_possible_truncation)]
+		for (bit, value) in bits.zip(raw_values) {
+			match ConfigureWindowMask::from_bits_truncate(bit) {
+				ConfigureWindowMask::X => values.x = Some(value as i16),
+				ConfigureWindowMask::Y => values.y = Some(value as i16),
+				ConfigureWindowMask::WIDTH => values.width = Some(value as u16),
+				ConfigureWindowMask::HEIGHT => values.height = Some(value as u16),
+				ConfigureWindowMask::BORDER_WIDTH => values.border_width = Some(value as u16),
+				ConfigureWindowMask::SIBLING => values.sibling = Some(Window::new(value)),
+
+				ConfigureWindowMask::STACK_MODE => {
+					values.stack_mode = Some(match value {
+						0 => StackMode::Above,
+						1 => StackMode::Below,
+						2 => StackMode::TopIf,
+						3 => StackMode::BottomIf,
+						4 => StackMode::Opposite,
+
+						other => {
+							return Err(ReadError::UnrecognizedDiscriminant(
+								u8::try_from(other).unwrap_or(u8::MAX),
+							))
+						}
+					});
+				}
+
+				_ => {}
+			}
+		}
+
+		Ok(values)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn values_are_written_in_mask_order() {
+		let values = ConfigureWindowValues::new()
+			.restack(StackMode::BottomIf, Some(Window::new(0x0040_0001)))
+			.position(-1, 2);
+
+		assert_eq!(
+			values.mask(),
+			ConfigureWindowMask::X
+				| ConfigureWindowMask::Y
+				| ConfigureWindowMask::SIBLING
+				| ConfigureWindowMask::STACK_MODE
+		);
+
+		let mut bytes = vec![];
+		values.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), values.byte_size());
+		assert_eq!(
+			bytes,
+			[
+				0x00, 0x63, 0x00, 0x00, // mask
+				0xff, 0xff, 0xff, 0xff, // x
+				0x00, 0x00, 0x00, 0x02, // y
+				0x00, 0x40, 0x00, 0x01, // sibling
+				0x00, 0x00, 0x00, 0x03, // stack mode
+			]
+		);
+
+		assert_eq!(
+			ConfigureWindowValues::read_from(&mut &bytes[..]).unwrap(),
+			values
+		);
+
+		// A sibling given without a value.
+		assert!(ConfigureWindowValues::read_from(&mut &bytes[..16]).is_err());
+	}
+}
@@ -0,0 +1,436 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+define! {
+	/// A fixed-point number with 16 integer bits and 16 fractional bits.
+	///
+	/// No message in the X protocol contains floating point numbers; non-integer
+	/// values, such as those used by the RENDER extension, are instead sent as
+	/// fixed-point numbers. This means that `Eq` and `Hash` can be derived for
+	/// every message.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Fixed(i32);
+
+	/// A fixed-point number with 32 integer bits and 32 fractional bits.
+	///
+	/// This is written as its signed integer part followed by its unsigned
+	/// fractional part.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct FixedPoint {
+		integral: i32,
+		fractional: u32,
+	}
+}
+
+/// How a floating point number is rounded when it is converted to a
+/// fixed-point number.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Rounding {
+	/// Rounds to the nearest fixed-point number, and away from zero when
+	/// halfway between two.
+	#[default]
+	Nearest,
+	/// Rounds towards negative infinity.
+	Floor,
+	/// Rounds towards positive infinity.
+	Ceil,
+	/// Rounds towards zero.
+	TowardZero,
+}
+
+impl Rounding {
+	const fn round(self, value: f64) -> f64 {
+		match self {
+			Self::Nearest => value.round(),
+			Self::Floor => value.floor(),
+			Self::Ceil => value.ceil(),
+			Self::TowardZero => value.trunc(),
+		}
+	}
+}
+
+impl Fixed {
+	/// The number of fractional bits in a [`Fixed`] number.
+	const FRACTIONAL_BITS: u32 = 16;
+
+	/// `0`.
+	pub const ZERO: Self = Self(0);
+	/// `1`.
+	pub const ONE: Self = Self(1 << Self::FRACTIONAL_BITS);
+	/// The smallest value that can be represented: `-32768`.
+	pub const MIN: Self = Self(i32::MIN);
+	/// The largest value that can be represented, just under `32768`.
+	pub const MAX: Self = Self(i32::MAX);
+
+	/// Creates a new [`Fixed`] number from its raw 16.16 representation.
+	#[must_use]
+	pub const fn from_raw(raw: i32) -> Self {
+		Self(raw)
+	}
+
+	/// Gets the raw 16.16 representation of the [`Fixed`] number.
+	#[must_use]
+	pub const fn raw(self) -> i32 {
+		self.0
+	}
+
+	/// Creates the [`Fixed`] number closest to the given `value`.
+	///
+	/// Values outside of the range of a [`Fixed`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	pub fn from_f64(value: f64) -> Self {
+		Self::from_f64_rounded(value, Rounding::Nearest)
+	}
+
+	/// Creates a [`Fixed`] number from the given `value`, rounded with the
+	/// given `rounding` if it can't be represented exactly.
+	///
+	/// Values outside of the range of a [`Fixed`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "float to int casts saturate, which is the intended behavior"
+	)]
+	pub fn from_f64_rounded(value: f64, rounding: Rounding) -> Self {
+		Self(rounding.round(value * f64::from(1 << Self::FRACTIONAL_BITS)) as i32)
+	}
+
+	/// Creates the [`Fixed`] number closest to the given `value`.
+	///
+	/// Values outside of the range of a [`Fixed`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	pub fn from_f32(value: f32) -> Self {
+		Self::from_f64(value.into())
+	}
+
+	/// Creates a [`Fixed`] number from the given `value`, rounded with the
+	/// given `rounding` if it can't be represented exactly.
+	///
+	/// Values outside of the range of a [`Fixed`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	pub fn from_f32_rounded(value: f32, rounding: Rounding) -> Self {
+		Self::from_f64_rounded(value.into(), rounding)
+	}
+
+	/// Converts the [`Fixed`] number to an `f64`.
+	///
+	/// This conversion is exact.
+	#[must_use]
+	pub fn to_f64(self) -> f64 {
+		f64::from(self.0) / f64::from(1 << Self::FRACTIONAL_BITS)
+	}
+
+	/// The integer part of the [`Fixed`] number, rounded towards negative
+	/// infinity.
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the 16 integer bits of a `Fixed` number always fit in an `i16`"
+	)]
+	pub const fn floor(self) -> i16 {
+		(self.0 >> Self::FRACTIONAL_BITS) as i16
+	}
+
+	/// Adds `other`, returning [`None`] if the result would overflow.
+	#[must_use]
+	pub const fn checked_add(self, other: Self) -> Option<Self> {
+		match self.0.checked_add(other.0) {
+			Some(raw) => Some(Self(raw)),
+			None => None,
+		}
+	}
+
+	/// Subtracts `other`, returning [`None`] if the result would overflow.
+	#[must_use]
+	pub const fn checked_sub(self, other: Self) -> Option<Self> {
+		match self.0.checked_sub(other.0) {
+			Some(raw) => Some(Self(raw)),
+			None => None,
+		}
+	}
+
+	/// Multiplies by `other`, returning [`None`] if the result would overflow.
+	///
+	/// The result is rounded towards negative infinity.
+	#[must_use]
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		let raw = (i64::from(self.0) * i64::from(other.0)) >> Self::FRACTIONAL_BITS;
+
+		i32::try_from(raw).ok().map(Self)
+	}
+
+	/// Divides by `other`, returning [`None`] if `other` is zero or the result
+	/// would overflow.
+	///
+	/// The result is rounded towards zero.
+	#[must_use]
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		let raw = (i64::from(self.0) << Self::FRACTIONAL_BITS).checked_div(i64::from(other.0))?;
+
+		i32::try_from(raw).ok().map(Self)
+	}
+}
+
+impl From<i16> for Fixed {
+	fn from(value: i16) -> Self {
+		Self(i32::from(value) << Self::FRACTIONAL_BITS)
+	}
+}
+
+impl FixedPoint {
+	/// The number of fractional bits in a [`FixedPoint`] number.
+	const FRACTIONAL_BITS: u32 = 32;
+
+	/// `0`.
+	pub const ZERO: Self = Self::from_raw(0);
+	/// `1`.
+	pub const ONE: Self = Self::from_raw(1 << Self::FRACTIONAL_BITS);
+	/// The smallest value that can be represented: `-2^31`.
+	pub const MIN: Self = Self::from_raw(i64::MIN);
+	/// The largest value that can be represented, just under `2^31`.
+	pub const MAX: Self = Self::from_raw(i64::MAX);
+
+	/// Creates a new [`FixedPoint`] number from its integer and fractional
+	/// parts.
+	#[must_use]
+	pub const fn new(integral: i32, fractional: u32) -> Self {
+		Self {
+			integral,
+			fractional,
+		}
+	}
+
+	/// Creates a new [`FixedPoint`] number from its raw 32.32 representation.
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		reason = "the raw representation is split into its upper and lower 32 bits"
+	)]
+	pub const fn from_raw(raw: i64) -> Self {
+		Self::new((raw >> Self::FRACTIONAL_BITS) as i32, raw as u32)
+	}
+
+	/// Gets the raw 32.32 representation of the [`FixedPoint`] number.
+	#[must_use]
+	pub const fn raw(self) -> i64 {
+		((self.integral as i64) << Self::FRACTIONAL_BITS) | self.fractional as i64
+	}
+
+	/// The integer part of the [`FixedPoint`] number, rounded towards negative
+	/// infinity.
+	#[must_use]
+	pub const fn integral(self) -> i32 {
+		self.integral
+	}
+
+	/// The fractional part of the [`FixedPoint`] number, in units of `2^-32`.
+	#[must_use]
+	pub const fn fractional(self) -> u32 {
+		self.fractional
+	}
+
+	/// Creates the [`FixedPoint`] number closest to the given `value`.
+	///
+	/// Values outside of the range of a [`FixedPoint`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	pub fn from_f64(value: f64) -> Self {
+		Self::from_f64_rounded(value, Rounding::Nearest)
+	}
+
+	/// Creates a [`FixedPoint`] number from the given `value`, rounded with
+	/// the given `rounding` if it can't be represented exactly.
+	///
+	/// Values outside of the range of a [`FixedPoint`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "float to int casts saturate, which is the intended behavior"
+	)]
+	pub fn from_f64_rounded(value: f64, rounding: Rounding) -> Self {
+		Self::from_raw(rounding.round(value * 2_f64.powi(32)) as i64)
+	}
+
+	/// Creates the [`FixedPoint`] number closest to the given `value`.
+	///
+	/// Values outside of the range of a [`FixedPoint`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	pub fn from_f32(value: f32) -> Self {
+		Self::from_f64(value.into())
+	}
+
+	/// Creates a [`FixedPoint`] number from the given `value`, rounded with
+	/// the given `rounding` if it can't be represented exactly.
+	///
+	/// Values outside of the range of a [`FixedPoint`] number saturate at its
+	/// minimum or maximum, and NaN is converted to zero.
+	#[must_use]
+	pub fn from_f32_rounded(value: f32, rounding: Rounding) -> Self {
+		Self::from_f64_rounded(value.into(), rounding)
+	}
+
+	/// Converts the [`FixedPoint`] number to an `f64`.
+	///
+	/// This conversion is exact unless the integer part is large enough that
+	/// the value needs more than 53 significant bits.
+	#[must_use]
+	pub fn to_f64(self) -> f64 {
+		f64::from(self.integral) + f64::from(self.fractional) / 2_f64.powi(32)
+	}
+
+	/// Adds `other`, returning [`None`] if the result would overflow.
+	#[must_use]
+	pub const fn checked_add(self, other: Self) -> Option<Self> {
+		match self.raw().checked_add(other.raw()) {
+			Some(raw) => Some(Self::from_raw(raw)),
+			None => None,
+		}
+	}
+
+	/// Subtracts `other`, returning [`None`] if the result would overflow.
+	#[must_use]
+	pub const fn checked_sub(self, other: Self) -> Option<Self> {
+		match self.raw().checked_sub(other.raw()) {
+			Some(raw) => Some(Self::from_raw(raw)),
+			None => None,
+		}
+	}
+
+	/// Multiplies by `other`, returning [`None`] if the result would overflow.
+	///
+	/// The result is rounded towards negative infinity.
+	#[must_use]
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		let raw = (i128::from(self.raw()) * i128::from(other.raw())) >> Self::FRACTIONAL_BITS;
+
+		i64::try_from(raw).ok().map(Self::from_raw)
+	}
+
+	/// Divides by `other`, returning [`None`] if `other` is zero or the result
+	/// would overflow.
+	///
+	/// The result is rounded towards zero.
+	#[must_use]
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		let raw =
+			(i128::from(self.raw()) << Self::FRACTIONAL_BITS).checked_div(other.raw().into())?;
+
+		i64::try_from(raw).ok().map(Self::from_raw)
+	}
+}
+
+impl From<i32> for FixedPoint {
+	fn from(value: i32) -> Self {
+		Self::new(value, 0)
+	}
+}
+
+impl From<Fixed> for FixedPoint {
+	fn from(value: Fixed) -> Self {
+		Self::from_raw(i64::from(value.0) << (Self::FRACTIONAL_BITS - Fixed::FRACTIONAL_BITS))
+	}
+}
+
+// Implements the arithmetic operators for a fixed-point type in terms of its
+// `checked_*` methods, panicking on overflow and division by zero like the
+// operators for integers do.
+macro_rules! arithmetic {
+	($($type:ty),*) => {
+		$(
+			arithmetic! {
+				$type:
+					Add::add, AddAssign::add_assign => checked_add, "overflow when adding";
+					Sub::sub, SubAssign::sub_assign => checked_sub, "overflow when subtracting";
+					Mul::mul, MulAssign::mul_assign => checked_mul, "overflow when multiplying";
+					Div::div, DivAssign::div_assign => checked_div, "division by zero or overflow when dividing";
+			}
+
+			impl Neg for $type {
+				type Output = Self;
+
+				fn neg(self) -> Self {
+					Self::ZERO - self
+				}
+			}
+		)*
+	};
+
+	($type:ty: $($op:ident::$method:ident, $op_assign:ident::$method_assign:ident => $checked:ident, $message:literal;)*) => {
+		$(
+			impl $op for $type {
+				type Output = Self;
+
+				fn $method(self, other: Self) -> Self {
+					self.$checked(other).expect($message)
+				}
+			}
+
+			impl $op_assign for $type {
+				fn $method_assign(&mut self, other: Self) {
+					*self = $op::$method(*self, other);
+				}
+			}
+		)*
+	};
+}
+
+arithmetic!(Fixed, FixedPoint);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fixed_arithmetic() {
+		let a = Fixed::from_f32(1.5);
+		let b = Fixed::from(-2);
+
+		assert_eq!(a + b, Fixed::from_f64(-0.5));
+		assert_eq!(a - b, Fixed::from_f64(3.5));
+		assert_eq!(a * b, Fixed::from(-3));
+		assert_eq!((b / a).raw(), -87381);
+		assert_eq!(-a, Fixed::from_f64(-1.5));
+		assert_eq!(Fixed::MAX.checked_add(Fixed::ONE), None);
+		assert_eq!(a.checked_div(Fixed::ZERO), None);
+		assert_eq!(b.floor(), -2);
+	}
+
+	#[test]
+	fn floats_are_rounded_explicitly() {
+		// One third of the smallest fraction of a `Fixed` number.
+		let value = 1.0 / f64::from(3 << 16);
+
+		assert_eq!(Fixed::from_f64_rounded(value, Rounding::Nearest).raw(), 0);
+		assert_eq!(Fixed::from_f64_rounded(value, Rounding::Ceil).raw(), 1);
+		assert_eq!(Fixed::from_f64_rounded(-value, Rounding::Floor).raw(), -1);
+		assert_eq!(
+			Fixed::from_f64_rounded(-value, Rounding::TowardZero).raw(),
+			0
+		);
+		assert_eq!(Fixed::from_f32(1e9), Fixed::MAX);
+		assert_eq!(Fixed::from_f32(f32::NAN), Fixed::ZERO);
+	}
+
+	#[test]
+	fn fixed_point_parts() {
+		let value = FixedPoint::from_f64(-1.25);
+
+		assert_eq!(value.integral(), -2);
+		assert_eq!(value.fractional(), 3 << 30);
+		assert_eq!(FixedPoint::from(Fixed::from_f64(-1.25)), value);
+		assert_eq!(value * FixedPoint::from(4), FixedPoint::from(-5));
+		assert_eq!(FixedPoint::from_raw(value.raw()), value);
+	}
+}
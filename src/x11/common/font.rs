@@ -0,0 +1,336 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ReadError, Readable, Writable, WriteError};
+use xrbk_macro::{ByteSize, StaticByteSize};
+
+use crate::x11::{
+	read::{skip_padding, UnexpectedEndOfData},
+	Atom, DrawDirection,
+};
+
+/// A property of a font, such as its family name or its weight.
+///
+/// How the `value` is interpreted depends on the property; it is often an
+/// [`Atom`].
+#[doc(alias = "FONTPROP")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub struct FontProperty {
+	/// The name of the property.
+	pub name: Atom,
+	/// The value of the property.
+	pub value: u32,
+}
+
+/// The metrics of a character in a font.
+///
+/// A character which does not exist in the font has all of its metrics set
+/// to `0`.
+#[doc(alias = "CHARINFO")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, StaticByteSize, ByteSize)]
+pub struct CharInfo {
+	/// The distance from the origin to the left edge of the glyph.
+	pub left_side_bearing: i16,
+	/// The distance from the origin to the right edge of the glyph.
+	pub right_side_bearing: i16,
+	/// The distance from the origin to the origin of the next character.
+	pub character_width: i16,
+	/// The distance from the baseline to the top edge of the glyph.
+	pub ascent: i16,
+	/// The distance from the baseline to the bottom edge of the glyph.
+	pub descent: i16,
+	/// Attributes of the character which are not interpreted by the X
+	/// server.
+	pub attributes: u16,
+}
+
+/// The metrics and properties of a font, as returned in a
+/// [`QueryFontReply`] or a [`ListFontsWithInfoReply`].
+///
+/// [`QueryFontReply`]: super::requests::QueryFontReply
+/// [`ListFontsWithInfoReply`]: super::requests::ListFontsWithInfoReply
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FontInfo {
+	/// The minimum of each metric over every character in the font.
+	pub min_bounds: CharInfo,
+	/// The maximum of each metric over every character in the font.
+	pub max_bounds: CharInfo,
+	/// The first character of a 1-byte font, or the first second byte of
+	/// each character of a 2-byte font.
+	pub min_char_or_byte2: u16,
+	/// The last character of a 1-byte font, or the last second byte of each
+	/// character of a 2-byte font.
+	pub max_char_or_byte2: u16,
+	/// The character which is drawn in place of characters which do not
+	/// exist in the font.
+	pub default_char: u16,
+	/// The direction in which most of the font's characters are drawn.
+	pub draw_direction: DrawDirection,
+	/// The first first byte of each character of a 2-byte font, or `0` for
+	/// a 1-byte font.
+	pub min_byte1: u8,
+	/// The last first byte of each character of a 2-byte font, or `0` for a
+	/// 1-byte font.
+	pub max_byte1: u8,
+	/// Whether every character between the first and last characters exists
+	/// in the font.
+	pub all_chars_exist: bool,
+	/// The distance from the baseline to the top of the font's lines.
+	pub font_ascent: i16,
+	/// The distance from the baseline to the bottom of the font's lines.
+	pub font_descent: i16,
+	/// The properties of the font.
+	pub properties: Vec<FontProperty>,
+}
+
+impl CharInfo {
+	/// Returns whether this character exists in the font, which is to say
+	/// whether any of its metrics are not `0`.
+	#[must_use]
+	pub fn exists(&self) -> bool {
+		*self != Self::default()
+	}
+}
+
+impl FontInfo {
+	/// The number of bytes of a [`FontInfo`] without its properties, including
+	/// the 4 bytes which follow it in a reply.
+	pub(crate) const HEADER_SIZE: usize = 52;
+
+	/// Returns whether this is a 1-byte font.
+	#[must_use]
+	pub const fn is_single_byte(&self) -> bool {
+		self.min_byte1 == 0 && self.max_byte1 == 0
+	}
+
+	/// The index of the character with the given bytes in the list of
+	/// [`CharInfo`]s of a [`QueryFontReply`], or [`None`] if it is outside
+	/// of the font's range of characters.
+	///
+	/// For a 1-byte font, `byte1` is `0` and `byte2` is the character.
+	///
+	/// [`QueryFontReply`]: super::requests::QueryFontReply
+	#[must_use]
+	pub fn char_index(&self, byte1: u8, byte2: u8) -> Option<usize> {
+		let byte2 = u16::from(byte2);
+
+		if !(self.min_byte1..=self.max_byte1).contains(&byte1)
+			|| !(self.min_char_or_byte2..=self.max_char_or_byte2).contains(&byte2)
+		{
+			return None;
+		}
+
+		// Characters are listed row by row, with one row for each first
+		// byte.
+		let row_len = usize::from(self.max_char_or_byte2 - self.min_char_or_byte2) + 1;
+		let row = usize::from(byte1 - self.min_byte1);
+
+		Some(row * row_len + usize::from(byte2 - self.min_char_or_byte2))
+	}
+
+	/// The value of the property with the given `name`, if the font has it.
+	#[must_use]
+	pub fn property(&self, name: Atom) -> Option<u32> {
+		self.properties
+			.iter()
+			.find(|property| property.name == name)
+			.map(|property| property.value)
+	}
+
+	/// Writes this [`FontInfo`] with the `trailing` value which follows it
+	/// before its properties: the number of [`CharInfo`]s in a
+	/// [`QueryFontReply`], or the number of replies remaining in a
+	/// [`ListFontsWithInfoReply`].
+	///
+	/// [`QueryFontReply`]: super::requests::QueryFontReply
+	/// [`ListFontsWithInfoReply`]: super::requests::ListFontsWithInfoReply
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the number of properties is written as a `u16` value by definition"
+	)]
+	pub(crate) fn write_with(
+		&self,
+		trailing: u32,
+		writer: &mut impl BufMut,
+	) -> Result<(), WriteError> {
+		self.min_bounds.write_to(writer)?;
+		writer.put_bytes(0, 4);
+		self.max_bounds.write_to(writer)?;
+		writer.put_bytes(0, 4);
+
+		writer.put_u16(self.min_char_or_byte2);
+		writer.put_u16(self.max_char_or_byte2);
+		writer.put_u16(self.default_char);
+		writer.put_u16(self.properties.len() as u16);
+		writer.put_u8(self.draw_direction as u8);
+		writer.put_u8(self.min_byte1);
+		writer.put_u8(self.max_byte1);
+		writer.put_u8(u8::from(self.all_chars_exist));
+		writer.put_i16(self.font_ascent);
+		writer.put_i16(self.font_descent);
+		writer.put_u32(trailing);
+
+		for property in &self.properties {
+			property.write_to(writer)?;
+		}
+
+		Ok(())
+	}
+
+	/// Reads a [`FontInfo`] and the `trailing` value which follows it before
+	/// its properties.
+	///
+	/// See [`FontInfo::write_with`].
+	pub(crate) fn read_with(reader: &mut impl Buf) -> Result<(Self, u32), ReadError> {
+		ensure(reader, Self::HEADER_SIZE)?;
+
+		let min_bounds = CharInfo::read_from(reader)?;
+		skip_padding(reader, 4)?;
+		let max_bounds = CharInfo::read_from(reader)?;
+		skip_padding(reader, 4)?;
+
+		let min_char_or_byte2 = reader.get_u16();
+		let max_char_or_byte2 = reader.get_u16();
+		let default_char = reader.get_u16();
+		let properties_len = usize::from(reader.get_u16());
+
+		let draw_direction = match reader.get_u8() {
+			0 => DrawDirection::LeftToRight,
+			1 => DrawDirection::RightToLeft,
+
+			other => return Err(ReadError::UnrecognizedDiscriminant(other)),
+		};
+
+		let min_byte1 = reader.get_u8();
+		let max_byte1 = reader.get_u8();
+		let all_chars_exist = reader.get_u8() != 0;
+		let font_ascent = reader.get_i16();
+		let font_descent = reader.get_i16();
+		let trailing = reader.get_u32();
+
+		ensure(reader, properties_len * 8)?;
+
+		let properties = (0..properties_len)
+			.map(|_| FontProperty::read_from(reader))
+			.collect::<Result<_, _>>()?;
+
+		Ok((
+			Self {
+				min_bounds,
+				max_bounds,
+				min_char_or_byte2,
+				max_char_or_byte2,
+				default_char,
+				draw_direction,
+				min_byte1,
+				max_byte1,
+				all_chars_exist,
+				font_ascent,
+				font_descent,
+				properties,
+			},
+			trailing,
+		))
+	}
+}
+
+/// Returns an error if fewer than `expected` bytes remain in the `reader`.
+fn ensure(reader: &impl Buf, expected: usize) -> Result<(), ReadError> {
+	if reader.remaining() < expected {
+		return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+			expected,
+			found: reader.remaining(),
+		})));
+	}
+
+	Ok(())
+}
+
+impl Writable for FontProperty {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u32(self.name.id);
+		writer.put_u32(self.value);
+
+		Ok(())
+	}
+}
+
+impl Readable for FontProperty {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure(reader, 8)?;
+
+		Ok(Self {
+			name: Atom::new(reader.get_u32()),
+			value: reader.get_u32(),
+		})
+	}
+}
+
+impl Writable for CharInfo {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_i16(self.left_side_bearing);
+		writer.put_i16(self.right_side_bearing);
+		writer.put_i16(self.character_width);
+		writer.put_i16(self.ascent);
+		writer.put_i16(self.descent);
+		writer.put_u16(self.attributes);
+
+		Ok(())
+	}
+}
+
+impl Readable for CharInfo {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure(reader, 12)?;
+
+		Ok(Self {
+			left_side_bearing: reader.get_i16(),
+			right_side_bearing: reader.get_i16(),
+			character_width: reader.get_i16(),
+			ascent: reader.get_i16(),
+			descent: reader.get_i16(),
+			attributes: reader.get_u16(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn chars_are_indexed_row_by_row() {
+		let info = FontInfo {
+			min_bounds: CharInfo::default(),
+			max_bounds: CharInfo::default(),
+			min_char_or_byte2: 0x20,
+			max_char_or_byte2: 0x7e,
+			default_char: 0x20,
+			draw_direction: DrawDirection::LeftToRight,
+			min_byte1: 0x30,
+			max_byte1: 0x31,
+			all_chars_exist: true,
+			font_ascent: 10,
+			font_descent: 2,
+			properties: vec![FontProperty {
+				name: Atom::new(1),
+				value: 2,
+			}],
+		};
+
+		assert!(!info.is_single_byte());
+		assert_eq!(info.char_index(0x30, 0x20), Some(0));
+		assert_eq!(info.char_index(0x31, 0x21), Some(96));
+		assert_eq!(info.char_index(0x32, 0x20), None);
+		assert_eq!(info.char_index(0x30, 0x7f), None);
+		assert_eq!(info.property(Atom::new(1)), Some(2));
+
+		let mut bytes = vec![];
+		info.write_with(3, &mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), FontInfo::HEADER_SIZE + 8);
+		assert_eq!(FontInfo::read_with(&mut &bytes[..]).unwrap(), (info, 3));
+	}
+}
@@ -0,0 +1,291 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use crate::x11::Timestamp;
+
+define! {
+	/// A point with coordinates.
+	///
+	/// The units for the coordinates are not specified: they are usually
+	/// pixels relative to the origin of a drawable.
+	#[doc(alias = "POINT")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, StaticByteSize, ByteSize)]
+	pub struct Point {
+		/// X-coordinate of the point.
+		pub x: i16,
+		/// Y-coordinate of the point.
+		pub y: i16,
+	}
+
+	/// A rectangle with coordinates and dimensions.
+	///
+	/// The coordinates are those of the upper-left corner of the rectangle. The
+	/// units for the coordinates and dimensions are not specified.
+	#[doc(alias = "RECTANGLE")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Rectangle {
+		/// X-coordinate of the upper-left corner of the rectangle.
+		pub x: i16,
+		/// Y-coordinate of the upper-left corner of the rectangle.
+		pub y: i16,
+		/// Width of the rectangle.
+		pub width: u16,
+		/// Height of the rectangle.
+		pub height: u16,
+	}
+
+	/// An arc (the geometry kind) with coordinates, dimensions, and angles.
+	///
+	/// The arc is part of the ellipse which fits within the rectangle given by
+	/// its coordinates and dimensions. Angles are given in 64ths of a degree,
+	/// counterclockwise from the three o'clock position.
+	#[doc(alias = "ARC")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct GeomArc {
+		/// X-coordinate of the arc.
+		pub x: i16,
+		/// Y-coordinate of the arc.
+		pub y: i16,
+		/// Width of the arc.
+		pub width: u16,
+		/// Height of the arc.
+		pub height: u16,
+		/// The angle at which the arc starts.
+		pub start: i16,
+		/// The angle which the arc extends through, relative to its `start`.
+		pub extent: i16,
+	}
+
+	/// A line segment between two points.
+	#[doc(alias = "SEGMENT")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, StaticByteSize, ByteSize)]
+	pub struct Segment {
+		/// X-coordinate of the start of the segment.
+		pub x1: i16,
+		/// Y-coordinate of the start of the segment.
+		pub y1: i16,
+		/// X-coordinate of the end of the segment.
+		pub x2: i16,
+		/// Y-coordinate of the end of the segment.
+		pub y2: i16,
+	}
+
+	/// The position of the pointer at a point in time, as recorded in its
+	/// motion history.
+	///
+	/// The coordinates are relative to the origin of the window given in the
+	/// [`GetMotionEvents`] request.
+	///
+	/// [`GetMotionEvents`]: crate::x11::requests::GetMotionEvents
+	#[doc(alias = "TIMECOORD")]
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct TimeCoord {
+		/// The time at which the pointer was at this position.
+		pub time: Timestamp,
+		/// X-coordinate of the pointer.
+		pub x: i16,
+		/// Y-coordinate of the pointer.
+		pub y: i16,
+	}
+}
+
+impl Point {
+	/// Creates a new [`Point`] with the given coordinates.
+	#[must_use]
+	pub const fn new(x: i16, y: i16) -> Self {
+		Self { x, y }
+	}
+
+	/// Moves this point by the given offsets.
+	///
+	/// Like the coordinates of the X protocol, the coordinates wrap on
+	/// overflow.
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self {
+			x: self.x.wrapping_add(dx),
+			y: self.y.wrapping_add(dy),
+		}
+	}
+}
+
+impl TimeCoord {
+	/// The position of the pointer as a [`Point`].
+	#[must_use]
+	pub const fn point(self) -> Point {
+		Point::new(self.x, self.y)
+	}
+}
+
+impl From<(i16, i16)> for Point {
+	fn from((x, y): (i16, i16)) -> Self {
+		Self { x, y }
+	}
+}
+
+impl From<Point> for (i16, i16) {
+	fn from(Point { x, y }: Point) -> Self {
+		(x, y)
+	}
+}
+
+impl Rectangle {
+	/// Creates a new [`Rectangle`] with the given coordinates of its
+	/// upper-left corner and dimensions.
+	#[must_use]
+	pub const fn new(x: i16, y: i16, width: u16, height: u16) -> Self {
+		Self {
+			x,
+			y,
+			width,
+			height,
+		}
+	}
+
+	/// Returns whether the given `point` is within this rectangle.
+	///
+	/// The right and bottom edges are not included.
+	#[must_use]
+	pub fn contains(&self, point: Point) -> bool {
+		let (x, y) = (i32::from(point.x), i32::from(point.y));
+
+		(i32::from(self.x)..self.right()).contains(&x)
+			&& (i32::from(self.y)..self.bottom()).contains(&y)
+	}
+
+	/// Returns the area covered by both this rectangle and the `other`, or
+	/// [`None`] if they do not overlap.
+	#[must_use]
+	pub fn intersect(&self, other: &Self) -> Option<Self> {
+		let left = self.x.max(other.x);
+		let top = self.y.max(other.y);
+
+		let right = self.right().min(other.right());
+		let bottom = self.bottom().min(other.bottom());
+
+		let width = u16::try_from(right - i32::from(left)).ok()?;
+		let height = u16::try_from(bottom - i32::from(top)).ok()?;
+
+		(width > 0 && height > 0).then_some(Self::new(left, top, width, height))
+	}
+
+	/// Moves this rectangle by the given offsets.
+	///
+	/// Like the coordinates of the X protocol, the coordinates wrap on
+	/// overflow.
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self {
+			x: self.x.wrapping_add(dx),
+			y: self.y.wrapping_add(dy),
+			..self
+		}
+	}
+
+	/// The x-coordinate just beyond the right edge of this rectangle.
+	fn right(&self) -> i32 {
+		i32::from(self.x) + i32::from(self.width)
+	}
+
+	/// The y-coordinate just beyond the bottom edge of this rectangle.
+	fn bottom(&self) -> i32 {
+		i32::from(self.y) + i32::from(self.height)
+	}
+}
+
+impl Default for Rectangle {
+	fn default() -> Self {
+		Self {
+			x: 0,
+			y: 0,
+			width: 1,
+			height: 1,
+		}
+	}
+}
+
+impl GeomArc {
+	/// The rectangle which bounds the ellipse that this arc is part of.
+	#[must_use]
+	pub const fn bounds(&self) -> Rectangle {
+		Rectangle::new(self.x, self.y, self.width, self.height)
+	}
+
+	/// Moves this arc by the given offsets.
+	///
+	/// Like the coordinates of the X protocol, the coordinates wrap on
+	/// overflow.
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self {
+			x: self.x.wrapping_add(dx),
+			y: self.y.wrapping_add(dy),
+			..self
+		}
+	}
+}
+
+impl Segment {
+	/// Creates a new [`Segment`] between the given points.
+	#[must_use]
+	pub const fn new(start: Point, end: Point) -> Self {
+		Self {
+			x1: start.x,
+			y1: start.y,
+			x2: end.x,
+			y2: end.y,
+		}
+	}
+
+	/// The point at which this segment starts.
+	#[must_use]
+	pub const fn start(&self) -> Point {
+		Point::new(self.x1, self.y1)
+	}
+
+	/// The point at which this segment ends.
+	#[must_use]
+	pub const fn end(&self) -> Point {
+		Point::new(self.x2, self.y2)
+	}
+
+	/// Moves this segment by the given offsets.
+	///
+	/// Like the coordinates of the X protocol, the coordinates wrap on
+	/// overflow.
+	#[must_use]
+	pub const fn translate(self, dx: i16, dy: i16) -> Self {
+		Self::new(self.start().translate(dx, dy), self.end().translate(dx, dy))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rectangles_intersect_where_they_overlap() {
+		let a = Rectangle::new(0, 0, 10, 10);
+		let b = Rectangle::new(5, -5, 10, 10);
+
+		assert_eq!(a.intersect(&b), Some(Rectangle::new(5, 0, 5, 5)));
+		assert_eq!(b.intersect(&a), a.intersect(&b));
+
+		// Rectangles which only share an edge do not overlap.
+		assert_eq!(a.intersect(&Rectangle::new(10, 0, 5, 5)), None);
+		assert_eq!(a.intersect(&Rectangle::new(-20, -20, 5, 5)), None);
+	}
+
+	#[test]
+	fn rectangles_contain_points_within_their_edges() {
+		let rectangle = Rectangle::new(-5, -5, 10, 10).translate(5, 5);
+
+		assert!(rectangle.contains(Point::new(0, 0)));
+		assert!(rectangle.contains(Point::new(9, 9)));
+		assert!(!rectangle.contains(Point::new(10, 9)));
+		assert!(!rectangle.contains(Point::new(-1, 0)));
+	}
+}
@@ -0,0 +1,407 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::BufMut;
+use cornflakes::{ByteSize, Writable, WriteError};
+
+use crate::x11::{
+	requests::{ChangeGraphicsContext, CreateGraphicsContext},
+	*,
+};
+
+/// A set of [graphics context components], built up one component at a time,
+/// for [`CreateGraphicsContext`] and [`ChangeGraphicsContext`] requests.
+///
+/// This is known as `GCValues` in Xlib. Only the components which are set are
+/// sent: the rest are left at their defaults for [`CreateGraphicsContext`],
+/// or unchanged for [`ChangeGraphicsContext`]. Setting a component again
+/// replaces its value.
+///
+/// The components are written as a [`GraphicsContextMask`] followed by one
+/// 4-byte value for each component that is set, in the order of their bits in
+/// the mask.
+///
+/// # Examples
+/// ```ignore
+/// let values = GraphicsContextValues::new()
+///     .foreground(0x00ff_0000)
+///     .line_width(2)
+///     .cap_style(CapStyle::Round)
+///     .graphics_exposures(false);
+///
+/// connection.send(values.create(context_id, window.into()))?;
+/// ```
+///
+/// [graphics context components]: GraphicsContextValue
+#[doc(alias = "GCValues")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct GraphicsContextValues {
+	/// The components which have been set, in the order of their bits in the
+	/// [`GraphicsContextMask`].
+	values: Vec<GraphicsContextValue>,
+}
+
+impl GraphicsContextValues {
+	/// Creates a new [`GraphicsContextValues`] with no components set.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { values: Vec::new() }
+	}
+
+	/// The [`GraphicsContextMask`] of the components which are set.
+	#[must_use]
+	pub fn mask(&self) -> GraphicsContextMask {
+		self.values
+			.iter()
+			.fold(GraphicsContextMask::empty(), |mask, value| {
+				mask | value.mask()
+			})
+	}
+
+	/// The components which are set, in the order that they are written.
+	#[must_use]
+	pub fn values(&self) -> &[GraphicsContextValue] {
+		&self.values
+	}
+
+	/// Returns whether no components are set.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Creates the [`CreateGraphicsContext`] request which creates a graphics
+	/// context with the given `context_id` for use with the given `drawable`,
+	/// with these components set.
+	#[must_use]
+	pub fn create(
+		&self,
+		context_id: GraphicsContext,
+		drawable: Drawable,
+	) -> CreateGraphicsContext<'_> {
+		CreateGraphicsContext {
+			context_id,
+			drawable,
+			value_mask: self.mask(),
+			values: &self.values,
+		}
+	}
+
+	/// Creates the [`ChangeGraphicsContext`] request which sets these
+	/// components on the given `context`.
+	#[must_use]
+	pub fn change(&self, context: GraphicsContext) -> ChangeGraphicsContext<'_> {
+		ChangeGraphicsContext {
+			context,
+			value_mask: self.mask(),
+			values: &self.values,
+		}
+	}
+
+	/// Sets the given component, replacing its previous value if it was
+	/// already set.
+	#[must_use]
+	pub fn with(mut self, component: GraphicsContextValue) -> Self {
+		let bits = component.mask().bits();
+
+		match self
+			.values
+			.binary_search_by_key(&bits, |value| value.mask().bits())
+		{
+			Ok(index) => self.values[index] = component,
+			Err(index) => self.values.insert(index, component),
+		}
+
+		self
+	}
+
+	/// Sets how the source and destination pixels are combined when drawing.
+	#[must_use]
+	pub fn function(self, function: Function) -> Self {
+		self.with(GraphicsContextValue::Function(function))
+	}
+
+	/// Sets which bit planes of the destination are drawn to.
+	#[must_use]
+	pub fn plane_mask(self, planes: u32) -> Self {
+		self.with(GraphicsContextValue::PlaneMask(planes))
+	}
+
+	/// Sets the pixel used for the foreground.
+	#[must_use]
+	pub fn foreground(self, pixel: u32) -> Self {
+		self.with(GraphicsContextValue::Foreground(pixel))
+	}
+
+	/// Sets the pixel used for the background.
+	#[must_use]
+	pub fn background(self, pixel: u32) -> Self {
+		self.with(GraphicsContextValue::Background(pixel))
+	}
+
+	/// Sets the width of lines in pixels.
+	///
+	/// See [`GraphicsContextValue::LineWidth`].
+	#[must_use]
+	pub fn line_width(self, width: u16) -> Self {
+		self.with(GraphicsContextValue::LineWidth(width))
+	}
+
+	/// Sets which sections of lines are drawn.
+	#[must_use]
+	pub fn line_style(self, style: LineStyle) -> Self {
+		self.with(GraphicsContextValue::LineStyle(style))
+	}
+
+	/// Sets how the ends of lines are drawn.
+	#[must_use]
+	pub fn cap_style(self, style: CapStyle) -> Self {
+		self.with(GraphicsContextValue::CapStyle(style))
+	}
+
+	/// Sets how the corners of wide lines are drawn.
+	#[must_use]
+	pub fn join_style(self, style: JoinStyle) -> Self {
+		self.with(GraphicsContextValue::JoinStyle(style))
+	}
+
+	/// Sets how lines, text, and fills are filled in.
+	#[must_use]
+	pub fn fill_style(self, style: FillStyle) -> Self {
+		self.with(GraphicsContextValue::FillStyle(style))
+	}
+
+	/// Sets which pixels are inside a filled polygon.
+	#[must_use]
+	pub fn fill_rule(self, rule: FillRule) -> Self {
+		self.with(GraphicsContextValue::FillRule(rule))
+	}
+
+	/// Sets the pixmap tiled by the [`FillStyle::Tiled`] fill style.
+	#[must_use]
+	pub fn tile(self, tile: Pixmap) -> Self {
+		self.with(GraphicsContextValue::Tile(tile))
+	}
+
+	/// Sets the bitmap used by the stippled fill styles.
+	#[must_use]
+	pub fn stipple(self, stipple: Pixmap) -> Self {
+		self.with(GraphicsContextValue::Stipple(stipple))
+	}
+
+	/// Sets the x-coordinate of the origin of the [tile] or [stipple].
+	///
+	/// [tile]: GraphicsContextValues::tile
+	/// [stipple]: GraphicsContextValues::stipple
+	#[must_use]
+	pub fn tile_stipple_x_origin(self, x: i16) -> Self {
+		self.with(GraphicsContextValue::TileStippleXorigin(x))
+	}
+
+	/// Sets the y-coordinate of the origin of the [tile] or [stipple].
+	///
+	/// [tile]: GraphicsContextValues::tile
+	/// [stipple]: GraphicsContextValues::stipple
+	#[must_use]
+	pub fn tile_stipple_y_origin(self, y: i16) -> Self {
+		self.with(GraphicsContextValue::TileStippleYorigin(y))
+	}
+
+	/// Sets the font used for text.
+	#[must_use]
+	pub fn font(self, font: Font) -> Self {
+		self.with(GraphicsContextValue::Font(font))
+	}
+
+	/// Sets whether drawing to a window is clipped by its children.
+	#[must_use]
+	pub fn subwindow_mode(self, mode: SubwindowMode) -> Self {
+		self.with(GraphicsContextValue::SubwindowMode(mode))
+	}
+
+	/// Sets whether `GraphicsExposure` events are generated for `CopyArea`
+	/// and `CopyPlane` requests.
+	#[must_use]
+	pub fn graphics_exposures(self, graphics_exposures: bool) -> Self {
+		self.with(GraphicsContextValue::GraphicsExposures(graphics_exposures))
+	}
+
+	/// Sets the x-coordinate of the origin of the [clip mask].
+	///
+	/// [clip mask]: GraphicsContextValues::clip_mask
+	#[must_use]
+	pub fn clip_x_origin(self, x: i16) -> Self {
+		self.with(GraphicsContextValue::ClipXorigin(x))
+	}
+
+	/// Sets the y-coordinate of the origin of the [clip mask].
+	///
+	/// [clip mask]: GraphicsContextValues::clip_mask
+	#[must_use]
+	pub fn clip_y_origin(self, y: i16) -> Self {
+		self.with(GraphicsContextValue::ClipYorigin(y))
+	}
+
+	/// Sets the bitmap which drawing is clipped to, or [`None`] to not clip
+	/// drawing.
+	#[must_use]
+	pub fn clip_mask(self, mask: Option<Pixmap>) -> Self {
+		self.with(GraphicsContextValue::ClipMask(mask))
+	}
+
+	/// Sets the offset into the dash pattern at which lines start.
+	#[must_use]
+	pub fn dash_offset(self, offset: u16) -> Self {
+		self.with(GraphicsContextValue::DashOffset(offset))
+	}
+
+	/// Sets the length of both the dashes and the gaps between them in dashed
+	/// lines.
+	///
+	/// Dash patterns with different lengths can be set with a [`SetDashes`]
+	/// request.
+	///
+	/// [`SetDashes`]: crate::x11::requests::SetDashes
+	#[must_use]
+	pub fn dashes(self, length: u8) -> Self {
+		self.with(GraphicsContextValue::Dashes(length))
+	}
+
+	/// Sets whether filled arcs are filled as chords or as pie slices.
+	#[must_use]
+	pub fn arc_mode(self, mode: ArcMode) -> Self {
+		self.with(GraphicsContextValue::ArcMode(mode))
+	}
+}
+
+impl GraphicsContextValue {
+	/// The bit in a [`GraphicsContextMask`] which specifies this component.
+	#[must_use]
+	pub const fn mask(&self) -> GraphicsContextMask {
+		match self {
+			Self::Function(_) => GraphicsContextMask::FUNCTION,
+			Self::PlaneMask(_) => GraphicsContextMask::PLANE_MASK,
+			Self::Foreground(_) => GraphicsContextMask::FOREGROUND,
+			Self::Background(_) => GraphicsContextMask::BACKGROUND,
+			Self::LineWidth(_) => GraphicsContextMask::LINE_WIDTH,
+			Self::LineStyle(_) => GraphicsContextMask::LINE_STYLE,
+			Self::CapStyle(_) => GraphicsContextMask::CAP_STYLE,
+			Self::JoinStyle(_) => GraphicsContextMask::JOIN_STYLE,
+			Self::FillStyle(_) => GraphicsContextMask::FILL_STYLE,
+			Self::FillRule(_) => GraphicsContextMask::FILL_RULE,
+			Self::Tile(_) => GraphicsContextMask::TILE,
+			Self::Stipple(_) => GraphicsContextMask::STIPPLE,
+			Self::TileStippleXorigin(_) => GraphicsContextMask::TILE_STIPPLE_X_ORIGIN,
+			Self::TileStippleYorigin(_) => GraphicsContextMask::TILE_STIPPLE_Y_ORIGIN,
+			Self::Font(_) => GraphicsContextMask::FONT,
+			Self::SubwindowMode(_) => GraphicsContextMask::SUBWINDOW_MODE,
+			Self::GraphicsExposures(_) => GraphicsContextMask::GRAPHICS_EXPOSURE,
+			Self::ClipXorigin(_) => GraphicsContextMask::CLIP_X_ORIGIN,
+			Self::ClipYorigin(_) => GraphicsContextMask::CLIP_Y_ORIGIN,
+			Self::ClipMask(_) => GraphicsContextMask::CLIP_MASK,
+			Self::DashOffset(_) => GraphicsContextMask::DASH_OFFSET,
+			Self::Dashes(_) => GraphicsContextMask::DASHES,
+			Self::ArcMode(_) => GraphicsContextMask::ARC_MODE,
+		}
+	}
+
+	/// The 4-byte value that this component is written as in a list of
+	/// values.
+	#[must_use]
+	pub fn value(&self) -> u32 {
+		match self {
+			Self::Function(function) => *function as u32,
+
+			Self::PlaneMask(pixel) | Self::Foreground(pixel) | Self::Background(pixel) => *pixel,
+
+			Self::LineWidth(value) | Self::DashOffset(value) => u32::from(*value),
+			Self::Dashes(length) => u32::from(*length),
+
+			Self::LineStyle(style) => *style as u32,
+			Self::CapStyle(style) => *style as u32,
+			Self::JoinStyle(style) => *style as u32,
+			Self::FillStyle(style) => *style as u32,
+			Self::FillRule(rule) => *rule as u32,
+			Self::SubwindowMode(mode) => *mode as u32,
+			Self::ArcMode(mode) => *mode as u32,
+
+			Self::Tile(pixmap) | Self::Stipple(pixmap) | Self::ClipMask(Some(pixmap)) => {
+				pixmap.res_id()
+			}
+			Self::ClipMask(None) => 0,
+			Self::Font(font) => font.res_id(),
+
+			// Coordinates are sign-extended to fill the value.
+			#[allow(clippy::cast_sign_loss)]
+			Self::TileStippleXorigin(coord)
+			| Self::TileStippleYorigin(coord)
+			| Self::ClipXorigin(coord)
+			| Self::ClipYorigin(coord) => i32::from(*coord) as u32,
+
+			Self::GraphicsExposures(value) => u32::from(*value),
+		}
+	}
+}
+
+impl ByteSize for GraphicsContextValues {
+	fn byte_size(&self) -> usize {
+		// The mask, followed by a 4-byte value for each component.
+		4 + 4 * self.values.len()
+	}
+}
+
+impl Writable for GraphicsContextValues {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u32(self.mask().bits());
+
+		for value in &self.values {
+			writer.put_u32(value.value());
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn components_are_written_in_mask_order() {
+		let values = GraphicsContextValues::new()
+			.arc_mode(ArcMode::Chord)
+			.line_width(3)
+			.function(Function::Xor)
+			.line_width(2);
+
+		assert_eq!(
+			values.mask(),
+			GraphicsContextMask::FUNCTION
+				| GraphicsContextMask::LINE_WIDTH
+				| GraphicsContextMask::ARC_MODE
+		);
+
+		let mut bytes = vec![];
+		values.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), values.byte_size());
+		assert_eq!(
+			bytes,
+			[
+				0x00, 0x40, 0x00, 0x11, // mask
+				0x00, 0x00, 0x00, 0x06, // function
+				0x00, 0x00, 0x00, 0x02, // line width
+				0x00, 0x00, 0x00, 0x00, // arc mode
+			]
+		);
+	}
+
+	#[test]
+	fn origins_are_sign_extended() {
+		assert_eq!(GraphicsContextValue::ClipXorigin(-1).value(), 0xffff_ffff);
+		assert_eq!(GraphicsContextValue::TileStippleYorigin(2).value(), 2);
+		assert_eq!(GraphicsContextValue::ClipMask(None).value(), 0);
+		assert_eq!(GraphicsContextValue::GraphicsExposures(true).value(), 1);
+	}
+}
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use xrb_proc_macros::{ByteSize, StaticByteSize};
+use xrbk_macro::{ByteSize, StaticByteSize};
 
 /// A unique ID corresponding to a defined string name.
 ///
@@ -2,9 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../../snapshot/x11/common/id/atoms.rs"
+)]
 pub mod atoms;
 
-use xrb_proc_macros::{ByteSize, StaticByteSize};
+use xrbk_macro::{ByteSize, StaticByteSize};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub struct VisualId(u32);
@@ -80,14 +84,49 @@ pub struct Colormap {
 	id: u32,
 }
 
-pub trait Drawable {}
-pub trait Fontable {}
+/// The resource ID of either a [`Window`] or a [`Pixmap`].
+///
+/// Both [`Window`]s and [`Pixmap`]s can be drawn to, and many requests accept
+/// either. A [`Drawable`] can be created from either with [`From`], or with
+/// [`Window::as_drawable`] and [`Pixmap::as_drawable`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub struct Drawable {
+	id: u32,
+}
+
+/// The resource ID of either a [`Font`] or a [`GraphicsContext`].
+///
+/// When a [`GraphicsContext`] is used as a [`Fontable`], its font is used. A
+/// [`Fontable`] can be created from either with [`From`], or with
+/// [`Font::as_fontable`] and [`GraphicsContext::as_fontable`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub struct Fontable {
+	id: u32,
+}
+
+impl From<Window> for Drawable {
+	fn from(window: Window) -> Self {
+		window.as_drawable()
+	}
+}
 
-impl Drawable for Window {}
-impl Drawable for Pixmap {}
+impl From<Pixmap> for Drawable {
+	fn from(pixmap: Pixmap) -> Self {
+		pixmap.as_drawable()
+	}
+}
+
+impl From<Font> for Fontable {
+	fn from(font: Font) -> Self {
+		font.as_fontable()
+	}
+}
 
-impl Fontable for Font {}
-impl Fontable for GraphicsContext {}
+impl From<GraphicsContext> for Fontable {
+	fn from(context: GraphicsContext) -> Self {
+		context.as_fontable()
+	}
+}
 
 impl ResId for Window {
 	fn res_id(&self) -> u32 {
@@ -125,6 +164,18 @@ impl ResId for Colormap {
 	}
 }
 
+impl ResId for Drawable {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
+impl ResId for Fontable {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
 impl Window {
 	/// Creates a new [`Window`] with the given `id`.
 	#[must_use]
@@ -137,6 +188,12 @@ impl Window {
 	pub const fn empty() -> Self {
 		Self { id: 0 }
 	}
+
+	/// Returns this [`Window`] as a [`Drawable`] with the same ID.
+	#[must_use]
+	pub const fn as_drawable(self) -> Drawable {
+		Drawable { id: self.id }
+	}
 }
 
 impl Pixmap {
@@ -151,6 +208,12 @@ impl Pixmap {
 	pub const fn empty() -> Self {
 		Self { id: 0 }
 	}
+
+	/// Returns this [`Pixmap`] as a [`Drawable`] with the same ID.
+	#[must_use]
+	pub const fn as_drawable(self) -> Drawable {
+		Drawable { id: self.id }
+	}
 }
 
 impl Cursor {
@@ -179,6 +242,12 @@ impl Font {
 	pub const fn empty() -> Self {
 		Self { id: 0 }
 	}
+
+	/// Returns this [`Font`] as a [`Fontable`] with the same ID.
+	#[must_use]
+	pub const fn as_fontable(self) -> Fontable {
+		Fontable { id: self.id }
+	}
 }
 
 impl GraphicsContext {
@@ -193,6 +262,12 @@ impl GraphicsContext {
 	pub const fn empty() -> Self {
 		Self { id: 0 }
 	}
+
+	/// Returns this [`GraphicsContext`] as a [`Fontable`] with the same ID.
+	#[must_use]
+	pub const fn as_fontable(self) -> Fontable {
+		Fontable { id: self.id }
+	}
 }
 
 impl Colormap {
@@ -209,4 +284,32 @@ impl Colormap {
 	}
 }
 
+impl Drawable {
+	/// Creates a new [`Drawable`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`Drawable`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
+impl Fontable {
+	/// Creates a new [`Fontable`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`Fontable`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
 fn _assert_object_safety(_res_id: &dyn ResId) {}
@@ -0,0 +1,485 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	read::{read_value_list, UnexpectedEndOfData},
+	*,
+};
+
+/// The keyboard settings changed by a [`ChangeKeyboardControl`] request,
+/// each of which is only changed if it is [`Some`].
+///
+/// The values are written as a [`KeyboardControlMask`], followed by one
+/// 4-byte value for each value that is [`Some`], in the order of their bits in
+/// the mask.
+///
+/// # Examples
+/// ```ignore
+/// connection.send(ChangeKeyboardControl {
+///     values: KeyboardControl::new()
+///         .bell_percent(Defaultable::Specific(50))
+///         .auto_repeat(Some(keycode), AutoRepeatMode::Off),
+/// })?;
+/// ```
+///
+/// [`ChangeKeyboardControl`]: super::requests::ChangeKeyboardControl
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct KeyboardControl {
+	/// The volume of key clicks, from `0` (off) to `100` (loud).
+	pub key_click_percent: Option<Defaultable<u8>>,
+	/// The base volume of the bell, from `0` (off) to `100` (loud).
+	pub bell_percent: Option<Defaultable<u8>>,
+	/// The pitch of the bell in Hz.
+	///
+	/// Pitches above [`i16::MAX`] generate a [`Value`] error.
+	///
+	/// [`Value`]: crate::x11::errors::Value
+	pub bell_pitch: Option<Defaultable<u16>>,
+	/// The duration of the bell in milliseconds.
+	///
+	/// Durations above [`i16::MAX`] generate a [`Value`] error.
+	///
+	/// [`Value`]: crate::x11::errors::Value
+	pub bell_duration: Option<Defaultable<u16>>,
+	/// The LED, from `1` to `32`, which the [`led_mode`] is applied to.
+	///
+	/// This may only be given with a [`led_mode`].
+	///
+	/// [`led_mode`]: KeyboardControl::led_mode
+	pub led: Option<u8>,
+	/// Whether the [`led`] is turned on or off, or, if no [`led`] is given,
+	/// every LED.
+	///
+	/// [`led`]: KeyboardControl::led
+	pub led_mode: Option<LedMode>,
+	/// The key which the [`auto_repeat_mode`] is applied to.
+	///
+	/// This may only be given with an [`auto_repeat_mode`].
+	///
+	/// [`auto_repeat_mode`]: KeyboardControl::auto_repeat_mode
+	pub key: Option<Keycode>,
+	/// Whether the [`key`] repeats when it is held, or, if no [`key`] is
+	/// given, whether keys repeat at all.
+	///
+	/// [`key`]: KeyboardControl::key
+	pub auto_repeat_mode: Option<AutoRepeatMode>,
+}
+
+/// Which keys repeat when they are held, as returned in a
+/// [`GetKeyboardControlReply`].
+///
+/// This is a bit vector of 256 bits, in which bit `i` of byte `n` represents
+/// the key with the keycode `8n + i`.
+///
+/// [`GetKeyboardControlReply`]: super::requests::GetKeyboardControlReply
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AutoRepeats([u8; 32]);
+
+impl KeyboardControl {
+	/// Creates a new [`KeyboardControl`] with no values given.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			key_click_percent: None,
+			bell_percent: None,
+			bell_pitch: None,
+			bell_duration: None,
+			led: None,
+			led_mode: None,
+			key: None,
+			auto_repeat_mode: None,
+		}
+	}
+
+	/// The [`KeyboardControlMask`] of the values which are given.
+	#[must_use]
+	pub fn mask(&self) -> KeyboardControlMask {
+		let mut mask = KeyboardControlMask::empty();
+
+		mask.set(
+			KeyboardControlMask::KEY_CLICK_PERCENT,
+			self.key_click_percent.is_some(),
+		);
+		mask.set(
+			KeyboardControlMask::BELL_PERCENT,
+			self.bell_percent.is_some(),
+		);
+		mask.set(KeyboardControlMask::BELL_PITCH, self.bell_pitch.is_some());
+		mask.set(
+			KeyboardControlMask::BELL_DURATION,
+			self.bell_duration.is_some(),
+		);
+		mask.set(KeyboardControlMask::LED, self.led.is_some());
+		mask.set(KeyboardControlMask::LED_MODE, self.led_mode.is_some());
+		mask.set(KeyboardControlMask::KEY, self.key.is_some());
+		mask.set(
+			KeyboardControlMask::AUTO_REPEAT_MODE,
+			self.auto_repeat_mode.is_some(),
+		);
+
+		mask
+	}
+
+	/// Returns whether no values are given.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.mask().is_empty()
+	}
+
+	/// Sets the volume of key clicks.
+	#[must_use]
+	pub const fn key_click_percent(mut self, percent: Defaultable<u8>) -> Self {
+		self.key_click_percent = Some(percent);
+		self
+	}
+
+	/// Sets the base volume of the bell.
+	#[must_use]
+	pub const fn bell_percent(mut self, percent: Defaultable<u8>) -> Self {
+		self.bell_percent = Some(percent);
+		self
+	}
+
+	/// Sets the pitch of the bell in Hz.
+	#[must_use]
+	pub const fn bell_pitch(mut self, pitch: Defaultable<u16>) -> Self {
+		self.bell_pitch = Some(pitch);
+		self
+	}
+
+	/// Sets the duration of the bell in milliseconds.
+	#[must_use]
+	pub const fn bell_duration(mut self, duration: Defaultable<u16>) -> Self {
+		self.bell_duration = Some(duration);
+		self
+	}
+
+	/// Turns the given `led` on or off, or every LED if it is [`None`].
+	#[must_use]
+	pub const fn led(mut self, led: Option<u8>, mode: LedMode) -> Self {
+		self.led = led;
+		self.led_mode = Some(mode);
+
+		self
+	}
+
+	/// Sets whether the given `key` repeats when it is held, or whether keys
+	/// repeat at all if it is [`None`].
+	#[must_use]
+	pub const fn auto_repeat(mut self, key: Option<Keycode>, mode: AutoRepeatMode) -> Self {
+		self.key = key;
+		self.auto_repeat_mode = Some(mode);
+
+		self
+	}
+
+	/// Checks that each of the values given is within the range which the X
+	/// server accepts for it.
+	///
+	/// This is checked when the values are written.
+	///
+	/// # Errors
+	/// Returns a [`BuildError::OutOfRange`] if a percentage is above `100`, a
+	/// pitch or duration is above [`i16::MAX`], or the LED is not between `1`
+	/// and `32`.
+	pub fn validate(&self) -> Result<(), BuildError> {
+		let percents = [
+			("key click percent", self.key_click_percent),
+			("bell percent", self.bell_percent),
+		];
+		let bell = [
+			("bell pitch", self.bell_pitch),
+			("bell duration", self.bell_duration),
+		];
+
+		for (name, percent) in percents {
+			if let Some(Defaultable::Specific(percent)) = percent {
+				BuildError::check_range(name, percent, 0, 100)?;
+			}
+		}
+
+		for (name, value) in bell {
+			if let Some(Defaultable::Specific(value)) = value {
+				BuildError::check_range(name, value, 0, i16::MAX)?;
+			}
+		}
+
+		if let Some(led) = self.led {
+			BuildError::check_range("LED", led, 1, 32)?;
+		}
+
+		Ok(())
+	}
+
+	/// The 4-byte values that are written, in the order of their bits in the
+	/// [`KeyboardControlMask`].
+	fn values(&self) -> impl Iterator<Item = u32> {
+		/// Writes a value, or `-1` if it is the default.
+		fn defaultable<T: Into<u32>>(value: Defaultable<T>) -> u32 {
+			match value {
+				Defaultable::Default => u32::MAX,
+				Defaultable::Specific(value) => value.into(),
+			}
+		}
+
+		[
+			self.key_click_percent.map(defaultable),
+			self.bell_percent.map(defaultable),
+			self.bell_pitch.map(defaultable),
+			self.bell_duration.map(defaultable),
+			self.led.map(u32::from),
+			self.led_mode.map(|led_mode| led_mode as u32),
+			self.key.map(u32::from),
+			self.auto_repeat_mode
+				.map(|auto_repeat_mode| auto_repeat_mode as u32),
+		]
+		.into_iter()
+		.flatten()
+	}
+}
+
+impl ByteSize for KeyboardControl {
+	fn byte_size(&self) -> usize {
+		// The mask, followed by a 4-byte value for each value given.
+		4 + 4 * self.values().count()
+	}
+}
+
+impl Writable for KeyboardControl {
+	/// Writes the mask followed by the values.
+	///
+	/// # Errors
+	/// Returns a [`BuildError`] if any of the values are out of range; see
+	/// [`KeyboardControl::validate`].
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		self.validate()?;
+
+		writer.put_u32(self.mask().bits());
+
+		for value in self.values() {
+			writer.put_u32(value);
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for KeyboardControl {
+	/// Reads a list of values which continues to the end of the `reader`, such
+	/// as at the end of a [`ChangeKeyboardControl`] request.
+	///
+	/// Values for bits which are not defined in [`KeyboardControlMask`] are
+	/// skipped.
+	///
+	/// # Errors
+	/// Returns a [`ValueListMismatch`] if the number of values does not match
+	/// the number of bits set in the mask.
+	///
+	/// [`ChangeKeyboardControl`]: super::requests::ChangeKeyboardControl
+	/// [`ValueListMismatch`]: crate::x11::read::ValueListMismatch
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		/// Reads a value which is `-1` if it is the default.
+		fn defaultable<T>(value: u32, specific: impl FnOnce(u32) -> T) -> Defaultable<T> {
+			match value {
+				u32::MAX => Defaultable::Default,
+				value => Defaultable::Specific(specific(value)),
+			}
+		}
+
+		if reader.remaining() < 4 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 4,
+				found: reader.remaining(),
+			})));
+		}
+
+		let mask = reader.get_u32();
+
+		let mut values = Self::new();
+		let raw_values = read_value_list(reader, mask)?;
+
+		// Each value is matched to the next bit set in the mask.
+		let bits = (0..32).map(|bit| 1 << bit).filter(|bit| mask & bit != 0);
+
+		// Percentages, pitches, durations, LEDs, and keys are only the lower
+		// bits of their values.
+		#[allow(clippy::cast_possible_truncation)]
+		for (bit, value) in bits.zip(raw_values) {
+			match KeyboardControlMask::from_bits_truncate(bit) {
+				KeyboardControlMask::KEY_CLICK_PERCENT => {
+					values.key_click_percent = Some(defaultable(value, |value| value as u8));
+				}
+				KeyboardControlMask::BELL_PERCENT => {
+					values.bell_percent = Some(defaultable(value, |value| value as u8));
+				}
+				KeyboardControlMask::BELL_PITCH => {
+					values.bell_pitch = Some(defaultable(value, |value| value as u16));
+				}
+				KeyboardControlMask::BELL_DURATION => {
+					values.bell_duration = Some(defaultable(value, |value| value as u16));
+				}
+				KeyboardControlMask::LED => values.led = Some(value as u8),
+				KeyboardControlMask::KEY => values.key = Some(value as Keycode),
+
+				KeyboardControlMask::LED_MODE => {
+					values.led_mode = Some(match value {
+						0 => LedMode::Off,
+						1 => LedMode::On,
+
+						other => {
+							return Err(ReadError::UnrecognizedDiscriminant(
+								u8::try_from(other).unwrap_or(u8::MAX),
+							))
+						}
+					});
+				}
+
+				KeyboardControlMask::AUTO_REPEAT_MODE => {
+					values.auto_repeat_mode = Some(match value {
+						0 => AutoRepeatMode::Off,
+						1 => AutoRepeatMode::On,
+						2 => AutoRepeatMode::Default,
+
+						other => {
+							return Err(ReadError::UnrecognizedDiscriminant(
+								u8::try_from(other).unwrap_or(u8::MAX),
+							))
+						}
+					});
+				}
+
+				_ => {}
+			}
+		}
+
+		Ok(values)
+	}
+}
+
+impl AutoRepeats {
+	/// Creates a new [`AutoRepeats`] from its bit vector.
+	#[must_use]
+	pub const fn new(bits: [u8; 32]) -> Self {
+		Self(bits)
+	}
+
+	/// Returns whether the given `key` repeats when it is held.
+	#[must_use]
+	pub const fn is_enabled(&self, key: Keycode) -> bool {
+		self.0[(key / 8) as usize] & (1 << (key % 8)) != 0
+	}
+
+	/// The keys which repeat when they are held, in ascending order.
+	pub fn keys(&self) -> impl Iterator<Item = Keycode> + '_ {
+		(0..=Keycode::MAX).filter(|&key| self.is_enabled(key))
+	}
+
+	/// The bit vector of this [`AutoRepeats`].
+	#[must_use]
+	pub const fn bits(&self) -> &[u8; 32] {
+		&self.0
+	}
+}
+
+impl ByteSize for AutoRepeats {
+	fn byte_size(&self) -> usize {
+		32
+	}
+}
+
+impl Writable for AutoRepeats {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_slice(&self.0);
+		Ok(())
+	}
+}
+
+impl Readable for AutoRepeats {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 32 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 32,
+				found: reader.remaining(),
+			})));
+		}
+
+		let mut bits = [0; 32];
+		reader.copy_to_slice(&mut bits);
+
+		Ok(Self(bits))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn values_are_written_in_mask_order() {
+		let values = KeyboardControl::new()
+			.auto_repeat(Some(38), AutoRepeatMode::Off)
+			.bell_percent(Defaultable::Default)
+			.bell_pitch(Defaultable::Specific(440));
+
+		assert_eq!(
+			values.mask(),
+			KeyboardControlMask::BELL_PERCENT
+				| KeyboardControlMask::BELL_PITCH
+				| KeyboardControlMask::KEY
+				| KeyboardControlMask::AUTO_REPEAT_MODE
+		);
+
+		let mut bytes = vec![];
+		values.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), values.byte_size());
+		assert_eq!(
+			bytes,
+			[
+				0x00, 0x00, 0x00, 0xc6, // mask
+				0xff, 0xff, 0xff, 0xff, // bell percent
+				0x00, 0x00, 0x01, 0xb8, // bell pitch
+				0x00, 0x00, 0x00, 0x26, // key
+				0x00, 0x00, 0x00, 0x00, // auto-repeat mode
+			]
+		);
+
+		assert_eq!(KeyboardControl::read_from(&mut &bytes[..]).unwrap(), values);
+	}
+
+	#[test]
+	fn out_of_range_values_are_not_written() {
+		let values = KeyboardControl::new().bell_percent(Defaultable::Specific(101));
+
+		assert_eq!(
+			values.validate(),
+			Err(BuildError::OutOfRange {
+				name: "bell percent",
+				value: 101,
+				min: 0,
+				max: 100,
+			})
+		);
+		assert!(values.write_to(&mut vec![]).is_err());
+
+		let values = KeyboardControl::new().led(Some(0), LedMode::On);
+		assert!(values.validate().is_err());
+	}
+
+	#[test]
+	fn auto_repeats_are_a_bit_vector() {
+		let mut bits = [0; 32];
+		bits[4] = 0b0100_0001;
+		bits[31] = 0b1000_0000;
+
+		let auto_repeats = AutoRepeats::new(bits);
+
+		assert!(auto_repeats.is_enabled(32));
+		assert!(!auto_repeats.is_enabled(33));
+		assert_eq!(auto_repeats.keys().collect::<Vec<_>>(), [32, 38, 255]);
+	}
+}
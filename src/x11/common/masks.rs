@@ -3,10 +3,89 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use bitflags::bitflags;
-use xrb_proc_macros::{ByteSize, StaticByteSize};
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, StaticByteSize, Writable, WriteError};
 
-bitflags! {
-	#[derive(StaticByteSize, ByteSize, Default)]
+use crate::x11::read::{check_reserved_bits, UnexpectedEndOfData};
+
+/// A flag defined for a mask, as listed in the mask's `FLAGS`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MaskFlag {
+	/// The name of the flag, such as `KEY_PRESS`.
+	pub name: &'static str,
+	/// The bit of the flag, widened to a `u32` for masks narrower than that.
+	pub bits: u32,
+}
+
+/// The flags defined for a mask, and the bits which are reserved in it.
+///
+/// Every mask has an `INFO` constant, and all of them are listed in
+/// [`MASKS`], so that masks can be shown without knowing their types, such as
+/// in an event mask picker in a debugger.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MaskInfo {
+	/// The name of the mask, such as `EventMask`.
+	pub name: &'static str,
+	/// Each flag defined for the mask, in the order they are defined.
+	pub flags: &'static [MaskFlag],
+	/// The bits which no flag is defined for, which must be zero.
+	pub reserved: u32,
+}
+
+impl MaskInfo {
+	/// Returns an iterator over the flags which are set in the given `bits`.
+	///
+	/// Reserved bits are ignored.
+	pub fn set_flags(&self, bits: u32) -> impl Iterator<Item = &'static MaskFlag> {
+		self.flags.iter().filter(move |flag| bits & flag.bits != 0)
+	}
+}
+
+/// Defines masks with `bitflags!`, along with a `FLAGS` constant listing the
+/// [`MaskFlag`] of each flag they define.
+macro_rules! masks {
+	($(
+		$(#[$outer:meta])*
+		pub struct $Mask:ident: $int:ty {
+			$(
+				// `bitflags!` can't match the flags' attributes once they
+				// have been parsed as `meta`.
+				$(#[$($inner:tt)*])*
+				const $FLAG:ident = $value:expr;
+			)*
+		}
+	)+) => {
+		bitflags! {$(
+			$(#[$outer])*
+			pub struct $Mask: $int {
+				$(
+					$(#[$($inner)*])*
+					const $FLAG = $value;
+				)*
+			}
+		)+}
+
+		$(
+			impl $Mask {
+				/// The name and bit of each flag defined for this mask, in the
+				/// order they are defined.
+				#[allow(
+					clippy::cast_lossless,
+					reason = "`u32::from` can't be used in constants"
+				)]
+				pub const FLAGS: &'static [MaskFlag] = &[$(
+					MaskFlag {
+						name: stringify!($FLAG),
+						bits: Self::$FLAG.bits() as u32,
+					},
+				)*];
+			}
+		)+
+	};
+}
+
+masks! {
+	#[derive(Default)]
 	pub struct ColorChannelMask: u8 {
 		/// Whether the red color channel is enabled.
 		const DO_RED = 0x01;
@@ -17,7 +96,8 @@ bitflags! {
 	}
 
 	/// A mask of events.
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[doc(alias = "SETofEVENT")]
+	#[derive(Default)]
 	pub struct EventMask: u32 {
 		/// Key press events.
 		const KEY_PRESS = 0x0000_0001;
@@ -129,6 +209,14 @@ bitflags! {
 		/// Events generated when there are changes to the current input focus.
 		// TODO: improve FOCUS_CHANGE docs
 		const FOCUS_CHANGE = 0x0010_0000;
+		/// Redirects attempts by other clients to resize the selected window
+		/// to the selecting client.
+		///
+		/// Rather than the window being resized, the selecting client is sent
+		/// a `ResizeRequest` event with the requested size, which it can
+		/// honor, modify, or reject. Only one client can select
+		/// `RESIZE_REDIRECT` on a window at a time.
+		const RESIZE_REDIRECT = 0x0020_0000;
 		/// Events generated when the properties of a window change.
 		const PROPERTY_CHANGE = 0x0040_0000;
 		// TODO: docs for COLORMAP_CHANGE
@@ -152,7 +240,8 @@ bitflags! {
 	/// - `PROPERTY_CHANGE`
 	/// - `COLORMAP_CHANGE`
 	/// - `OWNER_GRAB_BUTTON`
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[doc(alias = "SETofPOINTEREVENT")]
+	#[derive(Default)]
 	pub struct PointerEventMask: u32 {
 		// removes KEY_PRESS and KEY_RELEASE
 		/// Mouse button press events.
@@ -236,7 +325,8 @@ bitflags! {
 	/// - `PROPERTY_CHANGE`
 	/// - `COLORMAP_CHANGE`
 	/// - `OWNER_GRAB_BUTTON`
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[doc(alias = "SETofDEVICEEVENT")]
+	#[derive(Default)]
 	pub struct DeviceEventMask: u32 {
 		/// Key press events.
 		const KEY_PRESS = 0x0000_0001;
@@ -287,7 +377,9 @@ bitflags! {
 	///
 	/// This is the same as [`ModifierKeyMask`], but with masks for currently
 	/// held mouse buttons.
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[doc(alias = "SETofKEYBUTMASK")]
+	#[doc(alias = "KeyButtonMask")]
+	#[derive(Default)]
 	pub struct ModifierMask: u16 {
 		/// Whether `Shift` is held.
 		const SHIFT = 0x0001;
@@ -360,12 +452,14 @@ bitflags! {
 
 	/// A mask of currently held modifier keys.
 	///
-	/// This is the same as [`ButtonsModifierKeyMask`], but without mouse
+	/// This is the same as [`ModifierMask`], but without mouse
 	/// button masks. Unlike [`AnyModifierKeyMask`], this does not include a
 	/// mask for [`ANY_MODIFIER`].
 	///
 	/// [`ANY_MODIFIER`]: AnyModifierKeyMask::ANY_MODIFIER
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[doc(alias = "SETofKEYMASK")]
+	#[doc(alias = "KeyMask")]
+	#[derive(Default)]
 	pub struct ModifierKeyMask: u16 {
 		/// Whether `Shift` is held.
 		const SHIFT = 0x0001;
@@ -415,7 +509,7 @@ bitflags! {
 	/// [`ANY_MODIFIER`].
 	///
 	/// [`ANY_MODIFIER`]: AnyModifierKeyMask::ANY_MODIFIER
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[derive(Default)]
 	pub struct AnyModifierKeyMask: u16 {
 		/// Whether `Shift` is held.
 		const SHIFT = 0x0001;
@@ -429,7 +523,7 @@ bitflags! {
 		/// > common name, please [let us know].
 		///
 		/// [let us know]: https://github.com/XdotRS/xrb/issues
-		const MOD_1 = 0x0009;
+		const MOD_1 = 0x0008;
 		/// Whether 'modifier key 2' is held.
 		///
 		/// > Help wanted! If you know what `MOD_2` represents in terms of its
@@ -462,7 +556,7 @@ bitflags! {
 		const ANY_MODIFIER = 0x8000;
 	}
 
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[derive(Default)]
 	pub struct GraphicsContextMask: u32 {
 		const FUNCTION = 0x0000_0001;
 		const PLANE_MASK = 0x0000_0002;
@@ -530,7 +624,7 @@ bitflags! {
 	/// [Cursor]: crate::x11::requests::Attribute::Cursor
 	/// [`EventMask::none()`]: EventMask::none
 	/// [`DeviceEventMask::none()`]: DeviceEventMask::none
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[derive(Default)]
 	pub struct AttributeMask: u32 {
 		/// See also: [`BackgroundPixmap`]
 		///
@@ -594,7 +688,7 @@ bitflags! {
 		const CURSOR = 0x0000_4000;
 	}
 
-	#[derive(StaticByteSize, ByteSize, Default)]
+	#[derive(Default)]
 	pub struct ConfigureWindowMask: u16 {
 		const X = 0x0001;
 		const Y = 0x0002;
@@ -604,4 +698,284 @@ bitflags! {
 		const SIBLING = 0x0020;
 		const STACK_MODE = 0x0040;
 	}
+
+	/// The values given in a [`KeyboardControl`].
+	///
+	/// [`KeyboardControl`]: crate::x11::KeyboardControl
+	#[derive(Default)]
+	pub struct KeyboardControlMask: u32 {
+		const KEY_CLICK_PERCENT = 0x0001;
+		const BELL_PERCENT = 0x0002;
+		const BELL_PITCH = 0x0004;
+		const BELL_DURATION = 0x0008;
+		const LED = 0x0010;
+		const LED_MODE = 0x0020;
+		const KEY = 0x0040;
+		const AUTO_REPEAT_MODE = 0x0080;
+	}
+
+	/// The changes to a selection's owner which the XFIXES extension sends
+	/// [`SelectionNotify`] events for.
+	///
+	/// [`SelectionNotify`]: crate::x11::extensions::xfixes::SelectionNotify
+	#[derive(Default)]
+	pub struct SelectionEventMask: u32 {
+		/// The selection's owner being set with a `SetSelectionOwner`
+		/// request.
+		const SET_SELECTION_OWNER = 0x0000_0001;
+		/// The window which owns the selection being destroyed.
+		const SELECTION_WINDOW_DESTROY = 0x0000_0002;
+		/// The client which owns the selection closing its connection.
+		const SELECTION_CLIENT_CLOSE = 0x0000_0004;
+	}
+}
+
+/// Implements reading and writing masks at their declared width, their size,
+/// and iterating over the flags set in them, and lists their [`MaskInfo`] in
+/// [`MASKS`].
+///
+/// Any bits which are not defined for a mask are reserved, and must be zero.
+/// Reserved bits which are set are ignored when a mask is read, unless masks
+/// are read with [`Strictness::Strict`].
+///
+/// [`Strictness::Strict`]: crate::x11::read::Strictness::Strict
+macro_rules! impl_masks {
+	($($Mask:ident: $int:ty => ($get:ident, $put:ident)),+ $(,)?) => {
+	$(
+		impl $Mask {
+			/// The flags defined for this mask and its reserved bits.
+			#[allow(
+				clippy::cast_lossless,
+				reason = "`u32::from` can't be used in constants"
+			)]
+			pub const INFO: MaskInfo = MaskInfo {
+				name: stringify!($Mask),
+				flags: Self::FLAGS,
+				reserved: (!Self::all().bits()) as u32,
+			};
+
+			/// Returns the reserved bits set in the given `bits`, which must be
+			/// zero.
+			#[must_use]
+			pub const fn reserved_bits(bits: $int) -> $int {
+				bits & !Self::all().bits()
+			}
+
+			/// Returns an iterator over each flag set in this mask, from the
+			/// least significant bit to the most significant.
+			pub fn iter(&self) -> impl Iterator<Item = Self> {
+				let bits = self.bits();
+
+				(0..<$int>::BITS)
+					.map(|shift| 1 << shift)
+					.filter(move |bit| bits & bit != 0)
+					.map(Self::from_bits_truncate)
+			}
+		}
+
+		impl StaticByteSize for $Mask {
+			fn static_byte_size() -> usize {
+				<$int>::static_byte_size()
+			}
+		}
+
+		impl ByteSize for $Mask {
+			fn byte_size(&self) -> usize {
+				<$int>::static_byte_size()
+			}
+		}
+
+		impl Writable for $Mask {
+			fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+				writer.$put(self.bits());
+
+				Ok(())
+			}
+		}
+
+		impl Readable for $Mask {
+			/// Reads a mask at its declared width.
+			///
+			/// # Errors
+			/// Returns an [`UnexpectedReservedBits`] if any reserved bits are
+			/// set and masks are read with [`Strictness::Strict`].
+			///
+			/// [`Strictness::Strict`]: crate::x11::read::Strictness::Strict
+			fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+				const SIZE: usize = std::mem::size_of::<$int>();
+
+				if reader.remaining() < SIZE {
+					return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+						expected: SIZE,
+						found: reader.remaining(),
+					})));
+				}
+
+				let bits = reader.$get();
+				check_reserved_bits(stringify!($Mask), Self::reserved_bits(bits).into())?;
+
+				Ok(Self::from_bits_truncate(bits))
+			}
+		}
+	)+
+
+		/// The [`MaskInfo`] of every mask.
+		pub const MASKS: &[MaskInfo] = &[$($Mask::INFO),+];
+	};
+}
+
+impl_masks! {
+	ColorChannelMask: u8 => (get_u8, put_u8),
+	EventMask: u32 => (get_u32, put_u32),
+	PointerEventMask: u32 => (get_u32, put_u32),
+	DeviceEventMask: u32 => (get_u32, put_u32),
+	ModifierMask: u16 => (get_u16, put_u16),
+	ModifierKeyMask: u16 => (get_u16, put_u16),
+	AnyModifierKeyMask: u16 => (get_u16, put_u16),
+	GraphicsContextMask: u32 => (get_u32, put_u32),
+	AttributeMask: u32 => (get_u32, put_u32),
+	ConfigureWindowMask: u16 => (get_u16, put_u16),
+	KeyboardControlMask: u32 => (get_u32, put_u32),
+	SelectionEventMask: u32 => (get_u32, put_u32),
+}
+
+// Masks whose bits are a subset of another mask's can be converted into that
+// mask without losing any of them.
+
+impl From<ModifierKeyMask> for ModifierMask {
+	fn from(mask: ModifierKeyMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
+	}
+}
+
+impl From<ModifierKeyMask> for AnyModifierKeyMask {
+	fn from(mask: ModifierKeyMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
+	}
+}
+
+impl From<DeviceEventMask> for EventMask {
+	fn from(mask: DeviceEventMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn masks_are_written_at_their_width() {
+		let mut bytes = vec![];
+
+		ConfigureWindowMask::X
+			.union(ConfigureWindowMask::STACK_MODE)
+			.write_to(&mut bytes)
+			.unwrap();
+		EventMask::KEY_PRESS.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes, [0x00, 0x41, 0x00, 0x00, 0x00, 0x01]);
+
+		let reader = &mut &bytes[..];
+
+		assert_eq!(
+			ConfigureWindowMask::read_from(reader).unwrap(),
+			ConfigureWindowMask::X | ConfigureWindowMask::STACK_MODE
+		);
+		assert_eq!(EventMask::read_from(reader).unwrap(), EventMask::KEY_PRESS);
+	}
+
+	#[test]
+	fn reserved_bits_are_only_rejected_when_strict() {
+		use crate::x11::read::{Strictness, UnexpectedReservedBits};
+
+		assert_eq!(ConfigureWindowMask::reserved_bits(0x00c1), 0x0080);
+
+		let Err(ReadError::Other(error)) =
+			Strictness::Strict.read::<ConfigureWindowMask>(&mut &[0x00, 0xc1][..])
+		else {
+			panic!("a mask with reserved bits set was read strictly");
+		};
+
+		assert_eq!(
+			error.downcast_ref::<UnexpectedReservedBits>(),
+			Some(&UnexpectedReservedBits {
+				mask: "ConfigureWindowMask",
+				bits: 0x0080,
+			})
+		);
+
+		// Values are read leniently again once the strict read is done.
+		assert_eq!(
+			ConfigureWindowMask::read_from(&mut &[0x00, 0xc1][..]).unwrap(),
+			ConfigureWindowMask::X | ConfigureWindowMask::STACK_MODE
+		);
+	}
+
+	#[test]
+	fn resize_redirect_is_not_reserved() {
+		assert_eq!(EventMask::reserved_bits(0x0020_0000), 0);
+
+		let mask = EventMask::RESIZE_REDIRECT | EventMask::STRUCTURE_NOTIFY;
+
+		let mut bytes = vec![];
+		mask.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes, [0x00, 0x22, 0x00, 0x00]);
+		assert_eq!(EventMask::read_from(&mut &bytes[..]).unwrap(), mask);
+	}
+
+	#[test]
+	fn masks_are_converted_without_losing_bits() {
+		let keys = ModifierKeyMask::SHIFT | ModifierKeyMask::MOD_4;
+
+		assert_eq!(
+			ModifierMask::from(keys),
+			ModifierMask::SHIFT | ModifierMask::MOD_4
+		);
+		assert_eq!(
+			AnyModifierKeyMask::from(keys),
+			AnyModifierKeyMask::SHIFT | AnyModifierKeyMask::MOD_4
+		);
+		assert_eq!(
+			EventMask::from(DeviceEventMask::all()).bits(),
+			DeviceEventMask::all().bits()
+		);
+	}
+
+	#[test]
+	fn set_flags_are_iterated() {
+		let mask = ColorChannelMask::DO_RED | ColorChannelMask::DO_BLUE;
+
+		assert_eq!(
+			mask.iter().collect::<Vec<_>>(),
+			[ColorChannelMask::DO_RED, ColorChannelMask::DO_BLUE]
+		);
+		assert_eq!(ColorChannelMask::empty().iter().count(), 0);
+	}
+
+	#[test]
+	fn mask_flags_are_listed() {
+		let names: Vec<_> = ColorChannelMask::FLAGS
+			.iter()
+			.map(|flag| (flag.name, flag.bits))
+			.collect();
+		assert_eq!(names, [("DO_RED", 1), ("DO_GREEN", 2), ("DO_BLUE", 4)]);
+		assert_eq!(ConfigureWindowMask::INFO.reserved, 0xff80);
+		assert_eq!(ColorChannelMask::INFO.reserved, 0xf8);
+
+		let info = MASKS.iter().find(|info| info.name == "EventMask").unwrap();
+		let set: Vec<_> = info
+			.set_flags((EventMask::KEY_PRESS | EventMask::PROPERTY_CHANGE).bits())
+			.map(|flag| flag.name)
+			.collect();
+		assert_eq!(set, ["KEY_PRESS", "PROPERTY_CHANGE"]);
+
+		// Every bit is either a flag or reserved.
+		for info in MASKS {
+			let flags = info.flags.iter().fold(0, |bits, flag| bits | flag.bits);
+
+			assert_eq!(flags & info.reserved, 0, "{}", info.name);
+		}
+	}
 }
@@ -2,18 +2,74 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use xrb_proc_macros::{ByteSize, StaticByteSize};
-
+use std::fmt;
+
+use xrbk_macro::{ByteSize, StaticByteSize};
+
+mod any_event;
+mod build_error;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/color.rs"
+)]
+mod color;
+mod configure_window_values;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/fixed.rs"
+)]
+mod fixed;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/font.rs"
+)]
+mod font;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/geometry.rs"
+)]
+mod geometry;
+mod graphics_context_values;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/id/mod.rs"
+)]
 mod id;
+mod keyboard_control;
 mod masks;
+mod pointer_control;
+mod points;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/string.rs"
+)]
 mod string;
+mod text;
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/common/values.rs"
+)]
 mod values;
+mod window_attributes;
 mod wrappers;
 
+pub use any_event::*;
+pub use build_error::*;
+pub use color::*;
+pub use configure_window_values::*;
+pub use fixed::*;
+pub use font::*;
+pub use geometry::*;
+pub use graphics_context_values::*;
 pub use id::*;
+pub use keyboard_control::*;
 pub use masks::*;
+pub use pointer_control::*;
+pub use points::*;
 pub use string::*;
+pub use text::*;
 pub use values::*;
+pub use window_attributes::*;
 pub use wrappers::*;
 
 pub use id::atoms::Atom;
@@ -24,19 +80,116 @@ pub enum Status {
 	Busy,
 }
 
+/// Whether a [`ForceScreenSaver`] request activates the screen saver or
+/// resets it.
+///
+/// [`ForceScreenSaver`]: super::requests::ForceScreenSaver
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum ScreenSaverMode {
+	/// Deactivates the screen saver if it is active, and restarts its timeout
+	/// as if the keyboard or pointer had been used.
 	Reset,
+	/// Activates the screen saver, even if it is disabled.
 	Activate,
 }
 
+/// Whether the screen is blanked when the screen saver is activated, rather
+/// than showing a changing pattern.
+///
+/// This is set with a [`SetScreenSaver`] request, and returned in a
+/// [`GetScreenSaverReply`].
+///
+/// [`SetScreenSaver`]: super::requests::SetScreenSaver
+/// [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum Blanking {
+	/// A changing pattern is shown.
+	No,
+	/// The screen is blanked if the hardware supports it.
+	Yes,
+	/// The X server's default is restored.
+	///
+	/// This is never returned in a [`GetScreenSaverReply`].
+	///
+	/// [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply
+	Default,
+}
+
+/// Whether the screen saver may be activated while the screen can't be
+/// blanked and regenerating it would generate exposure events.
+///
+/// This is set with a [`SetScreenSaver`] request, and returned in a
+/// [`GetScreenSaverReply`].
+///
+/// [`SetScreenSaver`]: super::requests::SetScreenSaver
+/// [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum Exposures {
+	/// The screen saver is not activated until exposures are no longer
+	/// needed.
+	No,
+	/// The screen saver is activated, and windows are exposed once it is
+	/// deactivated.
+	Yes,
+	/// The X server's default is restored.
+	///
+	/// This is never returned in a [`GetScreenSaverReply`].
+	///
+	/// [`GetScreenSaverReply`]: super::requests::GetScreenSaverReply
+	Default,
+}
+
+/// What happens to a client's resources when its connection is closed, as
+/// set by a [`SetCloseDownMode`] request.
+///
+/// [`SetCloseDownMode`]: super::requests::SetCloseDownMode
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum CloseDownMode {
+	/// The client's resources are destroyed.
 	Destroy,
+	/// The client's resources are kept until they are killed with a
+	/// `KillClient` request.
 	RetainPermanent,
+	/// The client's resources are kept until they are killed with a
+	/// `KillClient` request with `AllTemporary`.
 	RetainTemporary,
 }
 
+impl fmt::Display for CloseDownMode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Destroy => "destroy resources",
+			Self::RetainPermanent => "retain resources permanently",
+			Self::RetainTemporary => "retain resources temporarily",
+		})
+	}
+}
+
+/// Whether the X server only accepts connections from hosts in its access
+/// control list.
+///
+/// This is set with a [`SetAccessControl`] request, and returned in a
+/// [`ListHostsReply`].
+///
+/// [`SetAccessControl`]: super::requests::SetAccessControl
+/// [`ListHostsReply`]: super::requests::ListHostsReply
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum AccessControl {
+	/// Connections are accepted from any host.
+	Disable,
+	/// Connections are only accepted from hosts in the access control list.
+	Enable,
+}
+
+impl fmt::Display for AccessControl {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Disable => "access control disabled",
+			Self::Enable => "access control enabled",
+		})
+	}
+}
+
 /// The 'type' of 'best size' being queried in a [`QueryBestSize`] request.
 ///
 /// [`QueryBestSize`]: super::QueryBestSize
@@ -72,12 +225,6 @@ impl Default for CoordinateMode {
 	}
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize, Default)]
-pub struct Segment {
-	pub start: (i16, i16),
-	pub end: (i16, i16),
-}
-
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum Ordering {
 	Unsorted,
@@ -98,31 +245,42 @@ impl Default for DrawDirection {
 	}
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
-pub struct FontProperty {
-	pub name: Atom,
-	pub value: u32,
-}
-
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
-pub struct CharInfo {
-	pub left_side_bearing: i16,
-	pub right_side_bearing: i16,
-	pub character_width: i16,
-	pub ascent: i16,
-	pub descent: i16,
-	pub attributes: u16,
-}
-
+/// Which frozen devices are released by an [`AllowEvents`] request, and how.
+///
+/// A device is frozen by a grab with a [`GrabMode::Sync`] mode: events for
+/// it are queued rather than sent until it is released.
+///
+/// [`AllowEvents`]: super::requests::AllowEvents
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum AllowEventsMode {
+	/// Releases the pointer, if it is frozen by this client.
 	AsyncPointer,
+	/// Releases the pointer, if it is frozen and actively grabbed by this
+	/// client, until the next `ButtonPress` or `ButtonRelease` event is
+	/// reported to this client, after which it is frozen again.
 	SyncPointer,
+	/// If the pointer is actively grabbed by this client and frozen by the
+	/// activation of a passive grab, the grab is released and the event which
+	/// activated it is processed again, ignoring passive grabs on the grab
+	/// window and its ancestors.
 	ReplayPointer,
+	/// Releases the keyboard, if it is frozen by this client.
 	AsyncKeyboard,
+	/// Releases the keyboard, if it is frozen and actively grabbed by this
+	/// client, until the next `KeyPress` or `KeyRelease` event is reported to
+	/// this client, after which it is frozen again.
 	SyncKeyboard,
+	/// If the keyboard is actively grabbed by this client and frozen by the
+	/// activation of a passive grab, the grab is released and the event which
+	/// activated it is processed again, ignoring passive grabs on the grab
+	/// window and its ancestors.
 	ReplayKeyboard,
+	/// Releases both the pointer and the keyboard, if they are both frozen by
+	/// this client.
 	AsyncBoth,
+	/// Releases both the pointer and the keyboard, if they are both frozen by
+	/// this client, until the next pointer button or key event is reported
+	/// to this client, after which they are both frozen again.
 	SyncBoth,
 }
 
@@ -145,31 +303,103 @@ impl Default for HostFamilyA {
 	}
 }
 
+/// How events for a device are processed while it is grabbed.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum GrabMode {
-	Synchronous,
-	Asynchronous,
+	/// The device is frozen: its events are queued, rather than processed,
+	/// until the grabbing client releases it with an [`AllowEvents`] request
+	/// or the grab is released.
+	///
+	/// [`AllowEvents`]: super::requests::AllowEvents
+	#[doc(alias = "Synchronous")]
+	Sync,
+	/// Events for the device continue to be processed as usual.
+	#[doc(alias = "Asynchronous")]
+	Async,
 }
 
 impl Default for GrabMode {
 	fn default() -> Self {
-		Self::Asynchronous
+		Self::Async
 	}
 }
 
+/// Whether a [`GrabPointer`] or [`GrabKeyboard`] request succeeded, or, if it
+/// did not, why.
+///
+/// [`GrabPointer`]: super::requests::GrabPointer
+/// [`GrabKeyboard`]: super::requests::GrabKeyboard
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum GrabStatus {
+	/// The device was grabbed.
 	Success,
+	/// The device is already actively grabbed by another client.
 	AlreadyGrabbed,
+	/// The time given is earlier than the last time the device was grabbed,
+	/// or later than the current time.
 	InvalidTime,
+	/// The grab window, or the window the pointer is confined to, is not
+	/// viewable, or the window the pointer is confined to is completely
+	/// outside of the root window.
 	NotViewable,
+	/// The device is frozen by an active grab of another client.
 	Frozen,
 }
 
+impl GrabStatus {
+	/// Returns whether the device was grabbed.
+	#[must_use]
+	pub const fn is_success(self) -> bool {
+		matches!(self, Self::Success)
+	}
+}
+
+/// The direction in which the children of a window are circulated by a
+/// [`CirculateWindow`] request.
+///
+/// [`CirculateWindow`]: super::requests::CirculateWindow
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum CirculateDirection {
+	/// Raises the lowest occluded child to the top of the stack.
 	RaiseLowest,
-	RaiseHighest,
+	/// Lowers the highest child which occludes another to the bottom of the
+	/// stack.
+	LowerHighest,
+}
+
+/// Where a window was placed in (or is to be placed in) the stack of its
+/// siblings as the result of a [`CirculateWindow`] request.
+///
+/// [`CirculateWindow`]: super::requests::CirculateWindow
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum Place {
+	/// The top of the stack, above all of its siblings.
+	Top,
+	/// The bottom of the stack, below all of its siblings.
+	Bottom,
+}
+
+/// How a property was changed, as reported in a [`PropertyNotify`] event.
+///
+/// [`PropertyNotify`]: super::events::PropertyNotify
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum PropertyState {
+	/// The property was given a new value, even if it is the same as its old
+	/// value.
+	NewValue,
+	/// The property was deleted.
+	Deleted,
+}
+
+/// Whether a colormap is installed, as reported in a [`ColormapNotify`] event.
+///
+/// [`ColormapNotify`]: super::events::ColormapNotify
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum ColormapState {
+	/// The colormap is not installed.
+	Uninstalled,
+	/// The colormap is installed.
+	Installed,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
@@ -190,12 +420,27 @@ pub enum Format {
 	Zpixmap = 2,
 }
 
+/// How a window is restacked by a `ConfigureWindow` request.
+///
+/// If a sibling is given, the window is restacked relative to that sibling;
+/// otherwise, it is restacked relative to all of its siblings.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum StackMode {
+	/// The window is placed just above the sibling, or at the top of the
+	/// stack.
 	Above,
+	/// The window is placed just below the sibling, or at the bottom of the
+	/// stack.
 	Below,
+	/// The window is placed at the top of the stack if the sibling, or any
+	/// sibling, occludes it.
 	TopIf,
-	Bottomif,
+	/// The window is placed at the bottom of the stack if it occludes the
+	/// sibling, or any sibling.
+	BottomIf,
+	/// The window is placed at the top of the stack if the sibling, or any
+	/// sibling, occludes it, or at the bottom of the stack if it occludes the
+	/// sibling, or any sibling.
 	Opposite,
 }
 
@@ -205,6 +450,25 @@ impl Default for StackMode {
 	}
 }
 
+/// Whether a keyboard LED is turned on or off by a [`KeyboardControl`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum LedMode {
+	Off,
+	On,
+}
+
+/// Whether keys repeat when they are held, as set by a [`KeyboardControl`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+pub enum AutoRepeatMode {
+	Off,
+	On,
+	/// The key is restored to its default auto-repeat mode.
+	///
+	/// This may only be used for a specific key; it can't be used to restore
+	/// the global auto-repeat mode.
+	Default,
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum MapState {
 	Unmapped,
@@ -279,50 +543,6 @@ impl Default for WinGravity {
 	}
 }
 
-/// A rectangle with coordinates and dimensions.
-///
-/// The coordinates are those of the upper-left corner of the rectangle. The
-/// units for the coordinates and dimensions are not specified.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
-pub struct Rectangle {
-	/// X-coordinate of the upper-left corner of the rectangle.
-	pub x: i16,
-	/// Y-coordinate of the upper-left corner of the rectangle.
-	pub y: i16,
-	/// Width of the rectangle.
-	pub width: u16,
-	/// Height of the rectangle.
-	pub height: u16,
-}
-
-impl Default for Rectangle {
-	fn default() -> Self {
-		Self {
-			x: 0,
-			y: 0,
-			width: 1,
-			height: 1,
-		}
-	}
-}
-
-/// An arc (the geometry kind) with coordinates, dimensions, and angles.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
-pub struct GeomArc {
-	/// X-coordinate of the arc.
-	pub x: i16,
-	/// Y-coordinate of the arc.
-	pub y: i16,
-	/// Width of the arc.
-	pub width: u16,
-	/// Height of the arc.
-	pub height: u16,
-	/// The start angle of the arc.
-	pub start: i16,
-	/// The end angle of the arc.
-	pub end: i16,
-}
-
 #[derive(Clone, Eq, PartialEq, Hash, Debug, ByteSize)]
 pub struct Host {
 	/// The protocol family of the host, e.g. [InternetV6](HostFamily::InternetV6).
@@ -412,6 +632,7 @@ impl Default for RevertTo {
 /// The destination for an [`Event`] in a [`SendEvent`] request.
 ///
 /// This is the window that the event will be sent to.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum Destination {
 	/// The [`Window`] the pointer is currently within.
 	PointerWindow,
@@ -0,0 +1,261 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, StaticByteSize, Writable, WriteError};
+
+use crate::x11::{read::UnexpectedEndOfData, BuildError, Defaultable};
+
+/// How much faster than the physical device the pointer moves once it has
+/// moved more than the threshold at once, as a fraction.
+///
+/// For example, an acceleration of `3/2` moves the pointer one and a half
+/// times as fast.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Acceleration {
+	/// The numerator of the fraction.
+	///
+	/// In a [`ChangePointerControl`] request, numerators above [`i16::MAX`]
+	/// generate a [`Value`] error.
+	///
+	/// [`ChangePointerControl`]: super::requests::ChangePointerControl
+	/// [`Value`]: crate::x11::errors::Value
+	pub numerator: u16,
+	/// The denominator of the fraction.
+	///
+	/// In a [`ChangePointerControl`] request, a denominator of `0` or above
+	/// [`i16::MAX`] generates a [`Value`] error.
+	///
+	/// [`ChangePointerControl`]: super::requests::ChangePointerControl
+	/// [`Value`]: crate::x11::errors::Value
+	pub denominator: u16,
+}
+
+/// The pointer settings changed by a [`ChangePointerControl`] request, each
+/// of which is only changed if it is [`Some`].
+///
+/// # Examples
+/// ```ignore
+/// connection.send(ChangePointerControl {
+///     values: PointerControl::new()
+///         .acceleration(Defaultable::Specific(Acceleration::new(2, 1)))
+///         .threshold(Defaultable::Default),
+/// })?;
+/// ```
+///
+/// [`ChangePointerControl`]: super::requests::ChangePointerControl
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PointerControl {
+	/// How much faster than the physical device the pointer moves once it has
+	/// moved more than the [`threshold`] at once.
+	///
+	/// [`threshold`]: PointerControl::threshold
+	pub acceleration: Option<Defaultable<Acceleration>>,
+	/// The number of pixels the pointer must move at once before its
+	/// [`acceleration`] applies.
+	///
+	/// Thresholds above [`i16::MAX`] generate a [`Value`] error.
+	///
+	/// [`acceleration`]: PointerControl::acceleration
+	/// [`Value`]: crate::x11::errors::Value
+	pub threshold: Option<Defaultable<u16>>,
+}
+
+impl Acceleration {
+	/// Creates a new [`Acceleration`] of `numerator / denominator`.
+	#[must_use]
+	pub const fn new(numerator: u16, denominator: u16) -> Self {
+		Self {
+			numerator,
+			denominator,
+		}
+	}
+
+	/// The value of the fraction, or [`None`] if the denominator is `0`.
+	#[must_use]
+	pub fn as_f64(self) -> Option<f64> {
+		(self.denominator != 0).then(|| f64::from(self.numerator) / f64::from(self.denominator))
+	}
+}
+
+impl PointerControl {
+	/// Creates a new [`PointerControl`] with no values given.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			acceleration: None,
+			threshold: None,
+		}
+	}
+
+	/// Sets how much faster than the physical device the pointer moves.
+	#[must_use]
+	pub const fn acceleration(mut self, acceleration: Defaultable<Acceleration>) -> Self {
+		self.acceleration = Some(acceleration);
+		self
+	}
+
+	/// Sets the number of pixels the pointer must move at once before it is
+	/// accelerated.
+	#[must_use]
+	pub const fn threshold(mut self, threshold: Defaultable<u16>) -> Self {
+		self.threshold = Some(threshold);
+		self
+	}
+
+	/// Checks that each of the values given is within the range which the X
+	/// server accepts for it.
+	///
+	/// This is checked when the values are written.
+	///
+	/// # Errors
+	/// Returns a [`BuildError::OutOfRange`] if the acceleration's numerator or
+	/// the threshold is above [`i16::MAX`], or if the acceleration's
+	/// denominator is not between `1` and [`i16::MAX`].
+	pub fn validate(&self) -> Result<(), BuildError> {
+		if let Some(Defaultable::Specific(acceleration)) = self.acceleration {
+			BuildError::check_range(
+				"acceleration numerator",
+				acceleration.numerator,
+				0,
+				i16::MAX,
+			)?;
+			BuildError::check_range(
+				"acceleration denominator",
+				acceleration.denominator,
+				1,
+				i16::MAX,
+			)?;
+		}
+
+		if let Some(Defaultable::Specific(threshold)) = self.threshold {
+			BuildError::check_range("threshold", threshold, 0, i16::MAX)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl StaticByteSize for Acceleration {
+	fn static_byte_size() -> usize {
+		4
+	}
+}
+
+impl ByteSize for Acceleration {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+
+impl Writable for Acceleration {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u16(self.numerator);
+		writer.put_u16(self.denominator);
+
+		Ok(())
+	}
+}
+
+impl Readable for Acceleration {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 4 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 4,
+				found: reader.remaining(),
+			})));
+		}
+
+		Ok(Self::new(reader.get_u16(), reader.get_u16()))
+	}
+}
+
+impl ByteSize for PointerControl {
+	fn byte_size(&self) -> usize {
+		8
+	}
+}
+
+impl Writable for PointerControl {
+	/// Writes the acceleration's numerator and denominator and the threshold,
+	/// each of which is `-1` if it is the default, followed by whether the
+	/// acceleration and the threshold are changed.
+	///
+	/// # Errors
+	/// Returns a [`BuildError`] if any of the values are out of range; see
+	/// [`PointerControl::validate`].
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		self.validate()?;
+
+		// The acceleration and threshold are ignored if they are not changed.
+		self.acceleration
+			.unwrap_or(Defaultable::Specific(Acceleration::new(0, 0)))
+			.write_to(writer)?;
+		self.threshold
+			.unwrap_or(Defaultable::Specific(0))
+			.write_to(writer)?;
+		writer.put_u8(u8::from(self.acceleration.is_some()));
+		writer.put_u8(u8::from(self.threshold.is_some()));
+
+		Ok(())
+	}
+}
+
+impl Readable for PointerControl {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 8 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 8,
+				found: reader.remaining(),
+			})));
+		}
+
+		let acceleration = Defaultable::<Acceleration>::read_from(reader)?;
+		let threshold = Defaultable::<u16>::read_from(reader)?;
+
+		let acceleration = (reader.get_u8() != 0).then_some(acceleration);
+		let threshold = (reader.get_u8() != 0).then_some(threshold);
+
+		Ok(Self {
+			acceleration,
+			threshold,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn unchanged_values_are_not_applied() {
+		let control =
+			PointerControl::new().acceleration(Defaultable::Specific(Acceleration::new(3, 2)));
+
+		let mut bytes = vec![];
+		control.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), control.byte_size());
+		assert_eq!(bytes, [0, 3, 0, 2, 0, 0, 1, 0]);
+		assert_eq!(PointerControl::read_from(&mut &bytes[..]).unwrap(), control);
+
+		let control = PointerControl::new()
+			.acceleration(Defaultable::Default)
+			.threshold(Defaultable::Default);
+
+		let mut bytes = vec![];
+		control.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes, [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 1, 1]);
+		assert_eq!(PointerControl::read_from(&mut &bytes[..]).unwrap(), control);
+
+		let control =
+			PointerControl::new().acceleration(Defaultable::Specific(Acceleration::new(3, 0)));
+		assert!(control.validate().is_err());
+		assert!(control.write_to(&mut vec![]).is_err());
+
+		assert_eq!(Acceleration::new(3, 2).as_f64(), Some(1.5));
+		assert_eq!(Acceleration::new(3, 0).as_f64(), None);
+	}
+}
@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::x11::{
+	requests::{FillPoly, PolyLine, PolyPoint},
+	CoordinateMode, Drawable, GraphicsContext, Point, Shape,
+};
+
+/// A list of points drawn by the [`PolyPoint`], [`PolyLine`], and [`FillPoly`]
+/// requests, written in a particular [`CoordinateMode`].
+///
+/// Points are always given relative to the origin of the drawable. If the
+/// `coordinate_mode` is [`CoordinateMode::Previous`], they are converted to
+/// be relative to the previous point as they are added.
+///
+/// `Points` can be collected from an iterator of [`Point`]s or `(x, y)`
+/// tuples, and extended with more:
+/// ```ignore
+/// let mut points: Points = (0..10).map(|x| (x * 10, x * x)).collect();
+/// points.extend([(100, 100), (0, 100)]);
+///
+/// let request = points.poly_line(drawable, context);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Points {
+	coordinate_mode: CoordinateMode,
+	/// The points as they are written in the request.
+	written: Vec<Point>,
+	/// The last point added, relative to the origin.
+	last: Option<Point>,
+}
+
+impl Points {
+	/// Creates a new, empty list of points written in the given
+	/// `coordinate_mode`.
+	#[must_use]
+	pub const fn new(coordinate_mode: CoordinateMode) -> Self {
+		Self {
+			coordinate_mode,
+			written: Vec::new(),
+			last: None,
+		}
+	}
+
+	/// The [`CoordinateMode`] in which these points are written.
+	#[must_use]
+	pub const fn coordinate_mode(&self) -> CoordinateMode {
+		self.coordinate_mode
+	}
+
+	/// Converts these points to be written in the given `coordinate_mode`.
+	#[must_use]
+	pub fn with_coordinate_mode(self, coordinate_mode: CoordinateMode) -> Self {
+		if coordinate_mode == self.coordinate_mode {
+			return self;
+		}
+
+		let mut points = Self::new(coordinate_mode);
+		points.extend(self.iter());
+
+		points
+	}
+
+	/// Adds a `point`, given relative to the origin of the drawable.
+	pub fn push(&mut self, point: impl Into<Point>) {
+		let point = point.into();
+
+		let written = match (self.coordinate_mode, self.last) {
+			(CoordinateMode::Previous, Some(last)) => {
+				Point::new(point.x.wrapping_sub(last.x), point.y.wrapping_sub(last.y))
+			}
+
+			_ => point,
+		};
+
+		self.written.push(written);
+		self.last = Some(point);
+	}
+
+	/// Returns an iterator over the points relative to the origin of the
+	/// drawable, regardless of the `coordinate_mode`.
+	pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+		let relative = self.coordinate_mode == CoordinateMode::Previous;
+
+		self.written
+			.iter()
+			.scan(None, move |last: &mut Option<Point>, &written| {
+				let point = match *last {
+					Some(last) if relative => last.translate(written.x, written.y),
+
+					_ => written,
+				};
+
+				*last = Some(point);
+				Some(point)
+			})
+	}
+
+	/// The points as they are written in the request, according to the
+	/// `coordinate_mode`.
+	#[must_use]
+	pub fn as_slice(&self) -> &[Point] {
+		&self.written
+	}
+
+	/// The number of points.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.written.len()
+	}
+
+	/// Returns whether there are no points.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.written.is_empty()
+	}
+
+	/// Creates a [`PolyPoint`] request drawing these points.
+	#[must_use]
+	pub fn poly_point(&self, drawable: Drawable, context: GraphicsContext) -> PolyPoint<'_> {
+		PolyPoint {
+			coordinate_mode: self.coordinate_mode,
+			drawable,
+			context,
+			points: &self.written,
+		}
+	}
+
+	/// Creates a [`PolyLine`] request drawing lines between these points.
+	#[must_use]
+	pub fn poly_line(&self, drawable: Drawable, context: GraphicsContext) -> PolyLine<'_> {
+		PolyLine {
+			coordinate_mode: self.coordinate_mode,
+			drawable,
+			context,
+			points: &self.written,
+		}
+	}
+
+	/// Creates a [`FillPoly`] request filling the polygon with these points as
+	/// its vertices.
+	#[must_use]
+	pub fn fill_poly(
+		&self,
+		drawable: Drawable,
+		context: GraphicsContext,
+		shape: Shape,
+	) -> FillPoly<'_> {
+		FillPoly {
+			drawable,
+			context,
+			shape,
+			coordinate_mode: self.coordinate_mode,
+			points: &self.written,
+		}
+	}
+}
+
+impl<P: Into<Point>> FromIterator<P> for Points {
+	/// Collects points written in [`CoordinateMode::Origin`].
+	fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+		let mut points = Self::new(CoordinateMode::Origin);
+		points.extend(iter);
+
+		points
+	}
+}
+
+impl<P: Into<Point>> Extend<P> for Points {
+	fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+		let iter = iter.into_iter();
+		self.written.reserve(iter.size_hint().0);
+
+		for point in iter {
+			self.push(point);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn previous_mode_points_are_relative() {
+		let points = [(10, 10), (15, 5), (0, 0)]
+			.into_iter()
+			.collect::<Points>()
+			.with_coordinate_mode(CoordinateMode::Previous);
+
+		assert_eq!(
+			points.as_slice(),
+			[Point::new(10, 10), Point::new(5, -5), Point::new(-15, -5)]
+		);
+	}
+
+	#[test]
+	fn points_convert_back_to_origin_mode() {
+		let mut points = Points::new(CoordinateMode::Previous);
+		points.extend([(-3, 7), (i16::MAX, i16::MIN), (1, 2)]);
+
+		let points = points.with_coordinate_mode(CoordinateMode::Origin);
+
+		assert_eq!(
+			points.iter().map(<(i16, i16)>::from).collect::<Vec<_>>(),
+			[(-3, 7), (i16::MAX, i16::MIN), (1, 2)]
+		);
+	}
+}
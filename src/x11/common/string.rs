@@ -3,7 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use cornflakes::*;
-use xrb_proc_macros::ByteSize;
+use xrbk_macro::ByteSize;
 
 use std::io::Error;
 use std::string::{FromUtf8Error, String};
@@ -0,0 +1,391 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{read::ensure_remaining, BuildError, Font, ResId};
+
+/// The maximum number of characters in a single [`TextItem::Text`].
+///
+/// A string length of `255` is used to indicate a [`TextItem::FontChange`]
+/// instead.
+pub const MAX_TEXT_ITEM_LEN: usize = 254;
+
+/// The string length which indicates a [`TextItem::FontChange`].
+const FONT_CHANGE: u8 = 255;
+
+/// An item in the list of items drawn by the `PolyText8` and `PolyText16`
+/// requests.
+///
+/// Rather than assembling these by hand, consider using a
+/// [`TextItemsBuilder`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TextItem<T> {
+	/// A string of text to draw.
+	Text {
+		/// The change in the x-coordinate of the position at which to draw
+		/// the `string`, relative to the end of the previous text item.
+		delta: i8,
+		/// The characters to draw: no more than [`MAX_TEXT_ITEM_LEN`] of them.
+		string: Vec<T>,
+	},
+
+	/// A change of the font used to draw the following text items.
+	FontChange(Font),
+}
+
+/// A [`TextItem`] used in `PolyText8` requests.
+pub type TextItem8 = TextItem<u8>;
+/// A [`TextItem`] used in `PolyText16` requests.
+pub type TextItem16 = TextItem<(u8, u8)>;
+
+/// A character which may be used in a [`TextItem`].
+pub trait TextChar: Copy {
+	/// The number of bytes the character is encoded as.
+	const SIZE: usize;
+
+	/// Writes the character to the given `writer`.
+	fn put(self, writer: &mut impl BufMut);
+	/// Reads a character from the given `reader`.
+	fn get(reader: &mut impl Buf) -> Self;
+}
+
+impl TextChar for u8 {
+	const SIZE: usize = 1;
+
+	fn put(self, writer: &mut impl BufMut) {
+		writer.put_u8(self);
+	}
+
+	fn get(reader: &mut impl Buf) -> Self {
+		reader.get_u8()
+	}
+}
+
+impl TextChar for (u8, u8) {
+	const SIZE: usize = 2;
+
+	fn put(self, writer: &mut impl BufMut) {
+		writer.put_u8(self.0);
+		writer.put_u8(self.1);
+	}
+
+	fn get(reader: &mut impl Buf) -> Self {
+		(reader.get_u8(), reader.get_u8())
+	}
+}
+
+/// Builds a list of [`TextItem`]s.
+///
+/// Strings longer than [`MAX_TEXT_ITEM_LEN`] are split across multiple
+/// [`TextItem::Text`]s, deltas which do not fit in an `i8` are spread across
+/// empty [`TextItem::Text`]s, and [`TextItem::FontChange`]s are only added
+/// when the font actually changes.
+///
+/// # Examples
+/// ```ignore
+/// let items = TextItemsBuilder::new()
+///     .font(regular)
+///     .text(b"Hello, ")
+///     .font(bold)
+///     .delta(2)
+///     .text(b"world!")
+///     .build();
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TextItemsBuilder<T> {
+	items: Vec<TextItem<T>>,
+	/// The font used for the text added next, if one has been set.
+	font: Option<Font>,
+	/// The delta which has not yet been applied to a [`TextItem::Text`].
+	delta: i32,
+}
+
+impl<T: TextChar> TextItemsBuilder<T> {
+	/// Creates a new [`TextItemsBuilder`] with no items.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			items: Vec::new(),
+			font: None,
+			delta: 0,
+		}
+	}
+
+	/// Uses the given `font` for the text added after this.
+	///
+	/// If `font` is already the current font, no [`TextItem::FontChange`] is
+	/// added.
+	#[must_use]
+	pub fn font(mut self, font: Font) -> Self {
+		if self.font != Some(font) {
+			self.items.push(TextItem::FontChange(font));
+			self.font = Some(font);
+		}
+
+		self
+	}
+
+	/// Moves the text added after this by `delta` along the x axis.
+	#[must_use]
+	pub fn delta(mut self, delta: i16) -> Self {
+		self.delta += i32::from(delta);
+		self
+	}
+
+	/// Adds the given `text`, split across as many [`TextItem::Text`]s as
+	/// necessary.
+	#[must_use]
+	pub fn text(mut self, text: &[T]) -> Self {
+		for string in text.chunks(MAX_TEXT_ITEM_LEN) {
+			let delta = self.take_delta();
+
+			self.items.push(TextItem::Text {
+				delta,
+				string: string.to_vec(),
+			});
+		}
+
+		self
+	}
+
+	/// Returns the built list of [`TextItem`]s.
+	///
+	/// Any delta which was not followed by text is discarded, as it has no
+	/// effect.
+	#[must_use]
+	pub fn build(self) -> Vec<TextItem<T>> {
+		self.items
+	}
+
+	/// Takes the pending delta for the next [`TextItem::Text`].
+	///
+	/// Any part of the delta that doesn't fit in an `i8` is added as empty
+	/// [`TextItem::Text`]s.
+	fn take_delta(&mut self) -> i8 {
+		loop {
+			if let Ok(delta) = i8::try_from(self.delta) {
+				self.delta = 0;
+
+				return delta;
+			}
+
+			let step = if self.delta > 0 { i8::MAX } else { i8::MIN };
+
+			self.items.push(TextItem::Text {
+				delta: step,
+				string: Vec::new(),
+			});
+			self.delta -= i32::from(step);
+		}
+	}
+}
+
+impl<T: TextChar> Default for TextItemsBuilder<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: TextChar> ByteSize for TextItem<T> {
+	fn byte_size(&self) -> usize {
+		match self {
+			// String length, delta, and the string itself.
+			Self::Text { string, .. } => 2 + string.len() * T::SIZE,
+			// 255, then the font.
+			Self::FontChange(_) => 5,
+		}
+	}
+}
+
+impl<T: TextChar> Writable for TextItem<T> {
+	/// Writes this [`TextItem`].
+	///
+	/// # Errors
+	/// Returns a [`BuildError::TooLong`] if a [`TextItem::Text`]'s string is
+	/// longer than [`MAX_TEXT_ITEM_LEN`]. A [`TextItemsBuilder`] splits long
+	/// strings so that this does not happen.
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "`TextItem::Text`'s string length is checked not to exceed `MAX_TEXT_ITEM_LEN`"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		match self {
+			Self::Text { delta, string } => {
+				if string.len() > MAX_TEXT_ITEM_LEN {
+					return Err(BuildError::TooLong {
+						name: "text item",
+						max: MAX_TEXT_ITEM_LEN,
+						len: string.len(),
+					}
+					.into());
+				}
+
+				writer.put_u8(string.len() as u8);
+				writer.put_i8(*delta);
+
+				for r#char in string {
+					r#char.put(writer);
+				}
+			}
+
+			Self::FontChange(font) => {
+				writer.put_u8(FONT_CHANGE);
+				// The font is always written most significant byte first,
+				// regardless of the connection's byte order.
+				writer.put_slice(&font.res_id().to_be_bytes());
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl<T: TextChar> Readable for TextItem<T> {
+	/// Reads a [`TextItem`].
+	///
+	/// # Errors
+	/// Returns an [`UnexpectedEndOfData`] error if the `reader` ends before
+	/// the text item does.
+	///
+	/// [`UnexpectedEndOfData`]: crate::x11::read::UnexpectedEndOfData
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure_remaining(reader, 1)?;
+
+		Ok(match reader.get_u8() {
+			FONT_CHANGE => {
+				ensure_remaining(reader, 4)?;
+
+				let mut font = [0; 4];
+				reader.copy_to_slice(&mut font);
+
+				Self::FontChange(Font::new(u32::from_be_bytes(font)))
+			}
+
+			len => {
+				// The delta, followed by the string.
+				ensure_remaining(reader, 1 + usize::from(len) * T::SIZE)?;
+
+				Self::Text {
+					delta: reader.get_i8(),
+					string: (0..len).map(|_| T::get(reader)).collect(),
+				}
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn long_text_is_split() {
+		let items = TextItemsBuilder::<u8>::new()
+			.delta(3)
+			.text(&[b'a'; MAX_TEXT_ITEM_LEN + 1])
+			.build();
+
+		assert_eq!(
+			items,
+			[
+				TextItem::Text {
+					delta: 3,
+					string: vec![b'a'; MAX_TEXT_ITEM_LEN],
+				},
+				TextItem::Text {
+					delta: 0,
+					string: vec![b'a'],
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn overlong_text_items_are_not_written() {
+		let item = TextItem8::Text {
+			delta: 0,
+			string: vec![b'a'; MAX_TEXT_ITEM_LEN + 1],
+		};
+
+		assert!(item.write_to(&mut vec![]).is_err());
+	}
+
+	#[test]
+	fn large_delta_uses_empty_items() {
+		let items = TextItemsBuilder::<u8>::new().delta(300).text(b"a").build();
+
+		assert_eq!(
+			items,
+			[
+				TextItem::Text {
+					delta: 127,
+					string: vec![],
+				},
+				TextItem::Text {
+					delta: 127,
+					string: vec![],
+				},
+				TextItem::Text {
+					delta: 46,
+					string: vec![b'a'],
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn font_changes_only_when_different() {
+		let (a, b) = (Font::new(1), Font::new(2));
+
+		let items = TextItemsBuilder::<u8>::new()
+			.font(a)
+			.text(b"x")
+			.font(a)
+			.text(b"y")
+			.font(b)
+			.build();
+
+		assert_eq!(
+			items,
+			[
+				TextItem::FontChange(a),
+				TextItem::Text {
+					delta: 0,
+					string: vec![b'x'],
+				},
+				TextItem::Text {
+					delta: 0,
+					string: vec![b'y'],
+				},
+				TextItem::FontChange(b),
+			]
+		);
+	}
+
+	#[test]
+	fn truncated_text_items_are_not_read() {
+		use crate::x11::read::UnexpectedEndOfData;
+
+		let truncated: [&[u8]; 4] = [&[], &[FONT_CHANGE, 0, 0, 1], &[3, 0, b'a', b'b'], &[2]];
+		let expected = [(1, 0), (4, 3), (4, 3), (3, 0)];
+
+		for (bytes, (expected, found)) in truncated.into_iter().zip(expected) {
+			let Err(ReadError::Other(error)) = TextItem8::read_from(&mut &bytes[..]) else {
+				panic!("a truncated text item was read: {bytes:?}");
+			};
+
+			assert_eq!(
+				error.downcast_ref::<UnexpectedEndOfData>(),
+				Some(&UnexpectedEndOfData { expected, found })
+			);
+		}
+
+		let Err(ReadError::Other(error)) = TextItem16::read_from(&mut &[1, 0, b'a'][..]) else {
+			panic!("a truncated 16-bit text item was read");
+		};
+		assert!(error.is::<UnexpectedEndOfData>());
+	}
+}
@@ -3,9 +3,9 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::x11::*;
-use xrb_proc_macros::{ByteSize, StaticByteSize};
+use xrbk_macro::{ByteSize, StaticByteSize};
 
-#[derive(StaticByteSize, ByteSize)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum Attribute {
 	BackgroundPixmap(Option<Relatable<Pixmap>>),
 	BackgroundPixel(u32),
@@ -24,45 +24,96 @@ pub enum Attribute {
 	Cursor(Option<Cursor>),
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// A component of a [`GraphicsContext`], as set in the list of values of a
+/// `CreateGraphicsContext` or `ChangeGraphicsContext` request.
+///
+/// Every value is written as 4 bytes in the list of values, regardless of its
+/// type; see [`GraphicsContextValues`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum GraphicsContextValue {
-	Function(Function), // TODO: 1 byte?
+	/// How the source and destination pixels are combined when drawing.
+	Function(Function),
+	/// Which bit planes of the destination are drawn to.
 	PlaneMask(u32),
+	/// The pixel used for the foreground.
 	Foreground(u32),
+	/// The pixel used for the background.
 	Background(u32),
-	LineWidth(u16),       // TODO: 2 bytes?
-	LineStyle(LineStyle), //  TODO: 1 byte?
-	CapStyle(CapStyle),   // TODO: 1 byte?
-	JoinStyle(JoinStyle), // TODO: 1 byte?
-	FillStyle(FillStyle), // TODO: 1 byte?
-	FillRule(FillRule),   // TODO: 1 byte?
+	/// The width of lines in pixels, where `0` draws 'thin' one-pixel lines
+	/// with a faster algorithm.
+	LineWidth(u16),
+	/// Which sections of lines are drawn.
+	LineStyle(LineStyle),
+	/// How the ends of lines are drawn.
+	CapStyle(CapStyle),
+	/// How the corners of wide lines are drawn.
+	JoinStyle(JoinStyle),
+	/// How lines, text, and fills are filled in.
+	FillStyle(FillStyle),
+	/// Which pixels are inside a polygon filled with `FillPoly`.
+	FillRule(FillRule),
+	/// The pixmap tiled by the [`FillStyle::Tiled`] fill style.
 	Tile(Pixmap),
+	/// The bitmap used by the stippled fill styles.
 	Stipple(Pixmap),
-	TileStippleXorigin(u16), // TODO: 2 bytes?
-	TileStippleYorigin(u16), // TODO: 2 bytes?
+	/// The x-coordinate of the origin of the tile or stipple.
+	TileStippleXorigin(i16),
+	/// The y-coordinate of the origin of the tile or stipple.
+	TileStippleYorigin(i16),
+	/// The font used for text.
 	Font(Font),
-	SubwindowMode(SubwindowMode), // TODO: 1 byte?
-	GraphicsExposures(bool),      // TODO: 1 byte?
-	ClipXorigin(u16),             // TODO: 2 bytes?
-	ClipYorigin(u16),             // TODO: 2 bytes?
+	/// Whether drawing to a window is clipped by its children.
+	SubwindowMode(SubwindowMode),
+	/// Whether `GraphicsExposure` events are generated for `CopyArea` and
+	/// `CopyPlane` requests.
+	GraphicsExposures(bool),
+	/// The x-coordinate of the origin of the clip mask.
+	ClipXorigin(i16),
+	/// The y-coordinate of the origin of the clip mask.
+	ClipYorigin(i16),
+	/// The bitmap which drawing is clipped to, or [`None`] to not clip.
 	ClipMask(Option<Pixmap>),
-	DashOffset(u16),  // TODO: 2 bytes?
-	Dashes(u8),       // TODO: 1 byte?
-	ArcMode(ArcMode), // TODO: 1 byte?
+	/// The offset into the dash pattern at which lines start.
+	DashOffset(u16),
+	/// The length of both the dashes and the gaps between them in dashed
+	/// lines.
+	Dashes(u8),
+	/// Whether arcs filled with `PolyFillArc` are filled as chords or as pie
+	/// slices.
+	ArcMode(ArcMode),
+}
+
+// Window attributes and graphics context values are written as 4 bytes in a
+// list of values, whichever value they are.
+
+impl cornflakes::StaticByteSize for Attribute {
+	fn static_byte_size() -> usize {
+		4
+	}
+}
+
+impl cornflakes::ByteSize for Attribute {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+
+impl cornflakes::StaticByteSize for GraphicsContextValue {
+	fn static_byte_size() -> usize {
+		4
+	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
-pub enum ConfigureWindowValue {
-	X(i16),
-	Y(i16),
-	Width(u16),
-	Height(u16),
-	BorderWidth(u16),
-	Sibling(Window),
-	StackMode(StackMode),
+impl cornflakes::ByteSize for GraphicsContextValue {
+	fn byte_size(&self) -> usize {
+		4
+	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// How the source pixels and destination pixels are combined when drawing.
+///
+/// Each function is a bitwise operation on the `source` and `destination`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum Function {
 	Clear,
 	And,
@@ -84,11 +135,12 @@ pub enum Function {
 
 impl Default for Function {
 	fn default() -> Self {
-		Self::NoOp
+		Self::Copy
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// Which sections of lines are drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum LineStyle {
 	Solid,
 	OnOffDash,
@@ -101,7 +153,8 @@ impl Default for LineStyle {
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// How the ends of lines are drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum CapStyle {
 	NotLast,
 	Butt,
@@ -115,7 +168,8 @@ impl Default for CapStyle {
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// How the corners of wide lines are drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum JoinStyle {
 	Miter,
 	Round,
@@ -128,7 +182,8 @@ impl Default for JoinStyle {
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// How lines, text, and fills are filled in.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum FillStyle {
 	Solid,
 	Tiled,
@@ -142,7 +197,8 @@ impl Default for FillStyle {
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// Which pixels are considered to be inside a polygon.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum FillRule {
 	EvenOdd,
 	Winding,
@@ -154,7 +210,8 @@ impl Default for FillRule {
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// Whether drawing to a window is clipped by its children.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum SubwindowMode {
 	ClipByChildren,
 	IncludeInferiors,
@@ -166,8 +223,15 @@ impl Default for SubwindowMode {
 	}
 }
 
-#[derive(StaticByteSize, ByteSize)]
+/// Whether filled arcs are filled as chords or as pie slices.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
 pub enum ArcMode {
 	Chord,
 	PieSlice,
 }
+
+impl Default for ArcMode {
+	fn default() -> Self {
+		Self::PieSlice
+	}
+}
@@ -0,0 +1,392 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::BufMut;
+use cornflakes::{ByteSize, Writable, WriteError};
+
+use crate::x11::{
+	requests::{ChangeWindowAttributes, GetWindowAttributesReply},
+	*,
+};
+
+/// A set of [window attributes], built up one attribute at a time, for
+/// [`CreateWindow`] and [`ChangeWindowAttributes`] requests.
+///
+/// Only the attributes which are set are sent: the rest are left at their
+/// [defaults] for [`CreateWindow`], or unchanged for
+/// [`ChangeWindowAttributes`]. Setting an attribute again replaces its value.
+///
+/// The attributes are written as an [`AttributeMask`] followed by one 4-byte
+/// value for each attribute that is set, in the order of their bits in the
+/// mask.
+///
+/// # Examples
+/// ```ignore
+/// let attributes = WindowAttributes::new()
+///     .background_pixel(0x00ff_ffff)
+///     .event_mask(EventMask::EXPOSURE | EventMask::KEY_PRESS)
+///     .override_redirect(true);
+///
+/// connection.send(CreateWindow {
+///     value_mask: attributes.mask(),
+///     values: attributes.values(),
+///     // ...
+/// })?;
+/// ```
+///
+/// [window attributes]: Attribute
+/// [defaults]: AttributeMask
+/// [`CreateWindow`]: super::requests::CreateWindow
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct WindowAttributes {
+	/// The attributes which have been set, in the order of their bits in the
+	/// [`AttributeMask`].
+	values: Vec<Attribute>,
+}
+
+impl WindowAttributes {
+	/// Creates a new [`WindowAttributes`] with no attributes set.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { values: Vec::new() }
+	}
+
+	/// Reads the attributes of a window which can be set with
+	/// [`ChangeWindowAttributes`] from the given `reply`.
+	///
+	/// This is useful to give another window the same attributes. Only the
+	/// client's own [`EventMask`] is included, and the colormap is only
+	/// included if the window has one; the background and border are not
+	/// returned in the `reply`, and so are not set.
+	#[must_use]
+	pub fn from_reply(reply: &GetWindowAttributesReply) -> Self {
+		let attributes = Self::new()
+			.bit_gravity(reply.bit_gravity)
+			.win_gravity(reply.win_gravity)
+			.backing_store(reply.backing_store)
+			.backing_planes(reply.backing_planes)
+			.backing_pixel(reply.backing_pixel)
+			.override_redirect(reply.override_redirect)
+			.save_under(reply.save_under)
+			.event_mask(reply.your_event_mask)
+			.do_not_propagate_mask(reply.do_not_propagate_mask);
+
+		match reply.colormap {
+			Some(colormap) => attributes.colormap(Inheritable::Specific(colormap)),
+			None => attributes,
+		}
+	}
+
+	/// The [`AttributeMask`] of the attributes which are set.
+	#[must_use]
+	pub fn mask(&self) -> AttributeMask {
+		self.values
+			.iter()
+			.fold(AttributeMask::empty(), |mask, value| mask | value.mask())
+	}
+
+	/// The attributes which are set, in the order that they are written.
+	#[must_use]
+	pub fn values(&self) -> &[Attribute] {
+		&self.values
+	}
+
+	/// Returns whether no attributes are set.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Creates the [`ChangeWindowAttributes`] request which sets these
+	/// attributes on the given `target` window.
+	#[must_use]
+	pub fn change(&self, target: Window) -> ChangeWindowAttributes<'_> {
+		ChangeWindowAttributes {
+			target,
+			value_mask: self.mask(),
+			values: &self.values,
+		}
+	}
+
+	/// Sets the given `attribute`, replacing its previous value if it was
+	/// already set.
+	#[must_use]
+	pub fn with(mut self, attribute: Attribute) -> Self {
+		let bits = attribute.mask().bits();
+
+		match self
+			.values
+			.binary_search_by_key(&bits, |value| value.mask().bits())
+		{
+			Ok(index) => self.values[index] = attribute,
+			Err(index) => self.values.insert(index, attribute),
+		}
+
+		self
+	}
+
+	/// Sets the pixmap used for the window's background.
+	///
+	/// See [`Attribute::BackgroundPixmap`].
+	#[must_use]
+	pub fn background_pixmap(self, pixmap: Option<Relatable<Pixmap>>) -> Self {
+		self.with(Attribute::BackgroundPixmap(pixmap))
+	}
+
+	/// Sets the pixel used for the window's background, overriding any
+	/// [background pixmap].
+	///
+	/// [background pixmap]: WindowAttributes::background_pixmap
+	#[must_use]
+	pub fn background_pixel(self, pixel: u32) -> Self {
+		self.with(Attribute::BackgroundPixel(pixel))
+	}
+
+	/// Sets the pixmap used for the window's border.
+	///
+	/// See [`Attribute::BorderPixmap`].
+	#[must_use]
+	pub fn border_pixmap(self, pixmap: Inheritable<Pixmap>) -> Self {
+		self.with(Attribute::BorderPixmap(pixmap))
+	}
+
+	/// Sets the pixel used for the window's border, overriding any
+	/// [border pixmap].
+	///
+	/// [border pixmap]: WindowAttributes::border_pixmap
+	#[must_use]
+	pub fn border_pixel(self, pixel: u32) -> Self {
+		self.with(Attribute::BorderPixel(pixel))
+	}
+
+	/// Sets which region of the window is retained when it is resized.
+	#[must_use]
+	pub fn bit_gravity(self, gravity: BitGravity) -> Self {
+		self.with(Attribute::BitGravity(gravity))
+	}
+
+	/// Sets how the window is moved when its parent is resized.
+	#[must_use]
+	pub fn win_gravity(self, gravity: WinGravity) -> Self {
+		self.with(Attribute::WinGravity(gravity))
+	}
+
+	/// Sets when the X server should maintain the window's contents while it
+	/// is obscured.
+	#[must_use]
+	pub fn backing_store(self, backing_store: BackingStore) -> Self {
+		self.with(Attribute::BackingStore(backing_store))
+	}
+
+	/// Sets which bit planes of the window are maintained by the
+	/// [backing store].
+	///
+	/// [backing store]: WindowAttributes::backing_store
+	#[must_use]
+	pub fn backing_planes(self, planes: u32) -> Self {
+		self.with(Attribute::BackingPlanes(planes))
+	}
+
+	/// Sets the value used for the bit planes which are not maintained by the
+	/// [backing store].
+	///
+	/// [backing store]: WindowAttributes::backing_store
+	#[must_use]
+	pub fn backing_pixel(self, pixel: u32) -> Self {
+		self.with(Attribute::BackingPixel(pixel))
+	}
+
+	/// Sets whether the window manager should be bypassed when the window is
+	/// mapped or configured, such as for popup menus.
+	#[must_use]
+	pub fn override_redirect(self, override_redirect: bool) -> Self {
+		self.with(Attribute::OverrideRedirect(override_redirect))
+	}
+
+	/// Sets whether the contents of windows below the window should be saved
+	/// while it is mapped.
+	#[must_use]
+	pub fn save_under(self, save_under: bool) -> Self {
+		self.with(Attribute::SaveUnder(save_under))
+	}
+
+	/// Sets the events that this client is interested in for the window.
+	#[must_use]
+	pub fn event_mask(self, mask: EventMask) -> Self {
+		self.with(Attribute::EventMask(mask))
+	}
+
+	/// Sets the device events which are not propagated to the window's
+	/// ancestors.
+	#[must_use]
+	pub fn do_not_propagate_mask(self, mask: DeviceEventMask) -> Self {
+		self.with(Attribute::DoNotPropagateMask(mask))
+	}
+
+	/// Sets the colormap of the window.
+	#[must_use]
+	pub fn colormap(self, colormap: Inheritable<Colormap>) -> Self {
+		self.with(Attribute::Colormap(colormap))
+	}
+
+	/// Sets the cursor shown while the pointer is in the window, or [`None`]
+	/// to use the parent's cursor.
+	#[must_use]
+	pub fn cursor(self, cursor: Option<Cursor>) -> Self {
+		self.with(Attribute::Cursor(cursor))
+	}
+}
+
+impl Attribute {
+	/// The bit in an [`AttributeMask`] which specifies this attribute.
+	#[must_use]
+	pub const fn mask(&self) -> AttributeMask {
+		match self {
+			Self::BackgroundPixmap(_) => AttributeMask::BACKGROUND_PIXMAP,
+			Self::BackgroundPixel(_) => AttributeMask::BACKGROUND_PIXEL,
+			Self::BorderPixmap(_) => AttributeMask::BORDER_PIXMAP,
+			Self::BorderPixel(_) => AttributeMask::BORDER_PIXEL,
+			Self::BitGravity(_) => AttributeMask::BIT_GRAVITY,
+			Self::WinGravity(_) => AttributeMask::WIN_GRAVITY,
+			Self::BackingStore(_) => AttributeMask::BACKING_STORE,
+			Self::BackingPlanes(_) => AttributeMask::BACKING_PLANES,
+			Self::BackingPixel(_) => AttributeMask::BACKING_PIXEL,
+			Self::OverrideRedirect(_) => AttributeMask::OVERRIDE_REDIRECT,
+			Self::SaveUnder(_) => AttributeMask::SAVE_UNDER,
+			Self::EventMask(_) => AttributeMask::EVENT_MASK,
+			Self::DoNotPropagateMask(_) => AttributeMask::DO_NOT_PROPAGATE_MASK,
+			Self::Colormap(_) => AttributeMask::COLORMAP,
+			Self::Cursor(_) => AttributeMask::CURSOR,
+		}
+	}
+
+	/// The 4-byte value that this attribute is written as in a list of
+	/// values.
+	#[must_use]
+	pub fn value(&self) -> u32 {
+		match self {
+			// No pixmap, colormap, or cursor, or copying it from the parent.
+			Self::BackgroundPixmap(None)
+			| Self::BorderPixmap(Inheritable::CopyFromParent)
+			| Self::Colormap(Inheritable::CopyFromParent)
+			| Self::Cursor(None) => 0,
+
+			Self::BackgroundPixmap(Some(Relatable::ParentRelative)) => 1,
+
+			Self::BackgroundPixmap(Some(Relatable::Specific(pixmap)))
+			| Self::BorderPixmap(Inheritable::Specific(pixmap)) => pixmap.res_id(),
+			Self::Colormap(Inheritable::Specific(colormap)) => colormap.res_id(),
+			Self::Cursor(Some(cursor)) => cursor.res_id(),
+
+			Self::BackgroundPixel(pixel)
+			| Self::BorderPixel(pixel)
+			| Self::BackingPlanes(pixel)
+			| Self::BackingPixel(pixel) => *pixel,
+
+			Self::BitGravity(gravity) => *gravity as u32,
+			Self::WinGravity(gravity) => *gravity as u32,
+			Self::BackingStore(backing_store) => *backing_store as u32,
+
+			Self::OverrideRedirect(value) | Self::SaveUnder(value) => u32::from(*value),
+
+			Self::EventMask(mask) => mask.bits(),
+			Self::DoNotPropagateMask(mask) => mask.bits(),
+		}
+	}
+}
+
+impl Writable for Attribute {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u32(self.value());
+
+		Ok(())
+	}
+}
+
+impl ByteSize for WindowAttributes {
+	fn byte_size(&self) -> usize {
+		// The mask, followed by a 4-byte value for each attribute.
+		4 + 4 * self.values.len()
+	}
+}
+
+impl Writable for WindowAttributes {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u32(self.mask().bits());
+
+		for value in &self.values {
+			value.write_to(writer)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::traits::Request;
+
+	#[test]
+	fn attributes_are_written_in_mask_order() {
+		let attributes = WindowAttributes::new()
+			.cursor(Some(Cursor::new(0x0040_0001)))
+			.override_redirect(true)
+			.background_pixel(0x00ff_ffff)
+			.override_redirect(false);
+
+		assert_eq!(
+			attributes.mask(),
+			AttributeMask::BACKGROUND_PIXEL
+				| AttributeMask::OVERRIDE_REDIRECT
+				| AttributeMask::CURSOR
+		);
+
+		let mut bytes = vec![];
+		attributes.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), attributes.byte_size());
+		assert_eq!(
+			bytes,
+			[
+				0x00, 0x00, 0x42, 0x02, // mask
+				0x00, 0xff, 0xff, 0xff, // background pixel
+				0x00, 0x00, 0x00, 0x00, // override redirect
+				0x00, 0x40, 0x00, 0x01, // cursor
+			]
+		);
+	}
+
+	#[test]
+	fn built_requests_write_their_attributes() {
+		let attributes = WindowAttributes::new()
+			.event_mask(EventMask::EXPOSURE)
+			.cursor(None);
+
+		let request = attributes.change(Window::new(0x0040_0001));
+		assert_eq!(request.values, attributes.values());
+
+		let mut bytes = vec![];
+		request.write_to(&mut bytes).unwrap();
+
+		// The header, target window, and then the same mask and values as
+		// the attributes themselves.
+		let mut expected = vec![2, 0, 0, 5, 0x00, 0x40, 0x00, 0x01];
+		attributes.write_to(&mut expected).unwrap();
+
+		assert_eq!(request.length(), 5);
+		assert_eq!(bytes, expected);
+	}
+
+	#[test]
+	fn wrapped_values_use_their_special_values() {
+		assert_eq!(Attribute::BackgroundPixmap(None).value(), 0);
+		assert_eq!(
+			Attribute::BackgroundPixmap(Some(Relatable::ParentRelative)).value(),
+			1
+		);
+		assert_eq!(Attribute::Colormap(Inheritable::CopyFromParent).value(), 0);
+		assert_eq!(Attribute::WinGravity(WinGravity::Static).value(), 10);
+	}
+}
@@ -2,95 +2,328 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::x11::*;
+use crate::x11::{
+	read::{check_reserved_bits, UnexpectedEndOfData},
+	*,
+};
 
-use cornflakes::{ByteSize, StaticByteSize};
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, StaticByteSize, Writable, WriteError};
 
-/// Allows a value to be copied from the parent at its initialization.
+/// The raw value which a wrapper defined with `wrappers!` is written as when
+/// it is not a specific value, in place of a value of the wrapped type.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum Inheritable<T> {
-	/// Initialise this value by copying it from the parent.
-	///
-	/// The value will be _copied_ at initialization: if there are changes in
-	/// the equivalent of this value in the parent, they are not reflected here.
-	CopyFromParent,
-	/// Provides a specific value, rather than copying from the parent.
-	Specific(T),
+enum Sentinel {
+	/// Every byte is `0`.
+	Zero,
+	/// The value `1`, written at the width of the wrapped type.
+	One,
+	/// Every bit is set: `-1` for signed types, or the maximum value for
+	/// unsigned types.
+	AllOnes,
 }
 
-impl<T> Default for Inheritable<T> {
-	fn default() -> Self {
-		Self::CopyFromParent
+impl Sentinel {
+	/// Writes this sentinel as `size` bytes.
+	fn write_to(self, size: usize, writer: &mut impl BufMut) {
+		match self {
+			Self::Zero => writer.put_bytes(0, size),
+
+			Self::One => {
+				writer.put_bytes(0, size.saturating_sub(1));
+				writer.put_u8(1);
+			}
+
+			Self::AllOnes => writer.put_bytes(0xff, size),
+		}
 	}
-}
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum Relatable<T> {
-	ParentRelative,
-	Specific(T),
-}
+	/// Returns whether the given `bytes` are this sentinel.
+	fn matches(self, bytes: &[u8]) -> bool {
+		match self {
+			Self::Zero => bytes.iter().all(|byte| *byte == 0),
 
-impl<T> Default for Relatable<T> {
-	fn default() -> Self {
-		Self::ParentRelative
+			Self::One => match bytes.split_last() {
+				Some((last, rest)) => *last == 1 && rest.iter().all(|byte| *byte == 0),
+				None => false,
+			},
+
+			Self::AllOnes => bytes.iter().all(|byte| *byte == 0xff),
+		}
 	}
 }
 
-/// Allows a value to be represented as an `Any` state.
+/// Defines wrappers which allow a value of any type `T` to be replaced by a
+/// special value, written as a [`Sentinel`] at the width of `T`.
 ///
-/// The meaning of `Any` is dependent on the nature of the value in question.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum Any<T> {
-	Any,
-	/// Provides a specific value, rather than representing `Any`.
-	Specific(T),
+/// The special variant is given first, along with the [`Sentinel`] it is
+/// written as, and is the [`Default`]. The same bytes are read as `T` if they
+/// are not the sentinel.
+macro_rules! wrappers {
+	($(
+		$(#[$meta:meta])*
+		pub enum $Wrapper:ident<T> {
+			$(#[$special_meta:meta])*
+			$Special:ident = $Sentinel:ident,
+			$(#[$specific_meta:meta])*
+			Specific(T),
+		}
+	)+) => {$(
+		$(#[$meta])*
+		#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+		pub enum $Wrapper<T> {
+			$(#[$special_meta])*
+			$Special,
+			$(#[$specific_meta])*
+			Specific(T),
+		}
+
+		impl<T> Default for $Wrapper<T> {
+			fn default() -> Self {
+				Self::$Special
+			}
+		}
+
+		impl<T> StaticByteSize for $Wrapper<T>
+		where
+			T: StaticByteSize,
+		{
+			fn static_byte_size() -> usize {
+				T::static_byte_size()
+			}
+		}
+
+		impl<T> ByteSize for $Wrapper<T>
+		where
+			T: StaticByteSize,
+		{
+			fn byte_size(&self) -> usize {
+				T::static_byte_size()
+			}
+		}
+
+		impl<T> Writable for $Wrapper<T>
+		where
+			T: StaticByteSize + Writable,
+		{
+			fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+				match self {
+					Self::$Special => {
+						Sentinel::$Sentinel.write_to(T::static_byte_size(), writer);
+
+						Ok(())
+					}
+
+					Self::Specific(value) => value.write_to(writer),
+				}
+			}
+		}
+
+		impl<T> Readable for $Wrapper<T>
+		where
+			T: StaticByteSize + Readable,
+		{
+			fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+				let size = T::static_byte_size();
+
+				if reader.remaining() < size {
+					return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+						expected: size,
+						found: reader.remaining(),
+					})));
+				}
+
+				let mut bytes = reader.copy_to_bytes(size);
+
+				if Sentinel::$Sentinel.matches(&bytes) {
+					Ok(Self::$Special)
+				} else {
+					T::read_from(&mut bytes).map(Self::Specific)
+				}
+			}
+		}
+	)+};
 }
 
-impl<T> Default for Any<T> {
-	fn default() -> Self {
-		Self::Any
+wrappers! {
+	/// Allows a value to be copied from the parent at its initialization.
+	///
+	/// [`CopyFromParent`] is written as `0`.
+	///
+	/// [`CopyFromParent`]: Inheritable::CopyFromParent
+	pub enum Inheritable<T> {
+		/// Initialise this value by copying it from the parent.
+		///
+		/// The value will be _copied_ at initialization: if there are changes in
+		/// the equivalent of this value in the parent, they are not reflected here.
+		CopyFromParent = Zero,
+		/// Provides a specific value, rather than copying from the parent.
+		Specific(T),
 	}
-}
 
-/// Allows a field to be implicitly initialized as its default value.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum Defaultable<T> {
-	/// The default for this particular field.
+	/// Allows a value to be taken from the parent whenever it is used.
 	///
-	/// *This is not the same as [`Default`] in [`std`].*
+	/// [`ParentRelative`] is written as `1`.
 	///
-	/// [`Default`]: std::default::Default
-	Default,
-	/// Provides a specific value, rather than initializing as the default.
-	Specific(T),
-}
+	/// [`ParentRelative`]: Relatable::ParentRelative
+	pub enum Relatable<T> {
+		/// Use the parent's value, whatever it is at the time.
+		ParentRelative = One,
+		/// Provides a specific value, rather than using the parent's.
+		Specific(T),
+	}
 
-impl<T> Default for Defaultable<T> {
-	fn default() -> Self {
-		Self::Default
+	/// Allows a value to be represented as an `Any` state.
+	///
+	/// The meaning of `Any` is dependent on the nature of the value in question,
+	/// such as `AnyKey` or `AnyButton`. It is written as `0`.
+	pub enum Any<T> {
+		/// Represents any value.
+		Any = Zero,
+		/// Provides a specific value, rather than representing `Any`.
+		Specific(T),
+	}
+
+	/// Allows a field to be implicitly initialized as its default value.
+	///
+	/// [`Default`] is written with every bit set: a `Defaultable<i16>`, such as
+	/// the timeout of a [`SetScreenSaver`] request, is written as `-1`.
+	///
+	/// [`SetScreenSaver`]: super::requests::SetScreenSaver
+	/// [`Default`]: Defaultable::Default
+	pub enum Defaultable<T> {
+		/// The default for this particular field.
+		///
+		/// *This is not the same as [`Default`] in [`std`].*
+		///
+		/// [`Default`]: std::default::Default
+		Default = AllOnes,
+		/// Provides a specific value, rather than initializing as the default.
+		Specific(T),
 	}
 }
 
-/// Represents a point in time.
+/// Defines an enum for a value which is either one of a few special values,
+/// or any other value of a specific type.
 ///
-/// This enum allows [`Current`] to be specified as in place of a [specifc]
-/// [`Timestamp`], if that is desired. Otherwise, [`Specific`] can be used to
-/// represent a specific [`Timestamp`].
+/// The specific variant is given first, followed by each special variant, the
+/// name of the constant holding the raw value it is written as, and that
+/// value:
+/// ```ignore
+/// sentinel_enum! {
+///     pub enum Time: u32 {
+///         _ => Specific(Timestamp),
 ///
-/// [`Current`]: Time::Current
-/// [`Specific`]: Time::Specific
-/// [specific]: Time::Specific
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum Time {
-	/// Represents the current time.
+///         const CURRENT_TIME = 0 => Current,
+///     }
+/// }
+/// ```
+///
+/// The raw value is read first: if it matches one of the constants, the
+/// special variant is read; otherwise, the same bytes are read as the
+/// specific type. A specific value equal to one of the constants is written
+/// as-is, so it will be read back as the special variant.
+macro_rules! sentinel_enum {
+	(
+		$(#[$meta:meta])*
+		$vis:vis enum $Enum:ident: $raw:ty {
+			$(#[$specific_meta:meta])*
+			_ => $Specific:ident($Type:ty),
+
+			$(
+				$(#[$variant_meta:meta])*
+				const $CONST:ident = $value:expr => $Variant:ident
+			),+ $(,)?
+		}
+	) => {
+		$(#[$meta])*
+		#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+		$vis enum $Enum {
+			$(
+				$(#[$variant_meta])*
+				$Variant,
+			)+
+			$(#[$specific_meta])*
+			$Specific($Type),
+		}
+
+		impl $Enum {
+			$(
+				#[doc = concat!(
+					"The raw value which [`", stringify!($Variant), "`](",
+					stringify!($Enum), "::", stringify!($Variant), ") is written as.",
+				)]
+				pub const $CONST: $raw = $value;
+			)+
+		}
+
+		impl StaticByteSize for $Enum {
+			fn static_byte_size() -> usize {
+				<$raw>::static_byte_size()
+			}
+		}
+
+		impl ByteSize for $Enum {
+			fn byte_size(&self) -> usize {
+				<$raw>::static_byte_size()
+			}
+		}
+
+		impl Writable for $Enum {
+			fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+				match self {
+					$(Self::$Variant => Self::$CONST.write_to(writer),)+
+					Self::$Specific(value) => value.write_to(writer),
+				}
+			}
+		}
+
+		impl Readable for $Enum {
+			fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+				const SIZE: usize = std::mem::size_of::<$raw>();
+
+				if reader.remaining() < SIZE {
+					return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+						expected: SIZE,
+						found: reader.remaining(),
+					})));
+				}
+
+				// The bytes are read as the raw value first, then as the
+				// specific type if they don't match any of the constants.
+				let mut bytes = reader.copy_to_bytes(SIZE);
+
+				Ok(match <$raw>::read_from(&mut bytes.clone())? {
+					$(Self::$CONST => Self::$Variant,)+
+					_ => Self::$Specific(<$Type>::read_from(&mut bytes)?),
+				})
+			}
+		}
+	};
+}
+
+sentinel_enum! {
+	/// Represents a point in time.
 	///
-	/// The X server replaces this value with the actual current [`Timestamp`].
-	Current,
-	/// Represents a specific [`Timestamp`], rather than being replaced by the
-	/// [current time].
+	/// This enum allows [`Current`] to be specified as in place of a [specifc]
+	/// [`Timestamp`], if that is desired. Otherwise, [`Specific`] can be used to
+	/// represent a specific [`Timestamp`].
 	///
-	/// [current time]: Time::Current
-	Specific(Timestamp),
+	/// [`Current`]: Time::Current
+	/// [`Specific`]: Time::Specific
+	/// [specific]: Time::Specific
+	pub enum Time: u32 {
+		/// Represents a specific [`Timestamp`], rather than being replaced by the
+		/// [current time].
+		///
+		/// [current time]: Time::Current
+		_ => Specific(Timestamp),
+
+		/// Represents the current time.
+		///
+		/// The X server replaces this value with the actual current [`Timestamp`].
+		const CURRENT_TIME = 0 => Current,
+	}
 }
 
 impl Default for Time {
@@ -99,10 +332,12 @@ impl Default for Time {
 	}
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum InputFocus {
-	PointerRoot,
-	Specific(Window),
+sentinel_enum! {
+	pub enum InputFocus: u32 {
+		_ => Specific(Window),
+
+		const POINTER_ROOT = 1 => PointerRoot,
+	}
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -111,114 +346,296 @@ pub enum BitmapFormat {
 	Specific(Format),
 }
 
-// Byte size implementations {{{
+/// The modifier keys which must be held for a passive grab to be activated:
+/// either exactly the given keys, or [any] combination of them.
+///
+/// This is written as a [`ModifierKeyMask`], with the `AnyModifier` bit,
+/// [`ANY_MODIFIER`], set for [any] modifiers.
+///
+/// [any]: Modifiers::Any
+/// [`ANY_MODIFIER`]: Modifiers::ANY_MODIFIER
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Modifiers {
+	/// Any combination of modifier keys, including none.
+	Any,
+	/// Exactly the given modifier keys.
+	Specific(ModifierKeyMask),
+}
 
-impl<T> StaticByteSize for Inheritable<T>
-where
-	T: StaticByteSize,
-{
-	fn static_byte_size() -> usize {
-		T::static_byte_size()
+impl Modifiers {
+	/// The bit which is set for [`Any`] modifiers.
+	///
+	/// [`Any`]: Modifiers::Any
+	pub const ANY_MODIFIER: u16 = 0x8000;
+
+	/// Returns the modifiers represented by the given `bits`, or [`None`] if
+	/// any bits are set other than those of a [`ModifierKeyMask`] and
+	/// [`ANY_MODIFIER`].
+	///
+	/// The X server ignores any other modifiers given with [`ANY_MODIFIER`],
+	/// so they are ignored here too.
+	///
+	/// [`ANY_MODIFIER`]: Modifiers::ANY_MODIFIER
+	#[must_use]
+	pub const fn from_bits(bits: u16) -> Option<Self> {
+		if Self::reserved_bits(bits) != 0 {
+			return None;
+		}
+
+		Some(Self::from_bits_truncate(bits))
 	}
-}
 
-impl<T> StaticByteSize for Relatable<T>
-where
-	T: StaticByteSize,
-{
-	fn static_byte_size() -> usize {
-		T::static_byte_size()
+	/// Returns the modifiers represented by the given `bits`, ignoring any
+	/// bits other than those of a [`ModifierKeyMask`] and [`ANY_MODIFIER`].
+	///
+	/// [`ANY_MODIFIER`]: Modifiers::ANY_MODIFIER
+	#[must_use]
+	pub const fn from_bits_truncate(bits: u16) -> Self {
+		if bits & Self::ANY_MODIFIER != 0 {
+			Self::Any
+		} else {
+			Self::Specific(ModifierKeyMask::from_bits_truncate(bits))
+		}
+	}
+
+	/// The bits which these modifiers are written as.
+	#[must_use]
+	pub const fn bits(self) -> u16 {
+		match self {
+			Self::Any => Self::ANY_MODIFIER,
+			Self::Specific(keys) => keys.bits(),
+		}
+	}
+
+	/// Returns the reserved bits set in the given `bits`, which must be zero.
+	const fn reserved_bits(bits: u16) -> u16 {
+		bits & !(ModifierKeyMask::all().bits() | Self::ANY_MODIFIER)
 	}
 }
 
-impl<T> StaticByteSize for Any<T>
-where
-	T: StaticByteSize,
-{
-	fn static_byte_size() -> usize {
-		T::static_byte_size()
+impl Default for Modifiers {
+	/// No modifier keys.
+	fn default() -> Self {
+		Self::Specific(ModifierKeyMask::empty())
 	}
 }
 
-impl<T> StaticByteSize for Defaultable<T>
-where
-	T: StaticByteSize,
-{
-	fn static_byte_size() -> usize {
-		T::static_byte_size()
+impl From<ModifierKeyMask> for Modifiers {
+	fn from(keys: ModifierKeyMask) -> Self {
+		Self::Specific(keys)
 	}
 }
 
-impl StaticByteSize for Time {
-	fn static_byte_size() -> usize {
-		Timestamp::static_byte_size()
+impl From<Modifiers> for AnyModifierKeyMask {
+	fn from(modifiers: Modifiers) -> Self {
+		Self::from_bits_truncate(modifiers.bits())
 	}
 }
 
-impl StaticByteSize for InputFocus {
-	fn static_byte_size() -> usize {
-		Window::static_byte_size()
+impl From<AnyModifierKeyMask> for Modifiers {
+	fn from(mask: AnyModifierKeyMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
 	}
 }
 
+// Byte size implementations {{{
+
 impl StaticByteSize for BitmapFormat {
 	fn static_byte_size() -> usize {
 		Format::static_byte_size()
 	}
 }
 
-impl<T> ByteSize for Inheritable<T>
-where
-	T: StaticByteSize,
-{
+impl ByteSize for BitmapFormat {
 	fn byte_size(&self) -> usize {
-		T::static_byte_size()
+		Format::static_byte_size()
 	}
 }
 
-impl<T> ByteSize for Relatable<T>
-where
-	T: StaticByteSize,
-{
-	fn byte_size(&self) -> usize {
-		T::static_byte_size()
+impl StaticByteSize for Modifiers {
+	fn static_byte_size() -> usize {
+		2
 	}
 }
 
-impl<T> ByteSize for Any<T>
-where
-	T: StaticByteSize,
-{
+impl ByteSize for Modifiers {
 	fn byte_size(&self) -> usize {
-		T::static_byte_size()
+		2
 	}
 }
 
-impl<T> ByteSize for Defaultable<T>
-where
-	T: StaticByteSize,
-{
-	fn byte_size(&self) -> usize {
-		T::static_byte_size()
+// }}}
+
+// Serialization of modifiers {{{
+
+impl Writable for Modifiers {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u16(self.bits());
+
+		Ok(())
 	}
 }
 
-impl ByteSize for Time {
-	fn byte_size(&self) -> usize {
-		Timestamp::static_byte_size()
+impl Readable for Modifiers {
+	/// Reads [`Modifiers`], which are [`Any`] if the [`ANY_MODIFIER`] bit is
+	/// set, regardless of any other bits.
+	///
+	/// # Errors
+	/// Returns an [`UnexpectedReservedBits`] if any reserved bits are set and
+	/// masks are read with [`Strictness::Strict`].
+	///
+	/// [`Any`]: Modifiers::Any
+	/// [`ANY_MODIFIER`]: Modifiers::ANY_MODIFIER
+	/// [`UnexpectedReservedBits`]: crate::x11::read::UnexpectedReservedBits
+	/// [`Strictness::Strict`]: crate::x11::read::Strictness::Strict
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 2 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 2,
+				found: reader.remaining(),
+			})));
+		}
+
+		let bits = reader.get_u16();
+		check_reserved_bits("Modifiers", Self::reserved_bits(bits).into())?;
+
+		Ok(Self::from_bits_truncate(bits))
 	}
 }
 
-impl ByteSize for InputFocus {
-	fn byte_size(&self) -> usize {
-		Window::static_byte_size()
+// }}}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn defaults_are_written_as_negative_one() {
+		let mut bytes = vec![];
+
+		Defaultable::<i16>::Default.write_to(&mut bytes).unwrap();
+		Defaultable::Specific(600_i16).write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes, [0xff, 0xff, 0x02, 0x58]);
+
+		let mut reader = &bytes[..];
+
+		assert_eq!(
+			Defaultable::<i16>::read_from(&mut reader).unwrap(),
+			Defaultable::Default
+		);
+		assert_eq!(
+			Defaultable::<i16>::read_from(&mut reader).unwrap(),
+			Defaultable::Specific(600)
+		);
 	}
-}
 
-impl ByteSize for BitmapFormat {
-	fn byte_size(&self) -> usize {
-		Format::static_byte_size()
+	#[test]
+	fn wrappers_are_written_at_the_width_of_their_type() {
+		let mut bytes = vec![];
+
+		Inheritable::<Window>::CopyFromParent
+			.write_to(&mut bytes)
+			.unwrap();
+		Relatable::<Window>::ParentRelative
+			.write_to(&mut bytes)
+			.unwrap();
+		Relatable::Specific(Window::new(2))
+			.write_to(&mut bytes)
+			.unwrap();
+		Any::<u8>::Any.write_to(&mut bytes).unwrap();
+		Any::Specific(8_u8).write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 8]);
+		assert_eq!(Relatable::<Window>::static_byte_size(), 4);
+
+		let mut reader = &bytes[..];
+
+		assert_eq!(
+			Inheritable::<Window>::read_from(&mut reader).unwrap(),
+			Inheritable::CopyFromParent
+		);
+		assert_eq!(
+			Relatable::<Window>::read_from(&mut reader).unwrap(),
+			Relatable::ParentRelative
+		);
+		assert_eq!(
+			Relatable::<Window>::read_from(&mut reader).unwrap(),
+			Relatable::Specific(Window::new(2))
+		);
+		assert_eq!(Any::<u8>::read_from(&mut reader).unwrap(), Any::Any);
+		assert_eq!(Any::<u8>::read_from(&mut reader).unwrap(), Any::Specific(8));
+
+		assert!(Inheritable::<Window>::read_from(&mut &[0, 0][..]).is_err());
 	}
-}
 
-// }}}
+	#[test]
+	fn any_modifier_is_a_single_bit() {
+		let mut bytes = vec![];
+
+		Modifiers::Any.write_to(&mut bytes).unwrap();
+		Modifiers::from(ModifierKeyMask::SHIFT | ModifierKeyMask::MOD_4)
+			.write_to(&mut bytes)
+			.unwrap();
+
+		assert_eq!(bytes, [0x80, 0x00, 0x00, 0x41]);
+
+		let mut reader = &bytes[..];
+
+		assert_eq!(Modifiers::read_from(&mut reader).unwrap(), Modifiers::Any);
+		assert_eq!(
+			Modifiers::read_from(&mut reader).unwrap(),
+			Modifiers::Specific(ModifierKeyMask::SHIFT | ModifierKeyMask::MOD_4)
+		);
+
+		// Other modifiers are ignored with `AnyModifier`.
+		assert_eq!(Modifiers::from_bits(0x8001), Some(Modifiers::Any));
+		// Button masks are not modifiers.
+		assert_eq!(Modifiers::from_bits(0x0100), None);
+	}
+
+	#[test]
+	fn current_time_is_written_as_zero() {
+		let mut bytes = vec![];
+
+		Time::Current.write_to(&mut bytes).unwrap();
+		Time::Specific(0x1234).write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0x12, 0x34]);
+
+		let mut reader = &bytes[..];
+
+		assert_eq!(Time::read_from(&mut reader).unwrap(), Time::Current);
+		assert_eq!(
+			Time::read_from(&mut reader).unwrap(),
+			Time::Specific(0x1234)
+		);
+	}
+
+	#[test]
+	fn sentinels_are_read_before_specific_values() {
+		assert_eq!(Time::CURRENT_TIME, 0);
+		assert_eq!(InputFocus::POINTER_ROOT, 1);
+
+		let mut bytes = vec![];
+
+		InputFocus::PointerRoot.write_to(&mut bytes).unwrap();
+		InputFocus::Specific(Window::new(0x20))
+			.write_to(&mut bytes)
+			.unwrap();
+
+		assert_eq!(bytes, [0, 0, 0, 1, 0, 0, 0, 0x20]);
+
+		let mut reader = &bytes[..];
+
+		assert_eq!(
+			InputFocus::read_from(&mut reader).unwrap(),
+			InputFocus::PointerRoot
+		);
+		assert_eq!(
+			InputFocus::read_from(&mut reader).unwrap(),
+			InputFocus::Specific(Window::new(0x20))
+		);
+		assert!(InputFocus::read_from(&mut &[0, 0, 1][..]).is_err());
+	}
+}
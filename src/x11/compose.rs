@@ -0,0 +1,434 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::BTreeMap;
+
+use crate::x11::Keysym;
+
+/// The `Multi_key` keysym, which starts a compose sequence.
+#[doc(alias = "XK_Multi_key")]
+pub const MULTI_KEY: Keysym = 0xff20;
+
+/// The `dead_grave` keysym.
+#[doc(alias = "XK_dead_grave")]
+pub const DEAD_GRAVE: Keysym = 0xfe50;
+/// The `dead_acute` keysym.
+#[doc(alias = "XK_dead_acute")]
+pub const DEAD_ACUTE: Keysym = 0xfe51;
+/// The `dead_circumflex` keysym.
+#[doc(alias = "XK_dead_circumflex")]
+pub const DEAD_CIRCUMFLEX: Keysym = 0xfe52;
+/// The `dead_tilde` keysym.
+#[doc(alias = "XK_dead_tilde")]
+pub const DEAD_TILDE: Keysym = 0xfe53;
+/// The `dead_macron` keysym.
+#[doc(alias = "XK_dead_macron")]
+pub const DEAD_MACRON: Keysym = 0xfe54;
+/// The `dead_breve` keysym.
+#[doc(alias = "XK_dead_breve")]
+pub const DEAD_BREVE: Keysym = 0xfe55;
+/// The `dead_abovedot` keysym.
+#[doc(alias = "XK_dead_abovedot")]
+pub const DEAD_ABOVEDOT: Keysym = 0xfe56;
+/// The `dead_diaeresis` keysym.
+#[doc(alias = "XK_dead_diaeresis")]
+pub const DEAD_DIAERESIS: Keysym = 0xfe57;
+/// The `dead_abovering` keysym.
+#[doc(alias = "XK_dead_abovering")]
+pub const DEAD_ABOVERING: Keysym = 0xfe58;
+/// The `dead_doubleacute` keysym.
+#[doc(alias = "XK_dead_doubleacute")]
+pub const DEAD_DOUBLEACUTE: Keysym = 0xfe59;
+/// The `dead_caron` keysym.
+#[doc(alias = "XK_dead_caron")]
+pub const DEAD_CARON: Keysym = 0xfe5a;
+/// The `dead_cedilla` keysym.
+#[doc(alias = "XK_dead_cedilla")]
+pub const DEAD_CEDILLA: Keysym = 0xfe5b;
+/// The `dead_ogonek` keysym.
+#[doc(alias = "XK_dead_ogonek")]
+pub const DEAD_OGONEK: Keysym = 0xfe5c;
+
+/// The bit set in keysyms which directly encode a Unicode code point.
+const UNICODE_KEYSYM: Keysym = 0x0100_0000;
+
+/// For each dead key: the character it produces on its own, the base
+/// characters it combines with, and the characters it composes them into.
+const DEAD_KEYS: &[(Keysym, char, &str, &str)] = &[
+	(DEAD_GRAVE, '`', "AEIOUaeiou", "ÀÈÌÒÙàèìòù"),
+	(
+		DEAD_ACUTE,
+		'\'',
+		"AEIOUYCNSZaeiouycnsz",
+		"ÁÉÍÓÚÝĆŃŚŹáéíóúýćńśź",
+	),
+	(DEAD_CIRCUMFLEX, '^', "AEIOUaeiou", "ÂÊÎÔÛâêîôû"),
+	(DEAD_TILDE, '~', "ANOano", "ÃÑÕãñõ"),
+	(DEAD_MACRON, '¯', "AEIOUaeiou", "ĀĒĪŌŪāēīōū"),
+	(DEAD_BREVE, '˘', "AGUagu", "ĂĞŬăğŭ"),
+	(DEAD_ABOVEDOT, '˙', "CEGIZcegz", "ĊĖĠİŻċėġż"),
+	(DEAD_DIAERESIS, '"', "AEIOUaeiouy", "ÄËÏÖÜäëïöüÿ"),
+	(DEAD_ABOVERING, '°', "AUau", "ÅŮåů"),
+	(DEAD_DOUBLEACUTE, '˝', "OUou", "ŐŰőű"),
+	(DEAD_CARON, 'ˇ', "CDENRSZcdenrsz", "ČĎĚŇŘŠŽčďěňřšž"),
+	(DEAD_CEDILLA, '¸', "CSTcst", "ÇŞŢçşţ"),
+	(DEAD_OGONEK, '˛', "AEIUaeiu", "ĄĘĮŲąęįų"),
+];
+
+/// Compose sequences of two characters typed after [`MULTI_KEY`], other than
+/// those for accented letters, which are derived from [`DEAD_KEYS`].
+const MULTI_KEY_SEQUENCES: &[(char, char, char)] = &[
+	('s', 's', 'ß'),
+	('a', 'e', 'æ'),
+	('A', 'E', 'Æ'),
+	('o', 'e', 'œ'),
+	('O', 'E', 'Œ'),
+	('/', 'o', 'ø'),
+	('/', 'O', 'Ø'),
+	('o', 'c', '©'),
+	('o', 'r', '®'),
+	('t', 'm', '™'),
+	('<', '<', '«'),
+	('>', '>', '»'),
+	('!', '!', '¡'),
+	('?', '?', '¿'),
+	('+', '-', '±'),
+	('1', '2', '½'),
+	('1', '4', '¼'),
+	('3', '4', '¾'),
+	('=', 'e', '€'),
+	('=', 'E', '€'),
+	('-', 'L', '£'),
+	('=', 'Y', '¥'),
+	('o', 'o', '°'),
+	('x', 'x', '×'),
+	(':', '-', '÷'),
+	('.', '.', '·'),
+];
+
+/// The characters typed after [`MULTI_KEY`] in place of the dead keys with
+/// which they share sequences for accented letters.
+const MULTI_KEY_ACCENTS: &[(char, Keysym)] = &[
+	('`', DEAD_GRAVE),
+	('\'', DEAD_ACUTE),
+	('^', DEAD_CIRCUMFLEX),
+	('~', DEAD_TILDE),
+	('"', DEAD_DIAERESIS),
+	(',', DEAD_CEDILLA),
+	('o', DEAD_ABOVERING),
+];
+
+/// Returns the keysym of the given character.
+///
+/// Latin-1 characters are their own keysyms; other characters are encoded in
+/// keysyms with [`UNICODE_KEYSYM`] set.
+const fn char_keysym(c: char) -> Keysym {
+	match c as u32 {
+		code @ (0x20..=0x7e | 0xa0..=0xff) => code,
+		code => UNICODE_KEYSYM | code,
+	}
+}
+
+/// Returns the character typed by the given `keysym`, if any.
+///
+/// This covers the Latin-1 keysyms, which are the same as their characters'
+/// code points, and keysyms which directly encode a Unicode code point. Other
+/// legacy keysyms return `None`.
+#[must_use]
+pub const fn keysym_to_char(keysym: Keysym) -> Option<char> {
+	match keysym {
+		0x20..=0x7e | 0xa0..=0xff => char::from_u32(keysym),
+
+		_ if keysym & 0xff00_0000 == UNICODE_KEYSYM => char::from_u32(keysym & !UNICODE_KEYSYM),
+
+		_ => None,
+	}
+}
+
+/// Returns whether the given `keysym` is a modifier key, such as `Shift_L`,
+/// which is ignored while composing.
+#[must_use]
+pub const fn is_modifier(keysym: Keysym) -> bool {
+	matches!(keysym, 0xffe1..=0xffee | 0xff7e | 0xfe01..=0xfe13)
+}
+
+/// Returns whether the given `keysym` is a dead key.
+#[must_use]
+pub const fn is_dead_key(keysym: Keysym) -> bool {
+	matches!(keysym, 0xfe50..=0xfe8f)
+}
+
+/// A table of compose sequences: sequences of keysyms which are composed
+/// into a single character.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ComposeTable {
+	sequences: BTreeMap<Vec<Keysym>, char>,
+}
+
+impl ComposeTable {
+	/// Creates an empty compose table.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			sequences: BTreeMap::new(),
+		}
+	}
+
+	/// Creates a compose table with the standard dead key and [`MULTI_KEY`]
+	/// sequences for Latin scripts.
+	///
+	/// This is a subset of the sequences in the `Compose` file of the
+	/// `en_US.UTF-8` locale.
+	#[must_use]
+	pub fn standard() -> Self {
+		let mut table = Self::new();
+
+		for &(dead_key, spacing, bases, composed) in DEAD_KEYS {
+			// A dead key followed by a space, or by itself, types the accent.
+			table.insert([dead_key, char_keysym(' ')], spacing);
+			table.insert([dead_key, dead_key], spacing);
+
+			for (base, composed) in bases.chars().zip(composed.chars()) {
+				table.insert([dead_key, char_keysym(base)], composed);
+			}
+		}
+
+		for &(accent, dead_key) in MULTI_KEY_ACCENTS {
+			let (_, _, bases, composed) = DEAD_KEYS
+				.iter()
+				.find(|(key, ..)| *key == dead_key)
+				.expect("every accent has a dead key");
+
+			for (base, composed) in bases.chars().zip(composed.chars()) {
+				table.insert(
+					[MULTI_KEY, char_keysym(accent), char_keysym(base)],
+					composed,
+				);
+			}
+		}
+
+		for &(first, second, composed) in MULTI_KEY_SEQUENCES {
+			table.insert(
+				[MULTI_KEY, char_keysym(first), char_keysym(second)],
+				composed,
+			);
+		}
+
+		table
+	}
+
+	/// Adds a compose `sequence` producing the character `composed`,
+	/// replacing any existing sequence of the same keysyms.
+	pub fn insert(&mut self, sequence: impl Into<Vec<Keysym>>, composed: char) {
+		self.sequences.insert(sequence.into(), composed);
+	}
+
+	/// Returns the character the given `sequence` is composed into, if it is
+	/// a complete sequence in this table.
+	#[must_use]
+	pub fn get(&self, sequence: &[Keysym]) -> Option<char> {
+		self.sequences.get(sequence).copied()
+	}
+
+	/// Returns whether any sequence in this table starts with, but is longer
+	/// than, the given `prefix`.
+	#[must_use]
+	pub fn continues(&self, prefix: &[Keysym]) -> bool {
+		self.sequences
+			.range(prefix.to_vec()..)
+			.find(|(sequence, _)| sequence.as_slice() != prefix)
+			.is_some_and(|(sequence, _)| sequence.starts_with(prefix))
+	}
+
+	/// The number of sequences in this table.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.sequences.len()
+	}
+
+	/// Returns whether this table contains no sequences.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.sequences.is_empty()
+	}
+}
+
+/// The result of feeding a keysym to a [`Composer`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Composed {
+	/// The keysym was not part of a compose sequence.
+	///
+	/// Contains the character typed by the keysym, if any.
+	Unchanged(Option<char>),
+
+	/// The keysym continued a compose sequence which is not yet complete.
+	Pending,
+	/// The keysym completed a compose sequence, composing this character.
+	Char(char),
+	/// The keysym did not match any compose sequence, so the sequence was
+	/// abandoned.
+	Cancelled,
+}
+
+/// Translates sequences of keysyms from `KeyPress` events into composed
+/// characters.
+///
+/// # Examples
+/// ```ignore
+/// let mut composer = Composer::new(ComposeTable::standard());
+///
+/// // For each `KeyPress` event...
+/// match composer.feed(keysym) {
+///     Composed::Unchanged(Some(c)) | Composed::Char(c) => text.push(c),
+///     Composed::Unchanged(None) | Composed::Pending | Composed::Cancelled => {}
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Composer {
+	table: ComposeTable,
+	pending: Vec<Keysym>,
+}
+
+impl Composer {
+	/// Creates a new [`Composer`] which composes the sequences in the given
+	/// `table`.
+	#[must_use]
+	pub const fn new(table: ComposeTable) -> Self {
+		Self {
+			table,
+			pending: Vec::new(),
+		}
+	}
+
+	/// Feeds the `keysym` of a pressed key to this composer.
+	///
+	/// Modifier keys, such as `Shift_L`, are ignored while composing so that
+	/// they can be used to type the sequence's keysyms.
+	pub fn feed(&mut self, keysym: Keysym) -> Composed {
+		if is_modifier(keysym) {
+			return if self.is_composing() {
+				Composed::Pending
+			} else {
+				Composed::Unchanged(None)
+			};
+		}
+
+		self.pending.push(keysym);
+
+		if let Some(composed) = self.table.get(&self.pending) {
+			self.pending.clear();
+
+			Composed::Char(composed)
+		} else if self.table.continues(&self.pending) {
+			Composed::Pending
+		} else if self.pending.len() == 1 {
+			self.pending.clear();
+
+			Composed::Unchanged(keysym_to_char(keysym))
+		} else {
+			self.pending.clear();
+
+			Composed::Cancelled
+		}
+	}
+
+	/// Returns whether a compose sequence has been started but not yet
+	/// completed.
+	#[must_use]
+	pub fn is_composing(&self) -> bool {
+		!self.pending.is_empty()
+	}
+
+	/// Abandons the current compose sequence, if any.
+	pub fn reset(&mut self) {
+		self.pending.clear();
+	}
+
+	/// The compose table used by this composer.
+	#[must_use]
+	pub const fn table(&self) -> &ComposeTable {
+		&self.table
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SHIFT_L: Keysym = 0xffe1;
+
+	fn compose(keysyms: &[Keysym]) -> Vec<Composed> {
+		let mut composer = Composer::new(ComposeTable::standard());
+
+		keysyms
+			.iter()
+			.map(|&keysym| composer.feed(keysym))
+			.collect()
+	}
+
+	#[test]
+	fn dead_key_tables_line_up() {
+		for (_, spacing, bases, composed) in DEAD_KEYS {
+			assert_eq!(bases.chars().count(), composed.chars().count(), "{spacing}");
+		}
+	}
+
+	#[test]
+	fn keysyms_are_converted_to_chars() {
+		assert_eq!(keysym_to_char(0x61), Some('a'));
+		assert_eq!(keysym_to_char(0xe9), Some('é'));
+		assert_eq!(keysym_to_char(0x0100_20ac), Some('€'));
+		assert_eq!(keysym_to_char(DEAD_ACUTE), None);
+		assert_eq!(keysym_to_char(SHIFT_L), None);
+	}
+
+	#[test]
+	fn dead_keys_compose_with_the_next_key() {
+		assert_eq!(
+			compose(&[DEAD_ACUTE, 0x65]),
+			[Composed::Pending, Composed::Char('é')]
+		);
+		assert_eq!(
+			compose(&[DEAD_CARON, SHIFT_L, 0x53]),
+			[Composed::Pending, Composed::Pending, Composed::Char('Š')]
+		);
+		assert_eq!(
+			compose(&[DEAD_TILDE, 0x20]),
+			[Composed::Pending, Composed::Char('~')]
+		);
+	}
+
+	#[test]
+	fn multi_key_sequences_are_composed() {
+		assert_eq!(
+			compose(&[MULTI_KEY, 0x27, 0x61]),
+			[Composed::Pending, Composed::Pending, Composed::Char('á')]
+		);
+		assert_eq!(
+			compose(&[MULTI_KEY, 0x73, 0x73]),
+			[Composed::Pending, Composed::Pending, Composed::Char('ß')]
+		);
+		// `o` starts both `<Multi_key> o a` and `<Multi_key> o c`.
+		assert_eq!(
+			compose(&[MULTI_KEY, 0x6f, 0x63]),
+			[Composed::Pending, Composed::Pending, Composed::Char('©')]
+		);
+	}
+
+	#[test]
+	fn unknown_sequences_are_cancelled() {
+		assert_eq!(
+			compose(&[DEAD_ACUTE, 0x71, 0x61]),
+			[
+				Composed::Pending,
+				Composed::Cancelled,
+				Composed::Unchanged(Some('a'))
+			]
+		);
+		assert_eq!(
+			compose(&[SHIFT_L, 0x41]),
+			[Composed::Unchanged(None), Composed::Unchanged(Some('A'))]
+		);
+	}
+}
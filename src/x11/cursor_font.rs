@@ -0,0 +1,310 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::x11::{requests::CreateGlyphCursor, Cursor, Font, Rgb16};
+
+/// The name with which the standard cursor font is opened in an `OpenFont`
+/// request.
+pub const NAME: &str = "cursor";
+
+/// The number of glyphs in the standard cursor font, including their masks.
+#[doc(alias = "XC_num_glyphs")]
+pub const NUM_GLYPHS: u16 = 154;
+
+/// Creates the [`CreateGlyphCursor`] request for a cursor with the given
+/// `cursor_id` which shows the given `glyph` of the standard cursor `font`.
+///
+/// Each glyph is followed in the font by its mask, so the glyph `n` is masked
+/// by the glyph `n + 1`.
+///
+/// # Examples
+/// ```ignore
+/// connection.send(OpenFont {
+///     font_id: font,
+///     name: String8::from(cursor_font::NAME.to_owned()),
+/// })?;
+///
+/// connection.send(cursor_font::create_cursor(
+///     cursor,
+///     font,
+///     cursor_font::LEFT_PTR,
+///     Rgb16::BLACK,
+///     Rgb16::WHITE,
+/// ))?;
+/// connection.send(CloseFont { font })?;
+/// ```
+#[must_use]
+pub const fn create_cursor(
+	cursor_id: Cursor,
+	font: Font,
+	glyph: u16,
+	foreground_color: Rgb16,
+	background_color: Rgb16,
+) -> CreateGlyphCursor {
+	CreateGlyphCursor {
+		cursor_id,
+		source_font: font,
+		mask_font: Some(font),
+		source_char: glyph,
+		mask_char: glyph + 1,
+		foreground_color,
+		background_color,
+	}
+}
+
+/// The `X_cursor` glyph.
+#[doc(alias = "XC_X_cursor")]
+pub const X_CURSOR: u16 = 0;
+/// The `arrow` glyph.
+#[doc(alias = "XC_arrow")]
+pub const ARROW: u16 = 2;
+/// The `based_arrow_down` glyph.
+#[doc(alias = "XC_based_arrow_down")]
+pub const BASED_ARROW_DOWN: u16 = 4;
+/// The `based_arrow_up` glyph.
+#[doc(alias = "XC_based_arrow_up")]
+pub const BASED_ARROW_UP: u16 = 6;
+/// The `boat` glyph.
+#[doc(alias = "XC_boat")]
+pub const BOAT: u16 = 8;
+/// The `bogosity` glyph.
+#[doc(alias = "XC_bogosity")]
+pub const BOGOSITY: u16 = 10;
+/// The `bottom_left_corner` glyph, used while resizing a window from its
+/// bottom-left corner.
+#[doc(alias = "XC_bottom_left_corner")]
+pub const BOTTOM_LEFT_CORNER: u16 = 12;
+/// The `bottom_right_corner` glyph, used while resizing a window from its
+/// bottom-right corner.
+#[doc(alias = "XC_bottom_right_corner")]
+pub const BOTTOM_RIGHT_CORNER: u16 = 14;
+/// The `bottom_side` glyph.
+#[doc(alias = "XC_bottom_side")]
+pub const BOTTOM_SIDE: u16 = 16;
+/// The `bottom_tee` glyph.
+#[doc(alias = "XC_bottom_tee")]
+pub const BOTTOM_TEE: u16 = 18;
+/// The `box_spiral` glyph.
+#[doc(alias = "XC_box_spiral")]
+pub const BOX_SPIRAL: u16 = 20;
+/// The `center_ptr` glyph.
+#[doc(alias = "XC_center_ptr")]
+pub const CENTER_PTR: u16 = 22;
+/// The `circle` glyph.
+#[doc(alias = "XC_circle")]
+pub const CIRCLE: u16 = 24;
+/// The `clock` glyph.
+#[doc(alias = "XC_clock")]
+pub const CLOCK: u16 = 26;
+/// The `coffee_mug` glyph.
+#[doc(alias = "XC_coffee_mug")]
+pub const COFFEE_MUG: u16 = 28;
+/// The `cross` glyph.
+#[doc(alias = "XC_cross")]
+pub const CROSS: u16 = 30;
+/// The `cross_reverse` glyph.
+#[doc(alias = "XC_cross_reverse")]
+pub const CROSS_REVERSE: u16 = 32;
+/// The `crosshair` glyph, used to select a point or area.
+#[doc(alias = "XC_crosshair")]
+pub const CROSSHAIR: u16 = 34;
+/// The `diamond_cross` glyph.
+#[doc(alias = "XC_diamond_cross")]
+pub const DIAMOND_CROSS: u16 = 36;
+/// The `dot` glyph.
+#[doc(alias = "XC_dot")]
+pub const DOT: u16 = 38;
+/// The `dotbox` glyph.
+#[doc(alias = "XC_dotbox")]
+pub const DOTBOX: u16 = 40;
+/// The `double_arrow` glyph.
+#[doc(alias = "XC_double_arrow")]
+pub const DOUBLE_ARROW: u16 = 42;
+/// The `draft_large` glyph.
+#[doc(alias = "XC_draft_large")]
+pub const DRAFT_LARGE: u16 = 44;
+/// The `draft_small` glyph.
+#[doc(alias = "XC_draft_small")]
+pub const DRAFT_SMALL: u16 = 46;
+/// The `draped_box` glyph.
+#[doc(alias = "XC_draped_box")]
+pub const DRAPED_BOX: u16 = 48;
+/// The `exchange` glyph.
+#[doc(alias = "XC_exchange")]
+pub const EXCHANGE: u16 = 50;
+/// The `fleur` glyph: four arrows, used while moving a window.
+#[doc(alias = "XC_fleur")]
+pub const FLEUR: u16 = 52;
+/// The `gobbler` glyph.
+#[doc(alias = "XC_gobbler")]
+pub const GOBBLER: u16 = 54;
+/// The `gumby` glyph.
+#[doc(alias = "XC_gumby")]
+pub const GUMBY: u16 = 56;
+/// The `hand1` glyph.
+#[doc(alias = "XC_hand1")]
+pub const HAND1: u16 = 58;
+/// The `hand2` glyph: a pointing hand, used over links and buttons.
+#[doc(alias = "XC_hand2")]
+pub const HAND2: u16 = 60;
+/// The `heart` glyph.
+#[doc(alias = "XC_heart")]
+pub const HEART: u16 = 62;
+/// The `icon` glyph.
+#[doc(alias = "XC_icon")]
+pub const ICON: u16 = 64;
+/// The `iron_cross` glyph.
+#[doc(alias = "XC_iron_cross")]
+pub const IRON_CROSS: u16 = 66;
+/// The `left_ptr` glyph: the usual arrow pointer, pointing up and to the left.
+#[doc(alias = "XC_left_ptr")]
+pub const LEFT_PTR: u16 = 68;
+/// The `left_side` glyph.
+#[doc(alias = "XC_left_side")]
+pub const LEFT_SIDE: u16 = 70;
+/// The `left_tee` glyph.
+#[doc(alias = "XC_left_tee")]
+pub const LEFT_TEE: u16 = 72;
+/// The `leftbutton` glyph.
+#[doc(alias = "XC_leftbutton")]
+pub const LEFTBUTTON: u16 = 74;
+/// The `ll_angle` glyph.
+#[doc(alias = "XC_ll_angle")]
+pub const LL_ANGLE: u16 = 76;
+/// The `lr_angle` glyph.
+#[doc(alias = "XC_lr_angle")]
+pub const LR_ANGLE: u16 = 78;
+/// The `man` glyph.
+#[doc(alias = "XC_man")]
+pub const MAN: u16 = 80;
+/// The `middlebutton` glyph.
+#[doc(alias = "XC_middlebutton")]
+pub const MIDDLEBUTTON: u16 = 82;
+/// The `mouse` glyph.
+#[doc(alias = "XC_mouse")]
+pub const MOUSE: u16 = 84;
+/// The `pencil` glyph.
+#[doc(alias = "XC_pencil")]
+pub const PENCIL: u16 = 86;
+/// The `pirate` glyph.
+#[doc(alias = "XC_pirate")]
+pub const PIRATE: u16 = 88;
+/// The `plus` glyph.
+#[doc(alias = "XC_plus")]
+pub const PLUS: u16 = 90;
+/// The `question_arrow` glyph.
+#[doc(alias = "XC_question_arrow")]
+pub const QUESTION_ARROW: u16 = 92;
+/// The `right_ptr` glyph.
+#[doc(alias = "XC_right_ptr")]
+pub const RIGHT_PTR: u16 = 94;
+/// The `right_side` glyph.
+#[doc(alias = "XC_right_side")]
+pub const RIGHT_SIDE: u16 = 96;
+/// The `right_tee` glyph.
+#[doc(alias = "XC_right_tee")]
+pub const RIGHT_TEE: u16 = 98;
+/// The `rightbutton` glyph.
+#[doc(alias = "XC_rightbutton")]
+pub const RIGHTBUTTON: u16 = 100;
+/// The `rtl_logo` glyph.
+#[doc(alias = "XC_rtl_logo")]
+pub const RTL_LOGO: u16 = 102;
+/// The `sailboat` glyph.
+#[doc(alias = "XC_sailboat")]
+pub const SAILBOAT: u16 = 104;
+/// The `sb_down_arrow` glyph.
+#[doc(alias = "XC_sb_down_arrow")]
+pub const SB_DOWN_ARROW: u16 = 106;
+/// The `sb_h_double_arrow` glyph, used while resizing a window horizontally.
+#[doc(alias = "XC_sb_h_double_arrow")]
+pub const SB_H_DOUBLE_ARROW: u16 = 108;
+/// The `sb_left_arrow` glyph.
+#[doc(alias = "XC_sb_left_arrow")]
+pub const SB_LEFT_ARROW: u16 = 110;
+/// The `sb_right_arrow` glyph.
+#[doc(alias = "XC_sb_right_arrow")]
+pub const SB_RIGHT_ARROW: u16 = 112;
+/// The `sb_up_arrow` glyph.
+#[doc(alias = "XC_sb_up_arrow")]
+pub const SB_UP_ARROW: u16 = 114;
+/// The `sb_v_double_arrow` glyph, used while resizing a window vertically.
+#[doc(alias = "XC_sb_v_double_arrow")]
+pub const SB_V_DOUBLE_ARROW: u16 = 116;
+/// The `shuttle` glyph.
+#[doc(alias = "XC_shuttle")]
+pub const SHUTTLE: u16 = 118;
+/// The `sizing` glyph.
+#[doc(alias = "XC_sizing")]
+pub const SIZING: u16 = 120;
+/// The `spider` glyph.
+#[doc(alias = "XC_spider")]
+pub const SPIDER: u16 = 122;
+/// The `spraycan` glyph.
+#[doc(alias = "XC_spraycan")]
+pub const SPRAYCAN: u16 = 124;
+/// The `star` glyph.
+#[doc(alias = "XC_star")]
+pub const STAR: u16 = 126;
+/// The `target` glyph.
+#[doc(alias = "XC_target")]
+pub const TARGET: u16 = 128;
+/// The `tcross` glyph.
+#[doc(alias = "XC_tcross")]
+pub const TCROSS: u16 = 130;
+/// The `top_left_arrow` glyph.
+#[doc(alias = "XC_top_left_arrow")]
+pub const TOP_LEFT_ARROW: u16 = 132;
+/// The `top_left_corner` glyph, used while resizing a window from its
+/// top-left corner.
+#[doc(alias = "XC_top_left_corner")]
+pub const TOP_LEFT_CORNER: u16 = 134;
+/// The `top_right_corner` glyph, used while resizing a window from its
+/// top-right corner.
+#[doc(alias = "XC_top_right_corner")]
+pub const TOP_RIGHT_CORNER: u16 = 136;
+/// The `top_side` glyph.
+#[doc(alias = "XC_top_side")]
+pub const TOP_SIDE: u16 = 138;
+/// The `top_tee` glyph.
+#[doc(alias = "XC_top_tee")]
+pub const TOP_TEE: u16 = 140;
+/// The `trek` glyph.
+#[doc(alias = "XC_trek")]
+pub const TREK: u16 = 142;
+/// The `ul_angle` glyph.
+#[doc(alias = "XC_ul_angle")]
+pub const UL_ANGLE: u16 = 144;
+/// The `umbrella` glyph.
+#[doc(alias = "XC_umbrella")]
+pub const UMBRELLA: u16 = 146;
+/// The `ur_angle` glyph.
+#[doc(alias = "XC_ur_angle")]
+pub const UR_ANGLE: u16 = 148;
+/// The `watch` glyph, used while a client is busy.
+#[doc(alias = "XC_watch")]
+pub const WATCH: u16 = 150;
+/// The `xterm` glyph: an I-beam, used over text.
+#[doc(alias = "XC_xterm")]
+pub const XTERM: u16 = 152;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn glyphs_are_masked_by_the_next_glyph() {
+		let request = create_cursor(
+			Cursor::new(1),
+			Font::new(2),
+			LEFT_PTR,
+			Rgb16::BLACK,
+			Rgb16::WHITE,
+		);
+
+		assert_eq!(request.source_char, 68);
+		assert_eq!(request.mask_char, 69);
+		assert_eq!(request.mask_font, Some(Font::new(2)));
+	}
+}
@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use cornflakes::ReadError;
+
+use crate::x11::{errors::request_name, extensions::Extensions};
+
+/// The kind of message which was being decoded when a [`DecodeError`]
+/// occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MessageKind {
+	/// A request, decoded by a [`RequestStreamDecoder`].
+	///
+	/// [`RequestStreamDecoder`]: crate::x11::request_stream::RequestStreamDecoder
+	Request,
+	/// A reply, decoded by a [`ReplyDecoder`].
+	///
+	/// [`ReplyDecoder`]: crate::x11::reply_decoder::ReplyDecoder
+	Reply,
+}
+
+/// Which message was being decoded when a [`DecodeError`] occurred, and
+/// where in that message.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DecodeContext {
+	/// The kind of message which was being decoded.
+	pub message_kind: MessageKind,
+	/// The major opcode of the request, or of the request which generated the
+	/// reply, if it is known.
+	pub major_opcode: Option<u8>,
+	/// The minor opcode of the request, or of the request which generated the
+	/// reply, if it is known and the request has one.
+	pub minor_opcode: Option<u8>,
+	/// The sequence number of the request, or of the request which generated
+	/// the reply, if it is known.
+	pub sequence: Option<u16>,
+	/// The offset, in bytes from the start of the message, at which the error
+	/// occurred.
+	pub offset: usize,
+}
+
+/// The error returned when a message in a stream of messages can't be
+/// decoded, with the [context] in which it occurred.
+///
+/// This is displayed with the name of the request, where it is known. For
+/// example: `failed to decode ConfigureWindow request (opcode 12, sequence
+/// 42) at byte 8: unexpected end of data: expected 4 more bytes, but only 0
+/// remain`.
+///
+/// [context]: DecodeContext
+#[derive(Debug)]
+pub struct DecodeError {
+	/// Which message was being decoded, and where in that message.
+	pub context: DecodeContext,
+	/// The error returned while reading the message.
+	pub error: ReadError,
+}
+
+impl DecodeError {
+	/// Creates a new [`DecodeError`] for the given `error` which occurred in
+	/// the given `context`.
+	#[must_use]
+	pub const fn new(context: DecodeContext, error: ReadError) -> Self {
+		Self { context, error }
+	}
+}
+
+impl fmt::Display for DecodeContext {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let kind = match self.message_kind {
+			MessageKind::Request => "request",
+			MessageKind::Reply => "reply",
+		};
+
+		let Some(major) = self.major_opcode else {
+			return write!(f, "{kind} at byte {}", self.offset);
+		};
+
+		let minor = self.minor_opcode;
+		let minor_opcode = minor.map_or(0, u16::from);
+		let name = request_name(&Extensions::current(), major, minor_opcode).unwrap_or("unknown");
+
+		write!(f, "{name} {kind} (opcode {major}")?;
+
+		if let Some(minor) = minor {
+			write!(f, ".{minor}")?;
+		}
+
+		if let Some(sequence) = self.sequence {
+			write!(f, ", sequence {sequence}")?;
+		}
+
+		write!(f, ") at byte {}", self.offset)
+	}
+}
+
+impl fmt::Display for DecodeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "failed to decode {}: ", self.context)?;
+
+		match &self.error {
+			ReadError::Other(error) => write!(f, "{error}"),
+			error => write!(f, "{error:?}"),
+		}
+	}
+}
+
+impl Error for DecodeError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match &self.error {
+			ReadError::Other(error) => Some(error.as_ref()),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::x11::read::UnexpectedEndOfData;
+
+	#[test]
+	fn errors_are_displayed_with_their_context() {
+		let error = DecodeError::new(
+			DecodeContext {
+				message_kind: MessageKind::Request,
+				major_opcode: Some(12),
+				minor_opcode: None,
+				sequence: Some(42),
+				offset: 8,
+			},
+			ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 4,
+				found: 0,
+			})),
+		);
+
+		assert_eq!(
+			error.to_string(),
+			"failed to decode ConfigureWindow request (opcode 12, sequence 42) at byte 8: \
+			 unexpected end of data: expected 4 more bytes, but only 0 remain"
+		);
+		assert!(error.source().unwrap().is::<UnexpectedEndOfData>());
+
+		let context = DecodeContext {
+			message_kind: MessageKind::Reply,
+			major_opcode: None,
+			minor_opcode: None,
+			sequence: None,
+			offset: 2,
+		};
+
+		assert_eq!(context.to_string(), "reply at byte 2");
+	}
+}
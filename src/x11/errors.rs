@@ -2,7 +2,20 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::error::Error;
+use std::{error::Error, fmt};
+
+use xrbk_macro::define;
+
+use crate::x11::extensions::{
+	composite,
+	damage,
+	render,
+	shm,
+	xc_misc,
+	xfixes,
+	Extension,
+	Extensions,
+};
 
 /// An X protocol error that can be returned when sending requests.
 pub trait Xerror: Error {
@@ -16,6 +29,425 @@ pub trait Xerror: Error {
 	fn major_opcode(&self) -> u8;
 }
 
+define! {
+	/// An X protocol error of any kind, as received from the X server.
+	///
+	/// Errors are distinguished from replies and events by a first byte of
+	/// `0`; that byte is not included when an `AnyError` is read or written.
+	///
+	/// An `AnyError` is displayed with the names of the error and of the
+	/// request which caused it, where they are known. For example:
+	/// `BadWindow (value 0x1234) in response to ConfigureWindow (opcode 12,
+	/// sequence 42)`.
+	///
+	/// The names of extension errors and requests are known if the error is
+	/// displayed within the [`scope`] of the connection's [`Extensions`].
+	///
+	/// [`scope`]: Extensions::scope
+	#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+	pub struct AnyError {
+		/// The code identifying the type of error.
+		pub code: u8,
+		/// The sequence number of the request which caused the error.
+		pub sequence: u16,
+		/// The resource ID, atom, or value which caused the error, if the
+		/// type of error has one.
+		pub bad_value: u32,
+		/// The minor opcode of the request which caused the error.
+		pub minor_opcode: u16,
+		/// The major opcode of the request which caused the error.
+		pub major_opcode: u8,
+		[(); 21],
+	}
+}
+
+impl Xerror for AnyError {
+	fn code(&self) -> u8 {
+		self.code
+	}
+
+	fn sequence(&self) -> u16 {
+		self.sequence
+	}
+
+	fn minor_opcode(&self) -> u16 {
+		self.minor_opcode
+	}
+
+	fn major_opcode(&self) -> u8 {
+		self.major_opcode
+	}
+}
+
+impl Error for AnyError {}
+
+impl fmt::Display for AnyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let extensions = Extensions::current();
+
+		match error(&extensions, self.code) {
+			Some((name, false)) => write!(f, "{name}")?,
+			Some((name, true)) => write!(f, "{name} (value {:#x})", self.bad_value)?,
+			None => write!(f, "unknown error {} (value {:#x})", self.code, self.bad_value)?,
+		}
+
+		write!(f, " in response to ")?;
+
+		let (major, minor, sequence) = (self.major_opcode, self.minor_opcode, self.sequence);
+
+		if let Some(extension) = extension_by_opcode(&extensions, major) {
+			let name = (extension.request)(minor).unwrap_or("request");
+
+			write!(
+				f,
+				"{} {name} (opcode {major}.{minor}, sequence {sequence})",
+				extension.extension.name()
+			)
+		} else if let Some(name) = core_request_name(major) {
+			write!(f, "{name} (opcode {major}, sequence {sequence})")
+		} else {
+			write!(f, "unknown request (opcode {major}.{minor}, sequence {sequence})")
+		}
+	}
+}
+
+/// Returns the name of the request with the given opcodes, if it is known.
+///
+/// The names of extension requests are only known if the extension's codes
+/// are in the given `extensions`.
+#[must_use]
+pub fn request_name(
+	extensions: &Extensions,
+	major_opcode: u8,
+	minor_opcode: u16,
+) -> Option<&'static str> {
+	extension_by_opcode(extensions, major_opcode).map_or_else(
+		|| core_request_name(major_opcode),
+		|extension| (extension.request)(minor_opcode),
+	)
+}
+
+/// Returns the name of the error with the given `code`, such as `BadWindow`,
+/// if it is known.
+///
+/// The names of extension errors are only known if the extension's codes are
+/// in the given `extensions`.
+#[must_use]
+pub fn error_name(extensions: &Extensions, code: u8) -> Option<&'static str> {
+	error(extensions, code).map(|(name, _)| name)
+}
+
+/// Returns the name of the error with the given `code`, and whether that type
+/// of error has a `bad_value`.
+fn error(extensions: &Extensions, code: u8) -> Option<(&'static str, bool)> {
+	if let Some(&error) = code
+		.checked_sub(1)
+		.and_then(|index| CORE_ERRORS.get(usize::from(index)))
+	{
+		return Some(error);
+	}
+
+	EXTENSIONS.iter().find_map(|names| {
+		let first_error = extensions.get(names.extension)?.first_error();
+
+		code.checked_sub(first_error)
+			.and_then(|index| names.errors.get(usize::from(index)))
+			.copied()
+	})
+}
+
+/// Returns the names of the extension assigned the given `major_opcode` in
+/// the `extensions`, if it is known.
+fn extension_by_opcode(
+	extensions: &Extensions,
+	major_opcode: u8,
+) -> Option<&'static ExtensionNames> {
+	let extension = extensions.by_major_opcode(major_opcode)?;
+
+	EXTENSIONS.iter().find(|names| names.extension == extension)
+}
+
+/// The names of an extension's requests and errors.
+struct ExtensionNames {
+	extension: Extension,
+	/// Returns the name of the request with the given minor opcode.
+	request: fn(u16) -> Option<&'static str>,
+	/// The extension's errors in order of their codes, and whether each has a
+	/// `bad_value`.
+	errors: &'static [(&'static str, bool)],
+}
+
+/// The core errors in order of their codes, starting at `1`, and whether each
+/// has a `bad_value`.
+pub(crate) const CORE_ERRORS: [(&str, bool); 17] = [
+	("BadRequest", false),
+	("BadValue", true),
+	("BadWindow", true),
+	("BadPixmap", true),
+	("BadAtom", true),
+	("BadCursor", true),
+	("BadFont", true),
+	("BadMatch", false),
+	("BadDrawable", true),
+	("BadAccess", false),
+	("BadAlloc", false),
+	("BadColormap", true),
+	("BadGContext", true),
+	("BadIDChoice", true),
+	("BadName", false),
+	("BadLength", false),
+	("BadImplementation", false),
+];
+
+static EXTENSIONS: [ExtensionNames; 6] = [
+	ExtensionNames {
+		extension: composite::EXTENSION,
+		request: composite_request_name,
+		errors: &[],
+	},
+	ExtensionNames {
+		extension: damage::EXTENSION,
+		request: damage_request_name,
+		errors: &[("BadDamage", true)],
+	},
+	ExtensionNames {
+		extension: render::EXTENSION,
+		request: render_request_name,
+		errors: &[
+			("BadPictFormat", true),
+			("BadPicture", true),
+			("BadPictOp", false),
+			("BadGlyphSet", true),
+			("BadGlyph", true),
+		],
+	},
+	ExtensionNames {
+		extension: shm::EXTENSION,
+		request: shm_request_name,
+		errors: &[("BadShmSeg", true)],
+	},
+	ExtensionNames {
+		extension: xc_misc::EXTENSION,
+		request: xc_misc_request_name,
+		errors: &[],
+	},
+	ExtensionNames {
+		extension: xfixes::EXTENSION,
+		request: xfixes_request_name,
+		errors: &[("BadRegion", true)],
+	},
+];
+
+// Generates a function returning the name of the request with a given opcode.
+macro_rules! request_names {
+	($(
+		$vis:vis fn $function:ident($opcode:ty) {
+			$($code:literal => $name:ident,)*
+		}
+	)*) => {
+		$(
+			$vis const fn $function(opcode: $opcode) -> Option<&'static str> {
+				match opcode {
+					$($code => Some(stringify!($name)),)*
+					_ => None,
+				}
+			}
+		)*
+	};
+}
+
+request_names! {
+	pub(crate) fn core_request_name(u8) {
+		1 => CreateWindow,
+		2 => ChangeWindowAttributes,
+		3 => GetWindowAttributes,
+		4 => DestroyWindow,
+		5 => DestroySubwindows,
+		6 => ChangeSaveSet,
+		7 => ReparentWindow,
+		8 => MapWindow,
+		9 => MapSubwindows,
+		10 => UnmapWindow,
+		11 => UnmapSubwindows,
+		12 => ConfigureWindow,
+		13 => CirculateWindow,
+		14 => GetGeometry,
+		15 => QueryTree,
+		16 => InternAtom,
+		17 => GetAtomName,
+		18 => ChangeProperty,
+		19 => DeleteProperty,
+		20 => GetProperty,
+		21 => ListProperties,
+		22 => SetSelectionOwner,
+		23 => GetSelectionOwner,
+		24 => ConvertSelection,
+		25 => SendEvent,
+		26 => GrabPointer,
+		27 => UngrabPointer,
+		28 => GrabButton,
+		29 => UngrabButton,
+		30 => ChangeActivePointerGrab,
+		31 => GrabKeyboard,
+		32 => UngrabKeyboard,
+		33 => GrabKey,
+		34 => UngrabKey,
+		35 => AllowEvents,
+		36 => GrabServer,
+		37 => UngrabServer,
+		38 => QueryPointer,
+		39 => GetMotionEvents,
+		40 => TranslateCoordinates,
+		41 => WarpPointer,
+		42 => SetInputFocus,
+		43 => GetInputFocus,
+		44 => QueryKeymap,
+		45 => OpenFont,
+		46 => CloseFont,
+		47 => QueryFont,
+		48 => QueryTextExtents,
+		49 => ListFonts,
+		50 => ListFontsWithInfo,
+		51 => SetFontPath,
+		52 => GetFontPath,
+		53 => CreatePixmap,
+		54 => FreePixmap,
+		55 => CreateGraphicsContext,
+		56 => ChangeGraphicsContext,
+		57 => CopyGraphicsContext,
+		58 => SetDashes,
+		59 => SetClipRectangles,
+		60 => FreeGraphicsContext,
+		61 => ClearArea,
+		62 => CopyArea,
+		63 => CopyPlane,
+		64 => PolyPoint,
+		65 => PolyLine,
+		66 => PolySegment,
+		67 => PolyRectangle,
+		68 => PolyArc,
+		69 => FillPoly,
+		70 => PolyFillRectangle,
+		71 => PolyFillArc,
+		72 => PutImage,
+		73 => GetImage,
+		74 => PolyText8,
+		75 => PolyText16,
+		76 => ImageText8,
+		77 => ImageText16,
+		78 => CreateColormap,
+		79 => FreeColormap,
+		80 => CopyColormapAndFree,
+		81 => InstallColormap,
+		82 => UninstallColormap,
+		83 => ListInstalledColormaps,
+		84 => AllocColor,
+		85 => AllocNamedColor,
+		86 => AllocColorCells,
+		87 => AllocColorPlanes,
+		88 => FreeColors,
+		89 => StoreColors,
+		90 => StoreNamedColor,
+		91 => QueryColors,
+		92 => LookupColor,
+		93 => CreateCursor,
+		94 => CreateGlyphCursor,
+		95 => FreeCursor,
+		96 => RecolorCursor,
+		97 => QueryBestSize,
+		98 => QueryExtension,
+		99 => ListExtensions,
+		100 => ChangeKeyboardMapping,
+		101 => GetKeyboardMapping,
+		102 => ChangeKeyboardControl,
+		103 => GetKeyboardControl,
+		104 => Bell,
+		105 => ChangePointerControl,
+		106 => GetPointerControl,
+		107 => SetScreenSaver,
+		108 => GetScreenSaver,
+		109 => ChangeHosts,
+		110 => ListHosts,
+		111 => SetAccessControl,
+		112 => SetCloseDownMode,
+		113 => KillClient,
+		114 => RotateProperties,
+		115 => ForceScreenSaver,
+		116 => SetPointerMapping,
+		117 => GetPointerMapping,
+		118 => SetModifierMapping,
+		119 => GetModifierMapping,
+		127 => NoOperation,
+	}
+
+	fn composite_request_name(u16) {
+		0 => QueryVersion,
+		1 => RedirectWindow,
+		2 => RedirectSubwindows,
+		3 => UnredirectWindow,
+		4 => UnredirectSubwindows,
+		5 => CreateRegionFromBorderClip,
+		6 => NameWindowPixmap,
+		7 => GetOverlayWindow,
+		8 => ReleaseOverlayWindow,
+	}
+
+	fn damage_request_name(u16) {
+		0 => QueryVersion,
+		1 => Create,
+		2 => Destroy,
+		3 => Subtract,
+		4 => Add,
+	}
+
+	fn render_request_name(u16) {
+		0 => QueryVersion,
+		1 => QueryPictFormats,
+		4 => CreatePicture,
+		7 => FreePicture,
+		8 => Composite,
+		17 => CreateGlyphSet,
+		19 => FreeGlyphSet,
+		20 => AddGlyphs,
+		22 => FreeGlyphs,
+		26 => FillRectangles,
+	}
+
+	fn shm_request_name(u16) {
+		0 => QueryVersion,
+		1 => Attach,
+		2 => Detach,
+		3 => PutImage,
+		4 => GetImage,
+		7 => CreateSegment,
+	}
+
+	fn xc_misc_request_name(u16) {
+		0 => GetVersion,
+		1 => GetXidRange,
+		2 => GetXidList,
+	}
+
+	fn xfixes_request_name(u16) {
+		0 => QueryVersion,
+		5 => CreateRegion,
+		6 => CreateRegionFromBitmap,
+		7 => CreateRegionFromWindow,
+		8 => CreateRegionFromGc,
+		10 => DestroyRegion,
+		11 => SetRegion,
+		12 => CopyRegion,
+		13 => UnionRegion,
+		14 => IntersectRegion,
+		15 => SubtractRegion,
+		16 => InvertRegion,
+		17 => TranslateRegion,
+		18 => RegionExtents,
+		19 => FetchRegion,
+		21 => SetWindowShapeRegion,
+	}
+}
+
 // NOTE: While these might be error messages of some kind, this will not be an
 // acceptable standard of error handling for X.RS. Work will need to be done to
 // keep track of the context when in debug environments so that more accurate
@@ -69,3 +501,65 @@ _errors! {
 	#[error("the X server does not support some aspect of this request")]
 	pub struct ImplementationXerror(17) {}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::x11::extensions::ExtensionInfo;
+
+	const fn any_error(code: u8, major_opcode: u8, minor_opcode: u16) -> AnyError {
+		AnyError {
+			code,
+			sequence: 42,
+			bad_value: 0x1234,
+			minor_opcode,
+			major_opcode,
+		}
+	}
+
+	#[test]
+	fn core_errors_are_named() {
+		assert_eq!(
+			any_error(3, 12, 0).to_string(),
+			"BadWindow (value 0x1234) in response to ConfigureWindow (opcode 12, sequence 42)"
+		);
+		assert_eq!(
+			any_error(8, 62, 0).to_string(),
+			"BadMatch in response to CopyArea (opcode 62, sequence 42)"
+		);
+	}
+
+	#[test]
+	fn unknown_errors_are_displayed_with_codes() {
+		assert_eq!(
+			any_error(200, 250, 7).to_string(),
+			"unknown error 200 (value 0x1234) in response to unknown request (opcode 250.7, \
+			 sequence 42)"
+		);
+	}
+
+	#[test]
+	fn extension_errors_are_named_with_their_codes() {
+		assert_eq!(
+			any_error(150, 140, 4).to_string(),
+			"unknown error 150 (value 0x1234) in response to unknown request (opcode 140.4, \
+			 sequence 42)"
+		);
+
+		let mut extensions = Extensions::new();
+		extensions.insert(render::EXTENSION, ExtensionInfo::new(140, 90, 150));
+
+		assert_eq!(request_name(&extensions, 140, 8), Some("Composite"));
+		assert_eq!(error_name(&extensions, 152), Some("BadPictOp"));
+
+		assert_eq!(request_name(&Extensions::new(), 140, 8), None);
+		assert_eq!(error_name(&Extensions::new(), 152), None);
+
+		assert_eq!(
+			extensions.scope(|| any_error(150, 140, 4).to_string()),
+			"BadPictFormat (value 0x1234) in response to RENDER CreatePicture (opcode 140.4, \
+			 sequence 42)"
+		);
+	}
+}
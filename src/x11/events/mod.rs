@@ -1,3 +1,346 @@
 // This Source Code Form is subject to the terms of the Mozilla Public
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::define;
+
+use crate::x11::*;
+
+define! {
+	/// Sent when a pointer `button` is pressed.
+	///
+	/// This event is sent to the client which has selected `ButtonPress` on
+	/// the `event` window, or to the client which has grabbed the pointer.
+	/// Pressing a button while the pointer is not grabbed may activate a grab;
+	/// see [`ButtonPress::activates_grab`].
+	pub struct ButtonPress: Event<4> {
+		/// The button which was pressed.
+		#[metabyte]
+		pub button: Button,
+		/// The time at which the event occurred.
+		pub time: Timestamp,
+		/// The root window of the screen on which the event occurred.
+		pub root: Window,
+		/// The window on which this event was selected, or the grab window of
+		/// an active pointer grab.
+		pub event: Window,
+		/// The child of the `event` window containing the pointer, if any.
+		pub child: Option<Window>,
+		/// The x-coordinate of the pointer, relative to the `root` window.
+		pub root_x: i16,
+		/// The y-coordinate of the pointer, relative to the `root` window.
+		pub root_y: i16,
+		/// The x-coordinate of the pointer, relative to the `event` window.
+		pub event_x: i16,
+		/// The y-coordinate of the pointer, relative to the `event` window.
+		pub event_y: i16,
+		/// The modifier keys and buttons held immediately before the event.
+		pub state: ModifierMask,
+		/// Whether the `event` window is on the same screen as the `root`
+		/// window.
+		///
+		/// If it is not, `event_x`, `event_y`, and `child` are zero.
+		pub same_screen: bool,
+		(),
+	}
+
+	/// Sent when a pointer `button` is released.
+	///
+	/// This event is sent to the client which has selected `ButtonRelease` on
+	/// the `event` window, or to the client which has grabbed the pointer.
+	/// Releasing the last button which is held releases a grab activated by a
+	/// button press; see [`ButtonRelease::releases_grab`].
+	pub struct ButtonRelease: Event<5> {
+		/// The button which was released.
+		#[metabyte]
+		pub button: Button,
+		/// The time at which the event occurred.
+		pub time: Timestamp,
+		/// The root window of the screen on which the event occurred.
+		pub root: Window,
+		/// The window on which this event was selected, or the grab window of
+		/// an active pointer grab.
+		pub event: Window,
+		/// The child of the `event` window containing the pointer, if any.
+		pub child: Option<Window>,
+		/// The x-coordinate of the pointer, relative to the `root` window.
+		pub root_x: i16,
+		/// The y-coordinate of the pointer, relative to the `root` window.
+		pub root_y: i16,
+		/// The x-coordinate of the pointer, relative to the `event` window.
+		pub event_x: i16,
+		/// The y-coordinate of the pointer, relative to the `event` window.
+		pub event_y: i16,
+		/// The modifier keys and buttons held immediately before the event.
+		pub state: ModifierMask,
+		/// Whether the `event` window is on the same screen as the `root`
+		/// window.
+		///
+		/// If it is not, `event_x`, `event_y`, and `child` are zero.
+		pub same_screen: bool,
+		(),
+	}
+
+	/// Sent when a `window` is destroyed.
+	///
+	/// This event is sent to clients which have selected `StructureNotify` on
+	/// the `window`, and to clients which have selected `SubstructureNotify`
+	/// on its parent.
+	pub struct DestroyNotify: Event<17> {
+		/// The window on which this event was selected: either the `window`
+		/// itself or its parent.
+		pub event: Window,
+		/// The window which was destroyed.
+		pub window: Window,
+		[(); 20],
+	}
+
+	/// Sent when a `window` is unmapped.
+	///
+	/// This event is sent to clients which have selected `StructureNotify` on
+	/// the `window`, and to clients which have selected `SubstructureNotify`
+	/// on its parent.
+	pub struct UnmapNotify: Event<18> {
+		/// The window on which this event was selected: either the `window`
+		/// itself or its parent.
+		pub event: Window,
+		/// The window which was unmapped.
+		pub window: Window,
+		/// Whether the `window` was unmapped because its parent was resized
+		/// and it has a `win_gravity` of [`WinGravity::Unmap`].
+		pub from_configure: bool,
+		[(); 19],
+	}
+
+	/// Sent when a [`MapWindow`] request is sent for a `window` whose parent
+	/// has `SubstructureRedirect` selected, and which doesn't have
+	/// `override_redirect` set.
+	///
+	/// The request is not carried out: the client which selected
+	/// `SubstructureRedirect`, typically a window manager, is responsible for
+	/// deciding whether to map the `window`.
+	///
+	/// [`MapWindow`]: crate::x11::requests::MapWindow
+	pub struct MapRequest: Event<20> {
+		pub parent: Window,
+		/// The window which would be mapped.
+		pub window: Window,
+		[(); 20],
+	}
+
+	/// Sent when a [`ConfigureWindow`] request is sent for a `window` whose
+	/// parent has `SubstructureRedirect` selected, and which doesn't have
+	/// `override_redirect` set.
+	///
+	/// The request is not carried out: the client which selected
+	/// `SubstructureRedirect`, typically a window manager, is responsible for
+	/// deciding whether, and how, to configure the `window`. Only the values
+	/// included in the `value_mask` were given in the request.
+	///
+	/// [`ConfigureWindow`]: crate::x11::requests::ConfigureWindow
+	pub struct ConfigureRequest: Event<23> {
+		#[metabyte]
+		pub stack_mode: StackMode,
+		pub parent: Window,
+		/// The window which would be configured.
+		pub window: Window,
+		pub sibling: Option<Window>,
+		pub x: i16,
+		pub y: i16,
+		pub width: u16,
+		pub height: u16,
+		pub border_width: u16,
+		/// Which of the values were given in the [`ConfigureWindow`] request.
+		///
+		/// [`ConfigureWindow`]: crate::x11::requests::ConfigureWindow
+		pub value_mask: ConfigureWindowMask,
+		[(); 4],
+	}
+
+	/// Sent when a `window` is restacked as the result of a
+	/// [`CirculateWindow`] request.
+	///
+	/// This event is sent to clients which have selected `StructureNotify` on
+	/// the `window`, and to clients which have selected `SubstructureNotify`
+	/// on its parent.
+	///
+	/// [`CirculateWindow`]: crate::x11::requests::CirculateWindow
+	pub struct CirculateNotify: Event<26> {
+		/// The window on which this event was selected: either the `window`
+		/// itself or its parent.
+		pub event: Window,
+		/// The window which was restacked.
+		pub window: Window,
+		[(); 4],
+		/// Where the `window` was placed in the stack of its siblings.
+		pub place: Place,
+		[(); 15],
+	}
+
+	/// Sent when a [`CirculateWindow`] request is sent for a `parent` window
+	/// on which `SubstructureRedirect` has been selected.
+	///
+	/// The request is not carried out: the client which selected
+	/// `SubstructureRedirect` is responsible for deciding whether, and how, to
+	/// restack the `window`.
+	///
+	/// [`CirculateWindow`]: crate::x11::requests::CirculateWindow
+	pub struct CirculateRequest: Event<27> {
+		pub parent: Window,
+		/// The window which would be restacked.
+		pub window: Window,
+		[(); 4],
+		/// Where the `window` would be placed in the stack of its siblings.
+		pub place: Place,
+		[(); 15],
+	}
+
+	/// Sent when a `property` of a `window` is changed or deleted.
+	///
+	/// This event is sent to clients which have selected `PropertyChange` on
+	/// the `window`. Properties may change many times in quick succession;
+	/// see [`PropertyDebouncer`] to handle only the latest of each.
+	///
+	/// [`PropertyDebouncer`]: crate::x11::property_debounce::PropertyDebouncer
+	pub struct PropertyNotify: Event<28> {
+		/// The window whose property was changed.
+		pub window: Window,
+		/// The property which was changed.
+		pub property: Atom,
+		/// The time at which the property was changed.
+		pub time: Timestamp,
+		/// Whether the property was given a new value or deleted.
+		pub state: PropertyState,
+		[(); 15],
+	}
+
+	/// Sent to the previous `owner` of a `selection` when it loses ownership
+	/// of it.
+	///
+	/// Ownership is lost when another client sets itself as the owner with a
+	/// [`SetSelectionOwner`] request, when the `owner` window is destroyed, or
+	/// when the selection is cleared by setting its owner to `None`.
+	///
+	/// [`SetSelectionOwner`]: crate::x11::requests::SetSelectionOwner
+	pub struct SelectionClear: Event<29> {
+		/// The time at which ownership of the `selection` was last changed, as
+		/// given in the [`SetSelectionOwner`] request.
+		///
+		/// [`SetSelectionOwner`]: crate::x11::requests::SetSelectionOwner
+		pub time: Timestamp,
+		/// The window which no longer owns the `selection`.
+		pub owner: Window,
+		/// The selection which was lost.
+		pub selection: Atom,
+		[(); 16],
+	}
+
+	/// Sent to the `owner` of a `selection` when another client requests that
+	/// it be converted with a [`ConvertSelection`] request.
+	///
+	/// The `owner` should convert the `selection` to the `target` type, store
+	/// the result in the `property` on the `requestor` window, and then send a
+	/// [`SelectionNotify`] event to the `requestor` with a [`SendEvent`]
+	/// request. If the `selection` can't be converted, the [`SelectionNotify`]
+	/// event should be sent with a `property` of `None`.
+	///
+	/// [`ConvertSelection`]: crate::x11::requests::ConvertSelection
+	/// [`SendEvent`]: crate::x11::requests::SendEvent
+	pub struct SelectionRequest: Event<30> {
+		/// The time given in the [`ConvertSelection`] request.
+		///
+		/// [`ConvertSelection`]: crate::x11::requests::ConvertSelection
+		pub time: Time,
+		/// The window which owns the `selection`.
+		pub owner: Window,
+		/// The window which requested the conversion.
+		pub requestor: Window,
+		/// The selection which is to be converted.
+		pub selection: Atom,
+		/// The type which the `selection` is to be converted to.
+		pub target: Atom,
+		/// The property in which the converted `selection` is to be stored.
+		///
+		/// If this is `None`, the requestor is an obsolete client, and the
+		/// `target` atom should be used as the property instead.
+		pub property: Option<Atom>,
+		[(); 4],
+	}
+
+	/// Sent in reply to a [`ConvertSelection`] request.
+	///
+	/// The X server sends this event itself if the `selection` has no owner.
+	/// Otherwise, it is sent by the owner of the `selection` with a
+	/// [`SendEvent`] request, in response to a [`SelectionRequest`] event.
+	///
+	/// [`ConvertSelection`]: crate::x11::requests::ConvertSelection
+	/// [`SendEvent`]: crate::x11::requests::SendEvent
+	pub struct SelectionNotify: Event<31> {
+		/// The time given in the [`ConvertSelection`] request.
+		///
+		/// [`ConvertSelection`]: crate::x11::requests::ConvertSelection
+		pub time: Time,
+		/// The window which requested the conversion.
+		pub requestor: Window,
+		/// The selection which was converted.
+		pub selection: Atom,
+		/// The type which the `selection` was converted to.
+		pub target: Atom,
+		/// The property on the `requestor` window in which the converted
+		/// `selection` was stored, or `None` if it could not be converted.
+		pub property: Option<Atom>,
+		[(); 8],
+	}
+
+	/// Sent when the colormap of a `window` is changed, or when that colormap
+	/// is installed or uninstalled.
+	///
+	/// This event is sent to clients which have selected `ColormapChange` on
+	/// the `window`. See [`ColormapTracker`] to follow which colormaps are
+	/// installed on each screen.
+	///
+	/// [`ColormapTracker`]: crate::x11::installed_colormaps::ColormapTracker
+	pub struct ColormapNotify: Event<32> {
+		/// The window whose colormap was changed, installed, or uninstalled.
+		pub window: Window,
+		/// The `window`'s colormap, or `None` if it was freed.
+		pub colormap: Option<Colormap>,
+		/// Whether the `window`'s colormap attribute was changed, rather than
+		/// the colormap being installed or uninstalled.
+		pub new: bool,
+		/// Whether the `colormap` is now installed.
+		pub state: ColormapState,
+		[(); 18],
+	}
+}
+
+impl ConfigureRequest {
+	/// Returns the values given in the [`ConfigureWindow`] request.
+	///
+	/// Only the values included in the `value_mask` are [`Some`]. This allows
+	/// a window manager to forward the request unchanged:
+	///
+	/// ```ignore
+	/// ConfigureWindow {
+	///     target: event.window,
+	///     values: event.values(),
+	/// }
+	/// ```
+	///
+	/// [`ConfigureWindow`]: crate::x11::requests::ConfigureWindow
+	#[must_use]
+	pub fn values(&self) -> ConfigureWindowValues {
+		let given = |bit| self.value_mask.contains(bit);
+
+		ConfigureWindowValues {
+			x: given(ConfigureWindowMask::X).then_some(self.x),
+			y: given(ConfigureWindowMask::Y).then_some(self.y),
+			width: given(ConfigureWindowMask::WIDTH).then_some(self.width),
+			height: given(ConfigureWindowMask::HEIGHT).then_some(self.height),
+			border_width: given(ConfigureWindowMask::BORDER_WIDTH).then_some(self.border_width),
+			// The `sibling` is always given if it is in the `value_mask`.
+			sibling: self.sibling.filter(|_| given(ConfigureWindowMask::SIBLING)),
+			stack_mode: given(ConfigureWindowMask::STACK_MODE).then_some(self.stack_mode),
+		}
+	}
+}
@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use super::xfixes::Region;
+use crate::x11::{extensions::Extension, *};
+
+/// The name of the Composite extension, used to query its codes with
+/// `QueryExtension`.
+pub const NAME: &str = "Composite";
+
+/// The Composite extension, whose codes are looked up in the [current]
+/// [`Extensions`] when its messages are written or read.
+///
+/// [current]: super::Extensions::current
+/// [`Extensions`]: super::Extensions
+pub const EXTENSION: Extension = Extension::new(NAME);
+
+define! {
+	/// Whether the X server or the client is responsible for updating the
+	/// parent of a redirected window.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub enum UpdateMode {
+		/// The X server automatically draws redirected windows to their
+		/// parent.
+		Automatic,
+		/// The client which redirected the windows, typically a compositing
+		/// manager, draws them to their parent itself.
+		Manual,
+	}
+}
+
+define! {
+	/// Informs the X server of the version of the Composite extension
+	/// supported by the client, and queries the version supported by the X
+	/// server.
+	pub struct QueryVersion: Request<{ EXTENSION.major_opcode() }, 0> -> QueryVersionReply {
+		pub client_major_version: u32,
+		pub client_minor_version: u32,
+	}
+
+	pub struct QueryVersionReply: Reply for QueryVersion {
+		pub major_version: u32,
+		pub minor_version: u32,
+		[(); 16],
+	}
+
+	/// Redirects the hierarchy of the given `window` to off-screen storage.
+	pub struct RedirectWindow: Request<{ EXTENSION.major_opcode() }, 1> {
+		pub window: Window,
+		/// Only one client may redirect a window with [`UpdateMode::Manual`].
+		pub update: UpdateMode,
+		[(); 3],
+	}
+
+	/// Redirects the hierarchies of all current and future children of the
+	/// given `window` to off-screen storage.
+	pub struct RedirectSubwindows: Request<{ EXTENSION.major_opcode() }, 2> {
+		pub window: Window,
+		/// Only one client may redirect a window with [`UpdateMode::Manual`].
+		pub update: UpdateMode,
+		[(); 3],
+	}
+
+	/// Stops redirecting the hierarchy of the given `window`.
+	pub struct UnredirectWindow: Request<{ EXTENSION.major_opcode() }, 3> {
+		pub window: Window,
+		/// The `update` mode the window was redirected with.
+		pub update: UpdateMode,
+		[(); 3],
+	}
+
+	/// Stops redirecting the hierarchies of the children of the given
+	/// `window`.
+	pub struct UnredirectSubwindows: Request<{ EXTENSION.major_opcode() }, 4> {
+		pub window: Window,
+		/// The `update` mode the children were redirected with.
+		pub update: UpdateMode,
+		[(); 3],
+	}
+
+	/// Creates a new `region` from the border clip of the given `window`: the
+	/// area of the window, including its border, which is visible.
+	pub struct CreateRegionFromBorderClip: Request<{ EXTENSION.major_opcode() }, 5> {
+		pub region: Region,
+		pub window: Window,
+	}
+
+	/// Assigns the given `pixmap` to the off-screen storage of the given
+	/// redirected `window`.
+	///
+	/// The `pixmap` remains valid after the window is unmapped or resized,
+	/// at which point the window is given new off-screen storage.
+	pub struct NameWindowPixmap: Request<{ EXTENSION.major_opcode() }, 6> {
+		pub window: Window,
+		pub pixmap: Pixmap,
+	}
+
+	/// Returns the overlay window of the screen of the given `window`,
+	/// mapping it if it isn't already.
+	pub struct GetOverlayWindow: Request<{ EXTENSION.major_opcode() }, 7> -> GetOverlayWindowReply {
+		pub window: Window,
+	}
+
+	pub struct GetOverlayWindowReply: Reply for GetOverlayWindow {
+		/// A window above all other windows but below the screen saver, which
+		/// is not affected by redirection.
+		pub overlay_window: Window,
+		[(); 20],
+	}
+
+	/// Releases the overlay window of the screen of the given `window`,
+	/// unmapping it once it has been released by all clients.
+	pub struct ReleaseOverlayWindow: Request<{ EXTENSION.major_opcode() }, 8> {
+		pub window: Window,
+	}
+}
@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use super::xfixes::Region;
+use crate::x11::{extensions::Extension, *};
+
+/// The name of the DAMAGE extension, used to query its codes with
+/// `QueryExtension`.
+pub const NAME: &str = "DAMAGE";
+
+/// The DAMAGE extension, whose codes are looked up in the [current]
+/// [`Extensions`] when its messages are written or read.
+///
+/// [current]: super::Extensions::current
+/// [`Extensions`]: super::Extensions
+pub const EXTENSION: Extension = Extension::new(NAME);
+
+define! {
+	/// An object which tracks the areas of a drawable which have been
+	/// modified ('damaged').
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Damage {
+		id: u32,
+	}
+
+	/// How often [`DamageNotify`] events are sent for a [`Damage`] object.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub enum ReportLevel {
+		/// A [`DamageNotify`] event is sent for every damaged rectangle.
+		RawRectangles,
+		/// A [`DamageNotify`] event is sent for every damaged rectangle not
+		/// already within the damaged area.
+		DeltaRectangles,
+		/// A [`DamageNotify`] event is sent whenever the bounding box of the
+		/// damaged area grows.
+		BoundingBox,
+		/// A [`DamageNotify`] event is sent only when the damaged area goes
+		/// from empty to non-empty.
+		NonEmpty,
+	}
+}
+
+impl Damage {
+	/// Creates a new [`Damage`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`Damage`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
+impl ResId for Damage {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
+define! {
+	/// Informs the X server of the version of the DAMAGE extension supported
+	/// by the client, and queries the version supported by the X server.
+	pub struct QueryVersion: Request<{ EXTENSION.major_opcode() }, 0> -> QueryVersionReply {
+		pub client_major_version: u32,
+		pub client_minor_version: u32,
+	}
+
+	pub struct QueryVersionReply: Reply for QueryVersion {
+		pub major_version: u32,
+		pub minor_version: u32,
+		[(); 16],
+	}
+
+	/// Creates a new `damage` object which tracks modifications to the given
+	/// drawable.
+	pub struct Create: Request<{ EXTENSION.major_opcode() }, 1> {
+		pub damage: Damage,
+		/// The [`Window`] or [`Pixmap`] to track.
+		pub drawable: Drawable,
+		pub level: ReportLevel,
+		[(); 3],
+	}
+
+	/// Destroys the given `damage` object.
+	pub struct Destroy: Request<{ EXTENSION.major_opcode() }, 2> {
+		pub damage: Damage,
+	}
+
+	/// Removes the `repair` region from the damaged area of the given
+	/// `damage` object.
+	pub struct Subtract: Request<{ EXTENSION.major_opcode() }, 3> {
+		pub damage: Damage,
+		/// The region to remove from the damaged area, or [`None`] to remove
+		/// the whole damaged area.
+		pub repair: Option<Region>,
+		/// If not [`None`], this region is set to the area which was removed.
+		pub parts: Option<Region>,
+	}
+
+	/// Reports the given `region` of a drawable as damaged, as if it had
+	/// been drawn to.
+	pub struct Add: Request<{ EXTENSION.major_opcode() }, 4> {
+		/// The [`Window`] or [`Pixmap`] which was damaged.
+		pub drawable: Drawable,
+		pub region: Region,
+	}
+
+	/// Sent when a drawable tracked by a [`Damage`] object is modified,
+	/// according to its [`ReportLevel`].
+	pub struct DamageNotify: Event<{ EXTENSION.first_event() }> {
+		/// The [`ReportLevel`] of the `damage` object, with the most
+		/// significant bit set if more `DamageNotify` events follow this one.
+		#[metabyte]
+		pub level: u8,
+		/// The [`Window`] or [`Pixmap`] which was damaged.
+		pub drawable: Drawable,
+		pub damage: Damage,
+		pub timestamp: Timestamp,
+		/// The area of the drawable which was damaged.
+		pub area: Rectangle,
+		/// The geometry of the drawable.
+		pub geometry: Rectangle,
+	}
+}
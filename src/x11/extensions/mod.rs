@@ -0,0 +1,278 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{cell::RefCell, collections::HashMap, mem, sync::Arc};
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ReadError, Readable, Writable, WriteError};
+
+/// The Composite extension: redirecting windows to off-screen storage.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/extensions/composite.rs"
+)]
+pub mod composite;
+/// The DAMAGE extension: tracking modified areas of drawables.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/extensions/damage.rs"
+)]
+pub mod damage;
+/// The RENDER extension: compositing images with alpha blending.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/extensions/render.rs"
+)]
+pub mod render;
+/// The MIT-SHM extension: transferring images through shared memory.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/extensions/shm.rs"
+)]
+pub mod shm;
+/// The XC-MISC extension: finding resource IDs which are no longer in use.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/extensions/xc_misc.rs"
+)]
+pub mod xc_misc;
+/// The XFIXES extension: regions and other fixes to the core protocol.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../../snapshot/x11/extensions/xfixes.rs"
+)]
+pub mod xfixes;
+
+/// The major opcode, first event code, and first error code assigned to an
+/// extension by the X server.
+///
+/// Unlike the core protocol, extensions are not assigned fixed codes: the X
+/// server allocates them itself, and they are returned in the `QueryExtension`
+/// reply for the extension's name. Different X servers may assign an extension
+/// different codes, so they are kept in the [`Extensions`] of each connection.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ExtensionInfo {
+	major_opcode: u8,
+	first_event: u8,
+	first_error: u8,
+}
+
+impl ExtensionInfo {
+	/// Creates a new `ExtensionInfo` with the codes assigned to an extension,
+	/// as returned in the `QueryExtension` reply.
+	#[must_use]
+	pub const fn new(major_opcode: u8, first_event: u8, first_error: u8) -> Self {
+		Self {
+			major_opcode,
+			first_event,
+			first_error,
+		}
+	}
+
+	/// The major opcode shared by all of the extension's requests.
+	#[must_use]
+	pub const fn major_opcode(self) -> u8 {
+		self.major_opcode
+	}
+
+	/// The code of the extension's first event; its other events' codes
+	/// follow on from this one.
+	#[must_use]
+	pub const fn first_event(self) -> u8 {
+		self.first_event
+	}
+
+	/// The code of the extension's first error; its other errors' codes
+	/// follow on from this one.
+	#[must_use]
+	pub const fn first_error(self) -> u8 {
+		self.first_error
+	}
+}
+
+/// An extension, identified by its name, whose codes are looked up in the
+/// [current] [`Extensions`].
+///
+/// Each extension module has an `EXTENSION` constant, from which the opcodes
+/// of its requests and the codes of its events are found when they are
+/// written or read.
+///
+/// [current]: Extensions::current
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Extension {
+	name: &'static str,
+}
+
+impl Extension {
+	/// Creates a new `Extension` with the given `name`, as used to query its
+	/// codes with `QueryExtension`.
+	#[must_use]
+	pub const fn new(name: &'static str) -> Self {
+		Self { name }
+	}
+
+	/// The name of the extension, as used to query its codes with
+	/// `QueryExtension`.
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		self.name
+	}
+
+	/// The codes assigned to the extension in the [current] [`Extensions`],
+	/// if they are known.
+	///
+	/// [current]: Extensions::current
+	#[must_use]
+	pub fn info(self) -> Option<ExtensionInfo> {
+		CURRENT.with(|current| current.borrow().get(self))
+	}
+
+	/// The major opcode shared by all of the extension's requests, or `0` if
+	/// its codes are not known.
+	#[must_use]
+	pub fn major_opcode(self) -> u8 {
+		self.info().map_or(0, ExtensionInfo::major_opcode)
+	}
+
+	/// The code of the extension's first event, or `0` if its codes are not
+	/// known.
+	#[must_use]
+	pub fn first_event(self) -> u8 {
+		self.info().map_or(0, ExtensionInfo::first_event)
+	}
+
+	/// The code of the extension's first error, or `0` if its codes are not
+	/// known.
+	#[must_use]
+	pub fn first_error(self) -> u8 {
+		self.info().map_or(0, ExtensionInfo::first_error)
+	}
+}
+
+/// The codes assigned to each extension used on a connection.
+///
+/// A connection keeps its own `Extensions`, to which the codes returned in
+/// each `QueryExtension` reply are [inserted]. Extension messages are then
+/// written and read within the connection's [`scope`], so that their
+/// opcodes and codes are those assigned by that connection's X server:
+/// ```ignore
+/// let mut extensions = Extensions::new();
+/// extensions.insert(shm::EXTENSION, ExtensionInfo::new(130, 90, 150));
+///
+/// extensions.write(&shm::Detach { segment }, &mut bytes)?;
+/// let event = extensions.read::<shm::ShmCompletion>(reader)?;
+/// ```
+///
+/// [inserted]: Extensions::insert
+/// [`scope`]: Extensions::scope
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Extensions {
+	infos: Arc<HashMap<Extension, ExtensionInfo>>,
+}
+
+thread_local! {
+	/// The extensions whose codes are being used on this thread.
+	static CURRENT: RefCell<Extensions> = RefCell::default();
+}
+
+impl Extensions {
+	/// Creates a new `Extensions` in which no extension's codes are known.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the extensions whose codes are being used on this thread:
+	/// those given to [`Extensions::scope`] while it is running, and none
+	/// otherwise.
+	#[must_use]
+	pub fn current() -> Self {
+		CURRENT.with(|current| current.borrow().clone())
+	}
+
+	/// Sets the codes assigned to the `extension`, as returned in its
+	/// `QueryExtension` reply.
+	pub fn insert(&mut self, extension: Extension, info: ExtensionInfo) {
+		Arc::make_mut(&mut self.infos).insert(extension, info);
+	}
+
+	/// The codes assigned to the `extension`, if they are known.
+	#[must_use]
+	pub fn get(&self, extension: Extension) -> Option<ExtensionInfo> {
+		self.infos.get(&extension).copied()
+	}
+
+	/// Returns the extension assigned the given `major_opcode`, if it is
+	/// known.
+	#[must_use]
+	pub fn by_major_opcode(&self, major_opcode: u8) -> Option<Extension> {
+		self.infos
+			.iter()
+			.find(|(_, info)| info.major_opcode() == major_opcode)
+			.map(|(&extension, _)| extension)
+	}
+
+	/// Runs `f` with these as the [current] extensions on this thread.
+	///
+	/// [current]: Extensions::current
+	pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+		/// Restores the extensions from before `f` was run, even if it
+		/// panics.
+		struct Restore(Extensions);
+
+		impl Drop for Restore {
+			fn drop(&mut self) {
+				CURRENT.with(|current| current.replace(mem::take(&mut self.0)));
+			}
+		}
+
+		let _restore = Restore(CURRENT.with(|current| current.replace(self.clone())));
+
+		f()
+	}
+
+	/// Reads a `T` from the `reader` with these extensions' codes.
+	///
+	/// # Errors
+	/// Returns any error returned by reading the `T`.
+	pub fn read<T: Readable>(&self, reader: &mut impl Buf) -> Result<T, ReadError> {
+		self.scope(|| T::read_from(reader))
+	}
+
+	/// Writes the `value` to the `writer` with these extensions' codes.
+	///
+	/// # Errors
+	/// Returns any error returned by writing the `value`.
+	pub fn write<T: Writable>(
+		&self,
+		value: &T,
+		writer: &mut impl BufMut,
+	) -> Result<(), WriteError> {
+		self.scope(|| value.write_to(writer))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::x11::traits::{Event, Request};
+
+	#[test]
+	fn codes_are_only_known_within_a_scope() {
+		let mut extensions = Extensions::new();
+		extensions.insert(shm::EXTENSION, ExtensionInfo::new(130, 90, 150));
+
+		assert_eq!(shm::QueryVersion::major_opcode(), 0);
+
+		extensions.scope(|| {
+			assert_eq!(shm::QueryVersion::major_opcode(), 130);
+			assert_eq!(shm::ShmCompletion::code(), 90);
+			assert_eq!(render::EXTENSION.info(), None);
+		});
+
+		assert_eq!(shm::EXTENSION.info(), None);
+		assert_eq!(extensions.by_major_opcode(130), Some(shm::EXTENSION));
+	}
+}
@@ -0,0 +1,386 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use crate::x11::{extensions::Extension, *};
+
+/// The name of the RENDER extension, used to query its codes with
+/// `QueryExtension`.
+pub const NAME: &str = "RENDER";
+
+/// The RENDER extension, whose codes are looked up in the [current]
+/// [`Extensions`] when its messages are written or read.
+///
+/// [current]: super::Extensions::current
+/// [`Extensions`]: super::Extensions
+pub const EXTENSION: Extension = Extension::new(NAME);
+
+/// The ID of a glyph within a [`GlyphSet`].
+pub type Glyph = u32;
+
+define! {
+	/// The ID of a [`PictFormInfo`]: a format which a [`Picture`] may have.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct PictFormat(u32);
+
+	/// A drawable, along with the format and other state used to composite it.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Picture {
+		id: u32,
+	}
+
+	/// A set of glyph images which may be composited.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct GlyphSet {
+		id: u32,
+	}
+}
+
+impl PictFormat {
+	/// Creates a new [`PictFormat`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self(id)
+	}
+
+	/// Creates a new [`PictFormat`] with an `id` of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self(0)
+	}
+
+	/// Gets the `id` of the [`PictFormat`].
+	#[must_use]
+	pub const fn id(&self) -> u32 {
+		self.0
+	}
+}
+
+impl Picture {
+	/// Creates a new [`Picture`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`Picture`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
+impl ResId for Picture {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
+impl GlyphSet {
+	/// Creates a new [`GlyphSet`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`GlyphSet`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
+impl ResId for GlyphSet {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
+define! {
+	/// Whether a [`PictFormInfo`] describes an indexed or a direct format.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub enum PictType {
+		/// Pixel values are indices into a colormap.
+		Indexed,
+		/// Pixel values directly encode their color channels.
+		Direct,
+	}
+
+	/// The position and size of each color channel within the pixel values of
+	/// a direct [`PictFormInfo`].
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct DirectFormat {
+		pub red_shift: u16,
+		pub red_mask: u16,
+		pub green_shift: u16,
+		pub green_mask: u16,
+		pub blue_shift: u16,
+		pub blue_mask: u16,
+		pub alpha_shift: u16,
+		pub alpha_mask: u16,
+	}
+
+	/// A format which a [`Picture`] may have.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct PictFormInfo {
+		pub id: PictFormat,
+		pub r#type: PictType,
+		pub depth: u8,
+		[(); 2],
+		/// The layout of color channels, if `r#type` is [`PictType::Direct`].
+		pub direct: DirectFormat,
+		/// The colormap of the format, if `r#type` is [`PictType::Indexed`].
+		pub colormap: Option<Colormap>,
+	}
+
+	/// The [`PictFormat`] used for windows with a particular `visual`.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct PictVisual {
+		pub visual: VisualId,
+		pub format: PictFormat,
+	}
+
+	/// The [`PictVisual`]s supported for a particular `depth` on a screen.
+	#[derive(Clone, Eq, PartialEq, Hash, Debug, ByteSize)]
+	pub struct PictDepth {
+		pub depth: u8,
+		[(); 1],
+		let visuals_len: u16 = self => self.visuals.len() as u16,
+		[(); 4],
+		#[context(visuals_len => *visuals_len as usize)]
+		pub visuals: Vec<PictVisual>,
+	}
+
+	/// The [`PictDepth`]s supported by a screen.
+	#[derive(Clone, Eq, PartialEq, Hash, Debug, ByteSize)]
+	pub struct PictScreen {
+		let depths_len: u32 = self => self.depths.len() as u32,
+		/// The format used for core protocol requests that have no visual.
+		pub fallback: PictFormat,
+		#[context(depths_len => *depths_len as usize)]
+		pub depths: Vec<PictDepth>,
+	}
+
+	/// The operator used to composite a source [`Picture`] onto a destination
+	/// [`Picture`].
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub enum PictOp {
+		Clear,
+		Src,
+		Dst,
+		Over,
+		OverReverse,
+		In,
+		InReverse,
+		Out,
+		OutReverse,
+		Atop,
+		AtopReverse,
+		Xor,
+		Add,
+		Saturate,
+
+		DisjointClear = 16,
+		DisjointSrc,
+		DisjointDst,
+		DisjointOver,
+		DisjointOverReverse,
+		DisjointIn,
+		DisjointInReverse,
+		DisjointOut,
+		DisjointOutReverse,
+		DisjointAtop,
+		DisjointAtopReverse,
+		DisjointXor,
+
+		ConjointClear = 32,
+		ConjointSrc,
+		ConjointDst,
+		ConjointOver,
+		ConjointOverReverse,
+		ConjointIn,
+		ConjointInReverse,
+		ConjointOut,
+		ConjointOutReverse,
+		ConjointAtop,
+		ConjointAtopReverse,
+		ConjointXor,
+
+		Multiply = 48,
+		Screen,
+		Overlay,
+		Darken,
+		Lighten,
+		ColorDodge,
+		ColorBurn,
+		HardLight,
+		SoftLight,
+		Difference,
+		Exclusion,
+		HslHue,
+		HslSaturation,
+		HslColor,
+		HslLuminosity,
+	}
+
+	/// A color with 16-bit red, green, blue, and alpha channels.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Color {
+		pub red: u16,
+		pub green: u16,
+		pub blue: u16,
+		pub alpha: u16,
+	}
+
+	/// The dimensions and positioning of a glyph's image.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct GlyphInfo {
+		pub width: u16,
+		pub height: u16,
+		/// The x-coordinate of the glyph's origin within its image.
+		pub x: i16,
+		/// The y-coordinate of the glyph's origin within its image.
+		pub y: i16,
+		/// The change in x-coordinate to the origin of the next glyph.
+		pub x_offset: i16,
+		/// The change in y-coordinate to the origin of the next glyph.
+		pub y_offset: i16,
+	}
+}
+
+define! {
+	/// Informs the X server of the version of the RENDER extension supported
+	/// by the client, and queries the version supported by the X server.
+	pub struct QueryVersion: Request<{ EXTENSION.major_opcode() }, 0> -> QueryVersionReply {
+		pub client_major_version: u32,
+		pub client_minor_version: u32,
+	}
+
+	pub struct QueryVersionReply: Reply for QueryVersion {
+		pub major_version: u32,
+		pub minor_version: u32,
+		[(); 16],
+	}
+
+	/// Queries the [`PictFormInfo`]s supported by the X server, and which of
+	/// them are used for each screen, depth, and visual.
+	pub struct QueryPictFormats: Request<{ EXTENSION.major_opcode() }, 1> -> QueryPictFormatsReply;
+
+	pub struct QueryPictFormatsReply: Reply for QueryPictFormats {
+		let formats_len: u32 = self => self.formats.len() as u32,
+		let screens_len: u32 = self => self.screens.len() as u32,
+		// The total number of depths across all screens.
+		let depths_len: u32 = self => self.screens.iter()
+			.map(|screen| screen.depths.len() as u32)
+			.sum::<u32>(),
+		// The total number of visuals across all depths of all screens.
+		let visuals_len: u32 = self => self.screens.iter()
+			.flat_map(|screen| &screen.depths)
+			.map(|depth| depth.visuals.len() as u32)
+			.sum::<u32>(),
+		let subpixels_len: u32 = self => self.subpixels.len() as u32,
+		[(); 4],
+		#[context(formats_len => *formats_len as usize)]
+		pub formats: Vec<PictFormInfo>,
+		#[context(screens_len => *screens_len as usize)]
+		pub screens: Vec<PictScreen>,
+		/// The subpixel order of each screen: unknown (`0`), horizontal RGB
+		/// (`1`), horizontal BGR (`2`), vertical RGB (`3`), vertical BGR (`4`),
+		/// or none (`5`).
+		#[context(subpixels_len => *subpixels_len as usize)]
+		pub subpixels: Vec<u32>,
+	}
+
+	/// Creates a new `picture` for the given drawable.
+	pub struct CreatePicture: Request<{ EXTENSION.major_opcode() }, 4> {
+		pub picture: Picture,
+		/// The [`Window`] or [`Pixmap`] to create the picture for.
+		pub drawable: Drawable,
+		/// The format of the picture, which must have the same depth as the
+		/// drawable.
+		pub format: PictFormat,
+		/// Which of the picture's attributes are given in `values`.
+		pub value_mask: u32,
+		/// One value for each bit set in the `value_mask`, in order.
+		#[context(value_mask => value_mask.count_ones() as usize)]
+		pub values: Vec<u32>,
+	}
+
+	/// Destroys the given `picture`.
+	pub struct FreePicture: Request<{ EXTENSION.major_opcode() }, 7> {
+		pub picture: Picture,
+	}
+
+	/// Composites the `source` picture onto the `destination` picture with
+	/// the given `op`.
+	pub struct Composite: Request<{ EXTENSION.major_opcode() }, 8> {
+		pub op: PictOp,
+		[(); 3],
+		pub source: Picture,
+		/// If not [`None`], the alpha channel of this picture is multiplied
+		/// with the `source` when compositing.
+		pub mask: Option<Picture>,
+		pub destination: Picture,
+		pub source_x: i16,
+		pub source_y: i16,
+		pub mask_x: i16,
+		pub mask_y: i16,
+		pub destination_x: i16,
+		pub destination_y: i16,
+		pub width: u16,
+		pub height: u16,
+	}
+
+	/// Creates a new `glyphset` with the given `format` for its glyph images.
+	pub struct CreateGlyphSet: Request<{ EXTENSION.major_opcode() }, 17> {
+		pub glyphset: GlyphSet,
+		pub format: PictFormat,
+	}
+
+	/// Destroys the given `glyphset`.
+	pub struct FreeGlyphSet: Request<{ EXTENSION.major_opcode() }, 19> {
+		pub glyphset: GlyphSet,
+	}
+
+	/// Adds glyphs to the given `glyphset`.
+	pub struct AddGlyphs: Request<{ EXTENSION.major_opcode() }, 20> {
+		pub glyphset: GlyphSet,
+		let glyphs_len: u32 = self => self.glyphs.len() as u32,
+		/// The IDs of the glyphs to add.
+		#[context(glyphs_len => *glyphs_len as usize)]
+		pub glyphs: Vec<Glyph>,
+		/// The [`GlyphInfo`] of each of the `glyphs`, in the same order.
+		#[context(glyphs_len => *glyphs_len as usize)]
+		pub infos: Vec<GlyphInfo>,
+		/// The images of each of the `glyphs`, in the same order, with each
+		/// scanline padded to a multiple of 4 bytes.
+		// The length of the images is implied by the length of the request,
+		// `_length_`, which is measured in 4-byte units.
+		#[context(glyphs_len => (usize::from(_length_) * 4).saturating_sub(12 + *glyphs_len as usize * 16))]
+		pub images: Vec<u8>,
+	}
+
+	/// Removes the given `glyphs` from the given `glyphset`.
+	pub struct FreeGlyphs: Request<{ EXTENSION.major_opcode() }, 22> {
+		pub glyphset: GlyphSet,
+		// The number of glyphs is implied by the length of the request,
+		// `_length_`, which is measured in 4-byte units.
+		#[context((usize::from(_length_) * 4).saturating_sub(8) / 4)]
+		pub glyphs: Vec<Glyph>,
+	}
+
+	/// Fills the given `rectangles` of the `destination` picture with the
+	/// given `color`, using the given `op`.
+	pub struct FillRectangles: Request<{ EXTENSION.major_opcode() }, 26> {
+		pub op: PictOp,
+		[(); 3],
+		pub destination: Picture,
+		pub color: Color,
+		// The number of rectangles is implied by the length of the request,
+		// `_length_`, which is measured in 4-byte units.
+		#[context((usize::from(_length_) * 4).saturating_sub(20) / 8)]
+		pub rectangles: Vec<Rectangle>,
+	}
+}
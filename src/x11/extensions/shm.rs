@@ -0,0 +1,176 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use crate::x11::{extensions::Extension, *};
+
+/// The name of the MIT-SHM extension, used to query its codes with
+/// `QueryExtension`.
+pub const NAME: &str = "MIT-SHM";
+
+/// The MIT-SHM extension, whose codes are looked up in the [current]
+/// [`Extensions`] when its messages are written or read.
+///
+/// [current]: super::Extensions::current
+/// [`Extensions`]: super::Extensions
+pub const EXTENSION: Extension = Extension::new(NAME);
+
+define! {
+	/// A shared memory segment which has been attached by the X server.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Seg {
+		id: u32,
+	}
+}
+
+impl Seg {
+	/// Creates a new [`Seg`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`Seg`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
+impl ResId for Seg {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
+define! {
+	/// Queries the version of the MIT-SHM extension supported by the X server.
+	pub struct QueryVersion: Request<{ EXTENSION.major_opcode() }, 0> -> QueryVersionReply;
+
+	pub struct QueryVersionReply: Reply for QueryVersion {
+		/// Whether pixmaps can be created with their contents in shared
+		/// memory.
+		#[metabyte]
+		pub shared_pixmaps: bool,
+		pub major_version: u16,
+		pub minor_version: u16,
+		/// The user ID of the X server process.
+		pub uid: u16,
+		/// The group ID of the X server process.
+		pub gid: u16,
+		/// The format of shared memory pixmaps, if `shared_pixmaps` is `true`.
+		pub pixmap_format: Format,
+		[(); 15],
+	}
+
+	/// Attaches the System V shared memory segment `shmid` to the X server as
+	/// the given `segment`.
+	pub struct Attach: Request<{ EXTENSION.major_opcode() }, 1> {
+		pub segment: Seg,
+		/// The System V shared memory identifier of the segment.
+		pub shmid: u32,
+		/// Whether the X server should attach the segment as read-only.
+		pub read_only: bool,
+		[(); 3],
+	}
+
+	/// Detaches the given `segment` from the X server.
+	///
+	/// The segment is only actually detached once any pixmaps which use it
+	/// have been destroyed.
+	pub struct Detach: Request<{ EXTENSION.major_opcode() }, 2> {
+		pub segment: Seg,
+	}
+
+	/// Draws an image read from a shared memory `segment` to a drawable.
+	pub struct PutImage: Request<{ EXTENSION.major_opcode() }, 3> {
+		/// The [`Window`] or [`Pixmap`] to draw to.
+		pub drawable: Drawable,
+		pub context: GraphicsContext,
+		/// The width of the whole image in the shared memory segment.
+		pub total_width: u16,
+		/// The height of the whole image in the shared memory segment.
+		pub total_height: u16,
+		pub src_x: u16,
+		pub src_y: u16,
+		pub src_width: u16,
+		pub src_height: u16,
+		pub dst_x: i16,
+		pub dst_y: i16,
+		pub depth: u8,
+		pub format: BitmapFormat,
+		/// Whether a [`ShmCompletion`] event should be sent once the X server
+		/// has finished reading from the shared memory segment.
+		pub send_event: bool,
+		[(); 1],
+		pub segment: Seg,
+		/// The offset of the image within the shared memory segment.
+		pub offset: u32,
+	}
+
+	/// Writes the contents of a rectangle of a drawable to a shared memory
+	/// `segment`.
+	pub struct GetImage: Request<{ EXTENSION.major_opcode() }, 4> -> GetImageReply {
+		/// The [`Window`] or [`Pixmap`] to read from.
+		pub drawable: Drawable,
+		pub x: i16,
+		pub y: i16,
+		pub width: u16,
+		pub height: u16,
+		pub plane_mask: u32,
+		pub format: Format,
+		[(); 3],
+		pub segment: Seg,
+		/// The offset within the shared memory segment at which to write the
+		/// image.
+		pub offset: u32,
+	}
+
+	pub struct GetImageReply: Reply for GetImage {
+		#[metabyte]
+		pub depth: u8,
+		pub visual: Option<VisualId>,
+		/// The number of bytes written to the shared memory segment.
+		pub size: u32,
+		[(); 16],
+	}
+
+	/// Has the X server create a new shared memory segment and attach it as
+	/// the given `segment`.
+	///
+	/// The segment's file descriptor is sent with the reply as ancillary data
+	/// over the connection's Unix domain socket; it is not part of the reply
+	/// itself.
+	pub struct CreateSegment: Request<{ EXTENSION.major_opcode() }, 7> -> CreateSegmentReply {
+		pub segment: Seg,
+		/// The size of the segment in bytes.
+		pub size: u32,
+		/// Whether the X server should attach the segment as read-only.
+		pub read_only: bool,
+		[(); 3],
+	}
+
+	pub struct CreateSegmentReply: Reply for CreateSegment {
+		// The number of file descriptors sent with the reply: always `1`.
+		#[metabyte]
+		let fd_count: u8 = 1,
+		[(); 24],
+	}
+
+	/// Sent once the X server has finished with a shared memory segment used
+	/// in a [`PutImage`] request with `send_event` set.
+	pub struct ShmCompletion: Event<{ EXTENSION.first_event() }> {
+		/// The [`Window`] or [`Pixmap`] which was drawn to.
+		pub drawable: Drawable,
+		/// The minor opcode of the request which generated this event.
+		pub minor_event: u16,
+		/// The major opcode of the request which generated this event.
+		pub major_event: u8,
+		[(); 1],
+		pub segment: Seg,
+		pub offset: u32,
+		[(); 12],
+	}
+}
@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::define;
+
+use crate::x11::extensions::Extension;
+
+/// The name of the XC-MISC extension, used to query its codes with
+/// `QueryExtension`.
+pub const NAME: &str = "XC-MISC";
+
+/// The XC-MISC extension, whose codes are looked up in the [current]
+/// [`Extensions`] when its messages are written or read.
+///
+/// [current]: super::Extensions::current
+/// [`Extensions`]: super::Extensions
+pub const EXTENSION: Extension = Extension::new(NAME);
+
+define! {
+	/// Informs the X server of the version of the XC-MISC extension supported
+	/// by the client, and queries the version supported by the X server.
+	pub struct GetVersion: Request<{ EXTENSION.major_opcode() }, 0> -> GetVersionReply {
+		pub client_major_version: u16,
+		pub client_minor_version: u16,
+	}
+
+	pub struct GetVersionReply: Reply for GetVersion {
+		pub major_version: u16,
+		pub minor_version: u16,
+		[(); 20],
+	}
+
+	/// Queries a range of resource IDs which are not in use by this client.
+	///
+	/// This is used once all of the resource IDs given in the connection
+	/// setup have been allocated; see [`XidAllocator`].
+	///
+	/// [`XidAllocator`]: crate::x11::xid::XidAllocator
+	pub struct GetXidRange: Request<{ EXTENSION.major_opcode() }, 1> -> GetXidRangeReply;
+
+	pub struct GetXidRangeReply: Reply for GetXidRange {
+		/// The first resource ID in the range.
+		pub start_id: u32,
+		/// The number of resource IDs in the range.
+		///
+		/// If this is `0`, there are no resource IDs left.
+		pub count: u32,
+		[(); 16],
+	}
+
+	/// Queries up to `count` individual resource IDs which are not in use by
+	/// this client.
+	pub struct GetXidList: Request<{ EXTENSION.major_opcode() }, 2> -> GetXidListReply {
+		pub count: u32,
+	}
+
+	pub struct GetXidListReply: Reply for GetXidList {
+		let ids_len: u32 = self => self.ids.len() as u32,
+		[(); 20],
+		#[context(ids_len => *ids_len as usize)]
+		pub ids: Vec<u32>,
+	}
+}
@@ -0,0 +1,238 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::{define, ByteSize, StaticByteSize};
+
+use crate::x11::{extensions::Extension, *};
+
+/// The name of the XFIXES extension, used to query its codes with
+/// `QueryExtension`.
+pub const NAME: &str = "XFIXES";
+
+/// The XFIXES extension, whose codes are looked up in the [current]
+/// [`Extensions`] when its messages are written or read.
+///
+/// [current]: super::Extensions::current
+/// [`Extensions`]: super::Extensions
+pub const EXTENSION: Extension = Extension::new(NAME);
+
+define! {
+	/// A set of rectangles stored by the X server.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub struct Region {
+		id: u32,
+	}
+
+	/// Which of a window's shapes a [`Region`] is taken from or applied to.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub enum ShapeKind {
+		/// The window's bounding shape, including its border.
+		Bounding,
+		/// The window's clip shape, excluding its border.
+		Clip,
+		/// The shape of the window which receives input.
+		Input,
+	}
+
+	/// The change to a selection's owner which a [`SelectionNotify`] event
+	/// was sent for.
+	#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, StaticByteSize, ByteSize)]
+	pub enum SelectionEvent {
+		/// The selection's owner was set with a `SetSelectionOwner` request.
+		SetSelectionOwner,
+		/// The window which owned the selection was destroyed.
+		SelectionWindowDestroy,
+		/// The client which owned the selection closed its connection.
+		SelectionClientClose,
+	}
+}
+
+impl Region {
+	/// Creates a new [`Region`] with the given `id`.
+	#[must_use]
+	pub const fn new(id: u32) -> Self {
+		Self { id }
+	}
+
+	/// Creates a new [`Region`] with an ID of `0`.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self { id: 0 }
+	}
+}
+
+impl ResId for Region {
+	fn res_id(&self) -> u32 {
+		self.id
+	}
+}
+
+define! {
+	/// Informs the X server of the version of the XFIXES extension supported
+	/// by the client, and queries the version supported by the X server.
+	///
+	/// This must be sent before any other XFIXES requests.
+	pub struct QueryVersion: Request<{ EXTENSION.major_opcode() }, 0> -> QueryVersionReply {
+		pub client_major_version: u32,
+		pub client_minor_version: u32,
+	}
+
+	pub struct QueryVersionReply: Reply for QueryVersion {
+		pub major_version: u32,
+		pub minor_version: u32,
+		[(); 16],
+	}
+
+	/// Selects which changes to the owner of the given `selection` the
+	/// `window`'s client is sent [`SelectionNotify`] events for.
+	///
+	/// An empty `event_mask` stops the events from being sent.
+	pub struct SelectSelectionInput: Request<{ EXTENSION.major_opcode() }, 2> {
+		pub window: Window,
+		pub selection: Atom,
+		pub event_mask: SelectionEventMask,
+	}
+
+	/// Creates a new `region` which is the union of the given `rectangles`.
+	pub struct CreateRegion: Request<{ EXTENSION.major_opcode() }, 5> {
+		pub region: Region,
+		// The number of rectangles is implied by the length of the request,
+		// `_length_`, which is measured in 4-byte units.
+		#[context((usize::from(_length_) * 4).saturating_sub(8) / 8)]
+		pub rectangles: Vec<Rectangle>,
+	}
+
+	/// Creates a new `region` from the set bits of the given `bitmap`.
+	pub struct CreateRegionFromBitmap: Request<{ EXTENSION.major_opcode() }, 6> {
+		pub region: Region,
+		/// A [`Pixmap`] with a depth of `1`.
+		pub bitmap: Pixmap,
+	}
+
+	/// Creates a new `region` from one of the shapes of the given `window`.
+	pub struct CreateRegionFromWindow: Request<{ EXTENSION.major_opcode() }, 7> {
+		pub region: Region,
+		pub window: Window,
+		/// Either [`ShapeKind::Bounding`] or [`ShapeKind::Clip`].
+		pub kind: ShapeKind,
+		[(); 3],
+	}
+
+	/// Creates a new `region` from the clip list of the given `context`.
+	pub struct CreateRegionFromGc: Request<{ EXTENSION.major_opcode() }, 8> {
+		pub region: Region,
+		pub context: GraphicsContext,
+	}
+
+	/// Destroys the given `region`.
+	pub struct DestroyRegion: Request<{ EXTENSION.major_opcode() }, 10> {
+		pub region: Region,
+	}
+
+	/// Replaces the contents of the given `region` with the union of the
+	/// given `rectangles`.
+	pub struct SetRegion: Request<{ EXTENSION.major_opcode() }, 11> {
+		pub region: Region,
+		// The number of rectangles is implied by the length of the request,
+		// `_length_`, which is measured in 4-byte units.
+		#[context((usize::from(_length_) * 4).saturating_sub(8) / 8)]
+		pub rectangles: Vec<Rectangle>,
+	}
+
+	/// Replaces the contents of the `destination` region with those of the
+	/// `source` region.
+	pub struct CopyRegion: Request<{ EXTENSION.major_opcode() }, 12> {
+		pub source: Region,
+		pub destination: Region,
+	}
+
+	/// Sets the `destination` region to the union of two regions.
+	pub struct UnionRegion: Request<{ EXTENSION.major_opcode() }, 13> {
+		pub source1: Region,
+		pub source2: Region,
+		pub destination: Region,
+	}
+
+	/// Sets the `destination` region to the intersection of two regions.
+	pub struct IntersectRegion: Request<{ EXTENSION.major_opcode() }, 14> {
+		pub source1: Region,
+		pub source2: Region,
+		pub destination: Region,
+	}
+
+	/// Sets the `destination` region to `source1` with `source2` subtracted
+	/// from it.
+	pub struct SubtractRegion: Request<{ EXTENSION.major_opcode() }, 15> {
+		pub source1: Region,
+		pub source2: Region,
+		pub destination: Region,
+	}
+
+	/// Sets the `destination` region to the area of the `bounds` not covered
+	/// by the `source` region.
+	pub struct InvertRegion: Request<{ EXTENSION.major_opcode() }, 16> {
+		pub source: Region,
+		pub bounds: Rectangle,
+		pub destination: Region,
+	}
+
+	/// Moves the given `region` by `dx` and `dy`.
+	pub struct TranslateRegion: Request<{ EXTENSION.major_opcode() }, 17> {
+		pub region: Region,
+		pub dx: i16,
+		pub dy: i16,
+	}
+
+	/// Sets the `destination` region to the smallest rectangle which contains
+	/// the `source` region.
+	pub struct RegionExtents: Request<{ EXTENSION.major_opcode() }, 18> {
+		pub source: Region,
+		pub destination: Region,
+	}
+
+	/// Returns the rectangles which make up the given `region`.
+	pub struct FetchRegion: Request<{ EXTENSION.major_opcode() }, 19> -> FetchRegionReply {
+		pub region: Region,
+	}
+
+	pub struct FetchRegionReply: Reply for FetchRegion {
+		/// The smallest rectangle which contains the region.
+		pub extents: Rectangle,
+		[(); 16],
+		// The number of rectangles is implied by the length of the reply,
+		// `_length_`, which is measured in 4-byte units.
+		#[context((_length_ as usize) / 2)]
+		pub rectangles: Vec<Rectangle>,
+	}
+
+	/// Sets one of the shapes of the given `window` to the given `region`,
+	/// offset by `x_offset` and `y_offset`.
+	///
+	/// If `region` is [`None`], the shape is removed.
+	pub struct SetWindowShapeRegion: Request<{ EXTENSION.major_opcode() }, 21> {
+		pub window: Window,
+		pub kind: ShapeKind,
+		[(); 3],
+		pub x_offset: i16,
+		pub y_offset: i16,
+		pub region: Option<Region>,
+	}
+
+	/// Sent when the owner of a `selection` changes, if the change was
+	/// selected with [`SelectSelectionInput`].
+	pub struct SelectionNotify: Event<{ EXTENSION.first_event() }> {
+		#[metabyte]
+		pub subtype: SelectionEvent,
+		/// The window which the event was selected on.
+		pub window: Window,
+		/// The new owner of the `selection`, or [`None`] if it has no owner.
+		pub owner: Option<Window>,
+		pub selection: Atom,
+		/// The time at which the event was generated.
+		pub timestamp: Timestamp,
+		/// The time at which ownership of the `selection` was last changed.
+		pub selection_timestamp: Timestamp,
+		[(); 8],
+	}
+}
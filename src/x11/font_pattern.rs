@@ -0,0 +1,269 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+/// A pattern of font names, as given in `ListFonts` and `ListFontsWithInfo`
+/// requests.
+///
+/// Patterns are matched the same way as by the X server:
+/// - `?` matches any single character;
+/// - `*` matches any number of characters, including none;
+/// - any other character matches itself, ignoring case.
+///
+/// Case is ignored for every letter in Latin-1, not only for ASCII letters.
+///
+/// # Examples
+/// ```ignore
+/// let pattern = FontPattern::new("-*-fixed-medium-r-*--13-*")?;
+///
+/// assert!(pattern.matches_str("-Misc-Fixed-Medium-R-Normal--13-120-75-75-C-70-ISO8859-1"));
+/// assert!(!pattern.matches_str("-Misc-Fixed-Bold-R-Normal--13-120-75-75-C-70-ISO8859-1"));
+///
+/// connection.send(ListFonts {
+///     max_names: 100,
+///     pattern: String8::from(pattern.as_bytes().to_vec()),
+/// })?;
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FontPattern(Vec<u8>);
+
+/// The reason a [`FontPattern`] is not valid.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FontPatternError {
+	/// The pattern is longer than [`FontPattern::MAX_LEN`] characters.
+	TooLong {
+		/// The length of the pattern.
+		len: usize,
+	},
+	/// The pattern contains a character which is not in Latin-1.
+	Unencodable(char),
+}
+
+impl FontPattern {
+	/// The maximum length of a pattern, in characters.
+	pub const MAX_LEN: usize = u16::MAX as usize;
+
+	/// The character which matches any single character.
+	pub const ANY_CHAR: u8 = b'?';
+	/// The character which matches any number of characters.
+	pub const ANY_CHARS: u8 = b'*';
+
+	/// Creates a new [`FontPattern`] from the given `pattern`.
+	///
+	/// # Errors
+	/// Returns [`FontPatternError::TooLong`] if the `pattern` is longer than
+	/// [`MAX_LEN`] characters, or [`FontPatternError::Unencodable`] if it
+	/// contains a character which is not in Latin-1.
+	///
+	/// [`MAX_LEN`]: FontPattern::MAX_LEN
+	pub fn new(pattern: &str) -> Result<Self, FontPatternError> {
+		let bytes = pattern
+			.chars()
+			.map(|r#char| u8::try_from(r#char).map_err(|_| FontPatternError::Unencodable(r#char)))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Self::from_bytes(bytes)
+	}
+
+	/// Creates a new [`FontPattern`] from the given Latin-1 `bytes`.
+	///
+	/// # Errors
+	/// Returns [`FontPatternError::TooLong`] if there are more than
+	/// [`MAX_LEN`] `bytes`.
+	///
+	/// [`MAX_LEN`]: FontPattern::MAX_LEN
+	pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, FontPatternError> {
+		let bytes = bytes.into();
+
+		if bytes.len() > Self::MAX_LEN {
+			return Err(FontPatternError::TooLong { len: bytes.len() });
+		}
+
+		Ok(Self(bytes))
+	}
+
+	/// The Latin-1 characters of this pattern.
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.0
+	}
+
+	/// Returns whether this pattern contains no wildcards, and so only
+	/// matches one name (ignoring case).
+	#[must_use]
+	pub fn is_literal(&self) -> bool {
+		!self
+			.0
+			.iter()
+			.any(|&byte| byte == Self::ANY_CHAR || byte == Self::ANY_CHARS)
+	}
+
+	/// Returns whether the given Latin-1 font `name` matches this pattern.
+	#[must_use]
+	pub fn matches(&self, name: &[u8]) -> bool {
+		let pattern = &self.0;
+
+		// The position in the pattern and name.
+		let (mut p, mut n) = (0, 0);
+		// The position in the pattern after the last `*`, and the position in
+		// the name which it has been matched up to.
+		let mut backtrack = None;
+
+		while n < name.len() {
+			match pattern.get(p) {
+				Some(&Self::ANY_CHARS) => {
+					p += 1;
+					backtrack = Some((p, n));
+				}
+
+				Some(&byte) if byte == Self::ANY_CHAR || fold(byte) == fold(name[n]) => {
+					p += 1;
+					n += 1;
+				}
+
+				// If this character doesn't match, have the last `*` match one
+				// more character and try again.
+				_ => match backtrack {
+					Some((after_star, matched)) => {
+						p = after_star;
+						n = matched + 1;
+						backtrack = Some((after_star, n));
+					}
+
+					None => return false,
+				},
+			}
+		}
+
+		// Any `*`s left over match nothing.
+		pattern[p..].iter().all(|&byte| byte == Self::ANY_CHARS)
+	}
+
+	/// Returns whether the given font `name` matches this pattern.
+	///
+	/// Names containing characters which are not in Latin-1 never match.
+	#[must_use]
+	pub fn matches_str(&self, name: &str) -> bool {
+		name.chars()
+			.map(u8::try_from)
+			.collect::<Result<Vec<_>, _>>()
+			.is_ok_and(|name| self.matches(&name))
+	}
+
+	/// Returns the `names` which match this pattern, in the same order.
+	pub fn filter<'a, N>(
+		&'a self,
+		names: impl IntoIterator<Item = N> + 'a,
+	) -> impl Iterator<Item = N> + 'a
+	where
+		N: AsRef<[u8]>,
+	{
+		names.into_iter().filter(|name| self.matches(name.as_ref()))
+	}
+}
+
+/// Folds the case of a Latin-1 character, as the X server does when it
+/// compares font names.
+const fn fold(byte: u8) -> u8 {
+	match byte {
+		// `A` to `Z`, `À` to `Ö`, and `Ø` to `Þ`; `×` has no lowercase form.
+		b'A'..=b'Z' | 0xc0..=0xd6 | 0xd8..=0xde => byte + 0x20,
+
+		_ => byte,
+	}
+}
+
+impl fmt::Display for FontPattern {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0
+			.iter()
+			.try_for_each(|&byte| write!(f, "{}", char::from(byte)))
+	}
+}
+
+impl fmt::Display for FontPatternError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::TooLong { len } => write!(
+				f,
+				"font patterns may be at most {} characters long, but this one is {len}",
+				FontPattern::MAX_LEN
+			),
+
+			Self::Unencodable(r#char) => {
+				write!(f, "{char:?} can't be encoded in a font pattern")
+			}
+		}
+	}
+}
+
+impl Error for FontPatternError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FIXED: &str = "-Misc-Fixed-Medium-R-Normal--13-120-75-75-C-70-ISO8859-1";
+	const FIXED_BOLD: &str = "-Misc-Fixed-Bold-R-Normal--13-120-75-75-C-70-ISO8859-1";
+	const HELVETICA: &str = "-Adobe-Helvetica-Bold-O-Normal--12-120-75-75-P-69-ISO8859-1";
+
+	fn matches(pattern: &str, name: &str) -> bool {
+		FontPattern::new(pattern).unwrap().matches_str(name)
+	}
+
+	#[test]
+	fn xlfd_names_are_matched() {
+		assert!(matches("-*-fixed-medium-r-*--13-*", FIXED));
+		assert!(!matches("-*-fixed-medium-r-*--13-*", FIXED_BOLD));
+		assert!(matches(
+			"-misc-fixed-*-r-normal--13-*-*-*-c-70-iso8859-1",
+			FIXED_BOLD
+		));
+		assert!(matches("*-helvetica-bold-?-*", HELVETICA));
+		assert!(!matches("*-helvetica-bold-r-*", HELVETICA));
+		assert!(matches("*", HELVETICA));
+		assert!(matches("*-iso8859-?", HELVETICA));
+		assert!(!matches(
+			"*-iso8859-?",
+			"-misc-fixed-medium-r-normal--13-120-75-75-c-70-iso10646-1"
+		));
+
+		// Aliases are matched in the same way.
+		assert!(matches("fixed", "fixed"));
+		assert!(matches("?ixe*", "fixed"));
+		assert!(!matches("fixed", "fixed-bold"));
+		assert!(!matches("fixed?", "fixed"));
+		assert!(matches("fixed*", "fixed"));
+		assert!(matches("", ""));
+		assert!(!matches("", "fixed"));
+	}
+
+	#[test]
+	fn case_is_ignored_in_latin_1() {
+		assert!(matches("-*-ÉTÉ-*", "-misc-été-medium"));
+		// `×` and `÷` are not letters.
+		assert!(!matches("×", "÷"));
+		assert!(!matches("*", "\u{0100}"));
+	}
+
+	#[test]
+	fn patterns_are_checked() {
+		assert_eq!(
+			FontPattern::new("-*-\u{2603}-*"),
+			Err(FontPatternError::Unencodable('\u{2603}'))
+		);
+		assert!(FontPattern::from_bytes(vec![b'*'; FontPattern::MAX_LEN + 1]).is_err());
+
+		assert!(FontPattern::new("fixed").unwrap().is_literal());
+		assert!(!FontPattern::new("fix?d").unwrap().is_literal());
+
+		let pattern = FontPattern::new("*bold*").unwrap();
+		let names = pattern
+			.filter([FIXED, FIXED_BOLD, HELVETICA])
+			.collect::<Vec<_>>();
+
+		assert_eq!(names, [FIXED_BOLD, HELVETICA]);
+	}
+}
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+	util,
+	x11::{byte_order::ByteOrder, AnyEvent},
+};
+
+/// The first byte of an error.
+pub const ERROR: u8 = 0;
+/// The first byte of a reply.
+pub const REPLY: u8 = 1;
+
+/// The bit which the X server sets in the first byte of an event which was
+/// sent with a [`SendEvent`] request.
+///
+/// [`SendEvent`]: crate::x11::requests::SendEvent
+pub const SENT_EVENT_FLAG: u8 = AnyEvent::SENT_FLAG;
+
+/// The code of a `GenericEvent`, which may be longer than 32 bytes.
+pub const GENERIC_EVENT_CODE: u8 = 35;
+
+/// The size of every error and event other than a `GenericEvent`, and the
+/// minimum size of a reply, in bytes.
+pub const MESSAGE_SIZE: usize = 32;
+
+/// The kind of a message sent by the X server, as given by its first byte.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ServerMessageKind {
+	/// An error, which is always [`MESSAGE_SIZE`] bytes.
+	Error,
+	/// A reply, which is at least [`MESSAGE_SIZE`] bytes.
+	Reply,
+	/// An event.
+	Event {
+		/// The code of the event, without the [`SENT_EVENT_FLAG`].
+		code: u8,
+		/// Whether the event was sent by a client with a [`SendEvent`]
+		/// request, rather than generated by the X server.
+		///
+		/// [`SendEvent`]: crate::x11::requests::SendEvent
+		sent: bool,
+	},
+}
+
+impl ServerMessageKind {
+	/// Returns whether this is a `GenericEvent`, which may be longer than
+	/// [`MESSAGE_SIZE`] bytes.
+	#[must_use]
+	pub const fn is_generic_event(self) -> bool {
+		matches!(
+			self,
+			Self::Event {
+				code: GENERIC_EVENT_CODE,
+				..
+			}
+		)
+	}
+}
+
+/// Returns the kind of message sent by the X server which starts with the
+/// given `first_byte`.
+///
+/// # Examples
+/// ```ignore
+/// match framing::classify(bytes[0]) {
+///     ServerMessageKind::Error => handle_error(AnyError::read_from(&mut &bytes[1..])?),
+///     ServerMessageKind::Reply => replies.decode(bytes),
+///
+///     ServerMessageKind::Event { code, sent } => {
+///         // ...
+///     }
+/// }
+/// ```
+#[must_use]
+pub const fn classify(first_byte: u8) -> ServerMessageKind {
+	match first_byte {
+		ERROR => ServerMessageKind::Error,
+		REPLY => ServerMessageKind::Reply,
+
+		byte => ServerMessageKind::Event {
+			code: byte & !SENT_EVENT_FLAG,
+			sent: byte & SENT_EVENT_FLAG != 0,
+		},
+	}
+}
+
+/// Returns the total length, in bytes, of the message sent by the X server
+/// which starts with the given `header`, read in the given `byte_order`.
+///
+/// Errors and events other than a `GenericEvent` are always
+/// [`MESSAGE_SIZE`] bytes. Replies and `GenericEvent`s give the number of
+/// 4-byte units which follow their first [`MESSAGE_SIZE`] bytes in their
+/// second to fifth bytes, so a reader knows how many more bytes to wait for
+/// once the header has arrived.
+#[must_use]
+pub const fn message_len(header: &[u8; MESSAGE_SIZE], byte_order: ByteOrder) -> usize {
+	let kind = classify(header[0]);
+
+	if !matches!(kind, ServerMessageKind::Reply) && !kind.is_generic_event() {
+		return MESSAGE_SIZE;
+	}
+
+	let length = [header[4], header[5], header[6], header[7]];
+	let length = match byte_order {
+		ByteOrder::MostSignificantFirst => u32::from_be_bytes(length),
+		ByteOrder::LeastSignificantFirst => u32::from_le_bytes(length),
+	};
+
+	util::extra_bytes(length as usize)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn messages_are_classified_by_their_first_byte() {
+		assert_eq!(classify(0), ServerMessageKind::Error);
+		assert_eq!(classify(1), ServerMessageKind::Reply);
+		assert_eq!(
+			classify(31),
+			ServerMessageKind::Event {
+				code: 31,
+				sent: false
+			}
+		);
+		assert_eq!(
+			classify(31 | SENT_EVENT_FLAG),
+			ServerMessageKind::Event {
+				code: 31,
+				sent: true
+			}
+		);
+
+		assert!(classify(GENERIC_EVENT_CODE).is_generic_event());
+		assert!(classify(GENERIC_EVENT_CODE | SENT_EVENT_FLAG).is_generic_event());
+		assert!(!classify(REPLY).is_generic_event());
+	}
+
+	#[test]
+	fn replies_and_generic_events_give_their_length() {
+		let mut header = [0; MESSAGE_SIZE];
+		header[4..8].copy_from_slice(&3u32.to_le_bytes());
+
+		for first_byte in [ERROR, 2, 31 | SENT_EVENT_FLAG] {
+			header[0] = first_byte;
+			assert_eq!(
+				message_len(&header, ByteOrder::LeastSignificantFirst),
+				MESSAGE_SIZE
+			);
+		}
+
+		for first_byte in [REPLY, GENERIC_EVENT_CODE] {
+			header[0] = first_byte;
+			assert_eq!(
+				message_len(&header, ByteOrder::LeastSignificantFirst),
+				MESSAGE_SIZE + 12
+			);
+			assert_eq!(
+				message_len(&header, ByteOrder::MostSignificantFirst),
+				MESSAGE_SIZE + 0x0300_0000 * 4
+			);
+		}
+	}
+}
@@ -0,0 +1,301 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::x11::{
+	events::{ButtonPress, ButtonRelease},
+	requests::GrabButton,
+	Any, AnyModifierKeyMask, Button, EventMask, ModifierKeyMask, ModifierMask, Modifiers,
+	PointerEventMask, Time, Timestamp,
+};
+
+impl ModifierMask {
+	/// The buttons which have masks: buttons 1 to 5.
+	const BUTTONS: Self = Self::from_bits_truncate(
+		Self::BUTTON_1.bits()
+			| Self::BUTTON_2.bits()
+			| Self::BUTTON_3.bits()
+			| Self::BUTTON_4.bits()
+			| Self::BUTTON_5.bits(),
+	);
+
+	/// Returns the mask for the given pointer `button`, or [`None`] if it has
+	/// none.
+	///
+	/// Only buttons 1 to 5 have masks: whether other buttons are held is not
+	/// reported.
+	#[must_use]
+	pub const fn button(button: Button) -> Option<Self> {
+		match button {
+			1..=5 => Some(Self::from_bits_truncate(
+				Self::BUTTON_1.bits() << (button - 1),
+			)),
+			_ => None,
+		}
+	}
+
+	/// The modifier keys held in this mask, without the buttons.
+	#[must_use]
+	pub const fn keys(self) -> ModifierKeyMask {
+		ModifierKeyMask::from_bits_truncate(self.bits())
+	}
+
+	/// The buttons held in this mask, without the modifier keys.
+	#[must_use]
+	pub const fn buttons(self) -> Self {
+		Self::from_bits_truncate(self.bits() & Self::BUTTONS.bits())
+	}
+}
+
+impl AnyModifierKeyMask {
+	/// Returns whether a key or button pressed with the given `state` matches
+	/// a passive grab with these modifiers.
+	///
+	/// The modifier keys held must be exactly these modifiers, or any
+	/// combination of modifier keys (including none) if this contains
+	/// [`ANY_MODIFIER`]. It does not matter whether the modifiers have keys
+	/// assigned to them. Buttons held in the `state` are ignored.
+	///
+	/// [`ANY_MODIFIER`]: AnyModifierKeyMask::ANY_MODIFIER
+	#[must_use]
+	pub const fn matches(self, state: ModifierMask) -> bool {
+		Modifiers::from_bits_truncate(self.bits()).matches(state)
+	}
+}
+
+impl Modifiers {
+	/// Returns whether a key or button pressed with the given `state` matches
+	/// a passive grab with these modifiers.
+	///
+	/// The modifier keys held must be exactly these modifiers, or any
+	/// combination of modifier keys (including none) if these are [`Any`].
+	/// It does not matter whether the modifiers have keys assigned to them.
+	/// Buttons held in the `state` are ignored.
+	///
+	/// [`Any`]: Modifiers::Any
+	#[must_use]
+	pub const fn matches(self, state: ModifierMask) -> bool {
+		match self {
+			Self::Any => true,
+			Self::Specific(keys) => keys.bits() == state.keys().bits(),
+		}
+	}
+}
+
+impl PointerEventMask {
+	/// Returns the given `mask` as a [`PointerEventMask`], or [`None`] if it
+	/// selects any events which can't be selected for a pointer grab, such as
+	/// `KEY_PRESS` or `STRUCTURE_NOTIFY`.
+	///
+	/// Pointer grabs report only pointer events and `KEYMAP_STATE`: the X
+	/// server generates a `Value` error for any other events in the event
+	/// mask of a [`GrabPointer`], [`GrabButton`], or
+	/// [`ChangeActivePointerGrab`] request.
+	///
+	/// [`GrabPointer`]: crate::x11::requests::GrabPointer
+	/// [`ChangeActivePointerGrab`]: crate::x11::requests::ChangeActivePointerGrab
+	#[must_use]
+	pub const fn from_event_mask(mask: EventMask) -> Option<Self> {
+		Self::from_bits(mask.bits())
+	}
+}
+
+impl From<PointerEventMask> for EventMask {
+	fn from(mask: PointerEventMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
+	}
+}
+
+impl Time {
+	/// Returns whether a grab request sent with this time takes effect, given
+	/// the time at which the grab was `last_changed` and the X server's
+	/// current time, `now`.
+	///
+	/// A request with a specific time is ignored if that time is earlier than
+	/// the time at which the grab was last changed, or later than the current
+	/// time. The [current time] is always between the two.
+	///
+	/// This applies to [`ChangeActivePointerGrab`], [`UngrabPointer`], and
+	/// [`UngrabKeyboard`] requests, and to [`GrabPointer`] and
+	/// [`GrabKeyboard`] requests when the device is already grabbed.
+	///
+	/// [current time]: Time::Current
+	/// [`ChangeActivePointerGrab`]: crate::x11::requests::ChangeActivePointerGrab
+	/// [`UngrabPointer`]: crate::x11::requests::UngrabPointer
+	/// [`UngrabKeyboard`]: crate::x11::requests::UngrabKeyboard
+	/// [`GrabPointer`]: crate::x11::requests::GrabPointer
+	/// [`GrabKeyboard`]: crate::x11::requests::GrabKeyboard
+	#[must_use]
+	pub const fn takes_effect(self, last_changed: Timestamp, now: Timestamp) -> bool {
+		match self {
+			Self::Current => true,
+			Self::Specific(time) => last_changed <= time && time <= now,
+		}
+	}
+}
+
+impl ButtonPress {
+	/// Returns whether this press activates a pointer grab, if the pointer
+	/// is not already grabbed: that is, whether no other buttons were held
+	/// when the `button` was pressed.
+	///
+	/// If a [passive grab] established with [`GrabButton`] [matches] this
+	/// press, that grab is activated. Otherwise, an automatic grab is
+	/// activated for the client which receives this event, as though it had
+	/// sent a [`GrabPointer`] request with the `event` window as the grab
+	/// window, the event mask it selected on that window, and
+	/// `owner_events` only if it selected `OwnerGrabButton`.
+	///
+	/// Either grab lasts until every button is released; see
+	/// [`ButtonRelease::releases_grab`].
+	///
+	/// [passive grab]: GrabButton
+	/// [matches]: GrabButton::matches
+	/// [`GrabPointer`]: crate::x11::requests::GrabPointer
+	#[must_use]
+	pub const fn activates_grab(&self) -> bool {
+		self.state.buttons().is_empty()
+	}
+}
+
+impl ButtonRelease {
+	/// Returns whether this release ends a pointer grab which was activated
+	/// by a button press: that is, whether no other buttons are still held.
+	///
+	/// Such grabs, both automatic grabs and passive grabs established with
+	/// [`GrabButton`], are released once every button has been released,
+	/// regardless of which modifier keys are held.
+	///
+	/// The `state` is the state immediately before the release, so it
+	/// includes the released `button`. Only buttons 1 to 5 are reported in
+	/// the `state`, so other buttons which are still held are not known.
+	#[must_use]
+	pub fn releases_grab(&self) -> bool {
+		let held = self.state.buttons();
+
+		ModifierMask::button(self.button).map_or(held.is_empty(), |button| held == button)
+	}
+}
+
+impl GrabButton {
+	/// Returns whether the button press reported by the given `event` matches
+	/// this passive grab's `button` and `modifiers`.
+	///
+	/// A passive grab is activated by a press of its `button` (or any button,
+	/// if it is [`Any`]) while exactly its `modifiers` are held, and no other
+	/// buttons are held; see [`Modifiers::matches`].
+	///
+	/// The grab is only activated if, in addition:
+	/// - the pointer is not already grabbed;
+	/// - the `target_window` contains the pointer;
+	/// - the `confine_to` window, if any, is viewable; and
+	/// - no ancestor of the `target_window` has a passive grab which matches
+	///   the same press, as the grab on the outermost window is activated.
+	///
+	/// These conditions are not checked.
+	///
+	/// [`Any`]: crate::x11::Any::Any
+	#[must_use]
+	pub const fn matches(&self, event: &ButtonPress) -> bool {
+		let button = match self.button {
+			Any::Any => true,
+			Any::Specific(button) => button == event.button,
+		};
+
+		button && event.activates_grab() && self.modifiers.matches(event.state)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::Window;
+
+	fn press(button: Button, state: ModifierMask) -> ButtonPress {
+		ButtonPress {
+			_sequence_: 0,
+			button,
+			time: 0,
+			root: Window::new(1),
+			event: Window::new(2),
+			child: None,
+			root_x: 0,
+			root_y: 0,
+			event_x: 0,
+			event_y: 0,
+			state,
+			same_screen: true,
+		}
+	}
+
+	fn release(button: Button, state: ModifierMask) -> ButtonRelease {
+		ButtonRelease {
+			_sequence_: 0,
+			button,
+			time: 0,
+			root: Window::new(1),
+			event: Window::new(2),
+			child: None,
+			root_x: 0,
+			root_y: 0,
+			event_x: 0,
+			event_y: 0,
+			state,
+			same_screen: true,
+		}
+	}
+
+	#[test]
+	fn modifiers_must_match_exactly_unless_any() {
+		let grab = AnyModifierKeyMask::MOD_4;
+
+		assert!(grab.matches(ModifierMask::MOD_4));
+		assert!(grab.matches(ModifierMask::MOD_4 | ModifierMask::BUTTON_1));
+		assert!(!grab.matches(ModifierMask::MOD_4 | ModifierMask::SHIFT));
+		assert!(!grab.matches(ModifierMask::empty()));
+
+		let any = AnyModifierKeyMask::ANY_MODIFIER;
+
+		assert!(any.matches(ModifierMask::empty()));
+		assert!(any.matches(ModifierMask::CONTROL | ModifierMask::MOD_1));
+
+		assert!(Modifiers::Any.matches(ModifierMask::SHIFT));
+		assert!(Modifiers::from(ModifierKeyMask::MOD_4).matches(ModifierMask::MOD_4));
+		assert!(!Modifiers::default().matches(ModifierMask::MOD_4));
+	}
+
+	#[test]
+	fn grabs_are_activated_by_the_first_button_and_released_by_the_last() {
+		assert!(press(1, ModifierMask::SHIFT).activates_grab());
+		assert!(!press(3, ModifierMask::BUTTON_1).activates_grab());
+
+		assert!(release(1, ModifierMask::BUTTON_1).releases_grab());
+		assert!(!release(1, ModifierMask::BUTTON_1 | ModifierMask::BUTTON_3).releases_grab());
+		assert!(release(8, ModifierMask::empty()).releases_grab());
+	}
+
+	#[test]
+	fn only_pointer_events_can_be_grabbed() {
+		assert_eq!(
+			PointerEventMask::from_event_mask(EventMask::BUTTON_PRESS | EventMask::KEYMAP_STATE),
+			Some(PointerEventMask::BUTTON_PRESS | PointerEventMask::KEYMAP_STATE)
+		);
+		assert_eq!(
+			PointerEventMask::from_event_mask(EventMask::BUTTON_PRESS | EventMask::KEY_PRESS),
+			None
+		);
+		assert_eq!(
+			EventMask::from(PointerEventMask::POINTER_MOTION),
+			EventMask::POINTER_MOTION
+		);
+	}
+
+	#[test]
+	fn grab_times_must_be_between_the_last_change_and_now() {
+		assert!(Time::Current.takes_effect(100, 200));
+		assert!(Time::Specific(100).takes_effect(100, 200));
+		assert!(Time::Specific(200).takes_effect(100, 200));
+		assert!(!Time::Specific(99).takes_effect(100, 200));
+		assert!(!Time::Specific(201).takes_effect(100, 200));
+	}
+}
@@ -0,0 +1,807 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use crate::x11::{
+	byte_order::ByteOrder,
+	requests::{GetImage, GetImageReply, PutImage},
+	BitmapFormat, Drawable, Format, GraphicsContext,
+};
+
+/// The order of the bits within each scanline unit of a bitmap.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BitOrder {
+	/// The leftmost pixel is the least significant bit of the unit.
+	LeastSignificantFirst,
+	/// The leftmost pixel is the most significant bit of the unit.
+	MostSignificantFirst,
+}
+
+/// A format in which the X server stores [`ZPixmap`] images of a particular
+/// depth, as listed in the connection setup.
+///
+/// [`ZPixmap`]: Format::Zpixmap
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PixmapFormat {
+	/// The depth of the images stored in this format.
+	pub depth: u8,
+	/// The number of bits used for each pixel: `1`, `4`, `8`, `16`, `24`, or
+	/// `32`.
+	pub bits_per_pixel: u8,
+	/// The number of bits which each scanline is padded to a multiple of: `8`,
+	/// `16`, or `32`.
+	pub scanline_pad: u8,
+}
+
+/// How the X server lays out the data of images, as given in the connection
+/// setup.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ImageLayout {
+	/// The order of the bytes of each pixel in [`ZPixmap`] images, and of the
+	/// bytes of each scanline unit in bitmaps.
+	///
+	/// [`ZPixmap`]: Format::Zpixmap
+	pub image_byte_order: ByteOrder,
+	/// The order of the bits within each scanline unit of a bitmap.
+	pub bitmap_bit_order: BitOrder,
+	/// The number of bits in each scanline unit of a bitmap: `8`, `16`, or
+	/// `32`.
+	pub bitmap_scanline_unit: u8,
+	/// The number of bits which each scanline of a bitmap is padded to a
+	/// multiple of: `8`, `16`, or `32`.
+	pub bitmap_scanline_pad: u8,
+	/// The formats in which [`ZPixmap`] images of each supported depth are
+	/// stored.
+	///
+	/// [`ZPixmap`]: Format::Zpixmap
+	pub pixmap_formats: Vec<PixmapFormat>,
+}
+
+/// The bits of a pixel which contain each of its color channels, such as
+/// those given for a `TrueColor` visual.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ColorMasks {
+	/// The bits containing the red channel.
+	pub red: u32,
+	/// The bits containing the green channel.
+	pub green: u32,
+	/// The bits containing the blue channel.
+	pub blue: u32,
+	/// The bits containing the alpha channel, if the pixel has one.
+	///
+	/// Pixels without an alpha channel are opaque.
+	pub alpha: u32,
+}
+
+/// An image with its data laid out as it is sent in [`PutImage`] requests and
+/// [`GetImageReply`]s.
+///
+/// The data of an image depends on its `format`:
+/// - [`XyBitmap`] images have a depth of `1`, and are written as a single
+///   bitmap.
+/// - [`XyPixmap`] images are written as one bitmap for each bit plane,
+///   starting with the most significant plane.
+/// - [`ZPixmap`] images are written as one value for each pixel, in the
+///   [`PixmapFormat`] for their depth.
+///
+/// Each scanline is padded as the X server requires, and the pixels are
+/// ordered by its [`ImageLayout`], so the data can be sent as it is.
+///
+/// # Examples
+/// ```ignore
+/// let masks = ColorMasks::new(0xff0000, 0x00ff00, 0x0000ff);
+/// let image = Image::from_rgba(width, height, 24, masks, &rgba, &layout)?;
+///
+/// connection.send(image.put(window, context, 0, 0))?;
+/// ```
+///
+/// [`XyBitmap`]: BitmapFormat::Bitmap
+/// [`XyPixmap`]: Format::XyPixmap
+/// [`ZPixmap`]: Format::Zpixmap
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Image {
+	width: u16,
+	height: u16,
+	depth: u8,
+	format: BitmapFormat,
+	data: Vec<u8>,
+
+	scanlines: Scanlines,
+}
+
+/// The layout of each scanline of an [`Image`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Scanlines {
+	byte_order: ByteOrder,
+	bit_order: BitOrder,
+	/// The number of bits in each scanline unit of a bitmap.
+	unit: u8,
+	bits_per_pixel: u8,
+	/// The number of bytes in each scanline, including padding.
+	len: usize,
+}
+
+/// The reason an [`Image`] could not be created.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ImageError {
+	/// There is no [`PixmapFormat`] for the image's depth, or the image is an
+	/// `XyBitmap` image without a depth of `1`.
+	UnsupportedDepth(u8),
+	/// The [`PixmapFormat`] or [`ImageLayout`] uses a number of bits which the
+	/// X protocol does not allow.
+	InvalidLayout,
+	/// The data is not the length required by the image's dimensions.
+	WrongLength {
+		/// The number of bytes required.
+		expected: usize,
+		/// The number of bytes given.
+		found: usize,
+	},
+}
+
+impl ImageLayout {
+	/// Returns the [`PixmapFormat`] for images of the given `depth`, if it is
+	/// supported.
+	#[must_use]
+	pub fn pixmap_format(&self, depth: u8) -> Option<&PixmapFormat> {
+		self.pixmap_formats
+			.iter()
+			.find(|format| format.depth == depth)
+	}
+}
+
+impl ColorMasks {
+	/// Creates new [`ColorMasks`] for pixels without an alpha channel.
+	#[must_use]
+	pub const fn new(red: u32, green: u32, blue: u32) -> Self {
+		Self {
+			red,
+			green,
+			blue,
+			alpha: 0,
+		}
+	}
+
+	/// Sets the bits containing the alpha channel.
+	#[must_use]
+	pub const fn with_alpha(mut self, alpha: u32) -> Self {
+		self.alpha = alpha;
+		self
+	}
+
+	/// Returns the pixel with the given 8-bit channels.
+	#[must_use]
+	pub const fn pixel(&self, [red, green, blue, alpha]: [u8; 4]) -> u32 {
+		pack(red, self.red)
+			| pack(green, self.green)
+			| pack(blue, self.blue)
+			| pack(alpha, self.alpha)
+	}
+
+	/// Returns the 8-bit channels of the given `pixel`.
+	///
+	/// The alpha channel is `255` if there are no bits for it.
+	#[must_use]
+	pub const fn channels(&self, pixel: u32) -> [u8; 4] {
+		let alpha = if self.alpha == 0 {
+			u8::MAX
+		} else {
+			unpack(pixel, self.alpha)
+		};
+
+		[
+			unpack(pixel, self.red),
+			unpack(pixel, self.green),
+			unpack(pixel, self.blue),
+			alpha,
+		]
+	}
+}
+
+/// Scales the 8-bit `channel` to the bits of the given `mask`.
+#[allow(clippy::cast_possible_truncation)]
+const fn pack(channel: u8, mask: u32) -> u32 {
+	if mask == 0 {
+		return 0;
+	}
+
+	let max = (mask >> mask.trailing_zeros()) as u64;
+	// Rounded to the nearest value; this can't exceed `max`.
+	let value = ((channel as u64 * max + 127) / 255) as u32;
+
+	(value << mask.trailing_zeros()) & mask
+}
+
+/// Scales the bits of the given `mask` in the `pixel` to an 8-bit channel.
+#[allow(clippy::cast_possible_truncation)]
+const fn unpack(pixel: u32, mask: u32) -> u8 {
+	if mask == 0 {
+		return 0;
+	}
+
+	let max = (mask >> mask.trailing_zeros()) as u64;
+	let value = ((pixel & mask) >> mask.trailing_zeros()) as u64;
+
+	// Rounded to the nearest value; this can't exceed `255`.
+	((value * 255 + max / 2) / max) as u8
+}
+
+impl Image {
+	/// Creates a new [`Image`] with every pixel set to `0`.
+	///
+	/// # Errors
+	/// Returns [`ImageError::UnsupportedDepth`] if the `layout` has no
+	/// [`PixmapFormat`] for a [`ZPixmap`] image's depth, or if the image is an
+	/// [`XyBitmap`] image and its depth is not `1`.
+	///
+	/// [`ZPixmap`]: Format::Zpixmap
+	/// [`XyBitmap`]: BitmapFormat::Bitmap
+	pub fn new(
+		width: u16,
+		height: u16,
+		depth: u8,
+		format: BitmapFormat,
+		layout: &ImageLayout,
+	) -> Result<Self, ImageError> {
+		let scanlines = Scanlines::new(width, depth, format, layout)?;
+		let len = Self::data_len(height, depth, format, &scanlines);
+
+		Ok(Self {
+			width,
+			height,
+			depth,
+			format,
+			data: vec![0; len],
+
+			scanlines,
+		})
+	}
+
+	/// Creates a new [`Image`] with the given `data`, which must already be
+	/// laid out as the X server requires.
+	///
+	/// # Errors
+	/// Returns [`ImageError::WrongLength`] if the `data` is not the length
+	/// required, or an error for the same reasons as [`Image::new`].
+	pub fn from_data(
+		width: u16,
+		height: u16,
+		depth: u8,
+		format: BitmapFormat,
+		data: Vec<u8>,
+		layout: &ImageLayout,
+	) -> Result<Self, ImageError> {
+		let scanlines = Scanlines::new(width, depth, format, layout)?;
+		let expected = Self::data_len(height, depth, format, &scanlines);
+
+		if data.len() != expected {
+			return Err(ImageError::WrongLength {
+				expected,
+				found: data.len(),
+			});
+		}
+
+		Ok(Self {
+			width,
+			height,
+			depth,
+			format,
+			data,
+
+			scanlines,
+		})
+	}
+
+	/// Creates a new [`Image`] from the image returned in the given `reply` to
+	/// the given `request`.
+	///
+	/// [`XyPixmap`] images must have been requested with a `plane_mask` which
+	/// includes every plane: only the planes in the `plane_mask` are returned.
+	///
+	/// # Errors
+	/// Returns an error for the same reasons as [`Image::from_data`].
+	///
+	/// [`XyPixmap`]: Format::XyPixmap
+	pub fn from_reply(
+		request: &GetImage,
+		reply: &GetImageReply,
+		layout: &ImageLayout,
+	) -> Result<Self, ImageError> {
+		Self::from_data(
+			request.width,
+			request.height,
+			reply.depth,
+			BitmapFormat::Specific(request.format),
+			reply.data.clone(),
+			layout,
+		)
+	}
+
+	/// Creates a new [`ZPixmap`] image from RGBA data, with 4 bytes for each
+	/// pixel, starting with the top-left pixel.
+	///
+	/// The channels are converted to pixels with the given `masks`.
+	///
+	/// # Errors
+	/// Returns [`ImageError::WrongLength`] if the `rgba` data does not contain
+	/// `width * height` pixels, or an error for the same reasons as
+	/// [`Image::new`].
+	///
+	/// [`ZPixmap`]: Format::Zpixmap
+	pub fn from_rgba(
+		width: u16,
+		height: u16,
+		depth: u8,
+		masks: ColorMasks,
+		rgba: &[u8],
+		layout: &ImageLayout,
+	) -> Result<Self, ImageError> {
+		let mut image = Self::new(
+			width,
+			height,
+			depth,
+			BitmapFormat::Specific(Format::Zpixmap),
+			layout,
+		)?;
+
+		let expected = usize::from(width) * usize::from(height) * 4;
+		if rgba.len() != expected {
+			return Err(ImageError::WrongLength {
+				expected,
+				found: rgba.len(),
+			});
+		}
+
+		for (index, channels) in rgba.chunks_exact(4).enumerate() {
+			let (x, y) = image.coordinates(index);
+			let channels = [channels[0], channels[1], channels[2], channels[3]];
+
+			image.set_pixel(x, y, masks.pixel(channels));
+		}
+
+		Ok(image)
+	}
+
+	/// Converts this image to RGBA data, with 4 bytes for each pixel, starting
+	/// with the top-left pixel.
+	///
+	/// The pixels are converted to channels with the given `masks`.
+	#[must_use]
+	pub fn to_rgba(&self, masks: ColorMasks) -> Vec<u8> {
+		let len = usize::from(self.width) * usize::from(self.height);
+
+		(0..len)
+			.flat_map(|index| {
+				let (x, y) = self.coordinates(index);
+
+				masks.channels(self.pixel(x, y))
+			})
+			.collect()
+	}
+
+	/// Creates the [`PutImage`] request which draws this image on the given
+	/// `drawable`, which may be a [`Window`] or a [`Pixmap`], with its top-left
+	/// corner at the given coordinates.
+	///
+	/// An image may be too large to send in a single request; it can be split
+	/// into several images with fewer scanlines each.
+	///
+	/// [`Window`]: crate::x11::Window
+	/// [`Pixmap`]: crate::x11::Pixmap
+	#[must_use]
+	pub fn put(
+		&self,
+		drawable: impl Into<Drawable>,
+		context: GraphicsContext,
+		dest_x: i16,
+		dest_y: i16,
+	) -> PutImage<'_> {
+		PutImage {
+			format: self.format,
+			drawable: drawable.into(),
+			context,
+			width: self.width,
+			height: self.height,
+			dest_x,
+			dest_y,
+			left_padding: 0,
+			depth: self.depth,
+			data: &self.data,
+		}
+	}
+
+	/// The width of this image in pixels.
+	#[must_use]
+	pub const fn width(&self) -> u16 {
+		self.width
+	}
+
+	/// The height of this image in pixels.
+	#[must_use]
+	pub const fn height(&self) -> u16 {
+		self.height
+	}
+
+	/// The number of bits in each pixel's value.
+	#[must_use]
+	pub const fn depth(&self) -> u8 {
+		self.depth
+	}
+
+	/// The format in which this image's data is laid out.
+	#[must_use]
+	pub const fn format(&self) -> BitmapFormat {
+		self.format
+	}
+
+	/// This image's data, as it is sent to the X server.
+	#[must_use]
+	pub fn data(&self) -> &[u8] {
+		&self.data
+	}
+
+	/// Returns this image's data, as it is sent to the X server.
+	#[must_use]
+	pub fn into_data(self) -> Vec<u8> {
+		self.data
+	}
+
+	/// Returns the value of the pixel at the given coordinates.
+	///
+	/// # Panics
+	/// Panics if the coordinates are outside of this image.
+	#[must_use]
+	pub fn pixel(&self, x: u16, y: u16) -> u32 {
+		self.check_bounds(x, y);
+
+		let (x, y) = (usize::from(x), usize::from(y));
+
+		match self.format {
+			BitmapFormat::Specific(Format::Zpixmap) => self.z_pixel(x, y),
+
+			_ => (0..self.planes()).fold(0, |pixel, plane| {
+				(pixel << 1) | u32::from(self.xy_bit(plane, x, y))
+			}),
+		}
+	}
+
+	/// Sets the value of the pixel at the given coordinates.
+	///
+	/// Bits of the `pixel` beyond this image's depth are ignored.
+	///
+	/// # Panics
+	/// Panics if the coordinates are outside of this image.
+	pub fn set_pixel(&mut self, x: u16, y: u16, pixel: u32) {
+		self.check_bounds(x, y);
+
+		let (x, y) = (usize::from(x), usize::from(y));
+		let pixel = pixel & depth_mask(self.depth);
+
+		if self.format == BitmapFormat::Specific(Format::Zpixmap) {
+			return self.set_z_pixel(x, y, pixel);
+		}
+
+		let planes = self.planes();
+
+		// Planes are written starting with the most significant.
+		for plane in 0..planes {
+			let bit = (pixel >> (planes - 1 - plane)) & 1 != 0;
+			self.set_xy_bit(plane, x, y, bit);
+		}
+	}
+
+	/// The number of bytes of data required for an image.
+	fn data_len(height: u16, depth: u8, format: BitmapFormat, scanlines: &Scanlines) -> usize {
+		let planes = match format {
+			BitmapFormat::Specific(Format::XyPixmap) => usize::from(depth),
+			_ => 1,
+		};
+
+		scanlines.len * usize::from(height) * planes
+	}
+
+	/// The number of bitmaps this image is written as.
+	const fn planes(&self) -> usize {
+		match self.format {
+			BitmapFormat::Specific(Format::XyPixmap) => self.depth as usize,
+			_ => 1,
+		}
+	}
+
+	/// The coordinates of the pixel at the given `index`, counting from the
+	/// top-left pixel along each row.
+	#[allow(clippy::cast_possible_truncation)]
+	fn coordinates(&self, index: usize) -> (u16, u16) {
+		let width = usize::from(self.width);
+
+		// Neither coordinate can exceed the image's dimensions.
+		((index % width) as u16, (index / width) as u16)
+	}
+
+	fn check_bounds(&self, x: u16, y: u16) {
+		assert!(
+			x < self.width && y < self.height,
+			"({x}, {y}) is outside of the {}x{} image",
+			self.width,
+			self.height
+		);
+	}
+
+	/// Returns the byte and bit of the given pixel in the given bitmap.
+	fn xy_position(&self, plane: usize, x: usize, y: usize) -> (usize, u8) {
+		let Scanlines {
+			byte_order,
+			bit_order,
+			unit,
+			len,
+			..
+		} = self.scanlines;
+
+		let unit = usize::from(unit);
+		let unit_len = unit / 8;
+
+		// The bit of the unit's value which contains the pixel.
+		let bit = match bit_order {
+			BitOrder::LeastSignificantFirst => x % unit,
+			BitOrder::MostSignificantFirst => unit - 1 - x % unit,
+		};
+		// The byte of the unit which contains that bit, as it is written.
+		let byte = match byte_order {
+			ByteOrder::LeastSignificantFirst => bit / 8,
+			ByteOrder::MostSignificantFirst => unit_len - 1 - bit / 8,
+		};
+
+		let line = (plane * usize::from(self.height) + y) * len;
+
+		// `bit % 8` is less than `8`.
+		#[allow(clippy::cast_possible_truncation)]
+		(line + x / unit * unit_len + byte, (bit % 8) as u8)
+	}
+
+	fn xy_bit(&self, plane: usize, x: usize, y: usize) -> bool {
+		let (byte, bit) = self.xy_position(plane, x, y);
+
+		self.data[byte] & (1 << bit) != 0
+	}
+
+	fn set_xy_bit(&mut self, plane: usize, x: usize, y: usize, value: bool) {
+		let (byte, bit) = self.xy_position(plane, x, y);
+
+		if value {
+			self.data[byte] |= 1 << bit;
+		} else {
+			self.data[byte] &= !(1 << bit);
+		}
+	}
+
+	fn z_pixel(&self, x: usize, y: usize) -> u32 {
+		let line = y * self.scanlines.len;
+
+		match self.scanlines.bits_per_pixel {
+			1 => u32::from(self.xy_bit(0, x, y)),
+
+			4 => {
+				let byte = self.data[line + x / 2];
+
+				// The nibbles of each byte are ordered by the image byte order.
+				u32::from(match (self.scanlines.byte_order, x % 2) {
+					(ByteOrder::MostSignificantFirst, 0)
+					| (ByteOrder::LeastSignificantFirst, 1) => byte >> 4,
+					_ => byte & 0x0f,
+				})
+			}
+
+			bits_per_pixel => {
+				let len = usize::from(bits_per_pixel / 8);
+				let bytes = &self.data[line + x * len..][..len];
+
+				match self.scanlines.byte_order {
+					ByteOrder::MostSignificantFirst => bytes
+						.iter()
+						.fold(0, |pixel, &byte| (pixel << 8) | u32::from(byte)),
+					ByteOrder::LeastSignificantFirst => bytes
+						.iter()
+						.rev()
+						.fold(0, |pixel, &byte| (pixel << 8) | u32::from(byte)),
+				}
+			}
+		}
+	}
+
+	// The pixel is masked to the image's depth, which fits in its bits per
+	// pixel, so truncating it to each byte or nibble loses nothing.
+	#[allow(clippy::cast_possible_truncation)]
+	fn set_z_pixel(&mut self, x: usize, y: usize, pixel: u32) {
+		let line = y * self.scanlines.len;
+
+		match self.scanlines.bits_per_pixel {
+			1 => self.set_xy_bit(0, x, y, pixel != 0),
+
+			4 => {
+				let byte = &mut self.data[line + x / 2];
+
+				match (self.scanlines.byte_order, x % 2) {
+					(ByteOrder::MostSignificantFirst, 0)
+					| (ByteOrder::LeastSignificantFirst, 1) => {
+						*byte = (*byte & 0x0f) | ((pixel as u8) << 4);
+					}
+					_ => *byte = (*byte & 0xf0) | (pixel as u8 & 0x0f),
+				}
+			}
+
+			bits_per_pixel => {
+				let len = usize::from(bits_per_pixel / 8);
+				let bytes = &mut self.data[line + x * len..][..len];
+
+				for (index, byte) in bytes.iter_mut().enumerate() {
+					let shift = match self.scanlines.byte_order {
+						ByteOrder::MostSignificantFirst => (len - 1 - index) * 8,
+						ByteOrder::LeastSignificantFirst => index * 8,
+					};
+
+					*byte = (pixel >> shift) as u8;
+				}
+			}
+		}
+	}
+}
+
+/// The bits of a pixel used by an image of the given `depth`.
+const fn depth_mask(depth: u8) -> u32 {
+	match depth {
+		0 => 0,
+		1..=31 => (1 << depth) - 1,
+		_ => u32::MAX,
+	}
+}
+
+impl Scanlines {
+	fn new(
+		width: u16,
+		depth: u8,
+		format: BitmapFormat,
+		layout: &ImageLayout,
+	) -> Result<Self, ImageError> {
+		let (bits_per_pixel, pad) = match format {
+			BitmapFormat::Specific(Format::Zpixmap) => {
+				let pixmap_format = layout
+					.pixmap_format(depth)
+					.ok_or(ImageError::UnsupportedDepth(depth))?;
+
+				if !matches!(pixmap_format.bits_per_pixel, 1 | 4 | 8 | 16 | 24 | 32)
+					|| pixmap_format.bits_per_pixel < depth
+				{
+					return Err(ImageError::InvalidLayout);
+				}
+
+				(pixmap_format.bits_per_pixel, pixmap_format.scanline_pad)
+			}
+
+			BitmapFormat::Bitmap if depth != 1 => return Err(ImageError::UnsupportedDepth(depth)),
+
+			_ => (1, layout.bitmap_scanline_pad),
+		};
+
+		let unit = layout.bitmap_scanline_unit;
+
+		if !matches!(unit, 8 | 16 | 32) || !matches!(pad, 8 | 16 | 32) || pad < unit {
+			return Err(ImageError::InvalidLayout);
+		}
+
+		let bits = usize::from(width) * usize::from(bits_per_pixel);
+		let pad = usize::from(pad);
+
+		Ok(Self {
+			byte_order: layout.image_byte_order,
+			bit_order: layout.bitmap_bit_order,
+			unit,
+			bits_per_pixel,
+			len: bits.div_ceil(pad) * pad / 8,
+		})
+	}
+}
+
+impl fmt::Display for ImageError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnsupportedDepth(depth) => {
+				write!(f, "images with a depth of {depth} are not supported")
+			}
+
+			Self::InvalidLayout => write!(
+				f,
+				"the image layout uses a number of bits not allowed by the X protocol"
+			),
+
+			Self::WrongLength { expected, found } => write!(
+				f,
+				"the image requires {expected} bytes of data, but {found} were given"
+			),
+		}
+	}
+}
+
+impl Error for ImageError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn layout(byte_order: ByteOrder, bit_order: BitOrder) -> ImageLayout {
+		ImageLayout {
+			image_byte_order: byte_order,
+			bitmap_bit_order: bit_order,
+			bitmap_scanline_unit: 32,
+			bitmap_scanline_pad: 32,
+			pixmap_formats: vec![
+				PixmapFormat {
+					depth: 1,
+					bits_per_pixel: 1,
+					scanline_pad: 32,
+				},
+				PixmapFormat {
+					depth: 24,
+					bits_per_pixel: 32,
+					scanline_pad: 32,
+				},
+			],
+		}
+	}
+
+	#[test]
+	fn rgba_round_trips_through_z_pixmaps() {
+		let layout = layout(
+			ByteOrder::LeastSignificantFirst,
+			BitOrder::LeastSignificantFirst,
+		);
+		let masks = ColorMasks::new(0x00ff_0000, 0x0000_ff00, 0x0000_00ff);
+
+		let rgba = [0x11, 0x22, 0x33, 0xff, 0xaa, 0xbb, 0xcc, 0xff];
+		let image = Image::from_rgba(1, 2, 24, masks, &rgba, &layout).unwrap();
+
+		assert_eq!(
+			image.data(),
+			[0x33, 0x22, 0x11, 0x00, 0xcc, 0xbb, 0xaa, 0x00]
+		);
+		assert_eq!(image.pixel(0, 1), 0x00aa_bbcc);
+		assert_eq!(image.to_rgba(masks), rgba);
+
+		assert_eq!(
+			Image::from_rgba(2, 2, 24, masks, &rgba, &layout),
+			Err(ImageError::WrongLength {
+				expected: 16,
+				found: 8,
+			})
+		);
+	}
+
+	#[test]
+	fn bitmaps_follow_the_bit_and_byte_order() {
+		let layout = layout(
+			ByteOrder::MostSignificantFirst,
+			BitOrder::MostSignificantFirst,
+		);
+
+		let mut image = Image::new(9, 2, 1, BitmapFormat::Bitmap, &layout).unwrap();
+		image.set_pixel(0, 0, 1);
+		image.set_pixel(8, 1, 1);
+
+		// Each scanline is padded to 32 bits.
+		assert_eq!(image.data(), [0x80, 0, 0, 0, 0, 0x80, 0, 0]);
+		assert_eq!(image.pixel(8, 1), 1);
+		assert_eq!(image.pixel(1, 0), 0);
+
+		let layout = ImageLayout {
+			bitmap_bit_order: BitOrder::LeastSignificantFirst,
+			image_byte_order: ByteOrder::LeastSignificantFirst,
+			..layout
+		};
+
+		let mut image = Image::new(9, 1, 1, BitmapFormat::Bitmap, &layout).unwrap();
+		image.set_pixel(8, 0, 1);
+
+		assert_eq!(image.data(), [0, 0x01, 0, 0]);
+	}
+}
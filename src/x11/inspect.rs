@@ -0,0 +1,500 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{
+	collections::{HashMap, VecDeque},
+	error::Error,
+	fmt,
+};
+
+use cornflakes::{ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	byte_order::ByteOrder,
+	framing::{self, ServerMessageKind},
+};
+
+/// The code of `KeymapNotify` events, which have no sequence number.
+const KEYMAP_NOTIFY: u8 = 11;
+
+/// Tracks the sequence numbers of requests forwarded by a proxy between a
+/// client and the X server, alongside those of the requests it [injects] of
+/// its own.
+///
+/// The X server numbers every request it receives on a connection, so each
+/// request a proxy injects shifts the sequence numbers of the client's
+/// requests which follow it. The X server's messages must then have their
+/// sequence numbers [translated] before they are forwarded to the client,
+/// and the replies and errors generated by injected requests must not be
+/// forwarded at all.
+///
+/// # Examples
+/// ```ignore
+/// let mut sequences = SequenceMap::new();
+///
+/// // For each request from the client...
+/// sequences.forward_request();
+///
+/// // For each request the proxy sends of its own...
+/// let sequence = sequences.inject_request();
+///
+/// // For each message from the X server...
+/// let server_sequence = inspect::sequence(&message, byte_order).unwrap();
+///
+/// if is_reply_or_error && sequences.is_injected(server_sequence) {
+///     handle_own_reply(&message);
+/// } else {
+///     let sequence = sequences.to_client(server_sequence);
+///     inspect::set_sequence(&mut message, sequence, byte_order);
+///
+///     client.write_all(&message)?;
+/// }
+/// ```
+///
+/// [injects]: SequenceMap::inject_request
+/// [translated]: SequenceMap::to_client
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SequenceMap {
+	/// The sequence number of the last request sent to the X server.
+	server: u16,
+	/// The number of injected requests which are no longer in `injected`.
+	offset: u16,
+	/// The sequence numbers of injected requests, in the order they were
+	/// sent, from which messages may still be received.
+	injected: VecDeque<u16>,
+}
+
+impl SequenceMap {
+	/// Creates a new [`SequenceMap`] for a connection on which no requests
+	/// have been sent.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			server: 0,
+			offset: 0,
+			injected: VecDeque::new(),
+		}
+	}
+
+	/// Records a request from the client which is forwarded to the X server,
+	/// returning the sequence number the X server assigns it.
+	pub fn forward_request(&mut self) -> u16 {
+		self.server = self.server.wrapping_add(1);
+		self.server
+	}
+
+	/// Records a request sent by the proxy itself, returning the sequence
+	/// number the X server assigns it.
+	pub fn inject_request(&mut self) -> u16 {
+		self.server = self.server.wrapping_add(1);
+		self.injected.push_back(self.server);
+
+		self.server
+	}
+
+	/// Returns whether the request with the given `server_sequence` was
+	/// injected by the proxy, in which case its replies and errors must not
+	/// be forwarded to the client.
+	#[must_use]
+	pub fn is_injected(&self, server_sequence: u16) -> bool {
+		self.injected.contains(&server_sequence)
+	}
+
+	/// Translates the given sequence number, from a message sent by the X
+	/// server, to the sequence number the client knows the request by.
+	///
+	/// An event's sequence number is that of the last request the X server
+	/// processed; if that request was injected, the event is given the
+	/// sequence number of the client's last request before it.
+	///
+	/// Messages from the X server are received in the order of their
+	/// sequence numbers, so translating a sequence number forgets any
+	/// injected requests before it.
+	pub fn to_client(&mut self, server_sequence: u16) -> u16 {
+		// How long ago the request was sent, which copes with sequence
+		// numbers wrapping around.
+		let server = self.server;
+		let age = |sequence: u16| server.wrapping_sub(sequence);
+
+		// Injected requests sent before this one can't be referred to again.
+		while let Some(&injected) = self.injected.front() {
+			if age(injected) <= age(server_sequence) {
+				break;
+			}
+
+			self.injected.pop_front();
+			self.offset = self.offset.wrapping_add(1);
+		}
+
+		// If this request was injected itself, the client's last request
+		// before it is used.
+		let injected = u16::from(self.injected.front() == Some(&server_sequence));
+
+		server_sequence
+			.wrapping_sub(self.offset)
+			.wrapping_sub(injected)
+	}
+}
+
+/// Returns the sequence number of the given `message` sent by the X server,
+/// read in the given `byte_order`.
+///
+/// Returns [`None`] if the `message` is too short, or is a `KeymapNotify`
+/// event, which has no sequence number.
+#[must_use]
+pub fn sequence(message: &[u8], byte_order: ByteOrder) -> Option<u16> {
+	let &[first, _, a, b, ..] = message else {
+		return None;
+	};
+
+	if has_no_sequence(first) {
+		return None;
+	}
+
+	Some(match byte_order {
+		ByteOrder::MostSignificantFirst => u16::from_be_bytes([a, b]),
+		ByteOrder::LeastSignificantFirst => u16::from_le_bytes([a, b]),
+	})
+}
+
+/// Rewrites the sequence number of the given `message` sent by the X server
+/// in the given `byte_order`.
+///
+/// Returns `false`, leaving the `message` unchanged, if it is too short, or
+/// is a `KeymapNotify` event, which has no sequence number.
+pub fn set_sequence(message: &mut [u8], sequence: u16, byte_order: ByteOrder) -> bool {
+	if message.len() < 4 || has_no_sequence(message[0]) {
+		return false;
+	}
+
+	message[2..4].copy_from_slice(&match byte_order {
+		ByteOrder::MostSignificantFirst => sequence.to_be_bytes(),
+		ByteOrder::LeastSignificantFirst => sequence.to_le_bytes(),
+	});
+
+	true
+}
+
+/// Returns whether the message starting with the given `first_byte` is a
+/// `KeymapNotify` event, which has no sequence number.
+const fn has_no_sequence(first_byte: u8) -> bool {
+	matches!(
+		framing::classify(first_byte),
+		ServerMessageKind::Event {
+			code: KEYMAP_NOTIFY,
+			..
+		}
+	)
+}
+
+/// Reads a `T` from the given `bytes` in the given `byte_order`, then writes
+/// it again, checking that it is written exactly as it was read.
+///
+/// This lets a proxy inspect and forward a message in one step, and finds
+/// asymmetries between the way a type is read and written.
+///
+/// # Errors
+/// Returns a [`ReemitError`] if the `bytes` could not be read as a `T`, if
+/// the `T` could not be written, or if it was written differently.
+pub fn reemit<T>(bytes: &[u8], byte_order: ByteOrder) -> Result<(T, Vec<u8>), ReemitError>
+where
+	T: Readable + Writable,
+{
+	let mut reader = byte_order.reader(bytes);
+	let value = T::read_from(&mut reader).map_err(ReemitError::Read)?;
+	let read = bytes.len() - reader.into_inner().len();
+
+	let mut written = vec![];
+	value
+		.write_to(&mut byte_order.writer(&mut written))
+		.map_err(ReemitError::Write)?;
+
+	if written != bytes[..read] {
+		let offset = written
+			.iter()
+			.zip(&bytes[..read])
+			.position(|(written, read)| written != read)
+			.unwrap_or(written.len().min(read));
+
+		return Err(ReemitError::Mismatch {
+			offset,
+			read,
+			written: written.len(),
+		});
+	}
+
+	Ok((value, written))
+}
+
+/// An error encountered when a message is [reemitted].
+///
+/// [reemitted]: reemit
+#[derive(Debug)]
+pub enum ReemitError {
+	/// The message could not be read.
+	Read(ReadError),
+	/// The message was read, but could not be written again.
+	Write(WriteError),
+
+	/// The message was written differently from how it was read.
+	Mismatch {
+		/// The offset of the first byte which differs.
+		offset: usize,
+		/// The number of bytes which were read.
+		read: usize,
+		/// The number of bytes which were written.
+		written: usize,
+	},
+}
+
+impl fmt::Display for ReemitError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Read(ReadError::Other(error)) => write!(f, "failed to read message: {error}"),
+			Self::Read(error) => write!(f, "failed to read message: {error:?}"),
+
+			Self::Write(WriteError::Other(error)) => {
+				write!(f, "failed to write message: {error}")
+			}
+			Self::Write(error) => write!(f, "failed to write message: {error:?}"),
+
+			Self::Mismatch {
+				offset,
+				read,
+				written,
+			} => write!(
+				f,
+				"message was written differently from how it was read: {read} bytes were read and \
+				 {written} bytes were written, differing from byte {offset}"
+			),
+		}
+	}
+}
+
+impl Error for ReemitError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Read(ReadError::Other(error)) | Self::Write(WriteError::Other(error)) => {
+				Some(error.as_ref())
+			}
+
+			_ => None,
+		}
+	}
+}
+
+/// Translates resource IDs from one connection to another, such as when a
+/// proxy forwards a client to a different X server.
+///
+/// Each connection's client is given its own range of resource IDs in the
+/// connection setup reply. IDs in the first connection's range are
+/// translated to the ID at the same position in the second connection's
+/// range. Other IDs, such as root windows and the resources of other
+/// clients, are translated as they were [inserted], or otherwise left
+/// unchanged.
+///
+/// # Examples
+/// ```ignore
+/// let mut xids = XidTranslator::new(
+///     (client_setup.resource_id_base, client_setup.resource_id_mask),
+///     (server_setup.resource_id_base, server_setup.resource_id_mask),
+/// );
+/// xids.insert(client_setup.roots[0].root.res_id(), server_setup.roots[0].root.res_id());
+///
+/// request.target = Window::new(xids.translate(request.target.res_id()));
+/// ```
+///
+/// [inserted]: XidTranslator::insert
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct XidTranslator {
+	/// The `resource_id_base` and `resource_id_mask` of each connection.
+	from: (u32, u32),
+	to: (u32, u32),
+
+	/// IDs translated from the first connection to the second.
+	forward: HashMap<u32, u32>,
+	/// IDs translated from the second connection to the first.
+	back: HashMap<u32, u32>,
+}
+
+impl XidTranslator {
+	/// Creates a new [`XidTranslator`] from the `resource_id_base` and
+	/// `resource_id_mask` given to each connection in its setup reply.
+	#[must_use]
+	pub fn new(from: (u32, u32), to: (u32, u32)) -> Self {
+		Self {
+			from,
+			to,
+
+			forward: HashMap::new(),
+			back: HashMap::new(),
+		}
+	}
+
+	/// Translates the given `from` ID to the given `to` ID, and back.
+	///
+	/// This is for IDs outside the clients' ranges which differ between the
+	/// connections, such as root windows.
+	pub fn insert(&mut self, from: u32, to: u32) {
+		self.forward.insert(from, to);
+		self.back.insert(to, from);
+	}
+
+	/// Translates the given `id` from the first connection to the second.
+	#[must_use]
+	pub fn translate(&self, id: u32) -> u32 {
+		self.forward
+			.get(&id)
+			.copied()
+			.or_else(|| translate_range(id, self.from, self.to))
+			.unwrap_or(id)
+	}
+
+	/// Translates the given `id` from the second connection back to the
+	/// first.
+	#[must_use]
+	pub fn translate_back(&self, id: u32) -> u32 {
+		self.back
+			.get(&id)
+			.copied()
+			.or_else(|| translate_range(id, self.to, self.from))
+			.unwrap_or(id)
+	}
+}
+
+/// Translates the given `id` in the `from` range of IDs to the ID at the
+/// same position in the `to` range, if it is in the `from` range and the
+/// `to` range is large enough.
+const fn translate_range(id: u32, from: (u32, u32), to: (u32, u32)) -> Option<u32> {
+	let ((from_base, from_mask), (to_base, to_mask)) = (from, to);
+
+	if from_mask == 0 || to_mask == 0 || id & !from_mask != from_base {
+		return None;
+	}
+
+	// IDs are multiples of the lowest bit set in the mask.
+	let index = (id & from_mask) / (from_mask & from_mask.wrapping_neg());
+
+	match index.checked_mul(to_mask & to_mask.wrapping_neg()) {
+		Some(offset) if offset <= to_mask => Some(to_base | offset),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn injected_requests_are_hidden_from_the_client() {
+		let mut sequences = SequenceMap::new();
+
+		assert_eq!(sequences.forward_request(), 1);
+		assert_eq!(sequences.inject_request(), 2);
+		assert_eq!(sequences.forward_request(), 3);
+		assert_eq!(sequences.inject_request(), 4);
+
+		assert!(!sequences.is_injected(1));
+		assert_eq!(sequences.to_client(1), 1);
+
+		// An event after the injected request is given the client's last
+		// sequence number.
+		assert!(sequences.is_injected(2));
+		assert_eq!(sequences.to_client(2), 1);
+
+		assert_eq!(sequences.to_client(3), 2);
+		assert_eq!(sequences.to_client(4), 2);
+
+		assert_eq!(sequences.forward_request(), 5);
+		assert_eq!(sequences.to_client(5), 3);
+		assert!(!sequences.is_injected(2));
+	}
+
+	#[test]
+	fn sequences_wrap_around() {
+		let mut sequences = SequenceMap::new();
+
+		for _ in 0..u16::MAX {
+			sequences.forward_request();
+		}
+		assert_eq!(sequences.inject_request(), 0);
+		assert_eq!(sequences.forward_request(), 1);
+
+		assert_eq!(sequences.to_client(u16::MAX), u16::MAX);
+		assert_eq!(sequences.to_client(1), 0);
+	}
+
+	#[test]
+	fn sequences_are_rewritten_in_the_byte_order() {
+		let mut reply = [0; 32];
+		reply[0] = framing::REPLY;
+		reply[2..4].copy_from_slice(&0x1234_u16.to_le_bytes());
+
+		let byte_order = ByteOrder::LeastSignificantFirst;
+
+		assert_eq!(sequence(&reply, byte_order), Some(0x1234));
+		assert!(set_sequence(&mut reply, 0x0102, byte_order));
+		assert_eq!(reply[2..4], [0x02, 0x01]);
+
+		let mut keymap_notify = [KEYMAP_NOTIFY; 32];
+		assert_eq!(sequence(&keymap_notify, byte_order), None);
+		assert!(!set_sequence(&mut keymap_notify, 1, byte_order));
+		assert_eq!(keymap_notify, [KEYMAP_NOTIFY; 32]);
+	}
+
+	/// A byte which is always written as `0`, whatever was read.
+	struct Lossy;
+
+	impl Readable for Lossy {
+		fn read_from(reader: &mut impl bytes::Buf) -> Result<Self, ReadError> {
+			reader.get_u8();
+			Ok(Self)
+		}
+	}
+
+	impl Writable for Lossy {
+		fn write_to(&self, writer: &mut impl bytes::BufMut) -> Result<(), WriteError> {
+			writer.put_u8(0);
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn messages_are_reemitted_unchanged() {
+		let byte_order = ByteOrder::LeastSignificantFirst;
+
+		let (value, written) = reemit::<u32>(&[1, 2, 3, 4, 5], byte_order).unwrap();
+		assert_eq!(value, 0x0403_0201);
+		assert_eq!(written, [1, 2, 3, 4]);
+
+		assert!(reemit::<Lossy>(&[0], byte_order).is_ok());
+		assert!(matches!(
+			reemit::<Lossy>(&[7], byte_order),
+			Err(ReemitError::Mismatch {
+				offset: 0,
+				read: 1,
+				written: 1,
+			})
+		));
+	}
+
+	#[test]
+	fn ids_are_translated_between_ranges() {
+		let mut xids = XidTranslator::new((0x0040_0000, 0x001f_ffff), (0x0120_0000, 0x001f_ffff));
+		xids.insert(0x0000_01e9, 0x0000_0260);
+
+		assert_eq!(xids.translate(0x0040_0005), 0x0120_0005);
+		assert_eq!(xids.translate_back(0x0120_0005), 0x0040_0005);
+
+		assert_eq!(xids.translate(0x0000_01e9), 0x0000_0260);
+		assert_eq!(xids.translate_back(0x0000_0260), 0x0000_01e9);
+
+		// Other clients' IDs are unchanged.
+		assert_eq!(xids.translate(0x0060_0001), 0x0060_0001);
+
+		// The second range is too small for the ID.
+		let xids = XidTranslator::new((0x0040_0000, 0x001f_ffff), (0x0120_0000, 0x0000_00ff));
+		assert_eq!(xids.translate(0x0040_0100), 0x0040_0100);
+	}
+}
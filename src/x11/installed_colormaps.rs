@@ -0,0 +1,200 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::x11::{events::ColormapNotify, Colormap, ColormapState, Window};
+
+/// Tracks which colormaps are installed on each screen.
+///
+/// Each screen has a fixed number of hardware colormaps, and windows whose
+/// colormap is not installed are displayed with the wrong colors. The ICCCM
+/// leaves installing colormaps to the window manager, which must know which
+/// are installed to decide which to install or uninstall next.
+///
+/// The tracker starts from the colormaps listed in a
+/// [`ListInstalledColormaps`] reply for each screen, then follows
+/// [`ColormapNotify`] events, which are only sent for windows with
+/// `ColormapChange` selected. Replies and events must be given in the order
+/// they are received, so that each event is applied after the reply which it
+/// follows.
+///
+/// Screens are identified by their root windows.
+///
+/// # Examples
+/// ```ignore
+/// let mut tracker = ColormapTracker::new();
+///
+/// let reply = connection.send_and_receive(ListInstalledColormaps {
+///     target_window: screen.root,
+/// })?;
+/// tracker.installed_listed(screen.root, &reply.colormaps);
+///
+/// // Later, for a `ColormapNotify` event on a window on that screen...
+/// if let Some(change) = tracker.colormap_notify(screen.root, &event) {
+///     println!("{:?} is now {:?}", change.colormap, change.state);
+/// }
+/// ```
+///
+/// [`ListInstalledColormaps`]: crate::x11::requests::ListInstalledColormaps
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ColormapTracker {
+	/// The colormaps installed on each screen, by the screen's root window.
+	screens: HashMap<Window, HashSet<Colormap>>,
+}
+
+/// A colormap being installed or uninstalled, as returned by
+/// [`ColormapTracker::colormap_notify`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ColormapChange {
+	/// The root window of the screen on which the `colormap` was installed
+	/// or uninstalled.
+	pub root: Window,
+	/// The colormap which was installed or uninstalled.
+	pub colormap: Colormap,
+	/// Whether the `colormap` is now installed.
+	pub state: ColormapState,
+}
+
+impl ColormapTracker {
+	/// Creates a new [`ColormapTracker`] which is not tracking any screens.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The colormaps installed on the screen with the given `root` window, in
+	/// no particular order.
+	///
+	/// No colormaps are installed on screens which are not tracked.
+	pub fn installed(&self, root: Window) -> impl Iterator<Item = Colormap> + '_ {
+		self.screens
+			.get(&root)
+			.into_iter()
+			.flat_map(|installed| installed.iter().copied())
+	}
+
+	/// Returns whether the given `colormap` is installed on the screen with
+	/// the given `root` window.
+	#[must_use]
+	pub fn is_installed(&self, root: Window, colormap: Colormap) -> bool {
+		self.screens
+			.get(&root)
+			.is_some_and(|installed| installed.contains(&colormap))
+	}
+
+	/// Replaces the colormaps installed on the screen with the given `root`
+	/// window with the `colormaps` of a [`ListInstalledColormaps`] reply.
+	///
+	/// The screen is tracked from now on if it wasn't already.
+	///
+	/// [`ListInstalledColormaps`]: crate::x11::requests::ListInstalledColormaps
+	pub fn installed_listed(&mut self, root: Window, colormaps: &[Colormap]) {
+		self.screens
+			.insert(root, colormaps.iter().copied().collect());
+	}
+
+	/// Stops tracking the screen with the given `root` window.
+	pub fn forget(&mut self, root: Window) {
+		self.screens.remove(&root);
+	}
+
+	/// Handles the given [`ColormapNotify`] `event`, which was sent for a
+	/// window on the screen with the given `root` window, returning the
+	/// change, if the event's colormap was installed or uninstalled.
+	///
+	/// Events for screens which are not tracked are ignored, as are events
+	/// for windows whose colormap was freed. The same change is reported to
+	/// every window with that colormap, but is only returned once.
+	pub fn colormap_notify(
+		&mut self,
+		root: Window,
+		event: &ColormapNotify,
+	) -> Option<ColormapChange> {
+		let installed = self.screens.get_mut(&root)?;
+		let colormap = event.colormap?;
+
+		let changed = match event.state {
+			ColormapState::Installed => installed.insert(colormap),
+			ColormapState::Uninstalled => installed.remove(&colormap),
+		};
+
+		changed.then_some(ColormapChange {
+			root,
+			colormap,
+			state: event.state,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::ResId;
+
+	const ROOT: Window = Window::new(0x0000_0100);
+	const WINDOW: Window = Window::new(0x0040_0001);
+	const DEFAULT: Colormap = Colormap::new(0x0000_0020);
+	const CLIENT: Colormap = Colormap::new(0x0040_0002);
+
+	fn notify(colormap: Option<Colormap>, new: bool, state: ColormapState) -> ColormapNotify {
+		ColormapNotify {
+			_sequence_: 0,
+			window: WINDOW,
+			colormap,
+			new,
+			state,
+		}
+	}
+
+	#[test]
+	fn installed_colormaps_follow_colormap_notify() {
+		let mut tracker = ColormapTracker::new();
+
+		// Screens are only tracked once their colormaps have been listed.
+		assert_eq!(
+			tracker.colormap_notify(ROOT, &notify(Some(CLIENT), false, ColormapState::Installed)),
+			None
+		);
+
+		tracker.installed_listed(ROOT, &[DEFAULT]);
+		assert!(tracker.is_installed(ROOT, DEFAULT));
+
+		assert_eq!(
+			tracker.colormap_notify(ROOT, &notify(Some(CLIENT), true, ColormapState::Installed)),
+			Some(ColormapChange {
+				root: ROOT,
+				colormap: CLIENT,
+				state: ColormapState::Installed,
+			})
+		);
+		// Other windows with the same colormap are told of the same change.
+		assert_eq!(
+			tracker.colormap_notify(ROOT, &notify(Some(CLIENT), false, ColormapState::Installed)),
+			None
+		);
+
+		let mut installed: Vec<_> = tracker.installed(ROOT).collect();
+		installed.sort_by_key(ResId::res_id);
+		assert_eq!(installed, [DEFAULT, CLIENT]);
+
+		assert_eq!(
+			tracker.colormap_notify(
+				ROOT,
+				&notify(Some(DEFAULT), false, ColormapState::Uninstalled)
+			),
+			Some(ColormapChange {
+				root: ROOT,
+				colormap: DEFAULT,
+				state: ColormapState::Uninstalled,
+			})
+		);
+		assert_eq!(
+			tracker.colormap_notify(ROOT, &notify(None, true, ColormapState::Uninstalled)),
+			None
+		);
+
+		assert_eq!(tracker.installed(ROOT).collect::<Vec<_>>(), [CLIENT]);
+	}
+}
@@ -0,0 +1,412 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt, io};
+
+use bytes::{buf::UninitSlice, BufMut};
+use cornflakes::{Writable, WriteError};
+
+use crate::x11::byte_order::ByteOrder;
+
+/// The number of buffered bytes beyond which [`MessageWriter::send`] sends
+/// the buffered messages, unless another capacity is given.
+pub const DEFAULT_CAPACITY: usize = 8192;
+
+/// A writer which serializes messages into a buffer, then sends them to an
+/// [`io::Write`] or `AsyncWrite` sink, such as a socket or file.
+///
+/// The buffer is reused for every message, so messages can be sent without
+/// first writing each to its own `BytesMut` or `Vec<u8>`. A message which
+/// can't be written is removed from the buffer, so the sink never receives
+/// part of a message.
+///
+/// If the sink only accepts some of the buffered bytes, the rest stay in the
+/// buffer until the next flush: a flush which fails, such as with
+/// [`WouldBlock`], can be retried without losing or repeating any bytes.
+/// Buffered messages are not sent when the writer is dropped.
+///
+/// # Examples
+/// ```ignore
+/// let mut writer = MessageWriter::new(stream, byte_order);
+///
+/// writer.send(&MapWindow { target: window })?;
+/// writer.send(&GetInputFocus)?;
+///
+/// writer.flush()?;
+/// ```
+///
+/// [`WouldBlock`]: io::ErrorKind::WouldBlock
+#[derive(Debug)]
+pub struct MessageWriter<W> {
+	writer: W,
+	byte_order: ByteOrder,
+
+	buffer: Vec<u8>,
+	capacity: usize,
+}
+
+/// An error encountered when sending a message with a [`MessageWriter`].
+#[derive(Debug)]
+pub enum SendError {
+	/// The message could not be written, so it was not buffered.
+	Write(WriteError),
+	/// The sink failed while the buffered messages were being sent.
+	Io(io::Error),
+}
+
+impl fmt::Display for SendError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Write(WriteError::Other(error)) => {
+				write!(f, "failed to write message: {error}")
+			}
+			Self::Write(error) => write!(f, "failed to write message: {error:?}"),
+
+			Self::Io(error) => write!(f, "{error}"),
+		}
+	}
+}
+
+impl Error for SendError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Write(WriteError::Other(error)) => Some(error.as_ref()),
+			Self::Io(error) => Some(error),
+
+			Self::Write(_) => None,
+		}
+	}
+}
+
+impl From<io::Error> for SendError {
+	fn from(error: io::Error) -> Self {
+		Self::Io(error)
+	}
+}
+
+impl<W> MessageWriter<W> {
+	/// Creates a new [`MessageWriter`] which sends messages to the given
+	/// `writer` in the given `byte_order`, buffering up to
+	/// [`DEFAULT_CAPACITY`] bytes.
+	#[must_use]
+	pub const fn new(writer: W, byte_order: ByteOrder) -> Self {
+		Self::with_capacity(DEFAULT_CAPACITY, writer, byte_order)
+	}
+
+	/// Creates a new [`MessageWriter`] which buffers up to `capacity` bytes
+	/// before sending them to the given `writer`.
+	///
+	/// A `capacity` of `0` sends every message as soon as it is written.
+	#[must_use]
+	pub const fn with_capacity(capacity: usize, writer: W, byte_order: ByteOrder) -> Self {
+		Self {
+			writer,
+			byte_order,
+
+			buffer: Vec::new(),
+			capacity,
+		}
+	}
+
+	/// Writes the given `message` to the buffer, without sending it.
+	///
+	/// # Errors
+	/// Returns an error if the `message` could not be written, in which case
+	/// none of it is left in the buffer.
+	pub fn write(&mut self, message: &impl Writable) -> Result<(), WriteError> {
+		let len = self.buffer.len();
+
+		let result = message.write_to(&mut self.byte_order.writer(&mut self.buffer));
+
+		if result.is_err() {
+			self.buffer.truncate(len);
+		}
+
+		result
+	}
+
+	/// Returns whether the buffer has reached its capacity, so the buffered
+	/// messages should be sent.
+	const fn is_full(&self) -> bool {
+		self.buffer.len() >= self.capacity
+	}
+
+	/// The byte order that messages are written in.
+	#[must_use]
+	pub const fn byte_order(&self) -> ByteOrder {
+		self.byte_order
+	}
+
+	/// The bytes which have been written but not yet sent.
+	#[must_use]
+	pub fn buffer(&self) -> &[u8] {
+		&self.buffer
+	}
+
+	/// The sink that messages are sent to.
+	#[must_use]
+	pub const fn get_ref(&self) -> &W {
+		&self.writer
+	}
+
+	/// The sink that messages are sent to.
+	///
+	/// Writing to the sink directly may interleave its bytes with those of
+	/// buffered messages; flush the buffered messages first.
+	pub const fn get_mut(&mut self) -> &mut W {
+		&mut self.writer
+	}
+
+	/// Returns the sink and the bytes which have been written but not yet
+	/// sent.
+	#[must_use]
+	pub fn into_parts(self) -> (W, Vec<u8>) {
+		(self.writer, self.buffer)
+	}
+}
+
+/// Records the result of a single write of the buffered bytes after the
+/// first `written`.
+///
+/// # Errors
+/// Returns an error if sending should stop: interrupted writes are retried.
+fn record_write(result: io::Result<usize>, written: &mut usize) -> io::Result<()> {
+	match result {
+		Ok(0) => Err(io::Error::new(
+			io::ErrorKind::WriteZero,
+			"failed to send buffered messages",
+		)),
+		Ok(len) => {
+			*written += len;
+
+			Ok(())
+		}
+
+		Err(error) if error.kind() == io::ErrorKind::Interrupted => Ok(()),
+		Err(error) => Err(error),
+	}
+}
+
+/// Sends the `buffer` to the `writer`, then flushes it, recording the number
+/// of bytes sent in `written`.
+fn send_buffer(writer: &mut impl io::Write, buffer: &[u8], written: &mut usize) -> io::Result<()> {
+	while *written < buffer.len() {
+		record_write(writer.write(&buffer[*written..]), written)?;
+	}
+
+	writer.flush()
+}
+
+impl<W: io::Write> MessageWriter<W> {
+	/// Writes the given `message` to the buffer, then sends the buffered
+	/// messages if the buffer has reached its capacity.
+	///
+	/// # Errors
+	/// Returns [`SendError::Write`] if the `message` could not be written, or
+	/// [`SendError::Io`] if the sink failed.
+	pub fn send(&mut self, message: &impl Writable) -> Result<(), SendError> {
+		self.write(message).map_err(SendError::Write)?;
+
+		if self.is_full() {
+			self.flush()?;
+		}
+
+		Ok(())
+	}
+
+	/// Sends every buffered message, then flushes the sink.
+	///
+	/// # Errors
+	/// Returns an error if the sink failed, in which case the bytes which
+	/// were not sent are left in the buffer.
+	pub fn flush(&mut self) -> io::Result<()> {
+		let mut written = 0;
+
+		let result = send_buffer(&mut self.writer, &self.buffer, &mut written);
+
+		self.buffer.drain(..written);
+		result
+	}
+}
+
+#[cfg(feature = "async")]
+impl<W: futures::io::AsyncWrite + Unpin> MessageWriter<W> {
+	/// Writes the given `message` to the buffer, then sends the buffered
+	/// messages to the `futures` sink if the buffer has reached its
+	/// capacity.
+	///
+	/// # Errors
+	/// Returns [`SendError::Write`] if the `message` could not be written, or
+	/// [`SendError::Io`] if the sink failed.
+	pub async fn send_async(&mut self, message: &impl Writable) -> Result<(), SendError> {
+		self.write(message).map_err(SendError::Write)?;
+
+		if self.is_full() {
+			self.flush_async().await?;
+		}
+
+		Ok(())
+	}
+
+	/// Sends every buffered message to the `futures` sink, then flushes it.
+	///
+	/// # Errors
+	/// Returns an error if the sink failed, in which case the bytes which
+	/// were not sent are left in the buffer.
+	pub async fn flush_async(&mut self) -> io::Result<()> {
+		use futures::io::AsyncWriteExt;
+
+		let mut written = 0;
+
+		let result = async {
+			while written < self.buffer.len() {
+				let result = self.writer.write(&self.buffer[written..]).await;
+				record_write(result, &mut written)?;
+			}
+
+			self.writer.flush().await
+		}
+		.await;
+
+		self.buffer.drain(..written);
+		result
+	}
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin> MessageWriter<W> {
+	/// Writes the given `message` to the buffer, then sends the buffered
+	/// messages to the `tokio` sink if the buffer has reached its capacity.
+	///
+	/// # Errors
+	/// Returns [`SendError::Write`] if the `message` could not be written, or
+	/// [`SendError::Io`] if the sink failed.
+	pub async fn send_tokio(&mut self, message: &impl Writable) -> Result<(), SendError> {
+		self.write(message).map_err(SendError::Write)?;
+
+		if self.is_full() {
+			self.flush_tokio().await?;
+		}
+
+		Ok(())
+	}
+
+	/// Sends every buffered message to the `tokio` sink, then flushes it.
+	///
+	/// # Errors
+	/// Returns an error if the sink failed, in which case the bytes which
+	/// were not sent are left in the buffer.
+	pub async fn flush_tokio(&mut self) -> io::Result<()> {
+		use tokio::io::AsyncWriteExt;
+
+		let mut written = 0;
+
+		let result = async {
+			while written < self.buffer.len() {
+				let result = self.writer.write(&self.buffer[written..]).await;
+				record_write(result, &mut written)?;
+			}
+
+			self.writer.flush().await
+		}
+		.await;
+
+		self.buffer.drain(..written);
+		result
+	}
+}
+
+// SAFETY: every method is forwarded to the `Vec<u8>` buffer, which upholds the
+// requirements of `BufMut` itself.
+unsafe impl<W> BufMut for MessageWriter<W> {
+	fn remaining_mut(&self) -> usize {
+		self.buffer.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		// SAFETY: the caller upholds the same requirements for the buffer.
+		unsafe { self.buffer.advance_mut(cnt) }
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.buffer.chunk_mut()
+	}
+
+	fn put_slice(&mut self, src: &[u8]) {
+		self.buffer.put_slice(src);
+	}
+
+	fn put_bytes(&mut self, val: u8, cnt: usize) {
+		self.buffer.put_bytes(val, cnt);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A sink which accepts at most three bytes at a time, and is interrupted
+	/// or would block on every other write.
+	#[derive(Default)]
+	struct Trickle {
+		bytes: Vec<u8>,
+		writes: usize,
+		blocking: bool,
+	}
+
+	impl io::Write for Trickle {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			self.writes += 1;
+
+			if self.writes.is_multiple_of(2) {
+				return Err(if self.blocking {
+					io::ErrorKind::WouldBlock
+				} else {
+					io::ErrorKind::Interrupted
+				}
+				.into());
+			}
+
+			let len = buf.len().min(3);
+			self.bytes.extend_from_slice(&buf[..len]);
+
+			Ok(len)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn partial_writes_are_continued() {
+		let mut writer = MessageWriter::new(Trickle::default(), ByteOrder::LeastSignificantFirst);
+
+		writer.send(&0x1234_5678_u32).unwrap();
+		writer.send(&0x9abc_u16).unwrap();
+		assert_eq!(writer.buffer(), [0x78, 0x56, 0x34, 0x12, 0xbc, 0x9a]);
+		assert!(writer.get_ref().bytes.is_empty());
+
+		writer.flush().unwrap();
+		assert!(writer.buffer().is_empty());
+		assert_eq!(writer.get_ref().bytes, [0x78, 0x56, 0x34, 0x12, 0xbc, 0x9a]);
+
+		// A sink which would block keeps the bytes it didn't accept.
+		let mut writer = MessageWriter::with_capacity(
+			0,
+			Trickle {
+				blocking: true,
+				..Trickle::default()
+			},
+			ByteOrder::MostSignificantFirst,
+		);
+
+		let error = writer.send(&0x1234_5678_u32).unwrap_err();
+		assert!(matches!(error, SendError::Io(error) if error.kind() == io::ErrorKind::WouldBlock));
+		assert_eq!(writer.buffer(), [0x78]);
+
+		writer.flush().unwrap();
+		assert_eq!(writer.get_ref().bytes, [0x12, 0x34, 0x56, 0x78]);
+	}
+}
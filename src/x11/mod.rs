@@ -3,14 +3,72 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 /// Common types and data structures.
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../snapshot/x11/common/mod.rs"
+)]
 mod common;
 pub use common::*;
 
 pub mod traits;
 
+/// Everything needed to define messages with `define!`, in XRB and in other
+/// crates.
+///
+/// Extensions which XRB does not implement can be defined in their own
+/// crates: the requests, replies, and events they define implement XRB's
+/// [`Request`], [`Reply`], and [`Event`] traits just like XRB's own.
+///
+/// The code generated by `define!` refers to `bytes::...` and
+/// `cornflakes::...`, which a glob import of this module brings into scope,
+/// and to XRB's traits and helpers as `xrb::...`, which are re-exported from
+/// the root of XRB for that reason. A crate which renames its dependency on
+/// XRB can instead give each definition `#[xrbk(crate = "renamed::prelude")]`.
+///
+/// # Examples
+/// ```ignore
+/// use xrb::prelude::*;
+/// use xrb::x11::{extensions::Extension, Window};
+///
+/// /// The extension, whose codes are set in a connection's `Extensions` from
+/// /// its `QueryExtension` reply.
+/// pub const EXTENSION: Extension = Extension::new("FROBNICATE");
+///
+/// define! {
+///     pub struct Frobnicate: Request<{ EXTENSION.major_opcode() }, 0> {
+///         pub window: Window,
+///     }
+/// }
+/// ```
+pub mod prelude;
+
+/// Extensions to the core X11 protocol.
+///
+/// Each extension is assigned its own major opcode, event codes, and error
+/// codes by the X server; see [`Extensions`](extensions::Extensions).
+pub mod extensions;
+
+/// The byte order in which messages are sent on a connection.
+pub mod byte_order;
+
+/// Telling errors, replies, and events from the X server apart by their
+/// first byte.
+pub mod framing;
+
+/// Allocation of resource IDs for new resources.
+pub mod xid;
+
 /// Errors generated by the X server.
+#[cfg_attr(feature = "codegen-snapshot", path = "../../snapshot/x11/errors.rs")]
 pub mod errors;
 
+/// Errors encountered when reading messages.
+pub mod read;
+
+/// The names of core requests, errors, and events by their codes, and
+/// decoding events of any type.
+pub mod registry;
+
 /// Events: messages to clients.
 ///
 /// Events are messages sent _from_ the X server (though, this might be at the
@@ -21,6 +79,10 @@ pub mod errors;
 /// [`requests`]: requests
 /// [request]: traits::Request
 /// [reply]: traits::Reply
+#[cfg_attr(
+	feature = "codegen-snapshot",
+	path = "../../snapshot/x11/events/mod.rs"
+)]
 pub mod events;
 
 /// Requests: commands to the X server.
@@ -31,3 +93,95 @@ pub mod events;
 ///
 /// [`replies`]: crate::replies
 pub mod requests;
+
+/// Matching replies to the requests which generated them.
+pub mod reply_decoder;
+
+/// Splitting and decoding the requests sent by a client, for X servers.
+pub mod request_stream;
+
+/// Sending messages straight from a reused buffer to `io::Write` and
+/// `AsyncWrite` sinks.
+pub mod message_writer;
+
+/// Errors encountered when decoding streams of messages, with the context in
+/// which they occurred.
+pub mod decode_error;
+
+/// Interning many atoms at once.
+pub mod atom_batch;
+
+/// Checking pointer button mappings before they are sent.
+pub mod pointer_mapping;
+
+/// Translating coordinates through chains of windows.
+pub mod translate;
+
+/// Typed values of the window manager properties defined by the ICCCM and
+/// EWMH.
+pub mod properties;
+
+/// Coalescing bursts of `PropertyNotify` events.
+pub mod property_debounce;
+
+/// Tracking the ICCCM `WM_STATE` of top-level windows from `PropertyNotify`
+/// events.
+pub mod wm_state;
+
+/// When pointer grabs are activated, changed, and released.
+pub mod grabs;
+
+/// Forwarding the requests redirected to window managers by
+/// `SubstructureRedirect`.
+pub mod redirect;
+
+/// Glyphs of the standard cursor font.
+pub mod cursor_font;
+
+/// Matching font names against the wildcard patterns of `ListFonts`
+/// requests.
+pub mod font_pattern;
+
+/// Parsing and building X Logical Font Description (XLFD) font names.
+pub mod xlfd;
+
+/// Converting images to and from the data sent in `PutImage` requests and
+/// `GetImage` replies.
+pub mod image;
+
+/// Caching the colormap cells allocated for colors with `AllocColor`
+/// requests.
+pub mod color_cache;
+
+/// Tracking which colormaps are installed on each screen from
+/// `ColormapNotify` events.
+pub mod installed_colormaps;
+
+/// Watching for changes to the owners of selections, with XFIXES
+/// `SelectionNotify` events or by polling `GetSelectionOwner`.
+pub mod selection_watchdog;
+
+/// Screens, their depths, and their visuals, as listed in the connection
+/// setup.
+pub mod screen;
+
+/// Reading and writing messages with asynchronous I/O.
+#[cfg(feature = "async")]
+pub mod async_io;
+
+/// Splitting the bytes sent by the X server into messages with `tokio-util`.
+#[cfg(feature = "tokio")]
+pub mod codec;
+
+/// Building blocks for proxies between clients and the X server, such as to
+/// trace the messages sent between them.
+#[cfg(feature = "inspect")]
+pub mod inspect;
+
+/// Composing characters from sequences of keysyms with dead keys and the
+/// `Multi_key`.
+#[cfg(feature = "compose")]
+pub mod compose;
+
+/// Human-readable dumps of messages' fields, for debugging.
+pub mod trace;
@@ -0,0 +1,309 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use crate::x11::{
+	requests::{GetPointerMappingReply, SetPointerMapping, SetPointerMappingReply},
+	Status,
+};
+
+/// A mapping of the pointer's physical buttons to logical buttons, which has
+/// been checked to be accepted in a [`SetPointerMapping`] request.
+///
+/// Element `i` of the mapping (counting from `1`) is the logical button
+/// reported when physical button `i` is pressed. A logical button of `0`
+/// disables the physical button, but no two physical buttons may be mapped to
+/// the same logical button.
+///
+/// The mapping must also have the same number of buttons as the pointer, which
+/// is the length of the map in the [`GetPointerMappingReply`]; see
+/// [`PointerMapping::for_pointer`].
+///
+/// # Examples
+/// ```ignore
+/// let current = PointerMapping::from_reply(&get_pointer_mapping_reply);
+///
+/// // Swap the left and right buttons.
+/// let mut map = current.as_slice().to_vec();
+/// map.swap(0, 2);
+///
+/// let swapped = PointerMapping::for_pointer(map, &get_pointer_mapping_reply)?;
+/// connection.send(swapped.request())?;
+///
+/// connection.receive::<SetPointerMappingReply>()?.result()?;
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PointerMapping {
+	map: Vec<u8>,
+}
+
+/// The reason a [`PointerMapping`] would not be, or was not, applied.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PointerMappingError {
+	/// The mapping does not have the same number of buttons as the pointer.
+	///
+	/// The X server would generate a `Value` error for this mapping.
+	WrongLength {
+		/// The number of buttons the pointer has.
+		expected: usize,
+		/// The number of buttons in the mapping.
+		found: usize,
+	},
+
+	/// Two physical buttons are mapped to the same logical button.
+	///
+	/// The X server would generate a `Value` error for this mapping.
+	Duplicate {
+		/// The logical button which is mapped to more than once.
+		logical: u8,
+		/// The first physical button mapped to `logical`.
+		first: u8,
+		/// The second physical button mapped to `logical`.
+		second: u8,
+	},
+
+	/// One of the buttons whose mapping was to be changed was pressed, so
+	/// the mapping was not changed.
+	///
+	/// This is returned by [`SetPointerMappingReply::result`]; the request
+	/// may be sent again once the buttons have been released.
+	Busy,
+}
+
+impl PointerMapping {
+	/// Creates a new [`PointerMapping`], checking that no two physical buttons
+	/// are mapped to the same logical button.
+	///
+	/// # Errors
+	/// Returns [`PointerMappingError::Duplicate`] if two physical buttons are
+	/// mapped to the same logical button, or [`PointerMappingError::WrongLength`]
+	/// if there are more than `255` buttons.
+	pub fn new(map: impl Into<Vec<u8>>) -> Result<Self, PointerMappingError> {
+		let map = map.into();
+
+		// The number of buttons is sent as a `u8`.
+		if map.len() > usize::from(u8::MAX) {
+			return Err(PointerMappingError::WrongLength {
+				expected: usize::from(u8::MAX),
+				found: map.len(),
+			});
+		}
+
+		// The physical button mapped to each logical button so far.
+		let mut physical = [0_u8; 256];
+
+		for (button, &logical) in (1..=u8::MAX).zip(&map) {
+			if logical == 0 {
+				continue;
+			}
+
+			match physical[usize::from(logical)] {
+				0 => physical[usize::from(logical)] = button,
+
+				first => {
+					return Err(PointerMappingError::Duplicate {
+						logical,
+						first,
+						second: button,
+					})
+				}
+			}
+		}
+
+		Ok(Self { map })
+	}
+
+	/// Creates a new [`PointerMapping`] for a pointer whose current mapping
+	/// is given in `current`, checking that it has the same number of buttons
+	/// and that no two physical buttons are mapped to the same logical button.
+	///
+	/// # Errors
+	/// Returns [`PointerMappingError::WrongLength`] if the mapping does not
+	/// have the same number of buttons as the `current` mapping, or
+	/// [`PointerMappingError::Duplicate`] if two physical buttons are mapped to
+	/// the same logical button.
+	pub fn for_pointer(
+		map: impl Into<Vec<u8>>,
+		current: &GetPointerMappingReply,
+	) -> Result<Self, PointerMappingError> {
+		let map = map.into();
+
+		if map.len() != current.map.len() {
+			return Err(PointerMappingError::WrongLength {
+				expected: current.map.len(),
+				found: map.len(),
+			});
+		}
+
+		Self::new(map)
+	}
+
+	/// Creates the mapping in which each of the pointer's `buttons` is mapped
+	/// to the logical button of the same number.
+	#[must_use]
+	pub fn identity(buttons: u8) -> Self {
+		Self {
+			map: (1..=buttons).collect(),
+		}
+	}
+
+	/// Returns the pointer's current mapping, as returned in the given
+	/// `reply`.
+	///
+	/// The mapping is not checked: the X server only accepts valid mappings.
+	#[must_use]
+	pub fn from_reply(reply: &GetPointerMappingReply) -> Self {
+		Self {
+			map: reply.map.clone(),
+		}
+	}
+
+	/// The logical button for each physical button, starting with physical
+	/// button `1`.
+	#[must_use]
+	pub fn as_slice(&self) -> &[u8] {
+		&self.map
+	}
+
+	/// The number of physical buttons in this mapping.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.map.len()
+	}
+
+	/// Returns whether this mapping has no buttons.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.map.is_empty()
+	}
+
+	/// Returns the logical button that the given `physical` button is mapped
+	/// to.
+	///
+	/// Returns [`None`] if the `physical` button is disabled or is not part of
+	/// this mapping.
+	#[must_use]
+	pub fn logical(&self, physical: u8) -> Option<u8> {
+		let index = usize::from(physical.checked_sub(1)?);
+
+		self.map.get(index).copied().filter(|&logical| logical != 0)
+	}
+
+	/// Returns the physical button which is mapped to the given `logical`
+	/// button, if any.
+	#[must_use]
+	pub fn physical(&self, logical: u8) -> Option<u8> {
+		if logical == 0 {
+			return None;
+		}
+
+		(1..=u8::MAX)
+			.zip(&self.map)
+			.find(|(_, &other)| other == logical)
+			.map(|(physical, _)| physical)
+	}
+
+	/// Creates the [`SetPointerMapping`] request to apply this mapping.
+	#[must_use]
+	pub fn request(&self) -> SetPointerMapping<'_> {
+		SetPointerMapping { map: &self.map }
+	}
+}
+
+impl SetPointerMappingReply {
+	/// Returns whether the mapping was changed, or, if it was not, why.
+	///
+	/// # Errors
+	/// Returns [`PointerMappingError::Busy`] if one of the buttons whose
+	/// mapping was to be changed was pressed.
+	pub const fn result(&self) -> Result<(), PointerMappingError> {
+		match self.status {
+			Status::Success => Ok(()),
+			Status::Busy => Err(PointerMappingError::Busy),
+		}
+	}
+}
+
+impl fmt::Display for PointerMappingError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::WrongLength { expected, found } => write!(
+				f,
+				"the pointer has {expected} buttons, but the mapping has {found}"
+			),
+
+			Self::Duplicate {
+				logical,
+				first,
+				second,
+			} => write!(
+				f,
+				"physical buttons {first} and {second} are both mapped to logical button {logical}"
+			),
+
+			Self::Busy => write!(
+				f,
+				"the pointer mapping was not changed because a remapped button was pressed"
+			),
+		}
+	}
+}
+
+impl Error for PointerMappingError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn duplicate_buttons_are_rejected() {
+		assert_eq!(
+			PointerMapping::new([3, 0, 1, 0, 3]),
+			Err(PointerMappingError::Duplicate {
+				logical: 3,
+				first: 1,
+				second: 5,
+			})
+		);
+
+		// Disabled buttons may be repeated.
+		let mapping = PointerMapping::new([3, 0, 1, 0, 2]).unwrap();
+
+		assert_eq!(mapping.logical(1), Some(3));
+		assert_eq!(mapping.logical(2), None);
+		assert_eq!(mapping.logical(6), None);
+		assert_eq!(mapping.physical(2), Some(5));
+		assert_eq!(mapping.physical(0), None);
+	}
+
+	#[test]
+	fn mappings_must_match_the_pointer() {
+		let current = GetPointerMappingReply {
+			__sequence: 0,
+			__major_opcode: None,
+			__minor_opcode: None,
+			map: vec![1, 2, 3],
+		};
+
+		assert_eq!(
+			PointerMapping::for_pointer([1, 2], &current),
+			Err(PointerMappingError::WrongLength {
+				expected: 3,
+				found: 2,
+			})
+		);
+		assert_eq!(
+			PointerMapping::for_pointer([3, 2, 1], &current)
+				.unwrap()
+				.request()
+				.map,
+			[3, 2, 1]
+		);
+		assert_eq!(
+			PointerMapping::from_reply(&current),
+			PointerMapping::identity(3)
+		);
+	}
+}
@@ -0,0 +1,23 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub use bytes;
+pub use cornflakes;
+pub use cornflakes::{
+	ByteSize, ContextualReadable, ReadError, Readable, StaticByteSize, Writable, WriteError,
+};
+#[cfg(feature = "macros")]
+pub use xrbk_macro::{define, ByteSize, Readable, StaticByteSize, Writable};
+
+pub use crate::{
+	util,
+	x11::{
+		read::{
+			skip_padding, CowBytes, DecodeBody, Header, ReadBorrowedBytes, ReadOwnedBytes,
+			UnexpectedEndOfData, UnexpectedEventCode, UnexpectedHeader,
+		},
+		trace::{PrettyPrint, Trace},
+		traits::*,
+	},
+};
@@ -0,0 +1,860 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use crate::x11::{
+	atoms,
+	requests::{card32, int32, PropertyData, PropertyValue},
+	Atom, Pixmap, ResId, WinGravity, Window,
+};
+
+/// A property defined by the ICCCM or EWMH, which can be converted to and
+/// from the [`PropertyValue`] of a [`ChangeProperty`] request or a
+/// `GetProperty` reply.
+///
+/// # Examples
+/// ```ignore
+/// connection.send(ChangeProperty {
+///     mode: PropertyMode::Replace,
+///     target: window,
+///     property: atoms::WM_CLASS,
+///     value: WmClass::new("xterm", "XTerm").to_value(),
+/// })?;
+///
+/// let hints = WmHints::from_value(&reply.value)?;
+/// ```
+///
+/// [`ChangeProperty`]: crate::x11::requests::ChangeProperty
+pub trait Property: Sized {
+	/// The name of the property.
+	///
+	/// Properties which are not predefined atoms, such as `WM_PROTOCOLS`, must
+	/// be interned before they can be changed; see [`InternAtomBatch`].
+	///
+	/// [`InternAtomBatch`]: crate::x11::atom_batch::InternAtomBatch
+	const NAME: &'static str;
+
+	/// The type of the property's data.
+	const TYPE: Atom;
+
+	/// Converts this property to the value which is set with a
+	/// [`ChangeProperty`] request.
+	///
+	/// [`ChangeProperty`]: crate::x11::requests::ChangeProperty
+	fn to_value(&self) -> PropertyValue;
+
+	/// Converts the given `value`, such as from a `GetProperty` reply, to this
+	/// property.
+	///
+	/// # Errors
+	/// Returns a [`PropertyError`] if the `value` has the wrong type or
+	/// format, is too short, or contains an invalid value.
+	fn from_value(value: &PropertyValue) -> Result<Self, PropertyError>;
+}
+
+/// The reason a [`PropertyValue`] can't be converted to a [`Property`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PropertyError {
+	/// The value does not have the property's type.
+	WrongType {
+		/// The property's type.
+		expected: Atom,
+		/// The type of the value.
+		found: Atom,
+	},
+
+	/// The value's data does not have the property's format.
+	WrongFormat {
+		/// The property's format: `8`, `16`, or `32`.
+		expected: u8,
+		/// The format of the value's data.
+		found: u8,
+	},
+
+	/// The value has fewer items of data than the property requires.
+	TooShort {
+		/// The number of items the property requires.
+		expected: usize,
+		/// The number of items of data in the value.
+		found: usize,
+	},
+
+	/// A field of the property has a value which it can't have.
+	InvalidValue {
+		/// The name of the field.
+		field: &'static str,
+		/// The invalid value.
+		value: u32,
+	},
+}
+
+/// The `WM_CLASS` property: the name of a particular instance of an
+/// application, and the name of the application's class, by which its
+/// resources are looked up.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct WmClass {
+	/// The name of this instance of the application, such as given with the
+	/// `-name` command line option.
+	pub instance: String,
+	/// The name of the application's class, such as `XTerm`.
+	pub class: String,
+}
+
+/// The `WM_PROTOCOLS` property: the protocols, such as `WM_DELETE_WINDOW`,
+/// which a client is willing to take part in.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct WmProtocols(pub Vec<Atom>);
+
+/// The `_NET_WM_STATE` property: the states, such as being fullscreen, which
+/// a window is in.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct NetWmState(pub Vec<Atom>);
+
+/// The `_NET_WM_WINDOW_TYPE` property: the functional types of a window,
+/// most preferred first.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct NetWmWindowType(pub Vec<Atom>);
+
+/// The state of a top-level window, as given in its [`WmStateProperty`], or
+/// the state in which a client would like its window to first be shown, as
+/// given in its [`WmHints`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum WmState {
+	/// The window is not shown.
+	Withdrawn,
+	/// The window is shown as usual.
+	Normal,
+	/// The window is shown as an icon.
+	Iconic,
+}
+
+/// The `WM_HINTS` property: hints about how a window would like to be
+/// managed, other than its size.
+///
+/// Each hint is [`None`] if it is not given.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct WmHints {
+	/// Whether the client relies on the window manager to give it input
+	/// focus.
+	pub input: Option<bool>,
+	/// The state in which the window should first be shown.
+	pub initial_state: Option<WmState>,
+	/// A bitmap shown as the window's icon.
+	pub icon_pixmap: Option<Pixmap>,
+	/// A window shown as the window's icon.
+	pub icon_window: Option<Window>,
+	/// The position of the window's icon, relative to the root window.
+	pub icon_position: Option<(i32, i32)>,
+	/// A bitmap of the pixels of the `icon_pixmap` which are part of the
+	/// icon.
+	pub icon_mask: Option<Pixmap>,
+	/// The leader of the group of windows to which the window belongs.
+	pub window_group: Option<Window>,
+	/// Whether the window needs the user's attention.
+	pub urgent: bool,
+}
+
+/// The `WM_STATE` property: the state of a top-level window, as set by the
+/// window manager.
+///
+/// Unlike the other properties here, the type of `WM_STATE` is not a
+/// predefined atom: it is also named `WM_STATE`, and must be interned before
+/// the property can be converted. It therefore does not implement
+/// [`Property`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct WmStateProperty {
+	/// The state of the window.
+	pub state: WmState,
+	/// The window used as the window's icon, if the window manager uses one.
+	pub icon: Option<Window>,
+}
+
+/// A ratio of width to height, as given in [`WmSizeHints`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AspectRatio {
+	/// The width.
+	pub numerator: i32,
+	/// The height.
+	pub denominator: i32,
+}
+
+/// Who chose the position or size of a window, as given in [`WmSizeHints`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum HintSource {
+	/// The user chose it, such as with the `-geometry` command line option.
+	User,
+	/// The client chose it.
+	Program,
+}
+
+/// The `WM_NORMAL_HINTS` property, of type `WM_SIZE_HINTS`: hints about the
+/// size and position of a window.
+///
+/// Each hint is [`None`] if it is not given.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct WmSizeHints {
+	/// Who chose the window's position, if it was chosen.
+	pub position: Option<HintSource>,
+	/// Who chose the window's size, if it was chosen.
+	pub size: Option<HintSource>,
+	/// The minimum width and height of the window.
+	pub min_size: Option<(i32, i32)>,
+	/// The maximum width and height of the window.
+	pub max_size: Option<(i32, i32)>,
+	/// The increments, starting at the `base_size`, in which the width and
+	/// height of the window should be changed.
+	pub resize_increments: Option<(i32, i32)>,
+	/// The minimum and maximum aspect ratios of the window.
+	pub aspect: Option<(AspectRatio, AspectRatio)>,
+	/// The width and height of the window from which `resize_increments` are
+	/// counted.
+	pub base_size: Option<(i32, i32)>,
+	/// How the window should be moved when it is given a frame by the window
+	/// manager.
+	pub win_gravity: Option<WinGravity>,
+}
+
+impl WmClass {
+	/// Creates a new [`WmClass`] with the given `instance` and `class` names.
+	pub fn new(instance: impl Into<String>, class: impl Into<String>) -> Self {
+		Self {
+			instance: instance.into(),
+			class: class.into(),
+		}
+	}
+}
+
+impl NetWmState {
+	/// The window is a modal dialog.
+	pub const MODAL: &'static str = "_NET_WM_STATE_MODAL";
+	/// The window stays in place when the virtual desktop scrolls.
+	pub const STICKY: &'static str = "_NET_WM_STATE_STICKY";
+	/// The window is maximized vertically.
+	pub const MAXIMIZED_VERT: &'static str = "_NET_WM_STATE_MAXIMIZED_VERT";
+	/// The window is maximized horizontally.
+	pub const MAXIMIZED_HORZ: &'static str = "_NET_WM_STATE_MAXIMIZED_HORZ";
+	/// The window is shaded: only its title bar is shown.
+	pub const SHADED: &'static str = "_NET_WM_STATE_SHADED";
+	/// The window is not shown on a taskbar.
+	pub const SKIP_TASKBAR: &'static str = "_NET_WM_STATE_SKIP_TASKBAR";
+	/// The window is not shown on a pager.
+	pub const SKIP_PAGER: &'static str = "_NET_WM_STATE_SKIP_PAGER";
+	/// The window is hidden, such as when it is minimized.
+	pub const HIDDEN: &'static str = "_NET_WM_STATE_HIDDEN";
+	/// The window fills the screen, without a frame.
+	pub const FULLSCREEN: &'static str = "_NET_WM_STATE_FULLSCREEN";
+	/// The window is shown above most other windows.
+	pub const ABOVE: &'static str = "_NET_WM_STATE_ABOVE";
+	/// The window is shown below most other windows.
+	pub const BELOW: &'static str = "_NET_WM_STATE_BELOW";
+	/// The window needs the user's attention.
+	pub const DEMANDS_ATTENTION: &'static str = "_NET_WM_STATE_DEMANDS_ATTENTION";
+	/// The window has input focus.
+	pub const FOCUSED: &'static str = "_NET_WM_STATE_FOCUSED";
+}
+
+impl NetWmWindowType {
+	/// A desktop window, shown below every other window.
+	pub const DESKTOP: &'static str = "_NET_WM_WINDOW_TYPE_DESKTOP";
+	/// A dock or panel.
+	pub const DOCK: &'static str = "_NET_WM_WINDOW_TYPE_DOCK";
+	/// A toolbar torn off from its main window.
+	pub const TOOLBAR: &'static str = "_NET_WM_WINDOW_TYPE_TOOLBAR";
+	/// A menu torn off from its main window.
+	pub const MENU: &'static str = "_NET_WM_WINDOW_TYPE_MENU";
+	/// A small persistent utility window, such as a palette.
+	pub const UTILITY: &'static str = "_NET_WM_WINDOW_TYPE_UTILITY";
+	/// A splash screen shown while an application starts.
+	pub const SPLASH: &'static str = "_NET_WM_WINDOW_TYPE_SPLASH";
+	/// A dialog window.
+	pub const DIALOG: &'static str = "_NET_WM_WINDOW_TYPE_DIALOG";
+	/// A dropdown menu, opened from a menubar.
+	pub const DROPDOWN_MENU: &'static str = "_NET_WM_WINDOW_TYPE_DROPDOWN_MENU";
+	/// A popup menu, such as a context menu.
+	pub const POPUP_MENU: &'static str = "_NET_WM_WINDOW_TYPE_POPUP_MENU";
+	/// A tooltip.
+	pub const TOOLTIP: &'static str = "_NET_WM_WINDOW_TYPE_TOOLTIP";
+	/// A notification bubble.
+	pub const NOTIFICATION: &'static str = "_NET_WM_WINDOW_TYPE_NOTIFICATION";
+	/// The popup of a combo box.
+	pub const COMBO: &'static str = "_NET_WM_WINDOW_TYPE_COMBO";
+	/// An object being dragged.
+	pub const DND: &'static str = "_NET_WM_WINDOW_TYPE_DND";
+	/// A normal, top-level window.
+	pub const NORMAL: &'static str = "_NET_WM_WINDOW_TYPE_NORMAL";
+}
+
+impl WmState {
+	const fn value(self) -> u32 {
+		match self {
+			Self::Withdrawn => 0,
+			Self::Normal => 1,
+			Self::Iconic => 3,
+		}
+	}
+
+	const fn from_value(value: u32) -> Option<Self> {
+		match value {
+			0 => Some(Self::Withdrawn),
+			1 => Some(Self::Normal),
+			3 => Some(Self::Iconic),
+
+			_ => None,
+		}
+	}
+}
+
+impl WmStateProperty {
+	/// The name of the property, which is also the name of its type.
+	pub const NAME: &'static str = "WM_STATE";
+
+	/// The number of values in a `WM_STATE` property.
+	const LEN: usize = 2;
+
+	/// Converts this property to the value which is set with a
+	/// [`ChangeProperty`] request, given the interned `WM_STATE` atom as its
+	/// `type`.
+	///
+	/// [`ChangeProperty`]: crate::x11::requests::ChangeProperty
+	#[must_use]
+	pub fn to_value(&self, r#type: Atom) -> PropertyValue {
+		PropertyValue::new(
+			r#type,
+			vec![
+				self.state.value(),
+				self.icon.map_or(0, |icon| icon.res_id()),
+			],
+		)
+	}
+
+	/// Converts the given `value`, such as from a `GetProperty` reply, to a
+	/// `WM_STATE` property, given the interned `WM_STATE` atom as its `type`.
+	///
+	/// # Errors
+	/// Returns a [`PropertyError`] if the `value` has the wrong type or
+	/// format, is too short, or has an invalid state.
+	pub fn from_value(value: &PropertyValue, r#type: Atom) -> Result<Self, PropertyError> {
+		let data = format32(value, r#type)?;
+		check_len(data, Self::LEN)?;
+
+		let state = WmState::from_value(data[0]).ok_or(PropertyError::InvalidValue {
+			field: "state",
+			value: data[0],
+		})?;
+
+		Ok(Self {
+			state,
+			icon: (data[1] != 0).then(|| Window::new(data[1])),
+		})
+	}
+}
+
+impl HintSource {
+	/// Returns the `user` flag or the `program` flag, depending on the
+	/// `source`, or `0` if there is none.
+	const fn flag(source: Option<Self>, user: u32, program: u32) -> u32 {
+		match source {
+			Some(Self::User) => user,
+			Some(Self::Program) => program,
+			None => 0,
+		}
+	}
+}
+
+impl WmHints {
+	/// The number of values in a `WM_HINTS` property.
+	const LEN: usize = 9;
+
+	const INPUT: u32 = 1 << 0;
+	const STATE: u32 = 1 << 1;
+	const ICON_PIXMAP: u32 = 1 << 2;
+	const ICON_WINDOW: u32 = 1 << 3;
+	const ICON_POSITION: u32 = 1 << 4;
+	const ICON_MASK: u32 = 1 << 5;
+	const WINDOW_GROUP: u32 = 1 << 6;
+	const URGENCY: u32 = 1 << 8;
+}
+
+impl WmSizeHints {
+	/// The number of values in a `WM_SIZE_HINTS` property.
+	const LEN: usize = 18;
+	/// The number of values in a `WM_SIZE_HINTS` property written by clients
+	/// from before the ICCCM, which lack the `base_size` and `win_gravity`.
+	const OLD_LEN: usize = 15;
+
+	const USER_POSITION: u32 = 1 << 0;
+	const USER_SIZE: u32 = 1 << 1;
+	const PROGRAM_POSITION: u32 = 1 << 2;
+	const PROGRAM_SIZE: u32 = 1 << 3;
+	const MIN_SIZE: u32 = 1 << 4;
+	const MAX_SIZE: u32 = 1 << 5;
+	const RESIZE_INCREMENTS: u32 = 1 << 6;
+	const ASPECT: u32 = 1 << 7;
+	const BASE_SIZE: u32 = 1 << 8;
+	const WIN_GRAVITY: u32 = 1 << 9;
+}
+
+/// Returns the `value`'s data if it has the `expected` type and is in format
+/// 32.
+fn format32(value: &PropertyValue, expected: Atom) -> Result<&[u32], PropertyError> {
+	check_type(value, expected)?;
+
+	match &value.data {
+		PropertyData::Format32(data) => Ok(data),
+
+		data => Err(PropertyError::WrongFormat {
+			expected: 32,
+			found: data.format(),
+		}),
+	}
+}
+
+/// Returns an error if the `value` does not have the `expected` type.
+fn check_type(value: &PropertyValue, expected: Atom) -> Result<(), PropertyError> {
+	if value.r#type == expected {
+		Ok(())
+	} else {
+		Err(PropertyError::WrongType {
+			expected,
+			found: value.r#type,
+		})
+	}
+}
+
+/// Returns an error if there are fewer than `expected` items of `data`.
+const fn check_len<T>(data: &[T], expected: usize) -> Result<(), PropertyError> {
+	if data.len() < expected {
+		Err(PropertyError::TooShort {
+			expected,
+			found: data.len(),
+		})
+	} else {
+		Ok(())
+	}
+}
+
+/// Returns the value of the given `flag`, if it is set in the `flags`.
+fn flagged<T>(flags: u32, flag: u32, value: impl FnOnce() -> T) -> Option<T> {
+	(flags & flag != 0).then(value)
+}
+
+/// Returns the given `flag` if it `is_set`, or `0` otherwise.
+const fn flag(is_set: bool, flag: u32) -> u32 {
+	if is_set {
+		flag
+	} else {
+		0
+	}
+}
+
+/// Encodes the given `string` in Latin-1, replacing characters which are not
+/// in Latin-1 with `?`.
+fn latin1(string: &str) -> impl Iterator<Item = u8> + '_ {
+	string
+		.chars()
+		.map(|r#char| u8::try_from(r#char).unwrap_or(b'?'))
+}
+
+impl Property for WmClass {
+	const NAME: &'static str = "WM_CLASS";
+	const TYPE: Atom = atoms::STRING;
+
+	fn to_value(&self) -> PropertyValue {
+		let data: Vec<u8> = latin1(&self.instance)
+			.chain([0])
+			.chain(latin1(&self.class))
+			.chain([0])
+			.collect();
+
+		PropertyValue::new(Self::TYPE, data)
+	}
+
+	fn from_value(value: &PropertyValue) -> Result<Self, PropertyError> {
+		check_type(value, Self::TYPE)?;
+
+		let PropertyData::Format8(data) = &value.data else {
+			return Err(PropertyError::WrongFormat {
+				expected: 8,
+				found: value.data.format(),
+			});
+		};
+
+		// Each name is terminated by a null byte, though some clients leave
+		// out the last one.
+		let mut names = data
+			.strip_suffix(&[0])
+			.unwrap_or(data)
+			.split(|&byte| byte == 0)
+			.map(|name| name.iter().copied().map(char::from).collect::<String>());
+
+		Ok(Self {
+			instance: names.next().unwrap_or_default(),
+			class: names.next().unwrap_or_default(),
+		})
+	}
+}
+
+/// Implements [`Property`] for a list of atoms.
+macro_rules! atom_list {
+	($($Property:ident: $name:literal;)*) => {
+		$(
+			impl Property for $Property {
+				const NAME: &'static str = $name;
+				const TYPE: Atom = atoms::ATOM;
+
+				fn to_value(&self) -> PropertyValue {
+					PropertyValue::atoms(self.0.iter().copied())
+				}
+
+				fn from_value(value: &PropertyValue) -> Result<Self, PropertyError> {
+					let data = format32(value, Self::TYPE)?;
+
+					Ok(Self(data.iter().copied().map(Atom::new).collect()))
+				}
+			}
+		)*
+	};
+}
+
+atom_list! {
+	WmProtocols: "WM_PROTOCOLS";
+	NetWmState: "_NET_WM_STATE";
+	NetWmWindowType: "_NET_WM_WINDOW_TYPE";
+}
+
+impl Property for WmHints {
+	const NAME: &'static str = "WM_HINTS";
+	const TYPE: Atom = atoms::WM_HINTS;
+
+	fn to_value(&self) -> PropertyValue {
+		let flags = flag(self.input.is_some(), Self::INPUT)
+			| flag(self.initial_state.is_some(), Self::STATE)
+			| flag(self.icon_pixmap.is_some(), Self::ICON_PIXMAP)
+			| flag(self.icon_window.is_some(), Self::ICON_WINDOW)
+			| flag(self.icon_position.is_some(), Self::ICON_POSITION)
+			| flag(self.icon_mask.is_some(), Self::ICON_MASK)
+			| flag(self.window_group.is_some(), Self::WINDOW_GROUP)
+			| flag(self.urgent, Self::URGENCY);
+
+		let (icon_x, icon_y) = self.icon_position.unwrap_or_default();
+
+		PropertyValue::new(
+			Self::TYPE,
+			vec![
+				flags,
+				self.input.map_or(0, u32::from),
+				self.initial_state.map_or(0, WmState::value),
+				self.icon_pixmap.map_or(0, |pixmap| pixmap.res_id()),
+				self.icon_window.map_or(0, |window| window.res_id()),
+				card32(icon_x),
+				card32(icon_y),
+				self.icon_mask.map_or(0, |pixmap| pixmap.res_id()),
+				self.window_group.map_or(0, |window| window.res_id()),
+			],
+		)
+	}
+
+	fn from_value(value: &PropertyValue) -> Result<Self, PropertyError> {
+		let data = format32(value, Self::TYPE)?;
+		check_len(data, Self::LEN)?;
+
+		let flags = data[0];
+
+		let initial_state = flagged(flags, Self::STATE, || data[2])
+			.map(|state| {
+				WmState::from_value(state).ok_or(PropertyError::InvalidValue {
+					field: "initial_state",
+					value: state,
+				})
+			})
+			.transpose()?;
+
+		Ok(Self {
+			input: flagged(flags, Self::INPUT, || data[1] != 0),
+			initial_state,
+			icon_pixmap: flagged(flags, Self::ICON_PIXMAP, || Pixmap::new(data[3])),
+			icon_window: flagged(flags, Self::ICON_WINDOW, || Window::new(data[4])),
+			icon_position: flagged(flags, Self::ICON_POSITION, || {
+				(int32(data[5]), int32(data[6]))
+			}),
+			icon_mask: flagged(flags, Self::ICON_MASK, || Pixmap::new(data[7])),
+			window_group: flagged(flags, Self::WINDOW_GROUP, || Window::new(data[8])),
+			urgent: flags & Self::URGENCY != 0,
+		})
+	}
+}
+
+impl Property for WmSizeHints {
+	const NAME: &'static str = "WM_NORMAL_HINTS";
+	const TYPE: Atom = atoms::WM_SIZE_HINTS;
+
+	fn to_value(&self) -> PropertyValue {
+		let flags = HintSource::flag(self.position, Self::USER_POSITION, Self::PROGRAM_POSITION)
+			| HintSource::flag(self.size, Self::USER_SIZE, Self::PROGRAM_SIZE)
+			| flag(self.min_size.is_some(), Self::MIN_SIZE)
+			| flag(self.max_size.is_some(), Self::MAX_SIZE)
+			| flag(self.resize_increments.is_some(), Self::RESIZE_INCREMENTS)
+			| flag(self.aspect.is_some(), Self::ASPECT)
+			| flag(self.base_size.is_some(), Self::BASE_SIZE)
+			| flag(self.win_gravity.is_some(), Self::WIN_GRAVITY);
+
+		let pair = |pair: Option<(i32, i32)>| {
+			let (a, b) = pair.unwrap_or_default();
+			[card32(a), card32(b)]
+		};
+		let aspect = |aspect: Option<AspectRatio>| {
+			pair(aspect.map(|ratio| (ratio.numerator, ratio.denominator)))
+		};
+
+		let mut data = vec![flags];
+		// The position and size are obsolete, and are no longer used.
+		data.extend([0; 4]);
+		data.extend(pair(self.min_size));
+		data.extend(pair(self.max_size));
+		data.extend(pair(self.resize_increments));
+		data.extend(aspect(self.aspect.map(|(min, _)| min)));
+		data.extend(aspect(self.aspect.map(|(_, max)| max)));
+		data.extend(pair(self.base_size));
+		data.push(self.win_gravity.map_or(0, |gravity| gravity as u32));
+
+		PropertyValue::new(Self::TYPE, data)
+	}
+
+	fn from_value(value: &PropertyValue) -> Result<Self, PropertyError> {
+		let data = format32(value, Self::TYPE)?;
+		check_len(data, Self::OLD_LEN)?;
+
+		let flags = data[0];
+		// The user's choice takes precedence, if both are given.
+		let source = |user, program| {
+			if flags & user != 0 {
+				Some(HintSource::User)
+			} else if flags & program != 0 {
+				Some(HintSource::Program)
+			} else {
+				None
+			}
+		};
+		let pair = |flag, index: usize| {
+			flagged(flags, flag, || (int32(data[index]), int32(data[index + 1])))
+		};
+		let ratio = |index: usize| AspectRatio {
+			numerator: int32(data[index]),
+			denominator: int32(data[index + 1]),
+		};
+
+		// Clients from before the ICCCM don't give the base size or window
+		// gravity, even if their flags are set.
+		let (base_size, win_gravity) = if data.len() >= Self::LEN {
+			let win_gravity = flagged(flags, Self::WIN_GRAVITY, || data[17])
+				.map(|gravity| {
+					win_gravity(gravity).ok_or(PropertyError::InvalidValue {
+						field: "win_gravity",
+						value: gravity,
+					})
+				})
+				.transpose()?;
+
+			(pair(Self::BASE_SIZE, 15), win_gravity)
+		} else {
+			(None, None)
+		};
+
+		Ok(Self {
+			position: source(Self::USER_POSITION, Self::PROGRAM_POSITION),
+			size: source(Self::USER_SIZE, Self::PROGRAM_SIZE),
+			min_size: pair(Self::MIN_SIZE, 5),
+			max_size: pair(Self::MAX_SIZE, 7),
+			resize_increments: pair(Self::RESIZE_INCREMENTS, 9),
+			aspect: flagged(flags, Self::ASPECT, || (ratio(11), ratio(13))),
+			base_size,
+			win_gravity,
+		})
+	}
+}
+
+/// Returns the [`WinGravity`] with the given `value`, unless it is `Unmap`,
+/// which a window can't be given by its [`WmSizeHints`].
+const fn win_gravity(value: u32) -> Option<WinGravity> {
+	Some(match value {
+		1 => WinGravity::NorthWest,
+		2 => WinGravity::North,
+		3 => WinGravity::NorthEast,
+		4 => WinGravity::West,
+		5 => WinGravity::Center,
+		6 => WinGravity::East,
+		7 => WinGravity::SouthWest,
+		8 => WinGravity::South,
+		9 => WinGravity::SouthEast,
+		10 => WinGravity::Static,
+
+		_ => return None,
+	})
+}
+
+impl fmt::Display for PropertyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::WrongType { expected, found } => write!(
+				f,
+				"expected a property of type {}, but found type {}",
+				expected.id, found.id
+			),
+
+			Self::WrongFormat { expected, found } => write!(
+				f,
+				"expected a property of format {expected}, but found format {found}"
+			),
+
+			Self::TooShort { expected, found } => write!(
+				f,
+				"expected a property at least {expected} long, but found one {found} long"
+			),
+
+			Self::InvalidValue { field, value } => write!(f, "invalid {field} of {value}"),
+		}
+	}
+}
+
+impl Error for PropertyError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn wm_class_round_trips() {
+		let class = WmClass::new("xterm", "XTerm");
+		let value = class.to_value();
+
+		assert_eq!(
+			value.data,
+			PropertyData::Format8(b"xterm\0XTerm\0".to_vec())
+		);
+		assert_eq!(WmClass::from_value(&value), Ok(class));
+
+		// The last null byte is sometimes left out.
+		assert_eq!(
+			WmClass::from_value(&PropertyValue::string("xterm\0XTerm")),
+			Ok(WmClass::new("xterm", "XTerm"))
+		);
+	}
+
+	#[test]
+	fn hints_round_trip() {
+		let hints = WmHints {
+			input: Some(true),
+			initial_state: Some(WmState::Iconic),
+			icon_position: Some((-10, 20)),
+			window_group: Some(Window::new(0x0040_0001)),
+			urgent: true,
+			..WmHints::default()
+		};
+		let value = hints.to_value();
+
+		assert_eq!(
+			value.data,
+			PropertyData::Format32(vec![
+				0b1_0101_0011,
+				1,
+				3,
+				0,
+				0,
+				card32(-10),
+				20,
+				0,
+				0x0040_0001
+			])
+		);
+		assert_eq!(WmHints::from_value(&value), Ok(hints));
+
+		let size_hints = WmSizeHints {
+			size: Some(HintSource::Program),
+			min_size: Some((100, 50)),
+			aspect: Some((
+				AspectRatio {
+					numerator: 4,
+					denominator: 3,
+				},
+				AspectRatio {
+					numerator: 16,
+					denominator: 9,
+				},
+			)),
+			win_gravity: Some(WinGravity::Static),
+			..WmSizeHints::default()
+		};
+
+		assert_eq!(
+			WmSizeHints::from_value(&size_hints.to_value()),
+			Ok(size_hints)
+		);
+	}
+
+	#[test]
+	fn wm_state_round_trips() {
+		// `WM_STATE` is interned, so its atom differs between servers.
+		let wm_state = Atom::new(0x1a3);
+
+		let state = WmStateProperty {
+			state: WmState::Iconic,
+			icon: None,
+		};
+		let value = state.to_value(wm_state);
+
+		assert_eq!(value.data, PropertyData::Format32(vec![3, 0]));
+		assert_eq!(WmStateProperty::from_value(&value, wm_state), Ok(state));
+		assert_eq!(
+			WmStateProperty::from_value(&value, atoms::WM_HINTS),
+			Err(PropertyError::WrongType {
+				expected: atoms::WM_HINTS,
+				found: wm_state,
+			})
+		);
+	}
+
+	#[test]
+	fn invalid_values_are_rejected() {
+		assert_eq!(
+			WmProtocols::from_value(&PropertyValue::cardinals([1])),
+			Err(PropertyError::WrongType {
+				expected: atoms::ATOM,
+				found: atoms::CARDINAL,
+			})
+		);
+		assert_eq!(
+			WmHints::from_value(&PropertyValue::new(atoms::WM_HINTS, vec![0_u32; 8])),
+			Err(PropertyError::TooShort {
+				expected: 9,
+				found: 8,
+			})
+		);
+		assert_eq!(
+			WmHints::from_value(&PropertyValue::new(
+				atoms::WM_HINTS,
+				vec![WmHints::STATE, 0, 2, 0, 0, 0, 0, 0, 0]
+			)),
+			Err(PropertyError::InvalidValue {
+				field: "initial_state",
+				value: 2,
+			})
+		);
+
+		// Clients from before the ICCCM write shorter size hints.
+		let mut old = WmSizeHints {
+			max_size: Some((640, 480)),
+			..WmSizeHints::default()
+		}
+		.to_value();
+		if let PropertyData::Format32(data) = &mut old.data {
+			data.truncate(WmSizeHints::OLD_LEN);
+		}
+
+		assert_eq!(
+			WmSizeHints::from_value(&old).map(|hints| hints.max_size),
+			Ok(Some((640, 480)))
+		);
+	}
+}
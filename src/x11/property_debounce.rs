@@ -0,0 +1,224 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::mem;
+
+use crate::x11::{events::PropertyNotify, Atom, Timestamp, Window};
+
+/// Coalesces bursts of [`PropertyNotify`] events for the same property of the
+/// same window, so that only the latest of each burst is handled.
+///
+/// Clients often change a property many times in quick succession, such as a
+/// terminal updating its `_NET_WM_NAME` as output scrolls past. Rather than
+/// fetching the property after every change, events can be [pushed] as they
+/// are received and [flushed] once they have settled.
+///
+/// A burst lasts for the `interval` given in [`PropertyDebouncer::new`],
+/// starting with its first event: however many more events are pushed, each
+/// property is flushed no more than `interval` milliseconds after it first
+/// changed. Times are [`Timestamp`]s from the X server, so the debouncer works
+/// without a clock of its own.
+///
+/// # Examples
+/// ```ignore
+/// let mut debouncer = PropertyDebouncer::new(50);
+///
+/// loop {
+///     match connection.next_event()? {
+///         Event::PropertyNotify(event) => debouncer.push(event),
+///         // ...
+///     }
+///
+///     for event in debouncer.flush(connection.last_timestamp()) {
+///         refresh_property(event.window, event.property);
+///     }
+/// }
+/// ```
+///
+/// [pushed]: PropertyDebouncer::push
+/// [flushed]: PropertyDebouncer::flush
+pub struct PropertyDebouncer {
+	/// The number of milliseconds after its first event that a burst ends.
+	interval: Timestamp,
+	/// The latest event of each pending burst, with the time of its first
+	/// event, in the order the bursts started.
+	pending: Vec<(Timestamp, PropertyNotify)>,
+}
+
+impl PropertyDebouncer {
+	/// Creates a new [`PropertyDebouncer`] which coalesces the events for each
+	/// property within `interval` milliseconds of the first.
+	#[must_use]
+	pub const fn new(interval: Timestamp) -> Self {
+		Self {
+			interval,
+			pending: Vec::new(),
+		}
+	}
+
+	/// The number of milliseconds after its first event that a burst ends.
+	#[must_use]
+	pub const fn interval(&self) -> Timestamp {
+		self.interval
+	}
+
+	/// Adds the given `event` to the burst of events for its property,
+	/// starting a new burst if there is none.
+	///
+	/// The latest event of a burst replaces the ones before it, so the
+	/// [`state`] that is flushed is always the most recent one.
+	///
+	/// [`state`]: PropertyNotify::state
+	pub fn push(&mut self, event: PropertyNotify) {
+		match self
+			.pending
+			.iter_mut()
+			.find(|(_, pending)| is_same_property(pending, &event))
+		{
+			Some((_, pending)) => *pending = event,
+			None => self.pending.push((event.time, event)),
+		}
+	}
+
+	/// Removes and returns the latest event of each burst which has ended by
+	/// the time `now`, in the order the bursts started.
+	///
+	/// `now` is usually the time of the most recent event received from the
+	/// X server. Times wrap around, as [`Timestamp`]s do, so bursts are only
+	/// compared by how long ago they started.
+	pub fn flush(&mut self, now: Timestamp) -> Vec<PropertyNotify> {
+		let (ended, pending): (Vec<_>, Vec<_>) = mem::take(&mut self.pending)
+			.into_iter()
+			.partition(|(first, _)| now.wrapping_sub(*first) >= self.interval);
+
+		self.pending = pending;
+
+		ended.into_iter().map(|(_, event)| event).collect()
+	}
+
+	/// Removes and returns the latest event of every burst, whether or not it
+	/// has ended, in the order the bursts started.
+	pub fn drain(&mut self) -> Vec<PropertyNotify> {
+		self.pending.drain(..).map(|(_, event)| event).collect()
+	}
+
+	/// The time at which the earliest pending burst ends, if there are any.
+	///
+	/// This can be used to wake up and [flush] the debouncer when no more
+	/// events are received.
+	///
+	/// [flush]: PropertyDebouncer::flush
+	#[must_use]
+	pub fn next_deadline(&self) -> Option<Timestamp> {
+		// Bursts are kept in the order they started, so the first ends first.
+		self.pending
+			.first()
+			.map(|(first, _)| first.wrapping_add(self.interval))
+	}
+
+	/// Returns whether an event for the given `property` of the `window` is
+	/// waiting to be flushed.
+	#[must_use]
+	pub fn is_pending(&self, window: Window, property: Atom) -> bool {
+		self.pending
+			.iter()
+			.any(|(_, event)| event.window == window && event.property == property)
+	}
+
+	/// The number of bursts waiting to be flushed.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.pending.len()
+	}
+
+	/// Returns whether there are no bursts waiting to be flushed.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.pending.is_empty()
+	}
+}
+
+/// Returns whether the two events are for the same property of the same
+/// window.
+fn is_same_property(a: &PropertyNotify, b: &PropertyNotify) -> bool {
+	a.window == b.window && a.property == b.property
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::x11::{atoms, PropertyState, ResId};
+
+	/// The window, property, and time of each of the `events`.
+	fn summary(events: &[PropertyNotify]) -> Vec<(u32, Atom, Timestamp)> {
+		events
+			.iter()
+			.map(|event| (event.window.res_id(), event.property, event.time))
+			.collect()
+	}
+
+	fn notify(window: u32, property: Atom, time: Timestamp) -> PropertyNotify {
+		PropertyNotify {
+			_sequence_: 0,
+			window: Window::new(window),
+			property,
+			time,
+			state: PropertyState::NewValue,
+		}
+	}
+
+	#[test]
+	fn bursts_are_coalesced_per_window_and_property() {
+		let mut debouncer = PropertyDebouncer::new(50);
+
+		debouncer.push(notify(1, atoms::WM_NAME, 100));
+		debouncer.push(notify(2, atoms::WM_NAME, 110));
+		debouncer.push(notify(1, atoms::WM_NAME, 120));
+		debouncer.push(notify(1, atoms::WM_ICON_NAME, 130));
+		debouncer.push(notify(1, atoms::WM_NAME, 140));
+
+		assert_eq!(debouncer.len(), 3);
+		assert_eq!(debouncer.next_deadline(), Some(150));
+		assert!(debouncer.flush(149).is_empty());
+
+		// The first burst ends 50ms after its first event, not its last.
+		assert_eq!(summary(&debouncer.flush(150)), [(1, atoms::WM_NAME, 140)]);
+		assert!(!debouncer.is_pending(Window::new(1), atoms::WM_NAME));
+
+		assert_eq!(
+			summary(&debouncer.flush(200)),
+			[(2, atoms::WM_NAME, 110), (1, atoms::WM_ICON_NAME, 130)]
+		);
+		assert!(debouncer.is_empty());
+	}
+
+	#[test]
+	fn latest_state_is_kept() {
+		let mut debouncer = PropertyDebouncer::new(10);
+
+		debouncer.push(notify(1, atoms::WM_NAME, 5));
+		debouncer.push(PropertyNotify {
+			state: PropertyState::Deleted,
+			..notify(1, atoms::WM_NAME, 6)
+		});
+
+		let events = debouncer.drain();
+
+		assert_eq!(events.len(), 1);
+		assert!(matches!(events[0].state, PropertyState::Deleted));
+		assert!(debouncer.is_empty());
+	}
+
+	#[test]
+	fn times_wrap_around() {
+		let mut debouncer = PropertyDebouncer::new(10);
+
+		debouncer.push(notify(1, atoms::WM_NAME, Timestamp::MAX - 4));
+
+		assert_eq!(debouncer.next_deadline(), Some(5));
+		assert!(debouncer.flush(4).is_empty());
+		assert_eq!(debouncer.flush(5).len(), 1);
+	}
+}
@@ -0,0 +1,717 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{borrow::Cow, cell::Cell, error::Error, fmt};
+
+use bytes::Buf;
+use cornflakes::{ReadError, Readable};
+
+/// How strictly values are checked when they are read.
+///
+/// The X protocol requires reserved bits in masks and unused padding bytes to
+/// be zero, but not every X server or client is careful to leave them so. By
+/// default, they are ignored; a protocol debugger may instead want to know
+/// when they are not zero.
+///
+/// Values are read with [`Strictness::Lenient`] unless they are read with
+/// [`Strictness::read`], whose strictness applies to the value it reads and
+/// every value read as part of it, whether their [`Readable`]
+/// implementations are written by hand or generated by `define!`. Each
+/// decoder can therefore read with a strictness of its own.
+///
+/// # Examples
+/// ```ignore
+/// match Strictness::Strict.read::<AnyError>(reader) {
+///     Err(ReadError::Other(error)) if error.is::<NonzeroPadding>() => {
+///         eprintln!("the X server sent an error with nonzero padding");
+///     }
+///
+///     // ...
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Strictness {
+	/// Reserved bits set in masks and nonzero padding bytes are ignored.
+	#[default]
+	Lenient,
+	/// Reserved bits set in masks return an [`UnexpectedReservedBits`] error,
+	/// and nonzero padding bytes return a [`NonzeroPadding`] error.
+	Strict,
+}
+
+thread_local! {
+	/// The strictness with which values are being read on this thread.
+	static CURRENT: Cell<Strictness> = const { Cell::new(Strictness::Lenient) };
+}
+
+impl Strictness {
+	/// Returns the strictness with which values are being read on this
+	/// thread: that given to [`Strictness::read`] while it is reading a
+	/// value, and [`Strictness::Lenient`] otherwise.
+	#[must_use]
+	pub fn current() -> Self {
+		CURRENT.with(Cell::get)
+	}
+
+	/// Reads a `T` from the `reader` with this strictness.
+	///
+	/// # Errors
+	/// Returns any error returned by reading the `T`, including an
+	/// [`UnexpectedReservedBits`] or [`NonzeroPadding`] error if this is
+	/// [`Strictness::Strict`].
+	pub fn read<T: Readable>(self, reader: &mut impl Buf) -> Result<T, ReadError> {
+		/// Restores the strictness from before the value was read, even if
+		/// reading it panics.
+		struct Restore(Strictness);
+
+		impl Drop for Restore {
+			fn drop(&mut self) {
+				CURRENT.with(|current| current.set(self.0));
+			}
+		}
+
+		let _restore = Restore(CURRENT.with(|current| current.replace(self)));
+
+		T::read_from(reader)
+	}
+}
+
+/// The error returned when a message is read from fewer bytes than it needs.
+///
+/// This is returned in a [`ReadError::Other`], from which it can be
+/// downcast:
+/// ```ignore
+/// match MapRequest::read_from(reader) {
+///     Err(ReadError::Other(error)) if error.is::<UnexpectedEndOfData>() => {
+///         // Wait for more bytes to be received...
+///     }
+///
+///     // ...
+/// }
+/// ```
+///
+/// [`ReadError::Other`]: cornflakes::ReadError::Other
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UnexpectedEndOfData {
+	/// The number of bytes needed to read the next value.
+	pub expected: usize,
+	/// The number of bytes which remained.
+	pub found: usize,
+}
+
+impl fmt::Display for UnexpectedEndOfData {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"unexpected end of data: expected {} more bytes, but only {} remain",
+			self.expected, self.found
+		)
+	}
+}
+
+impl Error for UnexpectedEndOfData {}
+
+/// Returns an [`UnexpectedEndOfData`] error if fewer than `expected` bytes
+/// remain in the `reader`.
+///
+/// This is the check generated before reading anything which would otherwise
+/// panic, for `Readable` implementations written by hand.
+pub(crate) fn ensure_remaining(reader: &impl Buf, expected: usize) -> Result<(), ReadError> {
+	if reader.remaining() < expected {
+		return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+			expected,
+			found: reader.remaining(),
+		})));
+	}
+
+	Ok(())
+}
+
+/// The error returned when the number of values in a list of values does not
+/// match the number of bits set in its mask.
+///
+/// Each bit set in the mask of a list of values, such as the list of a
+/// `ConfigureWindow` request, is followed by exactly one 4-byte value. If the
+/// number of values differs, the values can't be matched to their bits.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ValueListMismatch {
+	/// The mask of the list of values.
+	pub mask: u32,
+	/// The number of values which were given.
+	pub found: usize,
+}
+
+impl ValueListMismatch {
+	/// The number of values which the `mask` requires.
+	#[must_use]
+	pub const fn expected(&self) -> usize {
+		self.mask.count_ones() as usize
+	}
+
+	/// The bits set in the `mask` which no value was given for.
+	///
+	/// Values are given in the order of their bits, so these are the highest
+	/// bits set. This is empty if more values were given than required.
+	#[must_use]
+	pub const fn missing_bits(&self) -> u32 {
+		let mut missing = self.mask;
+		let mut matched = self.found;
+
+		// Clear the lowest bits set, each of which was given a value.
+		while missing != 0 && matched > 0 {
+			missing &= missing - 1;
+			matched -= 1;
+		}
+
+		missing
+	}
+}
+
+impl fmt::Display for ValueListMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"value list mask {:#010x} requires {} values, but {} were given",
+			self.mask,
+			self.expected(),
+			self.found
+		)?;
+
+		match self.missing_bits() {
+			0 => Ok(()),
+			missing => write!(f, " (missing values for bits {missing:#010x})"),
+		}
+	}
+}
+
+impl Error for ValueListMismatch {}
+
+/// The error returned when an event is read as a different type of event
+/// than its code says it is.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UnexpectedEventCode {
+	/// The code of the type of event which was to be read.
+	pub expected: u8,
+	/// The code of the event which was found.
+	pub found: u8,
+}
+
+impl fmt::Display for UnexpectedEventCode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"unexpected event code: expected {}, but found {}",
+			self.expected, self.found
+		)
+	}
+}
+
+impl Error for UnexpectedEventCode {}
+
+/// The error returned when a mask is read with bits set which are reserved,
+/// and must be zero, if values are read with [`Strictness::Strict`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UnexpectedReservedBits {
+	/// The name of the type of mask which was read.
+	pub mask: &'static str,
+	/// The reserved bits which were set.
+	pub bits: u32,
+}
+
+impl fmt::Display for UnexpectedReservedBits {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"reserved bits {:#010x} are set in a {}, but must be zero",
+			self.bits, self.mask
+		)
+	}
+}
+
+impl Error for UnexpectedReservedBits {}
+
+/// The error returned when unused padding bytes are not zero, if values are
+/// read with [`Strictness::Strict`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NonzeroPadding {
+	/// The index of the first nonzero byte within the padding.
+	pub index: usize,
+	/// The first nonzero byte.
+	pub byte: u8,
+}
+
+impl fmt::Display for NonzeroPadding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"padding must be zero, but byte {} of it is {:#04x}",
+			self.index, self.byte
+		)
+	}
+}
+
+impl Error for NonzeroPadding {}
+
+/// The header at the start of a message: the fields which precede its items.
+///
+/// A transport can parse the header of a message as soon as its first bytes
+/// arrive, then use it to find how long the message is and where to route it
+/// before the rest of the message is [decoded]. Multi-byte fields are read in
+/// the byte order of the bytes given, so wrap them with [`ByteOrder::reader`]
+/// for the connection's byte order.
+///
+/// # Examples
+/// ```ignore
+/// let header = Header::parse(byte_order.reader(&bytes[..]))?;
+///
+/// match header {
+///     Header::Event { code, .. } if code == MapRequest::code() => {
+///         let event = MapRequest::decode_body(&header, &mut &bytes[header.len()..])?;
+///     }
+///
+///     // ...
+/// }
+/// ```
+///
+/// [decoded]: DecodeBody::decode_body
+/// [`ByteOrder::reader`]: crate::x11::byte_order::ByteOrder::reader
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Header {
+	/// The header of a request.
+	Request {
+		/// The major opcode of the request.
+		major_opcode: u8,
+		/// The second byte, which is used by one of the request's items or,
+		/// for extension requests, is the minor opcode.
+		metabyte: u8,
+		/// The length of the request in 4-byte units, including the header.
+		length: u16,
+	},
+
+	/// The header of a reply.
+	Reply {
+		/// The second byte, which may be used by one of the reply's items.
+		metabyte: u8,
+		/// The sequence number of the request which generated the reply.
+		sequence: u16,
+		/// The number of 4-byte units in the reply after its first 32 bytes.
+		length: u32,
+	},
+
+	/// The header of an error.
+	Error {
+		/// The code of the error.
+		code: u8,
+		/// The sequence number of the request which generated the error.
+		sequence: u16,
+	},
+
+	/// The header of an event other than a `GenericEvent`.
+	Event {
+		/// The code of the event, without the bit set for sent events.
+		code: u8,
+		/// Whether the event was sent with a `SendEvent` request.
+		sent: bool,
+		/// The second byte, which may be used by one of the event's items.
+		metabyte: u8,
+		/// The sequence number of the last request the X server processed.
+		sequence: u16,
+	},
+
+	/// The header of a `GenericEvent`, which may be longer than 32 bytes.
+	GenericEvent {
+		/// The major opcode of the extension which generated the event.
+		extension: u8,
+		/// Whether the event was sent with a `SendEvent` request.
+		sent: bool,
+		/// The sequence number of the last request the X server processed.
+		sequence: u16,
+		/// The number of 4-byte units in the event after its first 32 bytes.
+		length: u32,
+	},
+}
+
+impl Header {
+	/// Parses the header of a request from its first four bytes.
+	///
+	/// The request's `length` is read in the byte order of `bytes`: wrap them
+	/// with [`ByteOrder::reader`] to parse a request sent by a client which
+	/// chose a different byte order.
+	///
+	/// # Errors
+	/// Returns [`UnexpectedEndOfData`] if fewer than four bytes are given.
+	///
+	/// [`ByteOrder::reader`]: crate::x11::byte_order::ByteOrder::reader
+	pub fn parse_request(mut bytes: impl Buf) -> Result<Self, ReadError> {
+		ensure_remaining(&bytes, 4)?;
+
+		Ok(Self::Request {
+			major_opcode: bytes.get_u8(),
+			metabyte: bytes.get_u8(),
+			length: bytes.get_u16(),
+		})
+	}
+
+	/// Parses the header of an error, reply, or event sent by the X server
+	/// from its first eight bytes.
+	///
+	/// The `sequence` and `length` are read in the byte order of `bytes`: wrap
+	/// them with [`ByteOrder::reader`] to parse a message sent on a connection
+	/// with a different byte order.
+	///
+	/// # Errors
+	/// Returns [`UnexpectedEndOfData`] if fewer than eight bytes are given.
+	///
+	/// [`ByteOrder::reader`]: crate::x11::byte_order::ByteOrder::reader
+	pub fn parse(mut bytes: impl Buf) -> Result<Self, ReadError> {
+		ensure_remaining(&bytes, 8)?;
+
+		let (first, second) = (bytes.get_u8(), bytes.get_u8());
+		let sequence = bytes.get_u16();
+		let length = bytes.get_u32();
+
+		let code = first & 0x7f;
+		let sent = first & 0x80 != 0;
+
+		Ok(match first {
+			0 => Self::Error {
+				code: second,
+				sequence,
+			},
+			1 => Self::Reply {
+				metabyte: second,
+				sequence,
+				length,
+			},
+
+			_ if code == 35 => Self::GenericEvent {
+				extension: second,
+				sent,
+				sequence,
+				length,
+			},
+			_ => Self::Event {
+				code,
+				sent,
+				metabyte: second,
+				sequence,
+			},
+		})
+	}
+
+	/// The number of bytes at the start of the message which make up this
+	/// header, after which the message's body is [decoded].
+	///
+	/// This is fewer than the eight bytes parsed for errors and events, as
+	/// only their first four bytes are common to every error and event.
+	///
+	/// [decoded]: DecodeBody::decode_body
+	#[must_use]
+	#[allow(clippy::len_without_is_empty)]
+	pub const fn len(&self) -> usize {
+		match self {
+			Self::Request { .. } | Self::Error { .. } | Self::Event { .. } => 4,
+			Self::Reply { .. } | Self::GenericEvent { .. } => 8,
+		}
+	}
+
+	/// The total length of the message, in bytes, as given by this header.
+	///
+	/// A request with a `length` of `0` uses the BIG-REQUESTS extension, so
+	/// its length is not known from this header.
+	#[must_use]
+	pub const fn message_len(&self) -> usize {
+		match self {
+			Self::Request { length, .. } => *length as usize * 4,
+
+			Self::Reply { length, .. } | Self::GenericEvent { length, .. } => {
+				32 + *length as usize * 4
+			}
+
+			Self::Error { .. } | Self::Event { .. } => 32,
+		}
+	}
+
+	/// The name of the kind of message this is the header of.
+	const fn kind(&self) -> &'static str {
+		match self {
+			Self::Request { .. } => "request",
+			Self::Reply { .. } => "reply",
+			Self::Error { .. } => "error",
+			Self::Event { .. } => "event",
+			Self::GenericEvent { .. } => "generic event",
+		}
+	}
+}
+
+/// A message whose body can be decoded once its [`Header`] has been parsed,
+/// without reading the header again.
+///
+/// This is implemented by `define!` for requests, replies, and events.
+pub trait DecodeBody: Sized {
+	/// Decodes the message with the given `header` from the bytes which
+	/// follow the header, starting [`header.len()`] bytes into the message.
+	///
+	/// # Errors
+	/// Returns an [`UnexpectedHeader`] if the `header` is not of this kind of
+	/// message, or any error encountered while reading the body.
+	///
+	/// [`header.len()`]: Header::len
+	fn decode_body(header: &Header, reader: &mut impl Buf) -> Result<Self, ReadError>;
+}
+
+/// The error returned when a message is [decoded] with the [`Header`] of a
+/// different kind of message.
+///
+/// [decoded]: DecodeBody::decode_body
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UnexpectedHeader {
+	/// The kind of message which was to be decoded.
+	pub expected: &'static str,
+	/// The header which was given.
+	pub found: Header,
+}
+
+impl fmt::Display for UnexpectedHeader {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"unexpected header: expected {}, but found {}",
+			self.expected,
+			self.found.kind()
+		)
+	}
+}
+
+impl Error for UnexpectedHeader {}
+
+/// Skips `len` bytes of padding, which the caller has checked remain in the
+/// `reader`.
+///
+/// This is public so that the unused bytes of types defined with `define!`
+/// outside of XRB can be read.
+///
+/// # Errors
+/// Returns a [`NonzeroPadding`] if values are read with
+/// [`Strictness::Strict`] and any of the bytes are not zero.
+pub fn skip_padding(reader: &mut impl Buf, len: usize) -> Result<(), ReadError> {
+	if Strictness::current() == Strictness::Lenient {
+		reader.advance(len);
+
+		return Ok(());
+	}
+
+	for index in 0..len {
+		match reader.get_u8() {
+			0 => {}
+			byte => return Err(ReadError::Other(Box::new(NonzeroPadding { index, byte }))),
+		}
+	}
+
+	Ok(())
+}
+
+/// The bytes of a `Cow<[u8]>` field of a type defined with `define!`, which
+/// are about to be read from a `reader`.
+///
+/// [`read_cow`] borrows the bytes if the `reader` is a byte slice, and copies
+/// them otherwise. Which it does is chosen by which of [`ReadBorrowedBytes`]
+/// and [`ReadOwnedBytes`] applies: method calls prefer an implementation for
+/// the value itself to one for a reference to it, so `read_cow` is only
+/// [`ReadOwnedBytes::read_cow`] if the reader isn't a byte slice. Both traits
+/// must be in scope.
+///
+/// The caller must have checked that `len` bytes remain in the `reader`.
+///
+/// [`read_cow`]: ReadBorrowedBytes::read_cow
+pub struct CowBytes<'r, R> {
+	reader: &'r mut R,
+	len: usize,
+}
+
+impl<'r, R> CowBytes<'r, R> {
+	/// Prepares to read `len` bytes from the given `reader`.
+	#[must_use]
+	pub fn new(reader: &'r mut R, len: usize) -> Self {
+		Self { reader, len }
+	}
+}
+
+/// Reads [`CowBytes`] borrowed from a byte slice.
+pub trait ReadBorrowedBytes<'a> {
+	/// Reads the bytes, borrowing them from the byte slice and advancing it
+	/// past them.
+	fn read_cow(self) -> Cow<'a, [u8]>;
+}
+
+impl<'a> ReadBorrowedBytes<'a> for CowBytes<'_, &'a [u8]> {
+	fn read_cow(self) -> Cow<'a, [u8]> {
+		let slice: &'a [u8] = self.reader;
+		let (bytes, rest) = slice.split_at(self.len);
+		*self.reader = rest;
+
+		Cow::Borrowed(bytes)
+	}
+}
+
+/// Reads [`CowBytes`] copied from any other reader.
+pub trait ReadOwnedBytes {
+	/// Reads the bytes, copying them from the reader.
+	fn read_cow(self) -> Cow<'static, [u8]>;
+}
+
+impl<R: Buf> ReadOwnedBytes for &mut CowBytes<'_, R> {
+	fn read_cow(self) -> Cow<'static, [u8]> {
+		let mut bytes = vec![0; self.len];
+		self.reader.copy_to_slice(&mut bytes);
+
+		Cow::Owned(bytes)
+	}
+}
+
+/// Checks the reserved `bits` set in a mask of the type named `mask`.
+///
+/// # Errors
+/// Returns an [`UnexpectedReservedBits`] if values are read with
+/// [`Strictness::Strict`] and any `bits` are set.
+pub(crate) fn check_reserved_bits(mask: &'static str, bits: u32) -> Result<(), ReadError> {
+	if bits != 0 && Strictness::current() == Strictness::Strict {
+		return Err(ReadError::Other(Box::new(UnexpectedReservedBits {
+			mask,
+			bits,
+		})));
+	}
+
+	Ok(())
+}
+
+/// Reads the values of a list of values with the given `mask`, which are the
+/// remaining bytes of the `reader`.
+///
+/// # Errors
+/// Returns a [`ValueListMismatch`] if the number of values remaining does not
+/// match the number of bits set in the `mask`, or [`UnexpectedEndOfData`] if
+/// the last value is incomplete.
+pub(crate) fn read_value_list(reader: &mut impl Buf, mask: u32) -> Result<Vec<u32>, ReadError> {
+	let remaining = reader.remaining();
+
+	if !remaining.is_multiple_of(4) {
+		return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+			expected: remaining.next_multiple_of(4),
+			found: remaining,
+		})));
+	}
+
+	let found = remaining / 4;
+
+	if found != mask.count_ones() as usize {
+		return Err(ReadError::Other(Box::new(ValueListMismatch {
+			mask,
+			found,
+		})));
+	}
+
+	Ok((0..found).map(|_| reader.get_u32()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn headers_are_parsed_by_kind() {
+		assert_eq!(
+			Header::parse_request(&[12, 0, 0, 5][..]).unwrap(),
+			Header::Request {
+				major_opcode: 12,
+				metabyte: 0,
+				length: 5
+			}
+		);
+		assert_eq!(
+			Header::parse_request(&[12, 0, 0, 5][..])
+				.unwrap()
+				.message_len(),
+			20
+		);
+
+		let reply = Header::parse(&[1, 8, 0, 42, 0, 0, 0, 2][..]).unwrap();
+		assert_eq!(
+			reply,
+			Header::Reply {
+				metabyte: 8,
+				sequence: 42,
+				length: 2
+			}
+		);
+		assert_eq!((reply.len(), reply.message_len()), (8, 40));
+
+		assert_eq!(
+			Header::parse(&[0, 3, 0, 42, 0, 0, 0, 7][..]).unwrap(),
+			Header::Error {
+				code: 3,
+				sequence: 42
+			}
+		);
+
+		let event = Header::parse(&[0x80 | 20, 0, 0, 42, 0, 0, 0, 9][..]).unwrap();
+		assert_eq!(
+			event,
+			Header::Event {
+				code: 20,
+				sent: true,
+				metabyte: 0,
+				sequence: 42
+			}
+		);
+		assert_eq!((event.len(), event.message_len()), (4, 32));
+
+		assert_eq!(
+			Header::parse(&[35, 131, 0, 42, 0, 0, 0, 1][..])
+				.unwrap()
+				.message_len(),
+			36
+		);
+	}
+
+	#[test]
+	fn truncated_headers_are_not_parsed() {
+		let Err(ReadError::Other(error)) = Header::parse(&[1, 8, 0, 42][..]) else {
+			panic!("a header was parsed from four bytes");
+		};
+		assert!(error.is::<UnexpectedEndOfData>());
+
+		assert!(Header::parse_request(&[12, 0][..]).is_err());
+	}
+
+	#[test]
+	fn value_lists_must_match_their_masks() {
+		let values = [0_u8; 8];
+
+		assert_eq!(read_value_list(&mut &values[..], 0b1001).unwrap(), [0, 0]);
+
+		let Err(ReadError::Other(error)) = read_value_list(&mut &values[..], 0b1101) else {
+			panic!("a value list missing a value was read");
+		};
+		let mismatch = error.downcast_ref::<ValueListMismatch>().unwrap();
+
+		assert_eq!(mismatch.missing_bits(), 0b1000);
+		assert_eq!(
+			mismatch.to_string(),
+			"value list mask 0x0000000d requires 3 values, but 2 were given \
+			 (missing values for bits 0x00000008)"
+		);
+
+		let Err(ReadError::Other(error)) = read_value_list(&mut &values[..], 0b1) else {
+			panic!("a value list with an extra value was read");
+		};
+		assert_eq!(
+			error
+				.downcast_ref::<ValueListMismatch>()
+				.unwrap()
+				.missing_bits(),
+			0
+		);
+	}
+}
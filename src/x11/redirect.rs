@@ -0,0 +1,213 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use crate::x11::{
+	events::{ConfigureRequest, MapRequest},
+	requests::{ConfigureWindow, MapWindow},
+	ConfigureWindowMask, ResId, Window,
+};
+
+impl MapRequest {
+	/// The [`MapWindow`] request which carries out this redirected request
+	/// unchanged.
+	///
+	/// Requests sent by the client which selected `SubstructureRedirect` are
+	/// not redirected, so this request maps the `window`.
+	#[must_use]
+	pub const fn forward(&self) -> MapWindow {
+		MapWindow {
+			target: self.window,
+		}
+	}
+}
+
+impl ConfigureRequest {
+	/// The [`ConfigureWindow`] request which carries out this redirected
+	/// request unchanged.
+	///
+	/// Only the values included in the `value_mask` are given, so values the
+	/// client did not ask to change are left as they are. A window manager
+	/// which changes the values before sending the request, such as to
+	/// constrain the window's size, can [check] that it still gives the same
+	/// values.
+	///
+	/// [check]: ConfigureRequest::check_forwarded
+	#[must_use]
+	pub fn forward(&self) -> ConfigureWindow {
+		ConfigureWindow {
+			target: self.window,
+			values: self.values(),
+		}
+	}
+
+	/// Checks that the given `request` forwards this redirected request: that
+	/// it configures the same `window`, and gives exactly the values included
+	/// in the `value_mask`.
+	///
+	/// The values themselves may differ. Giving a value the client did not
+	/// ask to change overrides the window's current value, and leaving out a
+	/// value the client gave ignores it; both are easy to do by accident when
+	/// building the request by hand.
+	///
+	/// # Errors
+	/// Returns a [`ForwardMismatch`] describing the first way in which the
+	/// `request` differs.
+	pub fn check_forwarded(&self, request: &ConfigureWindow) -> Result<(), ForwardMismatch> {
+		if request.target != self.window {
+			return Err(ForwardMismatch::Window {
+				expected: self.window,
+				found: request.target,
+			});
+		}
+
+		let mask = request.values.mask();
+
+		if mask != self.value_mask {
+			return Err(ForwardMismatch::Mask {
+				expected: self.value_mask,
+				found: mask,
+			});
+		}
+
+		// The X server generates a `Match` error for a `sibling` given
+		// without a `stack_mode`.
+		if mask.contains(ConfigureWindowMask::SIBLING)
+			&& !mask.contains(ConfigureWindowMask::STACK_MODE)
+		{
+			return Err(ForwardMismatch::SiblingWithoutStackMode);
+		}
+
+		Ok(())
+	}
+}
+
+/// The error returned when a [`ConfigureWindow`] request does not forward a
+/// [`ConfigureRequest`] event, as found by
+/// [`ConfigureRequest::check_forwarded`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ForwardMismatch {
+	/// The request configures a different window.
+	Window {
+		/// The window which was to be configured.
+		expected: Window,
+		/// The window which the request configures.
+		found: Window,
+	},
+
+	/// The request gives different values.
+	Mask {
+		/// The values which were given in the redirected request.
+		expected: ConfigureWindowMask,
+		/// The values which are given in the forwarded request.
+		found: ConfigureWindowMask,
+	},
+
+	/// The request gives a `sibling` without a `stack_mode`, which the X
+	/// server does not allow.
+	SiblingWithoutStackMode,
+}
+
+impl fmt::Display for ForwardMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Window { expected, found } => write!(
+				f,
+				"forwarded request configures window {:#x} instead of {:#x}",
+				found.res_id(),
+				expected.res_id()
+			),
+
+			Self::Mask { expected, found } => write!(
+				f,
+				"forwarded request gives values {found:?} instead of {expected:?}"
+			),
+
+			Self::SiblingWithoutStackMode => {
+				write!(f, "forwarded request gives a sibling without a stack mode")
+			}
+		}
+	}
+}
+
+impl Error for ForwardMismatch {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::StackMode;
+
+	fn request(value_mask: ConfigureWindowMask, sibling: Option<Window>) -> ConfigureRequest {
+		ConfigureRequest {
+			_sequence_: 0,
+			stack_mode: StackMode::Below,
+			parent: Window::new(1),
+			window: Window::new(2),
+			sibling,
+			x: 10,
+			y: 20,
+			width: 300,
+			height: 200,
+			border_width: 1,
+			value_mask,
+		}
+	}
+
+	#[test]
+	fn requests_are_forwarded_with_the_same_mask() {
+		let event = request(
+			ConfigureWindowMask::WIDTH
+				| ConfigureWindowMask::HEIGHT
+				| ConfigureWindowMask::SIBLING
+				| ConfigureWindowMask::STACK_MODE,
+			Some(Window::new(3)),
+		);
+
+		let mut forwarded = event.forward();
+		assert_eq!(forwarded.target, Window::new(2));
+		assert_eq!(forwarded.values.x, None);
+		assert_eq!(forwarded.values.width, Some(300));
+		assert_eq!(forwarded.values.sibling, Some(Window::new(3)));
+		assert_eq!(event.check_forwarded(&forwarded), Ok(()));
+
+		// Constraining the size keeps the same values.
+		forwarded.values = forwarded.values.size(200, 100);
+		assert_eq!(event.check_forwarded(&forwarded), Ok(()));
+
+		// Moving the window gives values the client did not.
+		forwarded.values = forwarded.values.position(0, 0);
+		assert_eq!(
+			event.check_forwarded(&forwarded),
+			Err(ForwardMismatch::Mask {
+				expected: event.value_mask,
+				found: event.value_mask | ConfigureWindowMask::X | ConfigureWindowMask::Y,
+			})
+		);
+
+		let map = MapRequest {
+			_sequence_: 0,
+			parent: Window::new(1),
+			window: Window::new(2),
+		};
+		assert_eq!(map.forward().target, Window::new(2));
+	}
+
+	#[test]
+	fn mismatches_are_found() {
+		let event = request(ConfigureWindowMask::SIBLING, Some(Window::new(3)));
+
+		let mut forwarded = event.forward();
+		assert_eq!(
+			event.check_forwarded(&forwarded),
+			Err(ForwardMismatch::SiblingWithoutStackMode)
+		);
+
+		forwarded.target = Window::new(4);
+		assert_eq!(
+			event.check_forwarded(&forwarded).unwrap_err().to_string(),
+			"forwarded request configures window 0x4 instead of 0x2"
+		);
+	}
+}
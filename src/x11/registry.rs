@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use cornflakes::{ReadError, Readable};
+
+use crate::x11::{errors, events::*, traits::Event};
+
+/// Returns the name of the core request with the given `major_opcode`, such
+/// as `ConfigureWindow`, if it is known.
+///
+/// Extension requests are named by [`errors::request_name`], as their major
+/// opcodes are only known once the connection has queried them.
+#[must_use]
+pub const fn request_name(major_opcode: u8) -> Option<&'static str> {
+	errors::core_request_name(major_opcode)
+}
+
+/// Returns the name of the core error with the given `code`, such as
+/// `BadWindow`, if it is known.
+///
+/// Extension errors are named by [`errors::error_name`].
+#[must_use]
+pub const fn error_name(code: u8) -> Option<&'static str> {
+	match code.checked_sub(1) {
+		Some(index) if (index as usize) < errors::CORE_ERRORS.len() => {
+			Some(errors::CORE_ERRORS[index as usize].0)
+		}
+
+		_ => None,
+	}
+}
+
+/// Returns the name of the core event with the given `code`, such as
+/// `MapRequest`, if it is known.
+///
+/// The bit set in the codes of events sent with `SendEvent` requests is
+/// ignored.
+#[must_use]
+pub const fn event_name(code: u8) -> Option<&'static str> {
+	core_event_name(code & 0x7f)
+}
+
+// Generates `DecodedEvent` and `decode_event` for the given event types.
+macro_rules! decoded_event {
+	($($event:ident,)*) => {
+		/// An event of any of the types defined in [`events`], as decoded by
+		/// [`decode_event`].
+		///
+		/// Unlike an [`AnyEvent`], which holds the encoded bytes of an event,
+		/// this holds the event itself.
+		///
+		/// [`events`]: crate::x11::events
+		/// [`AnyEvent`]: crate::x11::AnyEvent
+		#[non_exhaustive]
+		pub enum DecodedEvent {
+			$(
+				#[doc = concat!("A [`", stringify!($event), "`] event.")]
+				$event($event),
+			)*
+		}
+
+		impl DecodedEvent {
+			/// The code of the event's type.
+			#[must_use]
+			pub fn code(&self) -> u8 {
+				match self {
+					$(Self::$event(_) => <$event as Event>::code(),)*
+				}
+			}
+
+			/// The name of the event's type, such as `MapRequest`.
+			#[must_use]
+			pub const fn name(&self) -> &'static str {
+				match self {
+					$(Self::$event(_) => stringify!($event),)*
+				}
+			}
+		}
+
+		/// Decodes the event with the given `code` from its `bytes`,
+		/// following its code.
+		///
+		/// The bit set in the codes of events sent with `SendEvent` requests
+		/// is ignored. Returns [`None`] if no type of event with the given
+		/// `code` is defined in [`events`]; its name may still be known with
+		/// [`event_name`].
+		///
+		/// # Errors
+		/// Returns an error if the `bytes` could not be read as the event.
+		///
+		/// [`events`]: crate::x11::events
+		pub fn decode_event(code: u8, mut bytes: &[u8]) -> Result<Option<DecodedEvent>, ReadError> {
+			let code = code & 0x7f;
+
+			$(
+				if code == <$event as Event>::code() {
+					return $event::read_from(&mut bytes)
+						.map(|event| Some(DecodedEvent::$event(event)));
+				}
+			)*
+
+			Ok(None)
+		}
+	};
+}
+
+decoded_event! {
+	ButtonPress,
+	ButtonRelease,
+	DestroyNotify,
+	UnmapNotify,
+	MapRequest,
+	ConfigureRequest,
+	CirculateNotify,
+	CirculateRequest,
+	PropertyNotify,
+	SelectionClear,
+	SelectionRequest,
+	SelectionNotify,
+	ColormapNotify,
+}
+
+/// Returns the name of the core event with the given `code`.
+const fn core_event_name(code: u8) -> Option<&'static str> {
+	Some(match code {
+		2 => "KeyPress",
+		3 => "KeyRelease",
+		4 => "ButtonPress",
+		5 => "ButtonRelease",
+		6 => "MotionNotify",
+		7 => "EnterNotify",
+		8 => "LeaveNotify",
+		9 => "FocusIn",
+		10 => "FocusOut",
+		11 => "KeymapNotify",
+		12 => "Expose",
+		13 => "GraphicsExposure",
+		14 => "NoExposure",
+		15 => "VisibilityNotify",
+		16 => "CreateNotify",
+		17 => "DestroyNotify",
+		18 => "UnmapNotify",
+		19 => "MapNotify",
+		20 => "MapRequest",
+		21 => "ReparentNotify",
+		22 => "ConfigureNotify",
+		23 => "ConfigureRequest",
+		24 => "GravityNotify",
+		25 => "ResizeRequest",
+		26 => "CirculateNotify",
+		27 => "CirculateRequest",
+		28 => "PropertyNotify",
+		29 => "SelectionClear",
+		30 => "SelectionRequest",
+		31 => "SelectionNotify",
+		32 => "ColormapNotify",
+		33 => "ClientMessage",
+		34 => "MappingNotify",
+		35 => "GenericEvent",
+
+		_ => return None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::Window;
+
+	// The names are known at compile time.
+	const CONFIGURE_WINDOW: Option<&str> = request_name(12);
+
+	#[test]
+	fn codes_are_named() {
+		assert_eq!(CONFIGURE_WINDOW, Some("ConfigureWindow"));
+		assert_eq!(request_name(120), None);
+
+		assert_eq!(error_name(3), Some("BadWindow"));
+		assert_eq!(error_name(17), Some("BadImplementation"));
+		assert_eq!(error_name(0), None);
+		assert_eq!(error_name(18), None);
+
+		assert_eq!(event_name(20), Some("MapRequest"));
+		assert_eq!(event_name(0x80 | 33), Some("ClientMessage"));
+		assert_eq!(event_name(1), None);
+	}
+
+	#[test]
+	fn events_are_decoded_by_code() {
+		let mut bytes = [0; 31];
+		// The sequence number, `parent`, and `window` follow the unused
+		// metabyte.
+		bytes[1..3].copy_from_slice(&7u16.to_be_bytes());
+		bytes[3..7].copy_from_slice(&0x20u32.to_be_bytes());
+		bytes[7..11].copy_from_slice(&0x40_0001u32.to_be_bytes());
+
+		let Some(DecodedEvent::MapRequest(event)) = decode_event(0x80 | 20, &bytes).unwrap() else {
+			panic!("a `MapRequest` event was not decoded");
+		};
+		assert_eq!(event.window, Window::new(0x40_0001));
+
+		let event = decode_event(20, &bytes).unwrap().unwrap();
+		assert_eq!((event.code(), event.name()), (20, "MapRequest"));
+
+		// `Expose` is named, but not defined.
+		assert!(decode_event(12, &bytes).unwrap().is_none());
+		assert!(decode_event(20, &bytes[..10]).is_err());
+	}
+}
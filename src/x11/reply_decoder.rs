@@ -0,0 +1,394 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{any::Any, collections::HashMap, marker::PhantomData};
+
+use cornflakes::{ReadError, Readable};
+
+use crate::x11::{
+	decode_error::{DecodeContext, DecodeError, MessageKind},
+	read::UnexpectedEndOfData,
+	traits::{MultiReply, Reply, Request},
+};
+
+/// Reads a reply of type `R`, boxing it so that replies of different types can
+/// be returned from [`ReplyDecoder::decode`].
+///
+/// Also returns whether more replies follow for the same request.
+type Decode = fn(&mut &[u8]) -> Result<(Box<dyn Any>, bool), ReadError>;
+
+/// A registered request whose reply has not yet been decoded.
+#[derive(Copy, Clone, Debug)]
+struct Pending {
+	/// How to read the request's reply.
+	decode: Decode,
+	/// The major opcode of the request.
+	major_opcode: u8,
+	/// The minor opcode of the request, if it has one.
+	minor_opcode: Option<u8>,
+}
+
+/// Deserializes replies into the reply type of the request which generated
+/// them.
+///
+/// Replies only identify their request by its sequence number, so the type of
+/// a reply can't be known from its bytes alone. When a request which
+/// generates a reply is sent, it is [`register`]ed with its sequence number;
+/// when a reply is received, [`decode`] reads it as the reply type of the
+/// request registered with that sequence number.
+///
+/// If an error is received in place of a reply, the request should be
+/// [`discard`]ed, as no reply will follow.
+///
+/// # Examples
+/// ```ignore
+/// decoder.register(sequence, PhantomData::<GetGeometry>);
+///
+/// // ...
+///
+/// if let Some(reply) = decoder.decode(&bytes) {
+///     let geometry: GetGeometryReply = reply?.downcast().unwrap();
+/// }
+/// ```
+///
+/// [`register`]: ReplyDecoder::register
+/// [`decode`]: ReplyDecoder::decode
+/// [`discard`]: ReplyDecoder::discard
+#[derive(Clone, Debug, Default)]
+pub struct ReplyDecoder {
+	/// The request registered with each sequence number.
+	pending: HashMap<u16, Pending>,
+}
+
+impl ReplyDecoder {
+	/// Creates a new [`ReplyDecoder`] with no registered requests.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers that a request of type `Req` was sent with the given
+	/// `sequence` number, so that its reply can be [`decode`]d.
+	///
+	/// [`decode`]: ReplyDecoder::decode
+	pub fn register<Req, Rep>(&mut self, sequence: u16, _request: PhantomData<Req>)
+	where
+		Req: Request<Rep>,
+		Rep: Reply<Req> + Readable + 'static,
+	{
+		self.pending
+			.insert(sequence, Pending::new::<Req, Rep>(decode::<Rep>));
+	}
+
+	/// Registers that a request of type `Req`, which generates a series of
+	/// replies, was sent with the given `sequence` number, so that its replies
+	/// can be [`decode`]d.
+	///
+	/// The request remains registered until its last reply is decoded.
+	///
+	/// [`decode`]: ReplyDecoder::decode
+	pub fn register_multi<Req, Rep>(&mut self, sequence: u16, _request: PhantomData<Req>)
+	where
+		Req: Request<Rep>,
+		Rep: MultiReply<Req> + Readable + 'static,
+	{
+		self.pending
+			.insert(sequence, Pending::new::<Req, Rep>(decode_multi::<Req, Rep>));
+	}
+
+	/// Stops waiting for the reply to the request with the given `sequence`
+	/// number, such as when an error was generated instead.
+	///
+	/// Returns whether a request was registered with that `sequence` number.
+	pub fn discard(&mut self, sequence: u16) -> bool {
+		self.pending.remove(&sequence).is_some()
+	}
+
+	/// Returns whether a request is registered with the given `sequence`
+	/// number and its reply has not yet been [`decode`]d.
+	///
+	/// [`decode`]: ReplyDecoder::decode
+	#[must_use]
+	pub fn is_pending(&self, sequence: u16) -> bool {
+		self.pending.contains_key(&sequence)
+	}
+
+	/// The number of requests whose replies have not yet been [`decode`]d.
+	///
+	/// [`decode`]: ReplyDecoder::decode
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.pending.len()
+	}
+
+	/// Returns whether there are no requests whose replies have not yet been
+	/// [`decode`]d.
+	///
+	/// [`decode`]: ReplyDecoder::decode
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pending.is_empty()
+	}
+
+	/// Reads the given reply `bytes`, including the first byte, as the reply
+	/// type of the request registered with its sequence number.
+	///
+	/// Returns [`None`] if no request is registered with the reply's sequence
+	/// number. Otherwise, the request is no longer registered once its reply
+	/// has been decoded, unless it was [`register_multi`]ed and this was not
+	/// its last reply.
+	///
+	/// # Errors
+	/// Returns a [`DecodeError`] if the reply could not be read, with the
+	/// opcodes and sequence number of its request where they are known.
+	///
+	/// [`register_multi`]: ReplyDecoder::register_multi
+	pub fn decode(&mut self, bytes: &[u8]) -> Option<Result<DecodedReply, DecodeError>> {
+		// The sequence number follows the first byte and the metabyte.
+		let Some(&[high, low]) = bytes.get(2..4) else {
+			let context = DecodeContext {
+				message_kind: MessageKind::Reply,
+				major_opcode: None,
+				minor_opcode: None,
+				sequence: None,
+				offset: bytes.len(),
+			};
+
+			return Some(Err(DecodeError::new(
+				context,
+				ReadError::Other(Box::new(UnexpectedEndOfData {
+					expected: 4,
+					found: bytes.len(),
+				})),
+			)));
+		};
+		let sequence = u16::from_be_bytes([high, low]);
+
+		let pending = self.pending.remove(&sequence)?;
+
+		// The first byte (`1` for replies) is not read by the reply itself.
+		let reader = &mut &bytes[1..];
+
+		Some(match (pending.decode)(reader) {
+			Ok((reply, more)) => {
+				if more {
+					self.pending.insert(sequence, pending);
+				}
+
+				Ok(DecodedReply { sequence, reply })
+			}
+
+			Err(error) => {
+				let context = DecodeContext {
+					message_kind: MessageKind::Reply,
+					major_opcode: Some(pending.major_opcode),
+					minor_opcode: pending.minor_opcode,
+					sequence: Some(sequence),
+					offset: bytes.len() - reader.len(),
+				};
+
+				Err(DecodeError::new(context, error))
+			}
+		})
+	}
+}
+
+impl Pending {
+	fn new<Req: Request<Rep>, Rep>(decode: Decode) -> Self {
+		Self {
+			decode,
+			major_opcode: Req::major_opcode(),
+			minor_opcode: Req::minor_opcode(),
+		}
+	}
+}
+
+fn decode<R: Readable + 'static>(reader: &mut &[u8]) -> Result<(Box<dyn Any>, bool), ReadError> {
+	Ok((Box::new(R::read_from(reader)?), false))
+}
+
+fn decode_multi<Req, Rep>(reader: &mut &[u8]) -> Result<(Box<dyn Any>, bool), ReadError>
+where
+	Req: Request<Rep>,
+	Rep: MultiReply<Req> + Readable + 'static,
+{
+	let reply = Rep::read_from(reader)?;
+	let more = !reply.is_last();
+
+	Ok((Box::new(reply), more))
+}
+
+/// A reply read by a [`ReplyDecoder`], which can be [`downcast`] to its
+/// concrete type.
+///
+/// [`downcast`]: DecodedReply::downcast
+#[derive(Debug)]
+pub struct DecodedReply {
+	sequence: u16,
+	reply: Box<dyn Any>,
+}
+
+impl DecodedReply {
+	/// The sequence number of the request which generated this reply.
+	#[must_use]
+	pub const fn sequence(&self) -> u16 {
+		self.sequence
+	}
+
+	/// Returns whether this reply is of type `R`.
+	#[must_use]
+	pub fn is<R: 'static>(&self) -> bool {
+		self.reply.is::<R>()
+	}
+
+	/// Returns this reply as its concrete type `R`, or returns it unchanged if
+	/// it is not of type `R`.
+	///
+	/// # Errors
+	/// Returns `self` if this reply is not of type `R`.
+	pub fn downcast<R: 'static>(self) -> Result<R, Self> {
+		let sequence = self.sequence;
+
+		self.reply
+			.downcast()
+			.map(|reply| *reply)
+			.map_err(|reply| Self { sequence, reply })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use bytes::Buf;
+
+	struct GetCount;
+
+	#[derive(Debug, PartialEq, Eq)]
+	struct GetCountReply {
+		sequence: u16,
+		count: u32,
+	}
+
+	impl Request<GetCountReply> for GetCount {
+		fn major_opcode() -> u8 {
+			1
+		}
+
+		fn minor_opcode() -> Option<u8> {
+			None
+		}
+
+		fn length(&self) -> u16 {
+			1
+		}
+	}
+
+	impl Reply<GetCount> for GetCountReply {
+		fn length(&self) -> u32 {
+			0
+		}
+
+		fn sequence(&self) -> Option<u16> {
+			Some(self.sequence)
+		}
+	}
+
+	// When `GetCount` generates a series of replies, the last has a count
+	// of `0`.
+	impl MultiReply<GetCount> for GetCountReply {
+		fn is_last(&self) -> bool {
+			self.count == 0
+		}
+	}
+
+	impl Readable for GetCountReply {
+		fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+			if reader.remaining() < 11 {
+				return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+					expected: 11,
+					found: reader.remaining(),
+				})));
+			}
+
+			reader.advance(1);
+			let sequence = reader.get_u16();
+			reader.advance(4);
+			let count = reader.get_u32();
+
+			Ok(Self { sequence, count })
+		}
+	}
+
+	fn reply(sequence: u16, count: u32) -> Vec<u8> {
+		let mut bytes = vec![1, 0];
+		bytes.extend(sequence.to_be_bytes());
+		bytes.extend([0; 4]);
+		bytes.extend(count.to_be_bytes());
+
+		bytes
+	}
+
+	#[test]
+	fn replies_are_decoded_as_their_request_reply_type() {
+		let mut decoder = ReplyDecoder::new();
+		decoder.register(7, PhantomData::<GetCount>);
+
+		let result = decoder.decode(&reply(7, 3)).unwrap().unwrap();
+
+		assert_eq!(result.sequence(), 7);
+		assert_eq!(
+			result.downcast::<GetCountReply>().unwrap(),
+			GetCountReply {
+				sequence: 7,
+				count: 3
+			}
+		);
+		assert!(decoder.is_empty());
+	}
+
+	#[test]
+	fn unregistered_replies_are_not_decoded() {
+		let mut decoder = ReplyDecoder::new();
+		decoder.register(7, PhantomData::<GetCount>);
+
+		assert!(decoder.decode(&reply(8, 3)).is_none());
+		assert!(decoder.discard(7));
+		assert!(decoder.decode(&reply(7, 3)).is_none());
+	}
+
+	#[test]
+	fn series_of_replies_are_decoded_until_the_last() {
+		let mut decoder = ReplyDecoder::new();
+		decoder.register_multi(7, PhantomData::<GetCount>);
+
+		for count in [2, 1] {
+			assert!(decoder.decode(&reply(7, count)).unwrap().is_ok());
+			assert!(decoder.is_pending(7));
+		}
+
+		assert!(decoder.decode(&reply(7, 0)).unwrap().is_ok());
+		assert!(decoder.is_empty());
+	}
+
+	#[test]
+	fn errors_have_the_context_of_their_request() {
+		let mut decoder = ReplyDecoder::new();
+		decoder.register(7, PhantomData::<GetCount>);
+
+		let error = decoder.decode(&reply(7, 3)[..8]).unwrap().unwrap_err();
+
+		assert_eq!(
+			error.context,
+			DecodeContext {
+				message_kind: MessageKind::Reply,
+				major_opcode: Some(1),
+				minor_opcode: None,
+				sequence: Some(7),
+				offset: 1,
+			}
+		);
+		assert!(decoder.is_empty());
+	}
+}
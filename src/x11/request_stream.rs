@@ -0,0 +1,441 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{any::Any, borrow::Cow, collections::HashMap, marker::PhantomData};
+
+use bytes::Buf;
+use cornflakes::{ReadError, Readable};
+
+use crate::x11::{
+	byte_order::{ByteOrder, OrderedReader},
+	decode_error::{DecodeContext, DecodeError, MessageKind},
+	read::UnexpectedEndOfData,
+	traits::Request,
+};
+
+/// Reads a request of type `R`, boxing it so that requests of different types
+/// can be returned from [`RequestStreamDecoder::next_request`].
+type Decode = fn(&mut OrderedReader<&[u8]>) -> Result<Box<dyn Any>, ReadError>;
+
+/// Splits the requests sent by a client into individual requests, and
+/// deserializes those whose types have been [`register`]ed.
+///
+/// This is used by X servers, which receive requests from each client one
+/// after another on the same connection. Each request is assigned the next
+/// sequence number, starting from `1` and wrapping after `65535`, regardless
+/// of whether it could be decoded. Requests are read in the [`ByteOrder`]
+/// which the client chose in its connection setup.
+///
+/// # Examples
+/// ```ignore
+/// let mut decoder = RequestStreamDecoder::new(byte_order);
+/// decoder.register(PhantomData::<MapWindow>);
+///
+/// let mut unread = &received[..];
+///
+/// for request in decoder.decode(&mut unread) {
+///     let request = request?;
+///
+///     if let Some(map_window) = request.downcast_ref::<MapWindow>() {
+///         // ...
+///     }
+/// }
+///
+/// // `unread` now contains the start of a request which hasn't been
+/// // completely received yet.
+/// ```
+///
+/// [`register`]: RequestStreamDecoder::register
+#[derive(Clone, Debug)]
+pub struct RequestStreamDecoder {
+	/// The byte order in which the client sends requests.
+	byte_order: ByteOrder,
+	/// How to read each registered type of request, by its major opcode and,
+	/// if it has one, minor opcode.
+	decoders: HashMap<(u8, Option<u8>), Decode>,
+	/// The sequence number of the last request.
+	sequence: u16,
+	/// Whether the BIG-REQUESTS extension has been enabled for this client.
+	big_requests: bool,
+}
+
+/// A request split from a client's requests by a [`RequestStreamDecoder`].
+#[derive(Debug)]
+pub struct DecodedRequest<'a> {
+	sequence: u16,
+	bytes: &'a [u8],
+	request: Option<Box<dyn Any>>,
+}
+
+impl RequestStreamDecoder {
+	/// Creates a new [`RequestStreamDecoder`] for a client which has not yet
+	/// sent any requests, and which sends them in the given `byte_order`.
+	#[must_use]
+	pub fn new(byte_order: ByteOrder) -> Self {
+		Self {
+			byte_order,
+			decoders: HashMap::new(),
+			sequence: 0,
+			big_requests: false,
+		}
+	}
+
+	/// Registers a type of request to be deserialized when it is received.
+	///
+	/// Requests are identified by their major opcode and minor opcode, so
+	/// extension requests must be registered within the [`scope`] of the
+	/// client's [`Extensions`], once the extension's codes are known.
+	///
+	/// [`Extensions`]: crate::x11::extensions::Extensions
+	/// [`scope`]: crate::x11::extensions::Extensions::scope
+	pub fn register<Req, Rep>(&mut self, _request: PhantomData<Req>)
+	where
+		Req: Request<Rep> + Readable + 'static,
+	{
+		self.decoders
+			.insert((Req::major_opcode(), Req::minor_opcode()), decode::<Req>);
+	}
+
+	/// Enables the extended request lengths of the BIG-REQUESTS extension.
+	///
+	/// This should be done once the client has sent a `BigReqEnable` request.
+	/// A request with a length of `0` is then followed by its length as a
+	/// `u32`.
+	pub const fn enable_big_requests(&mut self) {
+		self.big_requests = true;
+	}
+
+	/// The sequence number of the last request, or `0` if no requests have
+	/// been received.
+	#[must_use]
+	pub const fn sequence(&self) -> u16 {
+		self.sequence
+	}
+
+	/// Splits the next request from the start of the given `bytes`, and
+	/// deserializes it if its type has been [`register`]ed.
+	///
+	/// Returns [`None`] if the whole request has not yet been received.
+	/// Otherwise, `bytes` is advanced past the request.
+	///
+	/// # Errors
+	/// If a registered request could not be read, or the request's length is
+	/// too short for its header (such as a length of `0` without BIG-REQUESTS
+	/// enabled), a [`DecodeError`] is returned. In the latter case, the
+	/// requests which follow can't be found, and so the client should be
+	/// disconnected.
+	///
+	/// [`register`]: RequestStreamDecoder::register
+	pub fn next_request<'a>(
+		&mut self,
+		bytes: &mut &'a [u8],
+	) -> Option<Result<DecodedRequest<'a>, DecodeError>> {
+		if bytes.len() < 4 {
+			return None;
+		}
+
+		let (major_opcode, minor_opcode) = (bytes[0], bytes[1]);
+
+		// The length of the request in bytes, and the length of its header.
+		let (len, header_len) = match self.byte_order.reader(&bytes[2..4]).get_u16() {
+			0 if self.big_requests => {
+				if bytes.len() < 8 {
+					return None;
+				}
+
+				let len = self.byte_order.reader(&bytes[4..8]).get_u32();
+
+				((len as usize).saturating_mul(4), 8)
+			}
+
+			len => (usize::from(len) * 4, 4),
+		};
+
+		// The request is too short to contain its own header, so only the
+		// header can be skipped.
+		if len < header_len {
+			*bytes = &bytes[header_len..];
+			self.sequence = self.sequence.wrapping_add(1);
+
+			let context = DecodeContext {
+				message_kind: MessageKind::Request,
+				major_opcode: Some(major_opcode),
+				minor_opcode: None,
+				sequence: Some(self.sequence),
+				// The length is the problem, rather than anything read after it.
+				offset: 2,
+			};
+
+			return Some(Err(DecodeError::new(
+				context,
+				ReadError::Other(Box::new(UnexpectedEndOfData {
+					expected: header_len,
+					found: len,
+				})),
+			)));
+		}
+
+		if bytes.len() < len {
+			return None;
+		}
+
+		let (request, rest) = bytes.split_at(len);
+		*bytes = rest;
+		self.sequence = self.sequence.wrapping_add(1);
+
+		let decoded = |request| DecodedRequest {
+			sequence: self.sequence,
+			bytes: request,
+			request: None,
+		};
+
+		let decode = self
+			.decoders
+			.get(&(major_opcode, Some(minor_opcode)))
+			.map(|decode| (decode, Some(minor_opcode)))
+			.or_else(|| {
+				self.decoders
+					.get(&(major_opcode, None))
+					.map(|decode| (decode, None))
+			});
+
+		let Some((decode, minor_opcode)) = decode else {
+			return Some(Ok(decoded(request)));
+		};
+
+		// The major opcode is not read by the request itself. With an extended
+		// length, the request is read as though its length were `0`.
+		let body: Cow<[u8]> = if header_len == 8 {
+			Cow::Owned([&request[1..4], &request[8..]].concat())
+		} else {
+			Cow::Borrowed(&request[1..])
+		};
+
+		let reader = &mut self.byte_order.reader(&body[..]);
+
+		Some(match decode(reader) {
+			Ok(decoded_request) => Ok(DecodedRequest {
+				request: Some(decoded_request),
+				..decoded(request)
+			}),
+
+			Err(error) => {
+				// The offset of the error within the body, which doesn't
+				// include the major opcode or an extended length.
+				let offset = body.len() - reader.remaining();
+
+				let context = DecodeContext {
+					message_kind: MessageKind::Request,
+					major_opcode: Some(major_opcode),
+					minor_opcode,
+					sequence: Some(self.sequence),
+					offset: if header_len == 8 && offset >= 3 {
+						offset + 5
+					} else {
+						offset + 1
+					},
+				};
+
+				Err(DecodeError::new(context, error))
+			}
+		})
+	}
+
+	/// Returns an iterator over the requests at the start of the given
+	/// `bytes`, as returned by [`next_request`].
+	///
+	/// The iterator ends once the rest of the `bytes` don't contain a whole
+	/// request.
+	///
+	/// [`next_request`]: RequestStreamDecoder::next_request
+	pub fn decode<'a, 'b>(
+		&'a mut self,
+		bytes: &'a mut &'b [u8],
+	) -> impl Iterator<Item = Result<DecodedRequest<'b>, DecodeError>> + 'a {
+		std::iter::from_fn(move || self.next_request(bytes))
+	}
+}
+
+fn decode<R: Readable + 'static>(
+	reader: &mut OrderedReader<&[u8]>,
+) -> Result<Box<dyn Any>, ReadError> {
+	Ok(Box::new(R::read_from(reader)?))
+}
+
+impl<'a> DecodedRequest<'a> {
+	/// The sequence number assigned to this request.
+	#[must_use]
+	pub const fn sequence(&self) -> u16 {
+		self.sequence
+	}
+
+	/// The major opcode of this request.
+	#[must_use]
+	pub const fn major_opcode(&self) -> u8 {
+		self.bytes[0]
+	}
+
+	/// The byte following the major opcode, which is the minor opcode of
+	/// extension requests.
+	#[must_use]
+	pub const fn minor_opcode(&self) -> u8 {
+		self.bytes[1]
+	}
+
+	/// The bytes of this request, including its header.
+	#[must_use]
+	pub const fn bytes(&self) -> &'a [u8] {
+		self.bytes
+	}
+
+	/// Returns whether this request's type was [`register`]ed, and so it was
+	/// deserialized.
+	///
+	/// [`register`]: RequestStreamDecoder::register
+	#[must_use]
+	pub const fn is_decoded(&self) -> bool {
+		self.request.is_some()
+	}
+
+	/// Returns this request as its concrete type `R`, if it was deserialized
+	/// as that type.
+	#[must_use]
+	pub fn downcast_ref<R: 'static>(&self) -> Option<&R> {
+		self.request.as_ref()?.downcast_ref()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Debug, PartialEq, Eq)]
+	struct Ping {
+		value: u32,
+	}
+
+	impl Request for Ping {
+		fn major_opcode() -> u8 {
+			200
+		}
+
+		fn minor_opcode() -> Option<u8> {
+			None
+		}
+
+		fn length(&self) -> u16 {
+			2
+		}
+	}
+
+	impl Readable for Ping {
+		fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+			if reader.remaining() < 7 {
+				return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+					expected: 7,
+					found: reader.remaining(),
+				})));
+			}
+
+			reader.advance(3);
+			let value = reader.get_u32();
+
+			Ok(Self { value })
+		}
+	}
+
+	#[test]
+	fn requests_are_split_and_counted() {
+		let mut decoder = RequestStreamDecoder::new(ByteOrder::MostSignificantFirst);
+		decoder.register(PhantomData::<Ping>);
+
+		// `Ping`, an unregistered request, and the start of another `Ping`.
+		let bytes = [200, 0, 0, 2, 0, 0, 0, 7, 8, 0, 0, 1, 200, 0];
+		let mut unread = &bytes[..];
+
+		let requests: Vec<_> = decoder.decode(&mut unread).map(Result::unwrap).collect();
+
+		assert_eq!(requests.len(), 2);
+		assert_eq!(requests[0].sequence(), 1);
+		assert_eq!(requests[0].downcast_ref(), Some(&Ping { value: 7 }));
+		assert_eq!(requests[1].sequence(), 2);
+		assert_eq!(requests[1].major_opcode(), 8);
+		assert!(!requests[1].is_decoded());
+		assert_eq!(unread, [200, 0]);
+	}
+
+	#[test]
+	fn big_requests_have_extended_lengths() {
+		let mut decoder = RequestStreamDecoder::new(ByteOrder::MostSignificantFirst);
+		decoder.register(PhantomData::<Ping>);
+		decoder.enable_big_requests();
+
+		let bytes = [200, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 9];
+		let mut unread = &bytes[..];
+
+		let request = decoder.next_request(&mut unread).unwrap().unwrap();
+
+		assert_eq!(request.downcast_ref(), Some(&Ping { value: 9 }));
+		assert!(unread.is_empty());
+	}
+
+	#[test]
+	fn requests_are_read_in_the_clients_byte_order() {
+		let mut decoder = RequestStreamDecoder::new(ByteOrder::LeastSignificantFirst);
+		decoder.register(PhantomData::<Ping>);
+
+		let bytes = [200, 0, 2, 0, 7, 0, 0, 0];
+		let mut unread = &bytes[..];
+
+		let request = decoder.next_request(&mut unread).unwrap().unwrap();
+
+		assert_eq!(request.bytes().len(), 8);
+		assert_eq!(request.downcast_ref(), Some(&Ping { value: 7 }));
+		assert!(unread.is_empty());
+
+		decoder.enable_big_requests();
+
+		let bytes = [200, 0, 0, 0, 3, 0, 0, 0, 9, 0, 0, 0];
+		let mut unread = &bytes[..];
+
+		let request = decoder.next_request(&mut unread).unwrap().unwrap();
+
+		assert_eq!(request.downcast_ref(), Some(&Ping { value: 9 }));
+		assert!(unread.is_empty());
+	}
+
+	#[test]
+	fn errors_have_the_context_of_their_request() {
+		let mut decoder = RequestStreamDecoder::new(ByteOrder::MostSignificantFirst);
+		decoder.register(PhantomData::<Ping>);
+
+		// An unregistered request, then a `Ping` too short for its value.
+		let bytes = [8, 0, 0, 1, 200, 0, 0, 1];
+		let mut unread = &bytes[..];
+
+		assert!(decoder.next_request(&mut unread).unwrap().is_ok());
+
+		let error = decoder.next_request(&mut unread).unwrap().unwrap_err();
+
+		assert_eq!(
+			error.context,
+			DecodeContext {
+				message_kind: MessageKind::Request,
+				major_opcode: Some(200),
+				minor_opcode: None,
+				sequence: Some(2),
+				offset: 1,
+			}
+		);
+
+		// A length of `0` without BIG-REQUESTS.
+		let error = decoder
+			.next_request(&mut &[200, 0, 0, 0][..])
+			.unwrap()
+			.unwrap_err();
+
+		assert_eq!(error.context.sequence, Some(3));
+		assert_eq!(error.context.offset, 2);
+	}
+}
@@ -0,0 +1,188 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use crate::x11::{requests::CreateWindow, AttributeMask, Inheritable, WindowClass};
+
+/// The attributes which an [`InputOnly`] window can be created with.
+///
+/// [`InputOnly`]: WindowClass::InputOnly
+const INPUT_ONLY_ATTRIBUTES: AttributeMask = AttributeMask::WIN_GRAVITY
+	.union(AttributeMask::EVENT_MASK)
+	.union(AttributeMask::DO_NOT_PROPAGATE_MASK)
+	.union(AttributeMask::OVERRIDE_REDIRECT)
+	.union(AttributeMask::CURSOR);
+
+/// The reason a [`CreateWindow`] request would generate a [`Match`] error
+/// because of its [window class].
+///
+/// [`InputOnly`] windows have no appearance of their own, so they have no
+/// depth or border, and most attributes don't apply to them.
+///
+/// [`Match`]: crate::x11::errors::Match
+/// [window class]: WindowClass
+/// [`InputOnly`]: WindowClass::InputOnly
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CreateWindowError {
+	/// An [`InputOnly`] window was given a `depth` other than
+	/// [`CopyFromParent`] or `0`.
+	///
+	/// [`InputOnly`]: WindowClass::InputOnly
+	/// [`CopyFromParent`]: Inheritable::CopyFromParent
+	InputOnlyDepth {
+		/// The depth which was given.
+		depth: u8,
+	},
+	/// An [`InputOnly`] window was given a `border_width` other than `0`.
+	///
+	/// [`InputOnly`]: WindowClass::InputOnly
+	InputOnlyBorderWidth {
+		/// The border width which was given.
+		border_width: u16,
+	},
+	/// An [`InputOnly`] window was given attributes which only
+	/// [`InputOutput`] windows can have, such as a background or a colormap.
+	///
+	/// [`InputOnly`]: WindowClass::InputOnly
+	/// [`InputOutput`]: WindowClass::InputOutput
+	InputOnlyAttributes {
+		/// The attributes which only [`InputOutput`] windows can have.
+		///
+		/// [`InputOutput`]: WindowClass::InputOutput
+		attributes: AttributeMask,
+	},
+}
+
+impl CreateWindow<'_> {
+	/// Checks that the `depth`, `border_width`, and attributes of the window
+	/// are allowed for its `class`.
+	///
+	/// Only [`InputOnly`] windows are checked: whether an [`InputOutput`]
+	/// window's `depth` and `visual` are supported depends on the screen, and
+	/// a `class` of [`CopyFromParent`] depends on the parent, neither of which
+	/// is known here. Likewise, an [`InputOnly`] window's `visual` need only be
+	/// supported by the screen, so it is not checked.
+	///
+	/// # Errors
+	/// If the window is [`InputOnly`], returns
+	/// [`CreateWindowError::InputOnlyDepth`] if its `depth` is not
+	/// [`CopyFromParent`] or `0`, [`CreateWindowError::InputOnlyBorderWidth`]
+	/// if its `border_width` is not `0`, or
+	/// [`CreateWindowError::InputOnlyAttributes`] if its `value_mask` includes
+	/// attributes which only [`InputOutput`] windows can have.
+	///
+	/// [`InputOnly`]: WindowClass::InputOnly
+	/// [`InputOutput`]: WindowClass::InputOutput
+	/// [`CopyFromParent`]: Inheritable::CopyFromParent
+	pub fn validate(&self) -> Result<(), CreateWindowError> {
+		if self.class != Inheritable::Specific(WindowClass::InputOnly) {
+			return Ok(());
+		}
+
+		if let Inheritable::Specific(depth @ 1..) = self.depth {
+			return Err(CreateWindowError::InputOnlyDepth { depth });
+		}
+
+		if self.border_width != 0 {
+			return Err(CreateWindowError::InputOnlyBorderWidth {
+				border_width: self.border_width,
+			});
+		}
+
+		let attributes = self.value_mask.difference(INPUT_ONLY_ATTRIBUTES);
+
+		if !attributes.is_empty() {
+			return Err(CreateWindowError::InputOnlyAttributes { attributes });
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Display for CreateWindowError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::InputOnlyDepth { depth } => write!(
+				f,
+				"InputOnly windows must have a depth of 0, but a depth of {depth} was given"
+			),
+
+			Self::InputOnlyBorderWidth { border_width } => write!(
+				f,
+				"InputOnly windows must have a border width of 0, but a border width of \
+				 {border_width} was given"
+			),
+
+			Self::InputOnlyAttributes { attributes } => write!(
+				f,
+				"InputOnly windows can't have the attributes {attributes:?}"
+			),
+		}
+	}
+}
+
+impl Error for CreateWindowError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::{Attribute, EventMask, Window};
+
+	fn input_only(depth: Inheritable<u8>, border_width: u16) -> CreateWindow<'static> {
+		CreateWindow {
+			window_id: Window::new(0x0040_0001),
+			parent: Window::new(0x0000_0100),
+			class: Inheritable::Specific(WindowClass::InputOnly),
+			depth,
+			visual: Inheritable::CopyFromParent,
+			x: 0,
+			y: 0,
+			width: 100,
+			height: 100,
+			border_width,
+			value_mask: AttributeMask::EVENT_MASK,
+			values: &[Attribute::EventMask(EventMask::BUTTON_PRESS)],
+		}
+	}
+
+	#[test]
+	fn input_only_windows_are_checked() {
+		assert_eq!(
+			input_only(Inheritable::CopyFromParent, 0).validate(),
+			Ok(())
+		);
+		assert_eq!(input_only(Inheritable::Specific(0), 0).validate(), Ok(()));
+
+		assert_eq!(
+			input_only(Inheritable::Specific(24), 0).validate(),
+			Err(CreateWindowError::InputOnlyDepth { depth: 24 })
+		);
+		assert_eq!(
+			input_only(Inheritable::CopyFromParent, 1).validate(),
+			Err(CreateWindowError::InputOnlyBorderWidth { border_width: 1 })
+		);
+
+		let request = CreateWindow {
+			value_mask: AttributeMask::CURSOR | AttributeMask::BACKGROUND_PIXEL,
+			values: &[Attribute::BackgroundPixel(0), Attribute::Cursor(None)],
+			..input_only(Inheritable::CopyFromParent, 0)
+		};
+		assert_eq!(
+			request.validate(),
+			Err(CreateWindowError::InputOnlyAttributes {
+				attributes: AttributeMask::BACKGROUND_PIXEL,
+			})
+		);
+
+		// `InputOutput` windows can have any attributes.
+		let request = CreateWindow {
+			class: Inheritable::Specific(WindowClass::InputOutput),
+			depth: Inheritable::Specific(24),
+			border_width: 1,
+			..request
+		};
+		assert_eq!(request.validate(), Ok(()));
+	}
+}
@@ -0,0 +1,440 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::{
+	util::{pad, padded},
+	x11::{
+		read::{skip_padding, UnexpectedEndOfData},
+		traits::{Reply, Request},
+	},
+};
+
+/// The length of a [`SetFontPath`] request without its path, in 4-byte
+/// units.
+const SET_FONT_PATH_HEADER_LEN: u16 = 2;
+
+/// The number of bytes of a [`GetFontPathReply`] before its path.
+const GET_FONT_PATH_REPLY_HEADER_SIZE: usize = 32;
+
+/// One element of the font path: a directory or a font server from which
+/// fonts are found, such as `/usr/share/fonts/X11/misc` or
+/// `tcp/localhost:7100`.
+///
+/// Elements are encoded as a length byte followed by that many Latin-1
+/// characters, so an element must be between 1 and 255 characters long, and
+/// each character must be in Latin-1. These are checked when the element is
+/// created.
+///
+/// How an element is interpreted is up to the X server; any element it does
+/// not recognize causes a [`Value`] error when it is set.
+///
+/// [`Value`]: crate::x11::errors::Value
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FontPathElement(Vec<u8>);
+
+/// The reason a [`FontPathElement`] is not valid.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FontPathError {
+	/// The element is empty.
+	Empty,
+	/// The element is longer than [`FontPathElement::MAX_LEN`] characters.
+	TooLong {
+		/// The length of the element.
+		len: usize,
+	},
+	/// The element contains a character which is not in Latin-1.
+	Unencodable(char),
+}
+
+/// Replaces the font path with the given `path`, in the order in which
+/// fonts are searched for.
+///
+/// If the `path` is empty, the X server's default font path is restored.
+///
+/// # Errors
+/// - [Value] -- Generated if the X server does not recognize an element of
+///   the `path`.
+///
+/// [Value]: crate::x11::errors::Value
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SetFontPath {
+	pub path: Vec<FontPathElement>,
+}
+
+/// Returns the current font path.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct GetFontPath;
+
+/// The reply to a [`GetFontPath`] request.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GetFontPathReply {
+	/// The sequence number of the [`GetFontPath`] request.
+	pub sequence: u16,
+	/// The current font path, in the order in which fonts are searched for.
+	pub path: Vec<FontPathElement>,
+}
+
+impl FontPathElement {
+	/// The maximum length of an element, in characters.
+	pub const MAX_LEN: usize = u8::MAX as usize;
+
+	/// Creates a new [`FontPathElement`] from the given `path`.
+	///
+	/// # Errors
+	/// Returns [`FontPathError::Empty`] if the `path` is empty,
+	/// [`FontPathError::TooLong`] if it is longer than [`MAX_LEN`]
+	/// characters, or [`FontPathError::Unencodable`] if it contains a
+	/// character which is not in Latin-1.
+	///
+	/// [`MAX_LEN`]: FontPathElement::MAX_LEN
+	pub fn new(path: &str) -> Result<Self, FontPathError> {
+		let bytes = path
+			.chars()
+			.map(|r#char| u8::try_from(r#char).map_err(|_| FontPathError::Unencodable(r#char)))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Self::from_bytes(bytes)
+	}
+
+	/// Creates a new [`FontPathElement`] from the given Latin-1 `bytes`.
+	///
+	/// # Errors
+	/// Returns [`FontPathError::Empty`] if there are no `bytes`, or
+	/// [`FontPathError::TooLong`] if there are more than [`MAX_LEN`].
+	///
+	/// [`MAX_LEN`]: FontPathElement::MAX_LEN
+	pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, FontPathError> {
+		let bytes = bytes.into();
+
+		match bytes.len() {
+			0 => Err(FontPathError::Empty),
+			len if len > Self::MAX_LEN => Err(FontPathError::TooLong { len }),
+
+			_ => Ok(Self(bytes)),
+		}
+	}
+
+	/// The Latin-1 characters of this element.
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+impl fmt::Display for FontPathElement {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0
+			.iter()
+			.try_for_each(|&byte| write!(f, "{}", char::from(byte)))
+	}
+}
+
+impl fmt::Display for FontPathError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Empty => write!(f, "font path elements may not be empty"),
+
+			Self::TooLong { len } => write!(
+				f,
+				"font path elements may be at most {} characters long, but this one is {len}",
+				FontPathElement::MAX_LEN
+			),
+
+			Self::Unencodable(r#char) => {
+				write!(f, "{char:?} can't be encoded in a font path element")
+			}
+		}
+	}
+}
+
+impl Error for FontPathError {}
+
+/// Returns an error if fewer than `expected` bytes remain in the `reader`.
+fn ensure(reader: &impl Buf, expected: usize) -> Result<(), ReadError> {
+	if reader.remaining() < expected {
+		return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+			expected,
+			found: reader.remaining(),
+		})));
+	}
+
+	Ok(())
+}
+
+/// The number of bytes of the given `path`, not including padding.
+fn unpadded_byte_size(path: &[FontPathElement]) -> usize {
+	// Each element is preceded by its length.
+	path.iter().map(|element| 1 + element.0.len()).sum()
+}
+
+/// Writes the `path` followed by padding to a multiple of 4 bytes.
+#[allow(
+	clippy::cast_possible_truncation,
+	reason = "the length of a `FontPathElement` is checked to fit in a `u8` value"
+)]
+fn write_path(path: &[FontPathElement], writer: &mut impl BufMut) {
+	for element in path {
+		writer.put_u8(element.0.len() as u8);
+		writer.put_slice(&element.0);
+	}
+
+	writer.put_bytes(0, pad(unpadded_byte_size(path)));
+}
+
+/// Reads a path of `len` elements, followed by padding to a multiple of 4
+/// bytes.
+fn read_path(reader: &mut impl Buf, len: usize) -> Result<Vec<FontPathElement>, ReadError> {
+	let mut path = Vec::with_capacity(len);
+
+	for _ in 0..len {
+		ensure(reader, 1)?;
+		let element_len = usize::from(reader.get_u8());
+
+		ensure(reader, element_len)?;
+		let bytes = reader.copy_to_bytes(element_len).to_vec();
+
+		path.push(
+			FontPathElement::from_bytes(bytes)
+				.map_err(|error| ReadError::Other(Box::new(error)))?,
+		);
+	}
+
+	let padding = pad(unpadded_byte_size(&path));
+	ensure(reader, padding)?;
+	skip_padding(reader, padding)?;
+
+	Ok(path)
+}
+
+impl Request for SetFontPath {
+	fn major_opcode() -> u8 {
+		51
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "requests longer than `u16::MAX` units can't be sent without BIG-REQUESTS"
+	)]
+	fn length(&self) -> u16 {
+		(self.byte_size() / 4) as u16
+	}
+}
+
+impl ByteSize for SetFontPath {
+	fn byte_size(&self) -> usize {
+		// The header, followed by the path padded to a multiple of 4 bytes.
+		usize::from(SET_FONT_PATH_HEADER_LEN) * 4 + padded(unpadded_byte_size(&self.path))
+	}
+}
+
+impl Writable for SetFontPath {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "each element is at least 2 bytes, so there can't be more than `u16::MAX` in a request"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(self.length());
+		writer.put_u16(self.path.len() as u16);
+		writer.put_bytes(0, 2);
+
+		write_path(&self.path, writer);
+
+		Ok(())
+	}
+}
+
+impl Readable for SetFontPath {
+	/// Reads a [`SetFontPath`] request, not including its major opcode.
+	///
+	/// # Errors
+	/// Returns a [`FontPathError`] if an element of the path is empty.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the major opcode.
+		ensure(reader, 7)?;
+
+		reader.advance(3);
+		let len = usize::from(reader.get_u16());
+		skip_padding(reader, 2)?;
+
+		Ok(Self {
+			path: read_path(reader, len)?,
+		})
+	}
+}
+
+impl Request<GetFontPathReply> for GetFontPath {
+	fn major_opcode() -> u8 {
+		52
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	fn length(&self) -> u16 {
+		1
+	}
+}
+
+impl ByteSize for GetFontPath {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+
+impl Writable for GetFontPath {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(self.length());
+
+		Ok(())
+	}
+}
+
+impl Readable for GetFontPath {
+	/// Reads a [`GetFontPath`] request, not including its major opcode.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure(reader, 3)?;
+		reader.advance(3);
+
+		Ok(Self)
+	}
+}
+
+impl Reply<GetFontPath> for GetFontPathReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the reply can't be longer than `u32::MAX` units"
+	)]
+	fn length(&self) -> u32 {
+		(unpadded_byte_size(&self.path).div_ceil(4)) as u32
+	}
+
+	fn sequence(&self) -> Option<u16> {
+		Some(self.sequence)
+	}
+}
+
+impl ByteSize for GetFontPathReply {
+	fn byte_size(&self) -> usize {
+		GET_FONT_PATH_REPLY_HEADER_SIZE + padded(unpadded_byte_size(&self.path))
+	}
+}
+
+impl Writable for GetFontPathReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the number of elements in a font path fits in a `u16` value"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		// `1` indicates this is a reply.
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(self.sequence);
+		writer.put_u32(self.length());
+		writer.put_u16(self.path.len() as u16);
+		writer.put_bytes(0, 22);
+
+		write_path(&self.path, writer);
+
+		Ok(())
+	}
+}
+
+impl Readable for GetFontPathReply {
+	/// Reads a [`GetFontPathReply`], not including its first byte.
+	///
+	/// # Errors
+	/// Returns a [`FontPathError`] if an element of the path is empty.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the first byte.
+		ensure(reader, GET_FONT_PATH_REPLY_HEADER_SIZE - 1)?;
+
+		skip_padding(reader, 1)?;
+		let sequence = reader.get_u16();
+		let _length = reader.get_u32();
+		let len = usize::from(reader.get_u16());
+		skip_padding(reader, 22)?;
+
+		Ok(Self {
+			sequence,
+			path: read_path(reader, len)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn elements_are_checked() {
+		assert_eq!(FontPathElement::new(""), Err(FontPathError::Empty));
+		assert_eq!(
+			FontPathElement::new(&"a".repeat(256)),
+			Err(FontPathError::TooLong { len: 256 })
+		);
+		assert_eq!(
+			FontPathElement::new("/usr/share/fonts/✓"),
+			Err(FontPathError::Unencodable('✓'))
+		);
+
+		let element = FontPathElement::new("/usr/share/fonts/Liberación").unwrap();
+
+		assert_eq!(element.as_bytes().len(), 27);
+		assert_eq!(element.to_string(), "/usr/share/fonts/Liberación");
+		assert!(FontPathElement::new(&"a".repeat(255)).is_ok());
+	}
+
+	#[test]
+	fn paths_are_written_as_lists_of_strings() {
+		let request = SetFontPath {
+			path: vec![
+				FontPathElement::new("built-ins").unwrap(),
+				FontPathElement::new("tcp/fs:7100").unwrap(),
+			],
+		};
+
+		let mut bytes = vec![];
+		request.write_to(&mut bytes).unwrap();
+
+		// 8 byte header + (1 + 9) + (1 + 11) bytes of path + 2 bytes padding
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes.len(), request.byte_size());
+		assert_eq!(bytes[..8], [51, 0, 0, 8, 0, 2, 0, 0]);
+		assert_eq!(bytes[8..18], *b"\x09built-ins");
+		assert_eq!(SetFontPath::read_from(&mut &bytes[1..]).unwrap(), request);
+
+		let reply = GetFontPathReply {
+			sequence: 3,
+			path: request.path,
+		};
+
+		let mut bytes = vec![];
+		reply.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), reply.byte_size());
+		assert_eq!(reply.length(), 6);
+		assert_eq!(
+			GetFontPathReply::read_from(&mut &bytes[1..]).unwrap(),
+			reply
+		);
+
+		// An empty element can't be read.
+		bytes[32] = 0;
+		assert!(GetFontPathReply::read_from(&mut &bytes[1..]).is_err());
+	}
+}
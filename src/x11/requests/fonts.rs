@@ -0,0 +1,497 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::{
+	util::pad,
+	x11::{
+		read::{skip_padding, UnexpectedEndOfData},
+		traits::{MultiReply, Reply, Request},
+		CharInfo, FontInfo, Fontable, ResId,
+	},
+};
+
+/// The number of bytes of a [`QueryFontReply`] or [`ListFontsWithInfoReply`]
+/// before the [`FontInfo`].
+const REPLY_HEADER_SIZE: usize = 8;
+
+/// Returns the metrics and properties of the given `font`, and the metrics
+/// of each of its characters.
+///
+/// # Errors
+/// - [Font]
+///
+/// [Font]: crate::x11::errors::Font
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct QueryFont {
+	pub font: Fontable,
+}
+
+/// The reply to a [`QueryFont`] request.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct QueryFontReply {
+	/// The sequence number of the [`QueryFont`] request.
+	pub sequence: u16,
+	/// The metrics and properties of the font.
+	pub info: FontInfo,
+	/// The metrics of each character in the font, row by row; see
+	/// [`FontInfo::char_index`].
+	///
+	/// If this is empty, every character in the font has the metrics of the
+	/// `max_bounds`.
+	pub char_infos: Vec<CharInfo>,
+}
+
+/// Returns the metrics and properties of up to `max_names` fonts whose names
+/// match the given `pattern`, each in its own [`ListFontsWithInfoReply`].
+///
+/// The `pattern` is a Latin-1 string, in which `?` matches any single
+/// character and `*` matches any number of characters. Case is not
+/// significant.
+///
+/// Unlike other requests, this generates a series of replies: one for each
+/// font, followed by a last reply with no font. See
+/// [`ReplyDecoder::register_multi`].
+///
+/// [`ReplyDecoder::register_multi`]: crate::x11::reply_decoder::ReplyDecoder::register_multi
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ListFontsWithInfo {
+	pub max_names: u16,
+	pub pattern: Vec<u8>,
+}
+
+/// One of the replies to a [`ListFontsWithInfo`] request.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ListFontsWithInfoReply {
+	/// The sequence number of the [`ListFontsWithInfo`] request.
+	pub sequence: u16,
+	/// The font which this reply describes, or [`None`] if this is the last
+	/// reply.
+	pub font: Option<ListedFont>,
+}
+
+/// A font described by a [`ListFontsWithInfoReply`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ListedFont {
+	/// The name of the font, in Latin-1.
+	///
+	/// Font names are at most 255 bytes long.
+	pub name: Vec<u8>,
+	/// The metrics and properties of the font.
+	pub info: FontInfo,
+	/// A hint for how many more fonts will be listed.
+	///
+	/// This is only a hint: there may be more or fewer fonts, and `0` does
+	/// not mean that this is the last font.
+	pub replies_hint: u32,
+}
+
+impl QueryFont {
+	/// Creates a [`QueryFont`] request for the given `font`, which may be a
+	/// [`Font`] or a [`GraphicsContext`].
+	///
+	/// [`Font`]: crate::x11::Font
+	/// [`GraphicsContext`]: crate::x11::GraphicsContext
+	#[must_use]
+	pub fn new(font: impl Into<Fontable>) -> Self {
+		Self { font: font.into() }
+	}
+}
+
+impl QueryFontReply {
+	/// The metrics of the character with the given bytes, or [`None`] if it
+	/// does not exist in the font.
+	///
+	/// For a 1-byte font, `byte1` is `0` and `byte2` is the character.
+	#[must_use]
+	pub fn char_info(&self, byte1: u8, byte2: u8) -> Option<CharInfo> {
+		let index = self.info.char_index(byte1, byte2)?;
+
+		if self.char_infos.is_empty() {
+			return Some(self.info.max_bounds);
+		}
+
+		self.char_infos.get(index).copied().filter(CharInfo::exists)
+	}
+}
+
+/// Returns an error if fewer than `expected` bytes remain in the `reader`.
+fn ensure(reader: &impl Buf, expected: usize) -> Result<(), ReadError> {
+	if reader.remaining() < expected {
+		return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+			expected,
+			found: reader.remaining(),
+		})));
+	}
+
+	Ok(())
+}
+
+impl Request<QueryFontReply> for QueryFont {
+	fn major_opcode() -> u8 {
+		47
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	fn length(&self) -> u16 {
+		2
+	}
+}
+
+impl ByteSize for QueryFont {
+	fn byte_size(&self) -> usize {
+		8
+	}
+}
+
+impl Writable for QueryFont {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(self.length());
+		writer.put_u32(self.font.res_id());
+
+		Ok(())
+	}
+}
+
+impl Readable for QueryFont {
+	/// Reads a [`QueryFont`] request, not including its major opcode.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure(reader, 7)?;
+
+		reader.advance(3);
+
+		Ok(Self {
+			font: Fontable::new(reader.get_u32()),
+		})
+	}
+}
+
+impl Reply<QueryFont> for QueryFontReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the reply can't be longer than `u32::MAX` units"
+	)]
+	fn length(&self) -> u32 {
+		((self.byte_size() - 32) / 4) as u32
+	}
+
+	fn sequence(&self) -> Option<u16> {
+		Some(self.sequence)
+	}
+}
+
+impl ByteSize for QueryFontReply {
+	fn byte_size(&self) -> usize {
+		REPLY_HEADER_SIZE
+			+ FontInfo::HEADER_SIZE
+			+ self.info.properties.len() * 8
+			+ self.char_infos.len() * 12
+	}
+}
+
+impl Writable for QueryFontReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the number of characters can't be more than `u32::MAX` in a reply"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		// `1` indicates this is a reply.
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(self.sequence);
+		writer.put_u32(self.length());
+
+		self.info.write_with(self.char_infos.len() as u32, writer)?;
+
+		for char_info in &self.char_infos {
+			char_info.write_to(writer)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for QueryFontReply {
+	/// Reads a [`QueryFontReply`], not including its first byte.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the first byte.
+		ensure(reader, REPLY_HEADER_SIZE - 1)?;
+
+		skip_padding(reader, 1)?;
+		let sequence = reader.get_u16();
+		let _length = reader.get_u32();
+
+		let (info, char_infos_len) = FontInfo::read_with(reader)?;
+		let char_infos_len = char_infos_len as usize;
+
+		// Every character's metrics must be there before any are read.
+		ensure(reader, char_infos_len.saturating_mul(12))?;
+
+		let char_infos = (0..char_infos_len)
+			.map(|_| CharInfo::read_from(reader))
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self {
+			sequence,
+			info,
+			char_infos,
+		})
+	}
+}
+
+impl Request<ListFontsWithInfoReply> for ListFontsWithInfo {
+	fn major_opcode() -> u8 {
+		50
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "requests longer than `u16::MAX` units can't be sent without BIG-REQUESTS"
+	)]
+	fn length(&self) -> u16 {
+		(self.byte_size() / 4) as u16
+	}
+}
+
+impl ByteSize for ListFontsWithInfo {
+	fn byte_size(&self) -> usize {
+		8 + self.pattern.len() + pad(self.pattern.len())
+	}
+}
+
+impl Writable for ListFontsWithInfo {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the pattern can't be longer than the request"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(self.length());
+		writer.put_u16(self.max_names);
+		writer.put_u16(self.pattern.len() as u16);
+		writer.put_slice(&self.pattern);
+		writer.put_bytes(0, pad(self.pattern.len()));
+
+		Ok(())
+	}
+}
+
+impl Readable for ListFontsWithInfo {
+	/// Reads a [`ListFontsWithInfo`] request, not including its major opcode.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the major opcode.
+		ensure(reader, 7)?;
+
+		reader.advance(3);
+		let max_names = reader.get_u16();
+		let len = usize::from(reader.get_u16());
+
+		ensure(reader, len + pad(len))?;
+
+		let pattern = reader.copy_to_bytes(len).to_vec();
+		skip_padding(reader, pad(len))?;
+
+		Ok(Self { max_names, pattern })
+	}
+}
+
+impl Reply<ListFontsWithInfo> for ListFontsWithInfoReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the reply can't be longer than `u32::MAX` units"
+	)]
+	fn length(&self) -> u32 {
+		((self.byte_size() - 32) / 4) as u32
+	}
+
+	fn sequence(&self) -> Option<u16> {
+		Some(self.sequence)
+	}
+}
+
+impl MultiReply<ListFontsWithInfo> for ListFontsWithInfoReply {
+	fn is_last(&self) -> bool {
+		self.font.is_none()
+	}
+}
+
+impl ByteSize for ListFontsWithInfoReply {
+	fn byte_size(&self) -> usize {
+		REPLY_HEADER_SIZE
+			+ FontInfo::HEADER_SIZE
+			+ self.font.as_ref().map_or(0, |font| {
+				font.info.properties.len() * 8 + font.name.len() + pad(font.name.len())
+			})
+	}
+}
+
+impl Writable for ListFontsWithInfoReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "font names are at most 255 bytes long"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		// `1` indicates this is a reply.
+		writer.put_u8(1);
+		// The length of the font's name, which is `0` for the last reply.
+		writer.put_u8(self.font.as_ref().map_or(0, |font| font.name.len() as u8));
+		writer.put_u16(self.sequence);
+		writer.put_u32(self.length());
+
+		match &self.font {
+			Some(font) => {
+				font.info.write_with(font.replies_hint, writer)?;
+
+				writer.put_slice(&font.name);
+				writer.put_bytes(0, pad(font.name.len()));
+			}
+
+			// The rest of the last reply is unused.
+			None => writer.put_bytes(0, FontInfo::HEADER_SIZE),
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for ListFontsWithInfoReply {
+	/// Reads a [`ListFontsWithInfoReply`], not including its first byte.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the first byte.
+		ensure(reader, REPLY_HEADER_SIZE - 1)?;
+
+		let name_len = usize::from(reader.get_u8());
+		let sequence = reader.get_u16();
+		let _length = reader.get_u32();
+
+		// The last reply has no name.
+		if name_len == 0 {
+			ensure(reader, FontInfo::HEADER_SIZE)?;
+			reader.advance(FontInfo::HEADER_SIZE);
+
+			return Ok(Self {
+				sequence,
+				font: None,
+			});
+		}
+
+		let (info, replies_hint) = FontInfo::read_with(reader)?;
+
+		ensure(reader, name_len + pad(name_len))?;
+
+		let name = reader.copy_to_bytes(name_len).to_vec();
+		skip_padding(reader, pad(name_len))?;
+
+		Ok(Self {
+			sequence,
+			font: Some(ListedFont {
+				name,
+				info,
+				replies_hint,
+			}),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::x11::{Atom, DrawDirection, FontProperty};
+
+	fn info() -> FontInfo {
+		let bounds = CharInfo {
+			left_side_bearing: 0,
+			right_side_bearing: 6,
+			character_width: 6,
+			ascent: 10,
+			descent: 2,
+			attributes: 0,
+		};
+
+		FontInfo {
+			min_bounds: bounds,
+			max_bounds: bounds,
+			min_char_or_byte2: 0x41,
+			max_char_or_byte2: 0x43,
+			default_char: 0x41,
+			draw_direction: DrawDirection::LeftToRight,
+			min_byte1: 0,
+			max_byte1: 0,
+			all_chars_exist: false,
+			font_ascent: 10,
+			font_descent: 2,
+			properties: vec![FontProperty {
+				name: Atom::new(0x40),
+				value: 0x41,
+			}],
+		}
+	}
+
+	#[test]
+	fn query_font_reply_lists_char_infos() {
+		let reply = QueryFontReply {
+			sequence: 5,
+			info: info(),
+			char_infos: vec![info().max_bounds, CharInfo::default(), info().max_bounds],
+		};
+
+		let mut bytes = vec![];
+		reply.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), reply.byte_size());
+		// 7 + 2 units for the property + 3 units for each character.
+		assert_eq!(reply.length(), 7 + 2 + 9);
+		assert_eq!(QueryFontReply::read_from(&mut &bytes[1..]).unwrap(), reply);
+
+		assert_eq!(reply.char_info(0, 0x41), Some(info().max_bounds));
+		// `B` does not exist in the font.
+		assert_eq!(reply.char_info(0, 0x42), None);
+		assert_eq!(reply.char_info(0, 0x44), None);
+	}
+
+	#[test]
+	fn list_fonts_with_info_replies_end_with_no_name() {
+		let replies = [
+			ListFontsWithInfoReply {
+				sequence: 9,
+				font: Some(ListedFont {
+					name: b"fixed".to_vec(),
+					info: info(),
+					replies_hint: 0,
+				}),
+			},
+			ListFontsWithInfoReply {
+				sequence: 9,
+				font: None,
+			},
+		];
+
+		for reply in replies {
+			let mut bytes = vec![];
+			reply.write_to(&mut bytes).unwrap();
+
+			assert_eq!(bytes.len(), reply.byte_size());
+			assert_eq!(bytes.len(), 32 + reply.length() as usize * 4);
+			assert_eq!(bytes[1], reply.font.as_ref().map_or(0, |_| 5));
+
+			let read = ListFontsWithInfoReply::read_from(&mut &bytes[1..]).unwrap();
+
+			assert_eq!(read.is_last(), reply.font.is_none());
+			assert_eq!(read, reply);
+		}
+	}
+}
@@ -2,9 +2,27 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::fmt;
+
 use crate::x11::*;
 use xrb_proc_macros::messages;
 
+mod create_window;
+mod font_path;
+mod fonts;
+mod properties;
+mod query_colors;
+mod send_event;
+mod set_dashes;
+
+pub use create_window::*;
+pub use font_path::*;
+pub use fonts::*;
+pub use properties::*;
+pub use query_colors::*;
+pub use send_event::*;
+pub use set_dashes::*;
+
 messages! {
 	/// Creates an unmapped window with the given `window_id`.
 	///
@@ -19,7 +37,7 @@ messages! {
 	/// - [Match] -- Generated if the `class` is [`InputOutput`] and the `visual`
 	///   type and `depth` are not a combination supported by the screen, or if
 	///   the `class` is [`InputOnly`] and the `depth` is not [`CopyFromParent`]
-	///   or `0`.
+	///   or `0`. The latter can be checked with [`CreateWindow::validate`].
 	/// - [Pixmap]
 	/// - [Value]
 	/// - [Window]
@@ -75,7 +93,7 @@ messages! {
 		/// A list of [window attributes] that are to configured for the window.
 		///
 		/// [window attributes]: Attribute
-		pub values: &'a [Attribute], // Window is a placeholder until WinAttr is done
+		pub values: &'a [Attribute],
 	}
 
 	pub struct ChangeWindowAttributes<'a>(2) {
@@ -128,18 +146,36 @@ messages! {
 	pub struct UnmapWindow(10): pub target: Window;
 	pub struct UnmapSubwindows(11): pub target: Window;
 
-	pub struct ConfigureWindow<'a>(12) {
+	pub struct ConfigureWindow(12) {
 		pub target: Window,
-		pub value_mask: ConfigureWindowMask,
-		pub values: &'a [ConfigureWindowValue],
+		pub values: ConfigureWindowValues,
 	}
 
+	/// Circulates the children of the `target` window in the given
+	/// `direction`.
+	///
+	/// If another client has selected `SubstructureRedirect` on the `target`,
+	/// a [CirculateRequest] event is sent to that client instead, and no
+	/// further processing is performed.
+	///
+	/// # Events
+	/// - [CirculateNotify] -- Generated if a child is actually restacked.
+	/// - [CirculateRequest]
+	///
+	/// # Errors
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [CirculateNotify]: crate::x11::events::CirculateNotify
+	/// [CirculateRequest]: crate::x11::events::CirculateRequest
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct CirculateWindow(13) {
 		pub $direction: CirculateDirection,
 		pub target: Window,
 	}
 
-	pub struct GetGeometry(14) -> GetGeometryReply: pub target: Box<dyn Drawable>;
+	pub struct GetGeometry(14) -> GetGeometryReply: pub target: Drawable;
 
 	pub struct GetGeometryReply for GetGeometry {
 		pub $depth: u8,
@@ -186,23 +222,50 @@ messages! {
 
 	// The property requests (`ChangeProperty(18)`, `DeleteProperty(19)`,
 	// `GetProperty(20)`, and `ListProperties(21)`) are special cases and need
-	// to be defined manually. You can find them in `mod properties;`.
+	// to be defined manually. You can find them in `mod properties;`, though
+	// only `ChangeProperty` has been defined so far.
 
+	/// Changes the owner of a `selection`.
+	///
+	/// If the `owner` is `None`, the `selection` is left without an owner.
+	///
+	/// The request has no effect if the `time` is earlier than the time at
+	/// which the owner was last changed, or later than the X server's current
+	/// time. Otherwise, if the previous owner is a different client, it is
+	/// sent a [`SelectionClear`] event.
+	///
+	/// If the `owner` window is destroyed, or the client which owns it
+	/// disconnects, the `selection` is left without an owner.
+	///
+	/// [`SelectionClear`]: crate::x11::events::SelectionClear
 	pub struct SetSelectionOwner(22) {
 		pub $owner: Option<Window>,
 		pub selection: Atom,
 		pub time: Time,
 	}
 
+	/// Gets the current owner of a `selection`.
 	pub struct GetSelectionOwner(23) -> GetSelectionOwnerReply {
 		pub selection: Atom,
 	}
 
+	/// The reply to a [`GetSelectionOwner`] request.
 	pub struct GetSelectionOwnerReply for GetSelectionOwner {
+		/// The window which owns the selection, or `None` if it has no owner.
 		pub owner: Option<Window>,
 		[(); 20],
 	}
 
+	/// Requests that a `selection` be converted to the `target` type and
+	/// stored in the `property` on the `requestor` window.
+	///
+	/// If the `selection` has an owner, the owner is sent a
+	/// [`SelectionRequest`] event and is responsible for the conversion.
+	/// Otherwise, the X server sends the `requestor` a [`SelectionNotify`]
+	/// event with a `property` of `None`.
+	///
+	/// [`SelectionRequest`]: crate::x11::events::SelectionRequest
+	/// [`SelectionNotify`]: crate::x11::events::SelectionNotify
 	pub struct ConvertSelection(24) {
 		pub requestor: Window,
 		pub selection: Atom,
@@ -211,13 +274,38 @@ messages! {
 		pub time: Time,
 	}
 
-	pub struct SendEvent(25) {
-		pub $propagate: bool,
-		pub destination: Destination,
-		pub event_mask: EventMask,
-		//pub event: Box<dyn Event>,
-	}
+	// `SendEvent(25)` carries an event of any type, and so needs to be
+	// defined manually. You can find it in `mod send_event;`.
 
+	/// Actively grabs the pointer, so that pointer events are only reported to
+	/// this client.
+	///
+	/// If `owner_events` is `false`, every pointer event selected in the
+	/// `event_mask` is reported relative to the `target_window`. If it is
+	/// `true`, events which would be reported to this client anyway are
+	/// reported as usual, and any others are reported as if `owner_events`
+	/// were `false`.
+	///
+	/// The `pointer_mode` and `keyboard_mode` determine whether the pointer
+	/// and keyboard are frozen by the grab; see [`GrabMode`]. If
+	/// `confine_to` is given, the pointer is kept within that window. If
+	/// `cursor_override` is given, that cursor is shown for the duration of
+	/// the grab.
+	///
+	/// Whether the grab succeeded is returned in the reply's [`GrabStatus`].
+	///
+	/// # Events
+	/// - `EnterNotify`
+	/// - `LeaveNotify`
+	///
+	/// # Errors
+	/// - [Cursor]
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [Cursor]: crate::x11::errors::Cursor
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct GrabPointer(26) -> GrabPointerReply {
 		pub $owner_events: bool,
 		pub target_window: Window,
@@ -229,13 +317,42 @@ messages! {
 		pub time: Time,
 	}
 
+	/// The reply to a [`GrabPointer`] request.
 	pub struct GrabPointerReply for GrabPointer {
 		pub $status: GrabStatus,
 		[(); 24],
 	}
 
+	/// Releases the pointer if it is actively grabbed by this client, and if
+	/// the grab was activated no later than the given `time`.
+	///
+	/// # Events
+	/// - `EnterNotify`
+	/// - `LeaveNotify`
 	pub struct UngrabPointer(27): pub time: Time;
 
+	/// Passively grabs the given `button` on the `target_window`.
+	///
+	/// Once the grab is set up, the pointer is actively grabbed, as if by a
+	/// [`GrabPointer`] request, when the `button` is pressed with exactly the
+	/// given `modifiers` held while the pointer is in the `target_window`,
+	/// unless a passive grab on one of its ancestors matches first. The
+	/// active grab is released once every button has been released.
+	///
+	/// [`Any`] button or [`Modifiers::Any`] may be given to grab any button or
+	/// any combination of modifiers.
+	///
+	/// # Errors
+	/// - [Access] -- Generated if another client has already grabbed the
+	///   same button and modifiers on the `target_window`.
+	/// - [Cursor]
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [Access]: crate::x11::errors::Access
+	/// [Cursor]: crate::x11::errors::Cursor
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct GrabButton(28) {
 		pub $owner_events: bool,
 		pub target_window: Window,
@@ -246,22 +363,78 @@ messages! {
 		pub cursor_override: Option<Cursor>,
 		pub button: Any<Button>,
 		(),
-		pub modifiers: AnyModifierKeyMask,
+		pub modifiers: Modifiers,
 	}
 
+	/// Releases the passive grab of the given `button` and `modifiers` on the
+	/// `target_window`, if it was set up by this client.
+	///
+	/// [`Any`] button or [`Modifiers::Any`] release grabs of every button or
+	/// every combination of modifiers respectively.
+	///
+	/// # Errors
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct UngrabButton(29) {
 		pub $button: Any<Button>,
 		pub target_window: Window,
+		pub modifiers: Modifiers,
 		[(); 2],
 	}
 
+	/// Changes the `event_mask` and `cursor_override` of the pointer grab held
+	/// by this client.
+	///
+	/// The request has no effect if the pointer is not grabbed by this client,
+	/// or if the `time` is earlier than the time at which the grab was
+	/// activated or later than the X server's current time; see
+	/// [`Time::takes_effect`]. Grabs activated by button presses, whether
+	/// automatic or established with [`GrabButton`], can be changed in the
+	/// same way as those established with [`GrabPointer`].
+	///
+	/// Only pointer events and `KEYMAP_STATE` can be selected for a pointer
+	/// grab; see [`PointerEventMask::from_event_mask`].
+	///
+	/// # Errors
+	/// - [Cursor]
+	/// - [Value]
+	///
+	/// [Cursor]: crate::x11::errors::Cursor
+	/// [Value]: crate::x11::errors::Value
 	pub struct ChangeActivePointerGrab(30) {
+		/// The cursor shown for the rest of the grab, or [`None`] to show the
+		/// cursor of whichever window the pointer is in.
 		pub cursor_override: Option<Cursor>,
 		pub time: Time,
+		/// The events reported for the rest of the grab, replacing the event
+		/// mask given when the grab was activated.
 		pub event_mask: PointerEventMask,
 		[(); 2],
 	}
 
+	/// Actively grabs the keyboard, so that key events are only reported to
+	/// this client.
+	///
+	/// If `owner_events` is `false`, every key event is reported relative to
+	/// the `target_window`. If it is `true`, events which would be reported to
+	/// this client anyway are reported as usual, and any others are reported
+	/// as if `owner_events` were `false`.
+	///
+	/// Whether the grab succeeded is returned in the reply's [`GrabStatus`].
+	///
+	/// # Events
+	/// - `FocusIn`
+	/// - `FocusOut`
+	///
+	/// # Errors
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct GrabKeyboard(31) -> GrabKeyboardReply {
 		pub $owner_events: bool,
 		pub target_window: Window,
@@ -271,37 +444,90 @@ messages! {
 		[(); 2],
 	}
 
+	/// The reply to a [`GrabKeyboard`] request.
 	pub struct GrabKeyboardReply for GrabKeyboard {
 		pub $status: GrabStatus,
 		[(); 24],
 	}
 
+	/// Releases the keyboard if it is actively grabbed by this client, and if
+	/// the grab was activated no later than the given `time`.
+	///
+	/// # Events
+	/// - `FocusIn`
+	/// - `FocusOut`
 	pub struct UngrabKeyboard(32): pub time: Time;
 
+	/// Passively grabs the given `key` on the `target_window`.
+	///
+	/// Once the grab is set up, the keyboard is actively grabbed, as if by a
+	/// [`GrabKeyboard`] request, when the `key` is pressed with exactly the
+	/// given `modifiers` held while the focus is in the `target_window`,
+	/// unless a passive grab on one of its ancestors matches first. The
+	/// active grab is released once the `key` has been released.
+	///
+	/// [`Any`] key or [`Modifiers::Any`] may be given to grab any key or any
+	/// combination of modifiers.
+	///
+	/// # Errors
+	/// - [Access] -- Generated if another client has already grabbed the
+	///   same key and modifiers on the `target_window`.
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [Access]: crate::x11::errors::Access
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct GrabKey(33) {
 		pub $owner_events: bool,
 		pub target_window: Window,
-		pub modifiers: AnyModifierKeyMask,
+		pub modifiers: Modifiers,
 		pub key: Any<Keycode>,
 		pub pointer_mode: GrabMode,
 		pub keyboard_mode: GrabMode,
 		[(); 3],
 	}
 
+	/// Releases the passive grab of the given `key` and `modifiers` on the
+	/// `target_window`, if it was set up by this client.
+	///
+	/// [`Any`] key or [`Modifiers::Any`] release grabs of every key or every
+	/// combination of modifiers respectively.
+	///
+	/// # Errors
+	/// - [Value]
+	/// - [Window]
+	///
+	/// [Value]: crate::x11::errors::Value
+	/// [Window]: crate::x11::errors::Window
 	pub struct UngrabKey(34) {
 		pub $key: Any<Keycode>,
 		pub target_window: Window,
-		pub modifiers: AnyModifierKeyMask,
+		pub modifiers: Modifiers,
 		[(); 2],
 	}
 
+	/// Releases queued events for devices frozen by this client's grabs; see
+	/// [`AllowEventsMode`].
+	///
+	/// This has no effect if the `time` is earlier than the last time the
+	/// device was grabbed, or later than the current time.
+	///
+	/// # Errors
+	/// - [Value]
+	///
+	/// [Value]: crate::x11::errors::Value
 	pub struct AllowEvents(35) {
 		pub $mode: AllowEventsMode,
 		pub time: Time,
 	}
 
+	/// Grabs the X server, so that requests from other clients are not
+	/// processed until it is released with an [`UngrabServer`] request, or
+	/// until this client disconnects.
 	pub struct GrabServer(36);
-	pub struct UngrabSever(37);
+	/// Releases the X server if it is grabbed by this client.
+	pub struct UngrabServer(37);
 
 	pub struct QueryPointer(38) -> QueryPointerReply: pub target: Window;
 
@@ -317,16 +543,33 @@ messages! {
 		[(); 6],
 	}
 
+	/// Returns the positions of the pointer recorded in its motion history
+	/// between the `start` and `stop` times, relative to the `target` window.
+	///
+	/// Only positions at which the pointer was within the `target` window are
+	/// returned. No positions are returned if the `start` time is after the
+	/// `stop` time, or if the `start` time is in the future. If the `stop`
+	/// time is in the future, it is treated as [`Time::Current`].
+	///
+	/// The number of positions the X server records, if any, is given as the
+	/// size of its motion buffer in the connection setup.
+	///
+	/// # Errors
+	/// - [Window]
+	///
+	/// [Window]: crate::x11::errors::Window
 	pub struct GetMotionEvents(39) -> GetMotionEventsReply {
 		pub target: Window,
 		pub start: Time,
 		pub stop: Time,
 	}
 
+	/// The reply to a [`GetMotionEvents`] request.
 	pub struct GetMotionEventsReply for GetMotionEvents {
 		#events: u32,
 		[(); 20],
-		pub events: Vec<(Timestamp, (i16, i16))>,
+		/// The recorded positions of the pointer, from earliest to latest.
+		pub events: Vec<TimeCoord>,
 	}
 
 	pub struct TranslateCoordinates(40) -> TranslateCoordinatesReply {
@@ -385,30 +628,13 @@ messages! {
 
 	pub struct CloseFont(46): pub font: Font;
 
-	pub struct QueryFont<'a>(47) -> QueryFontReply: pub font: &'a dyn Fontable;
-
-	pub struct QueryFontReply for QueryFont<'_> {
-		pub min_bounds: CharInfo,
-		[(); 4],
-		pub max_bounds: CharInfo,
-		[(); 4],
-		pub min_char_or_byte2: u16,
-		pub max_char_or_byte2: u16,
-		#properties: u16,
-		pub draw_direction: DrawDirection,
-		pub min_byte1: u8,
-		pub max_byte1: u8,
-		pub all_chars_exist: bool,
-		pub font_ascent: i16,
-		pub font_descent: i16,
-		#charinfos: u32,
-		pub properties: Vec<FontProperty>,
-		pub charinfos: Vec<CharInfo>,
-	}
+	// QueryFont's reply shares its font metrics with ListFontsWithInfo's replies,
+	// so QueryFont and its reply are done manually and can be found in the
+	// `mod fonts;` module.
 
 	pub struct QueryTextExtents(48) -> QueryTextExtentsReply {
 		pub $odd_length: bool,
-		pub font: Box<dyn Fontable>,
+		pub font: Fontable,
 		pub string: String16,
 		[(); {string}],
 	}
@@ -441,23 +667,16 @@ messages! {
 
 	// ListFontsWithInfo has a special format for its reply that needs to be
 	// done manually, so both the request and the reply are contained within the
-	// `mod list_fonts_with_info;` module.
+	// `mod fonts;` module.
 
-	pub struct SetFontPath<'a>(51) {
-		#path: u16,
-		[(); 2],
-		pub path: &'a [LenString8],
-		[(); {path}],
-	}
+	// The elements of the font path are checked when they are created, so
+	// SetFontPath, GetFontPath, and its reply are done manually and can be
+	// found in the `mod font_path;` module.
 
-	// GetFontPath has a special format for its request. Both the request and
-	// the reply are done manually and can be found in the `mod get_font_path;`
-	// module.
-
-	pub struct CreatePixmap<'a>(53) {
+	pub struct CreatePixmap(53) {
 		pub $depth: u8,
 		pub pixmap_id: Pixmap,
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub width: u16,
 		pub height: u16,
 	}
@@ -466,7 +685,7 @@ messages! {
 
 	pub struct CreateGraphicsContext<'a>(55) {
 		pub context_id: GraphicsContext,
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub value_mask: GraphicsContextMask,
 		pub values: &'a [GraphicsContextValue],
 	}
@@ -483,13 +702,9 @@ messages! {
 		pub value_mask: GraphicsContextMask,
 	}
 
-	pub struct SetDashes<'a>(58) {
-		pub context: GraphicsContext,
-		pub dash_offset: u16,
-		#dashes: u16,
-		pub dashes: &'a [u8],
-		[(); {dashes}],
-	}
+	// `SetDashes(58)` checks that its dash list contains no zero-length
+	// dashes, and so needs to be defined manually. You can find it in
+	// `mod set_dashes;`.
 
 	pub struct SetClipRectangles<'a>(59) {
 		pub $ordering: Ordering,
@@ -510,9 +725,9 @@ messages! {
 		pub height: u16,
 	}
 
-	pub struct CopyArea<'a>(62) {
-		pub source: &'a dyn Drawable,
-		pub destination: &'a dyn Drawable,
+	pub struct CopyArea(62) {
+		pub source: Drawable,
+		pub destination: Drawable,
 		pub context: GraphicsContext,
 		pub src_x: i16,
 		pub src_y: i16,
@@ -522,9 +737,9 @@ messages! {
 		pub height: u16,
 	}
 
-	pub struct CopyPlane<'a>(63) {
-		pub source: &'a dyn Drawable,
-		pub destination: &'a dyn Drawable,
+	pub struct CopyPlane(63) {
+		pub source: Drawable,
+		pub destination: Drawable,
 		pub context: GraphicsContext,
 		pub src_x: i16,
 		pub src_y: i16,
@@ -537,60 +752,60 @@ messages! {
 
 	pub struct PolyPoint<'a>(64) {
 		pub $coordinate_mode: CoordinateMode,
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
-		pub points: &'a [(i16, i16)],
+		pub points: &'a [Point],
 	}
 
 	pub struct PolyLine<'a>(65) {
 		pub $coordinate_mode: CoordinateMode,
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
-		pub points: &'a [(i16, i16)],
+		pub points: &'a [Point],
 	}
 
 	pub struct PolySegment<'a>(66) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub segments: &'a [Segment],
 	}
 
 	pub struct PolyRectangle<'a>(67) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub rectangles: &'a [Rectangle],
 	}
 
 	pub struct PolyArc<'a>(68) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub arcs: &'a [GeomArc],
 	}
 
 	pub struct FillPoly<'a>(69) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub shape: Shape,
 		pub coordinate_mode: CoordinateMode,
 		[(); 2],
-		pub points: &'a [(i16, i16)],
+		pub points: &'a [Point],
 	}
 
 	pub struct PolyFillRectangle<'a>(70) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub rectangles: &'a [Rectangle],
 	}
 
 	pub struct PolyFillArc<'a>(71) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub arcs: &'a [GeomArc],
 	}
 
 	pub struct PutImage<'a>(72) {
 		pub $format: BitmapFormat,
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub width: u16,
 		pub height: u16,
@@ -603,9 +818,9 @@ messages! {
 		[(); {data}],
 	}
 
-	pub struct GetImage<'a>(73) -> GetImageReply {
+	pub struct GetImage(73) -> GetImageReply {
 		pub $format: Format,
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub x: i16,
 		pub y: i16,
 		pub width: u16,
@@ -613,7 +828,7 @@ messages! {
 		pub plane_mask: u32,
 	}
 
-	pub struct GetImageReply for GetImage<'_> {
+	pub struct GetImageReply for GetImage {
 		pub $depth: u8,
 		pub visual: Option<VisualId>,
 		[(); 20],
@@ -622,25 +837,27 @@ messages! {
 	}
 
 	pub struct PolyText8<'a>(74) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub x: i16,
 		pub y: i16,
-		//pub items: &'a [TextItem8], // TODO: TextItem8 and TextItem16 need to be done separately
-		//[(); {items}],
+		/// The text to draw, best built with a [`TextItemsBuilder`].
+		pub items: &'a [TextItem8],
+		[(); {items}],
 	}
 
 	pub struct PolyText16<'a>(75) {
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub x: i16,
 		pub y: i16,
-		//pub items: [TextItem16], // TODO: TextItem8 and TextItem16 need to be done separately
-		//[(); {items}],
+		/// The text to draw, best built with a [`TextItemsBuilder`].
+		pub items: &'a [TextItem16],
+		[(); {items}],
 	}
 
-	pub struct ImageText8<'a>(76) {
-		pub drawable: &'a dyn Drawable,
+	pub struct ImageText8(76) {
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub x: i16,
 		pub y: i16,
@@ -648,8 +865,8 @@ messages! {
 		[(); {string}],
 	}
 
-	pub struct ImageText16<'a>(77) {
-		pub drawable: &'a dyn Drawable,
+	pub struct ImageText16(77) {
+		pub drawable: Drawable,
 		pub context: GraphicsContext,
 		pub x: i16,
 		pub y: i16,
@@ -674,7 +891,7 @@ messages! {
 	pub struct InstallColormap(81): pub colormap: Colormap;
 	pub struct UninstallColormap(82): pub colormap: Colormap;
 
-	pub struct ListInstalledColormaps(73) -> ListInstalledColormapsReply {
+	pub struct ListInstalledColormaps(83) -> ListInstalledColormapsReply {
 		pub target_window: Window,
 	}
 
@@ -684,19 +901,47 @@ messages! {
 		pub colormaps: Vec<Colormap>,
 	}
 
+	/// Allocates a read-only colormap cell with the closest color to the
+	/// given `color` that the hardware supports.
+	///
+	/// The pixel value of the cell and the color actually stored are returned
+	/// in the reply. A cell which already has that color may be shared.
+	///
+	/// # Errors
+	/// - [Alloc]
+	/// - [Colormap]
+	///
+	/// [Alloc]: crate::x11::errors::Alloc
+	/// [Colormap]: crate::x11::errors::Colormap
 	pub struct AllocColor(84) -> AllocColorReply {
 		pub colormap: Colormap,
-		pub color: (u16, u16, u16),
+		pub color: Rgb16,
 		[(); 2],
 	}
 
 	pub struct AllocColorReply for AllocColor {
-		pub color: (u16, u16, u16),
+		/// The color actually stored in the cell.
+		pub color: Rgb16,
 		[(); 2],
+		/// The pixel value of the cell.
 		pub pixel: u32,
 		[(); 12],
 	}
 
+	/// Allocates a read-only colormap cell with the color of the given `name`,
+	/// as with [`AllocColor`].
+	///
+	/// The `name` is looked up in the screen's color database, ignoring case.
+	/// It should use the ISO Latin-1 encoding.
+	///
+	/// # Errors
+	/// - [Alloc]
+	/// - [Colormap]
+	/// - [Name] -- Generated if the `name` is not in the color database.
+	///
+	/// [Alloc]: crate::x11::errors::Alloc
+	/// [Colormap]: crate::x11::errors::Colormap
+	/// [Name]: crate::x11::errors::Name
 	pub struct AllocNamedColor(85) -> AllocNamedColorReply {
 		pub colormap: Colormap,
 		#name: u16,
@@ -706,16 +951,37 @@ messages! {
 	}
 
 	pub struct AllocNamedColorReply for AllocNamedColor {
+		/// The pixel value of the cell.
 		pub pixel: u32,
-		pub exact_color: (u16, u16, u16),
-		pub visual_color: (u16, u16, u16),
+		/// The color given for the `name` in the color database.
+		pub exact_color: Rgb16,
+		/// The color actually stored in the cell.
+		pub visual_color: Rgb16,
 		[(); 8],
 	}
 
+	/// Allocates `num_colors` read-write colormap cells and `planes` plane
+	/// masks.
+	///
+	/// Every combination of the plane masks ORed with any of the pixels gives
+	/// the pixel value of an allocated cell, so `num_colors * 2^planes` cells
+	/// are allocated. If `contiguous` is true, the plane masks combined form a
+	/// contiguous set of bits.
+	///
+	/// # Errors
+	/// - [Alloc]
+	/// - [Colormap]
+	/// - [Value] -- Generated if `num_colors` is zero.
+	///
+	/// [Alloc]: crate::x11::errors::Alloc
+	/// [Colormap]: crate::x11::errors::Colormap
+	/// [Value]: crate::x11::errors::Value
 	pub struct AllocColorCells(86) -> AllocColorCellsReply {
 		pub $contiguous: bool,
 		pub colormap: Colormap,
-		pub num_colors: u16, // TODO: its just called `colors`... is it the number?
+		/// The number of pixels to allocate.
+		pub num_colors: u16,
+		/// The number of plane masks to allocate.
 		pub planes: u16,
 	}
 
@@ -727,30 +993,77 @@ messages! {
 		pub masks: Vec<u32>,
 	}
 
+	/// Allocates `num_colors` read-write colormap cells with `reds`, `greens`,
+	/// and `blues` planes for each of the color channels.
+	///
+	/// The channels of each cell can be changed independently: the red
+	/// channel is indexed by the bits of the `red_mask` in the reply, and
+	/// likewise for green and blue. If `contiguous` is true, the bits of
+	/// each mask are contiguous.
+	///
+	/// # Errors
+	/// - [Alloc]
+	/// - [Colormap]
+	/// - [Value] -- Generated if `num_colors` is zero.
+	///
+	/// [Alloc]: crate::x11::errors::Alloc
+	/// [Colormap]: crate::x11::errors::Colormap
+	/// [Value]: crate::x11::errors::Value
 	pub struct AllocColorPlanes(87) -> AllocColorPlanesReply {
 		pub $contiguous: bool,
 		pub colormap: Colormap,
-		pub num_colors: u16, // TODO: its just called `colors`... is it the number?
-		pub colors: (u16, u16, u16),
+		/// The number of pixels to allocate.
+		pub num_colors: u16,
+		/// The number of planes to allocate for the red channel.
+		pub reds: u16,
+		/// The number of planes to allocate for the green channel.
+		pub greens: u16,
+		/// The number of planes to allocate for the blue channel.
+		pub blues: u16,
 	}
 
 	pub struct AllocColorPlanesReply for AllocColorPlanes {
 		#pixels: u16,
 		[(); 2],
-		pub color_mask: (u16, u16, u16),
+		pub red_mask: u32,
+		pub green_mask: u32,
+		pub blue_mask: u32,
 		[(); 8],
 		pub pixels: Vec<u32>,
 	}
 
+	/// Frees the colormap cells with the given `pixels` values, ORed with
+	/// every combination of the bits in the `plane_mask`.
+	///
+	/// # Errors
+	/// - [Access] -- Generated if a cell was not allocated by this client.
+	/// - [Colormap]
+	/// - [Value] -- Generated if a pixel is not a valid index into the
+	///   `colormap`.
+	///
+	/// [Access]: crate::x11::errors::Access
+	/// [Colormap]: crate::x11::errors::Colormap
+	/// [Value]: crate::x11::errors::Value
 	pub struct FreeColors<'a>(88) {
 		pub colormap: Colormap,
 		pub plane_mask: u32,
 		pub pixels: &'a [u32],
 	}
 
-	pub struct StoreColors(89) {
+	/// Stores the colors of the given `items` in read-write colormap cells.
+	///
+	/// # Errors
+	/// - [Access] -- Generated if a cell is read-only or was not allocated.
+	/// - [Colormap]
+	/// - [Value] -- Generated if a pixel is not a valid index into the
+	///   `colormap`.
+	///
+	/// [Access]: crate::x11::errors::Access
+	/// [Colormap]: crate::x11::errors::Colormap
+	/// [Value]: crate::x11::errors::Value
+	pub struct StoreColors<'a>(89) {
 		pub colormap: Colormap,
-		//pub items: [ColorItem], // ColorItems need to be done separately
+		pub items: &'a [ColorItem],
 	}
 
 	pub struct StoreNamedColor(90) {
@@ -767,6 +1080,18 @@ messages! {
 	// for its list of colors, and so the reply must be done manually. The
 	// reply and request have been put in `mod query_colors;`.
 
+	/// Looks up the color of the given `name` in the color database of the
+	/// `colormap`'s screen, without allocating a cell.
+	///
+	/// The `name` is looked up ignoring case. It should use the ISO Latin-1
+	/// encoding.
+	///
+	/// # Errors
+	/// - [Colormap]
+	/// - [Name] -- Generated if the `name` is not in the color database.
+	///
+	/// [Colormap]: crate::x11::errors::Colormap
+	/// [Name]: crate::x11::errors::Name
 	pub struct LookupColor(92) -> LookupColorReply {
 		pub colormap: Colormap,
 		#name: u16,
@@ -776,31 +1101,93 @@ messages! {
 	}
 
 	pub struct LookupColorReply for LookupColor {
-		pub exact_color: (u16, u16, u16),
-		pub visual_color: (u16, u16, u16),
+		/// The color given for the `name` in the color database.
+		pub exact_color: Rgb16,
+		/// The closest color to the `exact_color` that the hardware supports.
+		pub visual_color: Rgb16,
 		[(); 12],
 	}
 
+	/// Creates a cursor with the given `cursor_id` from the `source` bitmap.
+	///
+	/// Pixels which are set in the `source` are drawn with the
+	/// `foreground_color`, and other pixels with the `background_color`. Only
+	/// pixels which are set in the `mask`, or every pixel if there is no
+	/// `mask`, are displayed.
+	///
+	/// The `source` and `mask` pixmaps may be freed immediately after the
+	/// cursor is created.
+	///
+	/// # Errors
+	/// - [Alloc]
+	/// - [IdChoice]
+	/// - [Match] -- Generated if the `source` or `mask` do not have a depth of
+	///   `1`, if they are not the same size, or if the hotspot is not within
+	///   the `source`.
+	/// - [Pixmap]
+	///
+	/// [Alloc]: crate::x11::errors::Alloc
+	/// [IdChoice]: crate::x11::errors::IdChoice
+	/// [Match]: crate::x11::errors::Match
+	/// [Pixmap]: crate::x11::errors::Pixmap
 	pub struct CreateCursor(93) {
 		pub cursor_id: Cursor,
 		pub source: Pixmap,
 		pub mask: Option<Pixmap>,
-		pub foreground_color: (u16, u16, u16),
-		pub background_color: (u16, u16, u16),
+		/// The color of pixels set in the `source`.
+		pub foreground_color: Rgb16,
+		/// The color of pixels not set in the `source`.
+		pub background_color: Rgb16,
+		/// The x-coordinate of the cursor's hotspot, relative to the top-left
+		/// corner of the `source`.
 		pub x: u16,
+		/// The y-coordinate of the cursor's hotspot, relative to the top-left
+		/// corner of the `source`.
 		pub y: u16,
 	}
 
+	/// Creates a cursor with the given `cursor_id` from glyphs of the given
+	/// fonts.
+	///
+	/// The cursor's hotspot is the origin of the `source_char` glyph. The
+	/// standard cursor font's glyphs are listed in [`cursor_font`], which can
+	/// also create this request.
+	///
+	/// The fonts may be closed immediately after the cursor is created.
+	///
+	/// # Errors
+	/// - [Alloc]
+	/// - [Font]
+	/// - [IdChoice]
+	/// - [Value] -- Generated if either character is not defined in its font.
+	///
+	/// [`cursor_font`]: crate::x11::cursor_font
+	/// [Alloc]: crate::x11::errors::Alloc
+	/// [Font]: crate::x11::errors::Font
+	/// [IdChoice]: crate::x11::errors::IdChoice
+	/// [Value]: crate::x11::errors::Value
 	pub struct CreateGlyphCursor(94) {
 		pub cursor_id: Cursor,
 		pub source_font: Font,
+		/// The font containing the `mask_char`, or [`None`] if every pixel of
+		/// the `source_char` glyph is displayed.
 		pub mask_font: Option<Font>,
 		pub source_char: u16,
 		pub mask_char: u16,
-		pub foreground_color: (u16, u16, u16),
-		pub background_color: (u16, u16, u16),
+		/// The color of pixels set in the `source`.
+		pub foreground_color: Rgb16,
+		/// The color of pixels not set in the `source`.
+		pub background_color: Rgb16,
 	}
 
+	/// Removes the association between the given `cursor` and its ID.
+	///
+	/// The cursor is only destroyed once no windows or grabs refer to it.
+	///
+	/// # Errors
+	/// - [Cursor]
+	///
+	/// [Cursor]: crate::x11::errors::Cursor
 	pub struct FreeCursor(95): pub cursor: Cursor;
 
 	/// Changes the color of the given `cursor`.
@@ -815,13 +1202,9 @@ messages! {
 	pub struct RecolorCursor(96) {
 		pub cursor: Cursor,
 		/// The tint to apply to the cursor's foreground.
-		///
-		/// This is in RGB format (i.e. `(red, green, blue)`).
-		pub foreground_color: (u16, u16, u16),
+		pub foreground_color: Rgb16,
 		/// The tint to apply to the cursor's background.
-		///
-		/// This is in RGB format (i.e. `(red, green, blue)`).
-		pub background_color: (u16, u16, u16),
+		pub background_color: Rgb16,
 	}
 
 	/// Gets the closest ideal size to the given `width` and `height`.
@@ -848,7 +1231,7 @@ messages! {
 	/// [`Value`]: crate::x11::errors::Value
 	/// [window]: Window
 	/// [`InputOnly`]: WindowClass::InputOnly
-	pub struct QueryBestSize<'a>(97) -> QueryBestSizeReply {
+	pub struct QueryBestSize(97) -> QueryBestSizeReply {
 		/// The 'type' of 'best size' being queried.
 		pub $class: QueryBestSizeClass,
 		/// Indicates the desired screen.
@@ -862,7 +1245,7 @@ messages! {
 		/// [`Tile`]: query_best_size::Class::Tile
 		/// [`Stipple`]: query_best_size::Class::Stipple
 		/// [`InputOnly`]: query_best_size::Class::InputOnly
-		pub drawable: &'a dyn Drawable,
+		pub drawable: Drawable,
 		/// The given width to find an ideal size for.
 		pub width: u16,
 		/// The given height to find an ideal size for.
@@ -874,7 +1257,7 @@ messages! {
 	/// This contains the closest ideal size to the `width` and `height` that
 	/// was given in the [`QueryBestSize`] request. See the request's docs for
 	/// more information.
-	pub struct QueryBestSizeReply for QueryBestSize<'_> {
+	pub struct QueryBestSizeReply for QueryBestSize {
 		/// The width of the ideal size found.
 		pub width: u16,
 		/// The height of the ideal size found.
@@ -911,57 +1294,102 @@ messages! {
 	// their lists of keysyms, and so have to be done manually. They can be
 	// found in the `mod keyboard_mapping;` module.
 
-	// The `ChangeKeyboardControl` request uses a special format for its values
-	// list, so it has to be done manually. It can be found in the
-	// `mod change_keyboard_control;` module.
+	/// Changes the settings of the keyboard given in the `values`.
+	///
+	/// # Errors
+	/// - [Match] -- Generated if the `values` give a `led` without a
+	///   `led_mode`, or a `key` without an `auto_repeat_mode`.
+	/// - [Value] -- Generated if a percentage is above `100`, or if a pitch or
+	///   duration is above [`i16::MAX`].
+	///
+	/// [Match]: crate::x11::errors::Match
+	/// [Value]: crate::x11::errors::Value
+	pub struct ChangeKeyboardControl(102) {
+		pub values: KeyboardControl,
+	}
 
+	/// Returns the current settings of the keyboard.
 	pub struct GetKeyboardControl(103) -> GetKeyboardControlReply;
 
 	pub struct GetKeyboardControlReply for GetKeyboardControl {
+		/// Whether keys repeat when they are held at all.
+		///
+		/// If this is `true`, `auto_repeats` lists which keys repeat.
 		pub $global_auto_repeat: bool,
+		/// Which LEDs are turned on: bit `n` represents LED `n + 1`.
 		pub led_mask: u32,
+		/// The volume of key clicks, from `0` (off) to `100` (loud).
 		pub key_click_percent: u8,
+		/// The base volume of the bell, from `0` (off) to `100` (loud).
 		pub bell_percent: u8,
+		/// The pitch of the bell in Hz.
 		pub bell_pitch: u16,
+		/// The duration of the bell in milliseconds.
 		pub bell_duration: u16,
 		[(); 2],
-		pub auto_repeats: [u8; 32],
+		/// Which keys repeat when they are held.
+		pub auto_repeats: AutoRepeats,
 	}
 
 	pub struct Bell(104): pub $percent: i8;
 
+	/// Changes the settings of the pointer given in the `values`.
+	///
+	/// # Errors
+	/// - [Value] -- Generated if the acceleration's denominator is `0`, or if
+	///   any value is above [`i16::MAX`].
+	///
+	/// [Value]: crate::x11::errors::Value
 	pub struct ChangePointerControl(105) {
-		pub acceleration_numerator: i16,
-		pub acceleration_denominator: i16,
-		pub threshold: i16,
-		pub accelerate: bool,
-		pub enable_threshold: bool,
+		pub values: PointerControl,
 	}
 
+	/// Returns the current settings of the pointer.
 	pub struct GetPointerControl(106) -> GetPointerControlReply;
 
 	pub struct GetPointerControlReply for GetPointerControl {
-		pub acceleration_numerator: i16,
-		pub acceleration_denominator: u16,
+		/// How much faster than the physical device the pointer moves once it
+		/// has moved more than the `threshold` at once.
+		pub acceleration: Acceleration,
+		/// The number of pixels the pointer must move at once before its
+		/// `acceleration` applies.
 		pub threshold: u16,
 		[(); 18],
 	}
 
+	/// Changes the settings of the screen saver.
+	///
+	/// # Errors
+	/// - [Value] -- Generated if the `timeout` or `interval` is negative.
+	///
+	/// [Value]: crate::x11::errors::Value
 	pub struct SetScreenSaver(107) {
-		pub timeout: i16,
-		pub interval: i16,
-		pub prefer_blanking: Defaultable<bool>,
-		pub allow_exposures: Defaultable<bool>,
+		/// The number of seconds without input from the keyboard or pointer
+		/// after which the screen saver is activated, or `0` to disable the
+		/// screen saver.
+		pub timeout: Defaultable<i16>,
+		/// The number of seconds between each change of the screen saver's
+		/// pattern, or `0` to never change it.
+		///
+		/// This has no effect if the screen is blanked.
+		pub interval: Defaultable<i16>,
+		pub prefer_blanking: Blanking,
+		pub allow_exposures: Exposures,
 		[(); 2],
 	}
 
+	/// Returns the current settings of the screen saver.
 	pub struct GetScreenSaver(108) -> GetScreenSaverReply;
 
 	pub struct GetScreenSaverReply for GetScreenSaver {
-		pub timeout: i16,
-		pub interval: i16,
-		pub prefer_blanking: bool,
-		pub allow_exposures: bool,
+		/// The number of seconds without input after which the screen saver
+		/// is activated, or `0` if it is disabled.
+		pub timeout: u16,
+		/// The number of seconds between each change of the screen saver's
+		/// pattern, or `0` if it never changes.
+		pub interval: u16,
+		pub prefer_blanking: Blanking,
+		pub allow_exposures: Exposures,
 		[(); 18],
 	}
 
@@ -977,13 +1405,13 @@ messages! {
 	pub struct ListHosts(110) -> ListHostsReply;
 
 	pub struct ListHostsReply for ListHosts {
-		pub $enabled: bool,
+		pub $mode: AccessControl,
 		#hosts: u16,
 		[(); 22],
 		pub hosts: Vec<Host>,
 	}
 
-	pub struct SetAccessControl(111): pub $enabled: bool;
+	pub struct SetAccessControl(111): pub $mode: AccessControl;
 
 	pub struct SetCloseDownMode(112): pub $mode: CloseDownMode;
 
@@ -996,6 +1424,12 @@ messages! {
 		pub properties: &'a [Atom],
 	}
 
+	/// Activates or resets the screen saver.
+	///
+	/// # Errors
+	/// - [Value]
+	///
+	/// [Value]: crate::x11::errors::Value
 	pub struct ForceScreenSaver(115): pub $mode: ScreenSaverMode;
 
 	pub struct SetPointerMapping<'a>(116) -> SetPointerMappingReply {
@@ -1029,11 +1463,44 @@ messages! {
 	// `mod no_operation;` module.
 }
 
+// Requests which change who may connect to the X server, and what is left
+// behind when a client disconnects, are displayed with their opcode and effect
+// so that they stand out in traces.
+
+impl fmt::Display for SetAccessControl {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "SetAccessControl (opcode 111): {}", self.mode)
+	}
+}
+
+impl fmt::Display for SetCloseDownMode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "SetCloseDownMode (opcode 112): {}", self.mode)
+	}
+}
+
+impl GetGeometry {
+	/// Creates a [`GetGeometry`] request for the given `target`, which may be
+	/// a [`Window`] or a [`Pixmap`].
+	#[must_use]
+	pub fn new(target: impl Into<Drawable>) -> Self {
+		Self {
+			target: target.into(),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::traits::*;
 
+	#[test]
+	fn get_geometry_accepts_windows_and_pixmaps() {
+		assert_eq!(GetGeometry::new(Window::new(7)).target, Drawable::new(7));
+		assert_eq!(GetGeometry::new(Pixmap::new(8)).target, Drawable::new(8));
+	}
+
 	#[test]
 	fn create_window_length_is_correct() {
 		let create_window = CreateWindow {
@@ -1159,8 +1626,8 @@ mod tests {
 			owner_events: false,
 			target_window: Window::new(0),
 			event_mask: PointerEventMask::empty(),
-			pointer_mode: GrabMode::Asynchronous,
-			keyboard_mode: GrabMode::Asynchronous,
+			pointer_mode: GrabMode::Async,
+			keyboard_mode: GrabMode::Async,
 			confine_to: None,
 			cursor_override: None,
 			time: Time::Current,
@@ -1187,4 +1654,32 @@ mod tests {
 
 		assert_eq!(grab_server.length(), 1);
 	}
+
+	#[test]
+	fn set_access_control_length_is_correct() {
+		let set_access_control = SetAccessControl {
+			mode: AccessControl::Enable,
+		};
+
+		assert_eq!(set_access_control.length(), 1);
+	}
+
+	#[test]
+	fn access_requests_are_displayed_with_their_effect() {
+		let set_access_control = SetAccessControl {
+			mode: AccessControl::Disable,
+		};
+		let set_close_down_mode = SetCloseDownMode {
+			mode: CloseDownMode::RetainTemporary,
+		};
+
+		assert_eq!(
+			set_access_control.to_string(),
+			"SetAccessControl (opcode 111): access control disabled"
+		);
+		assert_eq!(
+			set_close_down_mode.to_string(),
+			"SetCloseDownMode (opcode 112): retain resources temporarily"
+		);
+	}
 }
@@ -0,0 +1,693 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, ffi::c_long, fmt};
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::{
+	util::{pad, padded},
+	x11::{
+		atoms,
+		read::{skip_padding, UnexpectedEndOfData},
+		traits::Request,
+		Atom, BuildError, ResId, Window,
+	},
+};
+
+/// The length of a [`ChangeProperty`] request without its data, in 4-byte
+/// units.
+const CHANGE_PROPERTY_HEADER_LEN: u16 = 6;
+
+/// How a [`ChangeProperty`] request combines the new data with the property's
+/// existing data.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PropertyMode {
+	/// The property's existing data is discarded.
+	Replace,
+	/// The new data is inserted before the property's existing data.
+	///
+	/// The property's type and format must match the new data's.
+	Prepend,
+	/// The new data is inserted after the property's existing data.
+	///
+	/// The property's type and format must match the new data's.
+	Append,
+}
+
+/// The data of a property, as a list of 8-bit, 16-bit, or 32-bit values.
+///
+/// The format of the data lets the X server swap the bytes of each value
+/// when the data is sent to a client with a different byte order.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PropertyData {
+	/// A list of 8-bit values, such as a string.
+	Format8(Vec<u8>),
+	/// A list of 16-bit values.
+	Format16(Vec<u16>),
+	/// A list of 32-bit values, such as atoms or resource IDs.
+	///
+	/// Format 32 values are always 32 bits, written as 4 bytes each, even
+	/// where Xlib stores them in 64-bit C `long`s. See
+	/// [`from_longs`](Self::from_longs) and [`to_longs`](Self::to_longs) to
+	/// convert to and from Xlib's representation.
+	Format32(Vec<u32>),
+}
+
+/// A C `long` given to [`PropertyData::from_longs`] which does not hold a
+/// 32-bit value.
+///
+/// Xlib stores each format 32 value in its own `long`, which is 64 bits on
+/// most 64-bit platforms. A value outside of the 32-bit range usually means
+/// that two values were packed into one `long`, and would otherwise be
+/// silently truncated.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LongOutOfRange {
+	/// The index of the `long` in the given values.
+	pub index: usize,
+	/// The value of the `long`.
+	pub value: i64,
+}
+
+/// The type and data of a property.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PropertyValue {
+	/// The type of the data, which is not interpreted by the X server.
+	pub r#type: Atom,
+	/// The property's data.
+	pub data: PropertyData,
+}
+
+/// Changes the `property` of the `target` window, creating it if it does not
+/// already exist.
+///
+/// # Events
+/// - `PropertyNotify`
+///
+/// # Errors
+/// - [Alloc]
+/// - [Atom]
+/// - [Match] -- Generated if the `mode` is [`Prepend`] or [`Append`] and the
+///   existing property's type or format do not match the new `value`'s.
+/// - [Value]
+/// - [Window]
+///
+/// [`Prepend`]: PropertyMode::Prepend
+/// [`Append`]: PropertyMode::Append
+/// [Alloc]: crate::x11::errors::Alloc
+/// [Atom]: crate::x11::errors::Atom
+/// [Match]: crate::x11::errors::Match
+/// [Value]: crate::x11::errors::Value
+/// [Window]: crate::x11::errors::Window
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ChangeProperty {
+	pub mode: PropertyMode,
+	pub target: Window,
+	pub property: Atom,
+	pub value: PropertyValue,
+}
+
+/// A set of window properties to be changed at once, such as when a window
+/// is first set up.
+///
+/// Properties are changed in the order they were first given; giving a
+/// property again replaces its value.
+///
+/// # Examples
+/// ```ignore
+/// let properties: PropertyChanges = [
+///     (atoms::WM_NAME, PropertyValue::string("Terminal")),
+///     (wm_protocols, PropertyValue::atoms([wm_delete_window])),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// for request in properties.requests(window, setup.maximum_request_length)? {
+///     connection.send(request)?;
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PropertyChanges {
+	properties: Vec<(Atom, PropertyValue)>,
+}
+
+impl PropertyMode {
+	const fn from_u8(mode: u8) -> Option<Self> {
+		match mode {
+			0 => Some(Self::Replace),
+			1 => Some(Self::Prepend),
+			2 => Some(Self::Append),
+
+			_ => None,
+		}
+	}
+}
+
+impl PropertyData {
+	/// The number of bits in each value: `8`, `16`, or `32`.
+	#[must_use]
+	pub const fn format(&self) -> u8 {
+		match self {
+			Self::Format8(_) => 8,
+			Self::Format16(_) => 16,
+			Self::Format32(_) => 32,
+		}
+	}
+
+	/// The number of values in the data.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		match self {
+			Self::Format8(data) => data.len(),
+			Self::Format16(data) => data.len(),
+			Self::Format32(data) => data.len(),
+		}
+	}
+
+	/// Returns whether there are no values in the data.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Creates format 32 data from `INT32` values, which are sent as their
+	/// two's complement bits.
+	pub fn from_int32s(values: impl IntoIterator<Item = i32>) -> Self {
+		Self::Format32(values.into_iter().map(card32).collect())
+	}
+
+	/// Creates format 32 data from Xlib's representation of it: one C `long`
+	/// for each value, as given to `XChangeProperty`.
+	///
+	/// Each `long` may hold either a `CARD32`, zero-extended, or an `INT32`,
+	/// sign-extended; only its lower 32 bits are kept.
+	///
+	/// # Errors
+	/// Returns a [`LongOutOfRange`] error for the first `long` which holds
+	/// neither, such as one which two 32-bit values were packed into.
+	#[allow(
+		clippy::useless_conversion,
+		reason = "a C `long` is only 64 bits on some platforms"
+	)]
+	pub fn from_longs(longs: &[c_long]) -> Result<Self, LongOutOfRange> {
+		longs
+			.iter()
+			.enumerate()
+			.map(|(index, &long)| {
+				let value = i64::from(long);
+
+				u32::try_from(value)
+					.or_else(|_| i32::try_from(value).map(card32))
+					.map_err(|_| LongOutOfRange { index, value })
+			})
+			.collect::<Result<_, _>>()
+			.map(Self::Format32)
+	}
+
+	/// The values of format 32 data, or `None` if the data is in another
+	/// format.
+	#[must_use]
+	pub fn as_format32(&self) -> Option<&[u32]> {
+		match self {
+			Self::Format32(data) => Some(data),
+			_ => None,
+		}
+	}
+
+	/// The values of format 32 data as `INT32`s, or `None` if the data is in
+	/// another format.
+	#[must_use]
+	pub fn to_int32s(&self) -> Option<Vec<i32>> {
+		self.as_format32()
+			.map(|data| data.iter().copied().map(int32).collect())
+	}
+
+	/// Format 32 data in Xlib's representation: one C `long` for each value,
+	/// sign-extended as returned by `XGetWindowProperty`. Returns `None` if
+	/// the data is in another format.
+	///
+	/// Only the lower 32 bits of each `long` are meaningful; mask them with
+	/// `0xffff_ffff` to read a `CARD32`.
+	#[must_use]
+	pub fn to_longs(&self) -> Option<Vec<c_long>> {
+		self.as_format32().map(|data| {
+			data.iter()
+				.map(|&value| c_long::from(int32(value)))
+				.collect()
+		})
+	}
+
+	/// The number of bytes the data is written as, without padding.
+	fn unpadded_byte_size(&self) -> usize {
+		self.len() * usize::from(self.format() / 8)
+	}
+
+	/// Splits the data into consecutive chunks of no more than `len` values.
+	///
+	/// Empty data is returned as a single empty chunk.
+	fn chunks(&self, len: usize) -> Vec<Self> {
+		fn split<T: Clone>(
+			data: &[T],
+			len: usize,
+			f: fn(Vec<T>) -> PropertyData,
+		) -> Vec<PropertyData> {
+			if data.is_empty() {
+				return vec![f(Vec::new())];
+			}
+
+			data.chunks(len).map(|chunk| f(chunk.to_vec())).collect()
+		}
+
+		match self {
+			Self::Format8(data) => split(data, len, Self::Format8),
+			Self::Format16(data) => split(data, len, Self::Format16),
+			Self::Format32(data) => split(data, len, Self::Format32),
+		}
+	}
+}
+
+/// Reinterprets a `CARD32` value as an `INT32`.
+#[allow(
+	clippy::cast_possible_wrap,
+	reason = "`INT32` values are sent as their two's complement bits"
+)]
+pub(crate) const fn int32(value: u32) -> i32 {
+	value as i32
+}
+
+/// Reinterprets an `INT32` value as a `CARD32`.
+#[allow(
+	clippy::cast_sign_loss,
+	reason = "`INT32` values are sent as their two's complement bits"
+)]
+pub(crate) const fn card32(value: i32) -> u32 {
+	value as u32
+}
+
+impl From<Vec<u8>> for PropertyData {
+	fn from(data: Vec<u8>) -> Self {
+		Self::Format8(data)
+	}
+}
+
+impl From<Vec<u16>> for PropertyData {
+	fn from(data: Vec<u16>) -> Self {
+		Self::Format16(data)
+	}
+}
+
+impl From<Vec<u32>> for PropertyData {
+	fn from(data: Vec<u32>) -> Self {
+		Self::Format32(data)
+	}
+}
+
+impl PropertyValue {
+	/// Creates a new [`PropertyValue`] with the given `type` and `data`.
+	pub fn new(r#type: Atom, data: impl Into<PropertyData>) -> Self {
+		Self {
+			r#type,
+			data: data.into(),
+		}
+	}
+
+	/// Creates a `STRING` property value.
+	///
+	/// `STRING` properties are Latin-1 encoded; only ASCII strings are encoded
+	/// the same way in UTF-8.
+	#[must_use]
+	pub fn string(string: &str) -> Self {
+		Self::new(atoms::STRING, string.as_bytes().to_vec())
+	}
+
+	/// Creates an `ATOM` property value containing the given `atoms`.
+	pub fn atoms(atoms: impl IntoIterator<Item = Atom>) -> Self {
+		Self::new(
+			atoms::ATOM,
+			atoms.into_iter().map(|atom| atom.id).collect::<Vec<_>>(),
+		)
+	}
+
+	/// Creates a `CARDINAL` property value containing the given `cardinals`.
+	pub fn cardinals(cardinals: impl IntoIterator<Item = u32>) -> Self {
+		Self::new(atoms::CARDINAL, cardinals.into_iter().collect::<Vec<_>>())
+	}
+
+	/// Creates an `INTEGER` property value containing the given `integers`.
+	pub fn integers(integers: impl IntoIterator<Item = i32>) -> Self {
+		Self::new(atoms::INTEGER, PropertyData::from_int32s(integers))
+	}
+
+	/// Creates a `WINDOW` property value containing the given `window`.
+	#[must_use]
+	pub fn window(window: Window) -> Self {
+		Self::new(atoms::WINDOW, vec![window.res_id()])
+	}
+}
+
+impl PropertyChanges {
+	/// Creates a new, empty [`PropertyChanges`].
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			properties: Vec::new(),
+		}
+	}
+
+	/// Sets the `value` of the given `property`, replacing any value it was
+	/// already given.
+	pub fn insert(&mut self, property: Atom, value: PropertyValue) {
+		match self
+			.properties
+			.iter_mut()
+			.find(|(other, _)| *other == property)
+		{
+			Some((_, existing)) => *existing = value,
+			None => self.properties.push((property, value)),
+		}
+	}
+
+	/// Sets the `value` of the given `property`, replacing any value it was
+	/// already given.
+	#[must_use]
+	pub fn with(mut self, property: Atom, value: PropertyValue) -> Self {
+		self.insert(property, value);
+		self
+	}
+
+	/// The properties to be changed and their values, in the order that they
+	/// will be changed.
+	#[must_use]
+	pub fn properties(&self) -> &[(Atom, PropertyValue)] {
+		&self.properties
+	}
+
+	/// The number of properties to be changed.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.properties.len()
+	}
+
+	/// Returns whether there are no properties to be changed.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.properties.is_empty()
+	}
+
+	/// Creates the [`ChangeProperty`] requests which set these properties on
+	/// the `target` window, none of which are longer than the
+	/// `max_request_length`.
+	///
+	/// The `max_request_length` is in 4-byte units, as given in the
+	/// connection setup. A value too long to fit in one request is written
+	/// in a [`Replace`] request followed by as many [`Append`] requests as
+	/// needed.
+	///
+	/// # Errors
+	/// Returns a [`BuildError::OutOfRange`] if the `max_request_length` is too
+	/// short for any data to fit in a [`ChangeProperty`] request. The X
+	/// protocol guarantees a maximum request length of at least `4096`.
+	///
+	/// [`Replace`]: PropertyMode::Replace
+	/// [`Append`]: PropertyMode::Append
+	pub fn requests(
+		&self,
+		target: Window,
+		max_request_length: u16,
+	) -> Result<Vec<ChangeProperty>, BuildError> {
+		BuildError::check_range(
+			"maximum request length",
+			max_request_length,
+			CHANGE_PROPERTY_HEADER_LEN + 1,
+			u16::MAX,
+		)?;
+
+		let max_data_len = usize::from(max_request_length - CHANGE_PROPERTY_HEADER_LEN) * 4;
+		let mut requests = Vec::new();
+
+		for (property, value) in &self.properties {
+			let chunk_len = max_data_len / usize::from(value.data.format() / 8);
+
+			for (index, data) in value.data.chunks(chunk_len).into_iter().enumerate() {
+				requests.push(ChangeProperty {
+					mode: if index == 0 {
+						PropertyMode::Replace
+					} else {
+						PropertyMode::Append
+					},
+					target,
+					property: *property,
+					value: PropertyValue {
+						r#type: value.r#type,
+						data,
+					},
+				});
+			}
+		}
+
+		Ok(requests)
+	}
+}
+
+impl FromIterator<(Atom, PropertyValue)> for PropertyChanges {
+	fn from_iter<I: IntoIterator<Item = (Atom, PropertyValue)>>(iter: I) -> Self {
+		let mut changes = Self::new();
+		changes.extend(iter);
+
+		changes
+	}
+}
+
+impl Extend<(Atom, PropertyValue)> for PropertyChanges {
+	fn extend<I: IntoIterator<Item = (Atom, PropertyValue)>>(&mut self, iter: I) {
+		for (property, value) in iter {
+			self.insert(property, value);
+		}
+	}
+}
+
+impl Request for ChangeProperty {
+	fn major_opcode() -> u8 {
+		18
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "requests longer than `u16::MAX` units can't be sent without BIG-REQUESTS"
+	)]
+	fn length(&self) -> u16 {
+		(self.byte_size() / 4) as u16
+	}
+}
+
+impl ByteSize for ChangeProperty {
+	fn byte_size(&self) -> usize {
+		// The header, followed by the data padded to a multiple of 4 bytes.
+		usize::from(CHANGE_PROPERTY_HEADER_LEN) * 4
+			+ padded(self.value.data.unpadded_byte_size())
+	}
+}
+
+impl Writable for ChangeProperty {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the data can't be longer than the request, whose length is at most `u32::MAX` units"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		let data = &self.value.data;
+		let data_len = data.unpadded_byte_size();
+
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(self.mode as u8);
+		writer.put_u16(self.length());
+		writer.put_u32(self.target.res_id());
+		writer.put_u32(self.property.id);
+		writer.put_u32(self.value.r#type.id);
+		writer.put_u8(data.format());
+		writer.put_bytes(0, 3);
+		writer.put_u32(data.len() as u32);
+
+		match data {
+			PropertyData::Format8(data) => writer.put_slice(data),
+			PropertyData::Format16(data) => data.iter().for_each(|&value| writer.put_u16(value)),
+			PropertyData::Format32(data) => data.iter().for_each(|&value| writer.put_u32(value)),
+		}
+
+		writer.put_bytes(0, pad(data_len));
+
+		Ok(())
+	}
+}
+
+impl Readable for ChangeProperty {
+	/// Reads a [`ChangeProperty`] request, not including its major opcode.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		fn ensure(reader: &impl Buf, expected: usize) -> Result<(), ReadError> {
+			if reader.remaining() < expected {
+				return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+					expected,
+					found: reader.remaining(),
+				})));
+			}
+
+			Ok(())
+		}
+
+		// The rest of the header after the major opcode.
+		ensure(reader, 23)?;
+
+		let mode = reader.get_u8();
+		let mode = PropertyMode::from_u8(mode).ok_or(ReadError::UnrecognizedDiscriminant(mode))?;
+		let _length = reader.get_u16();
+
+		let target = Window::new(reader.get_u32());
+		let property = Atom::new(reader.get_u32());
+		let r#type = Atom::new(reader.get_u32());
+
+		let format = reader.get_u8();
+		skip_padding(reader, 3)?;
+		let len = reader.get_u32() as usize;
+
+		let unit = match format {
+			8 | 16 | 32 => usize::from(format / 8),
+			other => return Err(ReadError::UnrecognizedDiscriminant(other)),
+		};
+		let data_len = len.saturating_mul(unit);
+		ensure(reader, data_len.saturating_add(pad(data_len)))?;
+
+		let data = match format {
+			8 => PropertyData::Format8((0..len).map(|_| reader.get_u8()).collect()),
+			16 => PropertyData::Format16((0..len).map(|_| reader.get_u16()).collect()),
+			_ => PropertyData::Format32((0..len).map(|_| reader.get_u32()).collect()),
+		};
+		skip_padding(reader, pad(data_len))?;
+
+		Ok(Self {
+			mode,
+			target,
+			property,
+			value: PropertyValue { r#type, data },
+		})
+	}
+}
+
+impl fmt::Display for LongOutOfRange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"`long` {} of {:#x} does not hold a 32-bit value",
+			self.index, self.value
+		)
+	}
+}
+
+impl Error for LongOutOfRange {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn long_values_are_split_into_appends() {
+		let properties: PropertyChanges = [
+			(atoms::WM_NAME, PropertyValue::string("old")),
+			(atoms::WM_ICON_NAME, PropertyValue::cardinals(0..5)),
+			(atoms::WM_NAME, PropertyValue::string("terminal")),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(properties.len(), 2);
+
+		// Room for 8 bytes of data in each request.
+		let requests = properties.requests(Window::new(1), 8).unwrap();
+
+		let summary: Vec<_> = requests
+			.iter()
+			.map(|request| (request.mode, request.property, request.value.data.len()))
+			.collect();
+		assert_eq!(
+			summary,
+			[
+				(PropertyMode::Replace, atoms::WM_NAME, 8),
+				(PropertyMode::Replace, atoms::WM_ICON_NAME, 2),
+				(PropertyMode::Append, atoms::WM_ICON_NAME, 2),
+				(PropertyMode::Append, atoms::WM_ICON_NAME, 1),
+			]
+		);
+		assert!(requests.iter().all(|request| request.length() <= 8));
+
+		// No room for any data.
+		assert!(properties.requests(Window::new(1), 6).is_err());
+	}
+
+	#[test]
+	fn change_property_round_trips() {
+		let request = ChangeProperty {
+			mode: PropertyMode::Append,
+			target: Window::new(0x0040_0001),
+			property: atoms::WM_NAME,
+			value: PropertyValue::string("xterm"),
+		};
+
+		let mut bytes = vec![];
+		request.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), request.byte_size());
+		assert_eq!(bytes[..4], [18, 2, 0, 8]);
+
+		assert_eq!(
+			ChangeProperty::read_from(&mut &bytes[1..]).unwrap(),
+			request
+		);
+	}
+
+	#[test]
+	fn format32_values_are_32_bits() {
+		// Xlib's representation of `-1`, `0xffff`, and `7`.
+		let data = PropertyData::from_longs(&[-1, 0xffff, 7]).unwrap();
+		assert_eq!(data, PropertyData::Format32(vec![u32::MAX, 0xffff, 7]));
+
+		// Signed values are sign-extended, as `XGetWindowProperty` returns them.
+		assert_eq!(data.to_longs(), Some(vec![-1, 0xffff, 7]));
+		assert_eq!(data.to_int32s(), Some(vec![-1, 0xffff, 7]));
+		assert_eq!(PropertyData::from_int32s([-1, 0xffff, 7]), data);
+
+		// Each value is 4 bytes on the wire, whatever the size of a `long`.
+		let request = ChangeProperty {
+			mode: PropertyMode::Replace,
+			target: Window::new(1),
+			property: atoms::WM_NAME,
+			value: PropertyValue::integers([-1, 0xffff, 7]),
+		};
+		assert_eq!(request.byte_size(), 24 + 3 * 4);
+
+		assert_eq!(PropertyData::Format8(vec![1]).to_longs(), None);
+	}
+
+	#[cfg(all(target_pointer_width = "64", not(windows)))]
+	#[test]
+	fn packed_longs_are_rejected() {
+		// Zero-extended `CARD32`s and sign-extended `INT32`s are both kept.
+		assert_eq!(
+			PropertyData::from_longs(&[0xffff_ffff, -0x8000_0000]),
+			Ok(PropertyData::Format32(vec![u32::MAX, 0x8000_0000]))
+		);
+
+		// Two values packed into one 64-bit `long` would be truncated.
+		assert_eq!(
+			PropertyData::from_longs(&[1, 0x0000_0002_0000_0003]),
+			Err(LongOutOfRange {
+				index: 1,
+				value: 0x0000_0002_0000_0003,
+			})
+		);
+		assert!(PropertyData::from_longs(&[-0x8000_0001]).is_err());
+	}
+}
@@ -0,0 +1,338 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	read::{skip_padding, UnexpectedEndOfData},
+	traits::{Reply, Request},
+	BuildError, Colormap, ResId, Rgb16,
+};
+
+/// The length of a [`QueryColors`] request without its pixels, in 4-byte
+/// units.
+const QUERY_COLORS_HEADER_LEN: u16 = 2;
+
+/// The number of bytes of a [`QueryColorsReply`] before its colors.
+const QUERY_COLORS_REPLY_HEADER_SIZE: usize = 32;
+
+/// Returns the colors stored in the colormap cells with the given `pixels`
+/// values.
+///
+/// # Errors
+/// - [Colormap]
+/// - [Value] -- Generated if a pixel is not a valid index into the
+///   `colormap`.
+///
+/// [Colormap]: crate::x11::errors::Colormap
+/// [Value]: crate::x11::errors::Value
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct QueryColors {
+	pub colormap: Colormap,
+	pub pixels: Vec<u32>,
+}
+
+/// The reply to a [`QueryColors`] request.
+///
+/// Unlike the colors in other replies, each color in the list is followed by
+/// 2 unused bytes.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct QueryColorsReply {
+	/// The sequence number of the [`QueryColors`] request.
+	pub sequence: u16,
+	/// The colors stored in the cells, in the same order as the `pixels` of
+	/// the request.
+	///
+	/// The colors of unallocated cells are undefined.
+	pub colors: Vec<Rgb16>,
+}
+
+/// Returns an error if fewer than `expected` bytes remain in the `reader`.
+fn ensure(reader: &impl Buf, expected: usize) -> Result<(), ReadError> {
+	if reader.remaining() < expected {
+		return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+			expected,
+			found: reader.remaining(),
+		})));
+	}
+
+	Ok(())
+}
+
+impl QueryColors {
+	/// Creates the [`QueryColors`] requests which query the colors of the
+	/// given `pixels` in the `colormap`, none of which are longer than the
+	/// `max_request_length`.
+	///
+	/// The `max_request_length` is in 4-byte units, as given in the
+	/// connection setup. The pixels are split across the requests in order,
+	/// so the replies can be matched back to them with [`zip_replies`].
+	///
+	/// # Errors
+	/// Returns a [`BuildError::OutOfRange`] if the `max_request_length` is too
+	/// short for any pixels to fit in a [`QueryColors`] request. The X
+	/// protocol guarantees a maximum request length of at least `4096`.
+	///
+	/// [`zip_replies`]: QueryColors::zip_replies
+	pub fn batched(
+		colormap: Colormap,
+		pixels: &[u32],
+		max_request_length: u16,
+	) -> Result<Vec<Self>, BuildError> {
+		BuildError::check_range(
+			"maximum request length",
+			max_request_length,
+			QUERY_COLORS_HEADER_LEN + 1,
+			u16::MAX,
+		)?;
+
+		let max_pixels = usize::from(max_request_length - QUERY_COLORS_HEADER_LEN);
+
+		Ok(pixels
+			.chunks(max_pixels)
+			.map(|pixels| Self {
+				colormap,
+				pixels: pixels.to_vec(),
+			})
+			.collect())
+	}
+
+	/// Pairs each pixel of the given `requests` with its color in the
+	/// matching reply, in the order the pixels were requested.
+	///
+	/// The `replies` must be in the same order as the `requests`, such as
+	/// those made by [`batched`]. Returns [`None`] if there isn't a reply for
+	/// every request, or if a reply doesn't have a color for every pixel of
+	/// its request.
+	///
+	/// [`batched`]: QueryColors::batched
+	#[must_use]
+	pub fn zip_replies(
+		requests: &[Self],
+		replies: &[QueryColorsReply],
+	) -> Option<Vec<(u32, Rgb16)>> {
+		if requests.len() != replies.len() {
+			return None;
+		}
+
+		let mut colors = Vec::new();
+
+		for (request, reply) in requests.iter().zip(replies) {
+			if request.pixels.len() != reply.colors.len() {
+				return None;
+			}
+
+			colors.extend(
+				request
+					.pixels
+					.iter()
+					.copied()
+					.zip(reply.colors.iter().copied()),
+			);
+		}
+
+		Some(colors)
+	}
+}
+
+impl Request<QueryColorsReply> for QueryColors {
+	fn major_opcode() -> u8 {
+		91
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "requests longer than `u16::MAX` units can't be sent without BIG-REQUESTS"
+	)]
+	fn length(&self) -> u16 {
+		(self.byte_size() / 4) as u16
+	}
+}
+
+impl ByteSize for QueryColors {
+	fn byte_size(&self) -> usize {
+		usize::from(QUERY_COLORS_HEADER_LEN) * 4 + self.pixels.len() * 4
+	}
+}
+
+impl Writable for QueryColors {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(self.length());
+		writer.put_u32(self.colormap.res_id());
+
+		for &pixel in &self.pixels {
+			writer.put_u32(pixel);
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for QueryColors {
+	/// Reads a [`QueryColors`] request, not including its major opcode.
+	///
+	/// The number of pixels is found from the request's length.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the major opcode.
+		ensure(reader, 7)?;
+
+		skip_padding(reader, 1)?;
+		let length = reader.get_u16();
+		let colormap = Colormap::new(reader.get_u32());
+
+		let len = usize::from(length.saturating_sub(QUERY_COLORS_HEADER_LEN));
+		ensure(reader, len * 4)?;
+
+		Ok(Self {
+			colormap,
+			pixels: (0..len).map(|_| reader.get_u32()).collect(),
+		})
+	}
+}
+
+impl Reply<QueryColors> for QueryColorsReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the reply can't be longer than `u32::MAX` units"
+	)]
+	fn length(&self) -> u32 {
+		(self.colors.len() * 2) as u32
+	}
+
+	fn sequence(&self) -> Option<u16> {
+		Some(self.sequence)
+	}
+}
+
+impl ByteSize for QueryColorsReply {
+	fn byte_size(&self) -> usize {
+		QUERY_COLORS_REPLY_HEADER_SIZE + self.colors.len() * 8
+	}
+}
+
+impl Writable for QueryColorsReply {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "there can't be more colors than pixels in a request"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		// `1` indicates this is a reply.
+		writer.put_u8(1);
+		writer.put_u8(0);
+		writer.put_u16(self.sequence);
+		writer.put_u32(self.length());
+		writer.put_u16(self.colors.len() as u16);
+		writer.put_bytes(0, 22);
+
+		for color in &self.colors {
+			color.write_to(writer)?;
+			writer.put_bytes(0, 2);
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for QueryColorsReply {
+	/// Reads a [`QueryColorsReply`], not including its first byte.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the first byte.
+		ensure(reader, QUERY_COLORS_REPLY_HEADER_SIZE - 1)?;
+
+		skip_padding(reader, 1)?;
+		let sequence = reader.get_u16();
+		let _length = reader.get_u32();
+		let len = usize::from(reader.get_u16());
+		skip_padding(reader, 22)?;
+
+		ensure(reader, len * 8)?;
+
+		let mut colors = Vec::with_capacity(len);
+		for _ in 0..len {
+			colors.push(Rgb16::read_from(reader)?);
+			skip_padding(reader, 2)?;
+		}
+
+		Ok(Self { sequence, colors })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn query_colors_round_trips() {
+		let request = QueryColors {
+			colormap: Colormap::new(0x20),
+			pixels: vec![0, 0x00ff_8000],
+		};
+
+		let mut bytes = vec![];
+		request.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), request.byte_size());
+		assert_eq!(bytes[..4], [91, 0, 0, 4]);
+		assert_eq!(QueryColors::read_from(&mut &bytes[1..]).unwrap(), request);
+	}
+
+	#[test]
+	fn pixels_are_batched() {
+		let colormap = Colormap::new(0x20);
+		let pixels: Vec<u32> = (0..10).collect();
+
+		let requests = QueryColors::batched(colormap, &pixels, 6).unwrap();
+
+		assert_eq!(requests.len(), 3);
+		assert!(requests.iter().all(|request| request.length() <= 6));
+		assert_eq!(requests[2].pixels, [8, 9]);
+		assert!(QueryColors::batched(colormap, &pixels, 2).is_err());
+
+		let replies: Vec<_> = (1..)
+			.zip(&requests)
+			.map(|(sequence, request)| QueryColorsReply {
+				sequence,
+				colors: request
+					.pixels
+					.iter()
+					.map(|&pixel| Rgb16::new(u16::try_from(pixel).unwrap(), 0, 0))
+					.collect(),
+			})
+			.collect();
+
+		let colors = QueryColors::zip_replies(&requests, &replies).unwrap();
+
+		assert_eq!(colors.len(), pixels.len());
+		assert_eq!(colors[9], (9, Rgb16::new(9, 0, 0)));
+		assert!(QueryColors::zip_replies(&requests, &replies[..2]).is_none());
+	}
+
+	#[test]
+	fn reply_colors_are_padded() {
+		let reply = QueryColorsReply {
+			sequence: 7,
+			colors: vec![Rgb16::BLACK, Rgb16::new(0xffff, 0x8080, 0)],
+		};
+
+		let mut bytes = vec![];
+		reply.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), reply.byte_size());
+		assert_eq!(reply.length(), 4);
+		assert_eq!(bytes[40..], [0xff, 0xff, 0x80, 0x80, 0, 0, 0, 0]);
+
+		assert_eq!(
+			QueryColorsReply::read_from(&mut &bytes[1..]).unwrap(),
+			reply
+		);
+		assert!(QueryColorsReply::read_from(&mut &bytes[1..44]).is_err());
+	}
+}
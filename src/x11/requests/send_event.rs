@@ -0,0 +1,333 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	byte_order::ByteOrder,
+	read::UnexpectedEndOfData,
+	traits::{Event, Request},
+	AnyEvent, BuildError, Destination, EventMask, ResId, Window,
+};
+
+/// Sends an `event` to the clients which have selected any of the events in
+/// the `event_mask` on the `destination` window.
+///
+/// If the `event_mask` is empty, the `event` is sent to the client which
+/// created the `destination` window instead; if that client no longer
+/// exists, the `event` is not sent.
+///
+/// If `propagate` is `true` and no client has selected any of the events in
+/// the `event_mask` on the `destination`, the `event` is propagated up the
+/// window hierarchy to the closest ancestor on which a client has, stopping
+/// at any window whose `do_not_propagate_mask` includes them. If the
+/// [`Destination`] is [`InputFocus`], the `event` is not propagated beyond
+/// the focus window.
+///
+/// The `event` must be a core event or an extension event. The X server sets
+/// the [`SENT_FLAG`] in its code and fills in its sequence number, but does
+/// not otherwise change it.
+///
+/// # Errors
+/// - [Window]
+/// - [Value]
+///
+/// [`InputFocus`]: Destination::InputFocus
+/// [`SENT_FLAG`]: AnyEvent::SENT_FLAG
+/// [Window]: crate::x11::errors::Window
+/// [Value]: crate::x11::errors::Value
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SendEvent {
+	pub propagate: bool,
+	pub destination: Destination,
+	pub event_mask: EventMask,
+	/// The event which is sent, in the byte order of the connection.
+	pub event: AnyEvent,
+}
+
+/// Builds a [`SendEvent`] request for a synthetic event.
+///
+/// By default, the event is not propagated and the event mask is empty, so
+/// it is sent to the client which created the destination window. The event
+/// is checked to fit in the 32 bytes of the request, and the [`SENT_FLAG`]
+/// is cleared from its code: the X server sets it itself, and rejects codes
+/// which already have it set. This means an event which was itself received
+/// from a [`SendEvent`] request can be forwarded as it is.
+///
+/// # Examples
+/// ```ignore
+/// let request = SyntheticEventBuilder::new(Destination::Specific(root))
+///     .event_mask(EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY)
+///     .build(&unmap_notify, connection.byte_order())?;
+///
+/// connection.send(request)?;
+/// ```
+///
+/// [`SENT_FLAG`]: AnyEvent::SENT_FLAG
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SyntheticEventBuilder {
+	destination: Destination,
+	propagate: bool,
+	event_mask: EventMask,
+}
+
+impl SyntheticEventBuilder {
+	/// Creates a new [`SyntheticEventBuilder`] which sends its event to the
+	/// given `destination`.
+	#[must_use]
+	pub const fn new(destination: Destination) -> Self {
+		Self {
+			destination,
+			propagate: false,
+			event_mask: EventMask::empty(),
+		}
+	}
+
+	/// Sets whether the event is propagated up the window hierarchy if no
+	/// client has selected the `event_mask` on the destination.
+	#[must_use]
+	pub const fn propagate(mut self, propagate: bool) -> Self {
+		self.propagate = propagate;
+		self
+	}
+
+	/// Sends the event to the clients which have selected any of the events
+	/// in the `event_mask`, rather than to the creator of the destination.
+	#[must_use]
+	pub const fn event_mask(mut self, event_mask: EventMask) -> Self {
+		self.event_mask = event_mask;
+		self
+	}
+
+	/// Builds the [`SendEvent`] request carrying the given `event`, encoded in
+	/// the `byte_order` of the connection it is sent on.
+	///
+	/// # Errors
+	/// Returns a [`BuildError::TooLong`] if the `event` is longer than
+	/// [`AnyEvent::SIZE`] bytes, or a [`BuildError::OutOfRange`] if its code
+	/// is not that of an event. Any error returned while writing the `event`
+	/// is also returned.
+	pub fn build<E>(self, event: &E, byte_order: ByteOrder) -> Result<SendEvent, WriteError>
+	where
+		E: Event + Writable,
+	{
+		Ok(self.build_any(AnyEvent::new(event, byte_order)?)?)
+	}
+
+	/// Builds the [`SendEvent`] request carrying the given, already encoded,
+	/// `event`.
+	///
+	/// # Errors
+	/// Returns a [`BuildError::OutOfRange`] if the `event`'s code is that of
+	/// an error or a reply, rather than an event.
+	pub fn build_any(self, event: AnyEvent) -> Result<SendEvent, BuildError> {
+		// Codes 0 and 1 are errors and replies.
+		BuildError::check_range("event code", event.code(), 2, 0x7f)?;
+
+		let mut bytes = *event.as_bytes();
+		bytes[0] = event.code();
+
+		Ok(SendEvent {
+			propagate: self.propagate,
+			destination: self.destination,
+			event_mask: self.event_mask,
+			event: AnyEvent::from_bytes(bytes),
+		})
+	}
+}
+
+impl Request for SendEvent {
+	fn major_opcode() -> u8 {
+		25
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	fn length(&self) -> u16 {
+		11
+	}
+}
+
+impl ByteSize for SendEvent {
+	fn byte_size(&self) -> usize {
+		44
+	}
+}
+
+impl Writable for SendEvent {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(self.propagate.into());
+		writer.put_u16(self.length());
+		writer.put_u32(match self.destination {
+			Destination::PointerWindow => 0,
+			Destination::InputFocus => 1,
+			Destination::Specific(window) => window.res_id(),
+		});
+		writer.put_u32(self.event_mask.bits());
+		self.event.write_to(writer)?;
+
+		Ok(())
+	}
+}
+
+impl Readable for SendEvent {
+	/// Reads a [`SendEvent`] request, not including its major opcode.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 43 {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: 43,
+				found: reader.remaining(),
+			})));
+		}
+
+		let propagate = reader.get_u8() != 0;
+		// Skip the length, which is always the same.
+		reader.advance(2);
+
+		let destination = match reader.get_u32() {
+			0 => Destination::PointerWindow,
+			1 => Destination::InputFocus,
+			window => Destination::Specific(Window::new(window)),
+		};
+		let event_mask = EventMask::from_bits_truncate(reader.get_u32());
+
+		Ok(Self {
+			propagate,
+			destination,
+			event_mask,
+			event: AnyEvent::read_from(reader)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::x11::{
+		atoms, byte_order::ByteOrder, events::SelectionNotify, read::UnexpectedEventCode,
+		traits::Event, Time,
+	};
+
+	fn selection_notify() -> SelectionNotify {
+		SelectionNotify {
+			_sequence_: 0,
+			time: Time::Specific(0x0102_0304),
+			requestor: Window::new(0x0040_0001),
+			selection: atoms::PRIMARY,
+			target: atoms::STRING,
+			property: Some(atoms::CUT_BUFFER0),
+		}
+	}
+
+	#[test]
+	fn events_are_encoded_in_byte_order() {
+		let msb = AnyEvent::new(&selection_notify(), ByteOrder::MostSignificantFirst).unwrap();
+		let lsb = AnyEvent::new(&selection_notify(), ByteOrder::LeastSignificantFirst).unwrap();
+
+		assert_eq!(msb.code(), SelectionNotify::code());
+		assert!(!msb.is_sent());
+		assert_eq!(msb.as_bytes()[4..8], [0x01, 0x02, 0x03, 0x04]);
+		assert_eq!(lsb.as_bytes()[4..8], [0x04, 0x03, 0x02, 0x01]);
+		// The padding is filled with zeros.
+		assert_eq!(msb.as_bytes()[24..], [0; 8]);
+
+		for (event, byte_order) in [
+			(msb, ByteOrder::MostSignificantFirst),
+			(lsb, ByteOrder::LeastSignificantFirst),
+		] {
+			let decoded: SelectionNotify = event.decode(byte_order).unwrap();
+
+			assert_eq!(decoded.time, Time::Specific(0x0102_0304));
+			assert_eq!(decoded.requestor, Window::new(0x0040_0001));
+			assert_eq!(decoded.property, Some(atoms::CUT_BUFFER0));
+		}
+	}
+
+	#[test]
+	fn sent_events_are_decoded() {
+		let mut bytes = *AnyEvent::new(&selection_notify(), ByteOrder::MostSignificantFirst)
+			.unwrap()
+			.as_bytes();
+		bytes[0] |= AnyEvent::SENT_FLAG;
+
+		let event = AnyEvent::from_bytes(bytes);
+
+		assert!(event.is_sent());
+		assert!(event.is::<SelectionNotify>());
+		assert!(event
+			.decode::<SelectionNotify>(ByteOrder::MostSignificantFirst)
+			.is_ok());
+
+		bytes[0] = 2;
+		let result =
+			AnyEvent::from_bytes(bytes).decode::<SelectionNotify>(ByteOrder::MostSignificantFirst);
+
+		assert!(matches!(
+			result,
+			Err(ReadError::Other(error)) if error.downcast_ref::<UnexpectedEventCode>()
+				== Some(&UnexpectedEventCode { expected: 31, found: 2 })
+		));
+	}
+
+	#[test]
+	fn send_event_round_trips() {
+		let request = SendEvent {
+			propagate: true,
+			destination: Destination::Specific(Window::new(0x0040_0001)),
+			event_mask: EventMask::empty(),
+			event: AnyEvent::new(&selection_notify(), ByteOrder::MostSignificantFirst).unwrap(),
+		};
+
+		let mut bytes = vec![];
+		request.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), request.byte_size());
+		assert_eq!(bytes[..4], [25, 1, 0, 11]);
+		assert_eq!(bytes[12..], request.event.as_bytes()[..]);
+
+		assert_eq!(SendEvent::read_from(&mut &bytes[1..]).unwrap(), request);
+	}
+
+	#[test]
+	fn synthetic_events_are_built() {
+		let event = selection_notify();
+		let request = SyntheticEventBuilder::new(Destination::Specific(event.requestor))
+			.propagate(true)
+			.event_mask(EventMask::PROPERTY_CHANGE)
+			.build(&event, ByteOrder::MostSignificantFirst)
+			.unwrap();
+
+		assert!(request.propagate);
+		assert_eq!(request.destination, Destination::Specific(event.requestor));
+		assert_eq!(request.event_mask, EventMask::PROPERTY_CHANGE);
+		assert!(request.event.is::<SelectionNotify>());
+
+		// An event received from another client can be forwarded, without the
+		// flag set by the X server.
+		let mut bytes = *request.event.as_bytes();
+		bytes[0] |= AnyEvent::SENT_FLAG;
+
+		let forwarded = SyntheticEventBuilder::new(Destination::InputFocus)
+			.build_any(AnyEvent::from_bytes(bytes))
+			.unwrap();
+
+		assert!(!forwarded.propagate);
+		assert_eq!(forwarded.event_mask, EventMask::empty());
+		assert!(!forwarded.event.is_sent());
+		assert_eq!(forwarded.event, request.event);
+
+		// Replies can't be sent as events.
+		bytes[0] = 1;
+		assert!(matches!(
+			SyntheticEventBuilder::new(Destination::InputFocus)
+				.build_any(AnyEvent::from_bytes(bytes)),
+			Err(BuildError::OutOfRange { value: 1, .. })
+		));
+	}
+}
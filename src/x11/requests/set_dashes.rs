@@ -0,0 +1,284 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt};
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::{
+	util::{pad, padded},
+	x11::{
+		read::{skip_padding, UnexpectedEndOfData},
+		traits::Request,
+		GraphicsContext, ResId,
+	},
+};
+
+/// The length of a [`SetDashes`] request without its dash list, in 4-byte
+/// units.
+const SET_DASHES_HEADER_LEN: u16 = 3;
+
+/// The lengths, in pixels, of the alternating dashes and gaps in a dashed
+/// line.
+///
+/// The first length is that of a dash, the second that of the gap after it,
+/// and so on. If there are an odd number of lengths, the list is repeated so
+/// that each length is used for both a dash and a gap.
+///
+/// A dash list must contain at least one length, and no length may be `0`;
+/// the X server would generate a [`Value`] error for either. These are
+/// checked when the dash list is created.
+///
+/// [`Value`]: crate::x11::errors::Value
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DashList(Vec<u8>);
+
+/// The reason a [`DashList`] is not valid.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DashListError {
+	/// The dash list is empty.
+	Empty,
+	/// The dash list contains more than [`DashList::MAX_LEN`] lengths.
+	TooLong {
+		/// The number of lengths in the dash list.
+		len: usize,
+	},
+	/// A length in the dash list is `0`.
+	ZeroLength {
+		/// The index of the length which is `0`.
+		index: usize,
+	},
+}
+
+/// Sets the `dash_offset` and the [dash list] of the given graphics
+/// `context`, which are used when drawing dashed lines.
+///
+/// The `dash_offset` is the number of pixels into the pattern described by
+/// the `dashes` at which each dashed line starts.
+///
+/// A graphics context's `dashes` can also be set to a single length with its
+/// [`dashes`] component, which this request replaces.
+///
+/// # Errors
+/// - [GraphicsContext]
+/// - [Value] -- Generated if the `dashes` are empty or contain a `0`, which a
+///   [`DashList`] does not allow.
+///
+/// [dash list]: DashList
+/// [`dashes`]: crate::x11::GraphicsContextValues::dashes
+/// [GraphicsContext]: crate::x11::errors::GraphicsContext
+/// [Value]: crate::x11::errors::Value
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SetDashes {
+	pub context: GraphicsContext,
+	pub dash_offset: u16,
+	pub dashes: DashList,
+}
+
+impl DashList {
+	/// The maximum number of lengths in a dash list.
+	pub const MAX_LEN: usize = u16::MAX as usize;
+
+	/// Creates a new [`DashList`] from the given `lengths`.
+	///
+	/// # Errors
+	/// Returns [`DashListError::Empty`] if there are no `lengths`,
+	/// [`DashListError::TooLong`] if there are more than [`MAX_LEN`], or
+	/// [`DashListError::ZeroLength`] if any of them is `0`.
+	///
+	/// [`MAX_LEN`]: DashList::MAX_LEN
+	pub fn new(lengths: impl Into<Vec<u8>>) -> Result<Self, DashListError> {
+		let lengths = lengths.into();
+
+		if lengths.is_empty() {
+			return Err(DashListError::Empty);
+		}
+
+		if lengths.len() > Self::MAX_LEN {
+			return Err(DashListError::TooLong { len: lengths.len() });
+		}
+
+		if let Some(index) = lengths.iter().position(|&length| length == 0) {
+			return Err(DashListError::ZeroLength { index });
+		}
+
+		Ok(Self(lengths))
+	}
+
+	/// The lengths of the dashes and gaps in this dash list.
+	#[must_use]
+	pub fn as_slice(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+impl fmt::Display for DashListError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Empty => write!(f, "dash lists may not be empty"),
+
+			Self::TooLong { len } => write!(
+				f,
+				"dash lists may contain at most {} lengths, but this one contains {len}",
+				DashList::MAX_LEN
+			),
+
+			Self::ZeroLength { index } => {
+				write!(
+					f,
+					"dash lengths may not be 0, but the one at index {index} is"
+				)
+			}
+		}
+	}
+}
+
+impl Error for DashListError {}
+
+impl Request for SetDashes {
+	fn major_opcode() -> u8 {
+		58
+	}
+
+	fn minor_opcode() -> Option<u8> {
+		None
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "requests longer than `u16::MAX` units can't be sent without BIG-REQUESTS"
+	)]
+	fn length(&self) -> u16 {
+		(self.byte_size() / 4) as u16
+	}
+}
+
+impl ByteSize for SetDashes {
+	fn byte_size(&self) -> usize {
+		// The header, followed by the dash list padded to a multiple of 4 bytes.
+		usize::from(SET_DASHES_HEADER_LEN) * 4 + padded(self.dashes.0.len())
+	}
+}
+
+impl Writable for SetDashes {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the length of a `DashList` is checked to fit in a `u16` value"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		let len = self.dashes.0.len();
+
+		writer.put_u8(Self::major_opcode());
+		writer.put_u8(0);
+		writer.put_u16(self.length());
+		writer.put_u32(self.context.res_id());
+		writer.put_u16(self.dash_offset);
+		writer.put_u16(len as u16);
+		writer.put_slice(&self.dashes.0);
+		writer.put_bytes(0, pad(len));
+
+		Ok(())
+	}
+}
+
+impl Readable for SetDashes {
+	/// Reads a [`SetDashes`] request, not including its major opcode.
+	///
+	/// # Errors
+	/// Returns a [`DashListError`] if the dash list is empty or contains a
+	/// `0`.
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		// The rest of the header after the major opcode.
+		let header = usize::from(SET_DASHES_HEADER_LEN) * 4 - 1;
+
+		if reader.remaining() < header {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: header,
+				found: reader.remaining(),
+			})));
+		}
+
+		skip_padding(reader, 1)?;
+		// Skip the length, which is found from the number of dashes.
+		reader.advance(2);
+		let context = GraphicsContext::new(reader.get_u32());
+		let dash_offset = reader.get_u16();
+		let len = usize::from(reader.get_u16());
+
+		// The dash list, padded to a multiple of 4 bytes.
+		let padded_len = padded(len);
+
+		if reader.remaining() < padded_len {
+			return Err(ReadError::Other(Box::new(UnexpectedEndOfData {
+				expected: padded_len,
+				found: reader.remaining(),
+			})));
+		}
+
+		let lengths = reader.copy_to_bytes(len).to_vec();
+		skip_padding(reader, padded_len - len)?;
+
+		Ok(Self {
+			context,
+			dash_offset,
+			dashes: DashList::new(lengths).map_err(|error| ReadError::Other(Box::new(error)))?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn dash_lists_are_checked() {
+		assert_eq!(DashList::new([]), Err(DashListError::Empty));
+		assert_eq!(
+			DashList::new([4, 2, 0, 2]),
+			Err(DashListError::ZeroLength { index: 2 })
+		);
+		assert_eq!(
+			DashList::new(vec![1; DashList::MAX_LEN + 1]),
+			Err(DashListError::TooLong {
+				len: DashList::MAX_LEN + 1
+			})
+		);
+
+		assert_eq!(DashList::new([4, 2]).unwrap().as_slice(), [4, 2]);
+	}
+
+	#[test]
+	fn set_dashes_round_trips() {
+		for dashes in [vec![4], vec![4, 2, 1, 2], vec![8, 1, 1, 1, 1]] {
+			let request = SetDashes {
+				context: GraphicsContext::new(0x0040_0002),
+				dash_offset: 3,
+				dashes: DashList::new(dashes).unwrap(),
+			};
+
+			let mut bytes = vec![];
+			request.write_to(&mut bytes).unwrap();
+
+			assert_eq!(bytes.len(), request.byte_size());
+			assert_eq!(bytes.len() % 4, 0);
+			assert_eq!(usize::from(request.length()) * 4, bytes.len());
+			assert_eq!(bytes[0], 58);
+
+			assert_eq!(SetDashes::read_from(&mut &bytes[1..]).unwrap(), request);
+		}
+	}
+
+	#[test]
+	fn zero_length_dashes_are_not_read() {
+		// A `SetDashes` request with the dash list `[4, 0]`.
+		let bytes = [0, 0, 4, 0, 0x40, 0, 2, 0, 0, 0, 2, 4, 0, 0, 0];
+
+		assert!(matches!(
+			SetDashes::read_from(&mut &bytes[..]),
+			Err(ReadError::Other(error)) if error.downcast_ref::<DashListError>()
+				== Some(&DashListError::ZeroLength { index: 1 })
+		));
+	}
+}
@@ -0,0 +1,555 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, Writable, WriteError};
+
+use crate::x11::{
+	image::ColorMasks,
+	read::{ensure_remaining, skip_padding},
+	BackingStore, Colormap, EventMask, ResId, VisualId, Window,
+};
+
+/// How the pixel values of a visual are translated into colors.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum VisualClass {
+	/// Pixel values are shades of gray, and the colormap can't be changed.
+	StaticGray,
+	/// Pixel values are shades of gray, and the colormap can be changed.
+	GrayScale,
+	/// Pixel values are indices into a colormap which can't be changed.
+	StaticColor,
+	/// Pixel values are indices into a colormap which can be changed.
+	PseudoColor,
+	/// Pixel values are split into red, green, and blue channels by the
+	/// visual's [color masks], and the colormap can't be changed.
+	///
+	/// [color masks]: VisualType::color_masks
+	TrueColor,
+	/// Pixel values are split into red, green, and blue channels by the
+	/// visual's [color masks], and each channel is an index into a colormap
+	/// which can be changed.
+	///
+	/// [color masks]: VisualType::color_masks
+	DirectColor,
+}
+
+/// A visual: a way in which the pixel values of windows are displayed as
+/// colors.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VisualType {
+	/// The ID of this visual.
+	pub visual_id: VisualId,
+	/// How pixel values are translated into colors.
+	pub class: VisualClass,
+	/// The number of significant bits in each of the red, green, and blue
+	/// values of a color in this visual's colormaps.
+	pub bits_per_rgb_value: u8,
+	/// The number of entries in a colormap for this visual.
+	pub colormap_entries: u16,
+
+	/// The bits of a pixel which contain its red channel.
+	///
+	/// This is only meaningful for [`TrueColor`] and [`DirectColor`] visuals.
+	///
+	/// [`TrueColor`]: VisualClass::TrueColor
+	/// [`DirectColor`]: VisualClass::DirectColor
+	pub red_mask: u32,
+	/// The bits of a pixel which contain its green channel.
+	///
+	/// This is only meaningful for [`TrueColor`] and [`DirectColor`] visuals.
+	///
+	/// [`TrueColor`]: VisualClass::TrueColor
+	/// [`DirectColor`]: VisualClass::DirectColor
+	pub green_mask: u32,
+	/// The bits of a pixel which contain its blue channel.
+	///
+	/// This is only meaningful for [`TrueColor`] and [`DirectColor`] visuals.
+	///
+	/// [`TrueColor`]: VisualClass::TrueColor
+	/// [`DirectColor`]: VisualClass::DirectColor
+	pub blue_mask: u32,
+}
+
+/// A depth supported by a [`Screen`], and the visuals which windows of that
+/// depth may use.
+///
+/// A depth with no visuals may only be used for pixmaps.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Depth {
+	/// The number of bits in each pixel.
+	pub depth: u8,
+	/// The visuals which windows of this depth may use.
+	pub visual_types: Vec<VisualType>,
+}
+
+/// A screen of the X server, as listed in the connection setup.
+///
+/// # Examples
+/// ```ignore
+/// let screen = &setup.roots[0];
+///
+/// // Prefer a 32-bit `TrueColor` visual, so that windows can be translucent.
+/// let visual = screen
+///     .find_visual(32, VisualClass::TrueColor)
+///     .or_else(|| screen.root_visual_type())
+///     .expect("the root visual is listed");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Screen {
+	/// The root window of this screen.
+	pub root: Window,
+	/// The colormap which the root window is created with.
+	pub default_colormap: Colormap,
+	/// The pixel value of white in the `default_colormap`.
+	pub white_pixel: u32,
+	/// The pixel value of black in the `default_colormap`.
+	pub black_pixel: u32,
+	/// The events which all clients have selected on the root window when the
+	/// connection is set up.
+	pub current_input_masks: EventMask,
+
+	/// The width of this screen, in pixels.
+	pub width_in_pixels: u16,
+	/// The height of this screen, in pixels.
+	pub height_in_pixels: u16,
+	/// The width of this screen, in millimeters.
+	pub width_in_millimeters: u16,
+	/// The height of this screen, in millimeters.
+	pub height_in_millimeters: u16,
+
+	/// The minimum number of colormaps which can be installed at once.
+	pub min_installed_maps: u16,
+	/// The maximum number of colormaps which can be installed at once.
+	pub max_installed_maps: u16,
+
+	/// The visual of the root window.
+	pub root_visual: VisualId,
+	/// When this screen supports maintaining the contents of obscured
+	/// windows.
+	pub backing_stores: BackingStore,
+	/// Whether this screen supports saving the contents of windows obscured
+	/// by windows with `save_under` set.
+	pub save_unders: bool,
+	/// The depth of the root window.
+	pub root_depth: u8,
+	/// The depths which this screen supports, and the visuals of each.
+	pub allowed_depths: Vec<Depth>,
+}
+
+impl VisualClass {
+	/// Returns whether pixel values of this class are displayed in color,
+	/// rather than as shades of gray.
+	#[must_use]
+	pub const fn is_color(self) -> bool {
+		!matches!(self, Self::StaticGray | Self::GrayScale)
+	}
+
+	/// Returns whether colormaps for visuals of this class can be changed.
+	#[must_use]
+	pub const fn is_dynamic(self) -> bool {
+		matches!(
+			self,
+			Self::GrayScale | Self::PseudoColor | Self::DirectColor
+		)
+	}
+
+	const fn from_u8(class: u8) -> Option<Self> {
+		match class {
+			0 => Some(Self::StaticGray),
+			1 => Some(Self::GrayScale),
+			2 => Some(Self::StaticColor),
+			3 => Some(Self::PseudoColor),
+			4 => Some(Self::TrueColor),
+			5 => Some(Self::DirectColor),
+
+			_ => None,
+		}
+	}
+}
+
+impl VisualType {
+	/// The size of a [`VisualType`] in the connection setup, in bytes.
+	const SIZE: usize = 24;
+
+	/// The bits of a pixel which contain each of its color channels, if this
+	/// is a [`TrueColor`] or [`DirectColor`] visual.
+	///
+	/// [`TrueColor`]: VisualClass::TrueColor
+	/// [`DirectColor`]: VisualClass::DirectColor
+	#[must_use]
+	pub const fn color_masks(&self) -> Option<ColorMasks> {
+		match self.class {
+			VisualClass::TrueColor | VisualClass::DirectColor => Some(ColorMasks::new(
+				self.red_mask,
+				self.green_mask,
+				self.blue_mask,
+			)),
+
+			_ => None,
+		}
+	}
+}
+
+impl Depth {
+	/// The size of a [`Depth`] in the connection setup without its visuals,
+	/// in bytes.
+	const HEADER_SIZE: usize = 8;
+
+	/// Returns the visual of this depth with the given `class`, if there is
+	/// one.
+	#[must_use]
+	pub fn find_visual(&self, class: VisualClass) -> Option<&VisualType> {
+		self.visual_types
+			.iter()
+			.find(|visual_type| visual_type.class == class)
+	}
+}
+
+impl Screen {
+	/// The size of a [`Screen`] in the connection setup without its depths,
+	/// in bytes.
+	const HEADER_SIZE: usize = 40;
+
+	/// Returns the [`VisualType`] of the `root_visual`, if it is listed.
+	#[must_use]
+	pub fn root_visual_type(&self) -> Option<&VisualType> {
+		self.visual_type(self.root_visual)
+			.map(|(_, visual_type)| visual_type)
+	}
+
+	/// Returns the allowed [`Depth`] with the given `depth`, if there is one.
+	#[must_use]
+	pub fn depth(&self, depth: u8) -> Option<&Depth> {
+		self.allowed_depths
+			.iter()
+			.find(|allowed| allowed.depth == depth)
+	}
+
+	/// Returns an iterator over every visual of this screen, with the depth
+	/// which it is listed for.
+	pub fn visual_types(&self) -> impl Iterator<Item = (u8, &VisualType)> {
+		self.allowed_depths.iter().flat_map(|depth| {
+			depth
+				.visual_types
+				.iter()
+				.map(move |visual_type| (depth.depth, visual_type))
+		})
+	}
+
+	/// Returns the visual with the given `id`, with the depth which it is
+	/// listed for, if there is one.
+	#[must_use]
+	pub fn visual_type(&self, id: VisualId) -> Option<(u8, &VisualType)> {
+		self.visual_types()
+			.find(|(_, visual_type)| visual_type.visual_id == id)
+	}
+
+	/// Returns the first visual of the given `depth` with the given `class`,
+	/// if there is one.
+	#[must_use]
+	pub fn find_visual(&self, depth: u8, class: VisualClass) -> Option<&VisualType> {
+		self.depth(depth)?.find_visual(class)
+	}
+}
+
+impl ByteSize for VisualType {
+	fn byte_size(&self) -> usize {
+		Self::SIZE
+	}
+}
+
+impl Writable for VisualType {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u32(self.visual_id.id());
+		writer.put_u8(self.class as u8);
+		writer.put_u8(self.bits_per_rgb_value);
+		writer.put_u16(self.colormap_entries);
+		writer.put_u32(self.red_mask);
+		writer.put_u32(self.green_mask);
+		writer.put_u32(self.blue_mask);
+		writer.put_bytes(0, 4);
+
+		Ok(())
+	}
+}
+
+impl Readable for VisualType {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure_remaining(reader, Self::SIZE)?;
+
+		let visual_id = VisualId::new(reader.get_u32());
+		let class = reader.get_u8();
+		let class =
+			VisualClass::from_u8(class).ok_or(ReadError::UnrecognizedDiscriminant(class))?;
+
+		let visual_type = Self {
+			visual_id,
+			class,
+			bits_per_rgb_value: reader.get_u8(),
+			colormap_entries: reader.get_u16(),
+			red_mask: reader.get_u32(),
+			green_mask: reader.get_u32(),
+			blue_mask: reader.get_u32(),
+		};
+		skip_padding(reader, 4)?;
+
+		Ok(visual_type)
+	}
+}
+
+impl ByteSize for Depth {
+	fn byte_size(&self) -> usize {
+		Self::HEADER_SIZE + self.visual_types.len() * VisualType::SIZE
+	}
+}
+
+impl Writable for Depth {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the connection setup can't list more than `u16::MAX` visuals for a depth"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u8(self.depth);
+		writer.put_u8(0);
+		writer.put_u16(self.visual_types.len() as u16);
+		writer.put_bytes(0, 4);
+
+		for visual_type in &self.visual_types {
+			visual_type.write_to(writer)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for Depth {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure_remaining(reader, Self::HEADER_SIZE)?;
+
+		let depth = reader.get_u8();
+		skip_padding(reader, 1)?;
+		let len = usize::from(reader.get_u16());
+		skip_padding(reader, 4)?;
+
+		Ok(Self {
+			depth,
+			visual_types: (0..len)
+				.map(|_| VisualType::read_from(reader))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+impl ByteSize for Screen {
+	fn byte_size(&self) -> usize {
+		Self::HEADER_SIZE
+			+ self
+				.allowed_depths
+				.iter()
+				.map(Depth::byte_size)
+				.sum::<usize>()
+	}
+}
+
+impl Writable for Screen {
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "the connection setup can't list more than `u8::MAX` depths for a screen"
+	)]
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u32(self.root.res_id());
+		writer.put_u32(self.default_colormap.res_id());
+		writer.put_u32(self.white_pixel);
+		writer.put_u32(self.black_pixel);
+		writer.put_u32(self.current_input_masks.bits());
+		writer.put_u16(self.width_in_pixels);
+		writer.put_u16(self.height_in_pixels);
+		writer.put_u16(self.width_in_millimeters);
+		writer.put_u16(self.height_in_millimeters);
+		writer.put_u16(self.min_installed_maps);
+		writer.put_u16(self.max_installed_maps);
+		writer.put_u32(self.root_visual.id());
+		writer.put_u8(match self.backing_stores {
+			BackingStore::NotUseful => 0,
+			BackingStore::WhenMapped => 1,
+			BackingStore::Always => 2,
+		});
+		writer.put_u8(self.save_unders.into());
+		writer.put_u8(self.root_depth);
+		writer.put_u8(self.allowed_depths.len() as u8);
+
+		for depth in &self.allowed_depths {
+			depth.write_to(writer)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Readable for Screen {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		ensure_remaining(reader, Self::HEADER_SIZE)?;
+
+		let root = Window::new(reader.get_u32());
+		let default_colormap = Colormap::new(reader.get_u32());
+		let white_pixel = reader.get_u32();
+		let black_pixel = reader.get_u32();
+		let current_input_masks = EventMask::from_bits_truncate(reader.get_u32());
+		let width_in_pixels = reader.get_u16();
+		let height_in_pixels = reader.get_u16();
+		let width_in_millimeters = reader.get_u16();
+		let height_in_millimeters = reader.get_u16();
+		let min_installed_maps = reader.get_u16();
+		let max_installed_maps = reader.get_u16();
+		let root_visual = VisualId::new(reader.get_u32());
+		let backing_stores = match reader.get_u8() {
+			0 => BackingStore::NotUseful,
+			1 => BackingStore::WhenMapped,
+			2 => BackingStore::Always,
+
+			other => return Err(ReadError::UnrecognizedDiscriminant(other)),
+		};
+		let save_unders = reader.get_u8() != 0;
+		let root_depth = reader.get_u8();
+		let len = reader.get_u8();
+
+		Ok(Self {
+			root,
+			default_colormap,
+			white_pixel,
+			black_pixel,
+			current_input_masks,
+			width_in_pixels,
+			height_in_pixels,
+			width_in_millimeters,
+			height_in_millimeters,
+			min_installed_maps,
+			max_installed_maps,
+			root_visual,
+			backing_stores,
+			save_unders,
+			root_depth,
+			allowed_depths: (0..len)
+				.map(|_| Depth::read_from(reader))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn visual_type(id: u32, class: VisualClass) -> VisualType {
+		VisualType {
+			visual_id: VisualId::new(id),
+			class,
+			bits_per_rgb_value: 8,
+			colormap_entries: 256,
+			red_mask: 0x00ff_0000,
+			green_mask: 0x0000_ff00,
+			blue_mask: 0x0000_00ff,
+		}
+	}
+
+	fn screen() -> Screen {
+		Screen {
+			root: Window::new(0x0000_0530),
+			default_colormap: Colormap::new(0x0000_0020),
+			white_pixel: 0x00ff_ffff,
+			black_pixel: 0,
+			current_input_masks: EventMask::empty(),
+			width_in_pixels: 1920,
+			height_in_pixels: 1080,
+			width_in_millimeters: 508,
+			height_in_millimeters: 285,
+			min_installed_maps: 1,
+			max_installed_maps: 1,
+			root_visual: VisualId::new(0x21),
+			backing_stores: BackingStore::WhenMapped,
+			save_unders: false,
+			root_depth: 24,
+			allowed_depths: vec![
+				Depth {
+					depth: 24,
+					visual_types: vec![
+						visual_type(0x21, VisualClass::TrueColor),
+						visual_type(0x22, VisualClass::DirectColor),
+					],
+				},
+				Depth {
+					depth: 1,
+					visual_types: vec![],
+				},
+				Depth {
+					depth: 32,
+					visual_types: vec![visual_type(0x5e, VisualClass::TrueColor)],
+				},
+			],
+		}
+	}
+
+	#[test]
+	fn visuals_are_found() {
+		let screen = screen();
+
+		assert_eq!(
+			screen.root_visual_type().map(|visual| visual.visual_id),
+			Some(VisualId::new(0x21))
+		);
+		assert_eq!(
+			screen
+				.find_visual(32, VisualClass::TrueColor)
+				.map(|visual| visual.visual_id),
+			Some(VisualId::new(0x5e))
+		);
+		assert_eq!(screen.find_visual(1, VisualClass::TrueColor), None);
+		assert_eq!(screen.find_visual(8, VisualClass::PseudoColor), None);
+
+		assert_eq!(
+			screen
+				.visual_types()
+				.map(|(depth, visual)| (depth, visual.visual_id.id()))
+				.collect::<Vec<_>>(),
+			[(24, 0x21), (24, 0x22), (32, 0x5e)]
+		);
+		assert_eq!(
+			screen
+				.visual_type(VisualId::new(0x5e))
+				.map(|(depth, _)| depth),
+			Some(32)
+		);
+	}
+
+	#[test]
+	fn color_masks_are_only_given_for_color_channels() {
+		assert_eq!(
+			visual_type(0x21, VisualClass::TrueColor).color_masks(),
+			Some(ColorMasks::new(0x00ff_0000, 0x0000_ff00, 0x0000_00ff))
+		);
+		assert_eq!(
+			visual_type(0x21, VisualClass::PseudoColor).color_masks(),
+			None
+		);
+
+		assert!(VisualClass::DirectColor.is_color());
+		assert!(VisualClass::DirectColor.is_dynamic());
+		assert!(!VisualClass::StaticGray.is_color());
+		assert!(!VisualClass::TrueColor.is_dynamic());
+	}
+
+	#[test]
+	fn screens_round_trip() {
+		let screen = screen();
+
+		let mut bytes = vec![];
+		screen.write_to(&mut bytes).unwrap();
+
+		assert_eq!(bytes.len(), screen.byte_size());
+		assert_eq!(bytes.len(), 40 + 8 + 2 * 24 + 8 + 8 + 24);
+
+		assert_eq!(Screen::read_from(&mut &bytes[..]).unwrap(), screen);
+	}
+}
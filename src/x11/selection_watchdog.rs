@@ -0,0 +1,334 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use crate::x11::{
+	extensions::xfixes::{SelectSelectionInput, SelectionNotify},
+	requests::{GetSelectionOwner, GetSelectionOwnerReply},
+	Atom, SelectionEventMask, Window,
+};
+
+/// Watches for changes to the owners of selections, such as `CLIPBOARD`.
+///
+/// The core protocol only tells a selection's owner when it loses the
+/// selection, so other clients, such as clipboard managers, must poll each
+/// selection's owner with [`GetSelectionOwner`] requests. The XFIXES
+/// extension instead sends [`SelectionNotify`] events whenever a selection's
+/// owner changes, once they are selected with [`SelectSelectionInput`].
+///
+/// The watchdog uses XFIXES if it is told to with [`use_xfixes`], and polls
+/// otherwise. Either way, each change to a selection's owner is returned by
+/// [`owner_polled`] or [`selection_notify`] as an [`OwnerChange`], so the
+/// rest of the client need not know which was used.
+///
+/// Replies and events must be given in the order they are received, so that
+/// each is applied after the ones which it follows.
+///
+/// # Examples
+/// ```ignore
+/// let mut watchdog = SelectionWatchdog::new();
+///
+/// // If the XFIXES extension is available...
+/// for request in watchdog.use_xfixes(window) {
+///     connection.send(request)?;
+/// }
+///
+/// if let Some(request) = watchdog.watch(clipboard) {
+///     connection.send(request)?;
+/// }
+///
+/// // Periodically, for selections whose owners need to be polled...
+/// for request in watchdog.poll_requests() {
+///     let reply = connection.send_and_receive(request)?;
+///
+///     if let Some(change) = watchdog.owner_polled(request.selection, &reply) {
+///         println!("{:?} is now owned by {:?}", change.selection, change.owner);
+///     }
+/// }
+///
+/// // For each XFIXES `SelectionNotify` event...
+/// if let Some(change) = watchdog.selection_notify(&event) { /* ... */ }
+/// ```
+///
+/// [`use_xfixes`]: SelectionWatchdog::use_xfixes
+/// [`owner_polled`]: SelectionWatchdog::owner_polled
+/// [`selection_notify`]: SelectionWatchdog::selection_notify
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SelectionWatchdog {
+	/// The window which XFIXES [`SelectionNotify`] events are selected on, if
+	/// XFIXES is used.
+	xfixes_window: Option<Window>,
+	/// Each selection being watched, and its owner, if it is known.
+	selections: HashMap<Atom, Owner>,
+}
+
+/// The owner of a selection watched by a [`SelectionWatchdog`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Owner {
+	/// The owner has not been polled yet.
+	Unknown,
+	/// The owner, or [`None`] if the selection has no owner.
+	Known(Option<Window>),
+}
+
+/// A change to the owner of a selection, as returned by
+/// [`SelectionWatchdog::owner_polled`] and
+/// [`SelectionWatchdog::selection_notify`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OwnerChange {
+	/// The selection whose owner changed.
+	pub selection: Atom,
+	/// The previous owner of the `selection`, or [`None`] if it had no owner.
+	pub previous: Option<Window>,
+	/// The new owner of the `selection`, or [`None`] if it has no owner.
+	pub owner: Option<Window>,
+}
+
+/// The changes to a selection's owner which the watchdog selects with XFIXES.
+const SELECTION_EVENTS: SelectionEventMask = SelectionEventMask::all();
+
+impl SelectionWatchdog {
+	/// Creates a new [`SelectionWatchdog`] which is not watching any
+	/// selections, and which polls their owners until told to
+	/// [use XFIXES].
+	///
+	/// [use XFIXES]: SelectionWatchdog::use_xfixes
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Uses XFIXES [`SelectionNotify`] events, selected on the given
+	/// `window`, rather than polling, returning the requests to select them
+	/// for each selection which is already watched.
+	///
+	/// This should only be used once the X server has replied to an XFIXES
+	/// `QueryVersion` request.
+	pub fn use_xfixes(&mut self, window: Window) -> Vec<SelectSelectionInput> {
+		self.xfixes_window = Some(window);
+
+		self.selections
+			.keys()
+			.map(|&selection| SelectSelectionInput {
+				window,
+				selection,
+				event_mask: SELECTION_EVENTS,
+			})
+			.collect()
+	}
+
+	/// Returns whether XFIXES [`SelectionNotify`] events are used rather than
+	/// polling.
+	#[must_use]
+	pub const fn uses_xfixes(&self) -> bool {
+		self.xfixes_window.is_some()
+	}
+
+	/// Starts watching the given `selection`, returning the request to select
+	/// XFIXES [`SelectionNotify`] events for it, if XFIXES is used.
+	///
+	/// Its owner is unknown until it is first polled.
+	pub fn watch(&mut self, selection: Atom) -> Option<SelectSelectionInput> {
+		self.selections.entry(selection).or_insert(Owner::Unknown);
+
+		self.xfixes_window.map(|window| SelectSelectionInput {
+			window,
+			selection,
+			event_mask: SELECTION_EVENTS,
+		})
+	}
+
+	/// Stops watching the given `selection`, returning the request to stop
+	/// XFIXES [`SelectionNotify`] events from being sent for it, if XFIXES
+	/// is used.
+	pub fn unwatch(&mut self, selection: Atom) -> Option<SelectSelectionInput> {
+		self.selections.remove(&selection)?;
+
+		self.xfixes_window.map(|window| SelectSelectionInput {
+			window,
+			selection,
+			event_mask: SelectionEventMask::empty(),
+		})
+	}
+
+	/// The owner of the given `selection`, if it is watched and its owner is
+	/// known.
+	///
+	/// [`None`] is returned if the selection has no owner, too; use
+	/// [`is_known`] to tell the two apart.
+	///
+	/// [`is_known`]: SelectionWatchdog::is_known
+	#[must_use]
+	pub fn owner(&self, selection: Atom) -> Option<Window> {
+		match self.selections.get(&selection)? {
+			Owner::Known(owner) => *owner,
+			Owner::Unknown => None,
+		}
+	}
+
+	/// Returns whether the owner of the given `selection` is known, which is
+	/// only the case if it is watched and has been polled.
+	#[must_use]
+	pub fn is_known(&self, selection: Atom) -> bool {
+		matches!(self.selections.get(&selection), Some(Owner::Known(_)))
+	}
+
+	/// The [`GetSelectionOwner`] requests to poll the owners of the watched
+	/// selections, in no particular order.
+	///
+	/// If XFIXES is used, only the selections whose owners are not yet known
+	/// need to be polled, after which their changes are sent as events.
+	/// Otherwise, every watched selection is polled.
+	pub fn poll_requests(&self) -> impl Iterator<Item = GetSelectionOwner> + '_ {
+		self.selections
+			.iter()
+			.filter(|(_, owner)| !self.uses_xfixes() || **owner == Owner::Unknown)
+			.map(|(&selection, _)| GetSelectionOwner { selection })
+	}
+
+	/// Handles the `reply` to a [`GetSelectionOwner`] request polling the
+	/// owner of the given `selection`, returning the change, if its owner has
+	/// changed since it was last known.
+	///
+	/// The first time a selection's owner is polled is not a change. Replies
+	/// for selections which are not watched are ignored.
+	pub fn owner_polled(
+		&mut self,
+		selection: Atom,
+		reply: &GetSelectionOwnerReply,
+	) -> Option<OwnerChange> {
+		self.owner_found(selection, reply.owner)
+	}
+
+	/// Handles the given XFIXES [`SelectionNotify`] `event`, returning the
+	/// change, if its selection's owner has changed since it was last known.
+	///
+	/// Events for selections which are not watched, or whose owners have not
+	/// been polled yet, are ignored, as the previous owner is not known.
+	pub fn selection_notify(&mut self, event: &SelectionNotify) -> Option<OwnerChange> {
+		match self.selections.get(&event.selection)? {
+			Owner::Known(_) => self.owner_found(event.selection, event.owner),
+			Owner::Unknown => None,
+		}
+	}
+
+	/// Records that the given `selection` is owned by the given `owner`,
+	/// returning the change, if the previous owner was known and is
+	/// different.
+	fn owner_found(&mut self, selection: Atom, owner: Option<Window>) -> Option<OwnerChange> {
+		let known = self.selections.get_mut(&selection)?;
+		let previous = std::mem::replace(known, Owner::Known(owner));
+
+		match previous {
+			Owner::Known(previous) if previous != owner => Some(OwnerChange {
+				selection,
+				previous,
+				owner,
+			}),
+
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::x11::extensions::xfixes::SelectionEvent;
+
+	const CLIPBOARD: Atom = Atom::new(0x0000_01a0);
+	const WINDOW: Window = Window::new(0x0040_0001);
+	const EDITOR: Window = Window::new(0x0060_0001);
+	const BROWSER: Window = Window::new(0x0080_0001);
+
+	fn reply(owner: Option<Window>) -> GetSelectionOwnerReply {
+		GetSelectionOwnerReply {
+			__sequence: 0,
+			__major_opcode: None,
+			__minor_opcode: None,
+			owner,
+		}
+	}
+
+	fn notify(owner: Option<Window>, subtype: SelectionEvent) -> SelectionNotify {
+		SelectionNotify {
+			_sequence_: 0,
+			subtype,
+			window: WINDOW,
+			owner,
+			selection: CLIPBOARD,
+			timestamp: 0,
+			selection_timestamp: 0,
+		}
+	}
+
+	#[test]
+	fn owners_are_polled_without_xfixes() {
+		let mut watchdog = SelectionWatchdog::new();
+
+		assert!(watchdog.watch(CLIPBOARD).is_none());
+		assert!(!watchdog.is_known(CLIPBOARD));
+
+		// The first poll finds the owner, which is not a change.
+		assert_eq!(watchdog.owner_polled(CLIPBOARD, &reply(Some(EDITOR))), None);
+		assert_eq!(watchdog.owner(CLIPBOARD), Some(EDITOR));
+
+		assert_eq!(
+			watchdog.owner_polled(CLIPBOARD, &reply(Some(BROWSER))),
+			Some(OwnerChange {
+				selection: CLIPBOARD,
+				previous: Some(EDITOR),
+				owner: Some(BROWSER),
+			})
+		);
+		assert_eq!(
+			watchdog.owner_polled(CLIPBOARD, &reply(Some(BROWSER))),
+			None
+		);
+
+		// The selection keeps being polled.
+		let polled: Vec<_> = watchdog
+			.poll_requests()
+			.map(|request| request.selection)
+			.collect();
+		assert_eq!(polled, [CLIPBOARD]);
+	}
+
+	#[test]
+	fn owners_follow_xfixes_events_once_known() {
+		let mut watchdog = SelectionWatchdog::new();
+		watchdog.watch(CLIPBOARD);
+
+		let requests = watchdog.use_xfixes(WINDOW);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].selection, CLIPBOARD);
+		assert_eq!(requests[0].event_mask, SelectionEventMask::all());
+
+		// The previous owner isn't known until the selection is polled.
+		assert_eq!(
+			watchdog.selection_notify(&notify(Some(EDITOR), SelectionEvent::SetSelectionOwner)),
+			None
+		);
+		assert_eq!(watchdog.poll_requests().count(), 1);
+
+		watchdog.owner_polled(CLIPBOARD, &reply(Some(EDITOR)));
+		assert_eq!(watchdog.poll_requests().count(), 0);
+
+		assert_eq!(
+			watchdog.selection_notify(&notify(None, SelectionEvent::SelectionClientClose)),
+			Some(OwnerChange {
+				selection: CLIPBOARD,
+				previous: Some(EDITOR),
+				owner: None,
+			})
+		);
+		assert!(watchdog.is_known(CLIPBOARD));
+		assert_eq!(watchdog.owner(CLIPBOARD), None);
+
+		let request = watchdog.unwatch(CLIPBOARD).unwrap();
+		assert_eq!(request.event_mask, SelectionEventMask::empty());
+		assert!(!watchdog.is_known(CLIPBOARD));
+	}
+}
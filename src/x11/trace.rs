@@ -0,0 +1,250 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::fmt;
+
+use bytes::{buf::UninitSlice, BufMut};
+use cornflakes::WriteError;
+
+/// The number of bytes shown for each field of a [`Trace`]; the rest are
+/// elided.
+const MAX_SHOWN_BYTES: usize = 8;
+
+/// Messages which can be written as a human-readable [`Trace`] of their
+/// fields.
+///
+/// This is implemented by `define!` for every message it defines. A trace
+/// shows the offset, bytes, name, and value of each field, as well as of the
+/// message's header and unused bytes:
+/// ```text
+/// MapWindow (8 bytes)
+///   0x0000  08                          major_opcode: 8
+///   0x0001  00                          unused
+///   0x0002  00 02                       length: 2
+///   0x0004  00 40 00 01                 target: Window { id: 4194305 }
+/// ```
+///
+/// Every field must implement [`Debug`](fmt::Debug) so that its value can be
+/// shown.
+pub trait PrettyPrint {
+	/// The name of the message.
+	const NAME: &'static str;
+
+	/// Writes this message to the given `trace`, recording each of its fields
+	/// as it is written.
+	///
+	/// # Errors
+	/// Returns an error if one of the fields could not be written.
+	fn trace(&self, trace: &mut Trace) -> Result<(), WriteError>;
+
+	/// Returns a human-readable dump of this message's fields.
+	///
+	/// # Errors
+	/// Returns an error if one of the fields could not be written.
+	fn pretty_print(&self) -> Result<String, WriteError> {
+		let mut trace = Trace::new(Self::NAME);
+		self.trace(&mut trace)?;
+
+		Ok(trace.to_string())
+	}
+}
+
+/// A writer which records the name, value, and bytes of each field written to
+/// it.
+///
+/// This is created by [`PrettyPrint::pretty_print`], or can be given to
+/// [`PrettyPrint::trace`] directly.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Trace {
+	name: &'static str,
+	bytes: Vec<u8>,
+	fields: Vec<TraceField>,
+	/// The offset of the first byte which has not been recorded as part of a
+	/// field.
+	start: usize,
+}
+
+/// A field recorded in a [`Trace`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TraceField {
+	name: &'static str,
+	offset: usize,
+	len: usize,
+	/// The value of the field, or [`None`] for unused bytes.
+	value: Option<String>,
+}
+
+impl Trace {
+	/// Creates a new, empty [`Trace`] of the message with the given `name`.
+	#[must_use]
+	pub const fn new(name: &'static str) -> Self {
+		Self {
+			name,
+			bytes: Vec::new(),
+			fields: Vec::new(),
+			start: 0,
+		}
+	}
+
+	/// Records the bytes written since the last field as a field with the
+	/// given `name` and `value`.
+	pub fn field(&mut self, name: &'static str, value: &dyn fmt::Debug) {
+		self.record(name, Some(format!("{value:?}")));
+	}
+
+	/// Records the bytes written since the last field as unused bytes.
+	pub fn unused(&mut self) {
+		self.record("unused", None);
+	}
+
+	fn record(&mut self, name: &'static str, value: Option<String>) {
+		self.fields.push(TraceField {
+			name,
+			offset: self.start,
+			len: self.bytes.len() - self.start,
+			value,
+		});
+
+		self.start = self.bytes.len();
+	}
+
+	/// The name of the traced message.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	/// The bytes written to this trace.
+	#[must_use]
+	pub fn bytes(&self) -> &[u8] {
+		&self.bytes
+	}
+
+	/// The fields recorded in this trace, in the order they were written.
+	#[must_use]
+	pub fn fields(&self) -> &[TraceField] {
+		&self.fields
+	}
+}
+
+impl TraceField {
+	/// The name of the field, or `unused` for unused bytes.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	/// The offset of the field from the start of the message, in bytes.
+	#[must_use]
+	pub const fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// The number of bytes the field was written as.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether the field was written as no bytes at all, such as an
+	/// empty list.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// The value of the field, formatted with [`Debug`], or [`None`] for
+	/// unused bytes.
+	///
+	/// [`Debug`]: fmt::Debug
+	#[must_use]
+	pub fn value(&self) -> Option<&str> {
+		self.value.as_deref()
+	}
+}
+
+impl fmt::Display for Trace {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} ({} bytes)", self.name, self.bytes.len())?;
+
+		for field in &self.fields {
+			let bytes = &self.bytes[field.offset..][..field.len];
+
+			let mut hex = bytes
+				.iter()
+				.take(MAX_SHOWN_BYTES)
+				.map(|byte| format!("{byte:02x}"))
+				.collect::<Vec<_>>()
+				.join(" ");
+			if bytes.len() > MAX_SHOWN_BYTES {
+				hex.push_str(" ..");
+			}
+
+			write!(f, "\n  {:#06x}  {hex:<26}  {}", field.offset, field.name)?;
+
+			if let Some(value) = &field.value {
+				write!(f, ": {value}")?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+// SAFETY: every method is forwarded to the `Vec<u8>` of bytes, which upholds
+// the requirements of `BufMut` itself.
+unsafe impl BufMut for Trace {
+	fn remaining_mut(&self) -> usize {
+		self.bytes.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		// SAFETY: the caller upholds the same requirements for the bytes.
+		unsafe { self.bytes.advance_mut(cnt) }
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.bytes.chunk_mut()
+	}
+
+	fn put_slice(&mut self, src: &[u8]) {
+		self.bytes.put_slice(src);
+	}
+
+	fn put_bytes(&mut self, val: u8, cnt: usize) {
+		self.bytes.put_bytes(val, cnt);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fields_are_recorded_with_their_offsets() {
+		let mut trace = Trace::new("Example");
+
+		trace.put_u8(8);
+		trace.field("major_opcode", &8_u8);
+		trace.put_u8(0);
+		trace.unused();
+		trace.put_u16(0x1234);
+		trace.field("length", &0x1234_u16);
+		trace.put_bytes(0xff, 10);
+		trace.field("data", &"...");
+
+		assert_eq!(trace.fields()[2].offset(), 2);
+		assert_eq!(trace.fields()[2].len(), 2);
+		assert_eq!(trace.fields()[1].value(), None);
+
+		assert_eq!(
+			trace.to_string(),
+			"Example (14 bytes)\n  \
+			 0x0000  08                          major_opcode: 8\n  \
+			 0x0001  00                          unused\n  \
+			 0x0002  12 34                       length: 4660\n  \
+			 0x0004  ff ff ff ff ff ff ff ff ..  data: \"...\""
+		);
+	}
+}
@@ -38,6 +38,16 @@ pub trait Request<Reply = ()> {
 	/// need to be added to the end of the request to ensure its length is
 	/// brought up to a multiple of 4, if it is not already.
 	fn length(&self) -> u16;
+
+	/// Why this request is deprecated, if it is only kept for compatibility
+	/// with legacy clients and servers.
+	///
+	/// Requests marked with `#[deprecated_protocol]` in their definitions are
+	/// `#[deprecated]` with this note, but are still read and written as
+	/// usual.
+	fn deprecation() -> Option<&'static str> {
+		None
+	}
 }
 
 /// A reply is a message sent from the X server to an X client in response to a
@@ -47,7 +57,7 @@ pub trait Request<Reply = ()> {
 #[doc(notable_trait)]
 pub trait Reply<Request>
 where
-	Request: Request<Self>,
+	Request: self::Request<Self>,
 	Self: Sized,
 {
 	/// The length of this reply in 4-byte units minus 8.
@@ -74,18 +84,126 @@ where
 	fn sequence(&self) -> Option<u16>;
 }
 
-// An event is sent in a SendEvent request. It is 32 bytes long.
-//
-// TODO: docs!
+/// A reply of which its request generates a series, such as the replies to a
+/// `ListFontsWithInfo` request.
+///
+/// The series ends with the reply for which [`is_last`] returns `true`.
+///
+/// [`is_last`]: MultiReply::is_last
+pub trait MultiReply<Request>: Reply<Request>
+where
+	Request: self::Request<Self>,
+{
+	/// Returns whether this is the last reply in its series.
+	fn is_last(&self) -> bool;
+}
+
+/// An event is a message sent from the X server to an X client, either
+/// because of a change the client has selected, or at the request of another
+/// client with a `SendEvent` request.
+///
+/// Events are 32 bytes long, unless they are [generic events].
+///
+/// [generic events]: GenericEvent
 #[doc(notable_trait)]
 pub trait Event
 where
 	Self: Sized,
 {
-	// The code that uniquely identifies the event.
+	/// The code that uniquely identifies this event.
+	///
+	/// Core events have fixed codes, but the codes of an extension's events
+	/// are offset from the first event code assigned to it by the X server.
 	fn code() -> u8;
 
-	// The sequence number associated with the last request sent by the X
-	// server that relates to the event.
+	/// The sequence number of the last request processed by the X server
+	/// when this event was generated.
 	fn sequence(&self) -> u16;
+
+	/// Why this event is deprecated, if it is only kept for compatibility with
+	/// legacy clients and servers.
+	///
+	/// See [`Request::deprecation`].
+	fn deprecation() -> Option<&'static str> {
+		None
+	}
+}
+
+/// An X Generic Event (XGE): an event from an extension that may be longer
+/// than 32 bytes.
+///
+/// All generic events share the [event code] `35`. They are distinguished by
+/// the major opcode of the extension that generated them ([`extension`]) and
+/// an event type unique within that extension ([`event_type`]).
+///
+/// [event code]: Event::code
+/// [`extension`]: GenericEvent::extension
+/// [`event_type`]: GenericEvent::event_type
+#[doc(notable_trait)]
+pub trait GenericEvent: Event {
+	/// The major opcode of the extension that generated this event.
+	fn extension() -> u8;
+
+	/// The type of this event within its extension.
+	fn event_type() -> u16;
+
+	/// The length of this event in 4-byte units minus 8.
+	///
+	/// Every generic event consists of at least 32 bytes followed by zero or
+	/// more additional bytes of data; this method indicates the number of
+	/// additional bytes of data within this event, in the same way as
+	/// [`Reply::length`].
+	fn length(&self) -> u32;
+}
+
+/// The header written at the start of a [`Message`], for wire formats other
+/// than the core X protocol.
+///
+/// Formats used alongside X, such as XDMCP, frame their messages with their
+/// own headers. Implementing this trait for such a header allows the
+/// format's messages to be defined with `define!` as
+/// `pub struct Name: Message<Header, CODE>`.
+pub trait MessageHeader:
+	cornflakes::ByteSize + cornflakes::Readable + cornflakes::Writable + std::fmt::Debug
+{
+	/// The type of code which identifies which message follows the header.
+	type Code;
+
+	/// Creates the header for a message identified by the given `code`, the
+	/// rest of which is `content_len` bytes long.
+	fn new(code: Self::Code, content_len: usize) -> Self;
+
+	/// The code identifying which message follows this header.
+	///
+	/// This can be used to find which message to read: the header can be
+	/// read from a copy of the reader before the message itself is read.
+	fn code(&self) -> Self::Code;
+
+	/// The number of bytes in the message following this header.
+	fn content_len(&self) -> usize;
+}
+
+/// A message of a wire format other than the core X protocol, which is
+/// written with a custom [`MessageHeader`].
+#[doc(notable_trait)]
+pub trait Message {
+	/// The type of header written at the start of this message.
+	type Header: MessageHeader;
+
+	/// The code that uniquely identifies this message.
+	fn code() -> <Self::Header as MessageHeader>::Code;
+}
+
+/// An enum which is represented in the X protocol by its discriminant.
+///
+/// This is implemented for every enum defined with `define!`, so that generic
+/// code can handle any protocol enum without knowing its type, such as when
+/// writing value lists or displaying messages for debugging.
+#[doc(notable_trait)]
+pub trait WireEnum {
+	/// The number of bytes used to write the discriminant.
+	const WIDTH: usize;
+
+	/// The discriminant of this variant, as written in the X protocol.
+	fn raw(&self) -> u32;
 }
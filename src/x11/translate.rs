@@ -0,0 +1,263 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use crate::x11::{
+	requests::{TranslateCoordinates, TranslateCoordinatesReply},
+	Window,
+};
+
+/// A chain of windows, each the parent of the one before it, through which
+/// coordinates can be translated, such as from a window to the root window.
+///
+/// The offset of each window's origin within its parent is found with a
+/// [`TranslateCoordinates`] request. Once every offset is known, coordinates
+/// can be translated along the whole chain without sending any more requests.
+///
+/// Offsets can be shared between chains with an [`OffsetCache`]: offsets
+/// already in the cache need not be requested, and offsets received for one
+/// chain can be stored for the next. An offset becomes stale when its window
+/// is moved or reparented, so it should then be [invalidated].
+///
+/// # Examples
+/// ```ignore
+/// let mut chain = WindowChain::new([client, frame, root]);
+/// chain.load(&cache);
+///
+/// for request in chain.requests().collect::<Vec<_>>() {
+///     connection.send(&request)?;
+///     chain.resolve(&request, &connection.receive::<TranslateCoordinatesReply>()?);
+/// }
+///
+/// chain.store(&mut cache);
+/// let (root_x, root_y) = chain.translate(10, 20).unwrap();
+/// ```
+///
+/// [invalidated]: OffsetCache::invalidate
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct WindowChain {
+	/// The windows in the chain, starting with the innermost window.
+	windows: Vec<Window>,
+	/// The offset of each window's origin within the next window in the chain,
+	/// which is [`None`] until it is known.
+	offsets: Vec<Option<(i16, i16)>>,
+}
+
+/// A store of the offsets of windows' origins within their parents, shared
+/// between [`WindowChain`]s.
+///
+/// This is implemented for [`HashMap`]s keyed by the child and parent
+/// windows; it may be implemented for other stores, such as one which is
+/// invalidated as `ConfigureNotify` and `ReparentNotify` events are received.
+pub trait OffsetCache {
+	/// Returns the offset of the `child` window's origin within the `parent`
+	/// window, if it is known.
+	fn offset(&self, child: Window, parent: Window) -> Option<(i16, i16)>;
+
+	/// Stores the offset of the `child` window's origin within the `parent`
+	/// window.
+	fn store(&mut self, child: Window, parent: Window, offset: (i16, i16));
+
+	/// Removes any offsets of the given `window` within its parent, such as
+	/// after it has been moved or reparented.
+	fn invalidate(&mut self, window: Window);
+}
+
+impl WindowChain {
+	/// Creates a new [`WindowChain`] through the given `windows`, starting
+	/// with the innermost window, each of which is the parent of the one
+	/// before it.
+	///
+	/// No offsets are known yet.
+	pub fn new(windows: impl IntoIterator<Item = Window>) -> Self {
+		let windows: Vec<_> = windows.into_iter().collect();
+
+		Self {
+			offsets: vec![None; windows.len().saturating_sub(1)],
+			windows,
+		}
+	}
+
+	/// The windows in this chain, starting with the innermost window.
+	#[must_use]
+	pub fn windows(&self) -> &[Window] {
+		&self.windows
+	}
+
+	/// Returns whether the offsets of every window in this chain are known.
+	#[must_use]
+	pub fn is_complete(&self) -> bool {
+		self.offsets.iter().all(Option::is_some)
+	}
+
+	/// Returns an iterator over the [`TranslateCoordinates`] requests for the
+	/// offsets in this chain which are not yet known.
+	pub fn requests(&self) -> impl Iterator<Item = TranslateCoordinates> + '_ {
+		self.links()
+			.zip(&self.offsets)
+			.filter(|(_, offset)| offset.is_none())
+			.map(|((child, parent), _)| TranslateCoordinates {
+				source: child,
+				destination: parent,
+				src_x: 0,
+				src_y: 0,
+			})
+	}
+
+	/// Records the offset returned in the given `reply` to one of this chain's
+	/// [`requests`].
+	///
+	/// Returns whether the `request` is part of this chain. Replies for
+	/// windows which are not on the same screen are not recorded.
+	///
+	/// [`requests`]: WindowChain::requests
+	pub fn resolve(
+		&mut self,
+		request: &TranslateCoordinates,
+		reply: &TranslateCoordinatesReply,
+	) -> bool {
+		let Some(index) = self.position(request.source, request.destination) else {
+			return false;
+		};
+
+		// The requests translate the child's origin, `(0, 0)`, so the
+		// translated coordinates are the offset of its origin.
+		if reply.same_screen {
+			self.offsets[index] = Some((reply.dest_x, reply.dest_y));
+		}
+
+		true
+	}
+
+	/// Fills in the offsets in this chain which are known by the given
+	/// `cache`.
+	pub fn load(&mut self, cache: &impl OffsetCache) {
+		for (index, pair) in self.windows.windows(2).enumerate() {
+			if self.offsets[index].is_none() {
+				self.offsets[index] = cache.offset(pair[0], pair[1]);
+			}
+		}
+	}
+
+	/// Stores the offsets in this chain which are known in the given `cache`.
+	pub fn store(&self, cache: &mut impl OffsetCache) {
+		for ((child, parent), offset) in self.links().zip(&self.offsets) {
+			if let Some(offset) = offset {
+				cache.store(child, parent, *offset);
+			}
+		}
+	}
+
+	/// Translates the given coordinates within the innermost window to
+	/// coordinates within the outermost window.
+	///
+	/// The coordinates are returned as [`i32`]s, as the sum of the offsets may
+	/// not fit in an [`i16`]. Returns [`None`] if any offset is not yet known.
+	#[must_use]
+	pub fn translate(&self, x: i16, y: i16) -> Option<(i32, i32)> {
+		let (offset_x, offset_y) = self.total_offset()?;
+
+		Some((i32::from(x) + offset_x, i32::from(y) + offset_y))
+	}
+
+	/// Translates the given coordinates within the outermost window to
+	/// coordinates within the innermost window.
+	///
+	/// Returns [`None`] if any offset is not yet known.
+	#[must_use]
+	pub fn translate_back(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+		let (offset_x, offset_y) = self.total_offset()?;
+
+		Some((x - offset_x, y - offset_y))
+	}
+
+	/// The sum of every offset in this chain, if they are all known.
+	fn total_offset(&self) -> Option<(i32, i32)> {
+		self.offsets.iter().try_fold((0, 0), |(x, y), offset| {
+			let (offset_x, offset_y) = (*offset)?;
+
+			Some((x + i32::from(offset_x), y + i32::from(offset_y)))
+		})
+	}
+
+	/// Returns an iterator over each window in this chain, apart from the
+	/// outermost, paired with its parent.
+	fn links(&self) -> impl Iterator<Item = (Window, Window)> + '_ {
+		self.windows.windows(2).map(|pair| (pair[0], pair[1]))
+	}
+
+	fn position(&self, child: Window, parent: Window) -> Option<usize> {
+		self.links().position(|link| link == (child, parent))
+	}
+}
+
+impl OffsetCache for HashMap<(Window, Window), (i16, i16)> {
+	fn offset(&self, child: Window, parent: Window) -> Option<(i16, i16)> {
+		self.get(&(child, parent)).copied()
+	}
+
+	fn store(&mut self, child: Window, parent: Window, offset: (i16, i16)) {
+		self.insert((child, parent), offset);
+	}
+
+	fn invalidate(&mut self, window: Window) {
+		self.retain(|&(child, _), _| child != window);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn reply(dest_x: i16, dest_y: i16) -> TranslateCoordinatesReply {
+		TranslateCoordinatesReply {
+			__sequence: 0,
+			__major_opcode: None,
+			__minor_opcode: None,
+			same_screen: true,
+			child: None,
+			dest_x,
+			dest_y,
+		}
+	}
+
+	#[test]
+	fn offsets_are_composed_along_the_chain() {
+		let mut chain = WindowChain::new([Window::new(3), Window::new(2), Window::new(1)]);
+
+		let requests: Vec<_> = chain.requests().collect();
+		assert_eq!(requests.len(), 2);
+		assert_eq!(chain.translate(0, 0), None);
+
+		assert!(chain.resolve(&requests[0], &reply(4, 20)));
+		assert!(chain.resolve(&requests[1], &reply(100, -50)));
+		assert!(chain.is_complete());
+
+		assert_eq!(chain.translate(10, 10), Some((114, -20)));
+		assert_eq!(chain.translate_back(114, -20), Some((10, 10)));
+	}
+
+	#[test]
+	fn cached_offsets_are_not_requested() {
+		let (client, frame, root) = (Window::new(3), Window::new(2), Window::new(1));
+		let mut cache = HashMap::new();
+		cache.store(frame, root, (100, -50));
+
+		let mut chain = WindowChain::new([client, frame, root]);
+		chain.load(&cache);
+
+		let requests: Vec<_> = chain.requests().collect();
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].source, client);
+
+		chain.resolve(&requests[0], &reply(4, 20));
+		chain.store(&mut cache);
+		assert_eq!(cache.offset(client, frame), Some((4, 20)));
+
+		cache.invalidate(frame);
+		assert_eq!(cache.offset(frame, root), None);
+		assert_eq!(cache.offset(client, frame), Some((4, 20)));
+	}
+}
@@ -0,0 +1,439 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::x11::{
+	atoms,
+	events::PropertyNotify,
+	properties::{Property, PropertyError, WmHints, WmState, WmStateProperty},
+	requests::PropertyValue,
+	Atom, PropertyState, ResId, Window,
+};
+
+/// Tracks the ICCCM `WM_STATE` of a client's top-level windows, as set by
+/// the window manager.
+///
+/// A top-level window is either Withdrawn, Normal, or Iconic. The window
+/// manager sets the window's `WM_STATE` property whenever its state changes,
+/// and deletes it, or sets it to Withdrawn, once the window is withdrawn. The
+/// client changes the state indirectly, in a different way for each
+/// [transition]:
+///
+/// - Withdrawn to Normal or Iconic: the window is mapped, and the window
+///   manager chooses the state from the `initial_state` in its `WM_HINTS`.
+/// - Iconic to Normal: the window is mapped.
+/// - Normal to Iconic: a `WM_CHANGE_STATE` client message is sent to the root
+///   window.
+/// - Normal or Iconic to Withdrawn: the window is unmapped, and a synthetic
+///   `UnmapNotify` event is sent to the root window. The window must not be
+///   mapped again until the window manager has finished withdrawing it.
+///
+/// The tracker follows `WM_STATE` and `WM_HINTS` from [`PropertyNotify`]
+/// events, which are only sent for windows with `PropertyChange` selected,
+/// and returns the [`StateChange`] a client must make for each transition.
+///
+/// # Examples
+/// ```ignore
+/// let mut tracker = WmStateTracker::new(wm_state_atom);
+///
+/// match tracker.property_notify(&event) {
+///     PropertyUpdate::Fetch(property) => {
+///         let reply = connection.send_and_receive(GetProperty { .. })?;
+///
+///         if property == wm_state_atom {
+///             if let Some(transition) = tracker.wm_state_changed(event.window, &reply.value)? {
+///                 println!("{:?} -> {:?}", transition.from, transition.to);
+///             }
+///         } else {
+///             tracker.wm_hints_changed(event.window, &reply.value)?;
+///         }
+///     }
+///
+///     PropertyUpdate::Changed(transition) => { /* ... */ }
+///     PropertyUpdate::Ignored => {}
+/// }
+///
+/// // Later, to minimize the window...
+/// if let Some(StateChange::Iconify) = tracker.change(window, WmState::Iconic)? {
+///     connection.send(change_state_message(window))?;
+/// }
+/// ```
+///
+/// [transition]: Transition
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct WmStateTracker {
+	/// The interned `WM_STATE` atom, which is also the property's type.
+	wm_state: Atom,
+	windows: HashMap<Window, Tracked>,
+}
+
+/// The state of a window tracked by a [`WmStateTracker`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Tracked {
+	/// The window's state, as last set by the window manager.
+	state: WmState,
+	/// The `initial_state` given in the window's `WM_HINTS`.
+	initial_state: Option<WmState>,
+	/// Whether the client has withdrawn the window, and the window manager
+	/// has not yet set its state to Withdrawn.
+	withdrawing: bool,
+}
+
+/// A change in the [`WmState`] of a window.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Transition {
+	/// The window whose state changed.
+	pub window: Window,
+	/// The window's previous state.
+	pub from: WmState,
+	/// The window's new state.
+	pub to: WmState,
+}
+
+/// What to do after a [`PropertyNotify`] event is given to a
+/// [`WmStateTracker`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PropertyUpdate {
+	/// The event is not for `WM_STATE` or `WM_HINTS`.
+	Ignored,
+
+	/// The property was given a new value, which must be fetched with a
+	/// `GetProperty` request and given to
+	/// [`WmStateTracker::wm_state_changed`] or
+	/// [`WmStateTracker::wm_hints_changed`].
+	Fetch(Atom),
+
+	/// The property was deleted. Deleting `WM_STATE` withdraws the window,
+	/// which is a transition if it was not already Withdrawn.
+	Changed(Option<Transition>),
+}
+
+/// How a client changes the state of its window, as returned by
+/// [`WmStateTracker::change`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum StateChange {
+	/// Map the window.
+	///
+	/// If `initial_state` is [`Some`], the window is Withdrawn, and the
+	/// `initial_state` in its `WM_HINTS` must first be changed to it.
+	Map {
+		/// The `initial_state` the window's `WM_HINTS` must be given, if it
+		/// does not already have it.
+		initial_state: Option<WmState>,
+	},
+
+	/// Send a `WM_CHANGE_STATE` client message to the root window, with a
+	/// format of 32 and `IconicState` (`3`) as its first value.
+	Iconify,
+
+	/// Unmap the window, then send a synthetic `UnmapNotify` event to the
+	/// root window with `SubstructureRedirect` and `SubstructureNotify`
+	/// selected.
+	Withdraw,
+}
+
+/// The error returned when the state of a window is changed while it is
+/// still being withdrawn.
+///
+/// Once a client has withdrawn a window, it must wait until the window
+/// manager has set its `WM_STATE` to Withdrawn, or deleted it, before it maps
+/// the window again.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct WithdrawalPending {
+	/// The window which is being withdrawn.
+	pub window: Window,
+}
+
+impl WmStateTracker {
+	/// Creates a new [`WmStateTracker`], given the interned `WM_STATE` atom.
+	#[must_use]
+	pub fn new(wm_state: Atom) -> Self {
+		Self {
+			wm_state,
+			windows: HashMap::new(),
+		}
+	}
+
+	/// The state of the given `window`.
+	///
+	/// Windows which are not tracked are Withdrawn.
+	#[must_use]
+	pub fn state(&self, window: Window) -> WmState {
+		self.windows
+			.get(&window)
+			.map_or(WmState::Withdrawn, |tracked| tracked.state)
+	}
+
+	/// Returns whether the client has withdrawn the given `window`, and the
+	/// window manager has not yet finished withdrawing it.
+	#[must_use]
+	pub fn is_withdrawing(&self, window: Window) -> bool {
+		self.windows
+			.get(&window)
+			.is_some_and(|tracked| tracked.withdrawing)
+	}
+
+	/// Stops tracking the given `window`, such as once it is destroyed.
+	pub fn forget(&mut self, window: Window) {
+		self.windows.remove(&window);
+	}
+
+	/// Handles the given [`PropertyNotify`] `event`.
+	pub fn property_notify(&mut self, event: &PropertyNotify) -> PropertyUpdate {
+		let is_wm_state = event.property == self.wm_state;
+
+		if !is_wm_state && event.property != atoms::WM_HINTS {
+			return PropertyUpdate::Ignored;
+		}
+
+		match event.state {
+			PropertyState::NewValue => PropertyUpdate::Fetch(event.property),
+
+			PropertyState::Deleted if is_wm_state => {
+				PropertyUpdate::Changed(self.set_state(event.window, WmState::Withdrawn))
+			}
+			PropertyState::Deleted => {
+				self.tracked(event.window).initial_state = None;
+
+				PropertyUpdate::Changed(None)
+			}
+		}
+	}
+
+	/// Updates the state of the given `window` from the `value` of its
+	/// `WM_STATE` property, returning the transition, if its state changed.
+	///
+	/// # Errors
+	/// Returns a [`PropertyError`] if the `value` is not a `WM_STATE`
+	/// property.
+	pub fn wm_state_changed(
+		&mut self,
+		window: Window,
+		value: &PropertyValue,
+	) -> Result<Option<Transition>, PropertyError> {
+		let property = WmStateProperty::from_value(value, self.wm_state)?;
+
+		Ok(self.set_state(window, property.state))
+	}
+
+	/// Updates the `initial_state` of the given `window` from the `value` of
+	/// its `WM_HINTS` property.
+	///
+	/// # Errors
+	/// Returns a [`PropertyError`] if the `value` is not a `WM_HINTS`
+	/// property.
+	pub fn wm_hints_changed(
+		&mut self,
+		window: Window,
+		value: &PropertyValue,
+	) -> Result<(), PropertyError> {
+		let hints = WmHints::from_value(value)?;
+		self.tracked(window).initial_state = hints.initial_state;
+
+		Ok(())
+	}
+
+	/// Returns the [`StateChange`] the client must make to change the state
+	/// of the given `window` to `to`, or [`None`] if it is already in that
+	/// state.
+	///
+	/// If the window is to be withdrawn, it is considered to be withdrawing
+	/// from now on, until the window manager sets its state to Withdrawn.
+	///
+	/// # Errors
+	/// Returns [`WithdrawalPending`] if the window is still being withdrawn.
+	pub fn change(
+		&mut self,
+		window: Window,
+		to: WmState,
+	) -> Result<Option<StateChange>, WithdrawalPending> {
+		let tracked = self.tracked(window);
+
+		if tracked.withdrawing {
+			return Err(WithdrawalPending { window });
+		}
+
+		Ok(match (tracked.state, to) {
+			(from, to) if from == to => None,
+
+			(_, WmState::Withdrawn) => {
+				tracked.withdrawing = true;
+
+				Some(StateChange::Withdraw)
+			}
+
+			// The window manager chooses the state of a newly mapped window
+			// from its hints, which default to Normal.
+			(WmState::Withdrawn, to) => Some(StateChange::Map {
+				initial_state: (tracked.initial_state.unwrap_or(WmState::Normal) != to)
+					.then_some(to),
+			}),
+
+			(WmState::Iconic, _) => Some(StateChange::Map {
+				initial_state: None,
+			}),
+			(WmState::Normal, _) => Some(StateChange::Iconify),
+		})
+	}
+
+	/// Sets the state of the given `window`, returning the transition, if
+	/// its state changed.
+	fn set_state(&mut self, window: Window, state: WmState) -> Option<Transition> {
+		let tracked = self.tracked(window);
+		let from = tracked.state;
+
+		tracked.state = state;
+
+		if state == WmState::Withdrawn {
+			tracked.withdrawing = false;
+		}
+
+		(from != state).then_some(Transition {
+			window,
+			from,
+			to: state,
+		})
+	}
+
+	/// The tracked state of the given `window`, which is tracked from now on
+	/// if it wasn't already.
+	fn tracked(&mut self, window: Window) -> &mut Tracked {
+		self.windows.entry(window).or_insert(Tracked {
+			state: WmState::Withdrawn,
+			initial_state: None,
+			withdrawing: false,
+		})
+	}
+}
+
+impl fmt::Display for WithdrawalPending {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"window {:#x} is still being withdrawn by the window manager",
+			self.window.res_id()
+		)
+	}
+}
+
+impl Error for WithdrawalPending {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const WM_STATE: Atom = Atom::new(0x1a3);
+	const WINDOW: Window = Window::new(0x0040_0001);
+
+	fn wm_state(state: WmState) -> PropertyValue {
+		WmStateProperty { state, icon: None }.to_value(WM_STATE)
+	}
+
+	fn notify(property: Atom, state: PropertyState) -> PropertyNotify {
+		PropertyNotify {
+			_sequence_: 0,
+			window: WINDOW,
+			property,
+			time: 0,
+			state,
+		}
+	}
+
+	#[test]
+	fn transitions_follow_wm_state() {
+		let mut tracker = WmStateTracker::new(WM_STATE);
+
+		assert_eq!(
+			tracker.property_notify(&notify(WM_STATE, PropertyState::NewValue)),
+			PropertyUpdate::Fetch(WM_STATE)
+		);
+		assert_eq!(
+			tracker.property_notify(&notify(atoms::WM_NAME, PropertyState::NewValue)),
+			PropertyUpdate::Ignored
+		);
+
+		assert_eq!(
+			tracker.wm_state_changed(WINDOW, &wm_state(WmState::Normal)),
+			Ok(Some(Transition {
+				window: WINDOW,
+				from: WmState::Withdrawn,
+				to: WmState::Normal,
+			}))
+		);
+		assert_eq!(
+			tracker.wm_state_changed(WINDOW, &wm_state(WmState::Normal)),
+			Ok(None)
+		);
+
+		assert_eq!(
+			tracker.property_notify(&notify(WM_STATE, PropertyState::Deleted)),
+			PropertyUpdate::Changed(Some(Transition {
+				window: WINDOW,
+				from: WmState::Normal,
+				to: WmState::Withdrawn,
+			}))
+		);
+		assert_eq!(tracker.state(WINDOW), WmState::Withdrawn);
+	}
+
+	#[test]
+	fn changes_depend_on_the_current_state_and_hints() {
+		let mut tracker = WmStateTracker::new(WM_STATE);
+
+		// Windows are mapped Normal unless their hints say otherwise.
+		assert_eq!(
+			tracker.change(WINDOW, WmState::Iconic),
+			Ok(Some(StateChange::Map {
+				initial_state: Some(WmState::Iconic),
+			}))
+		);
+
+		let hints = WmHints {
+			initial_state: Some(WmState::Iconic),
+			..WmHints::default()
+		};
+		tracker.wm_hints_changed(WINDOW, &hints.to_value()).unwrap();
+
+		assert_eq!(
+			tracker.change(WINDOW, WmState::Iconic),
+			Ok(Some(StateChange::Map {
+				initial_state: None,
+			}))
+		);
+
+		tracker
+			.wm_state_changed(WINDOW, &wm_state(WmState::Normal))
+			.unwrap();
+
+		assert_eq!(tracker.change(WINDOW, WmState::Normal), Ok(None));
+		assert_eq!(
+			tracker.change(WINDOW, WmState::Iconic),
+			Ok(Some(StateChange::Iconify))
+		);
+
+		// The window can't be mapped again until it has been withdrawn.
+		assert_eq!(
+			tracker.change(WINDOW, WmState::Withdrawn),
+			Ok(Some(StateChange::Withdraw))
+		);
+		assert!(tracker.is_withdrawing(WINDOW));
+		assert_eq!(
+			tracker.change(WINDOW, WmState::Normal),
+			Err(WithdrawalPending { window: WINDOW })
+		);
+
+		tracker
+			.wm_state_changed(WINDOW, &wm_state(WmState::Withdrawn))
+			.unwrap();
+
+		assert!(!tracker.is_withdrawing(WINDOW));
+		assert_eq!(
+			tracker.change(WINDOW, WmState::Normal),
+			Ok(Some(StateChange::Map {
+				initial_state: Some(WmState::Normal),
+			}))
+		);
+	}
+}
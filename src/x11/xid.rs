@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Allocates resource IDs for new resources, such as [`Window`]s and
+/// [`Pixmap`]s.
+///
+/// Every client is given a range of resource IDs it may use in the connection
+/// setup reply: its `resource_id_base`, and a `resource_id_mask`. A resource
+/// ID is the `resource_id_base` with some subset of the bits in the
+/// `resource_id_mask` set.
+///
+/// Once those IDs have all been allocated, [`next_id`] returns [`None`]. If
+/// the X server supports the XC-MISC extension, the [`GetXidRange`] request
+/// can then be used to find a range of IDs which are no longer in use, which
+/// can be given to the allocator with [`set_range`].
+///
+/// # Examples
+/// ```ignore
+/// let mut xids = XidAllocator::new(setup.resource_id_base, setup.resource_id_mask);
+///
+/// let window = xids.next(Window::new).unwrap();
+/// let pixmap = xids.next(Pixmap::new).unwrap();
+/// ```
+///
+/// [`Window`]: crate::x11::Window
+/// [`Pixmap`]: crate::x11::Pixmap
+/// [`next_id`]: XidAllocator::next_id
+/// [`set_range`]: XidAllocator::set_range
+/// [`GetXidRange`]: crate::x11::extensions::xc_misc::GetXidRange
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct XidAllocator {
+	base: u32,
+	mask: u32,
+	/// The difference between consecutive IDs: the lowest bit set in the
+	/// `mask`.
+	increment: u32,
+
+	/// The next ID to allocate, without the `base`, if there are any left.
+	next: Option<u32>,
+	/// The last ID which may be allocated, without the `base`.
+	last: u32,
+}
+
+impl XidAllocator {
+	/// Creates a new [`XidAllocator`] with the `resource_id_base` and
+	/// `resource_id_mask` given in the connection setup reply.
+	#[must_use]
+	pub const fn new(base: u32, mask: u32) -> Self {
+		Self {
+			base,
+			mask,
+			increment: mask & mask.wrapping_neg(),
+
+			next: if mask == 0 { None } else { Some(0) },
+			last: mask,
+		}
+	}
+
+	/// Returns a new resource ID, or [`None`] if there are none left.
+	///
+	/// See [`set_range`] for allocating more IDs once there are none left.
+	///
+	/// [`set_range`]: XidAllocator::set_range
+	pub fn next_id(&mut self) -> Option<u32> {
+		let id = self.next?;
+
+		// IDs are always multiples of the `increment`, so if this isn't the
+		// last ID, the next one can't be past the `last` ID.
+		self.next = if id < self.last {
+			Some(id + self.increment)
+		} else {
+			None
+		};
+
+		Some(self.base | id)
+	}
+
+	/// Returns a new resource, constructed with its ID, or [`None`] if there
+	/// are no IDs left.
+	///
+	/// # Examples
+	/// ```ignore
+	/// let window: Option<Window> = xids.next(Window::new);
+	/// ```
+	pub fn next<T>(&mut self, new: impl FnOnce(u32) -> T) -> Option<T> {
+		self.next_id().map(new)
+	}
+
+	/// Allocates IDs from the range given in a [`GetXidRangeReply`], rather
+	/// than any IDs remaining from the previous range.
+	///
+	/// Returns `false` if the range is empty, in which case there are no IDs
+	/// left to allocate.
+	///
+	/// [`GetXidRangeReply`]: crate::x11::extensions::xc_misc::GetXidRangeReply
+	pub fn set_range(&mut self, start_id: u32, count: u32) -> bool {
+		let start = start_id & self.mask;
+
+		// The last ID in the range, making sure it doesn't go beyond the
+		// `mask`.
+		let last = count
+			.checked_sub(1)
+			.and_then(|count| count.checked_mul(self.increment))
+			.and_then(|len| start.checked_add(len))
+			.map(|last| last.min(self.mask));
+
+		match last {
+			Some(last) if self.increment != 0 => {
+				self.next = Some(start);
+				self.last = last;
+
+				true
+			}
+
+			_ => {
+				self.next = None;
+
+				false
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ids_are_allocated_within_mask() {
+		let mut xids = XidAllocator::new(0x0040_0000, 0x0000_0003);
+
+		assert_eq!(xids.next_id(), Some(0x0040_0000));
+		assert_eq!(xids.next_id(), Some(0x0040_0001));
+		assert_eq!(xids.next_id(), Some(0x0040_0002));
+		assert_eq!(xids.next_id(), Some(0x0040_0003));
+		assert_eq!(xids.next_id(), None);
+	}
+
+	#[test]
+	fn ids_step_by_lowest_mask_bit() {
+		let mut xids = XidAllocator::new(0x0000_0001, 0x0000_000c);
+
+		assert_eq!(xids.next_id(), Some(0x0000_0001));
+		assert_eq!(xids.next_id(), Some(0x0000_0005));
+		assert_eq!(xids.next_id(), Some(0x0000_0009));
+		assert_eq!(xids.next_id(), Some(0x0000_000d));
+		assert_eq!(xids.next_id(), None);
+	}
+
+	#[test]
+	fn set_range_continues_allocation() {
+		let mut xids = XidAllocator::new(0x0040_0000, 0x0000_00ff);
+
+		assert!(xids.set_range(0x0040_0010, 2));
+		assert_eq!(xids.next_id(), Some(0x0040_0010));
+		assert_eq!(xids.next_id(), Some(0x0040_0011));
+		assert_eq!(xids.next_id(), None);
+
+		assert!(!xids.set_range(0, 0));
+		assert_eq!(xids.next_id(), None);
+	}
+}
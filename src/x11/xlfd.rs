@@ -0,0 +1,582 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::x11::{
+	font_pattern::{FontPattern, FontPatternError},
+	Any,
+};
+
+/// The number of fields in an XLFD name.
+const FIELDS: usize = 14;
+
+/// The names of the fields of an XLFD name, in order.
+const FIELD_NAMES: [&str; FIELDS] = [
+	"foundry",
+	"family name",
+	"weight name",
+	"slant",
+	"setwidth name",
+	"add style name",
+	"pixel size",
+	"point size",
+	"x resolution",
+	"y resolution",
+	"spacing",
+	"average width",
+	"charset registry",
+	"charset encoding",
+];
+
+/// An X Logical Font Description: a font name made up of 14 fields separated
+/// by hyphens, such as
+/// `-misc-fixed-medium-r-normal--13-120-75-75-c-70-iso8859-1`.
+///
+/// Any field may be `*`, which matches any value when the name is used as a
+/// [`FontPattern`]. The text fields may also contain other wildcards; the
+/// numeric and enumerated fields must either be a value or [`Any`].
+///
+/// # Examples
+/// ```ignore
+/// let xlfd: Xlfd = "-misc-fixed-medium-r-normal--13-120-75-75-c-70-iso8859-1".parse()?;
+///
+/// assert_eq!(xlfd.family_name, "fixed");
+/// assert_eq!(xlfd.pixel_size, Any::Specific(13));
+///
+/// // Any bold, monospaced font encoded in ISO 8859-1.
+/// let pattern = Xlfd::new()
+///     .weight_name("bold")
+///     .spacing(Any::Specific(Spacing::Monospaced))
+///     .charset("iso8859", "1")
+///     .to_pattern()?;
+/// ```
+#[doc(alias = "XLFD")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Xlfd {
+	/// The organization which registered the font, such as `adobe` or
+	/// `misc`.
+	pub foundry: String,
+	/// The name of the font's typeface, such as `helvetica` or `fixed`.
+	pub family_name: String,
+	/// How heavy the font is, such as `medium` or `bold`.
+	pub weight_name: String,
+	/// How upright the font is.
+	pub slant: Any<Slant>,
+	/// How wide the font is, such as `normal` or `condensed`.
+	pub setwidth_name: String,
+	/// Any additional style which distinguishes the font, such as `sans`.
+	///
+	/// This is usually empty.
+	pub add_style_name: String,
+	/// The height of the font's em square, in pixels.
+	///
+	/// This is `0` for a scalable font.
+	pub pixel_size: Any<u32>,
+	/// The height of the font's em square, in tenths of a point.
+	///
+	/// This is `0` for a scalable font.
+	pub point_size: Any<u32>,
+	/// The horizontal resolution the font is designed for, in pixels per
+	/// inch.
+	pub resolution_x: Any<u32>,
+	/// The vertical resolution the font is designed for, in pixels per inch.
+	pub resolution_y: Any<u32>,
+	/// How the font's characters are spaced.
+	pub spacing: Any<Spacing>,
+	/// The average width of the font's characters, in tenths of a pixel.
+	///
+	/// This is `0` for a scalable font.
+	pub average_width: Any<u32>,
+	/// The organization which registered the font's encoding, such as
+	/// `iso8859` or `iso10646`.
+	pub charset_registry: String,
+	/// The encoding within the [`charset_registry`], such as `1`.
+	///
+	/// [`charset_registry`]: Xlfd::charset_registry
+	pub charset_encoding: String,
+}
+
+/// How upright the characters of a font are.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Slant {
+	/// Upright characters: `r`.
+	Roman,
+	/// Characters slanted clockwise, designed as italic: `i`.
+	Italic,
+	/// Roman characters slanted clockwise: `o`.
+	Oblique,
+	/// Characters slanted counterclockwise, designed as italic: `ri`.
+	ReverseItalic,
+	/// Roman characters slanted counterclockwise: `ro`.
+	ReverseOblique,
+	/// Any other slant: `ot`.
+	Other,
+}
+
+/// How the characters of a font are spaced.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Spacing {
+	/// Characters have different widths: `p`.
+	Proportional,
+	/// Every character has the same width: `m`.
+	Monospaced,
+	/// Every character has the same width, and is drawn entirely within its
+	/// cell: `c`.
+	CharCell,
+}
+
+/// The reason an XLFD name could not be parsed or used.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum XlfdError {
+	/// The name does not start with a hyphen.
+	MissingHyphen,
+	/// The name does not have 14 fields.
+	WrongFieldCount {
+		/// The number of fields found.
+		found: usize,
+	},
+	/// A field's value is not valid for that field.
+	///
+	/// Text fields may not contain hyphens, and numeric and enumerated
+	/// fields must be a value or `*`.
+	InvalidField {
+		/// The name of the field.
+		field: &'static str,
+		/// The value which was given.
+		value: String,
+	},
+	/// The name can't be used as a [`FontPattern`].
+	Pattern(FontPatternError),
+}
+
+impl Xlfd {
+	/// Creates a new [`Xlfd`] in which every field is `*`.
+	#[must_use]
+	pub fn new() -> Self {
+		let any = || String::from("*");
+
+		Self {
+			foundry: any(),
+			family_name: any(),
+			weight_name: any(),
+			slant: Any::Any,
+			setwidth_name: any(),
+			add_style_name: any(),
+			pixel_size: Any::Any,
+			point_size: Any::Any,
+			resolution_x: Any::Any,
+			resolution_y: Any::Any,
+			spacing: Any::Any,
+			average_width: Any::Any,
+			charset_registry: any(),
+			charset_encoding: any(),
+		}
+	}
+
+	/// Sets the organization which registered the font.
+	#[must_use]
+	pub fn foundry(mut self, foundry: impl Into<String>) -> Self {
+		self.foundry = foundry.into();
+		self
+	}
+
+	/// Sets the name of the font's typeface.
+	#[must_use]
+	pub fn family_name(mut self, family_name: impl Into<String>) -> Self {
+		self.family_name = family_name.into();
+		self
+	}
+
+	/// Sets how heavy the font is.
+	#[must_use]
+	pub fn weight_name(mut self, weight_name: impl Into<String>) -> Self {
+		self.weight_name = weight_name.into();
+		self
+	}
+
+	/// Sets how upright the font is.
+	#[must_use]
+	pub const fn slant(mut self, slant: Any<Slant>) -> Self {
+		self.slant = slant;
+		self
+	}
+
+	/// Sets how wide the font is.
+	#[must_use]
+	pub fn setwidth_name(mut self, setwidth_name: impl Into<String>) -> Self {
+		self.setwidth_name = setwidth_name.into();
+		self
+	}
+
+	/// Sets the additional style which distinguishes the font.
+	#[must_use]
+	pub fn add_style_name(mut self, add_style_name: impl Into<String>) -> Self {
+		self.add_style_name = add_style_name.into();
+		self
+	}
+
+	/// Sets the height of the font in pixels.
+	#[must_use]
+	pub const fn pixel_size(mut self, pixel_size: Any<u32>) -> Self {
+		self.pixel_size = pixel_size;
+		self
+	}
+
+	/// Sets the height of the font in tenths of a point.
+	#[must_use]
+	pub const fn point_size(mut self, point_size: Any<u32>) -> Self {
+		self.point_size = point_size;
+		self
+	}
+
+	/// Sets the horizontal and vertical resolutions the font is designed for.
+	#[must_use]
+	pub const fn resolution(mut self, x: Any<u32>, y: Any<u32>) -> Self {
+		self.resolution_x = x;
+		self.resolution_y = y;
+
+		self
+	}
+
+	/// Sets how the font's characters are spaced.
+	#[must_use]
+	pub const fn spacing(mut self, spacing: Any<Spacing>) -> Self {
+		self.spacing = spacing;
+		self
+	}
+
+	/// Sets the average width of the font's characters in tenths of a pixel.
+	#[must_use]
+	pub const fn average_width(mut self, average_width: Any<u32>) -> Self {
+		self.average_width = average_width;
+		self
+	}
+
+	/// Sets the font's encoding, such as `iso8859` and `1`.
+	#[must_use]
+	pub fn charset(mut self, registry: impl Into<String>, encoding: impl Into<String>) -> Self {
+		self.charset_registry = registry.into();
+		self.charset_encoding = encoding.into();
+
+		self
+	}
+
+	/// Returns whether this names a scalable font: one whose pixel size,
+	/// point size, and average width are all `0`.
+	#[must_use]
+	pub fn is_scalable(&self) -> bool {
+		[self.pixel_size, self.point_size, self.average_width]
+			.iter()
+			.all(|size| *size == Any::Specific(0))
+	}
+
+	/// Returns whether any field is a wildcard, so that this names more than
+	/// one font.
+	#[must_use]
+	pub fn is_pattern(&self) -> bool {
+		!FontPattern::new(&self.to_string()).is_ok_and(|pattern| pattern.is_literal())
+	}
+
+	/// Checks that none of the text fields contain hyphens, which would
+	/// separate them into more than one field.
+	///
+	/// # Errors
+	/// Returns [`XlfdError::InvalidField`] for the first text field which
+	/// contains a hyphen.
+	pub fn validate(&self) -> Result<(), XlfdError> {
+		let text = [
+			(0, &self.foundry),
+			(1, &self.family_name),
+			(2, &self.weight_name),
+			(4, &self.setwidth_name),
+			(5, &self.add_style_name),
+			(12, &self.charset_registry),
+			(13, &self.charset_encoding),
+		];
+
+		match text.into_iter().find(|(_, value)| value.contains('-')) {
+			Some((index, value)) => Err(XlfdError::InvalidField {
+				field: FIELD_NAMES[index],
+				value: value.clone(),
+			}),
+
+			None => Ok(()),
+		}
+	}
+
+	/// Returns this name as a [`FontPattern`], such as for a `ListFonts`
+	/// request.
+	///
+	/// # Errors
+	/// Returns [`XlfdError::InvalidField`] if a text field contains a hyphen,
+	/// or [`XlfdError::Pattern`] if a text field contains a character which
+	/// is not in Latin-1.
+	pub fn to_pattern(&self) -> Result<FontPattern, XlfdError> {
+		self.validate()?;
+
+		FontPattern::new(&self.to_string()).map_err(XlfdError::Pattern)
+	}
+}
+
+impl Default for Xlfd {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Slant {
+	/// The abbreviation of this slant in an XLFD name.
+	#[must_use]
+	pub const fn abbreviation(self) -> &'static str {
+		match self {
+			Self::Roman => "r",
+			Self::Italic => "i",
+			Self::Oblique => "o",
+			Self::ReverseItalic => "ri",
+			Self::ReverseOblique => "ro",
+			Self::Other => "ot",
+		}
+	}
+
+	/// The slant with the given `abbreviation`, ignoring case.
+	#[must_use]
+	pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+		[
+			Self::Roman,
+			Self::Italic,
+			Self::Oblique,
+			Self::ReverseItalic,
+			Self::ReverseOblique,
+			Self::Other,
+		]
+		.into_iter()
+		.find(|slant| slant.abbreviation().eq_ignore_ascii_case(abbreviation))
+	}
+}
+
+impl Spacing {
+	/// The abbreviation of this spacing in an XLFD name.
+	#[must_use]
+	pub const fn abbreviation(self) -> &'static str {
+		match self {
+			Self::Proportional => "p",
+			Self::Monospaced => "m",
+			Self::CharCell => "c",
+		}
+	}
+
+	/// The spacing with the given `abbreviation`, ignoring case.
+	#[must_use]
+	pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+		[Self::Proportional, Self::Monospaced, Self::CharCell]
+			.into_iter()
+			.find(|spacing| spacing.abbreviation().eq_ignore_ascii_case(abbreviation))
+	}
+}
+
+impl FromStr for Xlfd {
+	type Err = XlfdError;
+
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		/// Parses a numeric or enumerated field, which is `*` if it is
+		/// [`Any`].
+		fn any<T>(
+			index: usize,
+			value: &str,
+			parse: impl FnOnce(&str) -> Option<T>,
+		) -> Result<Any<T>, XlfdError> {
+			if value == "*" {
+				return Ok(Any::Any);
+			}
+
+			parse(value)
+				.map(Any::Specific)
+				.ok_or_else(|| XlfdError::InvalidField {
+					field: FIELD_NAMES[index],
+					value: value.to_owned(),
+				})
+		}
+
+		let fields = name
+			.strip_prefix('-')
+			.ok_or(XlfdError::MissingHyphen)?
+			.split('-')
+			.collect::<Vec<_>>();
+
+		let fields: [&str; FIELDS] =
+			fields
+				.try_into()
+				.map_err(|fields: Vec<_>| XlfdError::WrongFieldCount {
+					found: fields.len(),
+				})?;
+
+		// Only digits are accepted, not signs or whitespace.
+		let number = |value: &str| {
+			value
+				.bytes()
+				.all(|byte| byte.is_ascii_digit())
+				.then(|| value.parse().ok())
+				.flatten()
+		};
+
+		Ok(Self {
+			foundry: fields[0].to_owned(),
+			family_name: fields[1].to_owned(),
+			weight_name: fields[2].to_owned(),
+			slant: any(3, fields[3], Slant::from_abbreviation)?,
+			setwidth_name: fields[4].to_owned(),
+			add_style_name: fields[5].to_owned(),
+			pixel_size: any(6, fields[6], number)?,
+			point_size: any(7, fields[7], number)?,
+			resolution_x: any(8, fields[8], number)?,
+			resolution_y: any(9, fields[9], number)?,
+			spacing: any(10, fields[10], Spacing::from_abbreviation)?,
+			average_width: any(11, fields[11], number)?,
+			charset_registry: fields[12].to_owned(),
+			charset_encoding: fields[13].to_owned(),
+		})
+	}
+}
+
+impl fmt::Display for Xlfd {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		/// Writes a numeric field, or `*` if it is [`Any`].
+		fn any(value: Any<impl fmt::Display>) -> String {
+			match value {
+				Any::Any => String::from("*"),
+				Any::Specific(value) => value.to_string(),
+			}
+		}
+
+		write!(
+			f,
+			"-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}",
+			self.foundry,
+			self.family_name,
+			self.weight_name,
+			any(match self.slant {
+				Any::Any => Any::Any,
+				Any::Specific(slant) => Any::Specific(slant.abbreviation()),
+			}),
+			self.setwidth_name,
+			self.add_style_name,
+			any(self.pixel_size),
+			any(self.point_size),
+			any(self.resolution_x),
+			any(self.resolution_y),
+			any(match self.spacing {
+				Any::Any => Any::Any,
+				Any::Specific(spacing) => Any::Specific(spacing.abbreviation()),
+			}),
+			any(self.average_width),
+			self.charset_registry,
+			self.charset_encoding,
+		)
+	}
+}
+
+impl fmt::Display for XlfdError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::MissingHyphen => write!(f, "XLFD names must start with a hyphen"),
+
+			Self::WrongFieldCount { found } => write!(
+				f,
+				"XLFD names have {FIELDS} fields, but this one has {found}"
+			),
+
+			Self::InvalidField { field, value } => {
+				write!(f, "{value:?} is not a valid {field} in an XLFD name")
+			}
+
+			Self::Pattern(error) => write!(f, "{error}"),
+		}
+	}
+}
+
+impl Error for XlfdError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FIXED: &str = "-misc-fixed-medium-r-semicondensed--13-120-75-75-c-60-iso8859-1";
+
+	#[test]
+	fn names_are_parsed_into_fields() {
+		let xlfd: Xlfd = FIXED.parse().unwrap();
+
+		assert_eq!(
+			xlfd,
+			Xlfd::new()
+				.foundry("misc")
+				.family_name("fixed")
+				.weight_name("medium")
+				.slant(Any::Specific(Slant::Roman))
+				.setwidth_name("semicondensed")
+				.add_style_name("")
+				.pixel_size(Any::Specific(13))
+				.point_size(Any::Specific(120))
+				.resolution(Any::Specific(75), Any::Specific(75))
+				.spacing(Any::Specific(Spacing::CharCell))
+				.average_width(Any::Specific(60))
+				.charset("iso8859", "1")
+		);
+		assert_eq!(xlfd.to_string(), FIXED);
+		assert!(!xlfd.is_pattern());
+		assert!(!xlfd.is_scalable());
+
+		let scalable: Xlfd = "-Adobe-Helvetica-Bold-RO-Normal--0-0-0-0-P-0-ISO8859-1"
+			.parse()
+			.unwrap();
+
+		assert_eq!(scalable.slant, Any::Specific(Slant::ReverseOblique));
+		assert!(scalable.is_scalable());
+	}
+
+	#[test]
+	fn wildcards_are_any() {
+		let xlfd: Xlfd = "-*-helvetica-bold-*-*-*-12-*-*-*-p-*-iso8859-?"
+			.parse()
+			.unwrap();
+
+		assert_eq!(xlfd.slant, Any::Any);
+		assert_eq!(xlfd.spacing, Any::Specific(Spacing::Proportional));
+		assert_eq!(xlfd.charset_encoding, "?");
+		assert!(xlfd.is_pattern());
+
+		let pattern = xlfd.to_pattern().unwrap();
+
+		assert!(pattern.matches_str("-adobe-helvetica-bold-o-normal--12-120-75-75-p-69-iso8859-1"));
+		assert_eq!(Xlfd::new().to_string(), "-*-*-*-*-*-*-*-*-*-*-*-*-*-*");
+	}
+
+	#[test]
+	fn invalid_names_are_rejected() {
+		assert_eq!("fixed".parse::<Xlfd>(), Err(XlfdError::MissingHyphen));
+		assert_eq!(
+			"-misc-fixed-medium-r-normal--13".parse::<Xlfd>(),
+			Err(XlfdError::WrongFieldCount { found: 7 })
+		);
+		assert_eq!(
+			"-misc-fixed-medium-x-normal--13-120-75-75-c-70-iso8859-1".parse::<Xlfd>(),
+			Err(XlfdError::InvalidField {
+				field: "slant",
+				value: String::from("x"),
+			})
+		);
+		assert!("-misc-fixed-medium-r-normal--+13-120-75-75-c-70-iso8859-1"
+			.parse::<Xlfd>()
+			.is_err());
+
+		assert_eq!(
+			Xlfd::new().family_name("dejavu-sans").to_pattern(),
+			Err(XlfdError::InvalidField {
+				field: "family name",
+				value: String::from("dejavu-sans"),
+			})
+		);
+	}
+}
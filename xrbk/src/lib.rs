@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// `Request` and `Reply` are notable traits, as they are in XRB.
+#![feature(doc_notable_trait)]
+// This is so we can provide a reason when we ignore a particular lint with
+// `allow`.
+#![feature(lint_reasons)]
+
+//! # XRB's message definitions
+//! `xrbk` provides the [`define!`] macro which XRB uses to define the X11
+//! protocol's types and messages, so that it can be used for other binary
+//! protocols with similar layouts: padding, length-prefixed lists, and fields
+//! which are read in the context of earlier fields.
+//!
+//! Each definition is written as a struct or enum, and its `Readable`,
+//! `Writable`, and `PrettyPrint` implementations are generated from the
+//! order of its items. The code generated by [`define!`] refers to [`bytes`],
+//! [`cornflakes`], and the [`runtime`] (as `xrb`), all of which are brought
+//! into scope by the [`prelude`]. Alternatively, a definition given
+//! `#[xrbk(crate = "xrbk::runtime")]` brings them into scope itself.
+//!
+//! # Items
+//! - Fields are written and read in the order they are defined.
+//! - `let` items are written and read like fields, but are not stored in the
+//!   struct. They are given by a source: `let len: u16 = self =>
+//!   self.names.len() as u16` is written as the length of the `names` field.
+//! - `#[context(len => usize::from(*len))]` reads a field with
+//!   `ContextualReadable`, given a context found from earlier items.
+//! - `#[length(len)]` is the common case of that for lists: the list is read
+//!   with `len` elements, and `len` is written as the list's length, so it
+//!   needs no source of its own: `let len: u16,`.
+//! - `#[present_if(mask => mask.contains(Mask::X))]` makes an `Option`
+//!   field present only if the condition, found from earlier items, holds.
+//!   It is `None` if it is not, and only written if it is `Some`.
+//! - A `Cow<'a, [u8]>` field given its length as its context is borrowed
+//!   from the bytes it is read from by the generated `read_borrowed`, and
+//!   copied by `read_from`.
+//! - `()` is one unused byte, and `[(); 4]` is four. The number of unused
+//!   bytes can be found from earlier items: `[(); len => pad(*len)]`.
+//! - `[(); ..]` pads the item before it to a multiple of 4 bytes, as X11
+//!   pads most lists.
+//! - `#[metabyte]` places a single-byte item in the second byte of a
+//!   message's header, as X11 requests, replies, and events do.
+//!
+//! Enums are written with a `u8` discriminant, or with the type given after
+//! their name: `enum Rotation: u16 { ... }`.
+//!
+//! The layout of a message's body can depend on an earlier item, as a
+//! `match` would. An enum given `match` before its discriminant type,
+//! `enum Change: match u8 { ... }`, writes only the items of its variant,
+//! and is read with `ContextualReadable` given its discriminant. A field of
+//! that enum given `#[discriminant(kind)]` is read with the discriminant
+//! `kind`, and `kind` is written as the field's discriminant: `let kind: u8,`.
+//!
+//! # Messages
+//! X11's messages are defined with `Request<OPCODE> -> Reply`,
+//! `Reply for Request`, and `Event<CODE>` after the name of a struct. Other
+//! protocols define messages with their own header, which implements
+//! [`MessageHeader`]: `struct Name: Message<Header, CODE> { ... }`.
+//!
+//! # Examples
+//! ```
+//! use xrbk::prelude::*;
+//!
+//! define! {
+//!     #[derive(Debug, PartialEq, Eq)]
+//!     pub struct Greeting {
+//!         let name_len: u16,
+//!         [(); 2],
+//!         #[length(name_len)]
+//!         pub name: Vec<u8>,
+//!         [(); ..],
+//!     }
+//! }
+//!
+//! let greeting = Greeting { name: b"hello".to_vec() };
+//!
+//! let mut bytes = vec![];
+//! greeting.write_to(&mut bytes).unwrap();
+//!
+//! // The length, 2 unused bytes, the name, and 3 bytes of padding.
+//! assert_eq!(bytes.len(), 12);
+//! assert_eq!(Greeting::read_from(&mut &bytes[..]).unwrap(), greeting);
+//! ```
+//!
+//! [`MessageHeader`]: runtime::MessageHeader
+
+pub use bytes;
+pub use cornflakes;
+// The derive macros share the names of the traits they derive.
+pub use cornflakes::{
+	ByteSize, ContextualReadable, ReadError, Readable, StaticByteSize, Writable, WriteError,
+};
+pub use xrbk_macro::{define, ByteSize, Readable, StaticByteSize, Writable};
+
+/// The traits, errors, and length arithmetic used by the code generated by
+/// [`define!`].
+///
+/// These are the same as those of XRB, which refers to them as `xrb::...`,
+/// so the [`prelude`] brings this module into scope as `xrb`.
+pub mod runtime;
+
+/// Everything needed to use [`define!`].
+///
+/// The code it generates refers to `bytes::...`, `cornflakes::...`, and
+/// `xrb::...`, so a glob import of this module is the simplest way to bring
+/// them all into scope.
+pub mod prelude {
+	pub use crate::{
+		bytes, cornflakes, define,
+		runtime::{self as xrb, MessageHeader, PrettyPrint},
+		ByteSize, ContextualReadable, Readable, StaticByteSize, Writable,
+	};
+}
@@ -0,0 +1,31 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// These are shared with XRB in the same way as the definitions in `snapshot/`
+// are, so that there is only one implementation of each.
+
+// `#[xrbk(crate = "xrbk::runtime")]` brings these into scope from here, too.
+pub use bytes;
+pub use cornflakes;
+
+#[path = "../../src/x11/traits.rs"]
+mod traits;
+pub use traits::*;
+
+#[allow(
+	dead_code,
+	reason = "XRB's helpers for reading masks and value lists aren't used by `define!`"
+)]
+#[path = "../../src/x11/read.rs"]
+mod read;
+pub use read::*;
+
+#[path = "../../src/x11/trace.rs"]
+mod trace;
+pub use trace::*;
+
+/// Padding and length arithmetic, as used by the messages generated by
+/// `define!`.
+#[path = "../../src/util.rs"]
+pub mod util;
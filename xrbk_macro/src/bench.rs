@@ -0,0 +1,187 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Times the expansion of every [`define!`] invocation in XRB's source files,
+//! and of a generated definition of increasing size, so that changes which
+//! slow down expansion can be noticed:
+//! ```sh
+//! cargo run --release -p xrbk_macro --bin xrbk-bench
+//! ```
+//! Only the parsing and expansion of the definitions is timed, as that is
+//! what the compiler waits for; reading and parsing the source files is not.
+//!
+//! The generated definition is expanded with 250 to 4000 items. Since
+//! expansion should take linear time, the time taken for each item should
+//! stay about the same as the definition grows.
+//!
+//! [`define!`]: https://docs.aquariwm.org/doc/xrbk_macro/macro.define.html
+
+#![feature(anonymous_lifetime_in_impl_trait)]
+
+mod content;
+mod definition;
+mod impls;
+mod ts_ext;
+
+use std::{
+	error::Error,
+	fmt::Write,
+	fs,
+	path::{Path, PathBuf},
+	time::{Duration, Instant},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::{ItemMacro, ItemMod};
+
+pub(crate) use content::*;
+pub(crate) use definition::*;
+pub(crate) use impls::*;
+pub(crate) use ts_ext::*;
+
+/// The number of times each invocation is expanded, of which the fastest is
+/// taken.
+const RUNS: usize = 10;
+
+/// The numbers of items in the generated definitions.
+const SIZES: [usize; 5] = [250, 500, 1000, 2000, 4000];
+
+/// The number of slowest files whose times are shown.
+const SLOWEST: usize = 10;
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.expect("`xrbk_macro` is in XRB's workspace");
+
+	let mut files = vec![];
+
+	for path in source_files(&root.join("src"))? {
+		let source = fs::read_to_string(&path)?;
+
+		if !source.contains("define!") {
+			continue;
+		}
+
+		let file = syn::parse_file(&source)
+			.map_err(|error| format!("failed to parse {}: {error}", path.display()))?;
+
+		let mut invocations = vec![];
+		find_invocations(&file.items, &mut invocations);
+
+		let mut time = Duration::ZERO;
+		for tokens in invocations {
+			time += fastest(|| expand(tokens.clone()))?;
+		}
+
+		files.push((path.strip_prefix(root)?.to_owned(), time));
+	}
+
+	let total: Duration = files.iter().map(|(_, time)| *time).sum();
+	println!("expanded {} files in {total:?}", files.len());
+
+	files.sort_by_key(|(_, time)| std::cmp::Reverse(*time));
+	for (path, time) in files.iter().take(SLOWEST) {
+		println!("  {time:>12?}  {}", path.display());
+	}
+
+	println!();
+	println!("generated definitions:");
+
+	for size in SIZES {
+		let time = fastest(|| expand(generated(size)))?;
+		let per_item = time / u32::try_from(size)?;
+
+		println!("  {size:>5} items  {time:>12?}  ({per_item:?} per item)");
+	}
+
+	Ok(())
+}
+
+/// Parses and expands the given `define!` invocation's tokens.
+fn expand(tokens: TokenStream2) -> syn::Result<TokenStream2> {
+	let definitions: Definitions = syn::parse2(tokens)?;
+
+	Ok(TokenStream2::with_tokens(|tokens| {
+		definitions.to_tokens(tokens);
+		definitions.impl_tokens(tokens);
+	}))
+}
+
+/// Returns the fastest of [`RUNS`] runs of `f`.
+fn fastest(mut f: impl FnMut() -> syn::Result<TokenStream2>) -> syn::Result<Duration> {
+	let mut fastest = Duration::MAX;
+
+	for _ in 0..RUNS {
+		let start = Instant::now();
+		// The expansion is dropped after timing, as the compiler would
+		// consume it.
+		let expansion = f()?;
+		fastest = fastest.min(start.elapsed());
+
+		drop(expansion);
+	}
+
+	Ok(fastest)
+}
+
+/// Generates a `define!` invocation's tokens for a struct with `size` items:
+/// lists, each following a `let` item for its length and followed by its
+/// padding.
+fn generated(size: usize) -> TokenStream2 {
+	let mut items = String::new();
+
+	for index in 0..size / 3 {
+		write!(
+			items,
+			"let len{index}: u16 = self => self.list{index}.len() as u16,
+			#[context(len{index} => usize::from(*len{index}))]
+			pub list{index}: Vec<u8>,
+			[(); len{index} => (4 - usize::from(*len{index}) % 4) % 4],"
+		)
+		.expect("writing to a `String` can't fail");
+	}
+
+	format!("pub struct Generated: Request<1> {{ {items} }}")
+		.parse()
+		.expect("the generated definition is valid Rust")
+}
+
+/// Collects the tokens of every `define!` invocation in the given `items`,
+/// including within inline modules.
+fn find_invocations(items: &[syn::Item], invocations: &mut Vec<TokenStream2>) {
+	for item in items {
+		match item {
+			syn::Item::Macro(ItemMacro { mac, .. }) if mac.path.is_ident("define") => {
+				invocations.push(mac.tokens.clone());
+			}
+
+			syn::Item::Mod(ItemMod {
+				content: Some((_, content)),
+				..
+			}) => find_invocations(content, invocations),
+
+			_ => {}
+		}
+	}
+}
+
+/// Returns the paths of every Rust source file within `dir`, sorted.
+fn source_files(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+	let mut files = vec![];
+
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+
+		if path.is_dir() {
+			files.extend(source_files(&path)?);
+		} else if path.extension().is_some_and(|extension| extension == "rs") {
+			files.push(path);
+		}
+	}
+
+	files.sort();
+	Ok(files)
+}
@@ -7,11 +7,11 @@ use std::collections::HashMap;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::ToTokens;
 use syn::{
-	braced, bracketed, parenthesized, parse::ParseStream, spanned::Spanned, token, Error, Path,
-	Result, Token, Type,
+	braced, bracketed, parenthesized, parse::ParseStream, parse_quote, punctuated::Punctuated,
+	spanned::Spanned, token, Error, Expr, Ident, Path, Result, Token, Type,
 };
 
-use super::source::Source;
+use super::source::{Arg, Source};
 
 /// An attribute, reimplemented to allow for [`Context`] and metabyte attributes.
 pub struct Attribute {
@@ -31,6 +31,11 @@ impl Attribute {
 		matches!(self.content, AttrContent::Context(..))
 	}
 
+	/// Whether this is an [`AttrContent::PresentIf`] attribute.
+	pub const fn is_present_if(&self) -> bool {
+		matches!(self.content, AttrContent::PresentIf(..))
+	}
+
 	/// Whether this is an [`AttrContent::Metabyte`] attribute.
 	pub const fn is_metabyte(&self) -> bool {
 		matches!(self.content, AttrContent::Metabyte(..))
@@ -50,6 +55,9 @@ impl Attribute {
 /// The content of an [`Attribute`] (what is between the square brackets).
 pub enum AttrContent {
 	Context(Path, Box<Context>),
+	/// The condition under which an `Option` field is present, given like a
+	/// [`Context`]: `#[present_if(mask => mask.contains(Mask::X))]`.
+	PresentIf(Path, Box<Context>),
 	Metabyte(Path),
 
 	Other(Path, TokenStream2),
@@ -79,6 +87,20 @@ pub enum Context {
 	/// }]
 	/// ```
 	Brace(token::Brace, Source),
+	/// ```ignore
+	/// #[length(data_len)]
+	/// ```
+	///
+	/// The [`Source`] is `data_len => *data_len as usize`, and `data_len` is
+	/// written as the length of the field.
+	Length(token::Paren, Source),
+	/// ```ignore
+	/// #[discriminant(kind)]
+	/// ```
+	///
+	/// The [`Source`] is `kind => *kind`, and `kind` is written as the
+	/// discriminant of the field, which is a `match` enum.
+	Discriminant(token::Paren, Source),
 }
 
 impl Context {
@@ -89,6 +111,36 @@ impl Context {
 			Self::Paren(_, source) => source,
 			Self::Bracket(_, source) => source,
 			Self::Brace(_, source) => source,
+			Self::Length(_, source) => source,
+			Self::Discriminant(_, source) => source,
+		}
+	}
+
+	/// The `let` item which is written as the length of the field, if this is
+	/// a [`Context::Length`] attribute.
+	pub fn length(&self) -> Option<&Ident> {
+		match self {
+			Self::Length(_, source) => source
+				.args
+				.iter()
+				.flatten()
+				.next()
+				.map(|Arg(ident, _)| ident),
+			_ => None,
+		}
+	}
+
+	/// The `let` item which is written as the discriminant of the field, if
+	/// this is a [`Context::Discriminant`] attribute.
+	pub fn discriminant(&self) -> Option<&Ident> {
+		match self {
+			Self::Discriminant(_, source) => source
+				.args
+				.iter()
+				.flatten()
+				.next()
+				.map(|Arg(ident, _)| ident),
+			_ => None,
 		}
 	}
 }
@@ -122,13 +174,13 @@ impl Attribute {
 		let content;
 
 		let hash_token = input.parse()?;
-		let style: Option<Token![!]> = input.parse().ok();
+		let style: Option<Token![!]> = input.parse()?;
 		let bracket_token = bracketed!(content in input);
 		let attr_content = AttrContent::parse(&content, map)?;
 
 		// If this is an inner context attribute, generate an error:
 		if let Some(style) = style {
-			if let AttrContent::Context(..) = attr_content {
+			if let AttrContent::Context(..) | AttrContent::PresentIf(..) = attr_content {
 				return Err(Error::new(
 					style.span,
 					"inner context attributes are not allowed",
@@ -203,6 +255,12 @@ impl AttrContent {
 
 		Ok(if path.is_ident("context") {
 			Self::Context(path, Box::new(Context::parse(input, map)?))
+		} else if path.is_ident("length") {
+			Self::Context(path, Box::new(Context::parse_length(input, map)?))
+		} else if path.is_ident("discriminant") {
+			Self::Context(path, Box::new(Context::parse_discriminant(input, map)?))
+		} else if path.is_ident("present_if") {
+			Self::PresentIf(path, Box::new(Context::parse(input, map)?))
 		} else if path.is_ident("metabyte") {
 			Self::Metabyte(path)
 		} else {
@@ -265,6 +323,58 @@ impl Context {
 			Err(look.error())
 		}
 	}
+
+	/// Parses the content of a `#[length(data_len)]` attribute, which is the
+	/// same as `#[context(data_len => *data_len as usize)]`.
+	fn parse_length(input: ParseStream, map: &HashMap<String, Type>) -> Result<Self> {
+		let (paren_token, source) =
+			Self::parse_let_arg(input, map, "length", |ident| parse_quote!(*#ident as usize))?;
+
+		Ok(Self::Length(paren_token, source))
+	}
+
+	/// Parses the content of a `#[discriminant(kind)]` attribute, which is the
+	/// same as `#[context(kind => *kind)]`.
+	fn parse_discriminant(input: ParseStream, map: &HashMap<String, Type>) -> Result<Self> {
+		let (paren_token, source) =
+			Self::parse_let_arg(input, map, "discriminant", |ident| parse_quote!(*#ident))?;
+
+		Ok(Self::Discriminant(paren_token, source))
+	}
+
+	/// Parses the single `let` item named in the brackets of an attribute
+	/// such as `#[length(...)]`, returning the [`Source`] which gives the
+	/// `expr` found from it as the context.
+	fn parse_let_arg(
+		input: ParseStream,
+		map: &HashMap<String, Type>,
+		name: &str,
+		expr: impl FnOnce(&Ident) -> Expr,
+	) -> Result<(token::Paren, Source)> {
+		let content;
+
+		let paren_token = parenthesized!(content in input);
+		let arg = Arg::parse(&content, map)?;
+
+		if !content.is_empty() {
+			return Err(content.error(format!(
+				"expected `)`: `#[{name}(...)]` names a single `let` item written before the field"
+			)));
+		}
+
+		let expr = expr(&arg.0);
+
+		Ok((
+			paren_token,
+			Source {
+				receiver: None,
+				comma_token: None,
+				args: Some(Punctuated::from_iter([arg])),
+				arrow_token: None,
+				expr,
+			},
+		))
+	}
 }
 
 // }}}
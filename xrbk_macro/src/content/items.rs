@@ -7,11 +7,12 @@ use std::collections::HashMap;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-	braced, bracketed, parenthesized,
+	braced, parenthesized,
 	parse::{Parse, ParseStream, Result},
+	parse_quote,
 	punctuated::{Pair, Punctuated},
 	spanned::Spanned,
-	token, Error, Ident, Token, Type,
+	token, Error, Ident, Member, Token, Type,
 };
 
 use crate::*;
@@ -94,9 +95,20 @@ impl FieldId {
 
 impl ToTokens for Items {
 	fn to_tokens(&self, tokens: &mut TokenStream2) {
-		/// An internal-use function within `to_tokens` to reduce repeated
-		/// code. This ensures that commas are only converted to tokens if
-		/// their respective item is.
+		self.declaration_to_tokens(tokens, false);
+	}
+}
+
+impl Items {
+	/// Expands the declaration of these `Items`' fields, as they appear in a
+	/// struct or enum variant definition.
+	///
+	/// If `has_sequence` is `true`, a `_sequence_` field is prepended to the
+	/// fields (turning [`Items::Unit`] into named items).
+	pub fn declaration_to_tokens(&self, tokens: &mut TokenStream2, has_sequence: bool) {
+		/// An internal-use function within `declaration_to_tokens` to reduce
+		/// repeated code. This ensures that commas are only converted to
+		/// tokens if their respective item is.
 		fn items_to_tokens(items: &Punctuated<ItemWithId, Token![,]>, tokens: &mut TokenStream2) {
 			// For every pair of item and a possible comma...
 			for pair in items.pairs() {
@@ -116,19 +128,62 @@ impl ToTokens for Items {
 			}
 		}
 
+		// The sequence field, if any.
+		let sequence = |named: bool| {
+			if !has_sequence {
+				quote!()
+			} else if named {
+				quote!(
+					/// The sequence number associated with the last relevant
+					/// request sent to the X server.
+					#[allow(clippy::pub_underscore_fields)]
+					pub _sequence_: u16,
+				)
+			} else {
+				quote!(pub u16,)
+			}
+		};
+
 		match self {
 			// Surround named items with their curly brackets.
 			Self::Named { brace_token, items } => {
-				brace_token.surround(tokens, |tokens| items_to_tokens(items, tokens));
+				brace_token.surround(tokens, |tokens| {
+					sequence(true).to_tokens(tokens);
+					items_to_tokens(items, tokens);
+				});
 			}
 
 			// Surround unnamed items with their normal brackets.
 			Self::Unnamed { paren_token, items } => {
-				paren_token.surround(tokens, |tokens| items_to_tokens(items, tokens));
+				paren_token.surround(tokens, |tokens| {
+					sequence(false).to_tokens(tokens);
+					items_to_tokens(items, tokens);
+				});
 			}
 
-			// Don't convert `Self::Unit` to any tokens at all.
-			Self::Unit => (),
+			// Only convert `Self::Unit` to tokens if there is a sequence
+			// field.
+			Self::Unit => {
+				if has_sequence {
+					let sequence = sequence(true);
+
+					tokens.append_tokens(|| quote!({ #sequence }));
+				}
+			}
+		}
+	}
+
+	/// Returns whether there are no items at all.
+	pub fn is_empty(&self) -> bool {
+		self.iter().next().is_none()
+	}
+
+	/// Expands the tokens used to access the `_sequence_` field on `self`:
+	/// `_sequence_` for named and unit items, `0` for unnamed items.
+	pub fn sequence_member_tokens(&self) -> TokenStream2 {
+		match self {
+			Self::Unnamed { .. } => syn::Index::from(0).to_token_stream(),
+			Self::Named { .. } | Self::Unit => quote!(_sequence_),
 		}
 	}
 }
@@ -210,8 +265,13 @@ impl Items {
 				paren_token.surround(tokens, |tokens| fields(tokens, self, mode))
 			}
 
-			// Don't expand anything for `Self::Unit`.
-			Self::Unit => {}
+			// `Self::Unit` only has a field if it has a sequence field, in
+			// which case it is expanded as named items.
+			Self::Unit => {
+				if let ExpandMode::Reply { has_sequence: true } | ExpandMode::Event = mode {
+					tokens.append_tokens(|| quote!({ _sequence_, }));
+				}
+			}
 		}
 	}
 
@@ -235,9 +295,12 @@ impl Items {
 			metabyte.deserialize_tokens(tokens, id);
 		} else {
 			// Otherwise, skip.
+			let check = ensure_remaining_tokens(1_usize);
+
 			tokens.append_tokens(|| {
 				quote!(
-					reader.advance(1);
+					#check
+					xrb::skip_padding(reader, 1)?;
 				)
 			});
 		}
@@ -248,6 +311,147 @@ impl Items {
 
 // Parsing {{{
 
+/// The names of the values which the generated code reads from messages'
+/// headers, which sources can refer to, so fields and let items can't have
+/// them.
+///
+/// Any other name can be used, as fields and let items are bound to their
+/// names formatted as `__name__` in the generated code.
+const RESERVED_NAMES: [&str; 5] = [
+	"_sequence_",
+	"_length_",
+	"_metabyte_",
+	"_extension_",
+	"_evtype_",
+];
+
+/// Returns an error if the given name of a field or let item is reserved, or
+/// is already in the `map` of earlier items' names.
+fn check_name(ident: &Ident, map: &HashMap<String, Type>) -> Result<()> {
+	let name = ident.to_string();
+
+	if RESERVED_NAMES.contains(&name.as_str()) {
+		return Err(Error::new(
+			ident.span(),
+			format!("`{name}` is used by the code generated by `define!`, so it can't be the name of a field or let item"),
+		));
+	}
+
+	if map.contains_key(&name) {
+		return Err(Error::new(
+			ident.span(),
+			format!("`{name}` is already the name of an earlier field or let item"),
+		));
+	}
+
+	Ok(())
+}
+
+/// Returns an error if a field with the given `attributes` has a
+/// `#[present_if(...)]` attribute but is not an `Option`, or is also read
+/// with a context or in the metabyte position.
+fn check_present_if(attributes: &[Attribute], r#type: &Type) -> Result<()> {
+	let Some(present_if) = attributes.iter().find(|attr| attr.is_present_if()) else {
+		return Ok(());
+	};
+
+	if option_inner_type(r#type).is_none() {
+		return Err(Error::new(
+			r#type.span(),
+			"fields with a `#[present_if(...)]` attribute must be `Option`s, which are `None` if \
+			 they are not present",
+		));
+	}
+
+	if attributes
+		.iter()
+		.any(|attr| attr.is_context() || attr.is_metabyte())
+	{
+		return Err(Error::new(
+			present_if.bracket_token.span,
+			"`#[present_if(...)]` can't be combined with `#[context(...)]`, `#[length(...)]`, or \
+			 `#[metabyte]`",
+		));
+	}
+
+	Ok(())
+}
+
+/// Sets the source of the let item named by the `#[length(...)]` or
+/// `#[discriminant(...)]` attribute among the given field's `attributes`, if
+/// there is one, to the length or discriminant of the field, `member`.
+fn set_written_source(
+	items: &mut Punctuated<ItemWithId, Token![,]>,
+	attributes: &[Attribute],
+	member: &Member,
+) -> Result<()> {
+	let mut contexts = attributes.iter().filter(|attr| attr.is_context());
+
+	let Some(context) = contexts.next() else {
+		return Ok(());
+	};
+
+	if let Some(attr) = contexts.next() {
+		return Err(Error::new(
+			attr.bracket_token.span,
+			"a field can only have one `#[context(...)]`, `#[length(...)]`, or \
+			 `#[discriminant(...)]` attribute",
+		));
+	}
+
+	let AttrContent::Context(_, context) = &context.content else {
+		unreachable!("context attributes have context content");
+	};
+	let (ident, name, written) = if let Some(ident) = context.length() {
+		(ident, "length", quote!(self.#member.len()))
+	} else if let Some(ident) = context.discriminant() {
+		(
+			ident,
+			"discriminant",
+			quote!(xrb::WireEnum::raw(&self.#member)),
+		)
+	} else {
+		return Ok(());
+	};
+
+	let r#let = items.iter_mut().find_map(|(id, item)| match (id, item) {
+		(ItemId::Let(id), Item::Let(r#let)) if id == ident => Some(r#let),
+		_ => None,
+	});
+
+	match r#let {
+		None => Err(Error::new(
+			ident.span(),
+			format!(
+				"`#[{name}({ident})]` must name a `let` item, which is written as the {name} of \
+				 this field"
+			),
+		)),
+
+		Some(r#let) if r#let.source.is_some() => Err(Error::new(
+			ident.span(),
+			format!(
+				"`{ident}` is written as the {name} of this field, so it can't have a source of \
+				 its own"
+			),
+		)),
+
+		Some(r#let) => {
+			let r#type = &r#let.r#type;
+
+			r#let.source = Some(Source {
+				receiver: Some(parse_quote!(self)),
+				comma_token: None,
+				args: None,
+				arrow_token: Some(<Token![=>]>::default()),
+				expr: parse_quote!(#written as #r#type),
+			});
+
+			Ok(())
+		}
+	}
+}
+
 impl Items {
 	pub(self) fn parse_items(
 		input: ParseStream,
@@ -255,6 +459,8 @@ impl Items {
 	) -> Result<Punctuated<ItemWithId, Token![,]>> {
 		let mut unused_index: usize = 0;
 		let mut field_index: usize = 0;
+		// Whether an item has already been put in the metabyte position.
+		let mut has_metabyte = false;
 
 		let mut items = Punctuated::new();
 		// Keep track of the identifiers defined thus far and which types they
@@ -264,174 +470,205 @@ impl Items {
 		// While there are still tokens left in the `input` stream, we continue
 		// to parse items.
 		while !input.is_empty() {
-			if input.peek(Token![#]) {
-				let mut attributes = Attribute::parse_outer(input, &map)?;
+			// Parse the attributes associated with the item, if any.
+			let mut attributes = Attribute::parse_outer(input, &map)?;
+
+			// There is only one metabyte position, so only one item can be
+			// put in it.
+			for attr in attributes.iter().filter(|attr| attr.is_metabyte()) {
+				if has_metabyte {
+					// Metabyte attributes aren't converted back to tokens, so
+					// they can't be spanned by `attr.span()`.
+					return Err(Error::new(
+						attr.bracket_token.span,
+						"only one item can have a metabyte attribute: there is only one metabyte position",
+					));
+				}
 
-				if input.peek(token::Bracket) || input.peek(token::Paren) {
-					// Unused bytes item.
+				has_metabyte = true;
+			}
 
-					if let Some(attr) = attributes.first() {
-						if !attr.is_metabyte() {
-							return Err(Error::new(
-								attr.span(),
-								"only a metabyte attribute is allowed for unused items",
-							));
-						}
-					} else if let Some(attr) = attributes.get(1) {
+			if Unused::peek(input) {
+				// Unused bytes item.
+
+				if let Some(attr) = attributes.get(1) {
+					return Err(Error::new(
+						attr.span(),
+						"only zero or one (metabyte) attributes are allowed for unused items",
+					));
+				} else if let Some(attr) = attributes.first() {
+					if !attr.is_metabyte() {
 						return Err(Error::new(
 							attr.span(),
-							"only zero or one (metabyte) attributes are allowed for unused items",
+							"only a metabyte attribute is allowed for unused items",
 						));
 					}
+				}
+
+				if input.peek(token::Paren) {
+					// Unit, with or without a metabyte attribute.
 
 					let _unit;
 
-					if !attributes.is_empty() {
-						// Unit with attribute.
+					items.push_value((
+						ItemId::Unused(None),
+						Item::Unused(Unused::Unit {
+							attribute: attributes.pop(),
+							unit_token: parenthesized!(_unit in input),
+						}),
+					));
+				} else {
+					// Array.
 
-						items.push_value((
-							ItemId::Unused(None),
-							Item::Unused(Unused::Unit {
-								attribute: Some(attributes.remove(0)),
-								unit_token: parenthesized!(_unit in input),
-							}),
-						));
-					} else if input.peek(token::Paren) {
-						// Unit, no attribute.
-
-						items.push_value((
-							ItemId::Unused(None),
-							Item::Unused(Unused::Unit {
-								attribute: None,
-								unit_token: parenthesized!(_unit in input),
-							}),
-						));
-					} else {
-						// Array.
-
-						let content;
-
-						let index = unused_index;
-						unused_index += 1;
-
-						items.push_value((
-							ItemId::Unused(Some(index)),
-							Item::Unused(Unused::Array(Box::new(Array {
-								bracket_token: bracketed!(content in input),
-								unit_token: parenthesized!(_unit in content),
-								semicolon_token: content.parse()?,
-								source: Source::parse(input, &map)?,
-							}))),
+					// There is no guarantee that an array of unused bytes is
+					// exactly one byte long, so it cannot be the metabyte.
+					if let Some(attr) = attributes.first() {
+						return Err(Error::new(
+							attr.span(),
+							"a metabyte attribute is not allowed for unused bytes arrays",
 						));
 					}
-				} else if input.peek(Token![let]) {
-					// Let item.
-
-					if let Some(attr) = attributes.get(0) {
-						if !attr.is_metabyte() {
-							return Err(Error::new(
-								attr.span(),
-								"only a metabyte attribute is allowed for let items",
-							));
-						}
-					} else if let Some(attr) = attributes.get(1) {
+
+					let index = unused_index;
+					unused_index += 1;
+
+					items.push_value((
+						ItemId::Unused(Some(index)),
+						Item::Unused(Unused::Array(Box::new(Array::parse(
+							input,
+							&map,
+							items.last(),
+						)?))),
+					));
+				}
+			} else if input.peek(Token![let]) {
+				// Let item.
+
+				if let Some(attr) = attributes.get(1) {
+					return Err(Error::new(
+						attr.span(),
+						"only zero or one (metabyte) attributes are allowed for let items",
+					));
+				} else if let Some(attr) = attributes.first() {
+					if !attr.is_metabyte() {
 						return Err(Error::new(
 							attr.span(),
-							"only zero or one (metabyte) attributes are allowed for let items",
+							"only a metabyte attribute is allowed for let items",
 						));
 					}
+				}
 
-					let r#let = Let {
-						attribute: if !attributes.is_empty() {
-							Some(attributes.remove(0))
-						} else {
-							None
-						},
+				let attribute = attributes.pop();
 
-						let_token: input.parse()?,
+				let let_token = input.parse()?;
 
-						ident: input.parse()?,
-						colon_token: input.parse()?,
-						r#type: input.parse()?,
+				let ident: Ident = input.parse()?;
+				check_name(&ident, &map)?;
 
-						eq_token: input.parse()?,
+				let colon_token = input.parse()?;
+				let r#type = input.parse()?;
 
-						source: Source::parse_without_args(input)?,
-					};
+				// The source can be omitted if it is set by a later field's
+				// `#[length(...)]` attribute.
+				let eq_token: Option<Token![=]> = input.parse()?;
+				let source = if eq_token.is_some() {
+					Some(Source::parse_without_args(input)?)
+				} else {
+					None
+				};
 
-					// Insert the let item's `ident` and `type` to the `map` of
-					// known `Ident`s.
-					map.insert(r#let.ident.to_string(), r#let.r#type.to_owned());
+				let r#let = Let {
+					attribute,
 
-					// Push the let item's ID and the let item itself to the
-					// list of parsed items.
-					items.push_value((
-						ItemId::Let(r#let.ident.to_owned()),
-						Item::Let(Box::new(r#let)),
-					));
-				} else {
-					// Field item.
+					let_token,
 
-					if named {
-						// If this is a named field, parse it with an `ident`
-						// and a `colon_token`.
+					ident,
+					colon_token,
+					r#type,
 
-						let vis = input.parse()?;
+					eq_token,
 
-						let ident: Ident = input.parse()?;
-						let colon_token = input.parse()?;
+					source,
+				};
 
-						let r#type: Type = input.parse()?;
+				// Insert the let item's `ident` and `type` to the `map` of
+				// known `Ident`s.
+				map.insert(r#let.ident.to_string(), r#let.r#type.to_owned());
 
-						// Insert the field's `ident` and `type` to the `map`
-						// of known `Ident`s.
-						map.insert(ident.to_string(), r#type.to_owned());
+				// Push the let item's ID and the let item itself to the list
+				// of parsed items.
+				items.push_value((
+					ItemId::Let(r#let.ident.to_owned()),
+					Item::Let(Box::new(r#let)),
+				));
+			} else if named {
+				// Named field item: parse it with an `ident` and a
+				// `colon_token`.
 
-						// Push the field's ID and the field itself to the
-						// list of parsed items.
-						items.push_value((
-							ItemId::Field(FieldId::Ident(ident.to_owned())),
-							Item::Field(Box::new(Field {
-								attributes,
+				let vis = input.parse()?;
 
-								vis,
+				let ident: Ident = input.parse()?;
+				check_name(&ident, &map)?;
 
-								ident: Some(ident),
-								colon_token: Some(colon_token),
+				let colon_token = input.parse()?;
 
-								r#type,
-							})),
-						));
-					} else {
-						// Copy the current `field_index`.
-						let index = field_index;
-						// Increase the `field_index` by `1` without affecting
-						// `index`.
-						field_index += 1;
+				let r#type: Type = input.parse()?;
 
-						let vis = input.parse()?;
-						let r#type: Type = input.parse()?;
+				check_present_if(&attributes, &r#type)?;
+				set_written_source(&mut items, &attributes, &Member::Named(ident.clone()))?;
 
-						// Insert the field's `index` and `type` to the `map`
-						// of known `Ident`s.
-						map.insert(index.to_string(), r#type.to_owned());
+				// Insert the field's `ident` and `type` to the `map` of known
+				// `Ident`s.
+				map.insert(ident.to_string(), r#type.to_owned());
 
-						// Push the field's ID and the field itself to the list of parsed items.
-						items.push_value((
-							ItemId::Field(FieldId::Id(index)),
-							Item::Field(Box::new(Field {
-								attributes,
+				// Push the field's ID and the field itself to the list of
+				// parsed items.
+				items.push_value((
+					ItemId::Field(FieldId::Ident(ident.to_owned())),
+					Item::Field(Box::new(Field {
+						attributes,
 
-								vis,
+						vis,
 
-								ident: None,
-								colon_token: None,
+						ident: Some(ident),
+						colon_token: Some(colon_token),
 
-								r#type,
-							})),
-						));
-					}
-				}
+						r#type,
+					})),
+				));
+			} else {
+				// Unnamed field item.
+
+				// Copy the current `field_index`.
+				let index = field_index;
+				// Increase the `field_index` by `1` without affecting `index`.
+				field_index += 1;
+
+				let vis = input.parse()?;
+				let r#type: Type = input.parse()?;
+
+				check_present_if(&attributes, &r#type)?;
+				set_written_source(&mut items, &attributes, &Member::Unnamed(index.into()))?;
+
+				// Insert the field's `index` and `type` to the `map` of known
+				// `Ident`s.
+				map.insert(index.to_string(), r#type.to_owned());
+
+				// Push the field's ID and the field itself to the list of
+				// parsed items.
+				items.push_value((
+					ItemId::Field(FieldId::Id(index)),
+					Item::Field(Box::new(Field {
+						attributes,
+
+						vis,
+
+						ident: None,
+						colon_token: None,
+
+						r#type,
+					})),
+				));
 			}
 
 			// If the token following the item is not a comma, then it must be
@@ -445,6 +682,24 @@ impl Items {
 			}
 		}
 
+		// Every let item must have a source, either of its own or from a
+		// `#[length(...)]` attribute.
+		for (_, item) in &items {
+			if let Item::Let(r#let) = item {
+				if r#let.source.is_none() {
+					let ident = &r#let.ident;
+
+					return Err(Error::new(
+						ident.span(),
+						format!(
+							"`{ident}` has no source: give it one with `= self => ...`, or name \
+							 it in a later field's `#[length({ident})]` attribute"
+						),
+					));
+				}
+			}
+		}
+
 		Ok(items)
 	}
 
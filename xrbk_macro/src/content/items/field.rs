@@ -42,6 +42,15 @@ impl Field {
 			_ => None,
 		})
 	}
+
+	/// The condition under which this `Option` field is present, given with
+	/// a `#[present_if(...)]` attribute, if there is one.
+	pub fn present_if(&self) -> Option<&Context> {
+		self.attributes.iter().find_map(|attr| match &attr.content {
+			AttrContent::PresentIf(_, condition) => Some(&**condition),
+			_ => None,
+		})
+	}
 }
 
 // Expansion {{{
@@ -2,11 +2,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
 use syn::{Ident, Token, Type};
 
-use crate::{Attribute, TsExt};
+use crate::Attribute;
 
 use super::Source;
 
@@ -26,52 +24,13 @@ pub struct Let {
 	/// The [`Type`] used to `read` this data.
 	pub r#type: Type,
 
-	/// The equals token preceding the `expr`: `=`.
-	pub eq_token: Token![=],
+	/// The equals token preceding the `source`: `=`.
+	pub eq_token: Option<Token![=]>,
 
 	/// The [`Source`] used in the generated function for this `Let` item.
-	pub source: Source,
-}
-
-// Expansion {{{
-
-impl ToTokens for Let {
-	fn to_tokens(&self, tokens: &mut TokenStream2) {
-		// `let`
-		self.let_token.to_tokens(tokens);
-
-		// `product` -> `__product__`
-		format_ident!("__{}__", self.ident).to_tokens(tokens);
-		// `:`
-		self.colon_token.to_tokens(tokens);
-		// `Type`
-		self.r#type.to_tokens(tokens);
-
-		// `=`
-		self.eq_token.to_tokens(tokens);
-
-		quote! (
-			reader.read()?;
-		)
-		.to_tokens(tokens);
-	}
-}
-
-impl Let {
-	pub fn to_fn_tokens(&self, tokens: &mut TokenStream2) {
-		self.source
-			.fn_to_tokens(tokens, &format_ident!("__{}__", self.ident), &self.r#type);
-	}
-
-	pub fn to_write_tokens(&self, tokens: &mut TokenStream2) {
-		let name = format_ident!("__{}__", self.ident);
-
-		tokens.append_tokens(|| {
-			quote!(
-				writer.write(self.#name());
-			)
-		});
-	}
+	///
+	/// This is only omitted if the `Let` item is written as the length of a
+	/// later field given a `#[length(...)]` attribute, which sets it once
+	/// that field is parsed.
+	pub source: Option<Source>,
 }
-
-// }}}
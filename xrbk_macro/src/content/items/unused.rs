@@ -4,9 +4,12 @@
 
 use std::collections::HashMap;
 
-use syn::{bracketed, parenthesized, parse::ParseStream, token, Result, Token, Type};
+use syn::{bracketed, parenthesized, parse::ParseStream, token, Error, Ident, Result, Token, Type};
 
-use crate::content::Attribute;
+use crate::{
+	content::{Attribute, Item, ItemId},
+	is_cow_bytes,
+};
 
 use super::Source;
 
@@ -42,8 +45,30 @@ pub struct Array {
 	pub unit_token: token::Paren,
 	/// A semicolon token: `;`.
 	pub semicolon_token: Token![;],
-	/// The [`Source`] that provides the number of unused bytes.
-	pub source: Source,
+	/// The number of unused bytes.
+	pub len: ArrayLen,
+}
+
+/// The number of unused bytes in an [`Array`].
+#[allow(
+	clippy::large_enum_variant,
+	reason = "`Array`s are already boxed in `Unused::Array`"
+)]
+pub enum ArrayLen {
+	/// A [`Source`] that provides the number of unused bytes.
+	Source(Source),
+
+	/// `..`: enough unused bytes to pad the item before the array to a
+	/// multiple of 4 bytes, as X11 pads most lists.
+	Pad {
+		/// A double dot token: `..`.
+		dot2_token: Token![..],
+		/// The name that the item before the array is bound to.
+		previous: Ident,
+		/// Whether the item before the array is a `Cow<[u8]>` field, whose
+		/// size is its length rather than its `ByteSize`.
+		cow_bytes: bool,
+	},
 }
 
 impl Unused {
@@ -57,10 +82,15 @@ impl Unused {
 		matches!(self, Self::Array { .. })
 	}
 
-	/// Returns the contained [`Source`] if this is [`Unused::Array`].
+	/// Returns the contained [`Source`] if this is an [`Unused::Array`] with
+	/// its length given by one.
 	pub const fn source(&self) -> Option<&Source> {
 		match self {
-			Self::Array(array) => Some(&array.source),
+			Self::Array(array) => match &array.len {
+				ArrayLen::Source(source) => Some(source),
+				ArrayLen::Pad { .. } => None,
+			},
+
 			Self::Unit { .. } => None,
 		}
 	}
@@ -68,17 +98,121 @@ impl Unused {
 
 // Parsing {{{
 
+impl Unused {
+	/// Returns whether the next tokens in the `input` are an unused bytes
+	/// item: either a unit (`()`) or an array (`[(); ...]`).
+	///
+	/// This is needed to tell unused bytes items apart from unnamed fields
+	/// with tuple or array types, such as `(u8, u8)` or `[u8; 4]`.
+	pub fn peek(input: ParseStream) -> bool {
+		fn peek_unit(input: ParseStream) -> Result<bool> {
+			let content;
+			parenthesized!(content in input);
+
+			Ok(content.is_empty())
+		}
+
+		// Anything starting with `[()` is taken to be an unused bytes array,
+		// so that mistakes in its syntax are reported as such: no field can
+		// have that type anyway.
+		fn peek_array(input: ParseStream) -> Result<bool> {
+			let content;
+			bracketed!(content in input);
+
+			peek_unit(&content)
+		}
+
+		if input.peek(token::Paren) {
+			peek_unit(&input.fork()).unwrap_or(false)
+		} else if input.peek(token::Bracket) {
+			peek_array(&input.fork()).unwrap_or(false)
+		} else {
+			false
+		}
+	}
+}
+
 impl Array {
-	pub fn parse(input: ParseStream, map: &HashMap<String, Type>) -> Result<Self> {
+	/// Parses an `Array` following the `previous` item, if there is one,
+	/// which is padded if the array's length is `..`.
+	pub fn parse(
+		input: ParseStream,
+		map: &HashMap<String, Type>,
+		previous: Option<&(ItemId, Item)>,
+	) -> Result<Self> {
 		let (content, _unit);
 
+		let bracket_token = bracketed!(content in input);
+		let unit_token = parenthesized!(_unit in content);
+
+		if !content.peek(Token![;]) {
+			return Err(content.error(
+				"expected `;` after `()`: an array of unused bytes is written as `[(); len]`",
+			));
+		}
+
+		let semicolon_token = content.parse()?;
+
+		if content.is_empty() {
+			return Err(
+				content.error("expected the number of unused bytes after `;`, as in `[(); 4]`")
+			);
+		}
+
+		let len = if content.peek(Token![..]) && !content.peek(Token![..=]) {
+			let dot2_token: Token![..] = content.parse()?;
+
+			if !content.is_empty() {
+				return Err(content.error("expected `]` after `..`"));
+			}
+
+			ArrayLen::Pad {
+				dot2_token,
+				previous: padded_name(dot2_token, previous)?,
+				cow_bytes: matches!(
+					previous,
+					Some((_, Item::Field(field))) if is_cow_bytes(&field.r#type)
+				),
+			}
+		} else {
+			ArrayLen::Source(Source::parse_without_receiver(&content, map)?)
+		};
+
 		Ok(Self {
-			bracket_token: bracketed!(content in input),
-			unit_token: parenthesized!(_unit in content),
-			semicolon_token: content.parse()?,
-			source: Source::parse_without_receiver(&content, map)?,
+			bracket_token,
+			unit_token,
+			semicolon_token,
+			len,
 		})
 	}
 }
 
+/// Returns the name that the given `previous` item is bound to, so that it
+/// can be padded by a `[(); ..]` array.
+///
+/// Only fields and let items which are not in the metabyte position can be
+/// padded, as only they are written right before the array.
+fn padded_name(dot2_token: Token![..], previous: Option<&(ItemId, Item)>) -> Result<Ident> {
+	match previous {
+		Some((_, item)) if item.is_metabyte() => Err(Error::new_spanned(
+			dot2_token,
+			"`[(); ..]` can't pad an item in the metabyte position",
+		)),
+
+		Some((id, Item::Field(_) | Item::Let(_))) => {
+			Ok(id.formatted().expect("fields and let items have names"))
+		}
+
+		Some((_, Item::Unused(_))) => Err(Error::new_spanned(
+			dot2_token,
+			"`[(); ..]` pads the field or let item before it, not unused bytes",
+		)),
+
+		None => Err(Error::new_spanned(
+			dot2_token,
+			"`[(); ..]` pads the field or let item before it, so it can't be the first item",
+		)),
+	}
+}
+
 // }}}
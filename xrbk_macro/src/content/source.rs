@@ -2,8 +2,6 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::TsExt;
-
 use std::collections::HashMap;
 
 use proc_macro2::TokenStream as TokenStream2;
@@ -40,29 +38,51 @@ impl Source {
 // Expansion {{{
 
 impl Source {
-	pub fn fn_to_tokens(&self, tokens: &mut TokenStream2, ident: &Ident, r#type: &Type) {
-		let receiver = &self.receiver;
-		let comma = &self.comma_token;
-		let arg: &Vec<_> = &self.args.iter().flatten().collect();
+	/// Generates an expression which evaluates this `Source`.
+	///
+	/// If there are [`Arg`s](Arg), the expression is a block in which each
+	/// `Arg` is bound to a reference to the formatted identifier of the item
+	/// it refers to, so that the `Source`'s expression can use the original
+	/// name. A receiver, if any, simply refers to the `self` already in scope
+	/// where the expression is used.
+	///
+	/// # Examples
+	/// ```ignore
+	/// // data_len => *data_len as usize
+	/// {
+	///     let data_len: &u32 = &__data_len__;
+	///
+	///     *data_len as usize
+	/// }
+	/// ```
+	pub fn block_tokens(&self) -> TokenStream2 {
 		let expr = &self.expr;
 
-		tokens.append_tokens(|| {
-			quote!(
-				fn #ident(#receiver #comma #(#arg)*) -> #r#type {
+		match &self.args {
+			Some(args) if !args.is_empty() => {
+				let args = args.iter();
+
+				quote!({
+					#(#args)*
+
 					#expr
-				}
-			)
-		});
+				})
+			}
+
+			// Braces around a lone expression would trigger the
+			// `unused_braces` lint.
+			_ => expr.to_token_stream(),
+		}
 	}
 }
 
 impl ToTokens for Arg {
 	fn to_tokens(&self, tokens: &mut TokenStream2) {
 		let Self(ident, r#type) = self;
+		let formatted = format_ident!("__{}__", ident);
 
-		ident.to_tokens(tokens);
-		quote!(:).to_tokens(tokens);
-		r#type.to_tokens(tokens);
+		// let data_len: &u32 = &__data_len__;
+		quote!(let #ident: &#r#type = &#formatted;).to_tokens(tokens);
 	}
 }
 
@@ -70,13 +90,22 @@ impl ToTokens for Arg {
 
 // Parsing {{{
 
+/// Returns whether the next tokens in the `input` might be a receiver: `self`,
+/// `&self`, `&mut self`, or `mut self`.
+///
+/// Attempting to parse a receiver where there isn't one generates an error,
+/// which is slow enough to matter for large definitions.
+fn peek_receiver(input: ParseStream) -> bool {
+	input.peek(Token![self]) || input.peek(Token![&]) || input.peek(Token![mut])
+}
+
 impl Source {
 	/// Parse a `Source` that can have zero or more [`Arg`s](Arg) and a receiver.
 	pub fn parse(input: ParseStream, map: &HashMap<String, Type>) -> Result<Self> {
 		let fork = &input.fork();
 
 		// Parse a receiver (e.g. `self`, `&self`).
-		let receiver: Option<Receiver> = fork.parse().ok();
+		let receiver: Option<Receiver> = peek_receiver(fork).then(|| fork.parse().ok()).flatten();
 		let comma_token: Option<Token![,]> = receiver.as_ref().and_then(|_| fork.parse().ok());
 
 		// If there is EITHER:
@@ -134,7 +163,7 @@ impl Source {
 		let fork = input.fork();
 
 		// Parse a receiver (e.g. `self`, `&self`).
-		let receiver: Option<Receiver> = fork.parse().ok();
+		let receiver: Option<Receiver> = peek_receiver(&fork).then(|| fork.parse().ok()).flatten();
 		let comma_token: Option<Token![,]> = receiver.as_ref().and_then(|_| fork.parse().ok());
 
 		// If the next token is `=>`, then this is actually a `Source` with
@@ -176,7 +205,25 @@ impl Arg {
 		if let Some(r#type) = map.get(&ident.to_string()) {
 			Ok(Self(ident, r#type.to_owned()))
 		} else {
-			Err(Error::new(ident.span(), "unrecognized identifier"))
+			// Unnamed fields are known by their index, which can't be
+			// referred to.
+			let mut known: Vec<_> = map
+				.keys()
+				.filter(|name| !name.starts_with(|char: char| char.is_ascii_digit()))
+				.map(|name| format!("`{name}`"))
+				.collect();
+			known.sort();
+
+			let message = if known.is_empty() {
+				format!("unrecognized identifier `{ident}`: no fields or let items are defined before this")
+			} else {
+				format!(
+					"unrecognized identifier `{ident}`: only fields and let items defined before this can be used, which are {}",
+					known.join(", "),
+				)
+			};
+
+			Err(Error::new(ident.span(), message))
 		}
 	}
 
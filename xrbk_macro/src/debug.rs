@@ -0,0 +1,298 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Writes the expansion of [`define!`] to a file for debugging.
+//!
+//! This is enabled by setting the `XRBK_DEBUG_EXPANSION` environment variable
+//! when compiling, or by enabling the `debug-expansion` feature. Token streams
+//! can't contain comments, so the expansion is written to a file instead,
+//! with a comment describing the layout of each definition's elements: its
+//! name, size, and offset, where they are known at expansion time.
+//!
+//! Each file is named after the first definition in the `define!` invocation
+//! and a CRC-32 of its expansion, which is also written at the start of the
+//! file, so that expansions can be told apart between compilations.
+//!
+//! The files are written to the directory given by `XRBK_DEBUG_EXPANSION`, if
+//! any, otherwise to `OUT_DIR`, otherwise to `xrbk-expansion` in the
+//! temporary directory.
+//!
+//! [`define!`]: crate::define
+
+use std::{
+	env,
+	fmt::Write as _,
+	fs,
+	io::Write as _,
+	path::PathBuf,
+	process::{Command, Stdio},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Expr, ExprLit, Ident, Lit, Type};
+
+use crate::*;
+
+/// The environment variable which enables debug mode.
+const ENV_VAR: &str = "XRBK_DEBUG_EXPANSION";
+
+/// Returns the directory to write expansions to, if debug mode is enabled.
+fn target_dir() -> Option<PathBuf> {
+	let var = env::var_os(ENV_VAR);
+
+	if var.is_none() && !cfg!(feature = "debug-expansion") {
+		return None;
+	}
+
+	// `XRBK_DEBUG_EXPANSION=1` only enables debug mode; any other value is
+	// the directory to write to.
+	match var {
+		Some(dir) if !dir.is_empty() && dir != "1" => Some(dir.into()),
+
+		_ => Some(
+			env::var_os("OUT_DIR")
+				.map_or_else(|| env::temp_dir().join("xrbk-expansion"), PathBuf::from),
+		),
+	}
+}
+
+/// Writes the `expanded` tokens of the given `definitions`, and their
+/// layout, to a file if debug mode is enabled.
+///
+/// Failing to write the file doesn't fail the expansion; a warning is printed
+/// instead.
+pub(crate) fn write_expansion(definitions: &Definitions, expanded: &TokenStream2) {
+	let Some(dir) = target_dir() else {
+		return;
+	};
+
+	let source = expanded.to_string();
+	let trace_id = crc32(source.as_bytes());
+
+	let name = definitions.0.first().map_or_else(
+		|| "empty".to_owned(),
+		|definition| definition.name().to_string(),
+	);
+
+	let mut contents = format!("// trace id: {trace_id:08x}\n\n");
+
+	for definition in &definitions.0 {
+		definition.layout(&mut contents);
+	}
+
+	// The expansion is formatted if `rustfmt` is available.
+	contents.push('\n');
+	contents.push_str(&rustfmt(&source).unwrap_or(source));
+
+	let path = dir.join(format!("{name}.{trace_id:08x}.rs"));
+
+	if let Err(error) = fs::create_dir_all(&dir).and_then(|()| fs::write(&path, contents)) {
+		eprintln!(
+			"warning: failed to write the expansion of `{name}` to {}: {error}",
+			path.display()
+		);
+	}
+}
+
+impl Definition {
+	/// The name of the definition.
+	fn name(&self) -> &Ident {
+		match self {
+			Self::Enum(r#enum) => &r#enum.ident,
+			Self::Struct(r#struct) => r#struct.metadata.name(),
+		}
+	}
+
+	/// Writes a comment describing the layout of this definition's elements
+	/// to `out`.
+	fn layout(&self, out: &mut String) {
+		match self {
+			Self::Struct(r#struct) => {
+				// The number of bytes written before the items.
+				let (kind, header) = match &r#struct.metadata {
+					StructMetadata::Struct(_) => ("struct", Some(0)),
+					StructMetadata::Request(_) => ("request", Some(4)),
+
+					StructMetadata::Event(event) if event.is_generic() => {
+						("generic event", Some(10))
+					}
+					StructMetadata::Event(_) => ("event", Some(4)),
+
+					StructMetadata::Reply(_) => ("reply", Some(8)),
+					// The size of a message's header depends on its type.
+					StructMetadata::Message(_) => ("message", None),
+				};
+
+				let _ = writeln!(out, "// {kind} `{}`:", self.name());
+				items_layout(&r#struct.items, header, out);
+			}
+
+			Self::Enum(r#enum) => {
+				let _ = writeln!(out, "// enum `{}`:", r#enum.ident);
+
+				// Variants' items follow their discriminant, unless it is
+				// written by the definition containing a `match` enum.
+				let header = if r#enum.is_match() {
+					0
+				} else {
+					r#enum.discriminant_width()
+				};
+
+				for variant in &r#enum.variants {
+					let _ = writeln!(out, "//   variant `{}`:", variant.ident);
+					items_layout(&variant.items, Some(header), out);
+				}
+			}
+		}
+
+		out.push_str("//\n");
+	}
+}
+
+/// Writes one comment line for each of the given `items` to `out`.
+///
+/// Offsets are counted from `header`, if it is known, until the first item of
+/// unknown size.
+fn items_layout(items: &Items, header: Option<usize>, out: &mut String) {
+	let mut offset = header;
+
+	for (id, item) in items.pairs() {
+		let (name, size) = match item {
+			Item::Field(field) => (
+				match id {
+					ItemId::Field(FieldId::Ident(ident)) => format!("field `{ident}`"),
+					ItemId::Field(FieldId::Id(index)) => format!("field {index}"),
+					_ => "field".to_owned(),
+				},
+				static_size(&field.r#type),
+			),
+
+			Item::Let(r#let) => (format!("let `{}`", r#let.ident), static_size(&r#let.r#type)),
+
+			Item::Unused(Unused::Unit { .. }) => ("unused".to_owned(), Some(1)),
+			Item::Unused(Unused::Array(array)) => (
+				"unused".to_owned(),
+				match &array.len {
+					ArrayLen::Source(Source {
+						args: None,
+						expr: Expr::Lit(ExprLit {
+							lit: Lit::Int(len), ..
+						}),
+						..
+					}) => len.base10_parse().ok(),
+
+					_ => None,
+				},
+			),
+		};
+
+		let size_str = size.map_or_else(|| "? bytes".to_owned(), |size| format!("{size} bytes"));
+
+		if item.is_metabyte() {
+			let _ = writeln!(out, "//     {name}: {size_str}, in the metabyte position");
+			continue;
+		}
+
+		let offset_str = offset.map_or_else(|| "?".to_owned(), |offset| offset.to_string());
+		let _ = writeln!(out, "//     {name}: {size_str} at offset {offset_str}");
+
+		offset = offset.zip(size).map(|(offset, size)| offset + size);
+	}
+}
+
+/// Returns the number of bytes the given type is written as, if it is known
+/// at expansion time.
+fn static_size(r#type: &Type) -> Option<usize> {
+	match r#type {
+		Type::Array(array) => match &array.len {
+			Expr::Lit(ExprLit {
+				lit: Lit::Int(len), ..
+			}) => Some(static_size(&array.elem)? * len.base10_parse::<usize>().ok()?),
+
+			_ => None,
+		},
+
+		r#type => primitive_size(r#type),
+	}
+}
+
+/// Calculates the CRC-32 checksum of the given `bytes`.
+fn crc32(bytes: &[u8]) -> u32 {
+	let mut crc = !0u32;
+
+	for byte in bytes {
+		crc ^= u32::from(*byte);
+
+		for _ in 0..8 {
+			crc = if crc & 1 == 1 {
+				(crc >> 1) ^ 0xedb8_8320
+			} else {
+				crc >> 1
+			};
+		}
+	}
+
+	!crc
+}
+
+/// Formats the given `source` with `rustfmt`, if it is available.
+fn rustfmt(source: &str) -> Option<String> {
+	let mut rustfmt = Command::new("rustfmt")
+		.args(["--edition", "2021"])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()
+		.ok()?;
+
+	rustfmt.stdin.take()?.write_all(source.as_bytes()).ok()?;
+
+	let output = rustfmt.wait_with_output().ok()?;
+
+	if output.status.success() {
+		String::from_utf8(output.stdout).ok()
+	} else {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn crc32_matches_the_check_value() {
+		assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+	}
+
+	#[test]
+	fn layout_lists_elements_with_their_offsets() {
+		let definitions: Definitions = syn::parse_quote! {
+			pub struct Example: Request<1> {
+				#[metabyte]
+				pub flag: bool,
+				pub window: u32,
+				[(); 2],
+				pub name: String,
+				pub after: u16,
+			}
+		};
+
+		let mut out = String::new();
+		definitions.0[0].layout(&mut out);
+
+		assert_eq!(
+			out,
+			"\
+// request `Example`:
+//     field `flag`: 1 bytes, in the metabyte position
+//     field `window`: 4 bytes at offset 4
+//     unused: 2 bytes at offset 8
+//     field `name`: ? bytes at offset 10
+//     field `after`: 2 bytes at offset ?
+//
+"
+		);
+	}
+}
@@ -5,14 +5,17 @@
 use syn::{
 	braced, parenthesized,
 	parse::{Parse, ParseStream},
-	token, Attribute, Error, Expr, Generics, Ident, Result, Token, Type, Visibility,
+	parse_quote,
+	spanned::Spanned,
+	token, Attribute, Error, Expr, ExprBlock, Generics, Ident, Lit, LitStr, Meta, NestedMeta, Path,
+	Result, Stmt, Token, Type, Visibility,
 };
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::ToTokens;
+use quote::{format_ident, ToTokens};
 use syn::punctuated::Punctuated;
 
-use crate::Items;
+use crate::{Discriminant, Item, Items};
 
 /// A list of [`Definition`]s.
 pub struct Definitions(pub Vec<Definition>);
@@ -48,6 +51,16 @@ pub struct Struct {
 
 	/// A semicolon token if `items` is [`Items::Unit`] or [`Items::Unnamed`].
 	pub semicolon_token: Option<Token![;]>,
+
+	/// Whether round-trip tests are generated for the definition, as
+	/// specified with `#[derive_roundtrip_tests]`.
+	pub roundtrip_tests: bool,
+	/// The note given for a legacy message, if the definition is marked with
+	/// `#[deprecated_protocol]`.
+	pub deprecation: Option<LitStr>,
+	/// The path that XRB is referred to by, if it is given with
+	/// `#[xrbk(crate = "...")]`.
+	pub crate_path: Option<Path>,
 }
 
 /// The type of definition and metadata associated with it.
@@ -56,13 +69,17 @@ pub enum StructMetadata {
 	Struct(BasicStructMetadata),
 
 	/// An event message struct.
-	Event(Event),
+	Event(Box<Event>),
 
 	/// A request message struct.
 	Request(Box<Request>),
 
 	/// A reply message struct.
-	Reply(Reply),
+	Reply(Box<Reply>),
+
+	/// A message struct with a custom header, for wire formats other than the
+	/// core X protocol.
+	Message(Box<Message>),
 }
 
 /// The definition of an enum.
@@ -78,11 +95,27 @@ pub struct Enum {
 	pub ident: Ident,
 	/// Generics (lifetimes and/or generic types) associated with the enum.
 	pub generics: Generics,
+	/// The type the enum's discriminant is written as, if it is not `u8`:
+	/// `: u16` or `: u32`.
+	pub discriminant_type: Option<(Token![:], Ident)>,
+	/// The `match` token before the discriminant type, if the discriminant
+	/// is not written with the enum, but found from an earlier item of the
+	/// definition which contains it: `: match u8`.
+	pub match_token: Option<Token![match]>,
 
 	/// A pair of curly brackets (`{` and `}`) surrounding the enum variants.
 	pub brace_token: token::Brace,
 	/// The enum variants defined within the enum.
 	pub variants: Punctuated<Variant, Token![,]>,
+
+	/// Whether round-trip tests are generated for the enum, as specified with
+	/// `#[derive_roundtrip_tests]`.
+	pub roundtrip_tests: bool,
+	/// The note given if the enum is marked with `#[deprecated_protocol]`.
+	pub deprecation: Option<LitStr>,
+	/// The path that XRB is referred to by, if it is given with
+	/// `#[xrbk(crate = "...")]`.
+	pub crate_path: Option<Path>,
 }
 
 /// The definition of an enum variant.
@@ -140,6 +173,87 @@ pub struct Event {
 	pub event_code_expr: Expr,
 	/// A right arrow bracket token: `>`.
 	pub gt_token: Token![>],
+
+	/// An optional plus token followed by [`Generic`] metadata, denoting that
+	/// this is an X Generic Event.
+	pub generic: Option<(Token![+], Generic)>,
+}
+
+/// Metadata for an X Generic Event (XGE).
+///
+/// Generic events always have the event code `35`, but, unlike ordinary
+/// events, they are not limited to 32 bytes: they contain the major opcode of
+/// the extension that generated them, a 16-bit event type unique within that
+/// extension, and a 32-bit additional length field.
+///
+/// ```ignore
+/// pub struct MyEvent: Event<35> + generic(EXTENSION_OPCODE, 7) {
+///     // ...
+/// }
+/// ```
+pub struct Generic {
+	/// Specifies that this is a generic event: `generic`.
+	pub generic_ident: Ident,
+
+	/// An expression that evaluates to the major opcode of the extension that
+	/// generates this event.
+	pub extension_expr: Expr,
+	/// An expression that evaluates to the event type associated with this
+	/// event within its extension.
+	pub evtype_expr: Expr,
+}
+
+impl StructMetadata {
+	/// The name of the struct.
+	pub const fn name(&self) -> &Ident {
+		match self {
+			Self::Struct(meta) => &meta.name,
+			Self::Event(meta) => &meta.name,
+			Self::Request(meta) => &meta.name,
+			Self::Reply(meta) => &meta.name,
+			Self::Message(meta) => &meta.name,
+		}
+	}
+
+	/// The visibility of the struct.
+	pub const fn vis(&self) -> &Visibility {
+		match self {
+			Self::Struct(meta) => &meta.vis,
+			Self::Event(meta) => &meta.vis,
+			Self::Request(meta) => &meta.vis,
+			Self::Reply(meta) => &meta.vis,
+			Self::Message(meta) => &meta.vis,
+		}
+	}
+
+	/// The generics associated with the struct.
+	pub const fn generics(&self) -> &Generics {
+		match self {
+			Self::Struct(meta) => &meta.generics,
+			Self::Event(meta) => &meta.generics,
+			Self::Request(meta) => &meta.generics,
+			Self::Reply(meta) => &meta.generics,
+			Self::Message(meta) => &meta.generics,
+		}
+	}
+
+	/// Whether this struct has a `_sequence_` field: that is, whether it is
+	/// an [`Event`] or a [`Reply`] which has not opted out of it.
+	pub const fn has_sequence(&self) -> bool {
+		match self {
+			Self::Reply(reply) => reply.sequence_token.is_none(),
+			Self::Event(_) => true,
+
+			Self::Struct(_) | Self::Request(_) | Self::Message(_) => false,
+		}
+	}
+}
+
+impl Event {
+	/// Whether this is an X Generic Event.
+	pub const fn is_generic(&self) -> bool {
+		self.generic.is_some()
+	}
 }
 
 /// Metadata for a request struct.
@@ -208,6 +322,55 @@ pub struct Reply {
 	pub request_ty: Type,
 }
 
+/// Metadata for a message struct with a custom header.
+///
+/// This allows messages of other wire formats in the X ecosystem, such as
+/// XDMCP, to be defined with the same items as X protocol messages. The
+/// header type must implement `xrb::MessageHeader`: it is written before the
+/// message's items, created from the message's code and the length of its
+/// items.
+///
+/// ```ignore
+/// pub struct Query: Message<XdmcpHeader, 2> {
+///     // ...
+/// }
+/// ```
+pub struct Message {
+	/// Attributes associated with the message's struct, including doc
+	/// comments.
+	pub attributes: Vec<Attribute>,
+
+	/// The visibility of the message's struct.
+	pub vis: Visibility,
+	/// The struct token: `struct`.
+	pub struct_token: Token![struct],
+	/// The name of the message.
+	pub name: Ident,
+	/// Generics (lifetimes and/or generic types) associated with the message's
+	/// struct.
+	pub generics: Generics,
+
+	/// A colon token: `:`.
+	#[allow(dead_code, reason = "punctuation is parsed, but never reported on")]
+	pub colon_token: Token![:],
+	/// Specifies that this is a message with a custom header: `Message`.
+	pub message_ident: Ident,
+
+	/// A left arrow bracket token: `<`.
+	#[allow(dead_code, reason = "punctuation is parsed, but never reported on")]
+	pub lt_token: Token![<],
+	/// The type of header written before the message.
+	pub header_ty: Type,
+	/// A comma token: `,`.
+	#[allow(dead_code, reason = "punctuation is parsed, but never reported on")]
+	pub comma_token: Token![,],
+	/// An expression that evaluates to the code associated with the message.
+	pub code_expr: Expr,
+	/// A right arrow bracket token: `>`.
+	#[allow(dead_code, reason = "punctuation is parsed, but never reported on")]
+	pub gt_token: Token![>],
+}
+
 // Expansion {{{
 
 impl ToTokens for Definitions {
@@ -229,8 +392,16 @@ impl ToTokens for Definition {
 
 impl ToTokens for Struct {
 	fn to_tokens(&self, tokens: &mut TokenStream2) {
+		let has_sequence = self.metadata.has_sequence();
+
 		self.metadata.to_tokens(tokens);
-		self.items.to_tokens(tokens);
+		self.items.declaration_to_tokens(tokens, has_sequence);
+
+		// A unit struct with a sequence field is expanded as a struct with
+		// named fields, so it must not be followed by a semicolon.
+		if !(has_sequence && matches!(self.items, Items::Unit)) {
+			self.semicolon_token.to_tokens(tokens);
+		}
 	}
 }
 
@@ -252,9 +423,7 @@ impl ToTokens for Enum {
 
 		// Surround the enum's variants with its curly brackets (`{` and `}`).
 		self.brace_token.surround(tokens, |tokens| {
-			for variant in &self.variants {
-				variant.to_tokens(tokens);
-			}
+			self.variants.to_tokens(tokens);
 		});
 	}
 }
@@ -288,6 +457,7 @@ impl ToTokens for StructMetadata {
 			Self::Event(meta) => meta.to_tokens(tokens),
 			Self::Request(meta) => meta.to_tokens(tokens),
 			Self::Reply(meta) => meta.to_tokens(tokens),
+			Self::Message(meta) => meta.to_tokens(tokens),
 		}
 	}
 }
@@ -339,11 +509,262 @@ struct_tokens!(for BasicStructMetadata);
 struct_tokens!(for Event);
 struct_tokens!(for Request);
 struct_tokens!(for Reply);
+struct_tokens!(for Message);
 
 // }}}
 
 // Parsing {{{
 
+/// Removes the `#[derive_roundtrip_tests]` attribute from the given
+/// `attributes`, returning whether it was present.
+///
+/// The round-trip tests are always generated behind `#[cfg(test)]`, but the
+/// attribute may also be written as `#[cfg_attr(test, derive_roundtrip_tests)]`
+/// to make that clear.
+fn take_roundtrip_tests(attributes: &mut Vec<Attribute>) -> bool {
+	let len = attributes.len();
+	attributes.retain(|attribute| !is_roundtrip_tests(attribute));
+
+	attributes.len() != len
+}
+
+/// Whether the given `attribute` is `#[derive_roundtrip_tests]` or
+/// `#[cfg_attr(test, derive_roundtrip_tests)]`.
+fn is_roundtrip_tests(attribute: &Attribute) -> bool {
+	const IDENT: &str = "derive_roundtrip_tests";
+
+	if attribute.path.is_ident(IDENT) {
+		return true;
+	}
+
+	if !attribute.path.is_ident("cfg_attr") {
+		return false;
+	}
+
+	match attribute.parse_meta() {
+		Ok(Meta::List(list)) => match (list.nested.first(), list.nested.iter().nth(1)) {
+			(Some(NestedMeta::Meta(Meta::Path(cfg))), Some(NestedMeta::Meta(Meta::Path(attr)))) => {
+				list.nested.len() == 2 && cfg.is_ident("test") && attr.is_ident(IDENT)
+			}
+
+			_ => false,
+		},
+
+		_ => false,
+	}
+}
+
+/// The note used for `#[deprecated_protocol]` if none is given.
+const DEFAULT_DEPRECATION_NOTE: &str =
+	"this message is only kept for compatibility with legacy clients and servers";
+
+/// Replaces the `#[deprecated_protocol]` attribute in the given `attributes`
+/// with a `#[deprecated]` attribute, returning its note if it was present.
+///
+/// The note may be given as `#[deprecated_protocol = "..."]` or
+/// `#[deprecated_protocol(note = "...")]`; otherwise, a default note is used.
+fn take_deprecated_protocol(attributes: &mut [Attribute]) -> Result<Option<LitStr>> {
+	const IDENT: &str = "deprecated_protocol";
+
+	let mut deprecation: Option<LitStr> = None;
+
+	for attribute in attributes {
+		if !attribute.path.is_ident(IDENT) {
+			continue;
+		}
+
+		if deprecation.is_some() {
+			return Err(Error::new_spanned(
+				attribute,
+				"`#[deprecated_protocol]` can only be used once",
+			));
+		}
+
+		let note = match attribute.parse_meta()? {
+			Meta::Path(path) => LitStr::new(DEFAULT_DEPRECATION_NOTE, path.span()),
+
+			Meta::NameValue(name_value) => match name_value.lit {
+				Lit::Str(note) => note,
+				lit => return Err(Error::new_spanned(lit, "expected a string literal note")),
+			},
+
+			Meta::List(list) => match list.nested.first() {
+				Some(NestedMeta::Meta(Meta::NameValue(name_value)))
+					if list.nested.len() == 1 && name_value.path.is_ident("note") =>
+				{
+					match &name_value.lit {
+						Lit::Str(note) => note.clone(),
+						lit => {
+							return Err(Error::new_spanned(lit, "expected a string literal note"))
+						}
+					}
+				}
+
+				_ => {
+					return Err(Error::new_spanned(
+						list,
+						"expected `#[deprecated_protocol(note = \"...\")]`",
+					))
+				}
+			},
+		};
+
+		*attribute = parse_quote!(#[deprecated(note = #note)]);
+		deprecation = Some(note);
+	}
+
+	Ok(deprecation)
+}
+
+/// Returns the path given with `#[xrbk(crate = "...")]` in the given
+/// `attributes`, if there is one.
+///
+/// The code generated for a definition refers to XRB as `xrb`, and to its
+/// dependencies as `bytes` and `cornflakes`. If a path is given, they are
+/// instead brought into scope from that path, so that definitions can be made
+/// where XRB is renamed or re-exported.
+pub fn crate_path(attributes: &[Attribute]) -> Result<Option<Path>> {
+	const IDENT: &str = "xrbk";
+	const EXPECTED: &str = "expected `#[xrbk(crate = \"...\")]`";
+
+	let mut crate_path: Option<Path> = None;
+
+	for attribute in attributes {
+		if !attribute.path.is_ident(IDENT) {
+			continue;
+		}
+
+		if crate_path.is_some() {
+			return Err(Error::new_spanned(
+				attribute,
+				"`#[xrbk(crate = \"...\")]` can only be used once",
+			));
+		}
+
+		let path = match attribute.parse_meta()? {
+			Meta::List(list) => match list.nested.first() {
+				Some(NestedMeta::Meta(Meta::NameValue(name_value)))
+					if list.nested.len() == 1 && name_value.path.is_ident("crate") =>
+				{
+					match &name_value.lit {
+						Lit::Str(path) => path.parse()?,
+						lit => {
+							return Err(Error::new_spanned(lit, "expected a string literal path"))
+						}
+					}
+				}
+
+				_ => return Err(Error::new_spanned(list, EXPECTED)),
+			},
+
+			meta => return Err(Error::new_spanned(meta, EXPECTED)),
+		};
+
+		crate_path = Some(path);
+	}
+
+	Ok(crate_path)
+}
+
+/// Removes the `#[xrbk(crate = "...")]` attribute from the given `attributes`,
+/// returning its path if it was present.
+///
+/// The attribute is kept if the definition derives one of `xrbk_macro`'s
+/// derive macros, as they read it too.
+fn take_crate_path(attributes: &mut Vec<Attribute>) -> Result<Option<Path>> {
+	const DERIVES: [&str; 4] = ["Readable", "Writable", "ByteSize", "StaticByteSize"];
+
+	let crate_path = crate_path(attributes)?;
+
+	let derived = attributes.iter().any(|attribute| {
+		if !attribute.path.is_ident("derive") {
+			return false;
+		}
+
+		match attribute.parse_meta() {
+			Ok(Meta::List(list)) => list.nested.iter().any(|nested| match nested {
+				NestedMeta::Meta(Meta::Path(path)) => path
+					.segments
+					.last()
+					.is_some_and(|segment| DERIVES.iter().any(|derive| segment.ident == derive)),
+
+				_ => false,
+			}),
+
+			_ => false,
+		}
+	});
+
+	let (mut kept, removed): (Vec<_>, Vec<_>) = attributes
+		.drain(..)
+		.partition(|attribute| !attribute.path.is_ident("xrbk"));
+
+	// Derive helper attributes must come after the derive.
+	if derived {
+		kept.extend(removed);
+	}
+
+	*attributes = kept;
+
+	Ok(crate_path)
+}
+
+/// Generates an error if round-trip tests are requested for a definition with
+/// the given `generics`, as they can only be generated for concrete types.
+fn ensure_not_generic(generics: &Generics) -> Result<()> {
+	if generics.params.is_empty() {
+		Ok(())
+	} else {
+		Err(Error::new_spanned(
+			generics,
+			"round-trip tests cannot be generated for generic definitions",
+		))
+	}
+}
+
+/// Generates an error if the next tokens are `(?sequence)`, with which a
+/// reply opts out of its `sequence` field, giving the `message` to explain why
+/// this definition can't.
+///
+/// Unnamed items (e.g. `Request<1>(pub u8)`) are not mistaken for it, as they
+/// don't start with `?`.
+fn reject_sequence_opt_out(input: ParseStream, message: &str) -> Result<()> {
+	fn opt_out(input: ParseStream) -> Result<Option<token::Paren>> {
+		let content;
+		let paren_token = parenthesized!(content in input);
+
+		Ok(content.peek(Token![?]).then_some(paren_token))
+	}
+
+	match opt_out(&input.fork()) {
+		Ok(Some(paren_token)) => Err(Error::new(paren_token.span, message)),
+		_ => Ok(()),
+	}
+}
+
+/// Parses an expression in the position of a generic argument, such as the
+/// major opcode in `Request<1>`.
+///
+/// A full [`Expr`] can't be parsed here, because the closing `>` would be
+/// parsed as a comparison. Like const generic arguments, this is limited to
+/// literals, paths, and blocks (e.g. `Request<{ BASE + 1 }>`).
+fn parse_generic_expr(input: ParseStream) -> Result<Expr> {
+	if input.peek(Lit) {
+		Ok(Expr::Lit(input.parse()?))
+	} else if input.peek(token::Brace) {
+		let block: ExprBlock = input.parse()?;
+
+		// Unwrap blocks containing only a single expression, so that they
+		// don't trigger the `unused_braces` lint when expanded.
+		match &block.block.stmts[..] {
+			[Stmt::Expr(expr)] => Ok(expr.to_owned()),
+			_ => Ok(Expr::Block(block)),
+		}
+	} else {
+		Ok(Expr::Path(input.parse()?))
+	}
+}
+
 impl Parse for Definitions {
 	fn parse(input: ParseStream) -> Result<Self> {
 		let mut definitions = vec![];
@@ -392,9 +813,36 @@ impl Parse for Struct {
 }
 
 impl Struct {
-	fn parse_with(input: ParseStream, attributes: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+	fn parse_with(
+		input: ParseStream,
+		mut attributes: Vec<Attribute>,
+		vis: Visibility,
+	) -> Result<Self> {
+		let roundtrip_tests = take_roundtrip_tests(&mut attributes);
+		let deprecation = take_deprecated_protocol(&mut attributes)?;
+		let crate_path = take_crate_path(&mut attributes)?;
+
 		// Parse the struct's metadata.
 		let metadata = StructMetadata::parse_with(input, attributes, vis)?;
+
+		if roundtrip_tests {
+			ensure_not_generic(metadata.generics())?;
+		}
+
+		// Only replies can opt out of their `sequence` field.
+		match &metadata {
+			StructMetadata::Request(_) => reject_sequence_opt_out(
+				input,
+				"requests have no `sequence` field to opt out of: `(?sequence)` can only be used on replies",
+			)?,
+			StructMetadata::Event(_) => reject_sequence_opt_out(
+				input,
+				"events always have a `sequence` field: `(?sequence)` can only be used on replies",
+			)?,
+
+			_ => {}
+		}
+
 		// Parse the struct's items.
 		let items: Items = input.parse()?;
 
@@ -417,19 +865,145 @@ impl Struct {
 			Items::Named { .. } => None,
 		};
 
+		// Generic events use the metabyte position for the major opcode of
+		// their extension, so a metabyte item cannot be used.
+		let generic = match &metadata {
+			StructMetadata::Event(event) => event.generic.as_ref(),
+			_ => None,
+		};
+
+		if let Some((_, generic)) = generic {
+			if items.iter().any(Item::is_metabyte) {
+				return Err(Error::new(
+					generic.generic_ident.span(),
+					"generic events cannot have a metabyte item: the metabyte position is used for the extension's major opcode",
+				));
+			}
+		}
+
+		// Messages with custom headers are not written with the X protocol's
+		// headers, so there is no metabyte position.
+		if let StructMetadata::Message(message) = &metadata {
+			if items.iter().any(Item::is_metabyte) {
+				return Err(Error::new(
+					message.message_ident.span(),
+					"messages with custom headers cannot have a metabyte item: there is no metabyte position in their header",
+				));
+			}
+		}
+
 		Ok(Self {
 			metadata,
 			items,
 			semicolon_token,
+			roundtrip_tests,
+			deprecation,
+			crate_path,
 		})
 	}
 }
 
 impl Enum {
-	fn parse_with(input: ParseStream, attributes: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+	/// The type the enum's discriminant is written as: `u8`, unless another
+	/// type is specified.
+	pub fn discriminant_type(&self) -> Ident {
+		match &self.discriminant_type {
+			Some((_, ident)) => ident.clone(),
+			None => format_ident!("u8"),
+		}
+	}
+
+	/// Whether the enum's discriminant is not written with it, as specified
+	/// with `: match`.
+	pub const fn is_match(&self) -> bool {
+		self.match_token.is_some()
+	}
+
+	/// Whether any of the enum's variants have items.
+	pub fn has_items(&self) -> bool {
+		self.variants
+			.iter()
+			.any(|variant| variant.items.pairs().next().is_some())
+	}
+
+	/// The number of bytes the enum's discriminant is written as.
+	pub fn discriminant_width(&self) -> usize {
+		match &self.discriminant_type {
+			Some((_, ident)) if ident == "u32" => 4,
+			Some((_, ident)) if ident == "u16" => 2,
+
+			_ => 1,
+		}
+	}
+
+	/// The discriminant that each variant explicitly specifies, if any.
+	pub fn discriminants(&self) -> impl Iterator<Item = &Option<(Token![=], Expr)>> {
+		self.variants.iter().map(|variant| &variant.discriminant)
+	}
+
+	/// Returns an error if any variant's discriminant is known at expansion
+	/// time not to fit in the [discriminant type].
+	///
+	/// [discriminant type]: Self::discriminant_type
+	fn check_discriminants(&self) -> Result<()> {
+		let r#type = self.discriminant_type();
+		let max: u128 = match self.discriminant_width() {
+			4 => u128::from(u32::MAX),
+			2 => u128::from(u16::MAX),
+			_ => u128::from(u8::MAX),
+		};
+
+		let discrims = Discriminant::for_variants(self.discriminants());
+
+		for (variant, discrim) in self.variants.iter().zip(discrims) {
+			match discrim {
+				Discriminant::Literal(value) if value > max => {
+					// Suggest the narrowest discriminant type which fits, if
+					// any does.
+					let wider = if value <= u128::from(u16::MAX) {
+						Some("u16")
+					} else if value <= u128::from(u32::MAX) {
+						Some("u32")
+					} else {
+						None
+					};
+
+					let help = match wider {
+						Some(wider) => format!(
+							"; a wider discriminant type can be given, as in `enum {}: {wider}`",
+							self.ident
+						),
+						None => String::new(),
+					};
+
+					return Err(Error::new(
+						variant.ident.span(),
+						format!(
+							"the discriminant of `{}` is {value}, which does not fit in a `{type}`{help}",
+							variant.ident
+						),
+					));
+				}
+
+				_ => {}
+			}
+		}
+
+		Ok(())
+	}
+
+	fn parse_with(
+		input: ParseStream,
+		mut attributes: Vec<Attribute>,
+		vis: Visibility,
+	) -> Result<Self> {
+		let roundtrip_tests = take_roundtrip_tests(&mut attributes);
+		let deprecation = take_deprecated_protocol(&mut attributes)?;
+		let crate_path = take_crate_path(&mut attributes)?;
 		let content;
+		let mut match_token = None;
 
-		Ok(Self {
+		let r#enum = Self {
 			attributes,
 			vis,
 
@@ -439,6 +1013,26 @@ impl Enum {
 			ident: input.parse()?,
 			// Generics associated with the enum.
 			generics: input.parse()?,
+			// If the next token is a colon, parse the discriminant type.
+			discriminant_type: if input.peek(Token![:]) {
+				let colon_token = input.parse()?;
+				match_token = input.parse()?;
+				let ident: Ident = input.parse()?;
+
+				// Discriminants are only written as unsigned integers of the
+				// widths used by the X protocol.
+				if !(ident == "u8" || ident == "u16" || ident == "u32") {
+					return Err(Error::new(
+						ident.span(),
+						"expected `u8`, `u16`, or `u32` as the discriminant type",
+					));
+				}
+
+				Some((colon_token, ident))
+			} else {
+				None
+			},
+			match_token,
 
 			// A pair of curly brackets (`{` and `}`) surrounding the enum's
 			// variants.
@@ -467,7 +1061,27 @@ impl Enum {
 
 				variants
 			},
-		})
+
+			roundtrip_tests,
+			deprecation,
+			crate_path,
+		};
+
+		if roundtrip_tests {
+			ensure_not_generic(&r#enum.generics)?;
+
+			if let Some(match_token) = &r#enum.match_token {
+				return Err(Error::new(
+					match_token.span,
+					"round-trip tests cannot be generated for `match` enums, which can only be \
+					 read with their discriminant",
+				));
+			}
+		}
+
+		r#enum.check_discriminants()?;
+
+		Ok(r#enum)
 	}
 }
 
@@ -497,6 +1111,41 @@ impl Parse for Variant {
 	}
 }
 
+impl Parse for Generic {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let content;
+
+		let generic_ident: Ident = input.parse()?;
+
+		// If the identifier following the plus token is not `generic`, return
+		// an error.
+		if generic_ident != "generic" {
+			return Err(Error::new(
+				generic_ident.span(),
+				"expected `generic` after `+` to declare a generic event",
+			));
+		}
+
+		// `(` and `)`.
+		parenthesized!(content in input);
+
+		Ok(Self {
+			// `generic`.
+			generic_ident,
+
+			// An expression that evaluates to the extension's major opcode.
+			extension_expr: content.parse()?,
+			// An expression that evaluates to the event type.
+			evtype_expr: {
+				// `,`.
+				content.parse::<Token![,]>()?;
+
+				content.parse()?
+			},
+		})
+	}
+}
+
 impl Parse for StructMetadata {
 	fn parse(input: ParseStream) -> Result<Self> {
 		Self::parse_with(input, input.call(Attribute::parse_outer)?, input.parse()?)
@@ -536,7 +1185,7 @@ impl StructMetadata {
 
 			match message_ty_ident.to_string().as_str() {
 				// "Event" => parse event metadata
-				"Event" => Ok(Self::Event(Event {
+				"Event" => Ok(Self::Event(Box::new(Event {
 					// Attributes.
 					attributes,
 					// Visibility.
@@ -558,11 +1207,27 @@ impl StructMetadata {
 					lt_token: input.parse()?,
 
 					// An expression that evaluates to the event's code.
-					event_code_expr: input.parse()?,
+					event_code_expr: parse_generic_expr(input)?,
 
 					// `>`.
 					gt_token: input.parse()?,
-				})),
+
+					// Optional: `+` followed by `generic(extension, evtype)`
+					// to indicate that this is an X Generic Event.
+					generic: {
+						// If the next token can be successfully parsed as a
+						// plus...
+						if let Ok(plus) = input.parse::<Token![+]>() {
+							// Then evaluate `generic` to that plus and the
+							// generic event metadata.
+							Some((plus, input.parse()?))
+						} else {
+							// Otherwise, if there is no plus, this is not a
+							// generic event.
+							None
+						}
+					},
+				}))),
 
 				// "Request" => parse request metadata
 				"Request" => Ok(Self::Request(Box::new(Request {
@@ -588,7 +1253,7 @@ impl StructMetadata {
 
 					// An expression that evaluates to the request's major
 					// opcode.
-					major_opcode_expr: input.parse()?,
+					major_opcode_expr: parse_generic_expr(input)?,
 					// An optional expression (preceded by a comma) that
 					// evaluates to the request's minor opcode.
 					minor_opcode: {
@@ -597,7 +1262,7 @@ impl StructMetadata {
 						if let Ok(comma) = input.parse::<Token![,]>() {
 							// Then evaluate `minor_opcode` to that comma and
 							// a minor opcode expression.
-							Some((comma, input.parse()?))
+							Some((comma, parse_generic_expr(input)?))
 						} else {
 							// Otherwise, if there is no comma, there is no
 							// minor opcode.
@@ -671,7 +1336,7 @@ impl StructMetadata {
 						}
 					};
 
-					Self::Reply(Reply {
+					Self::Reply(Box::new(Reply {
 						// Attributes.
 						attributes,
 
@@ -701,15 +1366,48 @@ impl StructMetadata {
 						for_token: input.parse()?,
 						// The type of the request.
 						request_ty: input.parse()?,
-					})
+					}))
 				}),
 
+				// "Message" => parse custom header message metadata
+				"Message" => Ok(Self::Message(Box::new(Message {
+					// Attributes.
+					attributes,
+					// Visibility.
+					vis,
+					// `struct`.
+					struct_token,
+
+					// The name of the message.
+					name,
+					// Generics associated with the message struct.
+					generics,
+
+					// `:`.
+					colon_token,
+					// `Message`.
+					message_ident: message_ty_ident,
+
+					// `<`.
+					lt_token: input.parse()?,
+
+					// The type of the message's header.
+					header_ty: input.parse()?,
+					// `,`.
+					comma_token: input.parse()?,
+					// An expression that evaluates to the message's code.
+					code_expr: parse_generic_expr(input)?,
+
+					// `>`.
+					gt_token: input.parse()?,
+				}))),
+
 				// Otherwise, if the identifier following the colon is not
-				// `Event`, `Request`, nor `Reply`, then we generate an
-				// error over the identifier.
+				// `Event`, `Request`, `Reply`, nor `Message`, then we
+				// generate an error over the identifier.
 				_ => Err(Error::new(
 					message_ty_ident.span(),
-					"expected a message type of `Event`, `Request`, or `Reply`",
+					"expected a message type of `Event`, `Request`, `Reply`, or `Message`",
 				)),
 			}
 		}
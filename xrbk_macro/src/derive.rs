@@ -0,0 +1,430 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+	ext::IdentExt, parse_quote, spanned::Spanned, Data, DataEnum, DeriveInput, Error, Fields,
+	GenericParam, Generics, Ident, Path,
+};
+
+use crate::*;
+
+/// Returns the given `generics` with the given `bound` added to each of their
+/// type parameters.
+fn bounded(generics: &Generics, bound: &Path) -> Generics {
+	let mut generics = generics.clone();
+
+	for param in &mut generics.params {
+		if let GenericParam::Type(param) = param {
+			param.bounds.push(parse_quote!(#bound));
+		}
+	}
+
+	generics
+}
+
+/// Returns the names that the given `fields` are bound to, and the tokens
+/// which bind them: `{ x: __x__, y: __y__ }` or `(__0__, __1__)`.
+///
+/// The same tokens construct the struct or variant from those names.
+fn bindings(fields: &Fields) -> (Vec<Ident>, TokenStream2) {
+	match fields {
+		Fields::Named(fields) => {
+			let (idents, names): (Vec<_>, Vec<_>) = fields
+				.named
+				.iter()
+				.map(|field| {
+					let ident = field.ident.as_ref().expect("named fields have names");
+
+					(ident, format_ident!("__{}__", ident.unraw()))
+				})
+				.unzip();
+
+			let tokens = quote!({ #(#idents: #names),* });
+
+			(names, tokens)
+		}
+
+		Fields::Unnamed(fields) => {
+			let names: Vec<_> = (0..fields.unnamed.len())
+				.map(|index| format_ident!("__{}__", index))
+				.collect();
+
+			let tokens = quote!((#(#names),*));
+
+			(names, tokens)
+		}
+
+		Fields::Unit => (Vec::new(), TokenStream2::new()),
+	}
+}
+
+/// Generates the tokens to write the given `fields`, which are bound to the
+/// given `names`.
+///
+/// Like the reads and sizes, each write is spanned by the field's type, so
+/// that a type which doesn't implement the trait is reported at that field.
+fn write_fields_tokens(fields: &Fields, names: &[Ident]) -> TokenStream2 {
+	let writes = fields
+		.iter()
+		.map(|field| quote_spanned!(field.ty.span()=> cornflakes::Writable::write_to));
+
+	// cornflakes::Writable::write_to(__x__, writer)?;
+	quote!(#(#writes(#names, writer)?;)*)
+}
+
+/// Generates the tokens to read the given `fields`, binding them to the given
+/// `names`.
+fn read_fields_tokens(fields: &Fields, names: &[Ident]) -> TokenStream2 {
+	TokenStream2::with_tokens(|tokens| {
+		for (field, name) in fields.iter().zip(names) {
+			let r#type = &field.ty;
+			let check = primitive_size(r#type).map(ensure_remaining_tokens);
+			let read = quote_spanned!(r#type.span()=> <#r#type as cornflakes::Readable>::read_from);
+
+			tokens.append_tokens(|| {
+				quote!(
+					#check
+					// let __x__ = <i16 as Readable>::read_from(reader)?;
+					let #name = #read(reader)?;
+				)
+			});
+		}
+	})
+}
+
+/// Generates the tokens which sum the sizes of the given `fields`, which are
+/// bound to the given `names`.
+fn size_fields_tokens(fields: &Fields, names: &[Ident]) -> TokenStream2 {
+	let sizes = fields
+		.iter()
+		.map(|field| quote_spanned!(field.ty.span()=> cornflakes::ByteSize::byte_size));
+
+	// ByteSize::byte_size(__x__) + ByteSize::byte_size(__y__)
+	quote!(#(#sizes(#names))+*)
+}
+
+/// Returns whether none of the given enum's variants have fields.
+fn is_fieldless(data: &DataEnum) -> bool {
+	data.variants
+		.iter()
+		.all(|variant| variant.fields.is_empty())
+}
+
+/// Wraps the derived `r#impl` for the given `input` so that it refers to XRB
+/// by the path given with `#[xrbk(crate = "...")]`, if there is one.
+fn with_crate_path(input: &DeriveInput, r#impl: TokenStream2) -> Result<TokenStream2, Error> {
+	let crate_path = crate_path(&input.attrs)?;
+
+	Ok(TokenStream2::with_tokens(|tokens| {
+		impls_tokens(tokens, false, crate_path.as_ref(), r#impl);
+	}))
+}
+
+/// Returns an error for the given `input` if it is a union, which can't be
+/// derived.
+fn reject_union(input: &DeriveInput, r#trait: &str) -> Result<(), Error> {
+	match &input.data {
+		Data::Union(data) => Err(Error::new_spanned(
+			data.union_token,
+			format!("`{trait}` cannot be derived for unions"),
+		)),
+
+		_ => Ok(()),
+	}
+}
+
+/// Derives `cornflakes::Writable` for the given struct or enum.
+pub fn writable(input: &DeriveInput) -> Result<TokenStream2, Error> {
+	reject_union(input, "Writable")?;
+
+	let name = &input.ident;
+	let generics = bounded(&input.generics, &parse_quote!(cornflakes::Writable));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	// Nothing is written for a unit struct or an empty enum.
+	let mut writer_name = quote!(writer);
+
+	let body = match &input.data {
+		Data::Struct(data) => {
+			let (names, pat) = bindings(&data.fields);
+			let writes = write_fields_tokens(&data.fields, &names);
+
+			if names.is_empty() {
+				writer_name = quote!(_writer);
+			}
+
+			quote!(
+				let Self #pat = self;
+				#writes
+			)
+		}
+
+		Data::Enum(data) => {
+			let discrims = Discriminant::for_variants(
+				data.variants.iter().map(|variant| &variant.discriminant),
+			);
+
+			let arms = data
+				.variants
+				.iter()
+				.zip(discrims)
+				.map(|(variant, discrim)| {
+					let name = &variant.ident;
+					let (names, pat) = bindings(&variant.fields);
+					let writes = write_fields_tokens(&variant.fields, &names);
+
+					quote!(
+						Self::#name #pat => {
+							// Write the variant's discriminant.
+							writer.put_u8(#discrim);
+
+							#writes
+						}
+					)
+				});
+
+			if data.variants.is_empty() {
+				writer_name = quote!(_writer);
+			}
+
+			quote!(
+				match self {
+					#(#arms)*
+				}
+			)
+		}
+
+		Data::Union(_) => unreachable!("unions are rejected"),
+	};
+
+	with_crate_path(
+		input,
+		quote!(
+			#[automatically_derived]
+			impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
+				fn write_to(
+					&self,
+					#writer_name: &mut impl bytes::BufMut,
+				) -> Result<(), cornflakes::WriteError> {
+					#body
+
+					Ok(())
+				}
+			}
+		),
+	)
+}
+
+/// Derives `cornflakes::Readable` for the given struct or enum.
+///
+/// Enums are read from a `u8` discriminant followed by the fields of the
+/// variant with that discriminant, as they are written by [`writable`].
+pub fn readable(input: &DeriveInput) -> Result<TokenStream2, Error> {
+	reject_union(input, "Readable")?;
+
+	let name = &input.ident;
+	let generics = bounded(&input.generics, &parse_quote!(cornflakes::Readable));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	// Nothing is read for a unit struct.
+	let mut reader_name = quote!(reader);
+
+	let body = match &input.data {
+		Data::Struct(data) => {
+			let (names, cons) = bindings(&data.fields);
+			let reads = read_fields_tokens(&data.fields, &names);
+
+			if names.is_empty() {
+				reader_name = quote!(_reader);
+			}
+
+			quote!(
+				#reads
+
+				Ok(Self #cons)
+			)
+		}
+
+		Data::Enum(data) => {
+			let discrims = Discriminant::for_variants(
+				data.variants.iter().map(|variant| &variant.discriminant),
+			);
+
+			let arms = data
+				.variants
+				.iter()
+				.zip(discrims)
+				.map(|(variant, discrim)| {
+					let name = &variant.ident;
+					let pat = discrim.pattern_tokens();
+					let (names, cons) = bindings(&variant.fields);
+					let reads = read_fields_tokens(&variant.fields, &names);
+
+					quote!(
+						#pat => {
+							#reads
+
+							Ok(Self::#name #cons)
+						}
+					)
+				});
+
+			// Check that there are enough bytes for the discriminant.
+			let check = ensure_remaining_tokens(1_usize);
+
+			quote!(
+				#check
+
+				match <u8 as cornflakes::Readable>::read_from(reader)? {
+					#(#arms)*
+
+					other_discrim => Err(
+						cornflakes::ReadError::UnrecognizedDiscriminant(other_discrim)
+					),
+				}
+			)
+		}
+
+		Data::Union(_) => unreachable!("unions are rejected"),
+	};
+
+	with_crate_path(
+		input,
+		quote!(
+			#[automatically_derived]
+			impl #impl_generics cornflakes::Readable for #name #ty_generics #where_clause {
+				fn read_from(
+					#reader_name: &mut impl bytes::Buf,
+				) -> Result<Self, cornflakes::ReadError> {
+					#body
+				}
+			}
+		),
+	)
+}
+
+/// Derives `cornflakes::ByteSize` for the given struct or enum.
+///
+/// The size of an enum includes its `u8` discriminant.
+pub fn byte_size(input: &DeriveInput) -> Result<TokenStream2, Error> {
+	reject_union(input, "ByteSize")?;
+
+	let name = &input.ident;
+	let generics = bounded(&input.generics, &parse_quote!(cornflakes::ByteSize));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	let body = match &input.data {
+		Data::Struct(data) => {
+			let (names, pat) = bindings(&data.fields);
+
+			if names.is_empty() {
+				quote!(0)
+			} else {
+				let sizes = size_fields_tokens(&data.fields, &names);
+
+				quote!(
+					let Self #pat = self;
+					#sizes
+				)
+			}
+		}
+
+		// Only the discriminant is written for a fieldless enum.
+		Data::Enum(data) if is_fieldless(data) => quote!(1),
+
+		Data::Enum(data) => {
+			let arms = data.variants.iter().map(|variant| {
+				let name = &variant.ident;
+				let (names, pat) = bindings(&variant.fields);
+
+				if names.is_empty() {
+					quote!(Self::#name #pat => 1,)
+				} else {
+					let sizes = size_fields_tokens(&variant.fields, &names);
+
+					quote!(Self::#name #pat => 1 + #sizes,)
+				}
+			});
+
+			quote!(
+				match self {
+					#(#arms)*
+				}
+			)
+		}
+
+		Data::Union(_) => unreachable!("unions are rejected"),
+	};
+
+	with_crate_path(
+		input,
+		quote!(
+			#[automatically_derived]
+			impl #impl_generics cornflakes::ByteSize for #name #ty_generics #where_clause {
+				fn byte_size(&self) -> usize {
+					#body
+				}
+			}
+		),
+	)
+}
+
+/// Derives `cornflakes::StaticByteSize` for the given struct or fieldless
+/// enum.
+///
+/// The size of a fieldless enum is that of its `u8` discriminant. Enums with
+/// fields are rejected, as their size depends on their variant.
+pub fn static_byte_size(input: &DeriveInput) -> Result<TokenStream2, Error> {
+	reject_union(input, "StaticByteSize")?;
+
+	let name = &input.ident;
+	let generics = bounded(&input.generics, &parse_quote!(cornflakes::StaticByteSize));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	let body = match &input.data {
+		Data::Struct(data) if data.fields.is_empty() => quote!(0),
+
+		Data::Struct(data) => {
+			let sizes = data.fields.iter().map(|field| {
+				let r#type = &field.ty;
+
+				quote_spanned!(r#type.span()=> <#r#type as cornflakes::StaticByteSize>::static_byte_size)
+			});
+
+			// <i16 as StaticByteSize>::static_byte_size() + ...
+			quote!(#(#sizes())+*)
+		}
+
+		Data::Enum(data) if is_fieldless(data) => quote!(1),
+
+		Data::Enum(data) => {
+			let variant = data
+				.variants
+				.iter()
+				.find(|variant| !variant.fields.is_empty())
+				.expect("the enum is not fieldless");
+
+			return Err(Error::new_spanned(
+				&variant.fields,
+				"`StaticByteSize` can only be derived for enums without fields",
+			));
+		}
+
+		Data::Union(_) => unreachable!("unions are rejected"),
+	};
+
+	with_crate_path(
+		input,
+		quote!(
+			#[automatically_derived]
+			impl #impl_generics cornflakes::StaticByteSize for #name #ty_generics #where_clause {
+				fn static_byte_size() -> usize {
+					#body
+				}
+			}
+		),
+	)
+}
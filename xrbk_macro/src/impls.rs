@@ -2,8 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+	ext::IdentExt, spanned::Spanned, Expr, ExprLit, GenericArgument, Generics, Ident, Lit, LitStr,
+	Path, PathArguments, Token, Type, Visibility,
+};
 
 use crate::{ts_ext::TsExt, *};
 
@@ -17,6 +21,18 @@ pub trait ItemDeserializeTokens {
 	fn deserialize_tokens(&self, tokens: &mut TokenStream2, id: &ItemId);
 }
 
+pub trait ItemSizeTokens {
+	/// Generates the statements to add the number of bytes that a given item
+	/// is serialized as to a `size` variable.
+	fn size_tokens(&self, tokens: &mut TokenStream2, id: &ItemId);
+}
+
+pub trait ItemTraceTokens {
+	/// Generates the tokens to serialize a given item to an `xrb::Trace`, then
+	/// record it in that trace.
+	fn trace_tokens(&self, tokens: &mut TokenStream2, id: &ItemId);
+}
+
 pub trait SerializeMessageTokens {
 	fn serialize_tokens(&self, tokens: &mut TokenStream2, items: &Items);
 }
@@ -25,6 +41,10 @@ pub trait DeserializeMessageTokens {
 	fn deserialize_tokens(&self, tokens: &mut TokenStream2, items: &Items);
 }
 
+pub trait TraceMessageTokens {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, items: &Items);
+}
+
 impl Definitions {
 	/// Expands the trait implementations for the given definition.
 	pub fn impl_tokens(&self, tokens: &mut TokenStream2) {
@@ -32,41 +52,268 @@ impl Definitions {
 
 		for definition in definitions {
 			match definition {
-				Definition::Enum(r#enum) => {
-					r#enum.serialize_tokens(tokens);
-					r#enum.deserialize_tokens(tokens);
-				}
+				Definition::Enum(r#enum) => r#enum.impl_tokens(tokens),
+				Definition::Struct(r#struct) => r#struct.impl_tokens(tokens),
+			}
+		}
+	}
+}
 
-				Definition::Struct(r#struct) => {
-					r#struct.serialize_tokens(tokens);
-					r#struct.deserialize_tokens(tokens);
+impl Enum {
+	/// Expands the trait implementations for this enum, and its round-trip
+	/// tests if it has them.
+	pub fn impl_tokens(&self, tokens: &mut TokenStream2) {
+		let impls = TokenStream2::with_tokens(|tokens| {
+			self.serialize_tokens(tokens);
+			self.deserialize_tokens(tokens);
 
-					match &r#struct.metadata {
-						StructMetadata::Request(request) => {
-							request.impl_request_tokens(tokens);
-						}
+			self.impl_wire_enum_tokens(tokens);
+		});
+		impls_tokens(
+			tokens,
+			self.deprecation.is_some(),
+			self.crate_path.as_ref(),
+			impls,
+		);
+
+		if self.roundtrip_tests {
+			roundtrip_tests_tokens(tokens, &self.ident, 0, self.crate_path.as_ref());
+		}
+	}
+}
 
-						StructMetadata::Reply(reply) => {
-							reply.impl_reply_tokens(tokens);
-						}
+impl Struct {
+	/// Expands the trait implementations for this struct, including those of
+	/// the message it defines, and its round-trip tests if it has them.
+	pub fn impl_tokens(&self, tokens: &mut TokenStream2) {
+		let deprecation = self.deprecation.as_ref();
+
+		let impls = TokenStream2::with_tokens(|tokens| {
+			self.serialize_tokens(tokens);
+			self.deserialize_tokens(tokens);
+			self.trace_tokens(tokens);
+			self.const_constructor_tokens(tokens);
+
+			match &self.metadata {
+				StructMetadata::Request(request) => {
+					request.impl_request_tokens(tokens, deprecation);
+					request.decode_body_tokens(tokens, &self.items);
+				}
 
-						StructMetadata::Event(event) => {
-							event.impl_event_tokens(tokens);
-						}
+				StructMetadata::Reply(reply) => {
+					reply.impl_reply_tokens(tokens, &self.items);
+					reply.decode_body_tokens(tokens, &self.items);
+				}
 
-						_ => {}
-					}
+				StructMetadata::Event(event) => {
+					event.impl_event_tokens(tokens, &self.items, deprecation);
+					event.decode_body_tokens(tokens, &self.items);
+				}
+
+				StructMetadata::Message(message) => {
+					message.impl_message_tokens(tokens);
 				}
+
+				_ => {}
 			}
+		});
+		impls_tokens(
+			tokens,
+			deprecation.is_some(),
+			self.crate_path.as_ref(),
+			impls,
+		);
+
+		if self.roundtrip_tests {
+			self.roundtrip_tests_tokens(tokens);
+		}
+	}
+}
+
+/// Appends the given `impls` for a definition, which is marked with
+/// `#[deprecated_protocol]` if `deprecated` is `true`.
+///
+/// The implementations of a deprecated definition necessarily use it, so
+/// they are wrapped in an anonymous constant to allow the `deprecated` lint
+/// for them alone. Likewise, if a `crate_path` is given with
+/// `#[xrbk(crate = "...")]`, they are wrapped in an anonymous constant which
+/// brings `xrb`, `bytes`, and `cornflakes` into scope from that path.
+pub(crate) fn impls_tokens(
+	tokens: &mut TokenStream2,
+	deprecated: bool,
+	crate_path: Option<&Path>,
+	impls: TokenStream2,
+) {
+	if !deprecated && crate_path.is_none() {
+		impls.to_tokens(tokens);
+
+		return;
+	}
+
+	let allow = deprecated.then(|| quote!(#[allow(deprecated)]));
+	let uses = crate_path.map(crate_uses_tokens);
+
+	tokens.append_tokens(|| {
+		quote!(
+			#allow
+			const _: () = {
+				#uses
+				#impls
+			};
+		)
+	});
+}
+
+/// Generates the `use` declarations which bring `xrb`, `bytes`, and
+/// `cornflakes` into scope from the given `crate_path`, rather than referring
+/// to the crates of those names.
+fn crate_uses_tokens(crate_path: &Path) -> TokenStream2 {
+	quote!(
+		// Not every definition uses all of them.
+		#[allow(unused_imports)]
+		use #crate_path as xrb;
+		#[allow(unused_imports)]
+		use #crate_path::{bytes, cornflakes};
+	)
+}
+
+/// Generates the tokens to return an `xrb::UnexpectedEndOfData` error from
+/// `read_from` if fewer than `len` bytes remain in the `reader`.
+///
+/// This is used before reading anything which would otherwise panic if there
+/// weren't enough bytes. `len` must be a literal or a variable, as it is
+/// evaluated more than once.
+pub(crate) fn ensure_remaining_tokens(len: impl ToTokens) -> TokenStream2 {
+	quote!(
+		if reader.remaining() < #len {
+			// NOTE: in `xrb`, `extern crate self as xrb;` will have to be
+			//       used so that the error path works.
+			return Err(cornflakes::ReadError::Other(Box::new(
+				xrb::UnexpectedEndOfData {
+					expected: #len,
+					found: reader.remaining(),
+				},
+			)));
 		}
+	)
+}
+
+/// Returns the number of bytes the given type is read as, if it is a
+/// primitive type.
+///
+/// Primitive types are read directly from the reader by `cornflakes`, and so
+/// must have their length checked with [`ensure_remaining_tokens`] first.
+/// Other types are either generated by `define!`, and so check their own
+/// length, or are made up of primitive types.
+pub(crate) fn primitive_size(r#type: &Type) -> Option<usize> {
+	let Type::Path(path) = r#type else {
+		return None;
+	};
+
+	match path.path.get_ident()?.to_string().as_str() {
+		"u8" | "i8" | "bool" => Some(1),
+		"u16" | "i16" => Some(2),
+		"u32" | "i32" | "f32" => Some(4),
+		"u64" | "i64" | "f64" => Some(8),
+
+		_ => None,
+	}
+}
+
+/// Returns the type of the elements of the given type, if it is a `Vec`.
+fn vec_element_type(r#type: &Type) -> Option<&Type> {
+	wrapped_type(r#type, "Vec")
+}
+
+/// Returns the type wrapped by the given type, if it is an `Option`.
+pub(crate) fn option_inner_type(r#type: &Type) -> Option<&Type> {
+	wrapped_type(r#type, "Option")
+}
+
+/// Returns the single type argument of the given type, if it is named
+/// `wrapper`.
+fn wrapped_type<'a>(r#type: &'a Type, wrapper: &str) -> Option<&'a Type> {
+	let Type::Path(path) = r#type else {
+		return None;
+	};
+	let segment = path.path.segments.last()?;
+
+	if segment.ident != wrapper {
+		return None;
+	}
+
+	match &segment.arguments {
+		PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+			GenericArgument::Type(r#type) => Some(r#type),
+
+			_ => None,
+		},
+
+		_ => None,
+	}
+}
+
+/// Returns whether the given type is `Cow<[u8]>`, which is read borrowed from
+/// a byte slice and owned from any other reader.
+pub(crate) fn is_cow_bytes(r#type: &Type) -> bool {
+	let Type::Path(path) = r#type else {
+		return false;
+	};
+	let Some(segment) = path.path.segments.last() else {
+		return false;
+	};
+
+	if segment.ident != "Cow" {
+		return false;
 	}
+
+	let PathArguments::AngleBracketed(args) = &segment.arguments else {
+		return false;
+	};
+
+	args.args.iter().any(|arg| {
+		matches!(
+			arg,
+			GenericArgument::Type(Type::Slice(slice))
+				if matches!(&*slice.elem, Type::Path(elem) if elem.path.is_ident("u8"))
+		)
+	})
 }
 
+// The trait functions called for each item are spanned by the item's type, so
+// that an error for a type which doesn't implement the trait points at the
+// item, rather than at the whole `define!` invocation. Only the paths are
+// spanned, so that `reader`, `writer`, and `size` still resolve to the
+// generated bindings.
+
 impl ItemSerializeTokens for Field {
 	// Tokens to serialize a field.
 	fn serialize_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
 		let name = id.formatted();
-		tokens.append_tokens(|| quote!(#name.write_to(writer)?;));
+
+		if is_cow_bytes(&self.r#type) {
+			// writer.put_slice(__my_bytes__);
+			tokens.append_tokens(|| quote!(writer.put_slice(#name);));
+
+			return;
+		}
+
+		// An optional field is only written if it is present.
+		if let Some(inner) = self.present_if().and(option_inner_type(&self.r#type)) {
+			let write = quote_spanned!(inner.span()=> cornflakes::Writable::write_to);
+
+			// if let Some(value) = __my_field__ {
+			//     cornflakes::Writable::write_to(value, writer)?;
+			// }
+			tokens.append_tokens(|| quote!(if let Some(value) = #name { #write(value, writer)?; }));
+
+			return;
+		}
+
+		let write = quote_spanned!(self.r#type.span()=> cornflakes::Writable::write_to);
+
+		// cornflakes::Writable::write_to(__my_field__, writer)?;
+		tokens.append_tokens(|| quote!(#write(#name, writer)?;));
 	}
 }
 
@@ -77,25 +324,121 @@ impl ItemDeserializeTokens for Field {
 		let r#type = &self.r#type;
 
 		tokens.append_tokens(|| {
+			// If this is an optional field, it is only read if its condition,
+			// found from earlier items, holds.
+			if let Some(condition) = self.present_if() {
+				let condition = condition.source().block_tokens();
+				let inner = option_inner_type(r#type)
+					.expect("`#[present_if(...)]` fields are checked to be `Option`s when parsed");
+
+				let check = primitive_size(inner).map(ensure_remaining_tokens);
+				let read = quote_spanned!(inner.span()=>
+					<#inner as cornflakes::Readable>::read_from
+				);
+
+				return quote!(
+					// let __my_field__ = if { ... } {
+					//     Some(<u32 as Readable>::read_from(reader)?)
+					// } else {
+					//     None
+					// };
+					let #name = if #condition {
+						#check
+						Some(#read(reader)?)
+					} else {
+						None
+					};
+				);
+			}
+
 			// If this is a contextual field, that context must be provided.
 			if let Some(context) = self.context() {
-				let args = context.source().fmt_args();
+				let context = context.source().block_tokens();
+
+				// If this is a `Cow<[u8]>`, the context is its number of
+				// bytes, which are borrowed if the reader is a byte slice.
+				if is_cow_bytes(r#type) {
+					let check = ensure_remaining_tokens(quote!(len));
+
+					return quote!(
+						let #name = {
+							let len: usize = #context;
+							#check
+
+							#[allow(unused_imports)]
+							use xrb::{ReadBorrowedBytes as _, ReadOwnedBytes as _};
+
+							xrb::CowBytes::new(reader, len).read_cow()
+						};
+					);
+				}
+
+				// If this is a `Vec`, the context is its number of elements, so
+				// the list can be allocated before its elements are read.
+				if let Some(element) = vec_element_type(r#type) {
+					let capacity = if let Some(size) = primitive_size(element) {
+						// The length of a list of a primitive type can be
+						// checked, after which all of its elements are known
+						// to be there.
+						let check = ensure_remaining_tokens(quote!(len));
+
+						quote!({
+							let len = context.saturating_mul(#size);
+							#check
+
+							context
+						})
+					} else {
+						// Every other element is read from at least one byte,
+						// so the list can't have more elements than there
+						// are bytes remaining. This stops a malicious length
+						// from allocating more than the message could hold.
+						quote!(context.min(reader.remaining()))
+					};
+
+					let read_element = quote_spanned!(element.span()=>
+						<#element as cornflakes::Readable>::read_from
+					);
+
+					return quote!(
+						let #name = {
+							let context: usize = #context;
+							let mut list = Vec::with_capacity(#capacity);
+
+							for _ in 0..context {
+								list.push(#read_element(reader)?);
+							}
+
+							list
+						};
+					);
+				}
+
+				let read = quote_spanned!(r#type.span()=>
+					<#r#type as cornflakes::ContextualReadable>::read_with
+				);
 
 				quote!(
-					// let __my_field__ = <Vec<u8>>::read_with(
+					// let __my_field__ = <MyValues as ContextualReadable>::read_with(
 					//     reader,
-					//     __my_field__(__my_len__),
+					//     {
+					//         let my_len: &u32 = &__my_len__;
+					//
+					//         *my_len as usize
+					//     },
 					// )?;
-					let #name = <#r#type as cornflakes::ContextualReadable>
-						::read_with(
-							reader,
-							#name( #(#args,)* ),
-						)?;
+					let #name = #read(reader, #context)?;
 				)
 			} else {
+				let check = primitive_size(r#type).map(ensure_remaining_tokens);
+				let read = quote_spanned!(r#type.span()=>
+					<#r#type as cornflakes::Readable>::read_from
+				);
+
 				quote!(
-					// let __my_field2__ = u8::read_from(reader)?;
-					let #name = <r#type as cornflakes::Readable>::read_from(reader)?;
+					#check
+					// let __my_field2__ = <u8 as Readable>::read_from(reader)?;
+					let #name = #read(reader)?;
 				)
 			}
 		});
@@ -105,13 +448,12 @@ impl ItemDeserializeTokens for Field {
 impl ItemSerializeTokens for Let {
 	fn serialize_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
 		let name = id.formatted();
-		let args = self.source.fmt_args();
 
-		quote!(
-			// __data_len__(&__data__).write_to(writer)?;
-			#name( #( &#args, )* ).write_to(writer)?;
-		)
-		.to_tokens(tokens);
+		let write = quote_spanned!(self.r#type.span()=> cornflakes::Writable::write_to);
+
+		self.bind_tokens(tokens, id);
+		// cornflakes::Writable::write_to(&__data_len__, writer)?;
+		tokens.append_tokens(|| quote!(#write(&#name, writer)?;));
 	}
 }
 
@@ -121,67 +463,179 @@ impl ItemDeserializeTokens for Let {
 		let r#type = &self.r#type;
 
 		tokens.append_tokens(|| {
-			// let __data_len__: u32 = reader.read()?;
-			quote!(let #name: #r#type = reader.read()?;)
+			let check = primitive_size(r#type).map(ensure_remaining_tokens);
+			let read = quote_spanned!(r#type.span()=>
+				<#r#type as cornflakes::Readable>::read_from
+			);
+
+			quote!(
+				#check
+				// let __data_len__ = <u32 as Readable>::read_from(reader)?;
+				let #name = #read(reader)?;
+			)
 		});
 	}
 }
 
+impl Array {
+	/// Generates the tokens which evaluate to the number of unused bytes in
+	/// this array.
+	fn len_tokens(&self) -> TokenStream2 {
+		match &self.len {
+			ArrayLen::Source(source) => source.block_tokens(),
+
+			ArrayLen::Pad {
+				previous,
+				cow_bytes: true,
+				..
+			} => quote!(xrb::util::pad(#previous.len())),
+
+			ArrayLen::Pad { previous, .. } => quote!({
+				// The previous item is borrowed when writing and owned when
+				// reading, so `byte_size` is called as a method to accept
+				// both.
+				use cornflakes::ByteSize as _;
+
+				xrb::util::pad(#previous.byte_size())
+			}),
+		}
+	}
+}
+
 impl ItemSerializeTokens for Unused {
-	fn serialize_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
-		match self {
-			Self::Unit { .. } => {
-				// 0u8.write_to(writer)?;
-				tokens.append_tokens(|| {
-					quote!(
-						writer.put_u8(0);
-					)
-				});
+	fn serialize_tokens(&self, tokens: &mut TokenStream2, _id: &ItemId) {
+		tokens.append_tokens(|| match self {
+			// writer.put_u8(0);
+			Self::Unit { .. } => quote!(writer.put_u8(0);),
+
+			Self::Array(array) => {
+				let source = array.len_tokens();
+
+				// writer.put_bytes(0, { ... });
+				quote!(writer.put_bytes(0, #source);)
 			}
+		});
+	}
+}
 
+impl ItemDeserializeTokens for Unused {
+	fn deserialize_tokens(&self, tokens: &mut TokenStream2, _id: &ItemId) {
+		tokens.append_tokens(|| match self {
 			Self::Array(array) => {
-				let name = id.formatted();
-				let args = array.source.fmt_args();
+				let source = array.len_tokens();
+				let check = ensure_remaining_tokens(quote!(len));
 
-				tokens.append_tokens(|| {
-					quote!(
-						// writer.put_many(0u8, _unused_1_(&__data__));
-						writer.put_many(
-							0u8,
-							#name( #(#args,)* )
-						);
-					)
-				});
+				// xrb::skip_padding(reader, { ... })?;
+				quote!({
+					let len: usize = #source;
+					#check
+
+					xrb::skip_padding(reader, len)?;
+				})
+			}
+
+			// xrb::skip_padding(reader, 1)?;
+			Self::Unit { .. } => {
+				let check = ensure_remaining_tokens(1_usize);
+
+				quote!(
+					#check
+					xrb::skip_padding(reader, 1)?;
+				)
 			}
+		});
+	}
+}
+
+impl ItemSizeTokens for Field {
+	fn size_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
+		let name = id.formatted();
+
+		if is_cow_bytes(&self.r#type) {
+			// size += __my_bytes__.len();
+			tokens.append_tokens(|| quote!(size += #name.len();));
+
+			return;
+		}
+
+		// An optional field only takes up space if it is present.
+		if let Some(inner) = self.present_if().and(option_inner_type(&self.r#type)) {
+			let byte_size = quote_spanned!(inner.span()=> cornflakes::ByteSize::byte_size);
+
+			// if let Some(value) = __my_field__ {
+			//     size += cornflakes::ByteSize::byte_size(value);
+			// }
+			tokens.append_tokens(
+				|| quote!(if let Some(value) = #name { size += #byte_size(value); }),
+			);
+
+			return;
 		}
+
+		let byte_size = quote_spanned!(self.r#type.span()=> cornflakes::ByteSize::byte_size);
+
+		// size += cornflakes::ByteSize::byte_size(__my_field__);
+		tokens.append_tokens(|| quote!(size += #byte_size(#name);));
 	}
 }
 
-impl ItemDeserializeTokens for Unused {
-	fn deserialize_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
-		tokens.append_tokens(|| {
-			match self {
-				Self::Array(array) => {
-					let name = id.formatted();
-					let args = array.source.fmt_args();
+impl ItemSizeTokens for Let {
+	fn size_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
+		let name = id.formatted();
 
-					quote!(
-						// reader.advance(_unused_1_(&__data__) as usize);
-						reader.advance(
-							#name( #(#args,)* ) as usize,
-						);
-					)
-				}
+		let byte_size = quote_spanned!(self.r#type.span()=> cornflakes::ByteSize::byte_size);
 
-				Self::Unit { .. } => {
-					// reader.advance(1);
-					quote!(reader.advance(1);)
-				}
+		self.bind_tokens(tokens, id);
+		// size += cornflakes::ByteSize::byte_size(&__data_len__);
+		tokens.append_tokens(|| quote!(size += #byte_size(&#name);));
+	}
+}
+
+impl Let {
+	/// Generates the tokens to bind the value of this let item to its
+	/// formatted name, so that it can be used by later items.
+	fn bind_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
+		let name = id.formatted();
+		let r#type = &self.r#type;
+		let source = self
+			.source
+			.as_ref()
+			.expect("let items without sources are rejected when they are parsed")
+			.block_tokens();
+
+		// let __data_len__: u32 = { self.data.len() as u32 };
+		tokens.append_tokens(|| quote!(let #name: #r#type = #source;));
+	}
+}
+
+impl ItemSizeTokens for Unused {
+	fn size_tokens(&self, tokens: &mut TokenStream2, _id: &ItemId) {
+		tokens.append_tokens(|| match self {
+			// A single unused byte.
+			Self::Unit { .. } => quote!(size += 1;),
+
+			Self::Array(array) => {
+				let source = array.len_tokens();
+
+				// size += { ... };
+				quote!(size += #source;)
 			}
 		});
 	}
 }
 
+impl ItemSizeTokens for Item {
+	fn size_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
+		match self {
+			Item::Field(field) => field.size_tokens(tokens, id),
+
+			Item::Let(r#let) => r#let.size_tokens(tokens, id),
+
+			Item::Unused(unused) => unused.size_tokens(tokens, id),
+		}
+	}
+}
+
 impl ItemSerializeTokens for Item {
 	fn serialize_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
 		match self {
@@ -206,25 +660,107 @@ impl ItemDeserializeTokens for Item {
 	}
 }
 
+/// The discriminant of an enum variant.
+pub(crate) enum Discriminant {
+	/// A discriminant which is known at expansion time.
+	Literal(u128),
+	/// A discriminant which can only be evaluated by the generated code.
+	Expr(TokenStream2),
+}
+
+impl Discriminant {
+	/// Returns the discriminant of each variant, given the discriminant that
+	/// each variant explicitly specifies, if any.
+	///
+	/// Discriminants start at `0` and increase by `1` for each variant, unless
+	/// a variant explicitly specifies its discriminant. Where possible, the
+	/// discriminant is calculated during expansion, rather than generating
+	/// expressions like `0 + 1 + 1`.
+	pub(crate) fn for_variants<'a>(
+		discriminants: impl IntoIterator<Item = &'a Option<(Token![=], Expr)>>,
+	) -> Vec<Self> {
+		// The last explicitly specified discriminant, if any.
+		let mut base: Option<&Expr> = None;
+		// The number of variants since that discriminant.
+		let mut offset: u128 = 0;
+
+		discriminants
+			.into_iter()
+			.map(|discriminant| {
+				// If the variant explicitly specifies its discriminant, restart
+				// from that discriminant expression.
+				if let Some((_, expr)) = discriminant {
+					base = Some(expr);
+					offset = 0;
+				}
+
+				let discrim = match base {
+					None => Self::Literal(offset),
+
+					Some(Expr::Lit(ExprLit {
+						lit: Lit::Int(int), ..
+					})) if int.base10_parse::<u128>().is_ok() => {
+						Self::Literal(int.base10_parse::<u128>().unwrap() + offset)
+					}
+
+					Some(expr) if offset == 0 => Self::Expr(expr.to_token_stream()),
+					Some(expr) => {
+						let offset = Literal::u128_unsuffixed(offset);
+
+						Self::Expr(quote!((#expr) + #offset))
+					}
+				};
+
+				offset += 1;
+
+				discrim
+			})
+			.collect()
+	}
+
+	/// Generates the pattern which matches this discriminant.
+	pub(crate) fn pattern_tokens(&self) -> TokenStream2 {
+		match self {
+			// 3
+			Self::Literal(value) => Literal::u128_unsuffixed(*value).to_token_stream(),
+			// discrim if discrim == (MY_CONST) + 1
+			Self::Expr(expr) => quote!(discrim if discrim == #expr),
+		}
+	}
+}
+
+impl ToTokens for Discriminant {
+	fn to_tokens(&self, tokens: &mut TokenStream2) {
+		match self {
+			Self::Literal(value) => Literal::u128_unsuffixed(*value).to_tokens(tokens),
+			Self::Expr(expr) => expr.to_tokens(tokens),
+		}
+	}
+}
+
 impl Enum {
 	fn serialize_tokens(&self, tokens: &mut TokenStream2) {
 		let name = &self.ident;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+		// writer.put_u16
+		let put = format_ident!("put_{}", self.discriminant_type());
+		// A `match` enum's discriminant is written by the definition which
+		// contains it.
+		let is_match = self.is_match();
+		// If nothing is written, the writer is unused.
+		let writer = if is_match && !self.has_items() {
+			quote!(_writer)
+		} else {
+			quote!(writer)
+		};
 
 		let arms = TokenStream2::with_tokens(|tokens| {
-			// Start the variants' discriminant tokens at `0`. We add `1` each
-			// iteration, unless a variant explicitly specifies its
-			// discriminant.
-			let mut discrim = quote!(0);
+			let discrims = Discriminant::for_variants(self.discriminants());
 
-			for variant in &self.variants {
+			for (variant, discrim) in self.variants.iter().zip(discrims) {
 				let name = &variant.ident;
 
-				// If the variant explicitly specifies its discriminant, reset
-				// the `discrim` tokens to that discriminant expression.
-				if let Some((_, expr)) = &variant.discriminant {
-					discrim = expr.to_token_stream();
-				}
-
 				// Tokens to destructure the variant's fields.
 				let pat = TokenStream2::with_tokens(|tokens| {
 					variant.items.fields_to_tokens(tokens, ExpandMode::Normal);
@@ -237,23 +773,19 @@ impl Enum {
 					}
 				});
 
+				// Write the variant's discriminant.
+				let discrim = (!is_match).then(|| quote!(writer.#put(#discrim);));
+
 				// Append the variant's match arm.
 				tokens.append_tokens(|| {
 					quote!(
 						Self::#name #pat => {
-							// Write the variant's discriminant (as a single byte).
-							((#discrim) as u8).write_to(writer)?;
+							#discrim
 
 							#inner
 						}
 					)
 				});
-
-				// Add `1` to the discriminant tokens so that the next variant
-				// starts with a discriminant one more than the current
-				// variant's discriminant (unless that variant's discriminant
-				// is specified explicitly).
-				discrim.append_tokens(|| quote!(/* discrim */ + 1));
 			}
 		});
 
@@ -263,22 +795,26 @@ impl Enum {
 				//     fn write_to(
 				//         &self,
 				//         writer: &mut impl BufMut,
-				//     ) -> Result<(), Box<dyn Error>> {
+				//     ) -> Result<(), WriteError> {
 				//         match self {
 				//             Self::Variant => {
-				//                 (0 as u8).write_to(writer)?;
+				//                 writer.put_u8(0);
 				//             }
 				//         }
+				//
+				//         Ok(())
 				//     }
 				// }
-				impl cornflakes::Writable for #name {
+				impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
 					fn write_to(
 						&self,
-						writer: &mut impl bytes::BufMut,
-					) -> Result<(), Box<dyn std::error::Error>> {
+						#writer: &mut impl bytes::BufMut,
+					) -> Result<(), cornflakes::WriteError> {
 						match self {
 							#arms
 						}
+
+						Ok(())
 					}
 				}
 			)
@@ -289,21 +825,15 @@ impl Enum {
 impl Enum {
 	fn deserialize_tokens(&self, tokens: &mut TokenStream2) {
 		let name = &self.ident;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let discriminant_type = self.discriminant_type();
 
 		let arms = TokenStream2::with_tokens(|tokens| {
-			// Start the variants' discriminant tokens at `0`. We add `1` each
-			// iteration, unless a variant explicitly specifies its
-			// discriminant.
-			let mut discrim = quote!(0);
+			let discrims = Discriminant::for_variants(self.discriminants());
 
-			for variant in &self.variants {
+			for (variant, discrim) in self.variants.iter().zip(discrims) {
 				let name = &variant.ident;
-
-				// If the variant explicitly specifies its discriminant, reset
-				// the `discrim` tokens to that discriminant expression.
-				if let Some((_, expr)) = &variant.discriminant {
-					discrim = expr.to_token_stream();
-				}
+				let pat = discrim.pattern_tokens();
 
 				// Tokens to fill in the fields for the variant's constructor.
 				let cons = TokenStream2::with_tokens(|tokens| {
@@ -321,45 +851,144 @@ impl Enum {
 				tokens.append_tokens(|| {
 					quote!(
 						// Match against the discriminant...
-						#discrim => {
+						#pat => {
 							// Deserialize the items.
 							#inner
 
 							// Construct the variant.
-							Self::#name #cons
+							Ok(Self::#name #cons)
 						}
 					)
 				});
-
-				// Add `1` for the next variant's discriminant.
-				discrim.append_tokens(|| quote!(/* discrim */ + 1));
 			}
 		});
 
+		// `ReadError::UnrecognizedDiscriminant` only holds a `u8`, so wider
+		// discriminants which don't fit are saturated.
+		let unrecognized = if self.discriminant_width() == 1 {
+			quote!(other_discrim)
+		} else {
+			quote!(u8::try_from(other_discrim).unwrap_or(u8::MAX))
+		};
+
+		// A `match` enum's discriminant has already been read by the
+		// definition which contains it, so it is given as the context.
+		if self.is_match() {
+			// If nothing is read, the reader is unused.
+			let reader = if self.has_items() {
+				quote!(reader)
+			} else {
+				quote!(_reader)
+			};
+
+			tokens.append_tokens(|| {
+				quote!(
+					impl #impl_generics cornflakes::ContextualReadable
+					for #name #ty_generics #where_clause {
+						type Context = #discriminant_type;
+
+						fn read_with(
+							#reader: &mut impl bytes::Buf,
+							discriminant: #discriminant_type,
+						) -> Result<Self, cornflakes::ReadError> {
+							// Match against the discriminant...
+							match discriminant {
+								#arms
+
+								other_discrim => Err(
+									cornflakes::ReadError::UnrecognizedDiscriminant(#unrecognized)
+								),
+							}
+						}
+					}
+				)
+			});
+
+			return;
+		}
+
+		// Check that there are enough bytes for the discriminant.
+		let check = ensure_remaining_tokens(self.discriminant_width());
+
 		tokens.append_tokens(|| {
 			quote!(
 				// impl Readable for MyEnum {
-				//     fn read_from(reader: &mut impl Buf) -> Result<Self, Box<dyn Error>> {
-				//         match reader.read::<u8>() {
-				//             (0 as u8) => {
-				//                 Self::Variant
+				//     fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+				//         match <u8 as Readable>::read_from(reader)? {
+				//             0 => {
+				//                 Ok(Self::Variant)
 				//             }
-				//             _ => panic!("unrecognized enum variant discriminant"),
+				//
+				//             other_discrim => Err(
+				//                 ReadError::UnrecognizedDiscriminant(other_discrim)
+				//             ),
 				//         }
 				//     }
 				// }
-				impl cornflakes::Readable for #name {
+				impl #impl_generics cornflakes::Readable for #name #ty_generics #where_clause {
 					fn read_from(
 						reader: &mut impl bytes::Buf,
-					) -> Result<Self, Box<dyn std::error::Error>> {
+					) -> Result<Self, cornflakes::ReadError> {
+						#check
+
 						// Match against the discriminant...
-						Ok(match reader.read::<u8>()? {
+						match <#discriminant_type as cornflakes::Readable>::read_from(reader)? {
 							#arms
 
-							other_discrim => return Err(
-								cornflakes::ReadError::UnrecognizedDiscriminant(other_discrim)
+							other_discrim => Err(
+								cornflakes::ReadError::UnrecognizedDiscriminant(#unrecognized)
 							),
-						})
+						}
+					}
+				}
+			)
+		});
+	}
+}
+
+impl Enum {
+	pub fn impl_wire_enum_tokens(&self, tokens: &mut TokenStream2) {
+		let name = &self.ident;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let width = Literal::usize_unsuffixed(self.discriminant_width());
+
+		let arms = TokenStream2::with_tokens(|tokens| {
+			let discrims = Discriminant::for_variants(self.discriminants());
+
+			for (variant, discrim) in self.variants.iter().zip(discrims) {
+				let name = &variant.ident;
+
+				// Tokens to ignore the variant's fields, if any.
+				let pat = match &variant.items {
+					Items::Named { .. } => quote!({ .. }),
+					Items::Unnamed { .. } => quote!((..)),
+					Items::Unit => quote!(),
+				};
+
+				// Discriminants which aren't literals are evaluated as the
+				// discriminant type, the same as when they are read.
+				let raw = match &discrim {
+					Discriminant::Literal(value) => {
+						Literal::u128_unsuffixed(*value).to_token_stream()
+					}
+					Discriminant::Expr(expr) => quote!(u32::from(#expr)),
+				};
+
+				tokens.append_tokens(|| quote!(Self::#name #pat => #raw,));
+			}
+		});
+
+		tokens.append_tokens(|| {
+			quote!(
+				// NOTE: in `xrb`, `extern crate self as xrb;` will have to be
+				//       used so that the trait path works.
+				impl #impl_generics xrb::WireEnum for #name #ty_generics #where_clause {
+					const WIDTH: usize = #width;
+
+					fn raw(&self) -> u32 {
+						match self {
+							#arms
+						}
 					}
 				}
 			)
@@ -376,6 +1005,8 @@ impl Struct {
 			StructMetadata::Reply(reply) => reply.serialize_tokens(tokens, &self.items),
 
 			StructMetadata::Event(event) => event.serialize_tokens(tokens, &self.items),
+
+			StructMetadata::Message(message) => message.serialize_tokens(tokens, &self.items),
 		}
 	}
 }
@@ -389,6 +1020,8 @@ impl Struct {
 			StructMetadata::Reply(reply) => reply.deserialize_tokens(tokens, &self.items),
 
 			StructMetadata::Event(event) => event.deserialize_tokens(tokens, &self.items),
+
+			StructMetadata::Message(message) => message.deserialize_tokens(tokens, &self.items),
 		}
 	}
 }
@@ -396,6 +1029,14 @@ impl Struct {
 impl SerializeMessageTokens for BasicStructMetadata {
 	fn serialize_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+		// If there are no items, the writer is unused.
+		let writer = if items.is_empty() {
+			format_ident!("_writer")
+		} else {
+			format_ident!("writer")
+		};
 
 		// Tokens to destructure the struct's fields.
 		let pat = TokenStream2::with_tokens(|tokens| {
@@ -415,22 +1056,26 @@ impl SerializeMessageTokens for BasicStructMetadata {
 				//     fn write_to(
 				//         &self,
 				//         writer: &mut impl BufMut,
-				//     ) -> Result<(), Box<dyn Error>> {
+				//     ) -> Result<(), WriteError> {
 				//         let Self(__0__, __1__) = self;
 				//
-				//         __0__.write_to(writer)?;
-				//         __1__.write_to(writer)?;
+				//         cornflakes::Writable::write_to(__0__, writer)?;
+				//         cornflakes::Writable::write_to(__1__, writer)?;
+				//
+				//         Ok(())
 				//     }
 				// }
-				impl cornflakes::Writable for #name {
+				impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
 					fn write_to(
 						&self,
-						writer: &mut impl bytes::BufMut,
-					) -> Result<(), Box<dyn std::error::Error>> {
+						#writer: &mut impl bytes::BufMut,
+					) -> Result<(), cornflakes::WriteError> {
 						// Destructure the struct.
 						let Self #pat = self;
 
 						#inner
+
+						Ok(())
 					}
 				}
 			)
@@ -442,7 +1087,14 @@ impl DeserializeMessageTokens for BasicStructMetadata {
 	fn deserialize_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
 		let name = &self.name;
 
-		// Tokens to fill in the fields for the struct's constructor.
+		// If there are no items, the reader is unused.
+		let reader = if items.is_empty() {
+			format_ident!("_reader")
+		} else {
+			format_ident!("reader")
+		};
+
+		// Tokens to fill in the fields for the struct's constructor.
 		let cons = TokenStream2::with_tokens(|tokens| {
 			items.fields_to_tokens(tokens, ExpandMode::Normal);
 		});
@@ -454,27 +1106,40 @@ impl DeserializeMessageTokens for BasicStructMetadata {
 			}
 		});
 
-		tokens.append_tokens(|| {
-			quote!(
-				// impl Readable for MyStruct {
-				//     fn read_from(reader: &mut impl Buf) -> Result<Self, Box<dyn Error>> {
-				//         let __0__: i32 = reader.read();
-				//         let __1__: i32 = reader.read();
-				//
-				//         Self(__0__, __1__)
-				//     }
-				// }
-				impl cornflakes::Readable for #name {
-					fn read_from(
-						reader: &mut impl bytes::Buf,
-					) -> Result<Self, Box<dyn std::error::Error>> {
-						#inner
+		// impl Readable for MyStruct {
+		//     fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		//         let __0__ = <i32 as Readable>::read_from(reader)?;
+		//         let __1__ = <i32 as Readable>::read_from(reader)?;
+		//
+		//         Ok(Self(__0__, __1__))
+		//     }
+		// }
+		let body = quote!(
+			#inner
+
+			Ok(Self #cons)
+		);
+
+		impl_readable_tokens(
+			tokens,
+			name,
+			&self.vis,
+			&self.generics,
+			items,
+			&reader,
+			&body,
+		);
+	}
+}
 
-						Self #cons
-					}
-				}
-			)
-		});
+impl Request {
+	/// Generates the tokens for the type of reply generated by this request:
+	/// `()` if there is none.
+	fn reply_tokens(&self) -> TokenStream2 {
+		match &self.reply_ty {
+			Some((_, reply_ty)) => reply_ty.to_token_stream(),
+			None => quote!(()),
+		}
 	}
 }
 
@@ -488,6 +1153,8 @@ impl SerializeMessageTokens for Request {
 		// ...
 
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let reply = self.reply_tokens();
 
 		// Tokens required to destructure the request's fields.
 		let pat = TokenStream2::with_tokens(|tokens| {
@@ -496,12 +1163,12 @@ impl SerializeMessageTokens for Request {
 
 		// If there is a metabyte item, generate its serialization tokens first.
 		let metabyte = TokenStream2::with_tokens(|tokens| {
-			if self.minor_opcode.is_some() {
+			if let Some((_, minor)) = &self.minor_opcode {
 				// If this request has a minor opcode, then that is to be
 				// written in the metabyte position.
 				tokens.append_tokens(|| {
 					quote!(
-						writer.put_u16(<Self as crate::x11::traits::Request>::minor_opcode());
+						writer.put_u8(#minor);
 					)
 				});
 			} else {
@@ -520,23 +1187,25 @@ impl SerializeMessageTokens for Request {
 
 		tokens.append_tokens(|| {
 			quote!(
-				impl cornflakes::Writable for #name {
+				impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
 					fn write_to(
 						&self,
 						writer: &mut impl bytes::BufMut,
-					) -> Result<(), Box<dyn std::error::Error>> {
+					) -> Result<(), cornflakes::WriteError> {
 						// Destructure the struct.
 						let Self #pat = self;
 
 						// Major opcode.
-						writer.put_u8(<Self as crate::x11::traits::Request>::major_opcode());
+						writer.put_u8(<Self as xrb::Request<#reply>>::major_opcode());
 						// Metabyte (minor opcode, metabyte item, or nothing).
 						#metabyte
 						// Request length.
-						writer.put_u16(<Self as crate::x11::traits::Request>::length(&self));
+						writer.put_u16(<Self as xrb::Request<#reply>>::length(self));
 
 						// Rest of the items.
 						#inner
+
+						Ok(())
 					}
 				}
 			)
@@ -576,22 +1245,171 @@ impl DeserializeMessageTokens for Request {
 			items.fields_to_tokens(tokens, ExpandMode::Request);
 		});
 
+		let length_check = ensure_remaining_tokens(2_usize);
+
+		let body = quote!(
+			// Read the metabyte item, if any.
+			#metabyte
+			// Read the length of the request.
+			#length_check
+			let _length_ = reader.get_u16();
+
+			// Read the rest of the items.
+			#inner
+
+			// Call the constructor.
+			Ok(Self #cons)
+		);
+
+		impl_readable_tokens(
+			tokens,
+			name,
+			&self.vis,
+			&self.generics,
+			items,
+			&format_ident!("reader"),
+			&body,
+		);
+	}
+}
+
+impl SerializeMessageTokens for Message {
+	fn serialize_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		// Message
+		// =======
+		// ...	header
+		// ...
+
+		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Normal);
+		});
+
+		let header = self.header_tokens(items);
+
+		let inner = TokenStream2::with_tokens(|tokens| {
+			for (id, item) in items.pairs() {
+				item.serialize_tokens(tokens, id);
+			}
+		});
+
 		tokens.append_tokens(|| {
 			quote!(
-				impl cornflakes::Readable for #name {
-					fn read_from(
-						reader: &mut impl bytes::Buf,
-					) -> Result<Self, cornflakes::ReadError> {
-						// Read the metabyte item, if any.
-						#metabyte
-						// Read the length of the request.
-						let _length_ = reader.get_u16();
+				impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
+					fn write_to(
+						&self,
+						writer: &mut impl bytes::BufMut,
+					) -> Result<(), cornflakes::WriteError> {
+						// Destructure the struct.
+						let Self #pat = self;
+
+						// Header.
+						#header
+						cornflakes::Writable::write_to(&header, writer)?;
 
-						// Read the rest of the items.
+						// Items.
 						#inner
 
-						// Call the constructor.
-						Self #cons
+						Ok(())
+					}
+				}
+			)
+		});
+	}
+}
+
+impl DeserializeMessageTokens for Message {
+	fn deserialize_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		// Message
+		// =======
+		// ...	header
+		// ...
+
+		let name = &self.name;
+		let header_ty = &self.header_ty;
+
+		let inner = TokenStream2::with_tokens(|tokens| {
+			for (id, item) in items.pairs() {
+				item.deserialize_tokens(tokens, id);
+			}
+		});
+
+		let cons = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Normal);
+		});
+
+		let body = quote!(
+			// Read the header. Its code has already been found, by
+			// reading it from a copy of the reader, to know to read
+			// this message.
+			<#header_ty as cornflakes::Readable>::read_from(reader)?;
+
+			// Read the items.
+			#inner
+
+			// Call the constructor.
+			Ok(Self #cons)
+		);
+
+		impl_readable_tokens(
+			tokens,
+			name,
+			&self.vis,
+			&self.generics,
+			items,
+			&format_ident!("reader"),
+			&body,
+		);
+	}
+}
+
+impl Message {
+	/// Generates the statements to create the message's `header` from its
+	/// code and the number of bytes its items are written as.
+	///
+	/// The struct must already have been destructured.
+	fn header_tokens(&self, items: &Items) -> TokenStream2 {
+		let header_ty = &self.header_ty;
+
+		// Statements adding the size of every item in bytes to `size`.
+		let size = TokenStream2::with_tokens(|tokens| {
+			for (id, item) in items.pairs() {
+				item.size_tokens(tokens, id);
+			}
+		});
+		// `size` only needs to be mutable if there are items to add to it.
+		let mutability = (!size.is_empty()).then(|| quote!(mut));
+
+		quote!(
+			let #mutability size: usize = 0;
+			#size
+
+			let header = <#header_ty as xrb::MessageHeader>::new(
+				<Self as xrb::Message>::code(),
+				size,
+			);
+		)
+	}
+
+	pub fn impl_message_tokens(&self, tokens: &mut TokenStream2) {
+		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let header_ty = &self.header_ty;
+		// The expression evaluating to the message's code.
+		let code = &self.code_expr;
+
+		tokens.append_tokens(|| {
+			quote!(
+				// NOTE: in `xrb`, `extern crate self as xrb;` will have to be
+				//       used so that the trait path works.
+				impl #impl_generics xrb::Message for #name #ty_generics #where_clause {
+					type Header = #header_ty;
+
+					// The code uniquely identifying this message.
+					fn code() -> <#header_ty as xrb::MessageHeader>::Code {
+						#code
 					}
 				}
 			)
@@ -610,6 +1428,8 @@ impl SerializeMessageTokens for Reply {
 		// ...
 
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let request = &self.request_ty;
 
 		// Tokens required to destructure the reply's fields.
 		let pat = TokenStream2::with_tokens(|tokens| {
@@ -631,7 +1451,7 @@ impl SerializeMessageTokens for Reply {
 			if self.sequence_token.is_none() {
 				tokens.append_tokens(|| {
 					quote!(
-						writer.put_u16(_sequence_);
+						writer.put_u16(*_sequence_);
 					)
 				});
 			}
@@ -646,7 +1466,7 @@ impl SerializeMessageTokens for Reply {
 
 		tokens.append_tokens(|| {
 			quote!(
-				impl cornflakes::Writable for #name {
+				impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
 					fn write_to(
 						&self,
 						writer: &mut impl bytes::BufMut,
@@ -660,9 +1480,11 @@ impl SerializeMessageTokens for Reply {
 						// The sequence field, if there is one.
 						#sequence
 						// The length of the reply.
-						writer.put_u16(<Self as crate::x11::traits::Reply>::length(&self));
+						writer.put_u32(<Self as xrb::Reply<#request>>::length(self));
 
 						#inner
+
+						Ok(())
 					}
 				}
 			)
@@ -691,8 +1513,11 @@ impl DeserializeMessageTokens for Reply {
 			// If the sequence field hasn't been opted out of...
 			if self.sequence_token.is_none() {
 				// Deserialize the sequence field.
+				let check = ensure_remaining_tokens(2_usize);
+
 				tokens.append_tokens(|| {
 					quote!(
+						#check
 						let _sequence_ = reader.get_u16();
 					)
 				});
@@ -716,26 +1541,31 @@ impl DeserializeMessageTokens for Reply {
 			);
 		});
 
-		tokens.append_tokens(|| {
-			quote!(
-				impl cornflakes::Readable for #name {
-					fn read_from(
-						reader: &mut impl bytes::Buf,
-					) -> Result<Self, cornflakes::ReadError> {
-						// Deserialize the metabyte item.
-						#metabyte
-						// Deserialize the sequence field.
-						#sequence
-						// Deserialize the reply field.
-						let _length_ = reader.get_u32();
-
-						#inner
-
-						Self #cons
-					}
-				}
-			)
-		});
+		let length_check = ensure_remaining_tokens(4_usize);
+
+		let body = quote!(
+			// Deserialize the metabyte item.
+			#metabyte
+			// Deserialize the sequence field.
+			#sequence
+			// Deserialize the reply field.
+			#length_check
+			let _length_ = reader.get_u32();
+
+			#inner
+
+			Ok(Self #cons)
+		);
+
+		impl_readable_tokens(
+			tokens,
+			name,
+			&self.vis,
+			&self.generics,
+			items,
+			&format_ident!("reader"),
+			&body,
+		);
 	}
 }
 
@@ -748,7 +1578,17 @@ impl SerializeMessageTokens for Event {
 		// u16	sequence
 		// ...
 
+		// Generic Event
+		// =============
+		// u8	35 (generic event)
+		// u8	extension (major opcode)
+		// u16	sequence
+		// u32	length
+		// u16	evtype
+		// ...
+
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
 		// Pattern to destructure the event struct.
 		let pat = TokenStream2::with_tokens(|tokens| {
@@ -757,7 +1597,32 @@ impl SerializeMessageTokens for Event {
 
 		// Tokens to serialize the metabyte item, if any.
 		let metabyte = TokenStream2::with_tokens(|tokens| {
-			items.metabyte_serialize_tokens(tokens);
+			if self.is_generic() {
+				// If this is a generic event, the metabyte position contains
+				// the major opcode of the extension that generated it.
+				tokens.append_tokens(|| {
+					quote!(
+						writer.put_u8(<Self as xrb::GenericEvent>::extension());
+					)
+				});
+			} else {
+				items.metabyte_serialize_tokens(tokens);
+			}
+		});
+
+		// Tokens to serialize the additional length and event type of a
+		// generic event.
+		let generic = TokenStream2::with_tokens(|tokens| {
+			if self.is_generic() {
+				tokens.append_tokens(|| {
+					quote!(
+						// The number of 4-byte units beyond 32 bytes.
+						writer.put_u32(<Self as xrb::GenericEvent>::length(self));
+						// The event type within the extension.
+						writer.put_u16(<Self as xrb::GenericEvent>::event_type());
+					)
+				});
+			}
 		});
 
 		let inner = TokenStream2::with_tokens(|tokens| {
@@ -769,7 +1634,7 @@ impl SerializeMessageTokens for Event {
 
 		tokens.append_tokens(|| {
 			quote!(
-				impl cornflakes::Writable for #name {
+				impl #impl_generics cornflakes::Writable for #name #ty_generics #where_clause {
 					fn write_to(
 						&self,
 						writer: &mut impl bytes::BufMut,
@@ -777,13 +1642,17 @@ impl SerializeMessageTokens for Event {
 						let Self #pat = self;
 
 						// Event code.
-						writer.put_u8(<Self as crate::x11::traits::Event>::code());
+						writer.put_u8(<Self as xrb::Event>::code());
 						// Serialize the metabyte item.
 						#metabyte
 						// Serialize the sequence field.
-						writer.put_u16(_sequence_);
+						writer.put_u16(*_sequence_);
+						// Serialize the generic event header, if any.
+						#generic
 
 						#inner
+
+						Ok(())
 					}
 				}
 			)
@@ -804,7 +1673,37 @@ impl DeserializeMessageTokens for Event {
 
 		// Deserialize the metabyte item, if any (otherwise skip the byte).
 		let metabyte = TokenStream2::with_tokens(|tokens| {
-			items.metabyte_deserialize_tokens(tokens);
+			if self.is_generic() {
+				// If this is a generic event, the metabyte position contains
+				// the major opcode of the extension that generated it. It must
+				// have already been read to know to deserialize this event, so
+				// we skip it.
+				let check = ensure_remaining_tokens(1_usize);
+
+				tokens.append_tokens(|| {
+					quote!(
+						#check
+						let _extension_ = reader.get_u8();
+					)
+				});
+			} else {
+				items.metabyte_deserialize_tokens(tokens);
+			}
+		});
+
+		// Deserialize the additional length and event type of a generic event.
+		let generic = TokenStream2::with_tokens(|tokens| {
+			if self.is_generic() {
+				let check = ensure_remaining_tokens(6_usize);
+
+				tokens.append_tokens(|| {
+					quote!(
+						#check
+						let _length_ = reader.get_u32();
+						let _evtype_ = reader.get_u16();
+					)
+				});
+			}
 		});
 
 		let inner = TokenStream2::with_tokens(|tokens| {
@@ -819,52 +1718,62 @@ impl DeserializeMessageTokens for Event {
 			items.fields_to_tokens(tokens, ExpandMode::Event);
 		});
 
-		tokens.append_tokens(|| {
-			quote!(
-				impl cornflakes::Readable for #name {
-					fn read_from(
-						reader: &mut impl bytes::Buf,
-					) -> Result<Self, cornflakes::ReadError> {
-						// Deserialize the metabyte item.
-						#metabyte
-						// Deserialize the sequence field.
-						let _sequence_ = reader.get_u16();
-
-						#inner
-
-						Self #cons
-					}
-				}
-			)
-		});
+		let sequence_check = ensure_remaining_tokens(2_usize);
+
+		let body = quote!(
+			// Deserialize the metabyte item.
+			#metabyte
+			// Deserialize the sequence field.
+			#sequence_check
+			let _sequence_ = reader.get_u16();
+			// Deserialize the generic event header, if any.
+			#generic
+
+			#inner
+
+			Ok(Self #cons)
+		);
+
+		impl_readable_tokens(
+			tokens,
+			name,
+			&self.vis,
+			&self.generics,
+			items,
+			&format_ident!("reader"),
+			&body,
+		);
 	}
 }
 
 impl Request {
-	pub fn impl_request_tokens(&self, tokens: &mut TokenStream2) {
+	pub fn impl_request_tokens(&self, tokens: &mut TokenStream2, deprecation: Option<&LitStr>) {
 		// Request name.
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 		// Type of reply generated, if any.
-		let reply = self.reply_ty.as_ref().map(|(_, reply_ty)| reply_ty);
+		let reply = self.reply_tokens();
 
 		// The expression evaluating to the request's major opcode.
 		let major = &self.major_opcode_expr;
 
 		// The expression evaluating to the request's major opcode, if any.
 		let minor = if let Some((_, minor)) = &self.minor_opcode {
-			quote!(Some((#minor) as u8))
+			quote!(Some(#minor))
 		} else {
 			quote!(None)
 		};
 
+		let deprecation = deprecation_tokens(deprecation);
+
 		tokens.append_tokens(|| {
 			quote!(
 				// NOTE: in `xrb`, `extern crate self as xrb;` will have to be
 				//       used so that the trait path works.
-				impl xrb::Request<#reply> for #name {
+				impl #impl_generics xrb::Request<#reply> for #name #ty_generics #where_clause {
 					// The major opcode uniquely identifying the request.
 					fn major_opcode() -> u8 {
-						(#major) as u8
+						#major
 					}
 
 					// The minor opcode uniquely identifying the request
@@ -882,23 +1791,41 @@ impl Request {
 						//       minimum length from header etc.
 						0
 					}
+
+					#deprecation
 				}
 			)
 		});
 	}
 }
 
+/// Generates the `deprecation` method of a message trait for a message marked
+/// with `#[deprecated_protocol]`, or nothing if it is not.
+fn deprecation_tokens(deprecation: Option<&LitStr>) -> TokenStream2 {
+	deprecation.map_or_else(TokenStream2::new, |note| {
+		quote!(
+			// The note given with `#[deprecated_protocol]`.
+			fn deprecation() -> Option<&'static str> {
+				Some(#note)
+			}
+		)
+	})
+}
+
 impl Reply {
-	pub fn impl_reply_tokens(&self, tokens: &mut TokenStream2) {
+	pub fn impl_reply_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
 		//  The name of the reply.
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 		// The type of request associated with this reply.
 		let request = &self.request_ty;
 
 		// The sequence number associated with the request that generated this
 		// reply, if any.
 		let sequence = if self.sequence_token.is_none() {
-			quote!(Some(self._sequence_))
+			let member = items.sequence_member_tokens();
+
+			quote!(Some(self.#member))
 		} else {
 			quote!(None)
 		};
@@ -907,7 +1834,7 @@ impl Reply {
 			quote!(
 				// NOTE: in `xrb`, `extern crate self as xrb;` will have to be
 				//       used so that the trait path works.
-				impl xrb::Reply<#request> for #name {
+				impl #impl_generics xrb::Reply<#request> for #name #ty_generics #where_clause {
 					// The sequence number associated with the request that
 					// generated this reply, if any.
 					fn sequence(&self) -> Option<u16> {
@@ -927,29 +1854,913 @@ impl Reply {
 }
 
 impl Event {
-	pub fn impl_event_tokens(&self, tokens: &mut TokenStream2) {
+	pub fn impl_event_tokens(
+		&self,
+		tokens: &mut TokenStream2,
+		items: &Items,
+		deprecation: Option<&LitStr>,
+	) {
 		// Name of the event.
 		let name = &self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 		// The expression evaluating to the event's event code.
 		let code = &self.event_code_expr;
+		// The `_sequence_` field.
+		let sequence = items.sequence_member_tokens();
+
+		let deprecation = deprecation_tokens(deprecation);
 
 		tokens.append_tokens(|| {
 			quote!(
 				// NOTE: in `xrb`, `extern crate self as xrb;` will have to be
 				//       used so that the trait path works.
-				impl xrb::Event for #name {
+				impl #impl_generics xrb::Event for #name #ty_generics #where_clause {
 					// The code uniquely identifying this event.
 					fn code() -> u8 {
-						(#code) as u8
+						#code
 					}
 
 					// The sequence number associated with the last relevant
 					// request sent to the X server prior to this event.
 					fn sequence(&self) -> u16 {
-						self._sequence_
+						self.#sequence
+					}
+
+					#deprecation
+				}
+			)
+		});
+
+		if let Some((_, generic)) = &self.generic {
+			generic.impl_generic_event_tokens(tokens, self, items);
+		}
+	}
+}
+
+impl Generic {
+	pub fn impl_generic_event_tokens(
+		&self,
+		tokens: &mut TokenStream2,
+		event: &Event,
+		items: &Items,
+	) {
+		let name = &event.name;
+		let (impl_generics, ty_generics, where_clause) = event.generics.split_for_impl();
+
+		// The expression evaluating to the extension's major opcode.
+		let extension = &self.extension_expr;
+		// The expression evaluating to the event type within the extension.
+		let evtype = &self.evtype_expr;
+
+		// Pattern to destructure the event struct.
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Event);
+		});
+
+		// Statements adding the size of every item in bytes to `size`.
+		let size = TokenStream2::with_tokens(|tokens| {
+			for (id, item) in items.pairs() {
+				item.size_tokens(tokens, id);
+			}
+		});
+		// `size` only needs to be mutable if there are items to add to it.
+		let mutability = (!size.is_empty()).then(|| quote!(mut));
+
+		tokens.append_tokens(|| {
+			quote!(
+				impl #impl_generics xrb::GenericEvent for #name #ty_generics #where_clause {
+					// The major opcode of the extension that generated this
+					// event.
+					fn extension() -> u8 {
+						#extension
+					}
+
+					// The type of this event within its extension.
+					fn event_type() -> u16 {
+						#evtype
+					}
+
+					// The number of 4-byte units greater than the minimum
+					// length of 32 bytes.
+					#[allow(clippy::cast_possible_truncation)]
+					fn length(&self) -> u32 {
+						let Self #pat = self;
+
+						// The header (code, extension, sequence, length, and
+						// evtype) is 10 bytes.
+						let #mutability size: usize = 10;
+						#size
+
+						xrb::util::extra_units(size) as u32
+					}
+				}
+			)
+		});
+	}
+}
+
+// Traces {{{
+
+/// Generates the tokens to write a header `value` with the given `put` method,
+/// then record it in the trace as a field with the given `name`.
+fn header_trace_tokens(name: &str, put: &str, value: TokenStream2) -> TokenStream2 {
+	let put = format_ident!("{}", put);
+
+	quote!({
+		let value = #value;
+
+		writer.#put(value);
+		writer.field(#name, &value);
+	})
+}
+
+impl ItemTraceTokens for Item {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, id: &ItemId) {
+		self.serialize_tokens(tokens, id);
+
+		tokens.append_tokens(|| match (self, id) {
+			(Item::Field(_), ItemId::Field(field_id)) => {
+				let name = match field_id {
+					FieldId::Ident(ident) => ident.unraw().to_string(),
+					FieldId::Id(id) => id.to_string(),
+				};
+				let value = field_id.formatted();
+
+				// writer.field("my_field", __my_field__);
+				quote!(writer.field(#name, #value);)
+			}
+
+			(Item::Let(r#let), _) => {
+				let name = r#let.ident.unraw().to_string();
+				let value = id.formatted();
+
+				// writer.field("data_len", &__data_len__);
+				quote!(writer.field(#name, &#value);)
+			}
+
+			// writer.unused();
+			_ => quote!(writer.unused();),
+		});
+	}
+}
+
+impl Items {
+	/// Generates the tokens required to trace the metabyte item, if there is
+	/// one.
+	fn metabyte_trace_tokens(&self, tokens: &mut TokenStream2) {
+		if let Some((id, metabyte)) = self.pairs().find(|(_, item)| item.is_metabyte()) {
+			metabyte.trace_tokens(tokens, id);
+		} else {
+			// Otherwise, the byte is unused.
+			tokens.append_tokens(|| {
+				quote!(
+					writer.put_u8(0);
+					writer.unused();
+				)
+			});
+		}
+	}
+}
+
+impl Struct {
+	fn trace_tokens(&self, tokens: &mut TokenStream2) {
+		match &self.metadata {
+			StructMetadata::Struct(r#struct) => r#struct.trace_tokens(tokens, &self.items),
+
+			StructMetadata::Request(request) => request.trace_tokens(tokens, &self.items),
+			StructMetadata::Reply(reply) => reply.trace_tokens(tokens, &self.items),
+
+			StructMetadata::Event(event) => event.trace_tokens(tokens, &self.items),
+
+			StructMetadata::Message(message) => message.trace_tokens(tokens, &self.items),
+		}
+	}
+}
+
+/// Generates the `xrb::PrettyPrint` implementation for a struct named `name`,
+/// given the `pat` to destructure it, its `header` tokens, and its items.
+///
+/// `items` are traced after the header, except for the metabyte item if
+/// `skip_metabyte` is `true`.
+fn impl_pretty_print_tokens(
+	tokens: &mut TokenStream2,
+	(name, generics): (&Ident, &Generics),
+	pat: TokenStream2,
+	header: TokenStream2,
+	(items, skip_metabyte): (&Items, bool),
+) {
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	// If there are no header or items, the writer is unused.
+	let writer = if header.is_empty() && items.is_empty() {
+		format_ident!("_writer")
+	} else {
+		format_ident!("writer")
+	};
+
+	let inner = TokenStream2::with_tokens(|tokens| {
+		for (id, item) in items
+			.pairs()
+			.filter(|(_, item)| !(skip_metabyte && item.is_metabyte()))
+		{
+			item.trace_tokens(tokens, id);
+		}
+	});
+
+	let name_str = name.unraw().to_string();
+
+	tokens.append_tokens(|| {
+		quote!(
+			// NOTE: in `xrb`, either `extern crate self as xrb;` or
+			//       `#[xrbk(crate = "crate")]` will have to be used so that
+			//       the trait path works.
+			impl #impl_generics xrb::PrettyPrint for #name #ty_generics #where_clause {
+				const NAME: &'static str = #name_str;
+
+				fn trace(
+					&self,
+					#writer: &mut xrb::Trace,
+				) -> Result<(), cornflakes::WriteError> {
+					// `xrb::Trace` is written to with `BufMut`'s methods.
+					#[allow(unused_imports)]
+					use bytes::BufMut as _;
+
+					// Destructure the struct.
+					let Self #pat = self;
+
+					#header
+					#inner
+
+					Ok(())
+				}
+			}
+		)
+	});
+}
+
+impl TraceMessageTokens for BasicStructMetadata {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Normal);
+		});
+
+		impl_pretty_print_tokens(
+			tokens,
+			(&self.name, &self.generics),
+			pat,
+			TokenStream2::new(),
+			(items, false),
+		);
+	}
+}
+
+impl TraceMessageTokens for Request {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		let reply = self.reply_tokens();
+
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Request);
+		});
+
+		let header = TokenStream2::with_tokens(|tokens| {
+			tokens.append_tokens(|| {
+				header_trace_tokens(
+					"major_opcode",
+					"put_u8",
+					quote!(<Self as xrb::Request<#reply>>::major_opcode()),
+				)
+			});
+
+			if let Some((_, minor)) = &self.minor_opcode {
+				tokens.append_tokens(|| {
+					header_trace_tokens("minor_opcode", "put_u8", quote!(#minor))
+				});
+			} else {
+				items.metabyte_trace_tokens(tokens);
+			}
+
+			tokens.append_tokens(|| {
+				header_trace_tokens(
+					"length",
+					"put_u16",
+					quote!(<Self as xrb::Request<#reply>>::length(self)),
+				)
+			});
+		});
+
+		impl_pretty_print_tokens(
+			tokens,
+			(&self.name, &self.generics),
+			pat,
+			header,
+			(items, true),
+		);
+	}
+}
+
+impl TraceMessageTokens for Reply {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		let request = &self.request_ty;
+		let has_sequence = self.sequence_token.is_none();
+
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Reply { has_sequence });
+		});
+
+		let header = TokenStream2::with_tokens(|tokens| {
+			// `1` indicates this is a reply.
+			tokens.append_tokens(|| header_trace_tokens("reply", "put_u8", quote!(1_u8)));
+
+			items.metabyte_trace_tokens(tokens);
+
+			if has_sequence {
+				tokens.append_tokens(|| {
+					header_trace_tokens("sequence", "put_u16", quote!(*_sequence_))
+				});
+			}
+
+			tokens.append_tokens(|| {
+				header_trace_tokens(
+					"length",
+					"put_u32",
+					quote!(<Self as xrb::Reply<#request>>::length(self)),
+				)
+			});
+		});
+
+		impl_pretty_print_tokens(
+			tokens,
+			(&self.name, &self.generics),
+			pat,
+			header,
+			(items, true),
+		);
+	}
+}
+
+impl TraceMessageTokens for Event {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Event);
+		});
+
+		let header = TokenStream2::with_tokens(|tokens| {
+			tokens.append_tokens(|| {
+				header_trace_tokens("code", "put_u8", quote!(<Self as xrb::Event>::code()))
+			});
+
+			if self.is_generic() {
+				tokens.append_tokens(|| {
+					header_trace_tokens(
+						"extension",
+						"put_u8",
+						quote!(<Self as xrb::GenericEvent>::extension()),
+					)
+				});
+			} else {
+				items.metabyte_trace_tokens(tokens);
+			}
+
+			tokens
+				.append_tokens(|| header_trace_tokens("sequence", "put_u16", quote!(*_sequence_)));
+
+			if self.is_generic() {
+				tokens.append_tokens(|| {
+					let length = header_trace_tokens(
+						"length",
+						"put_u32",
+						quote!(<Self as xrb::GenericEvent>::length(self)),
+					);
+					let event_type = header_trace_tokens(
+						"event_type",
+						"put_u16",
+						quote!(<Self as xrb::GenericEvent>::event_type()),
+					);
+
+					quote!(#length #event_type)
+				});
+			}
+		});
+
+		impl_pretty_print_tokens(
+			tokens,
+			(&self.name, &self.generics),
+			pat,
+			header,
+			(items, true),
+		);
+	}
+}
+
+impl TraceMessageTokens for Message {
+	fn trace_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		let pat = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Normal);
+		});
+
+		let header = self.header_tokens(items);
+		let header = quote!(
+			#header
+			cornflakes::Writable::write_to(&header, writer)?;
+			writer.field("header", &header);
+		);
+
+		impl_pretty_print_tokens(
+			tokens,
+			(&self.name, &self.generics),
+			pat,
+			header,
+			(items, false),
+		);
+	}
+}
+
+// }}}
+
+// Constructors {{{
+
+/// The names of types whose values are allocated on the heap, and so can't
+/// be built at compile time.
+const HEAP_TYPES: [&str; 11] = [
+	"Vec", "VecDeque", "String", "Box", "Rc", "Arc", "Cow", "HashMap", "HashSet", "BTreeMap",
+	"BTreeSet",
+];
+
+/// Returns whether values of the given type can be built at compile time:
+/// that is, whether it doesn't contain any [heap-allocated types].
+///
+/// Types defined elsewhere can't be looked into, so they are assumed to be
+/// compatible unless their names say otherwise.
+///
+/// [heap-allocated types]: HEAP_TYPES
+fn is_const_compatible(r#type: &Type) -> bool {
+	match r#type {
+		Type::Path(path) => path.path.segments.iter().all(|segment| {
+			let arguments_compatible = match &segment.arguments {
+				PathArguments::AngleBracketed(args) => args.args.iter().all(|arg| match arg {
+					GenericArgument::Type(r#type) => is_const_compatible(r#type),
+					_ => true,
+				}),
+
+				_ => true,
+			};
+
+			arguments_compatible && !HEAP_TYPES.contains(&segment.ident.to_string().as_str())
+		}),
+
+		Type::Array(array) => is_const_compatible(&array.elem),
+		Type::Slice(slice) => is_const_compatible(&slice.elem),
+		Type::Reference(reference) => is_const_compatible(&reference.elem),
+		Type::Tuple(tuple) => tuple.elems.iter().all(is_const_compatible),
+		Type::Paren(paren) => is_const_compatible(&paren.elem),
+		Type::Group(group) => is_const_compatible(&group.elem),
+
+		// Trait objects, `impl Trait`, and so on.
+		_ => false,
+	}
+}
+
+impl Struct {
+	/// Generates a `const fn new` constructor taking each of this message's
+	/// fields, so that the message can be built at compile time.
+	///
+	/// The constructor is only generated where it is useful and doesn't
+	/// bypass anything: for messages (not plain structs, which may have
+	/// constructors of their own) with at least one field, none of which are
+	/// private or contain [heap-allocated types]. Messages with a sequence
+	/// field take the `sequence` first.
+	///
+	/// [heap-allocated types]: HEAP_TYPES
+	pub fn const_constructor_tokens(&self, tokens: &mut TokenStream2) {
+		if matches!(self.metadata, StructMetadata::Struct(_)) {
+			return;
+		}
+
+		let has_sequence = self.metadata.has_sequence();
+
+		let fields: Vec<_> = self
+			.items
+			.pairs()
+			.filter_map(|(id, item)| match (id, item) {
+				(ItemId::Field(id), Item::Field(field)) => Some((id, field)),
+				_ => None,
+			})
+			.collect();
+
+		let is_possible = fields.iter().all(|(id, field)| {
+			!matches!(field.vis, Visibility::Inherited)
+				&& is_const_compatible(&field.r#type)
+				// The `sequence` parameter would clash with the field's.
+				&& !(has_sequence && matches!(id, FieldId::Ident(ident) if ident == "sequence"))
+		});
+
+		if !is_possible || (fields.is_empty() && !has_sequence) {
+			return;
+		}
+
+		let names: Vec<_> = fields
+			.iter()
+			.map(|(id, _)| match id {
+				FieldId::Ident(ident) => ident.clone(),
+				FieldId::Id(index) => format_ident!("field_{}", index),
+			})
+			.collect();
+		let types = fields.iter().map(|(_, field)| &field.r#type);
+
+		let sequence_param = has_sequence.then(|| quote!(sequence: u16,));
+
+		let construct = if let Items::Unnamed { .. } = self.items {
+			let sequence = has_sequence.then(|| quote!(sequence,));
+
+			quote!(Self(#sequence #(#names),*))
+		} else {
+			let sequence = has_sequence.then(|| quote!(_sequence_: sequence,));
+
+			quote!(Self { #sequence #(#names),* })
+		};
+
+		let name = self.metadata.name();
+		let vis = self.metadata.vis();
+		let (impl_generics, ty_generics, where_clause) = self.metadata.generics().split_for_impl();
+
+		let doc = format!(
+			"Creates a new [`{name}`] from its fields.\n\nThis is a `const fn`, so it can \
+			 be used to build a [`{name}`] at compile time."
+		);
+
+		tokens.append_tokens(|| {
+			quote!(
+				impl #impl_generics #name #ty_generics #where_clause {
+					#[doc = #doc]
+					#[must_use]
+					#[allow(clippy::too_many_arguments)]
+					#vis const fn new(#sequence_param #(#names: #types),*) -> Self {
+						#construct
+					}
+				}
+			)
+		});
+	}
+}
+
+// }}}
+
+// Decoding bodies {{{
+
+/// Generates the statements which read the metabyte item, if any, from the
+/// `_metabyte_` taken from a message's header, rather than from the reader.
+fn metabyte_from_header_tokens(items: &Items) -> TokenStream2 {
+	let metabyte = TokenStream2::with_tokens(|tokens| {
+		items.metabyte_deserialize_tokens(tokens);
+	});
+
+	quote!(
+		let _metabyte_ = [_metabyte_];
+		let _body_reader_ = reader;
+
+		// Read the metabyte item from the header's metabyte, then switch back
+		// to reading the body. `Buf` is needed for the slice's methods, as it
+		// may not be in scope where the message is defined.
+		#[allow(unused_imports)]
+		use bytes::Buf as _;
+		let reader = &mut &_metabyte_[..];
+		#metabyte
+		let reader = _body_reader_;
+	)
+}
+
+/// Generates the `return` of an `xrb::UnexpectedHeader` error for a message
+/// of the given `kind`.
+fn unexpected_header_tokens(kind: &str) -> TokenStream2 {
+	quote!(
+		return Err(cornflakes::ReadError::Other(Box::new(xrb::UnexpectedHeader {
+			expected: #kind,
+			found: *header,
+		})))
+	)
+}
+
+/// Generates a `cornflakes::Readable` implementation with the given `body`,
+/// which reads from the given `reader`.
+///
+/// If any of the given `items` is a `Cow<[u8]>` field and the type has a
+/// lifetime, a `read_borrowed` function is generated too. Its body is the
+/// same, but it reads from a byte slice, so those fields are borrowed from
+/// the slice rather than copied. It is given the same `vis` as the type.
+fn impl_readable_tokens(
+	tokens: &mut TokenStream2,
+	name: &Ident,
+	vis: &Visibility,
+	generics: &Generics,
+	items: &Items,
+	reader: &Ident,
+	body: &TokenStream2,
+) {
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	tokens.append_tokens(|| {
+		quote!(
+			impl #impl_generics cornflakes::Readable for #name #ty_generics #where_clause {
+				fn read_from(
+					#reader: &mut impl bytes::Buf,
+				) -> Result<Self, cornflakes::ReadError> {
+					#body
+				}
+			}
+		)
+	});
+
+	let has_cow_bytes = items
+		.iter()
+		.any(|item| matches!(item, Item::Field(field) if is_cow_bytes(&field.r#type)));
+
+	if let Some(lifetime) = generics.lifetimes().next().filter(|_| has_cow_bytes) {
+		let lifetime = &lifetime.lifetime;
+		let doc = format!(
+			"Reads a [`{name}`] from the given byte slice, borrowing its `Cow<[u8]>` fields \
+			 from it.\n\n[`Readable::read_from`] copies those fields instead, as it can \
+			 read from any `Buf`.\n\n# Errors\nReturns the same errors as \
+			 [`Readable::read_from`].\n\n[`Readable::read_from`]: \
+			 cornflakes::Readable::read_from"
+		);
+
+		tokens.append_tokens(|| {
+			quote!(
+				impl #impl_generics #name #ty_generics #where_clause {
+					#[doc = #doc]
+					#vis fn read_borrowed(
+						#reader: &mut &#lifetime [u8],
+					) -> Result<Self, cornflakes::ReadError> {
+						#[allow(unused_imports)]
+						use bytes::Buf as _;
+
+						#body
 					}
 				}
 			)
 		});
 	}
 }
+
+/// Generates an `xrb::DecodeBody` implementation with the given `body`.
+fn impl_decode_body_tokens(
+	tokens: &mut TokenStream2,
+	name: &Ident,
+	generics: &Generics,
+	body: &TokenStream2,
+) {
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	tokens.append_tokens(|| {
+		quote!(
+			impl #impl_generics xrb::DecodeBody for #name #ty_generics #where_clause {
+				fn decode_body(
+					header: &xrb::Header,
+					reader: &mut impl bytes::Buf,
+				) -> Result<Self, cornflakes::ReadError> {
+					#body
+				}
+			}
+		)
+	});
+}
+
+/// Generates the statements to read every non-metabyte item.
+fn body_items_tokens(items: &Items) -> TokenStream2 {
+	TokenStream2::with_tokens(|tokens| {
+		for (id, item) in items.pairs().filter(|(_, item)| !item.is_metabyte()) {
+			item.deserialize_tokens(tokens, id);
+		}
+	})
+}
+
+impl Request {
+	/// Generates the `xrb::DecodeBody` implementation for this request, which
+	/// reads everything after the request's 4-byte header.
+	pub fn decode_body_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		// If the request has a minor opcode, it is in the metabyte position.
+		let metabyte = if self.minor_opcode.is_none() {
+			metabyte_from_header_tokens(items)
+		} else {
+			quote!()
+		};
+		let unexpected = unexpected_header_tokens("request");
+
+		let inner = body_items_tokens(items);
+		let cons = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Request);
+		});
+
+		let body = quote!(
+			let xrb::Header::Request {
+				metabyte: _metabyte_,
+				length: _length_,
+				..
+			} = *header else {
+				#unexpected
+			};
+
+			#metabyte
+			#inner
+
+			Ok(Self #cons)
+		);
+
+		impl_decode_body_tokens(tokens, &self.name, &self.generics, &body);
+	}
+}
+
+impl Reply {
+	/// Generates the `xrb::DecodeBody` implementation for this reply, which
+	/// reads everything after the reply's 8-byte header.
+	///
+	/// Nothing is generated if the reply has opted out of its sequence field,
+	/// as its header is then not that of other replies.
+	pub fn decode_body_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		if self.sequence_token.is_some() {
+			return;
+		}
+
+		let metabyte = metabyte_from_header_tokens(items);
+		let unexpected = unexpected_header_tokens("reply");
+
+		let inner = body_items_tokens(items);
+		let cons = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Reply { has_sequence: true });
+		});
+
+		let body = quote!(
+			let xrb::Header::Reply {
+				metabyte: _metabyte_,
+				sequence: _sequence_,
+				length: _length_,
+			} = *header else {
+				#unexpected
+			};
+
+			#metabyte
+			#inner
+
+			Ok(Self #cons)
+		);
+
+		impl_decode_body_tokens(tokens, &self.name, &self.generics, &body);
+	}
+}
+
+impl Event {
+	/// Generates the `xrb::DecodeBody` implementation for this event, which
+	/// reads everything after the event's 4-byte header, or a generic event's
+	/// 8-byte header.
+	///
+	/// An `xrb::UnexpectedEventCode` error is returned if the header is of a
+	/// different event.
+	pub fn decode_body_tokens(&self, tokens: &mut TokenStream2, items: &Items) {
+		let unexpected = unexpected_header_tokens("event");
+
+		let inner = body_items_tokens(items);
+		let cons = TokenStream2::with_tokens(|tokens| {
+			items.fields_to_tokens(tokens, ExpandMode::Event);
+		});
+
+		let header = if self.is_generic() {
+			let check = ensure_remaining_tokens(2_usize);
+
+			quote!(
+				let xrb::Header::GenericEvent {
+					sequence: _sequence_,
+					length: _length_,
+					..
+				} = *header else {
+					#unexpected
+				};
+
+				// The event type, which must have already been read to know to
+				// decode this event.
+				#check
+				let _evtype_ = reader.get_u16();
+			)
+		} else {
+			let metabyte = metabyte_from_header_tokens(items);
+
+			quote!(
+				let xrb::Header::Event {
+					code,
+					metabyte: _metabyte_,
+					sequence: _sequence_,
+					..
+				} = *header else {
+					#unexpected
+				};
+
+				if code != <Self as xrb::Event>::code() {
+					return Err(cornflakes::ReadError::Other(Box::new(xrb::UnexpectedEventCode {
+						expected: <Self as xrb::Event>::code(),
+						found: code,
+					})));
+				}
+
+				#metabyte
+			)
+		};
+
+		let body = quote!(
+			#header
+			#inner
+
+			Ok(Self #cons)
+		);
+
+		impl_decode_body_tokens(tokens, &self.name, &self.generics, &body);
+	}
+}
+
+// }}}
+
+// Round-trip tests {{{
+
+impl Struct {
+	fn roundtrip_tests_tokens(&self, tokens: &mut TokenStream2) {
+		// The code (and, for extension requests, minor opcode) at the start of
+		// a message must have already been read to know which message to read,
+		// so it is not read by the message's `read_from` implementation.
+		let header_len = match &self.metadata {
+			// The whole header of a message with a custom header is read by
+			// its `read_from` implementation.
+			StructMetadata::Struct(_) | StructMetadata::Message(_) => 0,
+
+			StructMetadata::Request(request) if request.minor_opcode.is_some() => 2,
+			StructMetadata::Request(_) | StructMetadata::Reply(_) | StructMetadata::Event(_) => 1,
+		};
+
+		roundtrip_tests_tokens(
+			tokens,
+			self.metadata.name(),
+			header_len,
+			self.crate_path.as_ref(),
+		);
+	}
+}
+
+/// Generates a `#[cfg(test)]` module containing a property test asserting
+/// that any value of the type `name` is read back as the same value once
+/// written, and that its `ByteSize` is the number of bytes written.
+///
+/// `header_len` is the number of bytes at the start of the written value
+/// which are not read by `read_from`. `cornflakes` is brought into scope from
+/// the `crate_path`, if one is given.
+///
+/// The values are generated with `proptest`, so the type must implement
+/// `proptest::arbitrary::Arbitrary`, as well as `Debug` and `PartialEq`.
+fn roundtrip_tests_tokens(
+	tokens: &mut TokenStream2,
+	name: &Ident,
+	header_len: usize,
+	crate_path: Option<&Path>,
+) {
+	let module = format_ident!("__{}_roundtrip_tests__", name);
+	let uses = crate_path.map(crate_uses_tokens);
+
+	tokens.append_tokens(|| {
+		quote!(
+			#[cfg(test)]
+			// The tests use the definition even if it is deprecated.
+			#[allow(non_snake_case, deprecated)]
+			mod #module {
+				use super::*;
+				#uses
+
+				proptest::proptest! {
+					#[test]
+					fn roundtrip(value in proptest::arbitrary::any::<#name>()) {
+						let mut bytes = Vec::new();
+						cornflakes::Writable::write_to(&value, &mut bytes).map_err(|error| {
+							proptest::test_runner::TestCaseError::fail(format!("{error:?}"))
+						})?;
+
+						proptest::prop_assert_eq!(
+							cornflakes::ByteSize::byte_size(&value),
+							bytes.len(),
+							"`byte_size` is not the number of bytes written",
+						);
+
+						let mut reader = &bytes[#header_len..];
+						let read = <#name as cornflakes::Readable>::read_from(&mut reader)
+							.map_err(|error| {
+								proptest::test_runner::TestCaseError::fail(format!("{error:?}"))
+							})?;
+
+						proptest::prop_assert!(
+							reader.is_empty(),
+							"{} bytes were written but not read",
+							reader.len(),
+						);
+						proptest::prop_assert_eq!(read, value);
+					}
+				}
+			}
+		)
+	});
+}
+
+// }}}
@@ -5,20 +5,32 @@
 #![feature(anonymous_lifetime_in_impl_trait)]
 
 mod content;
+mod debug;
 mod definition;
+mod derive;
 mod impls;
 mod ts_ext;
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::ToTokens;
-use syn::parse_macro_input;
+use syn::{parse_macro_input, DeriveInput};
 
 pub(crate) use content::*;
 pub(crate) use definition::*;
 pub(crate) use impls::*;
 pub(crate) use ts_ext::*;
 
+/// Defines structs and enums along with their `Readable`, `Writable`, and
+/// `ByteSize` implementations, and the `Request`, `Reply`, `Event`, or
+/// `Message` implementation of each message.
+///
+/// The generated code refers to `bytes::...`, `cornflakes::...`, and XRB's
+/// traits and helpers as `xrb::...`, which must be in scope wherever it is
+/// used. In crates which depend on XRB, `use xrb::prelude::*` brings `bytes`
+/// and `cornflakes` into scope, and `xrb` is the crate itself; `xrbk`'s
+/// prelude does the same for other protocols. A definition given
+/// `#[xrbk(crate = "path")]` brings all three into scope from `path` instead.
 #[proc_macro]
 pub fn define(input: TokenStream) -> TokenStream {
 	let definitions = parse_macro_input!(input as Definitions);
@@ -28,5 +40,63 @@ pub fn define(input: TokenStream) -> TokenStream {
 		definitions.impl_tokens(tokens);
 	});
 
+	debug::write_expansion(&definitions, &expanded);
+
 	expanded.into()
 }
+
+/// Derives `cornflakes::Readable` for a struct or enum, reading each field in
+/// order.
+///
+/// Enums are read from a `u8` discriminant followed by the fields of the
+/// variant with that discriminant. Every type parameter must be `Readable`.
+#[proc_macro_derive(Readable, attributes(xrbk))]
+pub fn derive_readable(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	derive::readable(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Derives `cornflakes::Writable` for a struct or enum, writing each field in
+/// order.
+///
+/// Enums are written as a `u8` discriminant followed by the fields of the
+/// variant. Every type parameter must be `Writable`.
+#[proc_macro_derive(Writable, attributes(xrbk))]
+pub fn derive_writable(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	derive::writable(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Derives `cornflakes::ByteSize` for a struct or enum, as the sum of the
+/// sizes of its fields.
+///
+/// The size of an enum includes its `u8` discriminant. Every type parameter
+/// must be `ByteSize`.
+#[proc_macro_derive(ByteSize, attributes(xrbk))]
+pub fn derive_byte_size(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	derive::byte_size(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Derives `cornflakes::StaticByteSize` for a struct or fieldless enum, as
+/// the sum of the sizes of its fields.
+///
+/// The size of a fieldless enum is that of its `u8` discriminant. Every type
+/// parameter must be `StaticByteSize`.
+#[proc_macro_derive(StaticByteSize, attributes(xrbk))]
+pub fn derive_static_byte_size(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	derive::static_byte_size(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
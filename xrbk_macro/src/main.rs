@@ -0,0 +1,355 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Expands every [`define!`] invocation and `xrbk_macro` derive in XRB's
+//! source files, writing the expanded files to `snapshot/` for XRB's
+//! `codegen-snapshot` feature.
+//!
+//! The snapshot must be regenerated whenever a definition, or the code
+//! generated for definitions, changes:
+//! ```sh
+//! cargo run -p xrbk_macro --bin xrbk-snapshot
+//! ```
+//! With `--check`, the snapshot is compared against the source files instead,
+//! failing if it is out of date.
+//!
+//! [`define!`]: https://docs.aquariwm.org/doc/xrbk_macro/macro.define.html
+
+#![feature(anonymous_lifetime_in_impl_trait)]
+
+mod content;
+mod definition;
+mod derive;
+mod impls;
+mod ts_ext;
+
+use std::{
+	error::Error,
+	fs,
+	io::Write,
+	path::{Path, PathBuf},
+	process::{Command, ExitCode, Stdio},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::{
+	parse_quote, punctuated::Punctuated, DeriveInput, File, ItemMacro, ItemUse, Token, UseTree,
+};
+
+pub(crate) use content::*;
+pub(crate) use definition::*;
+pub(crate) use impls::*;
+pub(crate) use ts_ext::*;
+
+/// The header written at the start of every file in the snapshot.
+const HEADER: &str = "\
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// @generated by `cargo run -p xrbk_macro --bin xrbk-snapshot` from the file of
+// the same path in `src/`. Do not edit this file directly.
+
+";
+
+/// Generates the implementations derived for the given item.
+type Derive = fn(&DeriveInput) -> syn::Result<TokenStream2>;
+
+/// The derive macros provided by `xrbk_macro`, which are expanded along with
+/// the definitions.
+const DERIVES: &[(&str, Derive)] = &[
+	("Readable", derive::readable),
+	("Writable", derive::writable),
+	("ByteSize", derive::byte_size),
+	("StaticByteSize", derive::static_byte_size),
+];
+
+fn main() -> ExitCode {
+	let check = std::env::args().any(|arg| arg == "--check");
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.expect("`xrbk_macro` is in XRB's workspace");
+
+	match snapshot(root, check) {
+		Ok(stale) if stale.is_empty() => ExitCode::SUCCESS,
+
+		Ok(stale) => {
+			for path in stale {
+				eprintln!("out of date: {}", path.display());
+			}
+			eprintln!("run `cargo run -p xrbk_macro --bin xrbk-snapshot` to update the snapshot");
+
+			ExitCode::FAILURE
+		}
+
+		Err(error) => {
+			eprintln!("error: {error}");
+
+			ExitCode::FAILURE
+		}
+	}
+}
+
+/// Expands the definitions and derives in every source file in `root/src`,
+/// writing them to `root/snapshot`.
+///
+/// If `check` is `true`, nothing is written; instead, the paths of snapshot
+/// files which differ from what would have been written are returned.
+fn snapshot(root: &Path, check: bool) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+	let src = root.join("src");
+	let mut stale = vec![];
+
+	for path in source_files(&src)? {
+		let source = fs::read_to_string(&path)?;
+
+		// Only files which might use `xrbk_macro` are parsed.
+		if !source.contains("xrbk_macro") {
+			continue;
+		}
+
+		let mut file = syn::parse_file(&source)
+			.map_err(|error| format!("failed to parse {}: {error}", path.display()))?;
+
+		if !expand(&mut file.items)? {
+			continue;
+		}
+
+		let target = root.join("snapshot").join(path.strip_prefix(&src)?);
+		locate_modules(&mut file.items, root, &path, &target)?;
+
+		let expanded = format!("{HEADER}{}", rustfmt(root, &file.into_token_stream())?);
+
+		if check {
+			if fs::read_to_string(&target).ok().as_deref() != Some(&*expanded) {
+				stale.push(target);
+			}
+		} else {
+			fs::create_dir_all(target.parent().expect("`target` is within `snapshot/`"))?;
+			fs::write(target, expanded)?;
+		}
+	}
+
+	Ok(stale)
+}
+
+/// Returns the paths of every Rust source file within `dir`, sorted.
+fn source_files(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+	let mut files = vec![];
+
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+
+		if path.is_dir() {
+			files.extend(source_files(&path)?);
+		} else if path.extension().is_some_and(|extension| extension == "rs") {
+			files.push(path);
+		}
+	}
+
+	files.sort();
+	Ok(files)
+}
+
+/// Replaces every `define!` invocation in the given `items` with its
+/// expansion, and every `xrbk_macro` derive with the implementations it
+/// derives, including within inline modules.
+///
+/// Imports from `xrbk_macro` and `#[cfg(test)]` items are removed, as they are
+/// not needed once the definitions have been expanded. Returns whether any
+/// definitions or derives were expanded.
+fn expand(items: &mut Vec<syn::Item>) -> syn::Result<bool> {
+	let mut expanded = false;
+
+	for item in std::mem::take(items) {
+		match item {
+			syn::Item::Macro(ItemMacro { mac, .. }) if mac.path.is_ident("define") => {
+				let definitions: Definitions = syn::parse2(mac.tokens)?;
+
+				let tokens = TokenStream2::with_tokens(|tokens| {
+					definitions.to_tokens(tokens);
+					definitions.impl_tokens(tokens);
+				});
+
+				// The definitions may use `xrbk_macro` derives themselves.
+				let mut expansion = syn::parse2::<File>(tokens)?.items;
+				expand(&mut expansion)?;
+
+				items.extend(expansion);
+				expanded = true;
+			}
+
+			syn::Item::Use(r#use) if is_macro_import(&r#use) => {}
+
+			syn::Item::Mod(module) if is_test(&module.attrs) => {}
+			syn::Item::Fn(function) if is_test(&function.attrs) => {}
+
+			item @ (syn::Item::Struct(_) | syn::Item::Enum(_)) => {
+				let derived = expand_derives(item)?;
+
+				expanded |= derived.len() > 1;
+				items.extend(derived);
+			}
+
+			syn::Item::Mod(mut module) => {
+				if let Some((_, content)) = &mut module.content {
+					expanded |= expand(content)?;
+				}
+
+				items.push(syn::Item::Mod(module));
+			}
+
+			item => items.push(item),
+		}
+	}
+
+	Ok(expanded)
+}
+
+/// Removes the `xrbk_macro` derives from the given struct or enum `item`,
+/// returning it followed by the implementations they derive.
+fn expand_derives(mut item: syn::Item) -> syn::Result<Vec<syn::Item>> {
+	let attrs = match &mut item {
+		syn::Item::Struct(r#struct) => &mut r#struct.attrs,
+		syn::Item::Enum(r#enum) => &mut r#enum.attrs,
+
+		_ => unreachable!("only structs and enums are derived"),
+	};
+
+	let mut derives: Vec<Derive> = vec![];
+
+	for attr in std::mem::take(attrs) {
+		if !attr.path.is_ident("derive") {
+			attrs.push(attr);
+			continue;
+		}
+
+		let (ours, others): (Vec<_>, Vec<_>) = attr
+			.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)?
+			.into_iter()
+			.partition(|path| DERIVES.iter().any(|(name, _)| path.is_ident(name)));
+
+		derives.extend(ours.iter().filter_map(|path| {
+			DERIVES
+				.iter()
+				.find(|(name, _)| path.is_ident(name))
+				.map(|&(_, derive)| derive)
+		}));
+
+		if !others.is_empty() {
+			attrs.push(parse_quote!(#[derive(#(#others),*)]));
+		}
+	}
+
+	let input: DeriveInput = syn::parse2(item.to_token_stream())?;
+	let mut items = vec![item];
+
+	for derive in derives {
+		items.extend(syn::parse2::<File>(derive(&input)?)?.items);
+	}
+
+	Ok(items)
+}
+
+/// Gives each module declared in the given `items` without a path the path
+/// of its file in `src/`, relative to the `target` snapshot file of the
+/// source file at `path`.
+///
+/// Otherwise, the modules declared by a snapshot file would be looked for
+/// within `snapshot/`, where only the files containing definitions are.
+/// Modules which are themselves expanded into the snapshot are given their
+/// snapshot path with `cfg_attr` in `src/`, which is left as it is.
+fn locate_modules(
+	items: &mut [syn::Item],
+	root: &Path,
+	path: &Path,
+	target: &Path,
+) -> Result<(), Box<dyn Error>> {
+	// The directory containing the files of the modules declared in `path`.
+	let dir = if path.ends_with("mod.rs") {
+		path.parent().expect("`path` is a file").to_owned()
+	} else {
+		path.with_extension("")
+	};
+
+	// The path from the directory of `target` back to `root`.
+	let up = "../".repeat(
+		target
+			.parent()
+			.expect("`target` is a file")
+			.strip_prefix(root)?
+			.components()
+			.count(),
+	);
+
+	for item in items {
+		let syn::Item::Mod(module) = item else {
+			continue;
+		};
+
+		if module.content.is_some() || has_path(&module.attrs) {
+			continue;
+		}
+
+		let name = module.ident.to_string();
+		let file = [
+			dir.join(format!("{name}.rs")),
+			dir.join(&name).join("mod.rs"),
+		]
+		.into_iter()
+		.find(|file| file.exists())
+		.ok_or_else(|| format!("no file found for module `{name}` in {}", dir.display()))?;
+
+		let relative = format!("{up}{}", file.strip_prefix(root)?.display());
+		module.attrs.push(parse_quote!(#[path = #relative]));
+	}
+
+	Ok(())
+}
+
+/// Returns whether the given attributes give a module's path, including with
+/// `cfg_attr`.
+fn has_path(attrs: &[syn::Attribute]) -> bool {
+	attrs.iter().any(|attr| {
+		attr.path.is_ident("path")
+			|| (attr.path.is_ident("cfg_attr") && attr.tokens.to_string().contains("path"))
+	})
+}
+
+/// Returns whether the given `use` item imports from `xrbk_macro`.
+fn is_macro_import(r#use: &ItemUse) -> bool {
+	matches!(&r#use.tree, UseTree::Path(path) if path.ident == "xrbk_macro")
+}
+
+/// Returns whether the given attributes include `#[cfg(test)]`.
+fn is_test(attrs: &[syn::Attribute]) -> bool {
+	attrs
+		.iter()
+		.any(|attr| attr.path.is_ident("cfg") && attr.tokens.to_string() == "(test)")
+}
+
+/// Formats the given `tokens` with `rustfmt`, using XRB's configuration.
+fn rustfmt(root: &Path, tokens: &TokenStream2) -> Result<String, Box<dyn Error>> {
+	let mut rustfmt = Command::new("rustfmt")
+		.args(["--edition", "2021"])
+		.current_dir(root)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.spawn()
+		.map_err(|error| format!("failed to run rustfmt: {error}"))?;
+
+	rustfmt
+		.stdin
+		.take()
+		.expect("stdin is piped")
+		.write_all(tokens.to_string().as_bytes())?;
+
+	let output = rustfmt.wait_with_output()?;
+
+	if !output.status.success() {
+		return Err("rustfmt failed to format the expanded definitions".into());
+	}
+
+	Ok(String::from_utf8(output.stdout)?)
+}
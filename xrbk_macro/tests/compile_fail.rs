@@ -0,0 +1,17 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Checks that misuse of [`define!`] is reported by the macro itself, with an
+//! error pointing at the offending tokens, rather than as errors in the code
+//! it would have generated.
+//!
+//! The expected errors are in `tests/ui/*.stderr`. After changing an error,
+//! run with `TRYBUILD=overwrite` to update them.
+//!
+//! [`define!`]: xrbk_macro::define
+
+#[test]
+fn misuse_is_reported() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
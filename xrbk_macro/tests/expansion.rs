@@ -0,0 +1,1147 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Expands representative definitions with [`define!`] while denying all
+//! warnings, so that any lint triggered by the generated code fails the build.
+
+#![feature(doc_notable_trait)]
+#![deny(warnings)]
+#![deny(clippy::all)]
+
+// The generated code refers to the message traits, read errors, traces, and
+// length arithmetic as `xrb::...`. Not all of them are used by these tests.
+#[allow(dead_code)]
+mod xrb {
+	include!("../../src/x11/traits.rs");
+	include!("../../src/x11/read.rs");
+
+	mod trace {
+		include!("../../src/x11/trace.rs");
+	}
+	pub use trace::*;
+
+	pub mod util {
+		include!("../../src/util.rs");
+	}
+
+	// Brought into scope by definitions given `#[xrbk(crate = "crate::xrb")]`.
+	pub use bytes;
+	pub use cornflakes;
+}
+
+use std::borrow::Cow;
+
+use bytes::{Buf, BufMut};
+use cornflakes::{ByteSize, ReadError, Readable, StaticByteSize, Writable, WriteError};
+use proptest::{prelude::*, strategy::LazyJust};
+use xrb::{
+	DecodeBody, Event, GenericEvent, Header, Message, MessageHeader, PrettyPrint, Reply, Request,
+	Trace, WireEnum,
+};
+use xrbk_macro::{define, ByteSize, Readable, StaticByteSize, Writable};
+
+const MY_EXTENSION: u8 = 130;
+
+define! {
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	pub struct Point {
+		pub x: i16,
+		pub y: i16,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[cfg_attr(test, derive_roundtrip_tests)]
+	pub struct Dimensions(pub u16, pub u16);
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Nothing;
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Polyline {
+		let points_len: u32 = self => self.points.len() as u32,
+		#[context(points_len => *points_len as usize)]
+		pub points: Vec<Point>,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub enum Direction {
+		Up = 1,
+		Down,
+		Left = 10,
+		Right,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub enum Rotation: u16 {
+		Rotate0 = 0x01,
+		Rotate90 = 0x02,
+		Rotate180 = 0x04,
+		Rotate270 = 0x08,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub enum DeviceUse: u32 {
+		Pointer,
+		Keyboard,
+		Extension(u16),
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub enum Shape {
+		Square(Point, u16),
+		Circle {
+			center: Point,
+			radius: u16,
+		},
+		Empty,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct GetName: Request<120> -> GetNameReply {
+		#[metabyte]
+		pub detail: u8,
+		pub point: Point,
+		let name_len: u16 = self => self.name.len() as u16,
+		[(); 2],
+		#[context(name_len => *name_len as usize)]
+		pub name: Vec<u8>,
+		[(); name_len => xrb::util::pad(usize::from(*name_len))],
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct SetName: Request<122> {
+		let name_len: u16 = self => self.name.len() as u16,
+		[(); 2],
+		#[context(name_len => usize::from(*name_len))]
+		pub name: Vec<u8>,
+		[(); ..],
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Route {
+		let points_len: u16,
+		[(); 2],
+		#[length(points_len)]
+		pub points: Vec<Point>,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Codes(let len: u8, #[length(len)] pub Vec<u8>);
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct SetGeometry: Request<123> {
+		let mask: u8 = self => u8::from(self.x.is_some()) | u8::from(self.width.is_some()) << 1,
+		#[present_if(mask => mask & 1 != 0)]
+		pub x: Option<i16>,
+		#[present_if(mask => mask & 2 != 0)]
+		pub width: Option<u16>,
+		pub labelled: bool,
+		#[present_if(labelled => *labelled)]
+		pub label: Option<u32>,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[repr(u8)]
+	pub enum Change: match u8 {
+		Moved { x: i16, y: i16 },
+		Resized { width: u16, height: u16 },
+		Closed = 4,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Notify {
+		let kind: u8,
+		pub window: u32,
+		#[discriminant(kind)]
+		pub change: Change,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Blob<'a> {
+		let len: u32 = self => self.data.len() as u32,
+		#[context(len => *len as usize)]
+		pub data: Cow<'a, [u8]>,
+		[(); ..],
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct GetNameReply: Reply for GetName {
+		pub count: u32,
+		[(); 20],
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[derive_roundtrip_tests]
+	pub struct QueryVersion: Request<MY_EXTENSION, 0> -> QueryVersionReply;
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct QueryVersionReply: Reply(?sequence) for QueryVersion;
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[deprecated_protocol = "use `GetName` instead"]
+	pub struct GetOldName: Request<121>;
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[cfg_attr(test, derive_roundtrip_tests)]
+	pub struct Motion: Event<6> {
+		#[metabyte]
+		pub detail: u8,
+		pub point: Point,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Unmapped: Event<18>;
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Moved: Event<22>(pub Point);
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[deprecated_protocol]
+	pub struct Obsolete: Event<23>;
+
+	#[derive(Debug, PartialEq, Eq)]
+	pub struct Changed: Event<35> + generic(MY_EXTENSION, 3) {
+		let len: u32 = self => self.values.len() as u32,
+		#[context(len => *len as usize)]
+		pub values: Vec<u32>,
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	#[derive_roundtrip_tests]
+	pub struct Query: Message<XdmcpHeader, 2> {
+		let names_len: u8 = self => self.names.len() as u8,
+		#[context(names_len => *names_len as usize)]
+		pub names: Vec<u8>,
+	}
+}
+
+// Plain types which derive their (de)serialization without `define!`.
+
+#[derive(Debug, PartialEq, Eq, Readable, Writable, ByteSize, StaticByteSize)]
+pub struct Pair<T> {
+	pub first: T,
+	pub r#type: T,
+}
+
+#[derive(Debug, PartialEq, Eq, Readable, Writable, ByteSize, StaticByteSize)]
+pub struct Marker;
+
+#[derive(Debug, PartialEq, Eq, Readable, Writable, ByteSize)]
+pub enum Stroke<T> {
+	Blank,
+	Dot(T),
+	Line { from: Pair<T>, to: Pair<T> },
+}
+
+#[derive(Debug, PartialEq, Eq, Readable, Writable, ByteSize, StaticByteSize)]
+pub enum Level {
+	Low = 3,
+	High,
+}
+
+/// Definitions made where `xrb` isn't in scope, which refer to it by the path
+/// given with `#[xrbk(crate = "...")]` instead.
+mod renamed {
+	use xrbk_macro::{define, Readable, StaticByteSize, Writable};
+
+	define! {
+		#[xrbk(crate = "crate::xrb")]
+		#[derive(Debug, PartialEq, Eq)]
+		pub struct Padded {
+			pub value: u16,
+			[(); 2],
+		}
+
+		#[xrbk(crate = "crate::xrb")]
+		#[derive(Debug, PartialEq, Eq)]
+		pub struct Ping: Request<125>;
+
+		#[xrbk(crate = "crate::xrb")]
+		#[derive(Debug, PartialEq, Eq)]
+		pub enum Mode: u16 {
+			Off,
+			On,
+		}
+
+		#[xrbk(crate = "crate::xrb")]
+		#[derive(Debug, PartialEq, Eq, StaticByteSize)]
+		pub struct Size {
+			pub width: u16,
+			pub height: u16,
+		}
+	}
+
+	#[derive(Debug, PartialEq, Eq, Readable, Writable)]
+	#[xrbk(crate = "crate::xrb")]
+	pub struct Derived(pub u32);
+}
+
+/// An XDMCP-style header: a version, the message's code, and the length of
+/// the rest of the message.
+#[derive(Debug, PartialEq, Eq)]
+pub struct XdmcpHeader {
+	version: u16,
+	code: u16,
+	length: u16,
+}
+
+impl MessageHeader for XdmcpHeader {
+	type Code = u16;
+
+	fn new(code: u16, content_len: usize) -> Self {
+		Self {
+			version: 1,
+			code,
+			length: content_len as u16,
+		}
+	}
+
+	fn code(&self) -> u16 {
+		self.code
+	}
+
+	fn content_len(&self) -> usize {
+		self.length.into()
+	}
+}
+
+impl ByteSize for XdmcpHeader {
+	fn byte_size(&self) -> usize {
+		6
+	}
+}
+
+impl Writable for XdmcpHeader {
+	fn write_to(&self, writer: &mut impl BufMut) -> Result<(), WriteError> {
+		writer.put_u16(self.version);
+		writer.put_u16(self.code);
+		writer.put_u16(self.length);
+
+		Ok(())
+	}
+}
+
+impl Readable for XdmcpHeader {
+	fn read_from(reader: &mut impl Buf) -> Result<Self, ReadError> {
+		if reader.remaining() < 6 {
+			return Err(ReadError::Other(Box::new(xrb::UnexpectedEndOfData {
+				expected: 6,
+				found: reader.remaining(),
+			})));
+		}
+
+		Ok(Self {
+			version: reader.get_u16(),
+			code: reader.get_u16(),
+			length: reader.get_u16(),
+		})
+	}
+}
+
+// The round-trip tests generated for `#[derive_roundtrip_tests]` need values
+// to be generated and their sizes to be known.
+
+impl ByteSize for Dimensions {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+
+impl Arbitrary for Dimensions {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with((): ()) -> Self::Strategy {
+		any::<(u16, u16)>()
+			.prop_map(|(width, height)| Self(width, height))
+			.boxed()
+	}
+}
+
+impl ByteSize for QueryVersion {
+	fn byte_size(&self) -> usize {
+		4
+	}
+}
+
+impl Arbitrary for QueryVersion {
+	type Parameters = ();
+	type Strategy = LazyJust<Self, fn() -> Self>;
+
+	fn arbitrary_with((): ()) -> Self::Strategy {
+		LazyJust::new(|| Self)
+	}
+}
+
+impl ByteSize for Query {
+	fn byte_size(&self) -> usize {
+		7 + self.names.len()
+	}
+}
+
+impl Arbitrary for Query {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with((): ()) -> Self::Strategy {
+		proptest::collection::vec(any::<u8>(), 0..=255)
+			.prop_map(|names| Self { names })
+			.boxed()
+	}
+}
+
+impl ByteSize for Motion {
+	fn byte_size(&self) -> usize {
+		8
+	}
+}
+
+impl Arbitrary for Motion {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with((): ()) -> Self::Strategy {
+		any::<(u16, u8, i16, i16)>()
+			.prop_map(|(sequence, detail, x, y)| Self {
+				_sequence_: sequence,
+				detail,
+				point: Point { x, y },
+			})
+			.boxed()
+	}
+}
+
+#[test]
+fn enum_discriminants_follow_explicit_ones() {
+	let mut bytes = vec![];
+
+	for direction in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+		direction.write_to(&mut bytes).unwrap();
+	}
+
+	assert_eq!(bytes, [1, 2, 10, 11]);
+	assert_eq!(Direction::read_from(&mut &[11][..]).unwrap(), Direction::Right);
+}
+
+#[test]
+fn wire_enums_expose_their_discriminants() {
+	assert_eq!(Direction::WIDTH, 1);
+	assert_eq!(Direction::Down.raw(), 2);
+	assert_eq!(Direction::Right.raw(), 11);
+
+	assert_eq!(Shape::Square(Point { x: 0, y: 0 }, 1).raw(), 0);
+	assert_eq!(
+		Shape::Circle {
+			center: Point { x: 0, y: 0 },
+			radius: 1,
+		}
+		.raw(),
+		1
+	);
+	assert_eq!(Shape::Empty.raw(), 2);
+}
+
+#[test]
+fn enums_are_written_with_their_discriminant_type() {
+	let mut bytes = vec![];
+	Rotation::Rotate270.write_to(&mut bytes).unwrap();
+	DeviceUse::Extension(7).write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes, [0, 8, 0, 0, 0, 2, 0, 7]);
+	assert_eq!((Rotation::WIDTH, DeviceUse::WIDTH), (2, 4));
+	assert_eq!(Rotation::Rotate180.raw(), 4);
+
+	assert_eq!(
+		Rotation::read_from(&mut &[0, 2][..]).unwrap(),
+		Rotation::Rotate90
+	);
+	assert_eq!(
+		DeviceUse::read_from(&mut &bytes[2..]).unwrap(),
+		DeviceUse::Extension(7)
+	);
+
+	// Too few bytes for the discriminant.
+	assert!(Rotation::read_from(&mut &[0][..]).is_err());
+	// Unrecognized discriminants too wide for the error are saturated.
+	assert!(matches!(
+		DeviceUse::read_from(&mut &[0, 0, 1, 0][..]),
+		Err(ReadError::UnrecognizedDiscriminant(u8::MAX))
+	));
+}
+
+#[test]
+fn enums_round_trip() {
+	for shape in [
+		Shape::Square(Point { x: 1, y: -1 }, 5),
+		Shape::Circle {
+			center: Point { x: 3, y: 4 },
+			radius: 2,
+		},
+		Shape::Empty,
+	] {
+		let mut bytes = vec![];
+		shape.write_to(&mut bytes).unwrap();
+
+		assert_eq!(Shape::read_from(&mut &bytes[..]).unwrap(), shape);
+	}
+}
+
+#[test]
+fn structs_round_trip() {
+	let mut bytes = vec![];
+
+	Dimensions(640, 480).write_to(&mut bytes).unwrap();
+	Nothing.write_to(&mut bytes).unwrap();
+
+	let reader = &mut &bytes[..];
+
+	assert_eq!(Dimensions::read_from(reader).unwrap(), Dimensions(640, 480));
+	assert_eq!(Nothing::read_from(reader).unwrap(), Nothing);
+}
+
+#[test]
+fn derived_types_round_trip() {
+	let line = Stroke::Line {
+		from: Pair { first: 1_i16, r#type: -1 },
+		to: Pair { first: 2, r#type: 3 },
+	};
+
+	let mut bytes = vec![];
+	line.write_to(&mut bytes).unwrap();
+	Marker.write_to(&mut bytes).unwrap();
+	Level::High.write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes, [2, 0, 1, 0xff, 0xff, 0, 2, 0, 3, 4]);
+	assert_eq!(line.byte_size(), 9);
+	assert_eq!((Marker.byte_size(), Level::Low.byte_size()), (0, 1));
+	assert_eq!(Stroke::<u32>::Dot(7).byte_size(), 5);
+
+	assert_eq!(Pair::<u32>::static_byte_size(), 8);
+	assert_eq!((Marker::static_byte_size(), Level::static_byte_size()), (0, 1));
+
+	let reader = &mut &bytes[..];
+
+	assert_eq!(Stroke::read_from(reader).unwrap(), line);
+	assert_eq!(Marker::read_from(reader).unwrap(), Marker);
+	assert_eq!(Level::read_from(reader).unwrap(), Level::High);
+
+	assert!(matches!(
+		Level::read_from(&mut &[0][..]),
+		Err(ReadError::UnrecognizedDiscriminant(0))
+	));
+	// Too few bytes for the discriminant.
+	assert!(Level::read_from(&mut &[][..]).is_err());
+}
+
+#[test]
+fn crate_paths_can_be_given() {
+	let padded = renamed::Padded { value: 2 };
+
+	let mut bytes = vec![];
+	padded.write_to(&mut bytes).unwrap();
+	renamed::Ping.write_to(&mut bytes).unwrap();
+	renamed::Mode::On.write_to(&mut bytes).unwrap();
+	renamed::Derived(3).write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes.len(), 4 + 4 + 2 + 4);
+	assert_eq!(renamed::Ping::major_opcode(), 125);
+	assert_eq!(renamed::Size::static_byte_size(), 4);
+
+	let reader = &mut &bytes[..];
+
+	assert_eq!(renamed::Padded::read_from(reader).unwrap(), padded);
+	reader.advance(4);
+	assert_eq!(renamed::Mode::read_from(reader).unwrap(), renamed::Mode::On);
+	assert_eq!(renamed::Derived::read_from(reader).unwrap(), renamed::Derived(3));
+}
+
+#[test]
+fn request_round_trips() {
+	let request = GetName {
+		detail: 7,
+		point: Point { x: 10, y: 20 },
+		name: b"hello".to_vec(),
+	};
+
+	let mut bytes = vec![];
+	request.write_to(&mut bytes).unwrap();
+
+	// opcode, detail, length (2), point (4), name_len (2), unused (2), name
+	// (5), padding (3)
+	assert_eq!(bytes.len(), 20);
+	assert_eq!(bytes[..2], [120, 7]);
+
+	// The major opcode has already been read to know which request to read.
+	assert_eq!(GetName::read_from(&mut &bytes[1..]).unwrap(), request);
+}
+
+#[test]
+fn lists_are_padded_to_four_bytes() {
+	for (name, len) in [(&b"hello"[..], 16), (&b"name"[..], 12)] {
+		let request = SetName { name: name.to_vec() };
+
+		let mut bytes = vec![];
+		request.write_to(&mut bytes).unwrap();
+
+		// opcode, unused, length (2), name_len (2), unused (2), name, padding
+		assert_eq!(bytes.len(), len);
+		assert_eq!(SetName::read_from(&mut &bytes[1..]).unwrap(), request);
+	}
+}
+
+#[test]
+fn lengths_are_written_from_their_lists() {
+	let route = Route {
+		points: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+	};
+
+	let mut bytes = vec![];
+	route.write_to(&mut bytes).unwrap();
+
+	// points_len (2), unused (2), points (2 * 4)
+	assert_eq!(bytes.len(), 12);
+	assert_eq!(bytes[..2], 2_u16.to_be_bytes());
+	assert_eq!(Route::read_from(&mut &bytes[..]).unwrap(), route);
+
+	let codes = Codes(vec![7, 8, 9]);
+
+	let mut bytes = vec![];
+	codes.write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes, [3, 7, 8, 9]);
+	assert_eq!(Codes::read_from(&mut &bytes[..]).unwrap(), codes);
+}
+
+#[test]
+fn optional_fields_are_only_present_if_their_condition_holds() {
+	let request = SetGeometry {
+		x: None,
+		width: Some(640),
+		labelled: true,
+		label: Some(7),
+	};
+
+	let mut bytes = vec![];
+	request.write_to(&mut bytes).unwrap();
+
+	// opcode, unused, length (2), mask, width (2), labelled, label (4)
+	assert_eq!(bytes.len(), 12);
+	assert_eq!(bytes[4], 0b10);
+	assert_eq!(SetGeometry::read_from(&mut &bytes[1..]).unwrap(), request);
+
+	// Nothing is read for `label` if `labelled` is false.
+	let bytes = [0, 0, 2, 0b01, 0xff, 0xfe, 0, 0];
+	assert_eq!(
+		SetGeometry::read_from(&mut &bytes[..]).unwrap(),
+		SetGeometry {
+			x: Some(-2),
+			width: None,
+			labelled: false,
+			label: None,
+		}
+	);
+}
+
+#[test]
+fn match_enums_are_read_with_an_earlier_discriminant() {
+	let notify = Notify {
+		window: 7,
+		change: Change::Moved { x: 1, y: -1 },
+	};
+
+	let mut bytes = vec![];
+	notify.write_to(&mut bytes).unwrap();
+
+	// kind, window (4), x (2), y (2)
+	assert_eq!(bytes, [0, 0, 0, 0, 7, 0, 1, 0xff, 0xff]);
+	assert_eq!(Notify::read_from(&mut &bytes[..]).unwrap(), notify);
+
+	for (change, kind, len) in [
+		(
+			Change::Resized {
+				width: 640,
+				height: 480,
+			},
+			1,
+			9,
+		),
+		(Change::Closed, 4, 5),
+	] {
+		let notify = Notify { window: 7, change };
+
+		let mut bytes = vec![];
+		notify.write_to(&mut bytes).unwrap();
+
+		assert_eq!((bytes[0], bytes.len()), (kind, len));
+		assert_eq!(Notify::read_from(&mut &bytes[..]).unwrap(), notify);
+	}
+
+	assert!(matches!(
+		Notify::read_from(&mut &[2, 0, 0, 0, 7][..]),
+		Err(ReadError::UnrecognizedDiscriminant(2))
+	));
+}
+
+#[test]
+fn cow_bytes_are_borrowed_from_byte_slices() {
+	let blob = Blob {
+		data: Cow::Owned(b"hello".to_vec()),
+	};
+
+	let mut bytes = vec![];
+	blob.write_to(&mut bytes).unwrap();
+
+	// len (4), data (5), padding (3)
+	assert_eq!(bytes.len(), 12);
+
+	let mut reader = &bytes[..];
+	let borrowed = Blob::read_borrowed(&mut reader).unwrap();
+	assert!(matches!(borrowed.data, Cow::Borrowed(b"hello")));
+	assert!(reader.is_empty());
+
+	// Any other reader has its bytes copied.
+	let owned = Blob::read_from(&mut bytes::Bytes::from(bytes.clone())).unwrap();
+	assert!(matches!(owned.data, Cow::Owned(_)));
+	assert_eq!(owned, blob);
+}
+
+#[test]
+fn nonzero_padding_is_only_rejected_when_strict() {
+	let request = GetName {
+		detail: 7,
+		point: Point { x: 10, y: 20 },
+		name: b"hello".to_vec(),
+	};
+
+	let mut bytes = vec![];
+	request.write_to(&mut bytes).unwrap();
+	// Set the last byte of the padding after the name.
+	bytes[19] = 0xff;
+
+	assert_eq!(GetName::read_from(&mut &bytes[1..]).unwrap(), request);
+
+	let Err(ReadError::Other(error)) = xrb::Strictness::Strict.read::<GetName>(&mut &bytes[1..])
+	else {
+		panic!("a request with nonzero padding was read strictly");
+	};
+
+	assert_eq!(
+		error.downcast_ref::<xrb::NonzeroPadding>(),
+		Some(&xrb::NonzeroPadding {
+			index: 2,
+			byte: 0xff
+		})
+	);
+}
+
+#[test]
+fn minor_opcode_is_written_in_metabyte_position() {
+	let mut bytes = vec![];
+	QueryVersion.write_to(&mut bytes).unwrap();
+
+	assert_eq!(<QueryVersion as Request<QueryVersionReply>>::minor_opcode(), Some(0));
+	assert_eq!(bytes[..2], [MY_EXTENSION, 0]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_messages_carry_their_notes() {
+	assert_eq!(<GetOldName as Request>::deprecation(), Some("use `GetName` instead"));
+	assert!(<Obsolete as Event>::deprecation().is_some());
+	assert_eq!(<GetName as Request<GetNameReply>>::deprecation(), None);
+
+	// Deprecated messages are still written as usual.
+	let mut bytes = vec![];
+	GetOldName.write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes[0], 121);
+}
+
+#[test]
+fn replies_have_sequence_fields_unless_opted_out() {
+	let reply = GetNameReply {
+		_sequence_: 42,
+		count: 3,
+	};
+
+	assert_eq!(reply.sequence(), Some(42));
+	assert_eq!(QueryVersionReply.sequence(), None);
+
+	let mut bytes = vec![];
+	reply.write_to(&mut bytes).unwrap();
+
+	assert_eq!(GetNameReply::read_from(&mut &bytes[1..]).unwrap(), reply);
+}
+
+#[test]
+fn events_have_sequence_fields() {
+	let motion = Motion {
+		_sequence_: 1,
+		detail: 2,
+		point: Point { x: 3, y: 4 },
+	};
+
+	assert_eq!(motion.sequence(), 1);
+	assert_eq!(Unmapped { _sequence_: 2 }.sequence(), 2);
+	assert_eq!(Moved(3, Point { x: 0, y: 0 }).sequence(), 3);
+
+	let mut bytes = vec![];
+	motion.write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes[..4], [6, 2, 0, 1]);
+	assert_eq!(Motion::read_from(&mut &bytes[1..]).unwrap(), motion);
+}
+
+// Messages without heap-allocated fields can be built at compile time.
+const MOTION: Motion = Motion::new(1, 2, Point { x: 3, y: 4 });
+const UNMAPPED: Unmapped = Unmapped::new(2);
+const MOVED: Moved = Moved::new(3, Point { x: 0, y: 0 });
+
+#[test]
+fn fixed_size_messages_have_const_constructors() {
+	assert_eq!(
+		MOTION,
+		Motion {
+			_sequence_: 1,
+			detail: 2,
+			point: Point { x: 3, y: 4 },
+		}
+	);
+	assert_eq!(UNMAPPED, Unmapped { _sequence_: 2 });
+	assert_eq!(MOVED, Moved(3, Point { x: 0, y: 0 }));
+
+	assert_eq!(
+		GetNameReply::new(42, 3),
+		GetNameReply {
+			_sequence_: 42,
+			count: 3,
+		}
+	);
+}
+
+#[test]
+fn generic_event_length_counts_units_beyond_32_bytes() {
+	let event = Changed {
+		_sequence_: 0,
+		values: vec![0; 10],
+	};
+
+	// 10 (header) + 4 (len) + 40 (values) = 54 bytes
+	assert_eq!(event.length(), 5);
+	assert_eq!(Changed::extension(), MY_EXTENSION);
+	assert_eq!(<Changed as Event>::code(), 35);
+
+	let mut bytes = vec![];
+	event.write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes[..2], [35, MY_EXTENSION]);
+	assert_eq!(Changed::read_from(&mut &bytes[1..]).unwrap(), event);
+}
+
+#[test]
+fn bodies_are_decoded_after_their_headers() {
+	let request = GetName {
+		detail: 7,
+		point: Point { x: 10, y: 20 },
+		name: b"hello".to_vec(),
+	};
+	let mut bytes = vec![];
+	request.write_to(&mut bytes).unwrap();
+
+	let header = Header::parse_request(&bytes[..]).unwrap();
+	assert_eq!(GetName::decode_body(&header, &mut &bytes[header.len()..]).unwrap(), request);
+
+	let reply = GetNameReply {
+		_sequence_: 42,
+		count: 3,
+	};
+	let mut bytes = vec![];
+	reply.write_to(&mut bytes).unwrap();
+
+	let reply_header = Header::parse(&bytes[..]).unwrap();
+	assert_eq!(reply_header.message_len(), bytes.len());
+	assert_eq!(
+		GetNameReply::decode_body(&reply_header, &mut &bytes[reply_header.len()..]).unwrap(),
+		reply
+	);
+
+	let motion = Motion {
+		_sequence_: 1,
+		detail: 2,
+		point: Point { x: 3, y: 4 },
+	};
+	let mut bytes = vec![];
+	motion.write_to(&mut bytes).unwrap();
+
+	let header = Header::parse(&bytes[..]).unwrap();
+	assert_eq!(Motion::decode_body(&header, &mut &bytes[header.len()..]).unwrap(), motion);
+
+	// A different event's header has a different code.
+	let Err(ReadError::Other(error)) = Moved::decode_body(&header, &mut &bytes[header.len()..])
+	else {
+		panic!("a `Motion` event was decoded as a `Moved` event");
+	};
+	assert!(error.is::<xrb::UnexpectedEventCode>());
+
+	// A reply's header is not an event's header.
+	let Err(ReadError::Other(error)) = Motion::decode_body(&reply_header, &mut &bytes[4..]) else {
+		panic!("a `Motion` event was decoded with a reply's header");
+	};
+	assert_eq!(error.to_string(), "unexpected header: expected event, but found reply");
+
+	let event = Changed {
+		_sequence_: 5,
+		values: vec![1, 2, 3],
+	};
+	let mut bytes = vec![];
+	event.write_to(&mut bytes).unwrap();
+
+	let header = Header::parse(&bytes[..]).unwrap();
+	assert_eq!(Changed::decode_body(&header, &mut &bytes[header.len()..]).unwrap(), event);
+}
+
+#[test]
+fn messages_are_written_with_custom_headers() {
+	let query = Query {
+		names: b"xdm".to_vec(),
+	};
+
+	let mut bytes = vec![];
+	query.write_to(&mut bytes).unwrap();
+
+	// version (1), code (2), length (4), names_len (3), names
+	assert_eq!(bytes, [0, 1, 0, 2, 0, 4, 3, b'x', b'd', b'm']);
+	assert_eq!(<Query as Message>::code(), 2);
+
+	// The header can be read from a copy of the reader to find which message
+	// follows it.
+	let header = XdmcpHeader::read_from(&mut &bytes[..]).unwrap();
+	assert_eq!(header.code(), <Query as Message>::code());
+	assert_eq!(header.content_len(), bytes.len() - 6);
+
+	assert_eq!(Query::read_from(&mut &bytes[..]).unwrap(), query);
+
+	let mut trace = Trace::new(Query::NAME);
+	query.trace(&mut trace).unwrap();
+
+	assert_eq!(trace.bytes(), bytes);
+
+	let fields: Vec<_> = trace
+		.fields()
+		.iter()
+		.map(|field| (field.name(), field.offset(), field.len()))
+		.collect();
+
+	assert_eq!(fields, [("header", 0, 6), ("names_len", 6, 1), ("names", 7, 3)]);
+}
+
+#[test]
+fn traces_record_every_field_as_written() {
+	let request = GetName {
+		detail: 7,
+		point: Point { x: 10, y: 20 },
+		name: b"hi".to_vec(),
+	};
+
+	let mut trace = Trace::new(GetName::NAME);
+	request.trace(&mut trace).unwrap();
+
+	let mut bytes = vec![];
+	request.write_to(&mut bytes).unwrap();
+
+	assert_eq!(trace.bytes(), bytes);
+
+	let fields: Vec<_> = trace
+		.fields()
+		.iter()
+		.map(|field| (field.name(), field.offset(), field.len()))
+		.collect();
+
+	assert_eq!(
+		fields,
+		[
+			("major_opcode", 0, 1),
+			("detail", 1, 1),
+			("length", 2, 2),
+			("point", 4, 4),
+			("name_len", 8, 2),
+			("unused", 10, 2),
+			("name", 12, 2),
+			("unused", 14, 2),
+		]
+	);
+	assert_eq!(trace.fields()[3].value(), Some("Point { x: 10, y: 20 }"));
+}
+
+#[test]
+fn events_are_pretty_printed() {
+	let event = Moved(3, Point { x: 1, y: -1 });
+
+	assert_eq!(
+		event.pretty_print().unwrap(),
+		"Moved (8 bytes)\n  \
+		 0x0000  16                          code: 22\n  \
+		 0x0001  00                          unused\n  \
+		 0x0002  00 03                       sequence: 3\n  \
+		 0x0004  00 01 ff ff                 0: Point { x: 1, y: -1 }"
+	);
+}
+
+/// A serialized example of a definition, and a function which returns whether
+/// that definition could be read from the given bytes.
+type Example = (Vec<u8>, fn(&[u8]) -> bool);
+
+/// Examples of every definition which reads anything. The first byte of
+/// messages is omitted, as it is read to know which message to read.
+fn examples() -> Vec<Example> {
+	fn example(value: &impl Writable, skip: usize) -> Vec<u8> {
+		let mut bytes = vec![];
+		value.write_to(&mut bytes).unwrap();
+
+		bytes.split_off(skip)
+	}
+
+	fn reads<T: Readable>(mut bytes: &[u8]) -> bool {
+		T::read_from(&mut bytes).is_ok()
+	}
+
+	let point = Point { x: 1, y: 2 };
+
+	vec![
+		(example(&point, 0), reads::<Point>),
+		(example(&Dimensions(3, 4), 0), reads::<Dimensions>),
+		(
+			example(
+				&Polyline {
+					points: vec![point, point],
+				},
+				0,
+			),
+			reads::<Polyline>,
+		),
+		(example(&Direction::Left, 0), reads::<Direction>),
+		(example(&Shape::Square(point, 5), 0), reads::<Shape>),
+		(
+			example(
+				&GetName {
+					detail: 0,
+					point,
+					name: b"name".to_vec(),
+				},
+				1,
+			),
+			reads::<GetName>,
+		),
+		(
+			example(
+				&GetNameReply {
+					_sequence_: 0,
+					count: 6,
+				},
+				1,
+			),
+			reads::<GetNameReply>,
+		),
+		(
+			example(
+				&Motion {
+					_sequence_: 0,
+					detail: 7,
+					point,
+				},
+				1,
+			),
+			reads::<Motion>,
+		),
+		(
+			example(
+				&Query {
+					names: b"host".to_vec(),
+				},
+				0,
+			),
+			reads::<Query>,
+		),
+		(
+			example(
+				&Changed {
+					_sequence_: 0,
+					values: vec![8, 9],
+				},
+				1,
+			),
+			reads::<Changed>,
+		),
+	]
+}
+
+#[test]
+fn truncated_input_is_an_error() {
+	for (bytes, reads) in examples() {
+		assert!(reads(&bytes));
+
+		for len in 0..bytes.len() {
+			assert!(!reads(&bytes[..len]), "read from {len} of {} bytes", bytes.len());
+		}
+	}
+}
+
+#[test]
+fn truncated_input_reports_missing_bytes() {
+	let mut bytes = vec![];
+	Point { x: 1, y: 2 }.write_to(&mut bytes).unwrap();
+
+	let Err(ReadError::Other(error)) = Point::read_from(&mut &bytes[..3]) else {
+		panic!("expected an error");
+	};
+
+	assert_eq!(
+		error.downcast_ref(),
+		Some(&xrb::UnexpectedEndOfData {
+			expected: 2,
+			found: 1,
+		})
+	);
+}
+
+#[test]
+fn list_lengths_do_not_allocate_beyond_the_input() {
+	// A length of `u32::MAX` points, followed by only one point.
+	let bytes = [0xff, 0xff, 0xff, 0xff, 0, 1, 0, 2];
+
+	assert!(Polyline::read_from(&mut &bytes[..]).is_err());
+
+	let mut bytes = vec![];
+	Polyline {
+		points: vec![Point { x: 1, y: 2 }; 3],
+	}
+	.write_to(&mut bytes)
+	.unwrap();
+
+	let polyline = Polyline::read_from(&mut &bytes[..]).unwrap();
+	assert_eq!(polyline.points.capacity(), 3);
+}
+
+#[test]
+fn arbitrary_input_does_not_panic() {
+	// A simple xorshift generator, so that the test is deterministic.
+	let mut state: u32 = 0x2545_f491;
+	let mut next = move || {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+
+		state
+	};
+
+	for (example, reads) in examples() {
+		for _ in 0..1000 {
+			let len = next() as usize % (example.len() * 2);
+			let bytes: Vec<u8> = (0..len).map(|_| next().to_le_bytes()[0]).collect();
+
+			// Only whether this panics matters, not whether it succeeds.
+			reads(&bytes);
+		}
+	}
+}
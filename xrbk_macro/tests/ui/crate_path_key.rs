@@ -0,0 +1,15 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::define;
+
+define! {
+	#[xrbk(path = "xrb")]
+	pub struct Point {
+		pub x: i16,
+		pub y: i16,
+	}
+}
+
+fn main() {}
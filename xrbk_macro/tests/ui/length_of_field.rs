@@ -0,0 +1,15 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::define;
+
+define! {
+	pub struct Polyline {
+		pub points_len: u32,
+		#[length(points_len)]
+		pub points: Vec<u32>,
+	}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::define;
+
+define! {
+	pub struct Polyline {
+		let points_len: u32 = self => self.points.len() as u32,
+		#[context(point_len => *point_len as usize)]
+		pub points: Vec<u32>,
+	}
+}
+
+fn main() {}